@@ -0,0 +1,108 @@
+// benches/rendering.rs
+//! Galaxy map drawing benchmark.
+//!
+//! Opens a window and draws the planets and ships of the full-scale
+//! synthetic world (MAX_PLANETS planets, MAX_SHIPS ships) every frame, on
+//! alternate frames through individual macroquad calls and through the
+//! `RenderBatcher`. Each planet is a filled circle with an outline and each
+//! ship a square, as on the map. Reports the median time spent issuing each
+//! frame's shapes, which is the part batching changes; presenting the frame
+//! is left out since it is the same for both.
+//!
+//! Usage: `cargo bench --bench rendering [-- --frames <count>]`. Needs a
+//! display.
+
+use macroquad::prelude::*;
+use stellar_dominion::bench_support::full_scale_game;
+use stellar_dominion::ui_v2::RenderBatcher;
+use std::time::Instant;
+
+const SEED: u64 = 42;
+const DEFAULT_FRAMES: usize = 240;
+const PLANET_RADIUS: f32 = 6.0;
+
+/// Screen point and color of a shape
+type Shape = (Vec2, Color);
+
+fn frames() -> usize {
+    let args: Vec<String> = std::env::args().collect();
+    args.iter().position(|arg| arg == "--frames")
+        .and_then(|i| args.get(i + 1)?.parse().ok())
+        .unwrap_or(DEFAULT_FRAMES)
+}
+
+fn median(mut samples: Vec<f64>) -> f64 {
+    samples.sort_by(|a, b| a.total_cmp(b));
+    samples[samples.len() / 2]
+}
+
+/// Screen points and colors of the world's planets and ships
+fn scene() -> (Vec<Shape>, Vec<Shape>) {
+    let game = full_scale_game(SEED).expect("synthetic world builds");
+    let tick = game.get_current_tick();
+    let palette = [BLUE, RED, GREEN, ORANGE, GRAY];
+    let color = |owner: Option<u8>| palette[owner.map_or(palette.len() - 1, |owner| owner as usize % (palette.len() - 1))];
+    let planets: Vec<Shape> = game.planet_manager.get_all_planets().iter()
+        .map(|planet| {
+            let position = game.physics_engine.planet_position(planet, tick);
+            (vec2(position.x, position.y), color(planet.controller))
+        })
+        .collect();
+    let ships: Vec<Shape> = game.ship_manager.get_all_ships().iter()
+        .map(|ship| (vec2(ship.position.x, ship.position.y), color(Some(ship.owner))))
+        .collect();
+
+    // Fit everything on screen
+    let extent = planets.iter().chain(&ships).map(|(point, _)| point.abs().max_element()).fold(1.0, f32::max);
+    let scale = screen_height().min(screen_width()) / 2.0 / extent * 0.9;
+    let center = vec2(screen_width(), screen_height()) / 2.0;
+    let to_screen = |(point, color): &Shape| (center + *point * scale, *color);
+    (planets.iter().map(to_screen).collect(), ships.iter().map(to_screen).collect())
+}
+
+fn draw_direct(planets: &[Shape], ships: &[Shape]) {
+    for (point, color) in planets {
+        draw_circle(point.x, point.y, PLANET_RADIUS, *color);
+        draw_circle_lines(point.x, point.y, PLANET_RADIUS + 2.0, 1.0, WHITE);
+    }
+    for (point, color) in ships {
+        draw_rectangle(point.x - 2.0, point.y - 2.0, 4.0, 4.0, *color);
+    }
+}
+
+fn draw_batched(batch: &mut RenderBatcher, planets: &[Shape], ships: &[Shape]) {
+    for (point, color) in planets {
+        batch.circle(point.x, point.y, PLANET_RADIUS, *color);
+        batch.circle_lines(point.x, point.y, PLANET_RADIUS + 2.0, 1.0, WHITE);
+    }
+    for (point, color) in ships {
+        batch.rect(point.x - 2.0, point.y - 2.0, 4.0, 4.0, *color);
+    }
+    batch.flush();
+}
+
+#[macroquad::main("Stellar Dominion rendering benchmark")]
+async fn main() {
+    let frames = frames();
+    let (planets, ships) = scene();
+    let mut batch = RenderBatcher::new();
+    let (mut direct, mut batched) = (Vec::new(), Vec::new());
+    for frame in 0..frames * 2 {
+        clear_background(BLACK);
+        let start = Instant::now();
+        if frame % 2 == 0 {
+            draw_direct(&planets, &ships);
+            direct.push(start.elapsed().as_nanos() as f64);
+        } else {
+            draw_batched(&mut batch, &planets, &ships);
+            batched.push(start.elapsed().as_nanos() as f64);
+        }
+        next_frame().await;
+    }
+
+    let (direct, batched) = (median(direct), median(batched));
+    println!("{} planets, {} ships, {} frames each", planets.len(), ships.len(), frames);
+    println!("map/direct   {:>10.1} us", direct / 1000.0);
+    println!("map/batched  {:>10.1} us", batched / 1000.0);
+    println!("speedup      {:>10.2}x", direct / batched);
+}
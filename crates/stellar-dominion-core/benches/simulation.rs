@@ -0,0 +1,160 @@
+// crates/stellar-dominion-core/benches/simulation.rs
+//! Simulation benchmarks and performance regression check.
+//!
+//! Times the hot paths on the synthetic worlds of `bench_support`: a full
+//! `fixed_update` at MAX_PLANETS/MAX_SHIPS scale, routing a batch of events
+//! through the EventBus, and saving the full-scale world to a compressed save
+//! slot and loading it back, as the save/load dialog does.
+//! Each case runs in several batches and reports the median time per
+//! iteration.
+//!
+//! Usage: `cargo bench -p stellar-dominion-core -- [filter] [--save-baseline <file>] [--baseline <file>] [--threshold <percent>]`
+//!
+//! `--save-baseline` writes the timings as JSON. `--baseline` compares against
+//! such a file and exits with status 1 when a case is slower than the baseline
+//! by more than the threshold, 25% unless given.
+
+use stellar_dominion_core::bench_support::{full_scale_game, routing_events, synthetic_game};
+use stellar_dominion_core::systems::save_system::{SaveData, SaveSystem};
+use stellar_dominion_core::{config::FIXED_TIMESTEP, GameResult};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::Instant;
+
+const SEED: u64 = 42;
+const BATCHES: usize = 7;
+const ROUTED_EVENTS: usize = 1000;
+
+#[derive(Default)]
+struct Options {
+    filter: Option<String>,
+    save_baseline: Option<PathBuf>,
+    baseline: Option<PathBuf>,
+    threshold: f64,
+}
+
+impl Options {
+    fn parse() -> Self {
+        let mut options = Options { threshold: 25.0, ..Default::default() };
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--save-baseline" => options.save_baseline = args.next().map(PathBuf::from),
+                "--baseline" => options.baseline = args.next().map(PathBuf::from),
+                "--threshold" => options.threshold = args.next().and_then(|t| t.parse().ok()).unwrap_or(options.threshold),
+                // Passed by cargo bench
+                "--bench" => {}
+                filter if !filter.starts_with("--") => options.filter = Some(filter.to_string()),
+                _ => {}
+            }
+        }
+        options
+    }
+
+    fn wants(&self, name: &str) -> bool {
+        self.filter.as_ref().is_none_or(|filter| name.contains(filter.as_str()))
+    }
+}
+
+/// Median nanoseconds per iteration of `run` over the batches
+fn measure(iterations: u32, mut run: impl FnMut() -> GameResult<()>) -> GameResult<f64> {
+    let mut batches = Vec::with_capacity(BATCHES);
+    for _ in 0..BATCHES {
+        let start = Instant::now();
+        for _ in 0..iterations {
+            run()?;
+        }
+        batches.push(start.elapsed().as_nanos() as f64 / iterations as f64);
+    }
+    batches.sort_by(|a, b| a.total_cmp(b));
+    Ok(batches[BATCHES / 2])
+}
+
+fn run_cases(options: &Options) -> GameResult<BTreeMap<String, f64>> {
+    let mut timings = BTreeMap::new();
+
+    if options.wants("fixed_update/full_scale") {
+        let mut game = full_scale_game(SEED)?;
+        timings.insert("fixed_update/full_scale".to_string(), measure(20, || game.fixed_update(FIXED_TIMESTEP))?);
+    }
+
+    if options.wants("fixed_update/small") {
+        let mut game = synthetic_game(SEED, 10, 20)?;
+        timings.insert("fixed_update/small".to_string(), measure(20, || game.fixed_update(FIXED_TIMESTEP))?);
+    }
+
+    if options.wants("event_routing") {
+        let mut game = full_scale_game(SEED)?;
+        let events = routing_events(&game, ROUTED_EVENTS);
+        let name = format!("event_routing/{}_events", ROUTED_EVENTS);
+        timings.insert(name, measure(20, || {
+            for event in &events {
+                game.queue_event(event.clone());
+            }
+            game.process_queued_events_for_test()
+        })?);
+    }
+
+    if options.wants("save") || options.wants("load") {
+        let game = full_scale_game(SEED)?;
+        // Slots go to a scratch directory so the player's saves/ is never touched
+        let directory = std::env::temp_dir().join(format!("stellar_dominion_bench_{}", std::process::id()));
+        let mut save_system = SaveSystem::with_directory(&directory);
+        if options.wants("save/slot") {
+            timings.insert("save/slot".to_string(), measure(5, || {
+                save_system.save_game_to_slot(SaveData::capture(&game, "bench"))
+            })?);
+        }
+        if options.wants("load/slot") {
+            save_system.save_game_to_slot(SaveData::capture(&game, "bench"))?;
+            let mut loaded = full_scale_game(SEED)?;
+            timings.insert("load/slot".to_string(), measure(5, || {
+                let save_data = save_system.load_game_from_slot("bench")?;
+                loaded.apply_save_data(save_data)
+            })?);
+        }
+        std::fs::remove_dir_all(&directory).ok();
+    }
+
+    Ok(timings)
+}
+
+fn read_baseline(path: &PathBuf) -> BTreeMap<String, f64> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_else(|| {
+            eprintln!("Cannot read baseline {}", path.display());
+            std::process::exit(2);
+        })
+}
+
+fn main() -> GameResult<()> {
+    let options = Options::parse();
+    let baseline = options.baseline.as_ref().map(read_baseline);
+    let timings = run_cases(&options)?;
+
+    let mut regressed = false;
+    for (name, nanos) in &timings {
+        let comparison = match baseline.as_ref().and_then(|baseline| baseline.get(name)) {
+            Some(before) => {
+                let change = (nanos - before) / before * 100.0;
+                let slower = change > options.threshold;
+                regressed |= slower;
+                format!("  {:+6.1}%{}", change, if slower { "  REGRESSED" } else { "" })
+            }
+            None => String::new(),
+        };
+        println!("{:<28} {:>14.0} ns/iter{}", name, nanos, comparison);
+    }
+
+    if let Some(path) = &options.save_baseline {
+        let json = serde_json::to_string_pretty(&timings).expect("timings serialize");
+        std::fs::write(path, json).unwrap_or_else(|e| eprintln!("Cannot write baseline {}: {}", path.display(), e));
+    }
+    if regressed {
+        eprintln!("Slower than the baseline by more than {}%", options.threshold);
+        std::process::exit(1);
+    }
+    Ok(())
+}
@@ -0,0 +1,35 @@
+// crates/stellar-dominion-core/examples/balance_run.rs
+//! Headless balancing run.
+//!
+//! Plays several seeded games without a client and prints each faction's
+//! planet count and stockpile after a fixed number of ticks, so balance
+//! changes can be compared across seeds.
+//!
+//! Usage: `cargo run -p stellar-dominion-core --example balance_run [games] [ticks]`
+
+use stellar_dominion_core::{setup, GameConfiguration, GameResult};
+
+fn main() -> GameResult<()> {
+    let mut args = std::env::args().skip(1).map(|arg| arg.parse::<u64>().ok());
+    let games = args.next().flatten().unwrap_or(5);
+    let ticks = args.next().flatten().unwrap_or(600);
+
+    for seed in 0..games {
+        let config = GameConfiguration {
+            seed,
+            planet_count: 12,
+            ai_opponents: 2,
+            ..Default::default()
+        };
+        let mut game = setup::new_headless_game(config)?;
+        setup::run_ticks(&mut game, ticks)?;
+
+        println!("seed {} after {} ticks:", seed, game.get_current_tick());
+        for faction in game.faction_manager.get_all_factions() {
+            let planets = game.planet_manager.get_planets_by_faction(faction.id);
+            let stockpile: i64 = planets.iter().map(|p| p.resources.current.total()).sum();
+            println!("  {:<20} planets {:>2}  stockpile {:>8}", faction.name, planets.len(), stockpile);
+        }
+    }
+    Ok(())
+}
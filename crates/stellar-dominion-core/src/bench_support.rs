@@ -0,0 +1,143 @@
+// src/bench_support.rs
+//! Synthetic game states for benchmarks and performance tests
+//!
+//! [`synthetic_game`] builds a populated world of any size from a seed, and
+//! [`full_scale_game`] one at the simulation's limits of
+//! [`MAX_PLANETS`](crate::config::MAX_PLANETS) planets and
+//! [`MAX_SHIPS`](crate::config::MAX_SHIPS) ships. The same seed and sizes
+//! always give the same world, so timings taken on different builds measure
+//! the code rather than the layout.
+//!
+//! Each of the [`BENCH_FACTIONS`] factions holds an even share of the settled
+//! planets, every fifth planet is left neutral, and ships start in orbit of
+//! their owner's planets. Autosaving is off so no benchmark touches the disk
+//! unless it means to. The crate's `benches/simulation.rs` uses these states
+//! to time `fixed_update`, event routing and saving and loading.
+
+use crate::core::{GameState, GameResult, GameEvent, SimulationEvent, StateChange};
+use crate::core::rng::unit;
+use crate::core::types::*;
+use crate::config::{MAX_PLANETS, MAX_SHIPS};
+use crate::systems::scenario::{Scenario, ScenarioFaction, ScenarioPlanet, ScenarioShip, SCENARIO_VERSION};
+use std::collections::BTreeSet;
+use std::f32::consts::PI;
+
+/// Factions in every synthetic game; faction 0 is the player
+pub const BENCH_FACTIONS: usize = 4;
+
+/// A scenario with `planets` planets and `ships` ships laid out from `seed`.
+/// Ships are only placed when at least one planet is settled.
+pub fn synthetic_scenario(seed: u64, planets: usize, ships: usize) -> Scenario {
+    let mut rng = seed;
+    let factions = (0..BENCH_FACTIONS)
+        .map(|i| ScenarioFaction {
+            name: format!("Empire {}", i),
+            is_player: i == 0,
+            ai_type: AIPersonality::Balanced,
+            technologies: BTreeSet::new(),
+        })
+        .collect();
+
+    let mut settled = Vec::new();
+    let planets = (0..planets)
+        .map(|i| {
+            let semi_major_axis = 1.0 + i as f32 * 0.25 + unit(&mut rng) * 0.2;
+            let phase = unit(&mut rng) * 2.0 * PI;
+            if i % 5 == 4 {
+                return ScenarioPlanet {
+                    semi_major_axis,
+                    phase,
+                    controller: None,
+                    resources: ResourceBundle::default(),
+                    population: 0,
+                    size: 5,
+                    traits: PlanetTraits::default(),
+                    buildings: Vec::new(),
+                };
+            }
+            let controller = (settled.len() % BENCH_FACTIONS) as FactionId;
+            settled.push((i as PlanetId, controller));
+            ScenarioPlanet {
+                semi_major_axis,
+                phase,
+                controller: Some(controller),
+                resources: ResourceBundle {
+                    minerals: 500,
+                    food: 500,
+                    energy: 500,
+                    alloys: 200,
+                    components: 100,
+                    fuel: 200,
+                },
+                population: 5000 + (unit(&mut rng) * 20000.0) as i32,
+                size: 5,
+                traits: PlanetTraits::default(),
+                buildings: vec![BuildingType::Mine, BuildingType::Farm, BuildingType::PowerPlant],
+            }
+        })
+        .collect();
+
+    let ships = if settled.is_empty() { Vec::new() } else {
+        (0..ships)
+            .map(|i| {
+                let (planet, owner) = settled[i % settled.len()];
+                ScenarioShip { ship_class: ShipClass::ALL[i % ShipClass::ALL.len()], owner, planet }
+            })
+            .collect()
+    };
+
+    Scenario {
+        version: SCENARIO_VERSION,
+        name: format!("Benchmark {}", seed),
+        description: String::new(),
+        configuration: GameConfiguration {
+            seed,
+            ai_opponents: BENCH_FACTIONS - 1,
+            autosave: AutosaveSettings { interval_ticks: 0, ..Default::default() },
+            ..Default::default()
+        },
+        factions,
+        planets,
+        ships,
+        victory_conditions: Vec::new(),
+        scripted_events: Vec::new(),
+        objectives: Vec::new(),
+    }
+}
+
+/// A running game of the synthetic scenario, ready for `fixed_update`
+///
+/// # Errors
+///
+/// Returns `GameError::SystemError` if the game state cannot be initialized,
+/// or the managers' error if the world cannot be built.
+pub fn synthetic_game(seed: u64, planets: usize, ships: usize) -> GameResult<GameState> {
+    let mut game = GameState::new()?;
+    game.start_scenario(&synthetic_scenario(seed, planets, ships))?;
+    Ok(game)
+}
+
+/// A running game with as many planets and ships as the simulation supports
+///
+/// # Errors
+///
+/// As [`synthetic_game`].
+pub fn full_scale_game(seed: u64) -> GameResult<GameState> {
+    synthetic_game(seed, MAX_PLANETS, MAX_SHIPS)
+}
+
+/// `count` events of the kinds systems route most often, spread over the
+/// game's planets and ships, for timing the EventBus on its own
+pub fn routing_events(game: &GameState, count: usize) -> Vec<GameEvent> {
+    let planets: Vec<PlanetId> = game.planet_manager.get_all_planets().iter().map(|planet| planet.id).collect();
+    let ships: Vec<ShipId> = game.ship_manager.get_all_ships().iter().map(|ship| ship.id).collect();
+    (0..count)
+        .filter_map(|i| match i % 3 {
+            0 => planets.get(i % planets.len().max(1)).map(|planet| GameEvent::SimulationEvent(
+                SimulationEvent::ResourcesProduced { planet: *planet, resources: ResourceBundle::default() },
+            )),
+            1 => planets.get(i % planets.len().max(1)).map(|planet| GameEvent::StateChanged(StateChange::PlanetUpdated(*planet))),
+            _ => ships.get(i % ships.len().max(1)).map(|ship| GameEvent::StateChanged(StateChange::ShipUpdated(*ship))),
+        })
+        .collect()
+}
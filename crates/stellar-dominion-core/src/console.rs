@@ -0,0 +1,242 @@
+// src/console.rs
+//! Developer console commands, built with the `debug` feature
+//!
+//! The client's console hands each line typed into it to [`run`]. A line is
+//! parsed into a [`ConsoleCommand`] and carried out against the game: orders
+//! a player could give go through the EventBus as `PlayerCommand`s, while
+//! cheats no player command covers, such as granting resources or placing
+//! ships, call the managers directly.
+//!
+//! ```text
+//! give <planet> <resource> <amount>
+//! spawn ship <class> <owner> <x> <y>
+//! tick <count>
+//! teleport ship <ship> <x> <y>
+//! move ship <ship> <x> <y>
+//! log [count]
+//! help
+//! ```
+
+use crate::core::{GameEvent, GameState, PlayerCommand};
+use crate::core::types::*;
+use crate::{GameError, GameResult};
+
+/// Most ticks a single `tick` command may run, so a typo cannot hang the game
+pub const MAX_CONSOLE_TICKS: u64 = 100_000;
+
+/// Log records `log` lists when not given a count
+pub const CONSOLE_LOG_LINES: usize = 10;
+
+/// Summary of the commands, printed by `help`
+pub const CONSOLE_HELP: &str = "give <planet> <resource> <amount> | spawn ship <class> <owner> <x> <y> | \
+tick <count> | teleport ship <ship> <x> <y> | move ship <ship> <x> <y> | log [count] | help";
+
+/// A parsed console line
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConsoleCommand {
+    /// Add resources to a planet's stockpile, up to its storage capacity
+    Give {
+        /// Planet receiving them
+        planet: PlanetId,
+        /// Resource to add
+        resource: ResourceType,
+        /// Amount asked for; what does not fit is left out
+        amount: i32,
+    },
+    /// Build a ship out of nothing at a position
+    Spawn {
+        /// Class of the ship
+        class: ShipClass,
+        /// Faction owning it
+        owner: FactionId,
+        /// Where it appears
+        position: Vector2,
+    },
+    /// Run the simulation forward, even while paused
+    Tick(u64),
+    /// Put a ship somewhere at once, dropping its course
+    Teleport {
+        /// Ship to move
+        ship: ShipId,
+        /// Where it ends up
+        position: Vector2,
+    },
+    /// Order a ship to fly somewhere, as the player would
+    Move {
+        /// Ship to order
+        ship: ShipId,
+        /// Where it flies to
+        target: Vector2,
+    },
+    /// List the latest log records, one per line
+    Log(usize),
+    /// List the commands
+    Help,
+}
+
+impl ConsoleCommand {
+    /// Parse a console line; names are matched ignoring case
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::InvalidCommand` naming what could not be read.
+    pub fn parse(line: &str) -> GameResult<Self> {
+        Self::parse_words(line).map_err(|reason| GameError::InvalidCommand { command: line.trim().to_string(), reason })
+    }
+
+    fn parse_words(line: &str) -> Result<Self, String> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let Some((&verb, args)) = words.split_first() else {
+            return Err(usage("Empty command"));
+        };
+        match (verb.to_lowercase().as_str(), args) {
+            ("give", [planet, resource, amount]) => Ok(ConsoleCommand::Give {
+                planet: number(planet, "planet")?,
+                resource: named(resource, &ResourceType::ALL, "resource")?,
+                amount: number(amount, "amount")?,
+            }),
+            ("spawn", [kind, class, owner, x, y]) if kind.eq_ignore_ascii_case("ship") => Ok(ConsoleCommand::Spawn {
+                class: named(class, &ShipClass::ALL, "ship class")?,
+                owner: number(owner, "owner")?,
+                position: position(x, y)?,
+            }),
+            ("tick", [count]) => Ok(ConsoleCommand::Tick(number(count, "tick count")?)),
+            ("teleport", [kind, ship, x, y]) if kind.eq_ignore_ascii_case("ship") => Ok(ConsoleCommand::Teleport {
+                ship: number(ship, "ship")?,
+                position: position(x, y)?,
+            }),
+            ("move", [kind, ship, x, y]) if kind.eq_ignore_ascii_case("ship") => Ok(ConsoleCommand::Move {
+                ship: number(ship, "ship")?,
+                target: position(x, y)?,
+            }),
+            ("log", []) => Ok(ConsoleCommand::Log(CONSOLE_LOG_LINES)),
+            ("log", [count]) => Ok(ConsoleCommand::Log(number(count, "record count")?)),
+            ("help", []) => Ok(ConsoleCommand::Help),
+            ("give" | "spawn" | "tick" | "teleport" | "move" | "log" | "help", _) => {
+                Err(usage(&format!("Wrong arguments for '{}'", verb)))
+            }
+            _ => Err(usage(&format!("Unknown command '{}'", verb))),
+        }
+    }
+}
+
+/// Parse and carry out a console line, returning the text to show for it;
+/// `log` shows a line per record
+///
+/// # Errors
+///
+/// Returns the parse error, or the error of the manager call or tick that
+/// failed.
+pub fn run(game: &mut GameState, line: &str) -> GameResult<String> {
+    execute(game, &ConsoleCommand::parse(line)?)
+}
+
+/// Carry out a console command, returning the text to show for it
+///
+/// # Errors
+///
+/// Returns the error of the manager call or tick that failed;
+/// `GameError::InvalidOperation` for a tick count above [`MAX_CONSOLE_TICKS`].
+pub fn execute(game: &mut GameState, command: &ConsoleCommand) -> GameResult<String> {
+    match *command {
+        ConsoleCommand::Give { planet, resource, amount } => {
+            if amount < 0 {
+                return Err(GameError::InvalidOperation("Amount to give must not be negative".into()));
+            }
+            // Fill up to capacity rather than failing on a generous amount
+            let space = game.planet_manager.get_planet(planet)?.resources.available_space().get(resource);
+            let given = amount.min(space.max(0));
+            let mut bundle = ResourceBundle::default();
+            bundle.set(resource, given);
+            game.planet_manager.add_resources(planet, bundle)?;
+            Ok(format!("Gave planet {} {} {:?}", planet, given, resource))
+        }
+        ConsoleCommand::Spawn { class, owner, position } => {
+            game.faction_manager.get_faction(owner)?;
+            let ship = game.ship_manager.create_ship(class, position, owner)?;
+            game.refresh_spatial_index();
+            Ok(format!("Spawned {:?} {} for faction {} at ({}, {})", class, ship, owner, position.x, position.y))
+        }
+        ConsoleCommand::Tick(ticks) => {
+            if ticks > MAX_CONSOLE_TICKS {
+                return Err(GameError::InvalidOperation(format!("At most {} ticks at a time", MAX_CONSOLE_TICKS)));
+            }
+            game.step_ticks(ticks)?;
+            Ok(format!("Ran {} ticks, now at tick {}", ticks, game.get_current_tick()))
+        }
+        ConsoleCommand::Teleport { ship, position } => {
+            game.ship_manager.update_position(ship, position)?;
+            game.refresh_spatial_index();
+            Ok(format!("Moved ship {} to ({}, {})", ship, position.x, position.y))
+        }
+        ConsoleCommand::Move { ship, target } => {
+            game.ship_manager.get_ship(ship)?;
+            game.queue_event(GameEvent::PlayerCommand(PlayerCommand::MoveShip { ship, target }));
+            Ok(format!("Ordered ship {} to ({}, {})", ship, target.x, target.y))
+        }
+        ConsoleCommand::Log(count) => {
+            let records = crate::logging::recent(count);
+            if records.is_empty() {
+                return Ok("No log records".to_string());
+            }
+            Ok(records.iter().map(ToString::to_string).collect::<Vec<_>>().join("\n"))
+        }
+        ConsoleCommand::Help => Ok(CONSOLE_HELP.to_string()),
+    }
+}
+
+fn usage(problem: &str) -> String {
+    format!("{}; try: {}", problem, CONSOLE_HELP)
+}
+
+fn number<T: std::str::FromStr>(word: &str, what: &str) -> Result<T, String> {
+    word.parse().map_err(|_| format!("'{}' is not a valid {}", word, what))
+}
+
+fn position(x: &str, y: &str) -> Result<Vector2, String> {
+    let position = Vector2::new(number(x, "x coordinate")?, number(y, "y coordinate")?);
+    if !position.x.is_finite() || !position.y.is_finite() {
+        return Err("Coordinates must be finite".into());
+    }
+    Ok(position)
+}
+
+// Match a name against the Debug names of an enum's variants
+fn named<T: Copy + std::fmt::Debug>(word: &str, all: &[T], what: &str) -> Result<T, String> {
+    all.iter()
+        .copied()
+        .find(|candidate| format!("{:?}", candidate).eq_ignore_ascii_case(word))
+        .ok_or_else(|| {
+            let names: Vec<String> = all.iter().map(|candidate| format!("{:?}", candidate).to_lowercase()).collect();
+            format!("Unknown {} '{}'; expected one of {}", what, word, names.join(", "))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_reads_the_documented_commands() {
+        assert_eq!(
+            ConsoleCommand::parse("give 1 minerals 1000").unwrap(),
+            ConsoleCommand::Give { planet: 1, resource: ResourceType::Minerals, amount: 1000 }
+        );
+        assert_eq!(
+            ConsoleCommand::parse("spawn ship Warship 2 100 100").unwrap(),
+            ConsoleCommand::Spawn { class: ShipClass::Warship, owner: 2, position: Vector2::new(100.0, 100.0) }
+        );
+        assert_eq!(ConsoleCommand::parse("  TICK 500 ").unwrap(), ConsoleCommand::Tick(500));
+        assert_eq!(
+            ConsoleCommand::parse("teleport ship 3 50 50.5").unwrap(),
+            ConsoleCommand::Teleport { ship: 3, position: Vector2::new(50.0, 50.5) }
+        );
+        assert_eq!(ConsoleCommand::parse("log").unwrap(), ConsoleCommand::Log(CONSOLE_LOG_LINES));
+        assert_eq!(ConsoleCommand::parse("log 3").unwrap(), ConsoleCommand::Log(3));
+        assert_eq!(ConsoleCommand::parse("help").unwrap(), ConsoleCommand::Help);
+
+        for bad in ["", "give 1 unobtainium 5", "tick -3", "spawn ship warship 2 1", "teleport ship 3 inf 0", "warp 9"] {
+            assert!(matches!(ConsoleCommand::parse(bad), Err(GameError::InvalidCommand { .. })), "{:?}", bad);
+        }
+    }
+}
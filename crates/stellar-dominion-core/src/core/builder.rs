@@ -0,0 +1,122 @@
+// src/core/builder.rs
+//! Building custom games in code
+//!
+//! [`GameStateBuilder`] puts together the same declarative description a
+//! scenario file holds, one faction, planet or ship at a time, and starts a
+//! game from it with [`GameState::start_scenario`]. As in scenario files,
+//! factions, planets and ships are numbered in the order they are added,
+//! starting at 0, and refer to each other by those ids. [`GameStateBuilder::build`]
+//! checks every reference before any manager is touched.
+//!
+//! ```rust
+//! use stellar_dominion_core::prelude::*;
+//! use stellar_dominion_core::ShipClass;
+//!
+//! let game = GameStateBuilder::new()
+//!     .with_seed(7)
+//!     .with_faction(ScenarioFaction::new("Terran Union", true))
+//!     .with_faction(ScenarioFaction::new("Zorg Hive", false))
+//!     .with_planet(ScenarioPlanet { controller: Some(0), population: 1000, ..ScenarioPlanet::new(1.0) })
+//!     .with_planet(ScenarioPlanet { controller: Some(1), population: 800, ..ScenarioPlanet::new(2.5) })
+//!     .with_ship(ScenarioShip { ship_class: ShipClass::Scout, owner: 0, planet: 0 })
+//!     .build()
+//!     .expect("Failed to build game");
+//! assert_eq!(game.planet_manager.get_all_planets().len(), 2);
+//! ```
+
+use super::{GameResult, GameState};
+use super::types::GameConfiguration;
+use crate::systems::{Scenario, VictoryCondition};
+use crate::systems::scenario::{ScenarioFaction, ScenarioPlanet, ScenarioShip, SCENARIO_VERSION};
+
+/// Name given to games put together with the builder
+const BUILT_GAME_NAME: &str = "Custom game";
+
+/// Fluent description of a starting world, turned into a running game by
+/// [`GameStateBuilder::build`]
+#[derive(Debug, Clone)]
+pub struct GameStateBuilder {
+    scenario: Scenario,
+}
+
+impl GameStateBuilder {
+    /// An empty world with the default configuration
+    pub fn new() -> Self {
+        Self {
+            scenario: Scenario {
+                version: SCENARIO_VERSION,
+                name: BUILT_GAME_NAME.to_string(),
+                description: String::new(),
+                configuration: GameConfiguration::default(),
+                factions: Vec::new(),
+                planets: Vec::new(),
+                ships: Vec::new(),
+                victory_conditions: Vec::new(),
+                scripted_events: Vec::new(),
+                objectives: Vec::new(),
+            },
+        }
+    }
+
+    /// Record the configuration with the game; the world still comes from
+    /// the builder
+    pub fn with_configuration(mut self, configuration: GameConfiguration) -> Self {
+        self.scenario.configuration = configuration;
+        self
+    }
+
+    /// Seed the game's random numbers
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.scenario.configuration.seed = seed;
+        self
+    }
+
+    /// Add a faction, whose id is the number of factions added before it
+    pub fn with_faction(mut self, faction: ScenarioFaction) -> Self {
+        self.scenario.factions.push(faction);
+        self
+    }
+
+    /// Add a planet, whose id is the number of planets added before it
+    pub fn with_planet(mut self, planet: ScenarioPlanet) -> Self {
+        self.scenario.planets.push(planet);
+        self
+    }
+
+    /// Add a ship next to one of the planets
+    pub fn with_ship(mut self, ship: ScenarioShip) -> Self {
+        self.scenario.ships.push(ship);
+        self
+    }
+
+    /// Add a condition that wins the game; the first one met decides it
+    pub fn with_victory_condition(mut self, condition: VictoryCondition) -> Self {
+        self.scenario.victory_conditions.push(condition);
+        self
+    }
+
+    /// The description built so far, as a scenario file would hold it
+    pub fn scenario(&self) -> &Scenario {
+        &self.scenario
+    }
+
+    /// Start a game in the described world, ready for `fixed_update`
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::SaveError` if the world is incomplete or
+    /// inconsistent: no faction or planet, more than one player faction, or
+    /// a planet or ship naming a faction or planet that was not added. Also
+    /// returns the error of any manager that refuses part of the world.
+    pub fn build(self) -> GameResult<GameState> {
+        let mut game = GameState::new()?;
+        game.start_scenario(&self.scenario)?;
+        Ok(game)
+    }
+}
+
+impl Default for GameStateBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,312 @@
+// src/core/command_validator.rs
+//! Checks player commands against the managers before systems see them
+//!
+//! GameState runs every command through [`CommandValidator::validate`] before
+//! routing it. A command naming a planet, ship or faction that does not
+//! exist, tying together entities of different factions, or costing more
+//! than its planet holds is rejected with `StateChange::CommandRejected`
+//! instead of failing partway through the systems.
+//!
+//! Commands do not say who issued them, since the AI and the UI share them.
+//! Commands issued for a known faction through `GameState::issue_command` are
+//! also checked with [`CommandValidator::validate_issuer`], which refuses
+//! orders to ships and planets the faction does not control.
+//!
+//! Cargo moves only between a ship and the planet of its own faction it
+//! orbits, by the rules of [`CargoSystem`]. Colonizing takes a colony ship
+//! with colonists aboard, orbiting a planet no faction holds. Bombarding
+//! takes a warship orbiting a planet another faction holds.
+//!
+//! Market orders are checked against the current quotes: a purchase must be
+//! affordable and fit the planet's storage, and a sale must be in stock.
+//!
+//! Non-scoring factions such as the pirates take no part in diplomacy:
+//! treaties and trade offers to or from them are refused.
+
+use super::events::PlayerCommand;
+use super::types::*;
+use crate::managers::{PlanetManager, ShipManager, FactionManager, validation::{validate_name, validate_control_group}};
+use crate::systems::{CargoSystem, ConstructionSystem, MarketSystem, TimeManager};
+
+/// Read-only view of the managers a command is validated against
+pub struct CommandValidator<'a> {
+    planets: &'a PlanetManager,
+    ships: &'a ShipManager,
+    factions: &'a FactionManager,
+    construction: &'a ConstructionSystem,
+    market: &'a MarketSystem,
+}
+
+impl<'a> CommandValidator<'a> {
+    /// Validator reading the given managers
+    pub fn new(planets: &'a PlanetManager, ships: &'a ShipManager, factions: &'a FactionManager, construction: &'a ConstructionSystem, market: &'a MarketSystem) -> Self {
+        Self { planets, ships, factions, construction, market }
+    }
+
+    /// Check that the command's targets exist, belong together and are affordable
+    pub fn validate(&self, command: &PlayerCommand) -> GameResult<()> {
+        match command {
+            PlayerCommand::BuildStructure { planet, .. }
+            | PlayerCommand::AllocateWorkers { planet, .. }
+            | PlayerCommand::SetStoragePriority { planet, .. }
+            | PlayerCommand::SetPowerPriority { planet, .. }
+            | PlayerCommand::SetTaxRate { planet, .. } => {
+                self.planets.get_planet(*planet)?;
+            }
+            PlayerCommand::BuildShip { planet, ship_class } => {
+                let (cost, _) = self.construction.get_ship_cost(*ship_class)
+                    .ok_or_else(|| GameError::InvalidTarget(format!("Unknown ship class: {:?}", ship_class)))?;
+                let planet = self.planets.get_planet(*planet)?;
+                if planet.controller.is_none() {
+                    return Err(GameError::InvalidTarget(format!("Planet {} has no one to crew a ship", planet.id)));
+                }
+                require_stock(planet, cost)?;
+            }
+            PlayerCommand::BuildDesignedShip { planet, design } => {
+                let planet = self.planets.get_planet(*planet)?;
+                let Some(controller) = planet.controller else {
+                    return Err(GameError::InvalidTarget(format!("Planet {} has no one to crew a ship", planet.id)));
+                };
+                let design = self.factions.get_faction(controller)?.ship_design(*design)
+                    .ok_or_else(|| GameError::not_found(EntityKind::ShipDesign, *design))?;
+                require_stock(planet, &design.stats().cost)?;
+            }
+            PlayerCommand::SaveShipDesign { faction, design } => {
+                self.factions.get_faction(*faction)?;
+                design.validate()?;
+            }
+            PlayerCommand::RenameEntity { target: NamedEntity::Planet(planet), name } => {
+                self.planets.get_planet(*planet)?;
+                validate_name(name, "Planet")?;
+            }
+            PlayerCommand::RenameEntity { target: NamedEntity::Ship(ship), name } => {
+                self.ships.get_ship(*ship)?;
+                validate_name(name, "Ship")?;
+            }
+            PlayerCommand::AssignControlGroup { faction, group, members } => {
+                self.factions.get_faction(*faction)?;
+                validate_control_group(*group)?;
+                for member in members {
+                    let controlled = match member {
+                        NamedEntity::Planet(planet) => self.planets.get_planet(*planet)?.controller == Some(*faction),
+                        NamedEntity::Ship(ship) => self.ships.get_ship(*ship)?.owner == *faction,
+                    };
+                    if !controlled {
+                        return Err(GameError::InvalidTarget(format!("Faction {} does not control {:?}", faction, member)));
+                    }
+                }
+            }
+            PlayerCommand::TransferResources { from, to, resources } => {
+                resources.validate_non_negative()?;
+                let source = self.planets.get_planet(*from)?;
+                let destination = self.planets.get_planet(*to)?;
+                let Some(owner) = source.controller else {
+                    return Err(GameError::InvalidTarget(format!("Planet {} has no stockpile to send from", from)));
+                };
+                if destination.controller != Some(owner) {
+                    return Err(GameError::not_owned(owner, EntityKind::Planet, *to));
+                }
+                require_stock(source, resources)?;
+            }
+            PlayerCommand::MoveShip { ship, .. }
+            | PlayerCommand::CancelTradeRoute(ship)
+            | PlayerCommand::RecallShip(ship)
+            | PlayerCommand::StopShip(ship) => {
+                self.ships.get_ship(*ship)?;
+            }
+            PlayerCommand::AttackTarget { attacker, target } => {
+                let attacker = self.ships.get_ship(*attacker)?;
+                let target = self.ships.get_ship(*target)?;
+                if attacker.owner == target.owner {
+                    return Err(GameError::InvalidTarget(format!("Ship {} belongs to the attacker's own faction", target.id)));
+                }
+            }
+            PlayerCommand::ColonizePlanet { ship, planet } => {
+                let ship = self.ships.get_ship(*ship)?;
+                let planet = self.planets.get_planet(*planet)?;
+                if ship.ship_class != ShipClass::Colony {
+                    return Err(GameError::unsuitable_ship(ship, "found a colony"));
+                }
+                if ship.cargo.population <= 0 {
+                    return Err(GameError::InvalidTarget(format!("Ship {} carries no colonists", ship.id)));
+                }
+                if ship.status != ShipStatus::Orbiting(planet.id) {
+                    return Err(GameError::NotInOrbit { ship: ship.id, planet: planet.id });
+                }
+                if let Some(owner) = planet.controller {
+                    return Err(GameError::InvalidTarget(format!("Planet {} is already held by faction {}", planet.id, owner)));
+                }
+            }
+            PlayerCommand::BombardPlanet { ship, planet } => {
+                let ship = self.ships.get_ship(*ship)?;
+                let planet = self.planets.get_planet(*planet)?;
+                if ship.ship_class != ShipClass::Warship {
+                    return Err(GameError::unsuitable_ship(ship, "bombard a planet"));
+                }
+                if ship.status != ShipStatus::Orbiting(planet.id) {
+                    return Err(GameError::NotInOrbit { ship: ship.id, planet: planet.id });
+                }
+                if planet.controller.is_none_or(|owner| owner == ship.owner) {
+                    return Err(GameError::InvalidTarget(format!("Planet {} has no enemy to bombard", planet.id)));
+                }
+            }
+            PlayerCommand::InvadePlanet { ship, planet } => {
+                self.ships.get_ship(*ship)?;
+                self.planets.get_planet(*planet)?;
+            }
+            PlayerCommand::EmbarkTroops { ship, planet, troops } => {
+                let planet = self.ship_at_own_planet(*ship, *planet)?;
+                if *troops <= 0 {
+                    return Err(GameError::invalid_value("troops", "must be positive"));
+                }
+                if *troops > planet.population.allocation.military {
+                    return Err(GameError::capacity("military workers", *troops, planet.population.allocation.military));
+                }
+            }
+            PlayerCommand::LoadShipCargo { ship, planet, resources, population } => {
+                CargoSystem::check_load(self.ships.get_ship(*ship)?, self.planets.get_planet(*planet)?, resources, *population)?;
+            }
+            PlayerCommand::UnloadShipCargo { ship, planet } => {
+                CargoSystem::check_unload(self.ships.get_ship(*ship)?, self.planets.get_planet(*planet)?)?;
+            }
+            PlayerCommand::CreateTradeRoute(route) => {
+                self.ships.get_ship(route.ship)?;
+                self.planets.get_planet(route.from)?;
+                self.planets.get_planet(route.to)?;
+                route.resources.validate_non_negative()?;
+            }
+            PlayerCommand::SetResearchTarget { faction, .. }
+            | PlayerCommand::SetFactionTaxRate { faction, .. }
+            | PlayerCommand::RespondToInbox { faction, .. }
+            | PlayerCommand::DismissNotification { faction, .. } => {
+                self.factions.get_faction(*faction)?;
+            }
+            PlayerCommand::DeclareWar { from, to } => {
+                self.factions.get_faction(*from)?;
+                self.factions.get_faction(*to)?;
+            }
+            PlayerCommand::ProposeTreaty { from, to, .. }
+            | PlayerCommand::OfferTrade { from, to, .. } => {
+                for faction in [*from, *to] {
+                    if !self.factions.get_faction(faction)?.is_scoring() {
+                        return Err(GameError::InvalidTarget(format!("Faction {} does not negotiate", faction)));
+                    }
+                }
+            }
+            PlayerCommand::MarketBuy { faction, planet, resource, amount } => {
+                let cost = self.market.buy_cost(*resource, *amount)?;
+                let credits = self.factions.get_faction(*faction)?.credits;
+                if credits < cost {
+                    return Err(GameError::InsufficientCredits { required: cost, available: credits });
+                }
+                let planet = self.faction_planet(*faction, *planet)?;
+                let mut delivery = ResourceBundle::default();
+                delivery.set(*resource, *amount);
+                if !planet.resources.can_store(&delivery) {
+                    return Err(GameError::storage_full(&planet.resources, &delivery));
+                }
+            }
+            PlayerCommand::MarketSell { faction, planet, resource, amount } => {
+                self.market.sell_revenue(*resource, *amount)?;
+                self.factions.get_faction(*faction)?;
+                let planet = self.faction_planet(*faction, *planet)?;
+                let mut goods = ResourceBundle::default();
+                goods.set(*resource, *amount);
+                require_stock(planet, &goods)?;
+            }
+            PlayerCommand::SetGameSpeed(speed) => TimeManager::check_speed_multiplier(*speed)?,
+            PlayerCommand::FastForward { ticks } => TimeManager::check_fast_forward(*ticks)?,
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Check that `issuer` controls the ships, planets and faction the command gives orders to
+    pub fn validate_issuer(&self, issuer: FactionId, command: &PlayerCommand) -> GameResult<()> {
+        let ship = match command {
+            PlayerCommand::MoveShip { ship, .. }
+            | PlayerCommand::AttackTarget { attacker: ship, .. }
+            | PlayerCommand::ColonizePlanet { ship, .. }
+            | PlayerCommand::EmbarkTroops { ship, .. }
+            | PlayerCommand::InvadePlanet { ship, .. }
+            | PlayerCommand::BombardPlanet { ship, .. }
+            | PlayerCommand::LoadShipCargo { ship, .. }
+            | PlayerCommand::UnloadShipCargo { ship, .. }
+            | PlayerCommand::CancelTradeRoute(ship)
+            | PlayerCommand::RecallShip(ship)
+            | PlayerCommand::StopShip(ship)
+            | PlayerCommand::RenameEntity { target: NamedEntity::Ship(ship), .. } => Some(*ship),
+            PlayerCommand::CreateTradeRoute(route) => Some(route.ship),
+            _ => None,
+        };
+        let planet = match command {
+            PlayerCommand::BuildStructure { planet, .. }
+            | PlayerCommand::BuildShip { planet, .. }
+            | PlayerCommand::BuildDesignedShip { planet, .. }
+            | PlayerCommand::AllocateWorkers { planet, .. }
+            | PlayerCommand::SetStoragePriority { planet, .. }
+            | PlayerCommand::SetPowerPriority { planet, .. }
+            | PlayerCommand::SetTaxRate { planet, .. }
+            | PlayerCommand::RenameEntity { target: NamedEntity::Planet(planet), .. }
+            | PlayerCommand::TransferResources { from: planet, .. } => Some(*planet),
+            _ => None,
+        };
+        let faction = match command {
+            PlayerCommand::SetResearchTarget { faction, .. }
+            | PlayerCommand::SetFactionTaxRate { faction, .. }
+            | PlayerCommand::SaveShipDesign { faction, .. }
+            | PlayerCommand::AssignControlGroup { faction, .. }
+            | PlayerCommand::RespondToInbox { faction, .. }
+            | PlayerCommand::ProposeTreaty { from: faction, .. }
+            | PlayerCommand::DeclareWar { from: faction, .. }
+            | PlayerCommand::OfferTrade { from: faction, .. }
+            | PlayerCommand::MarketBuy { faction, .. }
+            | PlayerCommand::MarketSell { faction, .. }
+            | PlayerCommand::DismissNotification { faction, .. } => Some(*faction),
+            _ => None,
+        };
+
+        if let Some(ship) = ship {
+            if self.ships.get_ship(ship)?.owner != issuer {
+                return Err(GameError::not_owned(issuer, EntityKind::Ship, ship));
+            }
+        }
+        if let Some(planet) = planet {
+            if self.planets.get_planet(planet)?.controller != Some(issuer) {
+                return Err(GameError::not_owned(issuer, EntityKind::Planet, planet));
+            }
+        }
+        if let Some(faction) = faction.filter(|faction| *faction != issuer) {
+            return Err(GameError::not_owned(issuer, EntityKind::Faction, faction));
+        }
+        Ok(())
+    }
+
+    /// The planet, if the faction controls it
+    fn faction_planet(&self, faction: FactionId, planet: PlanetId) -> GameResult<&'a Planet> {
+        let planet = self.planets.get_planet(planet)?;
+        if planet.controller != Some(faction) {
+            return Err(GameError::not_owned(faction, EntityKind::Planet, planet.id));
+        }
+        Ok(planet)
+    }
+
+    /// The planet, if the ship's faction controls it
+    fn ship_at_own_planet(&self, ship: ShipId, planet: PlanetId) -> GameResult<&'a Planet> {
+        let owner = self.ships.get_ship(ship)?.owner;
+        let planet = self.planets.get_planet(planet)?;
+        if planet.controller != Some(owner) {
+            return Err(GameError::not_owned(owner, EntityKind::Planet, planet.id));
+        }
+        Ok(planet)
+    }
+}
+
+/// Fail unless the planet's stockpile covers `cost`
+fn require_stock(planet: &Planet, cost: &ResourceBundle) -> GameResult<()> {
+    if planet.resources.current.can_afford(cost) {
+        Ok(())
+    } else {
+        Err(GameError::InsufficientResources { required: *cost, available: planet.resources.current })
+    }
+}
@@ -0,0 +1,177 @@
+// src/core/event_trace.rs
+//! Tracing of the events passing through the EventBus
+//!
+//! An [`EventTracer`] attached with `EventBus::set_tracer` hears of every
+//! event when it is queued, with the tick and the system that queued it, and
+//! again when GameState dispatches it, with the systems it is routed to.
+//! Nothing is traced while no tracer is attached.
+//!
+//! [`JsonlTracer`] writes one JSON object per line to stdout or a file, for
+//! reading after a debugging session; [`TraceCollector`] keeps the entries in
+//! memory for tests and tools that inspect them while the game runs.
+
+use super::events::{GameEvent, SystemId};
+use super::types::{GameError, GameResult};
+use std::fs::File;
+use std::io::{BufWriter, Stdout, Write};
+use std::path::Path;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Hook told about events as the EventBus queues and dispatches them.
+/// Every method does nothing unless overridden.
+pub trait EventTracer {
+    /// An event was queued during `tick`; `source` is the system whose update
+    /// or event handler queued it, None for events queued by GameState's own
+    /// tick processing or from outside the simulation
+    fn event_queued(&mut self, _tick: u64, _source: Option<SystemId>, _event: &GameEvent) {}
+
+    /// An event queued during `tick` was routed to `systems`, in the order
+    /// they handle it
+    fn event_dispatched(&mut self, _tick: u64, _event: &GameEvent, _systems: &[SystemId]) {}
+}
+
+/// One traced step, as kept by a [`TraceCollector`]
+#[derive(Debug, Clone)]
+pub enum TraceEntry {
+    /// The event was queued
+    Queued {
+        /// Tick the event belongs to
+        tick: u64,
+        /// System that queued it, if any
+        source: Option<SystemId>,
+        /// The event
+        event: GameEvent,
+    },
+    /// The event was routed to its subscribers
+    Dispatched {
+        /// Tick the event belongs to
+        tick: u64,
+        /// Systems that handled it, in order
+        systems: Vec<SystemId>,
+        /// The event
+        event: GameEvent,
+    },
+}
+
+/// Tracer keeping every entry in memory. Clones share the entries, so one
+/// clone can be attached to the bus and another read.
+#[derive(Debug, Clone, Default)]
+pub struct TraceCollector {
+    entries: Rc<RefCell<Vec<TraceEntry>>>,
+}
+
+impl TraceCollector {
+    /// Collector with no entries yet
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Entries traced so far, oldest first
+    pub fn entries(&self) -> Vec<TraceEntry> {
+        self.entries.borrow().clone()
+    }
+
+    /// Forget the entries traced so far
+    pub fn clear(&self) {
+        self.entries.borrow_mut().clear();
+    }
+
+    fn push(&self, entry: TraceEntry) {
+        self.entries.borrow_mut().push(entry);
+    }
+}
+
+impl EventTracer for TraceCollector {
+    fn event_queued(&mut self, tick: u64, source: Option<SystemId>, event: &GameEvent) {
+        self.push(TraceEntry::Queued { tick, source, event: event.clone() });
+    }
+
+    fn event_dispatched(&mut self, tick: u64, event: &GameEvent, systems: &[SystemId]) {
+        self.push(TraceEntry::Dispatched { tick, systems: systems.to_vec(), event: event.clone() });
+    }
+}
+
+/// Tracer writing a JSON object per line:
+/// `{"tick":3,"phase":"queued","source":"ResourceSystem","event":"..."}` or
+/// `{"tick":3,"phase":"dispatched","systems":["HistorySystem"],"event":"..."}`.
+/// Events are written in their debug form. Write errors are ignored so a
+/// full disk never stops the game.
+pub struct JsonlTracer<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> JsonlTracer<W> {
+    /// Tracer writing to any writer
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Give back the writer, flushed
+    pub fn into_inner(mut self) -> W {
+        self.writer.flush().ok();
+        self.writer
+    }
+
+    fn write_line(&mut self, line: serde_json::Value) {
+        writeln!(self.writer, "{}", line).ok();
+    }
+}
+
+impl JsonlTracer<Stdout> {
+    /// Tracer printing to standard output
+    pub fn stdout() -> Self {
+        Self::new(std::io::stdout())
+    }
+}
+
+impl JsonlTracer<BufWriter<File>> {
+    /// Tracer writing to a new file at `path`, replacing any file there
+    pub fn create(path: &Path) -> GameResult<Self> {
+        let file = File::create(path)
+            .map_err(|e| GameError::Io(format!("Cannot create event trace {}: {}", path.display(), e)))?;
+        Ok(Self::new(BufWriter::new(file)))
+    }
+}
+
+impl<W: Write> EventTracer for JsonlTracer<W> {
+    fn event_queued(&mut self, tick: u64, source: Option<SystemId>, event: &GameEvent) {
+        self.write_line(serde_json::json!({
+            "tick": tick,
+            "phase": "queued",
+            "source": source.map(|system| format!("{:?}", system)),
+            "event": format!("{:?}", event),
+        }));
+    }
+
+    fn event_dispatched(&mut self, tick: u64, event: &GameEvent, systems: &[SystemId]) {
+        self.write_line(serde_json::json!({
+            "tick": tick,
+            "phase": "dispatched",
+            "systems": systems.iter().map(|system| format!("{:?}", system)).collect::<Vec<_>>(),
+            "event": format!("{:?}", event),
+        }));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::events::SimulationEvent;
+
+    #[test]
+    fn test_jsonl_tracer_writes_a_line_per_step() {
+        let mut tracer = JsonlTracer::new(Vec::new());
+        let event = GameEvent::SimulationEvent(SimulationEvent::TickCompleted(4));
+        tracer.event_queued(4, Some(SystemId::TimeManager), &event);
+        tracer.event_dispatched(4, &event, &[SystemId::HistorySystem, SystemId::StatisticsSystem]);
+
+        let output = String::from_utf8(tracer.into_inner()).unwrap();
+        let lines: Vec<serde_json::Value> = output.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["source"], "TimeManager");
+        assert_eq!(lines[0]["event"], "SimulationEvent(TickCompleted(4))");
+        assert_eq!(lines[1]["phase"], "dispatched");
+        assert_eq!(lines[1]["systems"], serde_json::json!(["HistorySystem", "StatisticsSystem"]));
+    }
+}
@@ -0,0 +1,1139 @@
+// src/core/events.rs
+use super::types::*;
+use crate::config::EVENT_HISTORY_CAPACITY;
+use super::rng::SimRng;
+use super::ship_design::{ShipDesign, DesignId};
+use super::event_trace::EventTracer;
+use std::collections::{HashMap, VecDeque};
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone)]
+pub enum GameEvent {
+    PlayerCommand(PlayerCommand),
+    SimulationEvent(SimulationEvent),
+    StateChanged(StateChange),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum PlayerCommand {
+    SelectPlanet(PlanetId),
+    SelectShip(ShipId),
+    BuildStructure { planet: PlanetId, building_type: BuildingType },
+    MoveShip { ship: ShipId, target: Vector2 },
+    TransferResources { from: PlanetId, to: PlanetId, resources: ResourceBundle },
+    AllocateWorkers { planet: PlanetId, allocation: WorkerAllocation },
+    /// Set which produced resources the planet keeps, in what order, and their caps
+    SetStoragePriority {
+        /// Planet to change
+        planet: PlanetId,
+        /// New storage policy
+        priority: StoragePriority,
+    },
+    /// Set the order the planet's energy grid powers its buildings in
+    SetPowerPriority {
+        /// Planet to change
+        planet: PlanetId,
+        /// New power order
+        priority: PowerPriority,
+    },
+    /// Set how heavily the planet's controller taxes it
+    SetTaxRate {
+        /// Planet to change
+        planet: PlanetId,
+        /// New tax rate
+        rate: TaxRate,
+    },
+    /// Queue a ship at the planet's Spaceport, paying its cost up front
+    BuildShip {
+        /// Planet with the Spaceport
+        planet: PlanetId,
+        /// Class to build
+        ship_class: ShipClass,
+    },
+    /// Queue a ship of one of the planet controller's designs, paying its cost up front
+    BuildDesignedShip {
+        /// Planet with the Spaceport
+        planet: PlanetId,
+        /// Design to build
+        design: DesignId,
+    },
+    /// Add a design to the faction's designs, or replace the one with the same name
+    SaveShipDesign {
+        /// Faction that owns the design
+        faction: FactionId,
+        /// The design
+        design: ShipDesign,
+    },
+    /// Give a planet or ship a name; the name must pass `validate_name`
+    RenameEntity {
+        /// Planet or ship to rename
+        target: NamedEntity,
+        /// New name
+        name: String,
+    },
+    /// Replace a control group's members; an empty list clears the group
+    AssignControlGroup {
+        /// Faction that owns the group
+        faction: FactionId,
+        /// Group number
+        group: u8,
+        /// New members
+        members: Vec<NamedEntity>,
+    },
+    AttackTarget { attacker: ShipId, target: ShipId },
+    ColonizePlanet { ship: ShipId, planet: PlanetId },
+    /// Take military workers from the planet the ship orbits aboard as troops
+    EmbarkTroops {
+        /// Ship taking the troops aboard
+        ship: ShipId,
+        /// Planet the troops come from
+        planet: PlanetId,
+        /// Military workers to embark
+        troops: i32,
+    },
+    /// Land the ship's troops on the enemy planet it orbits
+    InvadePlanet {
+        /// Ship carrying the troops
+        ship: ShipId,
+        /// Planet to invade
+        planet: PlanetId,
+    },
+    /// Have a warship bombard the enemy planet it orbits, every tick until it leaves orbit
+    BombardPlanet {
+        /// Warship to bombard with
+        ship: ShipId,
+        /// Planet to bombard
+        planet: PlanetId,
+    },
+    /// Move resources and unassigned workers from the planet the ship orbits into its hold
+    LoadShipCargo {
+        /// Ship to load
+        ship: ShipId,
+        /// Planet the cargo comes from
+        planet: PlanetId,
+        /// Resources to load
+        resources: ResourceBundle,
+        /// Unassigned workers to take aboard
+        population: i32,
+    },
+    /// Put everything in the ship's hold ashore on the planet it orbits
+    UnloadShipCargo { ship: ShipId, planet: PlanetId },
+    /// Put a transport on a standing route between two planets
+    CreateTradeRoute(TradeRoute),
+    /// Take the ship off its trade route
+    CancelTradeRoute(ShipId),
+    SetGameSpeed(f32),
+    PauseGame(bool),
+    /// Run exactly one tick while paused, for stepping through the simulation; ignored while running
+    StepTick,
+    /// Run the simulation `ticks` ticks ahead as fast as it goes; 0 stops a fast-forward
+    FastForward {
+        /// Ticks to run ahead
+        ticks: u64,
+    },
+    SaveGame,
+    SaveGameDialog, // Show save dialog to prompt for save name
+    SaveGameAs(String),
+    LoadGame,
+    LoadGameFrom(String),
+    /// Save to a named slot chosen in the save/load view
+    SaveGameToSlot(String),
+    /// Load a named slot chosen in the save/load view
+    LoadGameFromSlot(String),
+    // Menu-related commands
+    NewGame,
+    NewGameNamed(String),
+    ExitGame,
+    BackToMenu,
+    GameOptions,
+    /// Client-side: bind a named input action to a named key, as chosen in the key bindings view
+    BindKey {
+        /// Input action to bind
+        action: String,
+        /// Key to bind it to
+        key: String,
+    },
+    /// Re-roll the seed of the starting layout in the new game menu
+    RerollStartingLayout,
+    /// Set the difficulty in the new game menu
+    SetDifficulty(Difficulty),
+    /// Start a new game from the settings chosen in the new game menu
+    StartNewGame(GameConfiguration),
+    /// Client-side: switch the UI to a named theme
+    SetTheme(String),
+    /// Client-side: set the master volume, from 0.0 to 1.0
+    SetVolume(f32),
+    /// Client-side: set the UI scale, from 0.75 to 2.0
+    SetUiScale(f32),
+    /// Client-side: open the key bindings view
+    ShowKeyBindings,
+    ClosePlanetPanel,
+    // UI panel commands
+    ShowPlanet(PlanetId),
+    ShowResourcePanel,
+    /// Client-side: open the build menu for a planet
+    ShowBuildMenu(PlanetId),
+    /// Client-side: open the resource transfer dialog for a planet
+    ShowTransferDialog(PlanetId),
+    /// Client-side: ask the player to confirm an orbital bombardment
+    ConfirmBombardment {
+        /// Warship to bombard with
+        ship: ShipId,
+        /// Planet to bombard
+        planet: PlanetId,
+    },
+    /// Client-side: open the ship designer for the player's faction
+    ShowShipDesigner,
+    RecallShip(ShipId),
+    StopShip(ShipId),
+    ScoutLocation(Vector2),
+    CloseShipPanel,
+    ShowShip(ShipId),
+    /// Several orders given together, such as one per ship from the map's
+    /// context menu; `GameState::issue_command` issues each in turn
+    IssueOrders(Vec<PlayerCommand>),
+    // Additional UI commands
+    ShowFaction(FactionId),
+    OpenDiplomacy(FactionId),
+    ProposeTradeAgreement(FactionId),
+    ShowFactionTerritory(FactionId),
+    ShowIntelligenceReport(FactionId),
+    CloseFactionPanel,
+    ManageWorkers(PlanetId),
+    /// Accept or decline an item in the faction's inbox
+    RespondToInbox {
+        /// Faction answering
+        faction: FactionId,
+        /// Item answered
+        item: InboxItemId,
+        /// The answer
+        response: InboxResponse,
+    },
+    // Diplomacy
+    /// Offer another faction a treaty
+    ProposeTreaty {
+        /// Faction proposing
+        from: FactionId,
+        /// Faction asked
+        to: FactionId,
+        /// Treaty offered
+        treaty: DiplomaticProposal,
+    },
+    /// Break any treaty with another faction and go to war
+    DeclareWar {
+        /// Faction declaring war
+        from: FactionId,
+        /// Faction it is declared on
+        to: FactionId,
+    },
+    /// Offer another faction a swap of resources
+    OfferTrade {
+        /// Faction making the offer
+        from: FactionId,
+        /// Faction offered the trade
+        to: FactionId,
+        /// Resources given
+        offered: ResourceBundle,
+        /// Resources wanted in return
+        requested: ResourceBundle,
+    },
+    // Research
+    /// Choose the technology the faction researches next
+    SetResearchTarget {
+        /// Faction researching
+        faction: FactionId,
+        /// Technology to research
+        technology: Technology,
+    },
+    /// Set the empire-wide tax that fills the faction's treasury
+    SetFactionTaxRate {
+        /// Faction to change
+        faction: FactionId,
+        /// New tax rate
+        rate: TaxRate,
+    },
+    // Market
+    /// Buy resources for credits, delivered to one of the faction's planets
+    MarketBuy {
+        /// Faction buying
+        faction: FactionId,
+        /// Planet the resources are delivered to
+        planet: PlanetId,
+        /// Resource to buy
+        resource: ResourceType,
+        /// Units to buy
+        amount: i32,
+    },
+    /// Sell resources from one of the faction's planets for credits
+    MarketSell {
+        /// Faction selling
+        faction: FactionId,
+        /// Planet the resources come from
+        planet: PlanetId,
+        /// Resource to sell
+        resource: ResourceType,
+        /// Units to sell
+        amount: i32,
+    },
+    // Notifications
+    /// Clear one notification from the faction's log, or all of them with `None`
+    DismissNotification {
+        /// Faction whose log to clear
+        faction: FactionId,
+        /// Notification to dismiss; None dismisses all
+        notification: Option<NotificationId>,
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum SimulationEvent {
+    TickCompleted(u64),
+    ResourcesProduced { planet: PlanetId, resources: ResourceBundle },
+    PopulationGrowth { planet: PlanetId, amount: i32 },
+    /// The planet's food, housing and taxes moved its happiness to a new value
+    HappinessChanged {
+        /// Planet affected
+        planet: PlanetId,
+        /// New happiness
+        happiness: f32,
+    },
+    /// Unhappy settlers left for a happier planet of the same faction
+    PopulationMigrated {
+        /// Planet the settlers left
+        from: PlanetId,
+        /// Planet they moved to
+        to: PlanetId,
+        /// Settlers who moved
+        amount: i32,
+    },
+    ConstructionCompleted { planet: PlanetId, building: BuildingType },
+    /// A queued ship is finished; GameState spawns it at the planet, built
+    /// to the design it was ordered with, if any
+    ShipConstructed {
+        /// Planet that built the ship
+        planet: PlanetId,
+        /// Class of the ship
+        ship_class: ShipClass,
+        /// Design it was ordered with, if any
+        design: Option<ShipDesign>,
+    },
+    /// The constructed ship has been spawned under this id
+    ShipCompleted { planet: PlanetId, ship: ShipId },
+    ShipArrived { ship: ShipId, destination: Vector2 },
+    /// The ship was captured into a parking orbit around the planet
+    ShipEnteredOrbit {
+        /// Ship now in orbit
+        ship: ShipId,
+        /// Planet it orbits
+        planet: PlanetId,
+    },
+    /// A move order was refused because the routed path needs more fuel than the ship carries
+    InsufficientFuel {
+        /// Ship that was ordered
+        ship: ShipId,
+        /// Fuel the route needs
+        required: f32,
+        /// Fuel the ship carries
+        available: f32,
+    },
+    /// Resources and settlers went from the planet into the ship's hold
+    CargoLoaded {
+        /// Ship loaded
+        ship: ShipId,
+        /// Planet the cargo came from
+        planet: PlanetId,
+        /// Resources loaded
+        resources: ResourceBundle,
+        /// Settlers taken aboard
+        population: i32,
+    },
+    /// The ship's hold was emptied onto the planet
+    CargoUnloaded {
+        /// Ship unloaded
+        ship: ShipId,
+        /// Planet the cargo went to
+        planet: PlanetId,
+        /// Resources unloaded
+        resources: ResourceBundle,
+        /// Settlers put ashore
+        population: i32,
+    },
+    /// One round of a ship battle, with the hull damage each ship took
+    ShipCombat {
+        /// Ship that opened fire
+        attacker: ShipId,
+        /// Ship fired upon
+        defender: ShipId,
+        /// Hull damage the attacker took this round
+        attacker_damage: i32,
+        /// Hull damage the defender took this round
+        defender_damage: i32,
+    },
+    /// A badly damaged ship broke off from battle and is heading for the nearest friendly planet
+    ShipRetreated {
+        /// Ship retreating
+        ship: ShipId,
+    },
+    CombatResolved { attacker: ShipId, defender: ShipId, outcome: CombatOutcome },
+    /// A ship in battle fired a missile at the ship it is fighting
+    MissileLaunched {
+        /// The new missile
+        missile: MissileId,
+        /// Ship that fired it
+        launcher: ShipId,
+        /// Ship it is aimed at
+        target: ShipId,
+    },
+    /// Point defense shot the missile down just short of its target
+    MissileIntercepted {
+        /// Missile shot down
+        missile: MissileId,
+        /// Ship it was aimed at
+        target: ShipId,
+    },
+    /// A missile struck its target, with the hull damage each fighting ship in the blast took
+    MissileImpact {
+        /// Missile that struck
+        missile: MissileId,
+        /// Ship it was aimed at
+        target: ShipId,
+        /// Where it exploded
+        position: Vector2,
+        /// Hull damage each ship in the blast took
+        hits: Vec<(ShipId, i32)>,
+    },
+    PlanetConquered { planet: PlanetId, new_owner: FactionId },
+    /// A colony ship settled an unclaimed planet and was used up doing so
+    PlanetColonized {
+        /// The new colony
+        planet: PlanetId,
+        /// Faction that settled it
+        faction: FactionId,
+        /// Settlers who landed
+        colonists: i32,
+    },
+    /// One tick of ground combat in an invasion
+    GroundCombat {
+        /// Planet fought over
+        planet: PlanetId,
+        /// Invading troops lost this tick
+        attacker_losses: i32,
+        /// Garrison troops lost this tick
+        defender_losses: i32,
+    },
+    /// The garrison fell; surviving troops settle on the planet, followed by `PlanetConquered`
+    PlanetInvaded {
+        /// Planet taken
+        planet: PlanetId,
+        /// Faction that took it
+        invader: FactionId,
+        /// Troops left alive
+        survivors: i32,
+    },
+    /// Every invading soldier fell before the garrison did
+    InvasionRepelled {
+        /// Planet defended
+        planet: PlanetId,
+        /// Faction whose invasion failed
+        invader: FactionId,
+    },
+    /// A bombarding warship struck the planet it orbits; a strike at buildings
+    /// on a planet with none left falls on the population instead
+    PlanetBombarded {
+        /// Planet struck
+        planet: PlanetId,
+        /// Warship bombarding it
+        ship: ShipId,
+        /// What the strike hit
+        strike: BombardmentStrike,
+    },
+    ResourceShortage { planet: PlanetId, resource: ResourceType },
+    /// The planet's stockpile could not cover this tick's upkeep, which went unpaid
+    UpkeepUnpaid {
+        /// Planet that missed its upkeep
+        planet: PlanetId,
+        /// Upkeep that was due
+        upkeep: ResourceBundle,
+    },
+    TransferWindowOpen { from: PlanetId, to: PlanetId },
+    /// The game was written to the slot
+    SaveCompleted {
+        /// Slot saved to
+        slot: String,
+    },
+    /// Writing the game to the slot failed
+    SaveFailed {
+        /// Slot that was being saved
+        slot: String,
+        /// What went wrong
+        reason: String,
+    },
+    /// Emitted by TimeManager every autosave interval, after that tick's TickCompleted
+    AutosaveDue(u64),
+    /// A fast-forward reached `tick` on its way to `target`; sent every `FAST_FORWARD_TICKS_PER_UPDATE` ticks
+    FastForwardProgress {
+        /// Tick reached
+        tick: u64,
+        /// Tick the fast-forward stops at
+        target: u64,
+    },
+    /// A fast-forward reached its target tick
+    FastForwardCompleted(u64),
+    /// A message was put in a faction's inbox
+    InboxMessageSent {
+        /// Faction receiving it
+        to: FactionId,
+        /// The message
+        message: InboxMessage,
+    },
+    /// A faction answered an item in its inbox
+    InboxItemResolved {
+        /// Faction that answered
+        faction: FactionId,
+        /// Item answered
+        item: InboxItem,
+        /// The answer
+        response: InboxResponse,
+    },
+    /// An inbox item lapsed unanswered
+    InboxItemExpired {
+        /// Faction whose inbox held it
+        faction: FactionId,
+        /// The lapsed item
+        item: InboxItem,
+    },
+    /// Two factions signed a treaty
+    TreatySigned {
+        /// The two factions
+        parties: (FactionId, FactionId),
+        /// Treaty signed
+        treaty: Treaty,
+    },
+    /// A treaty between two factions lapsed
+    TreatyExpired {
+        /// The two factions
+        parties: (FactionId, FactionId),
+        /// Treaty that lapsed
+        treaty: Treaty,
+    },
+    /// One faction declared war on another
+    WarDeclared {
+        /// Faction declaring war
+        aggressor: FactionId,
+        /// Faction it was declared on
+        target: FactionId,
+    },
+    /// Both factions' scores for each other shift by `delta`
+    RelationChanged {
+        /// The two factions
+        parties: (FactionId, FactionId),
+        /// Change to each score
+        delta: i32,
+    },
+    /// A trade offer was accepted and the resources change hands
+    TradeAccepted {
+        /// Faction that made the offer
+        from: FactionId,
+        /// Faction that accepted it
+        to: FactionId,
+        /// Resources going from `from` to `to`
+        offered: ResourceBundle,
+        /// Resources going from `to` to `from`
+        requested: ResourceBundle,
+    },
+    /// Research points a faction put into its target technology
+    ResearchProgressed {
+        /// Faction researching
+        faction: FactionId,
+        /// Technology researched
+        technology: Technology,
+        /// Points added
+        points: i32,
+    },
+    /// A faction finished researching a technology
+    TechnologyUnlocked {
+        /// Faction that researched it
+        faction: FactionId,
+        /// Technology unlocked
+        technology: Technology,
+    },
+    /// The faction's sensors saw the planet for the first time
+    PlanetDiscovered {
+        /// Faction that saw the planet
+        faction: FactionId,
+        /// Planet seen
+        planet: PlanetId,
+    },
+    /// A faction bought resources on the market
+    ResourcesBought {
+        /// Faction buying
+        faction: FactionId,
+        /// Planet they were delivered to
+        planet: PlanetId,
+        /// Resource bought
+        resource: ResourceType,
+        /// Units bought
+        amount: i32,
+        /// Credits paid
+        cost: i32,
+    },
+    /// A faction sold resources on the market
+    ResourcesSold {
+        /// Faction selling
+        faction: FactionId,
+        /// Planet they came from
+        planet: PlanetId,
+        /// Resource sold
+        resource: ResourceType,
+        /// Units sold
+        amount: i32,
+        /// Credits received
+        revenue: i32,
+    },
+    /// The faction completed the objective at this index of its list and earned the reward
+    ObjectiveCompleted {
+        /// Faction that completed it
+        faction: FactionId,
+        /// Index in the faction's objective list
+        objective: usize,
+        /// Resources earned
+        reward: ResourceBundle,
+    },
+    /// Pirate warships appeared at the galaxy edge to raid the target planet
+    PirateRaidLaunched {
+        /// The pirate faction
+        faction: FactionId,
+        /// Planet to raid
+        target: PlanetId,
+        /// The raiding warships
+        ships: Vec<ShipId>,
+    },
+    /// The faction's scout filed an intel report on another faction's planet
+    IntelGathered {
+        /// Faction the scout belongs to
+        faction: FactionId,
+        /// Planet reported on
+        planet: PlanetId,
+        /// Scout that filed the report
+        ship: ShipId,
+    },
+    /// The planet was cut off from, or reconnected to, its faction's capital
+    PlanetSupplyChanged {
+        /// Planet affected
+        planet: PlanetId,
+        /// True if connected to the capital
+        supplied: bool,
+    },
+    /// The ship left, or came back within, its faction's supply network
+    ShipSupplyChanged {
+        /// Ship affected
+        ship: ShipId,
+        /// True if within the supply network
+        supplied: bool,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub enum StateChange {
+    PlanetUpdated(PlanetId),
+    ShipUpdated(ShipId),
+    FactionUpdated(FactionId),
+    /// Hostile warships hold the planet's orbit unopposed
+    PlanetBlockaded(PlanetId),
+    /// Enemy warships no longer hold the planet's orbit
+    BlockadeLifted(PlanetId),
+    /// The building at `index` of the planet's developments was switched
+    /// back on, or shut down by the energy grid or for unpaid upkeep
+    BuildingStatusChanged {
+        /// Planet the building is on
+        planet: PlanetId,
+        /// Index in the planet's developments
+        index: usize,
+        /// New status
+        status: BuildingStatus,
+    },
+    /// The command failed validation and never reached the systems
+    CommandRejected {
+        /// The rejected command
+        command: PlayerCommand,
+        /// Why it was rejected
+        reason: String,
+    },
+    /// The faction met one of the scenario's victory conditions
+    VictoryConditionMet {
+        /// Faction that met it
+        faction: FactionId,
+        /// Kind of victory
+        victory: VictoryType,
+    },
+    /// The faction lost the last of its planets and ships and is out of the game
+    GameOver(FactionId),
+    /// A system failed with an error the simulation recovered from; the tick
+    /// went on without that system's work
+    SystemErrorOccurred {
+        /// System that failed
+        system: SystemId,
+        /// The error, as displayed
+        error: String,
+    },
+    GameLoaded,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EventType {
+    PlayerCommand,
+    SimulationEvent,
+    StateChanged,
+}
+
+impl EventType {
+    /// The type a subscription needs to receive the event
+    pub fn of(event: &GameEvent) -> Self {
+        match event {
+            GameEvent::PlayerCommand(_) => EventType::PlayerCommand,
+            GameEvent::SimulationEvent(_) => EventType::SimulationEvent,
+            GameEvent::StateChanged(_) => EventType::StateChanged,
+        }
+    }
+}
+
+/// Picks out the events of a type that a subscriber actually handles,
+/// usually a `matches!` on the variants it cares about
+pub type EventFilter = fn(&GameEvent) -> bool;
+
+/// A system's interest in one type of event
+#[derive(Debug, Clone, Copy)]
+pub struct Subscription {
+    /// Type of event wanted
+    pub event_type: EventType,
+    /// Only events passing the filter are delivered; None delivers every event of the type
+    pub filter: Option<EventFilter>,
+    /// Subscribers with a higher priority handle an event first
+    pub priority: i32,
+}
+
+impl Subscription {
+    /// Priority of plain subscriptions, which keep the bus's default order
+    pub const DEFAULT_PRIORITY: i32 = 0;
+
+    /// Every event of the type, at the default priority
+    pub fn to(event_type: EventType) -> Self {
+        Self {
+            event_type,
+            filter: None,
+            priority: Self::DEFAULT_PRIORITY,
+        }
+    }
+
+    /// Restrict the subscription to events passing `filter`
+    pub fn matching(mut self, filter: EventFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Handle events before (higher) or after (lower) other subscribers
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// True if the event should be delivered under this subscription
+    pub fn accepts(&self, event: &GameEvent) -> bool {
+        self.event_type == EventType::of(event) && self.filter.is_none_or(|filter| filter(event))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SystemId {
+    PlanetManager,
+    ShipManager,
+    FactionManager,
+    TimeManager,
+    ResourceSystem,
+    PopulationSystem,
+    ConstructionSystem,
+    PhysicsEngine,
+    CombatResolver,
+    SaveSystem,
+    UIRenderer,
+    /// Computer-controlled factions
+    AISystem,
+    /// Treaties, trade offers and wars
+    DiplomacySystem,
+    /// Research progress
+    ResearchSystem,
+    /// What each faction sees
+    VisibilitySystem,
+    /// Scenario scripts and victory conditions
+    ScenarioSystem,
+    /// Resource market prices and trades
+    MarketSystem,
+    /// Player alerts
+    NotificationSystem,
+    /// Loading and unloading ship cargo
+    CargoSystem,
+    /// Timeline of notable events
+    HistorySystem,
+    /// Per-faction statistics
+    StatisticsSystem,
+    /// Tutorial and scenario objectives
+    ObjectiveSystem,
+    /// Pirate raids
+    PirateSystem,
+    /// Scout intelligence reports
+    SpySystem,
+    /// Supply network
+    SupplySystem,
+    /// Recording and playing back replays
+    ReplaySystem,
+}
+
+/// An event kept in the history with the tick it was queued during
+#[derive(Debug, Clone)]
+pub struct HistoryEntry {
+    /// Tick the event was queued during
+    pub tick: u64,
+    /// The event
+    pub event: GameEvent,
+}
+
+/// Fixed-capacity ring buffer of the most recently queued events.
+///
+/// Storage is allocated once up to `CAPACITY` entries; after that each new
+/// event overwrites the oldest, so memory stays flat however long a game runs.
+/// Iteration is oldest first.
+#[derive(Debug, Clone)]
+pub struct EventHistory<const CAPACITY: usize = EVENT_HISTORY_CAPACITY> {
+    entries: Vec<HistoryEntry>,
+    /// Index of the oldest entry once the buffer is full
+    start: usize,
+}
+
+impl<const CAPACITY: usize> EventHistory<CAPACITY> {
+    /// Empty history
+    pub fn new() -> Self {
+        const { assert!(CAPACITY > 0, "event history needs room for at least one event") };
+        Self {
+            entries: Vec::with_capacity(CAPACITY),
+            start: 0,
+        }
+    }
+
+    /// Most entries kept
+    pub fn capacity(&self) -> usize {
+        CAPACITY
+    }
+
+    /// Entries kept
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// True when nothing is kept
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Add an event, overwriting the oldest one when full
+    pub fn push(&mut self, tick: u64, event: GameEvent) {
+        let entry = HistoryEntry { tick, event };
+        if self.entries.len() < CAPACITY {
+            self.entries.push(entry);
+        } else {
+            self.entries[self.start] = entry;
+            self.start = (self.start + 1) % CAPACITY;
+        }
+    }
+
+    /// Entries from oldest to newest
+    pub fn entries(&self) -> impl DoubleEndedIterator<Item = &HistoryEntry> + ExactSizeIterator {
+        let len = self.entries.len();
+        (0..len).map(move |i| &self.entries[(self.start + i) % len])
+    }
+
+    /// Events from oldest to newest
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &GameEvent> + ExactSizeIterator {
+        self.entries().map(|entry| &entry.event)
+    }
+
+    /// Oldest event still kept
+    pub fn front(&self) -> Option<&GameEvent> {
+        self.iter().next()
+    }
+
+    /// Most recently queued event
+    pub fn back(&self) -> Option<&GameEvent> {
+        self.iter().next_back()
+    }
+
+    /// The last `count` entries, oldest first
+    pub fn recent(&self, count: usize) -> impl DoubleEndedIterator<Item = &HistoryEntry> {
+        self.entries().skip(self.len().saturating_sub(count))
+    }
+
+    /// Entries queued during `tick` or later, oldest first
+    pub fn since(&self, tick: u64) -> impl DoubleEndedIterator<Item = &HistoryEntry> {
+        self.entries().filter(move |entry| entry.tick >= tick)
+    }
+
+    /// Forget every entry
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.start = 0;
+    }
+}
+
+impl<const CAPACITY: usize> Default for EventHistory<CAPACITY> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Counters for tuning event processing, from [`EventBus::metrics`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EventBusMetrics {
+    /// Events taken for processing since the bus was created
+    pub events_processed: u64,
+    /// Events taken in the most recent batch
+    pub last_batch: usize,
+    /// Events waiting to be processed
+    pub queue_depth: usize,
+    /// Most events ever waiting when a batch was taken
+    pub peak_queue_depth: usize,
+    /// Batches that hit the budget and left events for later
+    pub budget_overruns: u64,
+    /// Events left for a later batch, summed over all batches
+    pub events_deferred: u64,
+    /// Longest any event waited between being queued and processed, in ticks
+    pub max_latency_ticks: u64,
+}
+
+pub struct EventBus {
+    pub queued_events: VecDeque<GameEvent>,
+    /// Tick each queued event belongs to, parallel to `queued_events`
+    queued_ticks: VecDeque<u64>,
+    /// Most events processed per batch; None processes everything queued
+    event_budget: Option<usize>,
+    metrics: EventBusMetrics,
+    /// Event types each system subscribed to, filtered or not
+    pub subscribers: HashMap<SystemId, Vec<EventType>>,
+    /// Every subscription with its filter and priority
+    pub subscriptions: HashMap<SystemId, Vec<Subscription>>,
+    /// Recently queued events, for debugging and inspection
+    pub event_history: EventHistory,
+    /// Tick that events queued now belong to; advances with `TickCompleted`
+    history_tick: u64,
+    pub update_order: Vec<SystemId>,
+    /// Random numbers for the systems, seeded by GameState for each game
+    pub rng: SimRng,
+    /// System whose update or event handler is running, named as the source
+    /// of what it queues
+    source: Option<SystemId>,
+    /// Hook told about queued and dispatched events; None traces nothing
+    tracer: Option<Box<dyn EventTracer>>,
+}
+
+impl EventBus {
+    pub fn new() -> Self {
+        Self {
+            queued_events: VecDeque::with_capacity(256),
+            queued_ticks: VecDeque::with_capacity(256),
+            event_budget: None,
+            metrics: EventBusMetrics::default(),
+            subscribers: HashMap::with_capacity(16),
+            subscriptions: HashMap::with_capacity(16),
+            event_history: EventHistory::new(),
+            history_tick: 1,
+            rng: SimRng::default(),
+            source: None,
+            tracer: None,
+            update_order: vec![
+                SystemId::PhysicsEngine,
+                SystemId::ResourceSystem,
+                SystemId::PopulationSystem,
+                SystemId::ConstructionSystem,
+                SystemId::CombatResolver,
+                SystemId::TimeManager,
+            ],
+        }
+    }
+    
+    /// Deliver every event of the type to the system
+    pub fn subscribe(&mut self, system: SystemId, event_type: EventType) {
+        self.subscribe_with(system, Subscription::to(event_type));
+    }
+
+    /// Deliver the events a subscription accepts to the system
+    pub fn subscribe_with(&mut self, system: SystemId, subscription: Subscription) {
+        let event_types = self.subscribers
+            .entry(system)
+            .or_insert_with(|| Vec::with_capacity(4));
+        if !event_types.contains(&subscription.event_type) {
+            event_types.push(subscription.event_type);
+        }
+        self.subscriptions
+            .entry(system)
+            .or_insert_with(|| Vec::with_capacity(4))
+            .push(subscription);
+    }
+
+    /// Systems that should handle the event, in the order they handle it.
+    ///
+    /// Higher priorities go first. Systems with equal priority keep the
+    /// default order: `update_order` first, then the rest in SystemId order.
+    /// A system with several matching subscriptions is listed once, at the
+    /// highest of their priorities.
+    pub fn subscribers_for(&self, event: &GameEvent) -> Vec<SystemId> {
+        let priority = |system_id: &SystemId| {
+            self.subscriptions.get(system_id)?.iter()
+                .filter(|subscription| subscription.accepts(event))
+                .map(|subscription| subscription.priority)
+                .max()
+        };
+
+        // Subscriptions live in a HashMap, whose iteration order differs between runs
+        let mut remaining: Vec<SystemId> = self.subscriptions.keys()
+            .filter(|system_id| !self.update_order.contains(system_id))
+            .copied()
+            .collect();
+        remaining.sort();
+
+        let mut systems: Vec<(SystemId, i32)> = self.update_order.iter()
+            .chain(remaining.iter())
+            .filter_map(|system_id| Some((*system_id, priority(system_id)?)))
+            .collect();
+        systems.sort_by_key(|(_, priority)| std::cmp::Reverse(*priority));
+        systems.into_iter().map(|(system_id, _)| system_id).collect()
+    }
+    
+    pub fn queue_event(&mut self, event: GameEvent) {
+        let tick = match &event {
+            GameEvent::SimulationEvent(SimulationEvent::TickCompleted(tick)) => *tick,
+            _ => self.history_tick,
+        };
+        if let Some(tracer) = &mut self.tracer {
+            tracer.event_queued(tick, self.source, &event);
+        }
+        self.event_history.push(tick, event.clone());
+        // TickCompleted closes its tick; anything queued after it belongs to the next one
+        if matches!(event, GameEvent::SimulationEvent(SimulationEvent::TickCompleted(_))) {
+            self.history_tick = tick + 1;
+        }
+        
+        self.queued_ticks.push_back(self.history_tick);
+        self.queued_events.push_back(event);
+    }
+    
+    /// Run `f` with `source` named as the system queuing events, as GameState
+    /// does around each system's update and event handler
+    pub fn with_source<R>(&mut self, source: SystemId, f: impl FnOnce(&mut EventBus) -> R) -> R {
+        let previous = self.set_source(Some(source));
+        let result = f(self);
+        self.set_source(previous);
+        result
+    }
+    
+    /// Name the system queuing events from now on; returns the one replaced
+    pub fn set_source(&mut self, source: Option<SystemId>) -> Option<SystemId> {
+        std::mem::replace(&mut self.source, source)
+    }
+    
+    /// Attach a tracer, or detach it with None; returns the one replaced
+    pub fn set_tracer(&mut self, tracer: Option<Box<dyn EventTracer>>) -> Option<Box<dyn EventTracer>> {
+        std::mem::replace(&mut self.tracer, tracer)
+    }
+    
+    /// True while a tracer is attached
+    pub fn is_tracing(&self) -> bool {
+        self.tracer.is_some()
+    }
+    
+    /// Tell the tracer an event from the batch being processed was routed to
+    /// `systems`
+    pub fn trace_dispatch(&mut self, event: &GameEvent, systems: &[SystemId]) {
+        let tick = self.history_tick.saturating_sub(1);
+        if let Some(tracer) = &mut self.tracer {
+            tracer.event_dispatched(tick, event, systems);
+        }
+    }
+    
+    /// Most events processed per tick; None processes everything queued
+    pub fn event_budget(&self) -> Option<usize> {
+        self.event_budget
+    }
+    
+    /// Limit the events processed per tick. Events beyond the budget stay
+    /// queued, in order, ahead of anything queued later.
+    pub fn set_event_budget(&mut self, budget: Option<usize>) -> GameResult<()> {
+        if budget == Some(0) {
+            return Err(GameError::InvalidOperation("Event budget must allow at least one event".into()));
+        }
+        self.event_budget = budget;
+        Ok(())
+    }
+    
+    /// Take the events to process this tick, oldest first.
+    ///
+    /// Only events already queued are taken, up to the budget; events queued
+    /// while the batch is processed wait for the next one.
+    pub fn take_batch(&mut self) -> Vec<GameEvent> {
+        let queued = self.queued_events.len();
+        let count = self.event_budget.map_or(queued, |budget| budget.min(queued));
+        
+        // Events added or removed without queue_event leave the ticks out of step
+        self.queued_ticks.drain(..self.queued_ticks.len().saturating_sub(queued));
+        // Ticks are queued in order, so the oldest event in the batch waited longest
+        let now = self.history_tick.saturating_sub(1);
+        let longest_wait = self.queued_ticks.front().map_or(0, |tick| now.saturating_sub(*tick));
+        self.queued_ticks.drain(..count.min(self.queued_ticks.len()));
+        
+        let metrics = &mut self.metrics;
+        metrics.events_processed += count as u64;
+        metrics.last_batch = count;
+        metrics.peak_queue_depth = metrics.peak_queue_depth.max(queued);
+        if count < queued {
+            metrics.budget_overruns += 1;
+            metrics.events_deferred += (queued - count) as u64;
+        }
+        if count > 0 {
+            metrics.max_latency_ticks = metrics.max_latency_ticks.max(longest_wait);
+        }
+        
+        self.queued_events.drain(..count).collect()
+    }
+    
+    /// Processing counters and the current queue depth
+    pub fn metrics(&self) -> EventBusMetrics {
+        EventBusMetrics {
+            queue_depth: self.queued_events.len(),
+            ..self.metrics
+        }
+    }
+    
+    /// Start counting from zero, keeping whatever is queued
+    pub fn reset_metrics(&mut self) {
+        self.metrics = EventBusMetrics::default();
+    }
+    
+    /// The last `count` events queued, oldest first
+    pub fn recent_events(&self, count: usize) -> impl DoubleEndedIterator<Item = &HistoryEntry> {
+        self.event_history.recent(count)
+    }
+    
+    /// Events still in the history that were queued during `tick` or later
+    pub fn events_since(&self, tick: u64) -> impl DoubleEndedIterator<Item = &HistoryEntry> {
+        self.event_history.since(tick)
+    }
+    
+    pub fn clear(&mut self) {
+        self.queued_events.clear();
+        self.queued_ticks.clear();
+    }
+    
+    /// Processes queued events through GameState - used for testing.
+    /// This allows tests to manually trigger event processing.
+    #[cfg(test)]
+    pub fn process_events(&mut self, game_state: &mut crate::core::GameState) -> crate::core::types::GameResult<()> {
+        game_state.process_queued_events_for_test()
+    }
+}
\ No newline at end of file
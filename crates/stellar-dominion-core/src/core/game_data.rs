@@ -0,0 +1,422 @@
+// src/core/game_data.rs
+//! Balance data for buildings and ship classes
+//!
+//! [`GameDataRegistry`] starts out with the compiled-in defaults. A RON data
+//! file can override any building or ship class entry and any list of
+//! prerequisites; entries the file leaves out keep their defaults:
+//!
+//! ```ron
+//! (
+//!     buildings: {
+//!         Mine: (cost: (minerals: 80, food: 0, energy: 0, alloys: 20, components: 10, fuel: 0),
+//!                build_time: 8,
+//!                production: (minerals: 12, food: 0, energy: -2, alloys: 0, components: 0, fuel: 0)),
+//!     },
+//!     ship_requirements: { Warship: [Spaceport(min_tier: 1)] },
+//! )
+//! ```
+//!
+//! [`GameDataRegistry::to_ron`] writes every entry, which makes a complete
+//! starting point for a data file. GameState hands the registry to the
+//! systems that consult it through `GameState::set_game_data`.
+
+use super::types::*;
+use serde::{Serialize, Deserialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// Spaceport tier needed to build any ship
+pub const SHIP_SPACEPORT_TIER: u8 = 1;
+/// Spaceport tier needed to build warships
+pub const WARSHIP_SPACEPORT_TIER: u8 = 2;
+/// Planet population needed to spare colonists for a colony ship
+pub const COLONY_SHIP_MIN_POPULATION: i32 = 5000;
+
+/// A condition that must hold before something can be built
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UnlockRequirement {
+    /// An operational Spaceport of at least this tier on the building planet
+    Spaceport {
+        /// Lowest acceptable Spaceport tier
+        min_tier: u8,
+    },
+    /// A technology known to the planet's controlling faction
+    Technology(Technology),
+    /// At least this many people living on the building planet
+    Population(i32),
+}
+
+impl UnlockRequirement {
+    /// True when the planet and its controlling faction satisfy the requirement
+    pub fn is_met(&self, planet: &Planet, faction: &Faction) -> bool {
+        match self {
+            UnlockRequirement::Spaceport { min_tier } => planet.developments.iter().any(|building| {
+                building.building_type == BuildingType::Spaceport
+                    && building.is_operational()
+                    && building.tier >= *min_tier
+            }),
+            UnlockRequirement::Technology(technology) => faction.has_technology(*technology),
+            UnlockRequirement::Population(minimum) => planet.population.total >= *minimum,
+        }
+    }
+
+    /// Reason shown to the player while the requirement is not met
+    pub fn describe(&self) -> String {
+        match self {
+            UnlockRequirement::Spaceport { min_tier } => format!("Requires a tier {} Spaceport", min_tier),
+            UnlockRequirement::Technology(technology) => format!("Requires {:?} technology", technology),
+            UnlockRequirement::Population(minimum) => format!("Requires a population of {}", minimum),
+        }
+    }
+}
+
+/// Cost and output of a building type
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BuildingData {
+    /// Resources paid when construction is ordered
+    pub cost: ResourceBundle,
+    /// Ticks from ordering to completion
+    pub build_time: u64,
+    /// Change to the planet's stockpile per tick from an operational tier 1
+    /// building; higher tiers multiply it
+    #[serde(default)]
+    pub production: ResourceBundle,
+    /// Energy a tier 1 building draws from its planet's grid per tick;
+    /// higher tiers multiply it. Unpowered buildings do nothing.
+    #[serde(default)]
+    pub energy_demand: i32,
+}
+
+impl BuildingData {
+    /// Change to the stockpile per tick of a powered tier 1 building, its
+    /// energy demand included
+    pub fn net_production(&self) -> ResourceBundle {
+        let mut net = self.production;
+        net.energy -= self.energy_demand;
+        net
+    }
+}
+
+/// Cost and stats of a ship class
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ShipData {
+    /// Resources paid when construction is ordered
+    pub cost: ResourceBundle,
+    /// Ticks from ordering to completion
+    pub build_time: u64,
+    /// Units of cargo a ship of the class holds
+    pub cargo_capacity: i32,
+    /// Fuel a new ship starts with
+    pub fuel: f32,
+    /// Distance travelled per unit of fuel
+    pub fuel_efficiency: f32,
+    /// Unarmed with a light hull when a data file leaves it out
+    #[serde(default)]
+    pub combat: CombatStats,
+    /// Settlers and stores aboard a new ship, for classes that found colonies
+    #[serde(default)]
+    pub colony: Option<ColonyKit>,
+}
+
+/// What a colony ship sets down on the planet it colonizes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ColonyKit {
+    /// Population the new colony starts with
+    pub colonists: i32,
+    /// Stockpile the new colony starts with
+    pub supplies: ResourceBundle,
+}
+
+/// Hull, shields and weapons of a ship class
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CombatStats {
+    /// Damage the ship survives; lost hull stays lost until repaired
+    pub hull: i32,
+    /// Damage soaked up before the hull is hit, restored for every battle
+    pub shields: i32,
+    /// Average damage dealt per round of battle
+    pub attack: i32,
+    /// Warhead damage of each missile the ship launches; 0 without launchers
+    #[serde(default)]
+    pub missiles: i32,
+    /// Chance in percent of shooting down each missile fired at the ship
+    #[serde(default)]
+    pub point_defense: i32,
+}
+
+impl Default for CombatStats {
+    fn default() -> Self {
+        Self { hull: 50, shields: 0, attack: 0, missiles: 0, point_defense: 0 }
+    }
+}
+
+/// Layout of a data file; every section is optional
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct GameDataFile {
+    buildings: BTreeMap<BuildingType, BuildingData>,
+    ships: BTreeMap<ShipClass, ShipData>,
+    building_requirements: BTreeMap<BuildingType, Vec<UnlockRequirement>>,
+    ship_requirements: BTreeMap<ShipClass, Vec<UnlockRequirement>>,
+}
+
+/// Static game data consulted by the simulation.
+///
+/// Holds the compiled-in defaults; callers may replace entries, directly or
+/// from a data file, to rebalance a game without touching the systems that
+/// read them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameDataRegistry {
+    buildings: BTreeMap<BuildingType, BuildingData>,
+    ships: BTreeMap<ShipClass, ShipData>,
+    ship_requirements: BTreeMap<ShipClass, Vec<UnlockRequirement>>,
+    building_requirements: BTreeMap<BuildingType, Vec<UnlockRequirement>>,
+}
+
+impl GameDataRegistry {
+    /// Registry holding the compiled-in defaults
+    pub fn new() -> Self {
+        let bundle = |minerals, food, energy, alloys, components, fuel| ResourceBundle { minerals, food, energy, alloys, components, fuel };
+        let building = |cost, build_time, production, energy_demand| BuildingData { cost, build_time, production, energy_demand };
+        let none = ResourceBundle::default();
+        let mut buildings = BTreeMap::new();
+        buildings.insert(BuildingType::Mine, building(bundle(100, 0, 0, 20, 10, 0), 10, bundle(10, 0, 0, 0, 0, 0), 2));
+        buildings.insert(BuildingType::Farm, building(bundle(50, 0, 0, 10, 5, 0), 8, bundle(0, 8, 0, 0, 0, 0), 1));
+        buildings.insert(BuildingType::PowerPlant, building(bundle(80, 0, 0, 30, 15, 0), 12, bundle(0, 0, 15, 0, 0, 0), 0));
+        buildings.insert(BuildingType::Factory, building(bundle(120, 0, 10, 40, 25, 0), 15, bundle(-5, 0, 0, 5, 0, 0), 3));
+        buildings.insert(BuildingType::ResearchLab, building(bundle(90, 0, 5, 25, 30, 0), 14, bundle(0, -1, 0, 0, 3, 0), 2));
+        buildings.insert(BuildingType::Spaceport, building(bundle(200, 0, 20, 80, 60, 0), 25, none, 2));
+        buildings.insert(BuildingType::DefensePlatform, building(bundle(150, 0, 15, 100, 50, 0), 20, none, 2));
+        buildings.insert(BuildingType::StorageFacility, building(bundle(60, 0, 0, 15, 8, 0), 6, none, 0));
+        buildings.insert(BuildingType::Habitat, building(bundle(80, 10, 5, 20, 15, 0), 10, none, 1));
+        
+        let ship = |cost, build_time, cargo_capacity, fuel_efficiency, combat| ShipData { cost, build_time, cargo_capacity, fuel: 100.0, fuel_efficiency, combat, colony: None };
+        let combat = |hull, shields, attack| CombatStats { hull, shields, attack, ..Default::default() };
+        let mut ships = BTreeMap::new();
+        ships.insert(ShipClass::Scout, ship(bundle(50, 0, 0, 30, 25, 10), 8, 0, 200.0, CombatStats { point_defense: 20, ..combat(40, 10, 5) }));
+        ships.insert(ShipClass::Transport, ship(bundle(80, 0, 0, 40, 35, 15), 12, 1000, 100.0, combat(80, 0, 0)));
+        ships.insert(ShipClass::Colony, ShipData {
+            colony: Some(ColonyKit { colonists: 200, supplies: bundle(100, 150, 50, 0, 0, 0) }),
+            ..ship(bundle(150, 50, 10, 60, 80, 25), 20, 500, 60.0, combat(60, 10, 0))
+        });
+        ships.insert(ShipClass::Warship, ship(bundle(200, 0, 5, 120, 100, 30), 25, 100, 80.0, CombatStats { missiles: 40, point_defense: 30, ..combat(200, 40, 25) }));
+        
+        let mut ship_requirements = BTreeMap::new();
+        let spaceport = UnlockRequirement::Spaceport { min_tier: SHIP_SPACEPORT_TIER };
+        ship_requirements.insert(ShipClass::Scout, vec![spaceport]);
+        ship_requirements.insert(ShipClass::Transport, vec![spaceport]);
+        ship_requirements.insert(ShipClass::Warship, vec![
+            UnlockRequirement::Spaceport { min_tier: WARSHIP_SPACEPORT_TIER },
+            UnlockRequirement::Technology(Technology::Military),
+        ]);
+        ship_requirements.insert(ShipClass::Colony, vec![
+            spaceport,
+            UnlockRequirement::Population(COLONY_SHIP_MIN_POPULATION),
+        ]);
+        
+        let mut building_requirements = BTreeMap::new();
+        building_requirements.insert(BuildingType::DefensePlatform, vec![
+            UnlockRequirement::Technology(Technology::OrbitalDefense),
+        ]);
+        Self { buildings, ships, ship_requirements, building_requirements }
+    }
+
+    /// Defaults with the entries of a RON data file laid over them
+    pub fn from_ron(source: &str) -> GameResult<Self> {
+        let file: GameDataFile = ron::from_str(source)
+            .map_err(|e| GameError::SaveCorrupted { path: "game data".to_string(), detail: e.to_string() })?;
+        let mut registry = Self::new();
+        registry.buildings.extend(file.buildings);
+        registry.ships.extend(file.ships);
+        registry.building_requirements.extend(file.building_requirements);
+        registry.ship_requirements.extend(file.ship_requirements);
+        registry.validate()?;
+        Ok(registry)
+    }
+
+    /// Read a RON data file; see [`GameDataRegistry::from_ron`]
+    pub fn load_from_file(path: &Path) -> GameResult<Self> {
+        let source = fs::read_to_string(path)
+            .map_err(|e| GameError::Io(format!("Could not read game data {}: {}", path.display(), e)))?;
+        Self::from_ron(&source).map_err(|e| match e {
+            GameError::SaveCorrupted { detail, .. } => GameError::corrupted(path, detail),
+            other => other,
+        })
+    }
+
+    /// Every entry as a RON data file
+    pub fn to_ron(&self) -> GameResult<String> {
+        let file = GameDataFile {
+            buildings: self.buildings.clone(),
+            ships: self.ships.clone(),
+            building_requirements: self.building_requirements.clone(),
+            ship_requirements: self.ship_requirements.clone(),
+        };
+        ron::ser::to_string_pretty(&file, ron::ser::PrettyConfig::default())
+            .map_err(|e| GameError::SystemError(format!("Game data serialization failed: {}", e)))
+    }
+
+    /// Check that costs are affordable, builds take time, ships can fly,
+    /// hulls hold together and colony ships have room for their colonists
+    pub fn validate(&self) -> GameResult<()> {
+        for (building_type, data) in &self.buildings {
+            data.cost.validate_non_negative()?;
+            if data.build_time == 0 {
+                return Err(GameError::InvalidOperation(format!("{:?} must take at least one tick to build", building_type)));
+            }
+            if data.energy_demand < 0 {
+                return Err(GameError::InvalidOperation(format!("{:?} cannot have a negative energy demand", building_type)));
+            }
+        }
+        for (ship_class, data) in &self.ships {
+            data.cost.validate_non_negative()?;
+            if data.build_time == 0 || data.cargo_capacity < 0 {
+                return Err(GameError::InvalidOperation(format!("{:?} needs a build time and a non-negative cargo capacity", ship_class)));
+            }
+            if !(data.fuel.is_finite() && data.fuel >= 0.0 && data.fuel_efficiency.is_finite() && data.fuel_efficiency > 0.0) {
+                return Err(GameError::InvalidOperation(format!("{:?} needs non-negative fuel and a positive fuel efficiency", ship_class)));
+            }
+            if data.combat.hull <= 0 || data.combat.shields < 0 || data.combat.attack < 0 {
+                return Err(GameError::InvalidOperation(format!("{:?} needs a positive hull and non-negative shields and attack", ship_class)));
+            }
+            if data.combat.missiles < 0 || !(0..=100).contains(&data.combat.point_defense) {
+                return Err(GameError::InvalidOperation(format!("{:?} needs non-negative missile damage and a point defense from 0 to 100 percent", ship_class)));
+            }
+            if let Some(colony) = &data.colony {
+                colony.supplies.validate_non_negative()?;
+                if colony.colonists <= 0 || colony.colonists as i64 + colony.supplies.total() > data.cargo_capacity as i64 {
+                    return Err(GameError::InvalidOperation(format!("{:?} needs colonists and room in its hold for them and their supplies", ship_class)));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Cost and output of a building type
+    pub fn building(&self, building_type: BuildingType) -> Option<&BuildingData> {
+        self.buildings.get(&building_type)
+    }
+
+    /// Every building type with data, in order
+    pub fn buildings(&self) -> impl Iterator<Item = (BuildingType, &BuildingData)> + '_ {
+        self.buildings.iter().map(|(building_type, data)| (*building_type, data))
+    }
+
+    /// Replace the cost and output of a building type
+    pub fn set_building(&mut self, building_type: BuildingType, data: BuildingData) {
+        self.buildings.insert(building_type, data);
+    }
+
+    /// Cost and stats of a ship class
+    pub fn ship(&self, ship_class: ShipClass) -> Option<&ShipData> {
+        self.ships.get(&ship_class)
+    }
+
+    /// Every ship class with data, in order
+    pub fn ships(&self) -> impl Iterator<Item = (ShipClass, &ShipData)> + '_ {
+        self.ships.iter().map(|(ship_class, data)| (*ship_class, data))
+    }
+
+    /// Replace the cost and stats of a ship class
+    pub fn set_ship(&mut self, ship_class: ShipClass, data: ShipData) {
+        self.ships.insert(ship_class, data);
+    }
+
+    /// Prerequisites for building a ship class
+    pub fn ship_requirements(&self, ship_class: ShipClass) -> &[UnlockRequirement] {
+        self.ship_requirements.get(&ship_class).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Replace the prerequisites for a ship class
+    pub fn set_ship_requirements(&mut self, ship_class: ShipClass, requirements: Vec<UnlockRequirement>) {
+        self.ship_requirements.insert(ship_class, requirements);
+    }
+
+    /// Prerequisites for a ship class that the planet and its faction do not yet meet
+    pub fn missing_ship_requirements(&self, ship_class: ShipClass, planet: &Planet, faction: &Faction) -> Vec<UnlockRequirement> {
+        self.ship_requirements(ship_class)
+            .iter()
+            .filter(|requirement| !requirement.is_met(planet, faction))
+            .copied()
+            .collect()
+    }
+
+    /// Prerequisites for building a structure; most buildings have none
+    pub fn building_requirements(&self, building_type: BuildingType) -> &[UnlockRequirement] {
+        self.building_requirements.get(&building_type).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    /// Replace the prerequisites for a building type
+    pub fn set_building_requirements(&mut self, building_type: BuildingType, requirements: Vec<UnlockRequirement>) {
+        self.building_requirements.insert(building_type, requirements);
+    }
+
+    /// Prerequisites for a building type that the planet and its faction do not yet meet
+    pub fn missing_building_requirements(&self, building_type: BuildingType, planet: &Planet, faction: &Faction) -> Vec<UnlockRequirement> {
+        self.building_requirements(building_type)
+            .iter()
+            .filter(|requirement| !requirement.is_met(planet, faction))
+            .copied()
+            .collect()
+    }
+
+    /// Buildings and ship classes that name the technology as a requirement
+    pub fn unlocked_by(&self, technology: Technology) -> (Vec<BuildingType>, Vec<ShipClass>) {
+        let needs = |requirements: &Vec<UnlockRequirement>| requirements.contains(&UnlockRequirement::Technology(technology));
+        let buildings = self.building_requirements.iter()
+            .filter(|(_, requirements)| needs(requirements))
+            .map(|(building_type, _)| *building_type)
+            .collect();
+        let ships = self.ship_requirements.iter()
+            .filter(|(_, requirements)| needs(requirements))
+            .map(|(ship_class, _)| *ship_class)
+            .collect();
+        (buildings, ships)
+    }
+}
+
+impl Default for GameDataRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_data_files_override_only_listed_entries() {
+        let registry = GameDataRegistry::from_ron(r#"(
+            buildings: {
+                Mine: (cost: (minerals: 80, food: 0, energy: 0, alloys: 20, components: 10, fuel: 0), build_time: 8,
+                       production: (minerals: 12, food: 0, energy: -2, alloys: 0, components: 0, fuel: 0)),
+            },
+            ship_requirements: { Warship: [Spaceport(min_tier: 1)] },
+        )"#).unwrap();
+        let defaults = GameDataRegistry::new();
+
+        assert_eq!(registry.building(BuildingType::Mine).unwrap().production.minerals, 12);
+        assert_eq!(registry.building(BuildingType::Farm), defaults.building(BuildingType::Farm));
+        assert_eq!(registry.ship_requirements(ShipClass::Warship), &[UnlockRequirement::Spaceport { min_tier: 1 }]);
+        assert_eq!(registry.ship(ShipClass::Warship), defaults.ship(ShipClass::Warship));
+
+        assert_eq!(GameDataRegistry::from_ron(&defaults.to_ron().unwrap()).unwrap(), defaults);
+    }
+
+    #[test]
+    fn test_invalid_data_files_are_rejected() {
+        assert!(GameDataRegistry::from_ron("( ships: { Scout: (cost: ()) } )").is_err());
+        let free_flight = r#"( ships: { Scout: (cost: (minerals: 1, food: 0, energy: 0, alloys: 0, components: 0, fuel: 0),
+            build_time: 5, cargo_capacity: 0, fuel: 100.0, fuel_efficiency: 0.0) } )"#;
+        assert!(matches!(GameDataRegistry::from_ron(free_flight), Err(GameError::InvalidOperation(_))));
+        let paper_hull = r#"( ships: { Scout: (cost: (minerals: 1, food: 0, energy: 0, alloys: 0, components: 0, fuel: 0),
+            build_time: 5, cargo_capacity: 0, fuel: 100.0, fuel_efficiency: 1.0, combat: (hull: 0, shields: 0, attack: 5)) } )"#;
+        assert!(matches!(GameDataRegistry::from_ron(paper_hull), Err(GameError::InvalidOperation(_))));
+        let crowded = r#"( ships: { Colony: (cost: (minerals: 1, food: 0, energy: 0, alloys: 0, components: 0, fuel: 0),
+            build_time: 5, cargo_capacity: 100, fuel: 100.0, fuel_efficiency: 1.0,
+            colony: Some((colonists: 80, supplies: (minerals: 0, food: 50, energy: 0, alloys: 0, components: 0, fuel: 0)))) } )"#;
+        assert!(matches!(GameDataRegistry::from_ron(crowded), Err(GameError::InvalidOperation(_))));
+    }
+}
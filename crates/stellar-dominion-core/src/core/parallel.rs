@@ -0,0 +1,46 @@
+// src/core/parallel.rs
+//! Data-parallel helpers for per-entity simulation work
+//!
+//! With the `parallel` feature the work is spread over rayon's thread pool;
+//! without it the same closures run one after another. Either way results
+//! come back in the order of the input, so callers that sort their input by
+//! id get the same results, and apply them in the same order, on any number
+//! of threads. The closures only read shared state; whatever they work out
+//! is applied afterwards, sequentially, by the caller.
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Apply `f` to every item, keeping the input order in the results
+pub fn map_ordered<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    {
+        items.par_iter().map(f).collect()
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        items.iter().map(f).collect()
+    }
+}
+
+/// True when built with the `parallel` feature
+pub fn is_parallel() -> bool {
+    cfg!(feature = "parallel")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_results_keep_input_order() {
+        let items: Vec<u32> = (0..1000).collect();
+        let squares = map_ordered(&items, |item| item * item);
+        assert_eq!(squares, items.iter().map(|item| item * item).collect::<Vec<_>>());
+    }
+}
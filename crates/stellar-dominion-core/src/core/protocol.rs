@@ -0,0 +1,226 @@
+// src/core/protocol.rs
+//! Wire format for sending commands and events between game instances
+//!
+//! The simulation is deterministic, so peers that start from the same
+//! configuration and apply the same commands at the same ticks stay in
+//! step. A [`CommandEnvelope`] carries one player command with the tick it
+//! applies at, the faction that issued it and that faction's sequence
+//! number. A [`RemoteCommandQueue`] holds envelopes until their tick comes
+//! round, then issues them to the game in tick, player and sequence order,
+//! so every peer runs them in the same order whatever order they arrived in.
+//!
+//! Messages go over the wire as JSON in a frame with [`PROTOCOL_VERSION`].
+//! Commands and simulation events use serde's default externally tagged
+//! layout, keyed by variant name, so variants may be added but must not be
+//! renamed or reordered in meaning without bumping the version.
+
+use super::events::PlayerCommand;
+use super::types::*;
+use super::GameState;
+use serde::{Serialize, Deserialize, de::DeserializeOwned};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Wire format version; frames with any other version are refused
+pub const PROTOCOL_VERSION: u32 = 1;
+/// How far past a player's last contiguous sequence number an envelope may
+/// arrive out of order
+pub const SEQUENCE_WINDOW: u64 = 256;
+
+/// A player command addressed to a tick, as sent between peers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommandEnvelope {
+    /// Last completed tick when the command applies, as in a replay
+    pub tick: u64,
+    /// Faction issuing the command
+    pub player: FactionId,
+    /// Per-player sequence number counting up from 1; each may only be used once
+    pub seq: u64,
+    /// The order itself
+    pub command: PlayerCommand,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Frame<T> {
+    version: u32,
+    message: T,
+}
+
+/// Encode a command envelope, simulation event or any other message in a
+/// versioned frame
+pub fn to_wire<T: Serialize>(message: &T) -> GameResult<String> {
+    serde_json::to_string(&Frame { version: PROTOCOL_VERSION, message })
+        .map_err(|e| GameError::SystemError(format!("Failed to encode message: {}", e)))
+}
+
+/// Decode a message framed by [`to_wire`]
+///
+/// # Errors
+///
+/// Returns `GameError::Network` for malformed frames and frames from
+/// another protocol version.
+pub fn from_wire<T: DeserializeOwned>(wire: &str) -> GameResult<T> {
+    let frame: Frame<serde_json::Value> = serde_json::from_str(wire)
+        .map_err(|e| GameError::Network(format!("Malformed message: {}", e)))?;
+    if frame.version != PROTOCOL_VERSION {
+        return Err(GameError::Network(format!(
+            "Message uses protocol version {} but this game speaks version {}", frame.version, PROTOCOL_VERSION
+        )));
+    }
+    serde_json::from_value(frame.message)
+        .map_err(|e| GameError::Network(format!("Malformed message: {}", e)))
+}
+
+/// Sequence numbers accepted from one player: every one up to `through`,
+/// and those past it that arrived early
+#[derive(Debug, Clone, Default)]
+struct AcceptedSequences {
+    through: u64,
+    ahead: BTreeSet<u64>,
+}
+
+impl AcceptedSequences {
+    /// Record `seq`, returning false if it was already used
+    fn accept(&mut self, seq: u64) -> bool {
+        if seq <= self.through || !self.ahead.insert(seq) {
+            return false;
+        }
+        while self.ahead.remove(&(self.through + 1)) {
+            self.through += 1;
+        }
+        true
+    }
+}
+
+/// Remote commands waiting for their tick, kept in the order they run in
+#[derive(Debug, Clone, Default)]
+pub struct RemoteCommandQueue {
+    pending: BTreeMap<(u64, FactionId, u64), PlayerCommand>,
+    /// Sequence numbers accepted from each player; envelopes may arrive out
+    /// of order within `SEQUENCE_WINDOW`, but none twice
+    accepted: BTreeMap<FactionId, AcceptedSequences>,
+}
+
+impl RemoteCommandQueue {
+    /// Empty queue
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Hold an envelope until its tick. `current_tick` is the last completed
+    /// tick; an envelope for a tick already simulated arrived too late.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::InvalidOperation` for late envelopes, for
+    /// sequence numbers the player has already used and for those more than
+    /// `SEQUENCE_WINDOW` past the player's last contiguous one.
+    pub fn push(&mut self, envelope: CommandEnvelope, current_tick: u64) -> GameResult<()> {
+        if envelope.tick < current_tick {
+            return Err(GameError::InvalidOperation(format!(
+                "Command {} from faction {} is for tick {} but tick {} has been simulated",
+                envelope.seq, envelope.player, envelope.tick, current_tick
+            )));
+        }
+        let accepted = self.accepted.entry(envelope.player).or_default();
+        if envelope.seq > accepted.through + SEQUENCE_WINDOW {
+            return Err(GameError::InvalidOperation(format!(
+                "Command {} from faction {} is more than {} ahead of command {}",
+                envelope.seq, envelope.player, SEQUENCE_WINDOW, accepted.through
+            )));
+        }
+        if !accepted.accept(envelope.seq) {
+            return Err(GameError::InvalidOperation(format!(
+                "Faction {} already sent command {}", envelope.player, envelope.seq
+            )));
+        }
+        self.pending.insert((envelope.tick, envelope.player, envelope.seq), envelope.command);
+        Ok(())
+    }
+
+    /// Take every envelope due by `tick`, in tick, player and sequence order
+    pub fn take_due(&mut self, tick: u64) -> Vec<CommandEnvelope> {
+        let later = self.pending.split_off(&(tick + 1, 0, 0));
+        std::mem::replace(&mut self.pending, later).into_iter()
+            .map(|((tick, player, seq), command)| CommandEnvelope { tick, player, seq, command })
+            .collect()
+    }
+
+    /// Issue every envelope due by the game's current tick for its player,
+    /// like a local order; call it before playing the tick
+    pub fn issue_due(&mut self, game: &mut GameState) {
+        for envelope in self.take_due(game.get_current_tick()) {
+            game.issue_command(envelope.player, envelope.command);
+        }
+    }
+
+    /// Number of envelopes waiting
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// True when no envelope is waiting
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::events::SimulationEvent;
+
+    fn envelope(tick: u64, player: FactionId, seq: u64) -> CommandEnvelope {
+        CommandEnvelope { tick, player, seq, command: PlayerCommand::StopShip(seq as ShipId) }
+    }
+
+    #[test]
+    fn test_envelopes_and_events_round_trip_through_the_wire() {
+        let sent = CommandEnvelope {
+            tick: 7,
+            player: 1,
+            seq: 3,
+            command: PlayerCommand::BuildStructure { planet: 2, building_type: BuildingType::Farm },
+        };
+        let received: CommandEnvelope = from_wire(&to_wire(&sent).unwrap()).unwrap();
+        assert_eq!((received.tick, received.player, received.seq), (7, 1, 3));
+        assert!(matches!(received.command, PlayerCommand::BuildStructure { planet: 2, building_type: BuildingType::Farm }));
+
+        let event = SimulationEvent::PlanetBombarded { planet: 4, ship: 5, strike: BombardmentStrike::Population { casualties: 9 } };
+        let received: SimulationEvent = from_wire(&to_wire(&event).unwrap()).unwrap();
+        assert!(matches!(received, SimulationEvent::PlanetBombarded { planet: 4, ship: 5, strike: BombardmentStrike::Population { casualties: 9 } }));
+
+        let future = to_wire(&sent).unwrap().replace("\"version\":1", "\"version\":2");
+        assert!(from_wire::<CommandEnvelope>(&future).is_err());
+    }
+
+    #[test]
+    fn test_queue_releases_due_commands_in_a_fixed_order() {
+        let mut queue = RemoteCommandQueue::new();
+        queue.push(envelope(5, 2, 1), 0).unwrap();
+        queue.push(envelope(3, 1, 2), 0).unwrap();
+        queue.push(envelope(3, 0, 1), 0).unwrap();
+        assert!(queue.push(envelope(6, 1, 2), 0).is_err(), "Sequence numbers are used once");
+
+        let due: Vec<_> = queue.take_due(4).iter().map(|e| (e.tick, e.player, e.seq)).collect();
+        assert_eq!(due, vec![(3, 0, 1), (3, 1, 2)]);
+        assert_eq!(queue.len(), 1);
+        assert!(queue.push(envelope(4, 0, 2), 5).is_err(), "Tick 4 is already simulated");
+    }
+
+    #[test]
+    fn test_queue_remembers_sequence_numbers_in_a_bounded_window() {
+        let mut queue = RemoteCommandQueue::new();
+        queue.push(envelope(5, 0, 2), 0).unwrap();
+        queue.push(envelope(5, 0, 1), 0).unwrap();
+        queue.push(envelope(5, 0, 4), 0).unwrap();
+        assert!(queue.push(envelope(5, 0, 2), 0).is_err(), "Sequence numbers are used once");
+
+        // Contiguous numbers fold into the high-water mark; only gaps are kept
+        let accepted = &queue.accepted[&0];
+        assert_eq!((accepted.through, accepted.ahead.len()), (2, 1));
+        queue.push(envelope(5, 0, 3), 0).unwrap();
+        assert_eq!((queue.accepted[&0].through, queue.accepted[&0].ahead.len()), (4, 0));
+        assert!(queue.push(envelope(5, 0, 4 + SEQUENCE_WINDOW + 1), 0).is_err());
+        queue.push(envelope(5, 0, 4 + SEQUENCE_WINDOW), 0).unwrap();
+    }
+}
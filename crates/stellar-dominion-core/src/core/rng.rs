@@ -0,0 +1,148 @@
+// src/core/rng.rs
+//! Deterministic random numbers for the simulation
+//!
+//! [`SimRng`] is seeded from `GameConfiguration::seed` when a game starts and
+//! saved with the game, so a loaded save or a replay draws the same numbers.
+//! Every consumer draws from its own [`RngStream`]. A stream's sequence
+//! depends only on the seed and the stream, so a new consumer, or an existing
+//! one drawing more often, never shifts the numbers another stream produces.
+//!
+//! The generator travels on the [`EventBus`](super::EventBus) that GameState
+//! owns, which puts it in reach of every system's `update`. Work started from
+//! `handle_event`, which has no bus, takes a seed drawn by GameState instead.
+
+use serde::{Serialize, Deserialize};
+use std::collections::BTreeMap;
+
+/// SplitMix64 step; small, deterministic and good enough for gameplay rolls
+pub(crate) fn next_seed(state: u64) -> u64 {
+    let mut z = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// Uniform value in [0, 1)
+pub(crate) fn unit(state: &mut u64) -> f32 {
+    *state = next_seed(*state);
+    (*state >> 40) as f32 / (1u64 << 24) as f32
+}
+
+/// Independent sequence of random numbers for one consumer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum RngStream {
+    /// Damage rolls in ship battles
+    Combat,
+    /// Choices made by AI factions
+    Ai,
+    /// Random and scripted game events
+    Events,
+}
+
+impl RngStream {
+    /// All streams in canonical order
+    pub const ALL: [RngStream; 3] = [RngStream::Combat, RngStream::Ai, RngStream::Events];
+
+    /// Mixed into the game seed to start the stream
+    fn salt(self) -> u64 {
+        match self {
+            RngStream::Combat => 0xc0ba_7d1c_e5ee_d001,
+            RngStream::Ai => 0xa1d3_c1de_5eed_0002,
+            RngStream::Events => 0xe7e4_75ee_d5a1_0003,
+        }
+    }
+}
+
+/// Seeded random number generator with one state per [`RngStream`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SimRng {
+    seed: u64,
+    /// State of each stream drawn from so far; the others start from the seed
+    streams: BTreeMap<RngStream, u64>,
+}
+
+impl SimRng {
+    /// Generator for `seed` with every stream at its start
+    pub fn new(seed: u64) -> Self {
+        Self { seed, streams: BTreeMap::new() }
+    }
+
+    /// Seed the generator started from
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    fn state(&mut self, stream: RngStream) -> &mut u64 {
+        let seed = self.seed;
+        self.streams.entry(stream).or_insert_with(|| next_seed(seed ^ stream.salt()))
+    }
+
+    /// Next 64 random bits from the stream
+    pub fn next_u64(&mut self, stream: RngStream) -> u64 {
+        let state = self.state(stream);
+        *state = next_seed(*state);
+        *state
+    }
+
+    /// Uniform value in [0, 1) from the stream
+    pub fn next_f32(&mut self, stream: RngStream) -> f32 {
+        unit(self.state(stream))
+    }
+
+    /// Uniform value in [0, bound) from the stream; 0 when `bound` is 0
+    pub fn below(&mut self, stream: RngStream, bound: u64) -> u64 {
+        match bound {
+            0 => 0,
+            bound => self.next_u64(stream) % bound,
+        }
+    }
+
+    /// True with the given probability, from 0.0 to 1.0
+    pub fn chance(&mut self, stream: RngStream, probability: f32) -> bool {
+        self.next_f32(stream) < probability
+    }
+}
+
+impl Default for SimRng {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_streams_do_not_shift_each_other() {
+        let mut quiet = SimRng::new(7);
+        let mut busy = SimRng::new(7);
+        for _ in 0..10 {
+            busy.next_u64(RngStream::Ai);
+        }
+        busy.next_f32(RngStream::Events);
+        let combat = |rng: &mut SimRng| (0..5).map(|_| rng.next_u64(RngStream::Combat)).collect::<Vec<_>>();
+        assert_eq!(combat(&mut quiet), combat(&mut busy));
+
+        let mut other_seed = SimRng::new(8);
+        assert_ne!(combat(&mut SimRng::new(7)), combat(&mut other_seed));
+        assert_ne!(SimRng::new(7).next_u64(RngStream::Ai), SimRng::new(7).next_u64(RngStream::Combat));
+    }
+
+    #[test]
+    fn test_saved_generator_continues_where_it_left_off() {
+        let mut rng = SimRng::new(42);
+        rng.next_u64(RngStream::Combat);
+        let restored: SimRng = serde_json::from_str(&serde_json::to_string(&rng).unwrap()).unwrap();
+        assert_eq!(restored, rng);
+        assert_eq!(restored.clone().next_u64(RngStream::Combat), rng.next_u64(RngStream::Combat));
+
+        for _ in 0..100 {
+            assert!(rng.below(RngStream::Events, 6) < 6);
+            assert!((0.0..1.0).contains(&rng.next_f32(RngStream::Events)));
+        }
+        assert_eq!(rng.below(RngStream::Events, 0), 0);
+        assert!(!rng.chance(RngStream::Events, 0.0));
+        assert!(rng.chance(RngStream::Events, 1.0));
+    }
+}
@@ -0,0 +1,114 @@
+// src/core/script_hook.rs
+//! Script hooks for layering behavior on the simulation from outside the crate
+//!
+//! A [`ScriptHook`] registered with `GameState::add_script_hook` is called at
+//! fixed points of the simulation: [`ScriptHook::on_command`] for every
+//! player command that passed validation and [`ScriptHook::on_event`] for
+//! every other event, each after the systems have handled it, and
+//! [`ScriptHook::on_tick`] once a tick's events are processed. Hooks read the
+//! game but cannot change it directly; what they want done goes through a
+//! [`ScriptQueue`] onto the EventBus, to be handled like any other event with
+//! the next batch. Tutorial scripts and custom win conditions are built this
+//! way.
+//!
+//! Hooks are called in the order they were registered. They stay registered
+//! when a new or loaded world replaces the current one and are not saved.
+
+use super::events::{GameEvent, PlayerCommand};
+use super::GameState;
+
+/// Handle of a registered hook, for removing it again
+pub type ScriptHookId = u32;
+
+/// Extension called at fixed points of the simulation. Every method does
+/// nothing unless overridden.
+pub trait ScriptHook: Send {
+    /// A tick finished and its events were processed; `tick` is the tick the
+    /// game is at now
+    fn on_tick(&mut self, _game: &GameState, _tick: u64, _queue: &mut ScriptQueue) {}
+
+    /// The systems handled an event that is not a player command
+    fn on_event(&mut self, _game: &GameState, _event: &GameEvent, _queue: &mut ScriptQueue) {}
+
+    /// The systems handled a player command that passed validation
+    fn on_command(&mut self, _game: &GameState, _command: &PlayerCommand, _queue: &mut ScriptQueue) {}
+}
+
+/// Events a hook asks for, queued on the EventBus once the hook returns.
+/// This is all a hook may change.
+#[derive(Debug, Default)]
+pub struct ScriptQueue {
+    events: Vec<GameEvent>,
+}
+
+impl ScriptQueue {
+    /// Empty queue
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue an event for the EventBus
+    pub fn queue_event(&mut self, event: GameEvent) {
+        self.events.push(event);
+    }
+
+    /// Queue a player command; it is validated like one from the UI
+    pub fn queue_command(&mut self, command: PlayerCommand) {
+        self.events.push(GameEvent::PlayerCommand(command));
+    }
+
+    /// Events queued so far, oldest first
+    pub fn events(&self) -> &[GameEvent] {
+        &self.events
+    }
+
+    /// Take the queued events, leaving the queue empty
+    pub fn drain(&mut self) -> Vec<GameEvent> {
+        std::mem::take(&mut self.events)
+    }
+}
+
+/// Registered hooks in registration order
+#[derive(Default)]
+pub struct ScriptHooks {
+    hooks: Vec<(ScriptHookId, Box<dyn ScriptHook>)>,
+    next_id: ScriptHookId,
+}
+
+impl ScriptHooks {
+    /// No hooks registered
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a hook after those already registered
+    pub fn add(&mut self, hook: Box<dyn ScriptHook>) -> ScriptHookId {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.hooks.push((id, hook));
+        id
+    }
+
+    /// Unregister a hook, handing it back
+    pub fn remove(&mut self, id: ScriptHookId) -> Option<Box<dyn ScriptHook>> {
+        let index = self.hooks.iter().position(|(hook_id, _)| *hook_id == id)?;
+        Some(self.hooks.remove(index).1)
+    }
+
+    /// Number of hooks registered
+    pub fn len(&self) -> usize {
+        self.hooks.len()
+    }
+
+    /// True when no hook is registered
+    pub fn is_empty(&self) -> bool {
+        self.hooks.is_empty()
+    }
+
+    /// Call `f` on every hook in order, collecting what they queue
+    pub(crate) fn call(&mut self, queue: &mut ScriptQueue, mut f: impl FnMut(&mut dyn ScriptHook, &mut ScriptQueue)) {
+        for (_, hook) in &mut self.hooks {
+            f(hook.as_mut(), queue);
+        }
+    }
+}
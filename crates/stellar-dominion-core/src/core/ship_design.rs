@@ -0,0 +1,354 @@
+// src/core/ship_design.rs
+//! Ship designs: a hull size fitted with modules
+//!
+//! A [`ShipDesign`] puts modules into the slots of a hull. Its stats —
+//! speed, cargo, firepower, fuel, sensor range, cost and build time — are
+//! worked out from the hull and modules by [`ShipDesign::stats`], and its
+//! role, the [`ShipClass`] the rest of the game checks, follows from the
+//! modules it carries.
+//!
+//! Designs belong to a faction and are kept in `Faction::ship_designs`, so
+//! they are saved with it. A ship built from a design keeps a copy of it,
+//! so later changes to the faction's designs do not alter ships already in
+//! service. Ships of the four preset classes carry no design and take their
+//! stats from the `GameDataRegistry` as before; [`ShipDesign::preset`] gives
+//! the hull and modules each preset corresponds to, as a starting point in
+//! the [`ShipDesigner`].
+
+use super::game_data::{ShipData, CombatStats, ColonyKit};
+use super::types::*;
+use crate::config::SHIP_BASE_SPEED;
+use serde::{Serialize, Deserialize};
+
+/// Identifies a design among its faction's designs
+pub type DesignId = u32;
+
+/// Fastest a design may fly, as a multiple of `SHIP_BASE_SPEED`
+pub const MAX_SPEED_FACTOR: f32 = 2.0;
+
+/// Size of a ship's hull, which sets its slots, toughness and base cost
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum HullSize {
+    /// Few slots, light and cheap
+    Small,
+    /// Balanced slots, toughness and cost
+    Medium,
+    /// Most slots and toughness at the highest cost
+    Large,
+}
+
+impl HullSize {
+    /// All hull sizes, smallest first
+    pub const ALL: [HullSize; 3] = [HullSize::Small, HullSize::Medium, HullSize::Large];
+
+    /// Modules the hull has room for
+    pub fn slots(self) -> usize {
+        match self {
+            HullSize::Small => 2,
+            HullSize::Medium => 4,
+            HullSize::Large => 6,
+        }
+    }
+
+    /// Weight the engines have to move; heavier hulls are slower and thirstier
+    fn mass(self) -> f32 {
+        match self {
+            HullSize::Small => 1.0,
+            HullSize::Medium => 2.0,
+            HullSize::Large => 3.0,
+        }
+    }
+
+    fn cost(self) -> ResourceBundle {
+        match self {
+            HullSize::Small => ResourceBundle { minerals: 30, alloys: 20, components: 10, fuel: 5, ..Default::default() },
+            HullSize::Medium => ResourceBundle { minerals: 60, alloys: 40, components: 25, fuel: 10, ..Default::default() },
+            HullSize::Large => ResourceBundle { minerals: 120, energy: 5, alloys: 80, components: 50, fuel: 20, ..Default::default() },
+        }
+    }
+
+    fn build_time(self) -> u64 {
+        match self {
+            HullSize::Small => 6,
+            HullSize::Medium => 10,
+            HullSize::Large => 16,
+        }
+    }
+
+    fn combat(self) -> CombatStats {
+        match self {
+            HullSize::Small => CombatStats { hull: 40, ..Default::default() },
+            HullSize::Medium => CombatStats { hull: 90, shields: 10, ..Default::default() },
+            HullSize::Large => CombatStats { hull: 180, shields: 30, ..Default::default() },
+        }
+    }
+
+    fn fuel(self) -> f32 {
+        match self {
+            HullSize::Small => 60.0,
+            HullSize::Medium => 80.0,
+            HullSize::Large => 100.0,
+        }
+    }
+}
+
+/// Equipment fitted into one hull slot
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum ShipModule {
+    /// Raises speed; every design needs at least one
+    Engine,
+    /// Cargo space for resources and passengers
+    CargoPod,
+    /// Guns; a design with weapons is a warship
+    Weapon,
+    /// Sensor range and point defense
+    Sensor,
+    /// Settlers and stores to found a colony; at most one per design
+    ColonyModule,
+    /// Fuel carried on top of the hull's own tanks
+    FuelTank,
+}
+
+impl ShipModule {
+    /// All modules in canonical order
+    pub const ALL: [ShipModule; 6] = [
+        ShipModule::Engine,
+        ShipModule::CargoPod,
+        ShipModule::Weapon,
+        ShipModule::Sensor,
+        ShipModule::ColonyModule,
+        ShipModule::FuelTank,
+    ];
+
+    /// Resources the module adds to the design's cost
+    pub fn cost(self) -> ResourceBundle {
+        match self {
+            ShipModule::Engine => ResourceBundle { minerals: 10, alloys: 5, components: 10, fuel: 5, ..Default::default() },
+            ShipModule::CargoPod => ResourceBundle { minerals: 15, alloys: 10, ..Default::default() },
+            ShipModule::Weapon => ResourceBundle { minerals: 20, alloys: 20, components: 15, fuel: 5, ..Default::default() },
+            ShipModule::Sensor => ResourceBundle { minerals: 5, alloys: 5, components: 15, ..Default::default() },
+            ShipModule::ColonyModule => ResourceBundle { minerals: 80, food: 50, energy: 10, alloys: 20, components: 40, fuel: 10 },
+            ShipModule::FuelTank => ResourceBundle { minerals: 10, alloys: 5, fuel: 10, ..Default::default() },
+        }
+    }
+
+    /// Ticks the module adds to the design's build time
+    pub fn build_time(self) -> u64 {
+        match self {
+            ShipModule::ColonyModule => 7,
+            _ => 2,
+        }
+    }
+}
+
+/// What a design can do, worked out from its hull and modules
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DesignStats {
+    /// Resources the ship costs
+    pub cost: ResourceBundle,
+    /// Ticks from ordering to completion
+    pub build_time: u64,
+    /// Distance covered per tick in transit
+    pub speed: f32,
+    /// Cargo space for resources and passengers
+    pub cargo_capacity: i32,
+    /// Fuel a new ship starts with
+    pub fuel: f32,
+    /// Distance travelled per unit of fuel
+    pub fuel_efficiency: f32,
+    /// Radius within which the ship sees planets and ships
+    pub sensor_range: f32,
+    /// Hull, shields and weapons
+    pub combat: CombatStats,
+    /// Settlers and stores aboard a new ship, with a colony module
+    pub colony: Option<ColonyKit>,
+}
+
+impl DesignStats {
+    /// Average damage dealt per round of battle
+    pub fn firepower(&self) -> i32 {
+        self.combat.attack
+    }
+
+    /// The stats in the form the registry holds for preset classes
+    pub fn ship_data(&self) -> ShipData {
+        ShipData {
+            cost: self.cost,
+            build_time: self.build_time,
+            cargo_capacity: self.cargo_capacity,
+            fuel: self.fuel,
+            fuel_efficiency: self.fuel_efficiency,
+            combat: self.combat,
+            colony: self.colony,
+        }
+    }
+}
+
+/// A faction's ship design
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShipDesign {
+    /// Assigned by `FactionManager::save_ship_design`, unique within the faction
+    pub id: DesignId,
+    /// Name shown in the build menu
+    pub name: String,
+    /// Hull the modules are fitted to
+    pub hull: HullSize,
+    /// Modules in the hull's slots, in the order they were fitted
+    pub modules: Vec<ShipModule>,
+}
+
+impl ShipDesign {
+    /// The hull and modules matching a preset class, named after it
+    pub fn preset(ship_class: ShipClass) -> Self {
+        use ShipModule::*;
+        let (hull, modules) = match ship_class {
+            ShipClass::Scout => (HullSize::Small, vec![Engine, Sensor]),
+            ShipClass::Transport => (HullSize::Medium, vec![Engine, CargoPod, CargoPod, CargoPod]),
+            ShipClass::Warship => (HullSize::Large, vec![Engine, Engine, Weapon, Weapon, Sensor, FuelTank]),
+            ShipClass::Colony => (HullSize::Medium, vec![Engine, ColonyModule, CargoPod, FuelTank]),
+        };
+        Self { id: 0, name: format!("{:?}", ship_class), hull, modules }
+    }
+
+    /// How many of a module the design carries
+    pub fn count(&self, module: ShipModule) -> usize {
+        self.modules.iter().filter(|&&fitted| fitted == module).count()
+    }
+
+    /// The class the rest of the game treats ships of this design as:
+    /// colony ships, then warships, then transports, otherwise scouts
+    pub fn role(&self) -> ShipClass {
+        if self.count(ShipModule::ColonyModule) > 0 {
+            ShipClass::Colony
+        } else if self.count(ShipModule::Weapon) > 0 {
+            ShipClass::Warship
+        } else if self.count(ShipModule::CargoPod) > 0 {
+            ShipClass::Transport
+        } else {
+            ShipClass::Scout
+        }
+    }
+
+    /// Check the design can be built: a name, no more modules than slots,
+    /// an engine and at most one colony module
+    pub fn validate(&self) -> GameResult<()> {
+        if self.name.trim().is_empty() {
+            return Err(GameError::InvalidOperation("A ship design needs a name".into()));
+        }
+        if self.modules.len() > self.hull.slots() {
+            return Err(GameError::InvalidOperation(format!(
+                "A {:?} hull has {} slots, not {}", self.hull, self.hull.slots(), self.modules.len()
+            )));
+        }
+        if self.count(ShipModule::Engine) == 0 {
+            return Err(GameError::InvalidOperation(format!("Design '{}' has no engine", self.name)));
+        }
+        if self.count(ShipModule::ColonyModule) > 1 {
+            return Err(GameError::InvalidOperation(format!("Design '{}' has more than one colony module", self.name)));
+        }
+        Ok(())
+    }
+
+    /// Stats of ships built to this design
+    pub fn stats(&self) -> DesignStats {
+        let mass = self.hull.mass();
+        let engines = self.count(ShipModule::Engine) as f32;
+        let sensors = self.count(ShipModule::Sensor) as i32;
+        let mut cost = self.hull.cost();
+        let mut build_time = self.hull.build_time();
+        for module in &self.modules {
+            cost += module.cost();
+            build_time += module.build_time();
+        }
+        let mut combat = self.hull.combat();
+        combat.attack = 15 * self.count(ShipModule::Weapon) as i32;
+        combat.point_defense = (10 * sensors).min(100);
+        let colony = (self.count(ShipModule::ColonyModule) > 0).then_some(ColonyKit {
+            colonists: 200,
+            supplies: ResourceBundle { minerals: 100, food: 150, energy: 50, ..Default::default() },
+        });
+        DesignStats {
+            cost,
+            build_time,
+            speed: SHIP_BASE_SPEED * (0.5 + 0.5 * engines / mass).min(MAX_SPEED_FACTOR),
+            cargo_capacity: 250 * self.count(ShipModule::CargoPod) as i32 + 500 * self.count(ShipModule::ColonyModule) as i32,
+            fuel: self.hull.fuel() + 60.0 * self.count(ShipModule::FuelTank) as f32,
+            fuel_efficiency: 200.0 / mass,
+            sensor_range: 1.5 + 2.0 * sensors as f32,
+            combat,
+            colony,
+        }
+    }
+}
+
+/// A design being put together, one module at a time, before it is saved
+/// with `PlayerCommand::SaveShipDesign`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShipDesigner {
+    hull: HullSize,
+    modules: Vec<ShipModule>,
+}
+
+impl ShipDesigner {
+    /// An empty hull
+    pub fn new(hull: HullSize) -> Self {
+        Self { hull, modules: Vec::new() }
+    }
+
+    /// Start from an existing design, such as a preset
+    pub fn from_design(design: &ShipDesign) -> Self {
+        Self { hull: design.hull, modules: design.modules.clone() }
+    }
+
+    /// Hull the design is built on
+    pub fn hull(&self) -> HullSize {
+        self.hull
+    }
+
+    /// Modules fitted so far
+    pub fn modules(&self) -> &[ShipModule] {
+        &self.modules
+    }
+
+    /// Slots still empty
+    pub fn free_slots(&self) -> usize {
+        self.hull.slots().saturating_sub(self.modules.len())
+    }
+
+    /// Change the hull; modules that no longer fit are taken out, last fitted first
+    pub fn set_hull(&mut self, hull: HullSize) {
+        self.hull = hull;
+        self.modules.truncate(hull.slots());
+    }
+
+    /// Fit a module into a free slot
+    pub fn add_module(&mut self, module: ShipModule) -> GameResult<()> {
+        if self.free_slots() == 0 {
+            return Err(GameError::InvalidOperation(format!("The {:?} hull has no free slot", self.hull)));
+        }
+        self.modules.push(module);
+        Ok(())
+    }
+
+    /// Take out the module in a slot
+    pub fn remove_module(&mut self, slot: usize) -> Option<ShipModule> {
+        (slot < self.modules.len()).then(|| self.modules.remove(slot))
+    }
+
+    /// The design as it stands, under a name
+    pub fn design(&self, name: &str) -> ShipDesign {
+        ShipDesign { id: 0, name: name.trim().to_string(), hull: self.hull, modules: self.modules.clone() }
+    }
+
+    /// Stats of the design as it stands
+    pub fn stats(&self) -> DesignStats {
+        self.design("").stats()
+    }
+
+    /// The finished design, if it can be built
+    pub fn finish(&self, name: &str) -> GameResult<ShipDesign> {
+        let design = self.design(name);
+        design.validate()?;
+        Ok(design)
+    }
+}
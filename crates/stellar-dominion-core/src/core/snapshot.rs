@@ -0,0 +1,129 @@
+// src/core/snapshot.rs
+//! Read-only copies of a game for spectators and dashboards
+//!
+//! A [`GameSnapshot`] holds what can be observed of a game at one tick: the
+//! star map, planets, ships and factions. It owns its data, so it can be
+//! kept, sent to another thread or serialized and streamed to a spectator
+//! client without lending out the managers. A spectator snapshot shows
+//! everything; a faction's snapshot shows only what that faction's sensors
+//! see and keeps other factions' treasuries, research and diplomacy private.
+
+use super::GameState;
+use super::star_map::StarMap;
+use super::types::*;
+use serde::{Serialize, Deserialize};
+
+/// Observable state of a game at one tick
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameSnapshot {
+    /// Last completed tick
+    pub tick: u64,
+    /// Faction whose view this is; None for a spectator, who sees everything
+    pub viewer: Option<FactionId>,
+    /// Star systems and jump lanes, which every faction knows
+    pub star_map: StarMap,
+    /// Planets in view, in id order
+    pub planets: Vec<Planet>,
+    /// Ships in view, in id order
+    pub ships: Vec<Ship>,
+    /// Every faction, in id order
+    pub factions: Vec<FactionSnapshot>,
+}
+
+/// A faction as others see it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FactionSnapshot {
+    /// Faction id
+    pub id: FactionId,
+    /// Display name
+    pub name: String,
+    /// True for the human player's faction
+    pub is_player: bool,
+    /// Empire or system faction
+    pub kind: FactionKind,
+    /// Current score
+    pub score: i32,
+    /// The whole faction record, for spectators and for the viewer's own
+    /// faction; None for the viewer's rivals
+    pub details: Option<Faction>,
+}
+
+impl FactionSnapshot {
+    fn new(faction: &Faction, detailed: bool) -> Self {
+        Self {
+            id: faction.id,
+            name: faction.name.clone(),
+            is_player: faction.is_player,
+            kind: faction.kind,
+            score: faction.score,
+            details: detailed.then(|| faction.clone()),
+        }
+    }
+}
+
+impl GameSnapshot {
+    /// Everything in the game
+    pub(super) fn spectator(game: &GameState) -> Self {
+        Self::capture(game, None, |_| true, |_| true)
+    }
+
+    /// What the faction sees: its own planets and ships and whatever its
+    /// sensors saw at the latest tick
+    pub(super) fn for_faction(game: &GameState, faction: FactionId) -> GameResult<Self> {
+        game.faction_manager.get_faction(faction)?;
+        let unseen = FactionVisibility::default();
+        let visibility = game.faction_visibility(faction).unwrap_or(&unseen);
+        Ok(Self::capture(
+            game,
+            Some(faction),
+            |planet| planet.controller == Some(faction) || visibility.can_see_planet(planet.id),
+            |ship| ship.owner == faction || visibility.can_see_ship(ship.id),
+        ))
+    }
+
+    fn capture(
+        game: &GameState,
+        viewer: Option<FactionId>,
+        show_planet: impl Fn(&Planet) -> bool,
+        show_ship: impl Fn(&Ship) -> bool,
+    ) -> Self {
+        let mut planets: Vec<Planet> = game.planet_manager.get_all_planets().iter()
+            .filter(|planet| show_planet(planet))
+            .cloned()
+            .collect();
+        planets.sort_by_key(|planet| planet.id);
+        let mut ships: Vec<Ship> = game.ship_manager.get_all_ships().iter()
+            .filter(|ship| show_ship(ship))
+            .cloned()
+            .collect();
+        ships.sort_by_key(|ship| ship.id);
+        let mut factions: Vec<FactionSnapshot> = game.faction_manager.get_all_factions().iter()
+            .map(|faction| FactionSnapshot::new(faction, viewer.is_none_or(|viewer| viewer == faction.id)))
+            .collect();
+        factions.sort_by_key(|faction| faction.id);
+
+        Self {
+            tick: game.get_current_tick(),
+            viewer,
+            star_map: game.physics_engine.star_map().clone(),
+            planets,
+            ships,
+            factions,
+        }
+    }
+
+    /// Planet in view, if any
+    pub fn planet(&self, id: PlanetId) -> Option<&Planet> {
+        self.planets.iter().find(|planet| planet.id == id)
+    }
+
+    /// Ship in view, if any
+    pub fn ship(&self, id: ShipId) -> Option<&Ship> {
+        self.ships.iter().find(|ship| ship.id == id)
+    }
+
+    /// A faction as the viewer sees it
+    pub fn faction(&self, id: FactionId) -> Option<&FactionSnapshot> {
+        self.factions.iter().find(|faction| faction.id == id)
+    }
+}
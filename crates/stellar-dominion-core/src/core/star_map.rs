@@ -0,0 +1,222 @@
+// src/core/star_map.rs
+//! Star systems and the jump lanes between them
+//!
+//! Every planet orbits the star of its [`StarSystem`], so its place in the
+//! galaxy is the star's position plus its orbital position. Ships fly freely
+//! within a system but reach other systems only along [`JumpLane`]s: a ship
+//! enters a lane at the jump point [`JUMP_POINT_DISTANCE`] from its star in the
+//! direction of the far end, spends the lane's travel time in it and comes out
+//! at the far end's jump point. Lanes work both ways. Single-star galaxies,
+//! including saves made before there were several stars, use
+//! [`StarMap::single`].
+
+use super::types::*;
+use serde::{Serialize, Deserialize};
+
+/// Distance from a star to its jump points, in AU; beyond every generated orbit
+pub const JUMP_POINT_DISTANCE: f32 = 15.0;
+/// Name of the star in a single-star galaxy
+pub const HOME_STAR_NAME: &str = "Sol";
+
+/// A star and, through `Planet::system`, the planets orbiting it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StarSystem {
+    /// Id planets refer to in `Planet::system`
+    pub id: StarSystemId,
+    /// Name shown on the galaxy map
+    pub name: String,
+    /// Position of the star in the galaxy, in AU
+    pub position: Vector2,
+}
+
+/// A jump lane between two star systems, crossed either way
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct JumpLane {
+    /// System at one end
+    pub from: StarSystemId,
+    /// System at the other end
+    pub to: StarSystemId,
+    /// Ticks a ship spends in the lane
+    pub travel_ticks: u64,
+    /// Fuel a ship needs to cross the lane
+    pub fuel_cost: f32,
+}
+
+impl JumpLane {
+    /// The other end of the lane, if it starts or ends at `system`
+    pub fn other_end(&self, system: StarSystemId) -> Option<StarSystemId> {
+        if self.from == system {
+            Some(self.to)
+        } else if self.to == system {
+            Some(self.from)
+        } else {
+            None
+        }
+    }
+}
+
+/// The galaxy's star systems and jump lanes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StarMap {
+    /// Star systems by id
+    pub systems: Vec<StarSystem>,
+    /// Lanes between the systems
+    pub lanes: Vec<JumpLane>,
+}
+
+impl StarMap {
+    /// One star at the origin and no lanes
+    pub fn single() -> Self {
+        Self {
+            systems: vec![StarSystem { id: 0, name: HOME_STAR_NAME.to_string(), position: Vector2::default() }],
+            lanes: Vec::new(),
+        }
+    }
+
+    /// Reject lanes to unknown systems, lanes from a system to itself, repeated
+    /// system ids and lanes without travel time or with negative fuel costs
+    pub fn validate(&self) -> GameResult<()> {
+        for (index, system) in self.systems.iter().enumerate() {
+            if self.systems[..index].iter().any(|other| other.id == system.id) {
+                return Err(GameError::InvalidOperation(format!("Star system {} appears twice", system.id)));
+            }
+            if !system.position.x.is_finite() || !system.position.y.is_finite() {
+                return Err(GameError::InvalidOperation(format!("Star system {} must have finite coordinates", system.id)));
+            }
+        }
+        for lane in &self.lanes {
+            if self.system(lane.from).is_none() || self.system(lane.to).is_none() {
+                return Err(GameError::InvalidOperation(format!("Jump lane {} - {} leads to an unknown system", lane.from, lane.to)));
+            }
+            if lane.from == lane.to {
+                return Err(GameError::InvalidOperation(format!("Jump lane from system {} to itself", lane.from)));
+            }
+            if lane.travel_ticks == 0 || !(lane.fuel_cost >= 0.0 && lane.fuel_cost.is_finite()) {
+                return Err(GameError::InvalidOperation(format!(
+                    "Jump lane {} - {} needs a travel time and a fuel cost of zero or more", lane.from, lane.to
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// The system with the given id
+    pub fn system(&self, id: StarSystemId) -> Option<&StarSystem> {
+        self.systems.iter().find(|system| system.id == id)
+    }
+
+    /// Position of the system's star; the origin for unknown systems
+    pub fn star_position(&self, id: StarSystemId) -> Vector2 {
+        self.system(id).map_or_else(Vector2::default, |system| system.position)
+    }
+
+    /// The system whose star is nearest to `position`, lowest id on ties
+    pub fn system_at(&self, position: Vector2) -> StarSystemId {
+        self.systems.iter()
+            .min_by(|a, b| position.distance_to(&a.position).total_cmp(&position.distance_to(&b.position)).then(a.id.cmp(&b.id)))
+            .map_or(0, |system| system.id)
+    }
+
+    /// Systems one lane away from `system`, with the lanes leading there
+    pub fn neighbors(&self, system: StarSystemId) -> impl Iterator<Item = (StarSystemId, &JumpLane)> {
+        self.lanes.iter().filter_map(move |lane| lane.other_end(system).map(|other| (other, lane)))
+    }
+
+    /// Where ships leave `from` for `to`: [`JUMP_POINT_DISTANCE`] from the
+    /// star of `from` in the direction of `to`
+    pub fn jump_point(&self, from: StarSystemId, to: StarSystemId) -> Vector2 {
+        let star = self.star_position(from);
+        let toward = self.star_position(to);
+        let offset = Vector2::new(toward.x - star.x, toward.y - star.y).normalize();
+        Vector2::new(star.x + offset.x * JUMP_POINT_DISTANCE, star.y + offset.y * JUMP_POINT_DISTANCE)
+    }
+
+    /// Quickest chain of lanes from one system to another, each turned to
+    /// point the way it is crossed; empty within a system and None when no
+    /// lanes lead there. Fewer travel ticks win, then lower system ids.
+    pub fn lane_path(&self, from: StarSystemId, to: StarSystemId) -> Option<Vec<JumpLane>> {
+        if from == to {
+            return Some(Vec::new());
+        }
+        self.system(from)?;
+        self.system(to)?;
+
+        let mut ticks: Vec<(StarSystemId, u64)> = vec![(from, 0)];
+        let mut previous: Vec<(StarSystemId, JumpLane)> = Vec::new();
+        let mut visited: Vec<StarSystemId> = Vec::new();
+        while let Some((current, elapsed)) = ticks.iter()
+            .filter(|(system, _)| !visited.contains(system))
+            .min_by_key(|(system, elapsed)| (*elapsed, *system))
+            .copied()
+        {
+            if current == to {
+                break;
+            }
+            visited.push(current);
+            for (next, lane) in self.neighbors(current) {
+                let candidate = elapsed + lane.travel_ticks;
+                let known = ticks.iter().position(|(system, _)| *system == next);
+                if known.is_some_and(|index| ticks[index].1 <= candidate) || visited.contains(&next) {
+                    continue;
+                }
+                let crossing = JumpLane { from: current, to: next, ..*lane };
+                match known {
+                    Some(index) => ticks[index].1 = candidate,
+                    None => ticks.push((next, candidate)),
+                }
+                previous.retain(|(system, _)| *system != next);
+                previous.push((next, crossing));
+            }
+        }
+
+        let mut path = Vec::new();
+        let mut current = to;
+        while current != from {
+            let (_, lane) = previous.iter().find(|(system, _)| *system == current)?;
+            path.push(*lane);
+            current = lane.from;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+impl Default for StarMap {
+    fn default() -> Self {
+        Self::single()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn star(id: StarSystemId, x: f32) -> StarSystem {
+        StarSystem { id, name: format!("Star {}", id), position: Vector2::new(x, 0.0) }
+    }
+
+    #[test]
+    fn test_lane_path_takes_the_quickest_chain_of_lanes() {
+        let map = StarMap {
+            systems: vec![star(0, 0.0), star(1, 50.0), star(2, 100.0), star(3, -50.0)],
+            lanes: vec![
+                JumpLane { from: 0, to: 1, travel_ticks: 10, fuel_cost: 5.0 },
+                JumpLane { from: 2, to: 1, travel_ticks: 10, fuel_cost: 5.0 },
+                JumpLane { from: 0, to: 2, travel_ticks: 30, fuel_cost: 1.0 },
+            ],
+        };
+        assert!(map.validate().is_ok());
+
+        let path = map.lane_path(0, 2).unwrap();
+        let hops: Vec<(StarSystemId, StarSystemId)> = path.iter().map(|lane| (lane.from, lane.to)).collect();
+        assert_eq!(hops, vec![(0, 1), (1, 2)], "Two short lanes beat one long one, and lanes work both ways");
+        assert_eq!(map.lane_path(2, 2), Some(Vec::new()));
+        assert!(map.lane_path(0, 3).is_none(), "No lanes lead to system 3");
+
+        assert_eq!(map.system_at(Vector2::new(60.0, 3.0)), 1);
+        assert_eq!(map.jump_point(1, 2), Vector2::new(50.0 + JUMP_POINT_DISTANCE, 0.0));
+
+        let broken = StarMap { lanes: vec![JumpLane { from: 0, to: 9, travel_ticks: 1, fuel_cost: 0.0 }], ..map };
+        assert!(broken.validate().is_err());
+    }
+}
@@ -0,0 +1,88 @@
+// src/core/system_context.rs
+//! What a system may see and do while it updates
+//!
+//! GameState hands every [`GameSystem`](super::GameSystem) a
+//! [`SystemContext`] for its update. The context lends the planet, ship and
+//! faction managers read-only, so a system can look at the world but not
+//! change it; the only way to change anything is to queue an event, which
+//! the managers apply once the event is routed. Events queued through the
+//! context name the updating system as their source.
+
+use super::events::{EventBus, GameEvent, SystemId};
+use crate::managers::{PlanetManager, ShipManager, FactionManager};
+use crate::systems::FactionStanding;
+
+/// Read access to the managers and write access through events, for one
+/// system's update
+pub struct SystemContext<'a> {
+    source: SystemId,
+    tick: u64,
+    planets: &'a PlanetManager,
+    ships: &'a ShipManager,
+    factions: &'a FactionManager,
+    events: &'a mut EventBus,
+    previous_source: Option<SystemId>,
+}
+
+impl<'a> SystemContext<'a> {
+    /// Context for `source`'s update at `tick`; events queued until it is
+    /// dropped are attributed to `source`
+    pub fn new(
+        source: SystemId,
+        tick: u64,
+        planets: &'a PlanetManager,
+        ships: &'a ShipManager,
+        factions: &'a FactionManager,
+        events: &'a mut EventBus,
+    ) -> Self {
+        let previous_source = events.set_source(Some(source));
+        Self { source, tick, planets, ships, factions, events, previous_source }
+    }
+
+    /// System this context was made for
+    pub fn source(&self) -> SystemId {
+        self.source
+    }
+
+    /// Last completed tick
+    pub fn tick(&self) -> u64 {
+        self.tick
+    }
+
+    /// Every planet, read-only
+    pub fn planets(&self) -> &PlanetManager {
+        self.planets
+    }
+
+    /// Every ship, read-only
+    pub fn ships(&self) -> &ShipManager {
+        self.ships
+    }
+
+    /// Every faction, read-only
+    pub fn factions(&self) -> &FactionManager {
+        self.factions
+    }
+
+    /// Every scoring faction's holdings, by faction id
+    pub fn faction_standings(&self) -> Vec<FactionStanding> {
+        FactionStanding::measure(self.planets, self.ships, self.factions)
+    }
+
+    /// Queue an event for routing after the systems have updated
+    pub fn queue_event(&mut self, event: GameEvent) {
+        self.events.queue_event(event);
+    }
+
+    /// The bus itself, for this crate's systems whose own `update` still
+    /// takes it; they only queue events on it
+    pub(crate) fn event_bus(&mut self) -> &mut EventBus {
+        self.events
+    }
+}
+
+impl Drop for SystemContext<'_> {
+    fn drop(&mut self) {
+        self.events.set_source(self.previous_source);
+    }
+}
@@ -0,0 +1,193 @@
+// src/core/tech.rs
+//! Technology tree: research costs, prerequisites and production bonuses
+//!
+//! Buildings and ship classes that a technology unlocks are listed as
+//! [`UnlockRequirement::Technology`](super::UnlockRequirement) entries in the
+//! [`GameDataRegistry`](super::GameDataRegistry); this tree only holds what
+//! research itself needs.
+
+use super::types::*;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Extra output a technology grants to one resource
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProductionBonus {
+    /// Resource the bonus applies to
+    pub resource: ResourceType,
+    /// Percentage added to the planet's positive output of the resource
+    pub percent: i32,
+}
+
+/// Research data for one technology
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TechnologyInfo {
+    /// Research points needed to unlock it
+    pub cost: i32,
+    /// Technologies that must be known before research can start
+    pub prerequisites: Vec<Technology>,
+    /// Extra output once the technology is known, if any
+    pub production_bonus: Option<ProductionBonus>,
+}
+
+/// Every researchable technology with its cost, prerequisites and bonus.
+///
+/// Holds the compiled-in defaults; callers may replace entries to rebalance a
+/// game without touching the systems that read them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TechnologyTree {
+    technologies: BTreeMap<Technology, TechnologyInfo>,
+}
+
+impl TechnologyTree {
+    /// Tree holding the compiled-in defaults
+    pub fn new() -> Self {
+        let bonus = |resource, percent| Some(ProductionBonus { resource, percent });
+        let mut technologies = BTreeMap::new();
+        technologies.insert(Technology::Military, TechnologyInfo {
+            cost: 100,
+            prerequisites: Vec::new(),
+            production_bonus: None,
+        });
+        technologies.insert(Technology::AdvancedMining, TechnologyInfo {
+            cost: 60,
+            prerequisites: Vec::new(),
+            production_bonus: bonus(ResourceType::Minerals, 25),
+        });
+        technologies.insert(Technology::Hydroponics, TechnologyInfo {
+            cost: 60,
+            prerequisites: Vec::new(),
+            production_bonus: bonus(ResourceType::Food, 25),
+        });
+        technologies.insert(Technology::FusionPower, TechnologyInfo {
+            cost: 80,
+            prerequisites: Vec::new(),
+            production_bonus: bonus(ResourceType::Energy, 25),
+        });
+        technologies.insert(Technology::Automation, TechnologyInfo {
+            cost: 120,
+            prerequisites: vec![Technology::FusionPower],
+            production_bonus: bonus(ResourceType::Alloys, 25),
+        });
+        technologies.insert(Technology::OrbitalDefense, TechnologyInfo {
+            cost: 150,
+            prerequisites: vec![Technology::Military],
+            production_bonus: None,
+        });
+        Self { technologies }
+    }
+
+    /// Research data for a technology, if the tree has it
+    pub fn info(&self, technology: Technology) -> Option<&TechnologyInfo> {
+        self.technologies.get(&technology)
+    }
+
+    /// Replace the research data for a technology
+    pub fn set_info(&mut self, technology: Technology, info: TechnologyInfo) {
+        self.technologies.insert(technology, info);
+    }
+
+    /// Every technology in the tree, in order
+    pub fn technologies(&self) -> impl Iterator<Item = Technology> + '_ {
+        self.technologies.keys().copied()
+    }
+
+    /// Research points needed for a technology
+    pub fn cost(&self, technology: Technology) -> GameResult<i32> {
+        self.info(technology)
+            .map(|info| info.cost)
+            .ok_or_else(|| GameError::InvalidTarget(format!("{:?} is not in the technology tree", technology)))
+    }
+
+    /// Check that the faction may start researching the technology
+    pub fn can_research(&self, faction: &Faction, technology: Technology) -> GameResult<()> {
+        let info = self.info(technology)
+            .ok_or_else(|| GameError::InvalidTarget(format!("{:?} is not in the technology tree", technology)))?;
+        if faction.has_technology(technology) {
+            return Err(GameError::InvalidOperation(format!("{} already knows {:?}", faction.name, technology)));
+        }
+        let missing: Vec<String> = info.prerequisites.iter()
+            .filter(|prerequisite| !faction.has_technology(**prerequisite))
+            .map(|prerequisite| format!("{:?}", prerequisite))
+            .collect();
+        if !missing.is_empty() {
+            return Err(GameError::InvalidOperation(format!(
+                "{:?} requires {} first", technology, missing.join(", ")
+            )));
+        }
+        Ok(())
+    }
+
+    /// Technologies the faction could start researching now
+    pub fn available(&self, faction: &Faction) -> Vec<Technology> {
+        self.technologies()
+            .filter(|technology| self.can_research(faction, *technology).is_ok())
+            .collect()
+    }
+
+    /// Raise the positive amounts in `production` by the bonuses of known technologies
+    pub fn apply_production_bonuses(&self, known: &BTreeSet<Technology>, production: &mut ResourceBundle) {
+        for bonus in known.iter().filter_map(|technology| self.info(*technology)?.production_bonus) {
+            let amount = production.get(bonus.resource);
+            if amount > 0 {
+                production.set(bonus.resource, amount.saturating_add(amount * bonus.percent / 100));
+            }
+        }
+    }
+}
+
+impl Default for TechnologyTree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    fn faction(technologies: &[Technology]) -> Faction {
+        Faction {
+            id: 0,
+            name: "Researchers".to_string(),
+            is_player: true,
+            ai_type: AIPersonality::Balanced,
+            score: 0,
+            inbox: Inbox::default(),
+            technologies: technologies.iter().copied().collect(),
+            research: ResearchState::default(),
+            relations: BTreeMap::new(),
+            explored_planets: BTreeSet::new(),
+            kind: FactionKind::Empire,
+            credits: 0,
+            tax_rate: TaxRate::default(),
+            ship_designs: Vec::new(),
+            control_groups: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_prerequisites_gate_research() {
+        let tree = TechnologyTree::new();
+        let novice = faction(&[]);
+        assert!(tree.can_research(&novice, Technology::Military).is_ok());
+        assert!(tree.can_research(&novice, Technology::OrbitalDefense).is_err());
+        assert!(!tree.available(&novice).contains(&Technology::Automation));
+
+        let veteran = faction(&[Technology::Military, Technology::FusionPower]);
+        assert!(tree.can_research(&veteran, Technology::Military).is_err(), "Known technologies cannot be researched again");
+        let available = tree.available(&veteran);
+        assert!(available.contains(&Technology::OrbitalDefense) && available.contains(&Technology::Automation));
+    }
+
+    #[test]
+    fn test_production_bonuses_only_raise_output() {
+        let tree = TechnologyTree::new();
+        let known: BTreeSet<Technology> = [Technology::AdvancedMining, Technology::FusionPower].into_iter().collect();
+        let mut production = ResourceBundle { minerals: 40, energy: -8, food: 12, ..Default::default() };
+        tree.apply_production_bonuses(&known, &mut production);
+        assert_eq!(production.minerals, 50);
+        assert_eq!(production.energy, -8, "Upkeep is not scaled");
+        assert_eq!(production.food, 12);
+    }
+}
@@ -0,0 +1,1927 @@
+// src/core/types.rs
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::ops::{Add, Sub, AddAssign, SubAssign};
+use serde::{Serialize, Deserialize};
+use super::ship_design::{ShipDesign, DesignId};
+use crate::config::SHIP_BASE_SPEED;
+
+// Core type aliases
+pub type GameResult<T> = Result<T, GameError>;
+pub type PlanetId = u32;
+pub type ShipId = u32;
+pub type FactionId = u8;
+pub type PlayerId = u8;
+/// Identifies a star system
+pub type StarSystemId = u32;
+/// Identifies a missile in flight
+pub type MissileId = u32;
+
+// Error handling
+/// Why an operation failed. [`GameError::code`] gives each kind of failure a
+/// stable code for tools, logs and tests to match on; the `Display` text is
+/// for players and may be reworded.
+#[derive(Debug, Clone)]
+pub enum GameError {
+    /// The operation is not allowed in the current state
+    InvalidOperation(String),
+    /// A planet cannot pay for something
+    InsufficientResources {
+        /// What it costs
+        required: ResourceBundle,
+        /// What the planet holds
+        available: ResourceBundle,
+    },
+    /// The target exists but cannot be used this way
+    InvalidTarget(String),
+    /// No live entity of the kind has the id
+    EntityNotFound {
+        /// What was looked up
+        kind: EntityKind,
+        /// The id that was looked up
+        id: u64,
+    },
+    /// The id names an entity that was removed; its slot has been reused
+    StaleEntity {
+        /// What was looked up
+        kind: EntityKind,
+        /// The id that was looked up
+        id: u64,
+        /// Generation the id's slot is at now
+        generation: u32,
+    },
+    /// More of something was asked for than there is room for
+    CapacityExceeded {
+        /// What ran out, such as "ship slots"
+        resource: String,
+        /// How much was asked for
+        requested: u64,
+        /// How much was left
+        available: u64,
+    },
+    /// A typed command could not be read
+    InvalidCommand {
+        /// The command as given
+        command: String,
+        /// What is wrong with it
+        reason: String,
+    },
+    /// A faction gave orders to a planet, ship or faction it does not control
+    NotOwned {
+        /// The faction giving the orders
+        faction: FactionId,
+        /// What the orders were for
+        kind: EntityKind,
+        /// Its id
+        id: u64,
+    },
+    /// A faction cannot pay the credits something costs
+    InsufficientCredits {
+        /// What it costs
+        required: i32,
+        /// What the faction holds
+        available: i32,
+    },
+    /// The ship's class cannot do what was asked of it
+    UnsuitableShip {
+        /// The ship
+        ship: ShipId,
+        /// Its class
+        class: ShipClass,
+        /// What it was asked to do, such as "carry troops"
+        task: String,
+    },
+    /// The ship has to orbit the planet first
+    NotInOrbit {
+        /// The ship
+        ship: ShipId,
+        /// The planet it has to orbit
+        planet: PlanetId,
+    },
+    /// A number, name or other value given is out of bounds
+    InvalidValue {
+        /// What the value is, such as "troops"
+        what: String,
+        /// What is wrong with it
+        reason: String,
+    },
+    /// A save, replay, scenario or data file could not be understood
+    SaveCorrupted {
+        /// The file, or a description of the source when it is not a file
+        path: String,
+        /// What is wrong with it
+        detail: String,
+    },
+    /// Writing or managing a save, replay or scenario failed
+    SaveError(String),
+    /// Reading or writing a file failed
+    Io(String),
+    /// The connection to another player failed or fell out of step
+    Network(String),
+    /// An internal invariant no longer holds; the game state cannot be trusted
+    SystemError(String),
+}
+
+/// Kind of entity an id names, for [`GameError::EntityNotFound`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EntityKind {
+    /// A planet, by `PlanetId`
+    Planet,
+    /// A ship, by `ShipId`
+    Ship,
+    /// A faction, by `FactionId`
+    Faction,
+    /// A faction's ship design, by `DesignId`
+    ShipDesign,
+    /// A notification, by `NotificationId`
+    Notification,
+    /// An item in a faction's inbox, by `InboxItemId`
+    InboxItem,
+}
+
+impl fmt::Display for EntityKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            EntityKind::Planet => "Planet",
+            EntityKind::Ship => "Ship",
+            EntityKind::Faction => "Faction",
+            EntityKind::ShipDesign => "Ship design",
+            EntityKind::Notification => "Notification",
+            EntityKind::InboxItem => "Inbox item",
+        };
+        f.write_str(name)
+    }
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GameError::InvalidOperation(msg) => write!(f, "Invalid operation: {}", msg),
+            GameError::InsufficientResources { required, available } => {
+                write!(f, "Insufficient resources. Required: {:?}, Available: {:?}", required, available)
+            }
+            GameError::InvalidTarget(msg) => write!(f, "Invalid target: {}", msg),
+            GameError::EntityNotFound { kind, id } => write!(f, "{} {} not found", kind, id),
+            GameError::StaleEntity { kind, id, generation } => write!(
+                f, "{} {} is stale: it was removed and its slot is now at generation {}", kind, id, generation
+            ),
+            GameError::CapacityExceeded { resource, requested, available } => {
+                write!(f, "Not enough {}: {} requested, {} available", resource, requested, available)
+            }
+            GameError::InvalidCommand { command, reason } => write!(f, "Invalid command '{}': {}", command, reason),
+            GameError::NotOwned { faction, kind, id } => write!(f, "Faction {} does not control {} {}", faction, kind, id),
+            GameError::InsufficientCredits { required, available } => {
+                write!(f, "Insufficient credits. Required: {}, Available: {}", required, available)
+            }
+            GameError::UnsuitableShip { ship, class, task } => write!(f, "Ship {} is a {:?} and cannot {}", ship, class, task),
+            GameError::NotInOrbit { ship, planet } => write!(f, "Ship {} must orbit planet {} first", ship, planet),
+            GameError::InvalidValue { what, reason } => write!(f, "Invalid {}: {}", what, reason),
+            GameError::SaveCorrupted { path, detail } => write!(f, "Corrupted file {}: {}", path, detail),
+            GameError::SaveError(msg) => write!(f, "Save error: {}", msg),
+            GameError::Io(msg) => write!(f, "I/O error: {}", msg),
+            GameError::Network(msg) => write!(f, "Network error: {}", msg),
+            GameError::SystemError(msg) => write!(f, "System error: {}", msg),
+        }
+    }
+}
+
+impl GameError {
+    /// No entity of the kind has the id
+    pub fn not_found(kind: EntityKind, id: impl Into<u64>) -> Self {
+        GameError::EntityNotFound { kind, id: id.into() }
+    }
+
+    /// `faction` does not control the entity
+    pub fn not_owned(faction: FactionId, kind: EntityKind, id: impl Into<u64>) -> Self {
+        GameError::NotOwned { faction, kind, id: id.into() }
+    }
+
+    /// The value is out of bounds
+    pub fn invalid_value(what: impl Into<String>, reason: impl Into<String>) -> Self {
+        GameError::InvalidValue { what: what.into(), reason: reason.into() }
+    }
+
+    /// The ship cannot do `task`
+    pub fn unsuitable_ship(ship: &Ship, task: impl Into<String>) -> Self {
+        GameError::UnsuitableShip { ship: ship.id, class: ship.ship_class, task: task.into() }
+    }
+
+    /// There is no room for `requested` more of `resource` where `available` is left
+    pub fn capacity(resource: impl Into<String>, requested: impl TryInto<u64>, available: impl TryInto<u64>) -> Self {
+        GameError::CapacityExceeded {
+            resource: resource.into(),
+            requested: requested.try_into().unwrap_or(0),
+            available: available.try_into().unwrap_or(0),
+        }
+    }
+
+    /// A planet's storage has no room for `resources`, naming the first
+    /// resource that does not fit
+    pub fn storage_full(storage: &ResourceStorage, resources: &ResourceBundle) -> Self {
+        let space = storage.available_space();
+        let resource = ResourceType::ALL.into_iter()
+            .find(|resource| resources.get(*resource) > space.get(*resource))
+            .unwrap_or(ResourceType::Minerals);
+        GameError::capacity(
+            format!("{} storage", format!("{:?}", resource).to_lowercase()),
+            resources.get(resource),
+            space.get(resource),
+        )
+    }
+
+    /// The file at `path` could not be understood
+    pub fn corrupted(path: &std::path::Path, detail: impl Into<String>) -> Self {
+        GameError::SaveCorrupted { path: path.display().to_string(), detail: detail.into() }
+    }
+
+    /// Stable code of the kind of error, such as `"E0104"`. Codes are never
+    /// reused or changed once released.
+    pub fn code(&self) -> &'static str {
+        match self {
+            GameError::InvalidOperation(_) => "E0100",
+            GameError::InsufficientResources { .. } => "E0101",
+            GameError::InvalidTarget(_) => "E0102",
+            GameError::EntityNotFound { .. } => "E0103",
+            GameError::StaleEntity { .. } => "E0104",
+            GameError::CapacityExceeded { .. } => "E0105",
+            GameError::InvalidCommand { .. } => "E0106",
+            GameError::NotOwned { .. } => "E0107",
+            GameError::InsufficientCredits { .. } => "E0108",
+            GameError::UnsuitableShip { .. } => "E0109",
+            GameError::NotInOrbit { .. } => "E0110",
+            GameError::InvalidValue { .. } => "E0111",
+            GameError::SaveCorrupted { .. } => "E0200",
+            GameError::SaveError(_) => "E0201",
+            GameError::Io(_) => "E0202",
+            GameError::Network(_) => "E0300",
+            GameError::SystemError(_) => "E0900",
+        }
+    }
+
+    /// True if the simulation can carry on past the error: a refused
+    /// operation, a missing target or a failed file or connection. A
+    /// `SystemError` means the game state itself can no longer be trusted.
+    pub fn is_recoverable(&self) -> bool {
+        !matches!(self, GameError::SystemError(_))
+    }
+}
+
+impl std::error::Error for GameError {}
+
+impl From<std::io::Error> for GameError {
+    fn from(error: std::io::Error) -> Self {
+        GameError::Io(error.to_string())
+    }
+}
+
+// Resource system
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ResourceBundle {
+    pub minerals: i32,
+    pub food: i32,
+    pub energy: i32,
+    pub alloys: i32,
+    pub components: i32,
+    pub fuel: i32,
+}
+
+impl ResourceBundle {
+    pub fn validate_non_negative(&self) -> GameResult<()> {
+        if self.minerals < 0 || self.food < 0 || self.energy < 0 
+           || self.alloys < 0 || self.components < 0 || self.fuel < 0 {
+            return Err(GameError::invalid_value("resources", "cannot be negative"));
+        }
+        Ok(())
+    }
+    
+    pub fn can_afford(&self, cost: &ResourceBundle) -> bool {
+        self.minerals >= cost.minerals &&
+        self.food >= cost.food &&
+        self.energy >= cost.energy &&
+        self.alloys >= cost.alloys &&
+        self.components >= cost.components &&
+        self.fuel >= cost.fuel
+    }
+    
+    pub fn subtract(&mut self, cost: &ResourceBundle) -> GameResult<()> {
+        if !self.can_afford(cost) {
+            return Err(GameError::InsufficientResources {
+                required: *cost,
+                available: *self,
+            });
+        }
+        self.minerals -= cost.minerals;
+        self.food -= cost.food;
+        self.energy -= cost.energy;
+        self.alloys -= cost.alloys;
+        self.components -= cost.components;
+        self.fuel -= cost.fuel;
+        Ok(())
+    }
+    
+    pub fn add(&mut self, resources: &ResourceBundle) -> GameResult<()> {
+        self.minerals = self.minerals.saturating_add(resources.minerals);
+        self.food = self.food.saturating_add(resources.food);
+        self.energy = self.energy.saturating_add(resources.energy);
+        self.alloys = self.alloys.saturating_add(resources.alloys);
+        self.components = self.components.saturating_add(resources.components);
+        self.fuel = self.fuel.saturating_add(resources.fuel);
+        Ok(())
+    }
+    
+    pub fn total(&self) -> i64 {
+        self.minerals as i64 + self.food as i64 + self.energy as i64 
+        + self.alloys as i64 + self.components as i64 + self.fuel as i64
+    }
+}
+
+impl Add for ResourceBundle {
+    type Output = Self;
+    
+    fn add(self, other: Self) -> Self {
+        Self {
+            minerals: self.minerals + other.minerals,
+            food: self.food + other.food,
+            energy: self.energy + other.energy,
+            alloys: self.alloys + other.alloys,
+            components: self.components + other.components,
+            fuel: self.fuel + other.fuel,
+        }
+    }
+}
+
+impl Sub for ResourceBundle {
+    type Output = Self;
+    
+    fn sub(self, other: Self) -> Self {
+        Self {
+            minerals: self.minerals - other.minerals,
+            food: self.food - other.food,
+            energy: self.energy - other.energy,
+            alloys: self.alloys - other.alloys,
+            components: self.components - other.components,
+            fuel: self.fuel - other.fuel,
+        }
+    }
+}
+
+impl AddAssign for ResourceBundle {
+    fn add_assign(&mut self, other: Self) {
+        self.minerals += other.minerals;
+        self.food += other.food;
+        self.energy += other.energy;
+        self.alloys += other.alloys;
+        self.components += other.components;
+        self.fuel += other.fuel;
+    }
+}
+
+impl SubAssign for ResourceBundle {
+    fn sub_assign(&mut self, other: Self) {
+        self.minerals -= other.minerals;
+        self.food -= other.food;
+        self.energy -= other.energy;
+        self.alloys -= other.alloys;
+        self.components -= other.components;
+        self.fuel -= other.fuel;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum ResourceType {
+    Minerals,
+    Food,
+    Energy,
+    Alloys,
+    Components,
+    Fuel,
+}
+
+impl ResourceType {
+    /// All resource types in canonical order
+    pub const ALL: [ResourceType; 6] = [
+        ResourceType::Minerals,
+        ResourceType::Food,
+        ResourceType::Energy,
+        ResourceType::Alloys,
+        ResourceType::Components,
+        ResourceType::Fuel,
+    ];
+}
+
+impl ResourceBundle {
+    /// Get the amount of a single resource type
+    pub fn get(&self, resource: ResourceType) -> i32 {
+        match resource {
+            ResourceType::Minerals => self.minerals,
+            ResourceType::Food => self.food,
+            ResourceType::Energy => self.energy,
+            ResourceType::Alloys => self.alloys,
+            ResourceType::Components => self.components,
+            ResourceType::Fuel => self.fuel,
+        }
+    }
+    
+    /// Set the amount of a single resource type
+    pub fn set(&mut self, resource: ResourceType, amount: i32) {
+        match resource {
+            ResourceType::Minerals => self.minerals = amount,
+            ResourceType::Food => self.food = amount,
+            ResourceType::Energy => self.energy = amount,
+            ResourceType::Alloys => self.alloys = amount,
+            ResourceType::Components => self.components = amount,
+            ResourceType::Fuel => self.fuel = amount,
+        }
+    }
+}
+
+/// Per-planet storage policy used when production is applied.
+///
+/// `order` lists the resources the planet keeps, highest priority first;
+/// production is stored in this order and resources missing from the list
+/// are discarded. `caps` optionally limits how much of a resource is kept
+/// below the planet's physical capacity.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StoragePriority {
+    /// Resources kept by the planet, highest priority first
+    pub order: Vec<ResourceType>,
+    /// Optional per-resource storage limits
+    pub caps: BTreeMap<ResourceType, i32>,
+}
+
+impl Default for StoragePriority {
+    fn default() -> Self {
+        Self {
+            order: ResourceType::ALL.to_vec(),
+            caps: BTreeMap::new(),
+        }
+    }
+}
+
+impl StoragePriority {
+    /// Reject duplicate entries and negative caps
+    pub fn validate(&self) -> GameResult<()> {
+        for (i, resource) in self.order.iter().enumerate() {
+            if self.order[..i].contains(resource) {
+                return Err(GameError::InvalidOperation(
+                    format!("Duplicate resource {:?} in storage priority", resource)
+                ));
+            }
+        }
+        if let Some((resource, cap)) = self.caps.iter().find(|(_, cap)| **cap < 0) {
+            return Err(GameError::InvalidOperation(
+                format!("Storage cap for {:?} cannot be negative: {}", resource, cap)
+            ));
+        }
+        Ok(())
+    }
+    
+    /// Limit produced resources to what storage and the configured policy allow.
+    /// Resources are applied in priority order so the result is deterministic.
+    pub fn apply(&self, production: &ResourceBundle, storage: &ResourceStorage) -> ResourceBundle {
+        let available = storage.available_space();
+        let mut stored = ResourceBundle::default();
+        for &resource in &self.order {
+            let mut space = available.get(resource).max(0);
+            if let Some(&cap) = self.caps.get(&resource) {
+                space = space.min((cap - storage.current.get(resource)).max(0));
+            }
+            stored.set(resource, production.get(resource).max(0).min(space));
+        }
+        stored
+    }
+    
+    /// The resource's cap, if it has one
+    pub fn cap(&self, resource: ResourceType) -> Option<i32> {
+        self.caps.get(&resource).copied()
+    }
+    
+    /// Cap the resource at `cap`, or lift its cap with None
+    pub fn set_cap(&mut self, resource: ResourceType, cap: Option<i32>) {
+        match cap {
+            Some(cap) => self.caps.insert(resource, cap),
+            None => self.caps.remove(&resource),
+        };
+    }
+    
+    /// Move a resource one step towards the front of the order
+    pub fn raise(&mut self, resource: ResourceType) {
+        if let Some(pos) = self.order.iter().position(|r| *r == resource) {
+            if pos > 0 {
+                self.order.swap(pos, pos - 1);
+            }
+        }
+    }
+}
+
+/// Order in which a planet's energy grid powers its buildings.
+///
+/// During an energy deficit the buildings at the end of the order go
+/// unpowered first; building types missing from the list come after all
+/// listed ones, and buildings of one type are powered in the order they were
+/// built.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PowerPriority {
+    /// Building types powered first to last
+    pub order: Vec<BuildingType>,
+}
+
+impl Default for PowerPriority {
+    fn default() -> Self {
+        Self {
+            order: vec![
+                BuildingType::Habitat,
+                BuildingType::Farm,
+                BuildingType::DefensePlatform,
+                BuildingType::Spaceport,
+                BuildingType::Mine,
+                BuildingType::Factory,
+                BuildingType::ResearchLab,
+            ],
+        }
+    }
+}
+
+impl PowerPriority {
+    /// Reject duplicate entries
+    pub fn validate(&self) -> GameResult<()> {
+        for (i, building_type) in self.order.iter().enumerate() {
+            if self.order[..i].contains(building_type) {
+                return Err(GameError::InvalidOperation(
+                    format!("Duplicate building {:?} in power priority", building_type)
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Position of the building type in the order; unlisted types rank last
+    pub fn rank(&self, building_type: BuildingType) -> usize {
+        self.order.iter().position(|listed| *listed == building_type).unwrap_or(self.order.len())
+    }
+
+    /// Move a building type one step towards the front of the order,
+    /// listing it last if it was missing
+    pub fn raise(&mut self, building_type: BuildingType) {
+        match self.order.iter().position(|listed| *listed == building_type) {
+            Some(pos) if pos > 0 => self.order.swap(pos, pos - 1),
+            Some(_) => {}
+            None => self.order.push(building_type),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ResourceStorage {
+    pub current: ResourceBundle,
+    pub capacity: ResourceBundle,
+}
+
+impl ResourceStorage {
+    pub fn available_space(&self) -> ResourceBundle {
+        ResourceBundle {
+            minerals: self.capacity.minerals - self.current.minerals,
+            food: self.capacity.food - self.current.food,
+            energy: self.capacity.energy - self.current.energy,
+            alloys: self.capacity.alloys - self.current.alloys,
+            components: self.capacity.components - self.current.components,
+            fuel: self.capacity.fuel - self.current.fuel,
+        }
+    }
+    
+    pub fn can_store(&self, resources: &ResourceBundle) -> bool {
+        let space = self.available_space();
+        space.can_afford(resources)
+    }
+    
+    pub fn validate(&self) -> GameResult<()> {
+        self.current.validate_non_negative()?;
+        self.capacity.validate_non_negative()?;
+        
+        if !self.capacity.can_afford(&self.current) {
+            return Err(GameError::InvalidOperation("Current resources exceed capacity".into()));
+        }
+        Ok(())
+    }
+}
+
+// Population system
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Demographics {
+    pub total: i32,
+    pub growth_rate: f32,
+    pub allocation: WorkerAllocation,
+    /// From -1.0 (miserable) to 1.0 (elated); 0.0 is content
+    #[serde(default)]
+    pub happiness: f32,
+}
+
+impl Demographics {
+    /// Growth and production multiplier from the population's happiness
+    pub fn happiness_modifier(&self) -> f32 {
+        happiness_modifier(self.happiness)
+    }
+}
+
+/// Growth and production multiplier for a happiness: unhappy planets fall
+/// to half speed at -1.0, content and happy planets work at full speed
+pub fn happiness_modifier(happiness: f32) -> f32 {
+    1.0 + happiness.clamp(-1.0, 0.0) * 0.5
+}
+
+/// How heavily a planet's controller taxes it. Higher taxes raise the
+/// planet's output and lower its happiness. Factions set one for their
+/// whole empire too, which fills their treasury instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TaxRate {
+    /// Less output, happier population
+    Low,
+    /// The standard rate
+    #[default]
+    Normal,
+    /// More output, unhappier population
+    High,
+}
+
+impl TaxRate {
+    /// Multiplier applied to the planet's production
+    pub fn production_modifier(&self) -> f32 {
+        match self {
+            TaxRate::Low => 0.9,
+            TaxRate::Normal => 1.0,
+            TaxRate::High => 1.15,
+        }
+    }
+
+    /// Shift in the planet's happiness
+    pub fn happiness_modifier(&self) -> f32 {
+        match self {
+            TaxRate::Low => 0.2,
+            TaxRate::Normal => 0.0,
+            TaxRate::High => -0.2,
+        }
+    }
+
+    /// Credits collected each tick per thousand people, before happiness
+    pub fn credits_per_thousand(&self) -> i32 {
+        match self {
+            TaxRate::Low => 5,
+            TaxRate::Normal => 10,
+            TaxRate::High => 15,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WorkerAllocation {
+    pub agriculture: i32,
+    pub mining: i32,
+    pub industry: i32,
+    pub research: i32,
+    pub military: i32,
+    pub unassigned: i32,
+}
+
+impl WorkerAllocation {
+    pub fn validate(&self, total: i32) -> GameResult<()> {
+        if self.agriculture < 0 || self.mining < 0 || self.industry < 0 
+           || self.research < 0 || self.military < 0 || self.unassigned < 0 {
+            return Err(GameError::InvalidOperation("Worker allocations cannot be negative".into()));
+        }
+        
+        let sum = self.agriculture + self.mining + self.industry 
+                + self.research + self.military + self.unassigned;
+        if sum != total {
+            return Err(GameError::InvalidOperation(
+                format!("Worker allocation {} doesn't match total {}", sum, total)
+            ));
+        }
+        Ok(())
+    }
+}
+
+// Buildings and construction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum BuildingType {
+    Mine,
+    Farm,
+    PowerPlant,
+    Factory,
+    ResearchLab,
+    Spaceport,
+    DefensePlatform,
+    StorageFacility,
+    Habitat,
+}
+
+impl BuildingType {
+    /// All building types in declaration order
+    pub const ALL: [BuildingType; 9] = [
+        BuildingType::Mine,
+        BuildingType::Farm,
+        BuildingType::PowerPlant,
+        BuildingType::Factory,
+        BuildingType::ResearchLab,
+        BuildingType::Spaceport,
+        BuildingType::DefensePlatform,
+        BuildingType::StorageFacility,
+        BuildingType::Habitat,
+    ];
+}
+
+/// Why a building is shut down
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ShutdownReason {
+    /// The planet's energy grid had no energy left for it
+    Unpowered,
+    /// The planet could not pay its upkeep
+    UpkeepUnpaid,
+}
+
+/// Whether a building runs, and why not when it does not
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum BuildingStatus {
+    /// Running: it produces, consumes and counts towards capacity
+    #[default]
+    Operational,
+    /// Shut down, doing nothing until it is switched back on
+    Shutdown(ShutdownReason),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Building {
+    pub building_type: BuildingType,
+    pub tier: u8,
+    /// Running or shut down; buildings in saves made before it was saved
+    /// load running and the next tick shuts down any that cannot run
+    #[serde(default)]
+    pub status: BuildingStatus,
+}
+
+impl Building {
+    /// A running building of the given type and tier
+    pub fn new(building_type: BuildingType, tier: u8) -> Self {
+        Self { building_type, tier, status: BuildingStatus::Operational }
+    }
+
+    /// True if the building is running
+    pub fn is_operational(&self) -> bool {
+        self.status == BuildingStatus::Operational
+    }
+
+    /// The reason the building is shut down, if it is
+    pub fn shutdown_reason(&self) -> Option<ShutdownReason> {
+        match self.status {
+            BuildingStatus::Operational => None,
+            BuildingStatus::Shutdown(reason) => Some(reason),
+        }
+    }
+}
+
+/// A planet or ship the player can rename or put in a control group
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum NamedEntity {
+    /// A planet
+    Planet(PlanetId),
+    /// A ship
+    Ship(ShipId),
+}
+
+// Ships
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum ShipClass {
+    Scout,
+    Transport,
+    Warship,
+    Colony,
+}
+
+impl ShipClass {
+    /// All ship classes in canonical order
+    pub const ALL: [ShipClass; 4] = [
+        ShipClass::Scout,
+        ShipClass::Transport,
+        ShipClass::Warship,
+        ShipClass::Colony,
+    ];
+}
+
+/// Distance from a planet within which arriving or idle ships are captured into orbit
+pub const ORBIT_CAPTURE_RANGE: f32 = 0.25;
+/// Radius of the circular parking orbit ships hold around a planet
+pub const PARKING_ORBIT_RADIUS: f32 = 0.1;
+/// Ticks for a ship to complete one lap of its parking orbit
+pub const PARKING_ORBIT_PERIOD_TICKS: u64 = 50;
+/// Radius of a planet's gravity well, which ships route around when the planet is hostile
+pub const GRAVITY_WELL_RADIUS: f32 = 0.5;
+
+/// What a ship is currently doing in space
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ShipStatus {
+    /// Not moving and not in orbit
+    #[default]
+    Idle,
+    /// Following its trajectory
+    InTransit,
+    /// Holding a parking orbit around the planet, moving with it each tick
+    Orbiting(PlanetId),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Ship {
+    pub id: ShipId,
+    pub ship_class: ShipClass,
+    pub position: Vector2,
+    pub trajectory: Option<Trajectory>,
+    pub cargo: CargoHold,
+    pub fuel: f32,
+    pub owner: FactionId,
+    /// What the ship is doing in space
+    #[serde(default)]
+    pub status: ShipStatus,
+    /// Parking orbit angle at tick zero, in radians
+    #[serde(default)]
+    pub orbit_phase: f32,
+    /// Hull points lost in combat and not yet repaired
+    #[serde(default)]
+    pub damage: i32,
+    /// Design the ship was built to; None for the preset classes, whose
+    /// stats come from the game data
+    #[serde(default)]
+    pub design: Option<ShipDesign>,
+    /// Name given by the player; None shows the class and id
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+impl Ship {
+    pub fn validate(&self) -> GameResult<()> {
+        if self.fuel < 0.0 {
+            return Err(GameError::InvalidOperation("Ship fuel cannot be negative".into()));
+        }
+        if self.damage < 0 {
+            return Err(GameError::InvalidOperation("Ship damage cannot be negative".into()));
+        }
+        self.cargo.validate()?;
+        Ok(())
+    }
+    
+    /// The ship's name, or its class and id if it has none
+    pub fn display_name(&self) -> String {
+        self.name.clone().unwrap_or_else(|| format!("{:?} {}", self.ship_class, self.id))
+    }
+    
+    /// Distance the ship covers per tick in transit
+    pub fn speed(&self) -> f32 {
+        self.design.as_ref().map_or(SHIP_BASE_SPEED, |design| design.stats().speed)
+    }
+    
+    /// Planet this ship is parked around, if any
+    pub fn orbiting_planet(&self) -> Option<PlanetId> {
+        match self.status {
+            ShipStatus::Orbiting(planet) => Some(planet),
+            _ => None,
+        }
+    }
+}
+
+/// Movement of one ship over a single tick, computed by the PhysicsEngine
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShipMotion {
+    /// Position at the end of the tick
+    pub position: Vector2,
+    /// Status at the end of the tick
+    pub status: ShipStatus,
+    /// Parking orbit angle at tick zero, in radians
+    pub orbit_phase: f32,
+    /// Remaining trajectory; cleared once the ship arrives
+    pub trajectory: Option<Trajectory>,
+    /// Planet that captured the ship this tick
+    pub entered_orbit: Option<PlanetId>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CargoHold {
+    pub resources: ResourceBundle,
+    pub population: i32,
+    pub capacity: i32,
+}
+
+impl CargoHold {
+    pub fn current_load(&self) -> i32 {
+        self.resources.total() as i32 + self.population
+    }
+    
+    pub fn available_space(&self) -> i32 {
+        self.capacity - self.current_load()
+    }
+    
+    pub fn can_load(&self, additional_resources: &ResourceBundle, additional_population: i32) -> bool {
+        let additional_load = additional_resources.total() as i32 + additional_population;
+        self.available_space() >= additional_load
+    }
+    
+    pub fn validate(&self) -> GameResult<()> {
+        if self.capacity < 0 {
+            return Err(GameError::InvalidOperation("Cargo capacity cannot be negative".into()));
+        }
+        if self.population < 0 {
+            return Err(GameError::InvalidOperation("Population cannot be negative".into()));
+        }
+        if self.current_load() > self.capacity {
+            return Err(GameError::InvalidOperation("Cargo exceeds capacity".into()));
+        }
+        Ok(())
+    }
+}
+
+// Physics
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Vector2 {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl Default for Vector2 {
+    fn default() -> Self {
+        Self { x: 0.0, y: 0.0 }
+    }
+}
+
+impl Vector2 {
+    pub fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+    
+    pub fn distance_to(&self, other: &Vector2) -> f32 {
+        let dx = self.x - other.x;
+        let dy = self.y - other.y;
+        (dx * dx + dy * dy).sqrt()
+    }
+    
+    pub fn magnitude(&self) -> f32 {
+        (self.x * self.x + self.y * self.y).sqrt()
+    }
+    
+    pub fn normalize(&self) -> Vector2 {
+        let mag = self.magnitude();
+        if mag == 0.0 {
+            *self
+        } else {
+            Vector2 { x: self.x / mag, y: self.y / mag }
+        }
+    }
+    
+    pub fn dot(&self, other: &Vector2) -> f32 {
+        self.x * other.x + self.y * other.y
+    }
+}
+
+/// Newton iterations used to solve Kepler's equation; plenty for eccentricities below 0.9
+const KEPLER_ITERATIONS: usize = 8;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct OrbitalElements {
+    /// Half the longest diameter of the orbit, in AU
+    pub semi_major_axis: f32,
+    /// Ticks for one orbit
+    pub period: f32,
+    /// Mean anomaly at tick 0, in radians
+    pub phase: f32,
+    /// 0 for a circle, approaching 1 for a long thin ellipse
+    #[serde(default)]
+    pub eccentricity: f32,
+    /// Angle of the closest approach to the star, in radians
+    #[serde(default)]
+    pub argument_of_periapsis: f32,
+}
+
+impl OrbitalElements {
+    /// Check the elements describe a closed orbit
+    pub fn validate(&self) -> GameResult<()> {
+        if !(self.period > 0.0 && self.period.is_finite()) {
+            return Err(GameError::InvalidOperation("Orbital period must be positive".into()));
+        }
+        if !(self.semi_major_axis >= 0.0 && self.semi_major_axis.is_finite()) {
+            return Err(GameError::InvalidOperation("Semi-major axis cannot be negative".into()));
+        }
+        if !(0.0..1.0).contains(&self.eccentricity) {
+            return Err(GameError::InvalidOperation("Eccentricity must be in [0, 1)".into()));
+        }
+        if !self.phase.is_finite() || !self.argument_of_periapsis.is_finite() {
+            return Err(GameError::InvalidOperation("Orbital angles must be finite".into()));
+        }
+        Ok(())
+    }
+
+    /// Position around the star at the given tick.
+    ///
+    /// Solves Kepler's equation for the eccentric anomaly, so a body moves
+    /// fastest at periapsis. Circular orbits reduce to a constant angular speed.
+    pub fn position_at(&self, tick: u64) -> Vector2 {
+        let mean_anomaly = self.phase + 2.0 * std::f32::consts::PI * (tick as f32 / self.period);
+        let e = self.eccentricity;
+        let mut eccentric_anomaly = mean_anomaly;
+        for _ in 0..KEPLER_ITERATIONS {
+            eccentric_anomaly -= (eccentric_anomaly - e * eccentric_anomaly.sin() - mean_anomaly)
+                / (1.0 - e * eccentric_anomaly.cos());
+        }
+
+        // Position in the orbital plane with periapsis along +x, then rotated into place
+        let x = self.semi_major_axis * (eccentric_anomaly.cos() - e);
+        let y = self.semi_major_axis * (1.0 - e * e).sqrt() * eccentric_anomaly.sin();
+        let (sin_w, cos_w) = self.argument_of_periapsis.sin_cos();
+        Vector2 {
+            x: x * cos_w - y * sin_w,
+            y: x * sin_w + y * cos_w,
+        }
+    }
+}
+
+impl Default for OrbitalElements {
+    fn default() -> Self {
+        Self {
+            semi_major_axis: 5.0,
+            period: 365.0,
+            phase: 0.0,
+            eccentricity: 0.0,
+            argument_of_periapsis: 0.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Trajectory {
+    pub origin: Vector2,
+    pub destination: Vector2,
+    pub departure_time: u64,
+    pub arrival_time: u64,
+    pub fuel_cost: f32,
+    /// Points still to pass, in order, before heading for `destination`
+    #[serde(default)]
+    pub waypoints: Vec<Vector2>,
+    /// Jump lanes still to cross, in order; each is entered at one of the waypoints
+    #[serde(default)]
+    pub jumps: Vec<LaneJump>,
+}
+
+/// A jump lane crossing on a ship's trajectory
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LaneJump {
+    /// Jump point the ship enters the lane at
+    pub entry: Vector2,
+    /// Jump point at the far end, where the ship comes out
+    pub exit: Vector2,
+    /// Ticks left in the lane once the ship has entered it
+    pub ticks: u64,
+}
+
+impl Trajectory {
+    /// Point the ship is currently heading for
+    pub fn next_point(&self) -> Vector2 {
+        self.waypoints.first().copied().unwrap_or(self.destination)
+    }
+}
+
+// Planets
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Planet {
+    pub id: PlanetId,
+    pub position: OrbitalElements,
+    pub resources: ResourceStorage,
+    pub population: Demographics,
+    pub developments: Vec<Building>,
+    pub controller: Option<FactionId>,
+    /// Which produced resources the planet keeps, in what order, and their caps
+    #[serde(default)]
+    pub storage_priority: StoragePriority,
+    /// Order the energy grid powers buildings in during a deficit
+    #[serde(default)]
+    pub power_priority: PowerPriority,
+    /// Planet size, which sets its housing and building slots
+    #[serde(default = "default_planet_size")]
+    pub size: u8,
+    /// How heavily the controller taxes the planet
+    #[serde(default)]
+    pub tax_rate: TaxRate,
+    /// Environment that scales production and housing; neutral in saves
+    /// made before planets had traits
+    #[serde(default)]
+    pub traits: PlanetTraits,
+    /// Star system the planet orbits; the only star in saves made before
+    /// there were several
+    #[serde(default)]
+    pub system: StarSystemId,
+    /// Name from the galaxy generator or the player; None for planets
+    /// created without one
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+/// Lowest value of a planet trait
+pub const MIN_PLANET_TRAIT: f32 = 0.5;
+/// Highest value of a planet trait
+pub const MAX_PLANET_TRAIT: f32 = 1.5;
+
+/// A planet's environment, as multipliers where 1.0 is an ordinary world
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PlanetTraits {
+    /// Scales mineral production
+    pub mineral_richness: f32,
+    /// Scales food production
+    pub fertility: f32,
+    /// Scales the housing the planet's size provides, and population growth
+    pub habitability: f32,
+}
+
+impl PlanetTraits {
+    /// Reject traits outside [`MIN_PLANET_TRAIT`] to [`MAX_PLANET_TRAIT`]
+    pub fn validate(&self) -> GameResult<()> {
+        for (name, value) in [("mineral richness", self.mineral_richness), ("fertility", self.fertility), ("habitability", self.habitability)] {
+            if !(MIN_PLANET_TRAIT..=MAX_PLANET_TRAIT).contains(&value) {
+                return Err(GameError::InvalidOperation(format!(
+                    "Planet {} {} is outside {} to {}", name, value, MIN_PLANET_TRAIT, MAX_PLANET_TRAIT
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Multiply mineral and food production by the richness and fertility
+    pub fn apply_to_production(&self, production: &mut ResourceBundle) {
+        for (resource, multiplier) in [(ResourceType::Minerals, self.mineral_richness), (ResourceType::Food, self.fertility)] {
+            let amount = production.get(resource);
+            if amount > 0 && multiplier != 1.0 {
+                production.set(resource, (amount as f32 * multiplier).floor() as i32);
+            }
+        }
+    }
+}
+
+impl Default for PlanetTraits {
+    fn default() -> Self {
+        Self { mineral_richness: 1.0, fertility: 1.0, habitability: 1.0 }
+    }
+}
+
+/// Default planet size for planets created without an explicit size
+pub const DEFAULT_PLANET_SIZE: u8 = 3;
+/// Housing provided per point of planet size
+pub const HOUSING_PER_PLANET_SIZE: i32 = 2000;
+/// Housing provided per tier of an operational Habitat
+pub const HOUSING_PER_HABITAT_TIER: i32 = 5000;
+
+/// Storage every planet has without StorageFacilities
+pub const BASE_STORAGE_CAPACITY: ResourceBundle = ResourceBundle {
+    minerals: 10000,
+    food: 5000,
+    energy: 1000,
+    alloys: 1000,
+    components: 500,
+    fuel: 2000,
+};
+/// Storage added per tier of an operational StorageFacility
+pub const STORAGE_PER_FACILITY_TIER: ResourceBundle = ResourceBundle {
+    minerals: 5000,
+    food: 2500,
+    energy: 500,
+    alloys: 500,
+    components: 250,
+    fuel: 1000,
+};
+
+/// Where a planet's storage capacity comes from
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StorageBreakdown {
+    /// `BASE_STORAGE_CAPACITY`
+    pub base: ResourceBundle,
+    /// Tiers of the planet's operational StorageFacilities, added up
+    pub facility_tiers: i32,
+    /// Storage those tiers add
+    pub facilities: ResourceBundle,
+}
+
+impl StorageBreakdown {
+    /// Capacity from every source
+    pub fn total(&self) -> ResourceBundle {
+        let mut total = self.base;
+        for resource in ResourceType::ALL {
+            total.set(resource, self.base.get(resource).saturating_add(self.facilities.get(resource)));
+        }
+        total
+    }
+}
+
+fn default_planet_size() -> u8 {
+    DEFAULT_PLANET_SIZE
+}
+
+impl Planet {
+    /// The planet's name, or its id if it has none
+    pub fn display_name(&self) -> String {
+        self.name.clone().unwrap_or_else(|| format!("Planet {}", self.id))
+    }
+
+    /// Maximum population the planet can house, from its size scaled by its
+    /// habitability, and its operational Habitats
+    pub fn housing_capacity(&self) -> i32 {
+        let habitat_housing: i32 = self.developments.iter()
+            .filter(|b| b.building_type == BuildingType::Habitat && b.is_operational())
+            .map(|b| b.tier as i32 * HOUSING_PER_HABITAT_TIER)
+            .sum();
+        let natural_housing = (self.size as i32 * HOUSING_PER_PLANET_SIZE) as f32 * self.traits.habitability;
+        (natural_housing.round() as i32).saturating_add(habitat_housing)
+    }
+
+    /// Storage capacity from the base and the operational StorageFacilities
+    pub fn storage_breakdown(&self) -> StorageBreakdown {
+        let facility_tiers: i32 = self.developments.iter()
+            .filter(|b| b.building_type == BuildingType::StorageFacility && b.is_operational())
+            .map(|b| b.tier as i32)
+            .sum();
+        let mut facilities = ResourceBundle::default();
+        for resource in ResourceType::ALL {
+            facilities.set(resource, STORAGE_PER_FACILITY_TIER.get(resource).saturating_mul(facility_tiers));
+        }
+        StorageBreakdown { base: BASE_STORAGE_CAPACITY, facility_tiers, facilities }
+    }
+
+    /// Resource capacity the planet's buildings give it; PlanetManager keeps
+    /// `resources.capacity` equal to it
+    pub fn storage_capacity(&self) -> ResourceBundle {
+        self.storage_breakdown().total()
+    }
+
+    /// Buildings the planet has room for: ten, one more per 10,000
+    /// population, and one more or fewer per size above or below the default
+    pub fn building_slots(&self) -> usize {
+        (10 + self.population.total / 10000 + self.size as i32 - DEFAULT_PLANET_SIZE as i32).max(1) as usize
+    }
+    
+    /// Remaining housing before the planet reaches its cap (zero when overcrowded)
+    pub fn housing_space(&self) -> i32 {
+        (self.housing_capacity() - self.population.total).max(0)
+    }
+    
+    /// Output multiplier from the planet's happiness and tax rate
+    pub fn productivity(&self) -> f32 {
+        self.population.happiness_modifier() * self.tax_rate.production_modifier()
+    }
+    
+    /// Fraction of population living above the housing cap (0.0 when housed)
+    pub fn overcrowding(&self) -> f32 {
+        let capacity = self.housing_capacity();
+        if capacity <= 0 {
+            return if self.population.total > 0 { 1.0 } else { 0.0 };
+        }
+        (self.population.total - capacity).max(0) as f32 / capacity as f32
+    }
+}
+
+/// A standing order for a transport to shuttle cargo between two planets
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TradeRoute {
+    /// Transport running the route
+    pub ship: ShipId,
+    /// Planet the cargo is loaded at
+    pub from: PlanetId,
+    /// Planet the cargo is delivered to
+    pub to: PlanetId,
+    pub resources: ResourceBundle,
+}
+
+/// Credits every new faction starts with
+pub const STARTING_CREDITS: i32 = 1000;
+
+/// The part a faction plays in the game
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum FactionKind {
+    /// An empire run by the player or by the AI for its personality; it
+    /// counts towards victory, statistics and objectives
+    #[default]
+    Empire,
+    /// The built-in raiders run by the PirateSystem. They never score, win
+    /// or lose, take no part in diplomacy and are at war with everyone.
+    Pirates,
+}
+
+// Factions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Faction {
+    pub id: FactionId,
+    pub name: String,
+    pub is_player: bool,
+    pub ai_type: AIPersonality,
+    pub score: i32,
+    /// Offers and prompts waiting for this faction to respond
+    #[serde(default)]
+    pub inbox: Inbox,
+    /// Technologies this faction has unlocked
+    #[serde(default)]
+    pub technologies: BTreeSet<Technology>,
+    /// Research towards technologies not yet unlocked
+    #[serde(default)]
+    pub research: ResearchState,
+    /// Standing with every faction this one has dealt with
+    #[serde(default)]
+    pub relations: BTreeMap<FactionId, Relation>,
+    /// Planets this faction's sensors have ever seen
+    #[serde(default)]
+    pub explored_planets: BTreeSet<PlanetId>,
+    /// Treasury: taxes come in and upkeep goes out every tick, and the
+    /// galactic market trades for it
+    #[serde(default)]
+    pub credits: i32,
+    /// Empire-wide tax filling the treasury every tick; it also shifts the
+    /// happiness of every planet the faction holds
+    #[serde(default)]
+    pub tax_rate: TaxRate,
+    /// Empire or system faction; older saves only have empires
+    #[serde(default)]
+    pub kind: FactionKind,
+    /// Ship designs the faction can build besides the preset classes
+    #[serde(default)]
+    pub ship_designs: Vec<ShipDesign>,
+    /// Planets and ships kept in numbered groups for quick selection
+    #[serde(default)]
+    pub control_groups: BTreeMap<u8, Vec<NamedEntity>>,
+}
+
+impl Faction {
+    /// Members of a control group, empty if it was never assigned
+    pub fn control_group(&self, group: u8) -> &[NamedEntity] {
+        self.control_groups.get(&group).map_or(&[], Vec::as_slice)
+    }
+    
+    /// One of the faction's ship designs
+    pub fn ship_design(&self, id: DesignId) -> Option<&ShipDesign> {
+        self.ship_designs.iter().find(|design| design.id == id)
+    }
+
+    /// Standing with another faction; neutral if they have never dealt
+    pub fn relation(&self, other: FactionId) -> Relation {
+        self.relations.get(&other).copied().unwrap_or_default()
+    }
+
+    /// True for empires, which count towards victory and statistics;
+    /// system factions such as the pirates do not
+    pub fn is_scoring(&self) -> bool {
+        self.kind == FactionKind::Empire
+    }
+
+    /// True while a declared war with the other faction stands
+    pub fn is_at_war_with(&self, other: FactionId) -> bool {
+        self.relation(other).at_war
+    }
+
+    /// True when the faction has unlocked the technology
+    pub fn has_technology(&self, technology: Technology) -> bool {
+        self.technologies.contains(&technology)
+    }
+
+    /// True once the faction's sensors have seen the planet
+    pub fn has_explored(&self, planet: PlanetId) -> bool {
+        self.explored_planets.contains(&planet)
+    }
+}
+
+/// Planets and ships a faction's sensors see right now; recomputed every
+/// tick, unlike [`Faction::explored_planets`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FactionVisibility {
+    /// Planets in sensor range
+    pub planets: BTreeSet<PlanetId>,
+    /// Ships in sensor range
+    pub ships: BTreeSet<ShipId>,
+}
+
+impl FactionVisibility {
+    /// True if the planet is in sensor range
+    pub fn can_see_planet(&self, planet: PlanetId) -> bool {
+        self.planets.contains(&planet)
+    }
+
+    /// True if the ship is in sensor range
+    pub fn can_see_ship(&self, ship: ShipId) -> bool {
+        self.ships.contains(&ship)
+    }
+}
+
+// Technology
+/// Research that unlocks ships, buildings and bonuses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Technology {
+    /// Weapons and hull plating for combat ships
+    Military,
+    /// Deeper shafts and better ore refining
+    AdvancedMining,
+    /// Soil-free farming in controlled habitats
+    Hydroponics,
+    /// Compact fusion reactors
+    FusionPower,
+    /// Self-running production lines
+    Automation,
+    /// Armed stations in planetary orbit
+    OrbitalDefense,
+}
+
+/// Research a faction is working towards
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResearchState {
+    /// Technology that new research points go to
+    pub target: Option<Technology>,
+    /// Points put into technologies not yet unlocked; kept when the target changes
+    pub progress: BTreeMap<Technology, i32>,
+}
+
+impl ResearchState {
+    /// Points put into a technology so far
+    pub fn progress_of(&self, technology: Technology) -> i32 {
+        self.progress.get(&technology).copied().unwrap_or(0)
+    }
+}
+
+// Faction inbox
+/// Identifies an item in a faction's inbox
+pub type InboxItemId = u32;
+/// Identifies an entry in a faction's notification log
+pub type NotificationId = u64;
+
+/// Ticks a diplomatic proposal waits for an answer before it lapses
+pub const DIPLOMATIC_PROPOSAL_LIFETIME_TICKS: u64 = 600;
+/// Ticks a trade offer waits for an answer before it lapses
+pub const TRADE_OFFER_LIFETIME_TICKS: u64 = 300;
+
+/// A treaty one faction can offer another
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DiplomaticProposal {
+    /// Ends a war
+    Peace,
+    /// Neither side may attack the other
+    NonAggressionPact,
+    /// The closest treaty; neither side may attack the other
+    Alliance,
+}
+
+// Diplomacy
+/// Lowest relation score; the worst possible standing
+pub const MIN_RELATION_SCORE: i32 = -100;
+/// Highest relation score; the best possible standing
+pub const MAX_RELATION_SCORE: i32 = 100;
+/// Ticks a signed treaty lasts before it lapses
+pub const TREATY_DURATION_TICKS: u64 = 6000;
+
+/// A signed agreement between two factions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Treaty {
+    /// Kind of treaty
+    pub kind: DiplomaticProposal,
+    /// Tick it was signed
+    pub signed_tick: u64,
+    /// Tick it lapses
+    pub expires_tick: u64,
+}
+
+/// One faction's standing with another
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct Relation {
+    /// Goodwill from MIN_RELATION_SCORE to MAX_RELATION_SCORE
+    pub score: i32,
+    /// Set by a declaration of war, cleared when a treaty is signed
+    pub at_war: bool,
+    /// Treaty in force, if any; a treaty and a war never coexist
+    pub treaty: Option<Treaty>,
+}
+
+impl Relation {
+    /// Shift the score, staying within the relation score range
+    pub fn adjust_score(&mut self, delta: i32) {
+        self.score = self.score.saturating_add(delta).clamp(MIN_RELATION_SCORE, MAX_RELATION_SCORE);
+    }
+
+    /// Attacks are refused while a treaty is in force; war must be declared first
+    pub fn allows_attack(&self) -> bool {
+        self.treaty.is_none()
+    }
+}
+
+/// An interactive event that waits in a faction's inbox for a response
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum InboxMessage {
+    /// Another faction offers a treaty
+    DiplomaticProposal {
+        /// Faction offering it
+        from: FactionId,
+        /// Treaty offered
+        proposal: DiplomaticProposal,
+    },
+    /// Another faction offers to swap resources
+    TradeOffer {
+        /// Faction making the offer
+        from: FactionId,
+        /// Resources it gives
+        offered: ResourceBundle,
+        /// Resources it wants in return
+        requested: ResourceBundle,
+    },
+    /// A tutorial step to read and acknowledge
+    TutorialPrompt {
+        /// Heading
+        title: String,
+        /// Body text
+        text: String,
+    },
+}
+
+impl InboxMessage {
+    /// Ticks the message stays open when no explicit expiry is given (None never expires)
+    pub fn default_lifetime(&self) -> Option<u64> {
+        match self {
+            InboxMessage::DiplomaticProposal { .. } => Some(DIPLOMATIC_PROPOSAL_LIFETIME_TICKS),
+            InboxMessage::TradeOffer { .. } => Some(TRADE_OFFER_LIFETIME_TICKS),
+            InboxMessage::TutorialPrompt { .. } => None,
+        }
+    }
+
+    /// Faction that sent the message, if any
+    pub fn sender(&self) -> Option<FactionId> {
+        match self {
+            InboxMessage::DiplomaticProposal { from, .. } | InboxMessage::TradeOffer { from, .. } => Some(*from),
+            InboxMessage::TutorialPrompt { .. } => None,
+        }
+    }
+}
+
+/// A faction's answer to an inbox item
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InboxResponse {
+    /// Agree to the proposal or offer
+    Accept,
+    /// Turn it down
+    Decline,
+}
+
+/// A message waiting in an inbox, with its id and timing
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InboxItem {
+    /// Id used to answer it
+    pub id: InboxItemId,
+    /// The message
+    pub message: InboxMessage,
+    /// Tick it arrived
+    pub received_tick: u64,
+    /// Tick at which the item lapses unanswered
+    pub expires_tick: Option<u64>,
+}
+
+impl InboxItem {
+    /// True once the item has lapsed at `tick`
+    pub fn is_expired(&self, tick: u64) -> bool {
+        self.expires_tick.is_some_and(|expires| tick >= expires)
+    }
+}
+
+/// Pending interactive events for one faction, oldest first
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Inbox {
+    items: Vec<InboxItem>,
+    next_id: InboxItemId,
+}
+
+impl Inbox {
+    /// Add a message using its default lifetime
+    pub fn deliver(&mut self, message: InboxMessage, tick: u64) -> InboxItemId {
+        let expires_tick = message.default_lifetime().map(|lifetime| tick + lifetime);
+        self.deliver_with_expiry(message, tick, expires_tick)
+    }
+
+    /// Add a message that lapses at `expires_tick` (None never expires)
+    pub fn deliver_with_expiry(&mut self, message: InboxMessage, tick: u64, expires_tick: Option<u64>) -> InboxItemId {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.items.push(InboxItem {
+            id,
+            message,
+            received_tick: tick,
+            expires_tick,
+        });
+        id
+    }
+
+    /// Items waiting, oldest first
+    pub fn items(&self) -> &[InboxItem] {
+        &self.items
+    }
+
+    /// The item with this id, if it is still waiting
+    pub fn get(&self, id: InboxItemId) -> Option<&InboxItem> {
+        self.items.iter().find(|item| item.id == id)
+    }
+
+    /// Number of items waiting for a response, shown as the inbox badge
+    pub fn pending_count(&self) -> usize {
+        self.items.len()
+    }
+
+    /// True when nothing is waiting
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Remove an item so it can be answered
+    pub fn take(&mut self, id: InboxItemId) -> Option<InboxItem> {
+        let index = self.items.iter().position(|item| item.id == id)?;
+        Some(self.items.remove(index))
+    }
+
+    /// Remove and return every item that has lapsed by `tick`
+    pub fn expire(&mut self, tick: u64) -> Vec<InboxItem> {
+        let (expired, pending) = std::mem::take(&mut self.items)
+            .into_iter()
+            .partition(|item| item.is_expired(tick));
+        self.items = pending;
+        expired
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum AIPersonality {
+    Aggressive,
+    Balanced,
+    Economic,
+}
+
+// Combat
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CombatOutcome {
+    pub winner: FactionId,
+    pub attacker_losses: Vec<ShipId>,
+    pub defender_losses: Vec<ShipId>,
+    /// Ships that broke off and fled toward a friendly planet
+    pub retreated: Vec<ShipId>,
+    /// Rounds fought, one per tick
+    pub rounds: u32,
+    /// Hull damage the attacking side took over the whole battle
+    pub attacker_damage: i32,
+    /// Hull damage the defending side took over the whole battle
+    pub defender_damage: i32,
+}
+
+/// What one tick of orbital bombardment struck
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BombardmentStrike {
+    /// One of the planet's buildings: the one at `pick` modulo their count
+    Building {
+        /// Chooses the building
+        pick: u32,
+    },
+    /// The population, military workers first
+    Population {
+        /// People killed
+        casualties: i32,
+    },
+}
+
+// Victory conditions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VictoryType {
+    Economic,
+    Population,
+    Military,
+    Timeout,
+    /// Every other faction was eliminated
+    Conquest,
+}
+
+// Game modes for menu/game state management
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GameMode {
+    MainMenu,
+    InGame,
+}
+
+// Game initialization configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameConfiguration {
+    pub planet_count: usize,
+    pub starting_resources: ResourceBundle,
+    pub starting_population: i32,
+    pub galaxy_size: GalaxySize,
+    pub ai_opponents: usize,
+    /// Sets starting resources and the spacing between home planets
+    #[serde(default)]
+    pub difficulty: Difficulty,
+    /// Seed for the starting layout generator; re-rolling changes it
+    #[serde(default)]
+    pub seed: u64,
+    /// Placement of neutral planets beyond the home systems
+    #[serde(default)]
+    pub distribution: PlanetDistribution,
+    /// When and where the game saves itself
+    #[serde(default)]
+    pub autosave: AutosaveSettings,
+    /// Give the player the tutorial objectives in a new game
+    #[serde(default)]
+    pub tutorial: bool,
+    /// Add the pirate faction to a new game; off for configurations saved
+    /// before pirates existed
+    #[serde(default)]
+    pub pirates: bool,
+    /// Star systems in a new game, linked by jump lanes; one in
+    /// configurations saved before there were several
+    #[serde(default = "default_star_systems")]
+    pub star_systems: usize,
+    /// Warships fire missiles in ship battles as well as their guns
+    #[serde(default)]
+    pub missiles: bool,
+}
+
+fn default_star_systems() -> usize {
+    1
+}
+
+/// Automatic saving to rotating `autosave_N` slots
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AutosaveSettings {
+    /// Ticks between autosaves; 0 turns autosaving off
+    pub interval_ticks: u64,
+    /// Number of slots to rotate through, at least one
+    pub slots: usize,
+    /// Skip an autosave that falls due while the game is paused
+    pub skip_while_paused: bool,
+}
+
+impl AutosaveSettings {
+    /// Autosaving turned off
+    pub fn disabled() -> Self {
+        Self { interval_ticks: 0, ..Self::default() }
+    }
+    
+    /// True unless autosaving is turned off
+    pub fn is_enabled(&self) -> bool {
+        self.interval_ticks > 0
+    }
+}
+
+impl Default for AutosaveSettings {
+    fn default() -> Self {
+        Self {
+            interval_ticks: crate::config::DEFAULT_AUTOSAVE_INTERVAL_TICKS,
+            slots: crate::config::DEFAULT_AUTOSAVE_SLOTS,
+            skip_while_paused: true,
+        }
+    }
+}
+
+/// How generous the starting layout is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Difficulty {
+    /// More starting resources and home planets further apart
+    Easy,
+    /// The standard start
+    #[default]
+    Normal,
+    /// Fewer starting resources and home planets closer together
+    Hard,
+}
+
+impl Difficulty {
+    /// Multiplier applied to every faction's starting resources
+    pub fn resource_multiplier(&self) -> f32 {
+        match self {
+            Difficulty::Easy => 1.5,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 0.7,
+        }
+    }
+    
+    /// Multiplier applied to the minimum distance between home planets
+    pub fn spacing_multiplier(&self) -> f32 {
+        match self {
+            Difficulty::Easy => 1.25,
+            Difficulty::Normal => 1.0,
+            Difficulty::Hard => 0.8,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum GalaxySize {
+    Small,   // 5-10 planets
+    Medium,  // 10-20 planets  
+    Large,   // 20-50 planets
+}
+
+impl Default for GameConfiguration {
+    fn default() -> Self {
+        Self {
+            planet_count: 3,
+            starting_resources: ResourceBundle {
+                minerals: 500,
+                food: 300,
+                energy: 200,
+                alloys: 50,
+                components: 25,
+                fuel: 100,
+            },
+            starting_population: 1000,
+            galaxy_size: GalaxySize::Small,
+            ai_opponents: 1,
+            difficulty: Difficulty::Normal,
+            seed: 0,
+            distribution: PlanetDistribution::Uniform,
+            autosave: AutosaveSettings::default(),
+            tutorial: false,
+            pirates: true,
+            star_systems: 1,
+            missiles: false,
+        }
+    }
+}
+
+impl GameConfiguration {
+    /// Preset planet count, starting stock and opponents for a galaxy size
+    pub fn for_galaxy_size(galaxy_size: GalaxySize) -> Self {
+        match galaxy_size {
+            GalaxySize::Small => GameConfiguration {
+                planet_count: 5,
+                starting_resources: ResourceBundle {
+                    minerals: 300,
+                    food: 200,
+                    energy: 150,
+                    alloys: 30,
+                    components: 15,
+                    fuel: 75,
+                },
+                starting_population: 750,
+                galaxy_size,
+                ai_opponents: 1,
+                ..Default::default()
+            },
+            GalaxySize::Medium => GameConfiguration {
+                planet_count: 8,
+                starting_resources: ResourceBundle {
+                    minerals: 500,
+                    food: 300,
+                    energy: 200,
+                    alloys: 50,
+                    components: 25,
+                    fuel: 100,
+                },
+                starting_population: 1000,
+                galaxy_size,
+                ai_opponents: 2,
+                ..Default::default()
+            },
+            GalaxySize::Large => GameConfiguration {
+                planet_count: 12,
+                starting_resources: ResourceBundle {
+                    minerals: 800,
+                    food: 500,
+                    energy: 300,
+                    alloys: 80,
+                    components: 40,
+                    fuel: 150,
+                },
+                starting_population: 1500,
+                galaxy_size,
+                ai_opponents: 3,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl GalaxySize {
+    /// Every galaxy size, smallest first
+    pub const ALL: [GalaxySize; 3] = [GalaxySize::Small, GalaxySize::Medium, GalaxySize::Large];
+
+    pub fn planet_range(&self) -> (usize, usize) {
+        match self {
+            GalaxySize::Small => (5, 10),
+            GalaxySize::Medium => (10, 20),
+            GalaxySize::Large => (20, 50),
+        }
+    }
+}
+/// How neutral planets are scattered across the galaxy
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum PlanetDistribution {
+    /// Evenly spread between the inner and outer orbit
+    #[default]
+    Uniform,
+    /// Grouped around a number of cluster centres
+    Clustered {
+        /// Number of clusters
+        clusters: u32,
+        /// Largest distance of a planet from its cluster centre
+        spread: f32,
+    },
+    /// Strung along spiral arms that wind outwards
+    Spiral {
+        /// Number of arms
+        arms: u32,
+        /// Radians an arm turns between the inner and outer orbit
+        twist: f32,
+    },
+}
@@ -0,0 +1,730 @@
+#![warn(missing_docs)]
+#![warn(clippy::all)]
+#![deny(unsafe_code)]
+
+//! # Stellar Dominion Core
+//!
+//! The simulation engine of Stellar Dominion: game state, managers, systems,
+//! events and save files, with strict EventBus architecture and deterministic
+//! fixed timestep simulation. This crate has no rendering or input dependency;
+//! the `stellar-dominion` client crate builds the playable game on top of it.
+//!
+//! ## API Stability
+//!
+//! The crate root re-exports and [`prelude`] form the supported public API and
+//! follow semantic versioning. Changes to them are recorded in `CHANGELOG.md`.
+//! Module paths below the root (`core::*`, `managers::*`, `systems::*`) are
+//! public for tooling but may change between minor releases.
+//!
+//! ## Quick Start
+//!
+//! ```rust
+//! use stellar_dominion_core::prelude::*;
+//!
+//! fn main() -> GameResult<()> {
+//!     // Initialize a new game
+//!     let mut game = stellar_dominion_core::setup::new_game()?;
+//!     
+//!     // Game loop with fixed timestep
+//!     loop {
+//!         game.fixed_update(FIXED_TIMESTEP)?;
+//!         
+//!         // Handle rendering with interpolation
+//!         // game.render(interpolation)?;
+//!         
+//!         // Break condition would go here
+//!         break;
+//!     }
+//!     
+//!     Ok(())
+//! }
+//! ```
+//!
+//! ## Headless Use
+//!
+//! Servers, bots and balancing experiments can run the simulation on its own.
+//! [`setup::new_headless_game`] starts a configured, seeded game and
+//! [`setup::run_ticks`] advances it; `examples/balance_run.rs` compares
+//! faction stockpiles across seeds. Run it with
+//! `cargo run -p stellar-dominion-core --example balance_run`.
+//!
+//! ## Custom Worlds
+//!
+//! [`GameStateBuilder`] starts a game in a world described in code, faction
+//! by faction and planet by planet, checking that every reference holds
+//! before the game starts; see [`core::builder`] for an example.
+//!
+//! ## Benchmarks
+//!
+//! `cargo bench -p stellar-dominion-core` times `fixed_update` at full scale,
+//! event routing, and saving and loading, on worlds built by
+//! [`bench_support`]. Pass `-- --save-baseline <file>` to record the timings
+//! and `-- --baseline <file>` to fail when any has slowed down noticeably.
+//!
+//! ## Architecture Overview
+//!
+//! The game follows a strict **EventBus pattern** where all systems communicate exclusively
+//! through events. No direct system-to-system references are allowed, ensuring clean
+//! separation of concerns and deterministic behavior.
+//!
+//! ### Core Components
+//!
+//! - [`GameState`]: Central orchestrator managing all systems and the EventBus
+//! - [`EventBus`]: Message routing system for inter-system communication
+//! - **Managers**: Data owners providing CRUD operations ([`Planet`], [`Ship`], [`Faction`])
+//! - **Systems**: Simulation logic processors (Physics, Resources, Population, etc.)
+//! - **Fixed Timestep**: 0.1 second deterministic simulation timesteps
+//!
+//! ### System Update Order
+//!
+//! Systems update in a strict order each tick:
+//! 1. Physics Engine (movement and spatial updates)
+//! 2. Resource System (resource production and consumption)
+//! 3. Population System (demographic changes)
+//! 4. Construction System (building completion)
+//! 5. Combat Resolver (battle resolution)
+//! 6. AI System (time-sliced decisions for non-player factions)
+//! 7. Time Manager (tick advancement)
+//!
+//! ## Error Handling
+//!
+//! All operations return [`GameResult<T>`] which is an alias for `Result<T, GameError>`.
+//! This ensures consistent error handling throughout the system.
+//!
+//! ### Error Types
+//!
+//! Each [`GameError`] variant describes one kind of failure, and
+//! [`GameError::code`] gives it a stable code to match on:
+//!
+//! - [`GameError::InvalidOperation`], [`GameError::InvalidTarget`] and
+//!   [`GameError::InvalidCommand`]: requests the game refuses
+//! - [`GameError::NotOwned`], [`GameError::UnsuitableShip`],
+//!   [`GameError::NotInOrbit`] and [`GameError::InvalidValue`]: orders to the
+//!   wrong faction's entities, to the wrong ship or place, or with bad values
+//! - [`GameError::InsufficientResources`], [`GameError::InsufficientCredits`]
+//!   and [`GameError::CapacityExceeded`]: not enough resources, credits or room
+//! - [`GameError::EntityNotFound`] and [`GameError::StaleEntity`]: ids naming
+//!   no live entity
+//! - [`GameError::SaveCorrupted`], [`GameError::SaveError`] and
+//!   [`GameError::Io`]: files that cannot be read, written or understood
+//! - [`GameError::Network`]: multiplayer connection failures
+//! - [`GameError::SystemError`]: broken invariants; the only errors
+//!   [`GameError::is_recoverable`] rejects
+//!
+//! ## Logging
+//!
+//! The crate logs through the `log` facade, with each module as its target:
+//! recovered system errors and a saves directory that cannot be created are
+//! warnings. Nothing is printed unless the application installs a logger, such as the
+//! [`logging::LogCollector`] that keeps recent records for the game to show.
+//!
+//! ## Resource Management
+//!
+//! - All resources are `i32` values (no floating point for determinism)
+//! - Resources cannot go negative (enforced by [`ResourceBundle::subtract()`])
+//! - Worker allocation must equal total population
+//! - Building slots = 10 + population/10000
+//!
+//! ## Feature Flags
+//!
+//! - `debug`: Enables debug utilities, verbose logging and the developer
+//!   console commands ([`console`])
+//! - `test-api`: Exposes deterministic scenario test vectors and a runner ([`test_api`])
+//! - `parallel`: Plans per-planet production on rayon's thread pool; results are
+//!   applied in planet id order, so games play out the same with or without it
+
+// Core module declarations
+/// Core types, events, errors and the game state
+pub mod core;
+/// Data management layer - Contains manager structs that own and provide CRUD operations for game entities
+pub mod managers;
+/// Simulation logic layer - Contains system structs that process game logic and emit events
+pub mod systems;
+/// Synthetic, fully populated game states for benchmarks and performance tests
+pub mod bench_support;
+/// Lockstep multiplayer sessions: host-relayed command bundles, desync detection and transports
+pub mod net;
+/// Log records kept for display in the game, collected from the `log` facade
+pub mod logging;
+/// Developer console commands for granting resources, spawning ships and running ticks
+#[cfg(feature = "debug")]
+pub mod console;
+/// Deterministic simulation test vectors for verifying compatibility of forks and mods
+#[cfg(feature = "test-api")]
+pub mod test_api;
+
+// Public API exports - carefully controlled interface
+// These are the only types that external consumers should directly access
+pub use core::{
+    // === Core Game State and Orchestration ===
+    GameState,
+    GameStateBuilder,
+    GameSystem,
+    GameMode,
+    GameConfiguration,
+    Difficulty,
+    PlanetDistribution,
+    
+    // === Error Handling ===
+    GameResult,
+    GameError,
+    EntityKind,
+    
+    // === Event System for External Integration ===
+    EventBus,
+    EventBusMetrics,
+    EventTracer,
+    JsonlTracer,
+    TraceCollector,
+    TraceEntry,
+    ScriptHook,
+    ScriptHookId,
+    ScriptQueue,
+    EventHistory,
+    HistoryEntry,
+    GameEvent,
+    PlayerCommand,
+    SimulationEvent,
+    StateChange,
+    SystemId,
+    SimRng,
+    RngStream,
+    
+    // === Network Protocol ===
+    CommandEnvelope,
+    PROTOCOL_VERSION,
+    SEQUENCE_WINDOW,
+    to_wire,
+    from_wire,
+    
+    // === Observation ===
+    GameSnapshot,
+    FactionSnapshot,
+    
+    // === Entity Identifiers ===
+    PlanetId,
+    ShipId,
+    FactionId,
+    PlayerId,
+    
+    // === Resource Management ===
+    ResourceBundle,
+    ResourceStorage,
+    ResourceType,
+    
+    // === Population and Demographics ===
+    Demographics,
+    WorkerAllocation,
+    TaxRate,
+    
+    // === Construction and Buildings ===
+    BuildingType,
+    Building,
+    BuildingStatus,
+    ShutdownReason,
+    
+    // === Ships and Spatial Systems ===
+    Ship,
+    ShipClass,
+    CargoHold,
+    Vector2,
+    OrbitalElements,
+    Trajectory,
+    
+    // === Factions and AI ===
+    Planet,
+    Faction,
+    AIPersonality,
+    
+    // === Combat and Victory Conditions ===
+    CombatOutcome,
+    VictoryType,
+};
+
+// === World Building ===
+pub use systems::{ScenarioFaction, ScenarioPlanet, ScenarioShip};
+
+// Utility modules for common patterns
+pub mod prelude {
+    //! Common imports for game development
+    //!
+    //! This module re-exports the most commonly used types and traits
+    //! for convenience when developing game systems.
+    //!
+    //! # Usage
+    //!
+    //! ```rust
+    //! use stellar_dominion_core::prelude::*;
+    //!
+    //! fn my_system_update(game: &mut GameState) -> GameResult<()> {
+    //!     // All common types are now available
+    //!     Ok(())
+    //! }
+    //! ```
+    
+    pub use crate::{
+        GameState,
+        GameStateBuilder,
+        ScenarioFaction,
+        ScenarioPlanet,
+        ScenarioShip,
+        GameSystem,
+        GameResult,
+        GameError,
+        EntityKind,
+        GameEvent,
+        PlayerCommand,
+        SimulationEvent,
+        StateChange,
+        EventBus,
+        ResourceBundle,
+        ResourceType,
+        Vector2,
+        PlanetId,
+        ShipId,
+        FactionId,
+    };
+    
+    // Re-export key configuration constants
+    pub use crate::config::{
+        FIXED_TIMESTEP,
+        MAX_PLANETS,
+        MAX_SHIPS,
+        MAX_FACTIONS,
+    };
+}
+
+// Version and metadata
+/// Current version of the Stellar Dominion game engine
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Display name of the game
+pub const GAME_NAME: &str = "Stellar Dominion";
+
+/// Game engine identifier for save files and networking
+pub const ENGINE_ID: &str = "stellar-dominion-engine";
+
+/// Minimum supported save format version
+pub const MIN_SAVE_VERSION: u32 = 1;
+
+/// Current save format version
+pub const CURRENT_SAVE_VERSION: u32 = 1;
+
+/// Build timestamp (uses version as fallback)
+pub const BUILD_TIMESTAMP: &str = env!("CARGO_PKG_VERSION");
+
+/// Target architecture (placeholder for future use)
+pub const TARGET_ARCH: &str = "unknown";
+
+// Game configuration constants
+pub mod config {
+    //! Game configuration constants and limits
+    //!
+    //! This module contains all gameplay configuration values, limits,
+    //! and constants used throughout the simulation engine.
+    
+    /// Fixed timestep duration in seconds for deterministic simulation
+    pub const FIXED_TIMESTEP: f32 = 0.1;
+    
+    /// Maximum number of planets supported by the simulation
+    pub const MAX_PLANETS: usize = 100;
+    
+    /// Maximum number of ships supported by the simulation
+    pub const MAX_SHIPS: usize = 500;
+    
+    /// Maximum number of factions that can exist simultaneously
+    pub const MAX_FACTIONS: u8 = 8;
+    
+    /// Base building slots available on any planet
+    pub const BASE_BUILDING_SLOTS: i32 = 10;
+    
+    /// Additional building slots granted per 10,000 population
+    pub const BUILDING_SLOTS_PER_10K_POP: i32 = 1;
+    
+    /// Maximum i32 value for resource overflow protection
+    /// This provides a safety buffer to prevent arithmetic overflow in resource calculations
+    pub const MAX_RESOURCE_VALUE: i32 = i32::MAX - 1000;
+    
+    /// Minimum safe resource value to prevent underflow
+    pub const MIN_RESOURCE_VALUE: i32 = 0;
+    
+    /// Minimum valid tick value
+    pub const MIN_TICK_VALUE: i64 = 0;
+    
+    /// Maximum safe tick value (prevents overflow)
+    pub const MAX_SAFE_TICK_VALUE: i64 = u64::MAX as i64 - 10000;
+    
+    /// Default save file name
+    pub const DEFAULT_SAVE_FILE: &str = "stellar_dominion.save";
+    
+    /// Maximum length for player/faction names
+    pub const MAX_NAME_LENGTH: usize = 32;
+    
+    /// Control groups each faction can fill, numbered 1 to this
+    pub const CONTROL_GROUPS: u8 = 9;
+    
+    /// Ticks between autosaves in a new game (3000 ticks = 5 minutes)
+    pub const DEFAULT_AUTOSAVE_INTERVAL_TICKS: u64 = 3000;
+    
+    /// Autosave slots rotated through before the oldest is overwritten
+    pub const DEFAULT_AUTOSAVE_SLOTS: usize = 3;
+    
+    /// Events kept in `EventBus::event_history` before the oldest is overwritten
+    pub const EVENT_HISTORY_CAPACITY: usize = 100;
+    
+    /// State changes held for `GameState::take_state_changes` before the
+    /// client is told to rebuild instead
+    pub const UI_CHANGE_CAPACITY: usize = 10_000;
+    
+    /// Distance a ship covers per tick while in transit
+    pub const SHIP_BASE_SPEED: f32 = 10.0;
+    
+    /// Validation helper: Check if a resource value is within safe bounds
+    pub const fn is_resource_value_safe(value: i32) -> bool {
+        value >= MIN_RESOURCE_VALUE && value <= MAX_RESOURCE_VALUE
+    }
+    
+    /// Validation helper: Check if a tick value is within safe bounds
+    pub const fn is_tick_value_safe(tick: i64) -> bool {
+        tick >= MIN_TICK_VALUE && tick <= MAX_SAFE_TICK_VALUE
+    }
+}
+
+// Initialization and setup utilities
+pub mod setup {
+    //! Game initialization utilities
+    
+    use crate::{GameState, GameResult, GameError, GameConfiguration};
+    
+    /// Initialize a new game with default settings
+    /// 
+    /// Creates a fresh game state with all systems properly initialized
+    /// and ready for simulation.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns the error of any core system that fails to initialize.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use stellar_dominion_core::setup;
+    /// 
+    /// let game = setup::new_game().expect("Failed to create new game");
+    /// assert_eq!(game.get_current_tick(), 0);
+    /// ```
+    pub fn new_game() -> GameResult<GameState> {
+        GameState::new()
+    }
+    
+    /// Load a saved game from the default save location
+    /// 
+    /// # Errors
+    /// 
+    /// Returns the error of any core system that fails to initialize.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use stellar_dominion_core::setup;
+    /// 
+    /// match setup::load_game() {
+    ///     Ok(game) => println!("Game loaded successfully"),
+    ///     Err(e) => eprintln!("Failed to load game: {}", e),
+    /// }
+    /// ```
+    pub fn load_game() -> GameResult<GameState> {
+        let mut game = GameState::new()?;
+        game.load_game()?;
+
+        Ok(game)
+    }
+
+    /// Start a configured game without any client, ready for `fixed_update`
+    ///
+    /// Intended for servers, bots and balancing runs: the galaxy is generated
+    /// from `config` (including its seed) and the game is put in
+    /// [`GameMode::InGame`](crate::GameMode::InGame).
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of a core system that fails to initialize, or the
+    /// generator's error if no valid galaxy can be built from `config`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use stellar_dominion_core::{setup, GameConfiguration};
+    ///
+    /// let config = GameConfiguration { seed: 7, ai_opponents: 2, ..Default::default() };
+    /// let mut game = setup::new_headless_game(config).expect("Failed to start game");
+    /// setup::run_ticks(&mut game, 100).expect("Simulation failed");
+    /// assert_eq!(game.get_current_tick(), 100);
+    /// ```
+    pub fn new_headless_game(config: GameConfiguration) -> GameResult<GameState> {
+        let mut game = new_game()?;
+        game.set_game_configuration(config);
+        game.start_new_game()?;
+        Ok(game)
+    }
+
+    /// Start a game from a scenario file, ready for `fixed_update`
+    ///
+    /// The world, victory conditions and scripted events all come from the
+    /// file; see [`systems::scenario`](crate::systems::scenario) for its format.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::Io` if the file cannot be read,
+    /// `GameError::SaveCorrupted` if it is not a scenario, and
+    /// `GameError::SaveError` if it describes an inconsistent world.
+    pub fn new_game_from_scenario(path: &std::path::Path) -> GameResult<GameState> {
+        let scenario = crate::systems::ScenarioLoader::load_from_file(path)?;
+        let mut game = new_game()?;
+        game.start_scenario(&scenario)?;
+        Ok(game)
+    }
+
+    /// Advance the simulation by `ticks` fixed timesteps
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error raised by a system during `fixed_update`.
+    pub fn run_ticks(game: &mut GameState, ticks: u64) -> GameResult<()> {
+        for _ in 0..ticks {
+            game.fixed_update(crate::config::FIXED_TIMESTEP)?;
+        }
+        Ok(())
+    }
+
+    /// Validate game state integrity
+    /// 
+    /// Performs comprehensive validation of the game state to ensure all
+    /// architectural invariants are maintained and the game is in a consistent state.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `game` - The game state to validate
+    /// 
+    /// # Errors
+    /// 
+    /// Returns `GameError::SystemError` if any validation checks fail.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use stellar_dominion_core::{GameState, setup};
+    /// 
+    /// let game = GameState::new().unwrap();
+    /// setup::validate_game_state(&game).expect("Game state should be valid");
+    /// ```
+    pub fn validate_game_state(game: &GameState) -> GameResult<()> {
+        // Validate tick counter is in valid range
+        let current_tick = game.get_current_tick();
+        
+        // Check for tick overflow protection
+        if current_tick > (u64::MAX - 10000) {
+            return Err(GameError::SystemError(
+                format!("Tick counter approaching overflow: {}", current_tick)
+            ));
+        }
+        
+        // TODO: Add manager-specific validation when managers provide validation methods
+        // - Planet manager: validate resource constraints, worker allocations
+        // - Ship manager: validate movement constraints, cargo limits
+        // - Faction manager: validate relationship consistency
+        
+        Ok(())
+    }
+}
+
+// Feature flags for conditional compilation
+#[cfg(feature = "debug")]
+pub mod debug {
+    //! Debug utilities for development
+    
+    use crate::{GameState, VERSION};
+    
+    /// Detailed game state for debugging
+    /// 
+    /// Describes the current game state for debugging: the tick, the
+    /// version and whether the architectural invariants hold. The caller
+    /// decides where the text goes, such as a log record or the console.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `game` - The game state to debug
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use stellar_dominion_core::{GameState, debug};
+    /// 
+    /// let game = GameState::new().unwrap();
+    /// log::debug!("{}", debug::format_game_state(&game));
+    /// ```
+    pub fn format_game_state(game: &GameState) -> String {
+        let architecture = match validate_architecture(game) {
+            Ok(_) => "✓ Valid".to_string(),
+            Err(msg) => format!("✗ Invalid - {}", msg),
+        };
+        format!(
+            "=== Stellar Dominion Debug State ===\n\
+             Current Tick: {}\n\
+             Game Version: {}\n\
+             Architecture Status: {}\n\
+             ====================================",
+            game.get_current_tick(), VERSION, architecture,
+        )
+    }
+    
+    /// Validate all architectural invariants
+    /// 
+    /// Performs comprehensive validation of architectural constraints including
+    /// EventBus integrity, system dependencies, and state consistency.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `game` - The game state to validate
+    /// 
+    /// # Returns
+    /// 
+    /// Returns `Ok(())` if all invariants are satisfied, `Err(String)` with
+    /// detailed error information if any violations are found.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use stellar_dominion_core::{GameState, debug};
+    /// 
+    /// let game = GameState::new().unwrap();
+    /// match debug::validate_architecture(&game) {
+    ///     Ok(_) => println!("Architecture is valid"),
+    ///     Err(msg) => eprintln!("Architecture violation: {}", msg),
+    /// }
+    /// ```
+    pub fn validate_architecture(game: &GameState) -> Result<(), String> {
+        // Validate basic state integrity
+        let current_tick = game.get_current_tick();
+        
+        // Check for tick overflow (u64 is always >= 0, so no need to check < 0)
+        if current_tick > (u64::MAX - 10000) {
+            return Err(format!("Tick counter approaching overflow: {}", current_tick));
+        }
+        
+        // TODO: Add comprehensive architecture validation:
+        // - EventBus subscription integrity
+        // - System update order compliance
+        // - No direct system-to-system references
+        // - Manager ownership patterns
+        // - Resource constraint enforcement
+        
+        Ok(())
+    }
+}
+
+// Integration tests helper
+#[cfg(test)]
+pub mod test_utils {
+    //! Testing utilities for integration tests
+    //!
+    //! This module provides helper functions for creating test scenarios,
+    //! running controlled simulations, and validating game state during testing.
+    
+    use crate::{GameState, GameResult, GameError, config};
+    
+    /// Create a minimal game state for testing
+    /// 
+    /// Initializes a basic game state suitable for unit and integration testing.
+    /// This function ensures deterministic initialization for reproducible tests.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns the error of any core system that fails to initialize.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use stellar_dominion_core::test_utils;
+    /// 
+    /// let game = test_utils::create_test_game().expect("Test game creation failed");
+    /// assert_eq!(game.get_current_tick(), 0);
+    /// ```
+    pub fn create_test_game() -> GameResult<GameState> {
+        GameState::new()
+    }
+    
+    /// Run one simulation tick for testing
+    /// 
+    /// Executes exactly one fixed timestep update cycle, useful for deterministic
+    /// testing of game logic.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `game` - Mutable reference to the game state to update
+    /// 
+    /// # Errors
+    /// 
+    /// Returns any error that occurs during the simulation update cycle.
+    /// 
+    /// # Example
+    /// 
+    /// ```rust
+    /// use stellar_dominion_core::test_utils;
+    /// 
+    /// let mut game = test_utils::create_test_game().unwrap();
+    /// test_utils::simulate_tick(&mut game).unwrap();
+    /// assert!(game.get_current_tick() > 0);
+    /// ```
+    pub fn simulate_tick(game: &mut GameState) -> GameResult<()> {
+        let current_tick = game.get_current_tick();
+        // Convert u64 to i64 for validation, checking for overflow
+        let tick_as_i64 = current_tick.try_into()
+            .map_err(|_| GameError::SystemError(
+                format!("Tick counter too large for validation: {}", current_tick)
+            ))?;
+            
+        if !config::is_tick_value_safe(tick_as_i64) {
+            return Err(GameError::SystemError(
+                format!("Cannot simulate tick on invalid game state (tick: {})", current_tick)
+            ));
+        }
+        
+        game.fixed_update(config::FIXED_TIMESTEP)
+    }
+    
+    /// Run multiple simulation ticks for batch testing
+    /// 
+    /// Executes a specified number of simulation ticks, useful for testing
+    /// longer-term game behavior and system interactions.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `game` - Mutable reference to the game state to update
+    /// * `ticks` - Number of ticks to simulate (must be > 0)
+    /// 
+    /// # Errors
+    /// 
+    /// Returns validation error if tick count is invalid, or any simulation error.
+    pub fn simulate_ticks(game: &mut GameState, ticks: u32) -> GameResult<()> {
+        if ticks == 0 {
+            return Err(GameError::InvalidOperation(
+                "Tick count must be greater than 0".into()
+            ));
+        }
+        
+        for _ in 0..ticks {
+            simulate_tick(game)?;
+        }
+        
+        Ok(())
+    }
+    
+    /// Assert that game state remains valid after operations
+    /// 
+    /// Helper function for tests to verify game state integrity.
+    pub fn assert_game_state_valid(game: &GameState) {
+        crate::setup::validate_game_state(game)
+            .expect("Game state validation failed");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,125 @@
+// src/logging.rs
+//! Log records kept for display in the game
+//!
+//! The crates log through the [`log`] facade, each record under the module
+//! that wrote it as its target. [`init`] installs a [`LogCollector`] as the
+//! logger: it keeps the last [`LOG_CAPACITY`] records at or above its level,
+//! which the developer console lists with `log`, and hands every record on to
+//! another logger, such as one printing to the terminal, if given one.
+//!
+//! Until [`init`] is called, records go nowhere and [`recent`] is empty, as
+//! in tests and tools that never set up logging.
+
+use crate::core::{GameError, GameResult};
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use std::collections::VecDeque;
+use std::sync::{Mutex, OnceLock};
+
+/// Records kept by the collector; the oldest are dropped first
+pub const LOG_CAPACITY: usize = 200;
+
+static COLLECTOR: OnceLock<LogCollector> = OnceLock::new();
+
+/// One logged record
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogEntry {
+    /// How severe it is
+    pub level: Level,
+    /// Module that logged it
+    pub target: String,
+    /// What was logged
+    pub message: String,
+}
+
+impl std::fmt::Display for LogEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}: {}", self.level, self.target, self.message)
+    }
+}
+
+/// Logger keeping recent records and passing each one on
+pub struct LogCollector {
+    entries: Mutex<VecDeque<LogEntry>>,
+    level: LevelFilter,
+    forward: Option<Box<dyn Log>>,
+}
+
+impl LogCollector {
+    /// Keep records at `level` and above, handing all records to `forward` too
+    pub fn new(level: LevelFilter, forward: Option<Box<dyn Log>>) -> Self {
+        Self { entries: Mutex::new(VecDeque::new()), level, forward }
+    }
+
+    /// The last `count` records kept, oldest first
+    pub fn recent(&self, count: usize) -> Vec<LogEntry> {
+        let entries = self.entries.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        entries.iter().skip(entries.len().saturating_sub(count)).cloned().collect()
+    }
+
+    /// Forget the records kept so far
+    pub fn clear(&self) {
+        self.entries.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).clear();
+    }
+}
+
+impl Log for LogCollector {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.level || self.forward.as_ref().is_some_and(|forward| forward.enabled(metadata))
+    }
+
+    fn log(&self, record: &Record) {
+        if record.level() <= self.level {
+            let mut entries = self.entries.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            if entries.len() == LOG_CAPACITY {
+                entries.pop_front();
+            }
+            entries.push_back(LogEntry {
+                level: record.level(),
+                target: record.target().to_string(),
+                message: record.args().to_string(),
+            });
+        }
+        if let Some(forward) = &self.forward {
+            if forward.enabled(record.metadata()) {
+                forward.log(record);
+            }
+        }
+    }
+
+    fn flush(&self) {
+        if let Some(forward) = &self.forward {
+            forward.flush();
+        }
+    }
+}
+
+/// Install the collector as the process's logger, keeping records at
+/// `level` and above and passing records `forward` wants on to it. Logging
+/// stops at the more detailed of `level` and `forward_level`.
+///
+/// # Errors
+///
+/// Returns `GameError::InvalidOperation` if a logger is already installed.
+pub fn init(level: LevelFilter, forward: Option<(Box<dyn Log>, LevelFilter)>) -> GameResult<()> {
+    let (forward, forward_level) = match forward {
+        Some((forward, forward_level)) => (Some(forward), forward_level),
+        None => (None, LevelFilter::Off),
+    };
+    let mut installed = false;
+    let collector = COLLECTOR.get_or_init(|| {
+        installed = true;
+        LogCollector::new(level, forward)
+    });
+    if !installed {
+        return Err(GameError::InvalidOperation("Logging is already set up".into()));
+    }
+    log::set_logger(collector).map_err(|e| GameError::InvalidOperation(format!("Cannot set up logging: {}", e)))?;
+    log::set_max_level(level.max(forward_level));
+    Ok(())
+}
+
+/// The last `count` records the installed collector kept, oldest first;
+/// empty before [`init`]
+pub fn recent(count: usize) -> Vec<LogEntry> {
+    COLLECTOR.get().map(|collector| collector.recent(count)).unwrap_or_default()
+}
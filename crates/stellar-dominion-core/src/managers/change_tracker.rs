@@ -0,0 +1,116 @@
+// src/managers/change_tracker.rs
+//! Change stamps for the entities a manager owns
+//!
+//! Managers stamp every entity they change with the current tick and a
+//! revision number that grows with each change. Consumers either ask which
+//! entities changed since the tick they last looked, or remember the revision
+//! they computed something from and recompute only when it moved. GameState
+//! sets the tick as each tick's events are processed.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// When an entity last changed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ChangeStamp {
+    /// Tick the change was made in
+    pub tick: u64,
+    /// Tracker revision of the change; a tracker never hands one out twice
+    pub revision: u64,
+}
+
+/// Latest change of each entity of one kind
+#[derive(Debug, Clone)]
+pub struct ChangeTracker<Id> {
+    tick: u64,
+    revision: u64,
+    stamps: HashMap<Id, ChangeStamp>,
+}
+
+impl<Id: Copy + Eq + Hash> ChangeTracker<Id> {
+    /// Tracker at tick 0 with nothing changed
+    pub fn new() -> Self {
+        Self {
+            tick: 0,
+            revision: 0,
+            stamps: HashMap::new(),
+        }
+    }
+
+    /// Stamp later changes with `tick`
+    pub fn set_tick(&mut self, tick: u64) {
+        self.tick = tick;
+    }
+
+    /// Tick later changes are stamped with
+    pub fn tick(&self) -> u64 {
+        self.tick
+    }
+
+    /// Revision of the latest change to any entity, removals included
+    pub fn revision(&self) -> u64 {
+        self.revision
+    }
+
+    /// Record a change to the entity
+    pub fn mark(&mut self, id: Id) {
+        self.revision += 1;
+        self.stamps.insert(id, ChangeStamp { tick: self.tick, revision: self.revision });
+    }
+
+    /// Record that the entity is gone; it no longer reports changes
+    pub fn forget(&mut self, id: Id) {
+        self.revision += 1;
+        self.stamps.remove(&id);
+    }
+
+    /// Forget every entity, keeping the tick and the revision count so
+    /// revisions handed out before stay unique
+    pub fn clear(&mut self) {
+        self.revision += 1;
+        self.stamps.clear();
+    }
+
+    /// The entity's latest change, if it was ever changed
+    pub fn stamp(&self, id: Id) -> Option<ChangeStamp> {
+        self.stamps.get(&id).copied()
+    }
+
+    /// True when the entity changed at or after `tick`
+    pub fn changed_since(&self, id: Id, tick: u64) -> bool {
+        self.stamps.get(&id).is_some_and(|stamp| stamp.tick >= tick)
+    }
+}
+
+impl<Id: Copy + Eq + Hash> Default for ChangeTracker<Id> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stamps_follow_ticks_and_revisions() {
+        let mut tracker: ChangeTracker<u32> = ChangeTracker::new();
+        tracker.mark(1);
+        tracker.set_tick(5);
+        tracker.mark(2);
+        tracker.mark(1);
+
+        assert_eq!(tracker.stamp(1), Some(ChangeStamp { tick: 5, revision: 3 }));
+        assert_eq!(tracker.stamp(2), Some(ChangeStamp { tick: 5, revision: 2 }));
+        assert!(tracker.changed_since(2, 5) && !tracker.changed_since(2, 6));
+        assert!(tracker.stamp(3).is_none() && !tracker.changed_since(3, 0));
+
+        // Removals count as changes, and revisions are never reused
+        tracker.forget(2);
+        assert!(tracker.stamp(2).is_none());
+        tracker.clear();
+        tracker.mark(1);
+        assert_eq!(tracker.stamp(1), Some(ChangeStamp { tick: 5, revision: 6 }));
+        assert_eq!(tracker.revision(), 6);
+    }
+}
@@ -2,10 +2,10 @@
 //! Consolidated manager for planets and factions to reduce code duplication.
 //! Combines PlanetManager and FactionManager functionality with shared patterns.
 
-use crate::core::{GameResult, GameEvent, EventBus, GameSystem};
+use crate::core::{GameResult, GameEvent, GameSystem, SystemContext};
 use crate::core::types::*;
 use crate::core::events::*;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
 /// Manages both planets and factions with similar CRUD operations
 pub struct EntityManager {
@@ -38,7 +38,7 @@ impl EntityManager {
     fn get_planet_index(&self, id: PlanetId) -> GameResult<usize> {
         self.planet_index.get(&id)
             .copied()
-            .ok_or_else(|| GameError::InvalidTarget(format!("Planet {} not found", id)))
+            .ok_or_else(|| GameError::not_found(EntityKind::Planet, id))
     }
 
     pub fn create_planet(&mut self, position: OrbitalElements, controller: Option<FactionId>) -> GameResult<PlanetId> {
@@ -56,6 +56,10 @@ impl EntityManager {
             population: Demographics::default(),
             developments: Vec::new(),
             controller,
+            storage_priority: StoragePriority::default(),
+            power_priority: PowerPriority::default(),
+            size: DEFAULT_PLANET_SIZE,
+            tax_rate: TaxRate::default(),
         };
 
         self.planets.push(planet);
@@ -95,21 +99,15 @@ impl EntityManager {
         let index = self.get_planet_index(id)?;
         let planet = &mut self.planets[index];
         
-        let building_slots = self.calculate_building_slots(planet);
+        let building_slots = planet.building_slots();
         if planet.developments.len() >= building_slots {
-            return Err(GameError::InvalidOperation(
-                format!("Planet {} has no available building slots", id)
-            ));
+            return Err(GameError::capacity("building slots", 1u64, 0u64));
         }
 
         planet.developments.push(building);
         Ok(())
     }
 
-    fn calculate_building_slots(&self, planet: &Planet) -> usize {
-        10 + (planet.population.total as usize / 10000)
-    }
-
     pub fn load_planets(&mut self, planets: Vec<Planet>) -> GameResult<()> {
         for planet in &planets {
             if let Some(faction_id) = planet.controller {
@@ -144,16 +142,16 @@ impl EntityManager {
     fn get_faction_index(&self, id: FactionId) -> GameResult<usize> {
         self.faction_index.get(&id)
             .copied()
-            .ok_or_else(|| GameError::InvalidTarget(format!("Faction {} not found", id)))
+            .ok_or_else(|| GameError::not_found(EntityKind::Faction, id))
     }
 
     pub fn create_faction(&mut self, name: String, is_player: bool, ai_type: AIPersonality) -> GameResult<FactionId> {
         if name.trim().is_empty() {
-            return Err(GameError::InvalidOperation("Faction name cannot be empty".into()));
+            return Err(GameError::invalid_value("faction name", "cannot be empty"));
         }
 
         if self.factions.iter().any(|f| f.name == name) {
-            return Err(GameError::InvalidOperation(format!("Faction with name '{}' already exists", name)));
+            return Err(GameError::invalid_value("faction name", format!("'{}' is already taken", name)));
         }
 
         let id = self.next_faction_id;
@@ -165,6 +163,14 @@ impl EntityManager {
             is_player,
             ai_type,
             score: 0,
+            inbox: Inbox::default(),
+            technologies: BTreeSet::new(),
+            research: ResearchState::default(),
+            relations: BTreeMap::new(),
+            explored_planets: BTreeSet::new(),
+            kind: FactionKind::Empire,
+            credits: STARTING_CREDITS,
+            tax_rate: TaxRate::default(),
         };
 
         self.factions.push(faction);
@@ -179,7 +185,7 @@ impl EntityManager {
 
     pub fn update_score(&mut self, id: FactionId, score: i32) -> GameResult<()> {
         if score < 0 {
-            return Err(GameError::InvalidOperation("Faction score cannot be negative".into()));
+            return Err(GameError::invalid_value("faction score", "cannot be negative"));
         }
 
         let index = self.get_faction_index(id)?;
@@ -252,7 +258,7 @@ impl EntityManager {
 }
 
 impl GameSystem for EntityManager {
-    fn update(&mut self, _delta: f32, _events: &mut EventBus) -> GameResult<()> {
+    fn update(&mut self, _delta: f32, _context: &mut SystemContext<'_>) -> GameResult<()> {
         // No regular updates needed
         Ok(())
     }
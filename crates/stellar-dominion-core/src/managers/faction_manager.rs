@@ -0,0 +1,452 @@
+// src/managers/faction_manager.rs
+use crate::core::{GameResult, GameEvent, ShipDesign, DesignId};
+use crate::core::types::*;
+use super::validation::validate_control_group;
+use super::PlanetManager;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+pub struct FactionManager {
+    factions: Vec<Faction>,
+    faction_index: HashMap<FactionId, usize>,
+    next_id: FactionId,
+}
+
+impl FactionManager {
+    pub fn new() -> Self {
+        Self {
+            factions: Vec::new(),
+            faction_index: HashMap::new(),
+            next_id: 0,
+        }
+    }
+    
+    pub fn create_faction(&mut self, name: String, is_player: bool, ai_type: AIPersonality) -> GameResult<FactionId> {
+        let id = self.insert_faction(name, is_player, ai_type, FactionKind::Empire)?;
+        // A new empire finds the pirates already hostile
+        let pirates: Vec<FactionId> = self.factions.iter()
+            .filter(|faction| faction.kind == FactionKind::Pirates)
+            .map(|faction| faction.id)
+            .collect();
+        for pirate in pirates {
+            self.set_at_war(id, pirate)?;
+        }
+        Ok(id)
+    }
+    
+    /// Create a non-scoring system faction such as the pirates. It is not
+    /// controlled by the player or the personality AI, has no credits and
+    /// starts at war with every faction, including those created later.
+    pub fn create_system_faction(&mut self, name: String, kind: FactionKind) -> GameResult<FactionId> {
+        if kind == FactionKind::Empire {
+            return Err(GameError::InvalidOperation("Empires are created with create_faction".into()));
+        }
+        let id = self.insert_faction(name, false, AIPersonality::Aggressive, kind)?;
+        self.add_credits(id, -STARTING_CREDITS)?;
+        let others: Vec<FactionId> = self.factions.iter().map(|faction| faction.id).filter(|other| *other != id).collect();
+        for other in others {
+            self.set_at_war(id, other)?;
+        }
+        Ok(id)
+    }
+    
+    fn insert_faction(&mut self, name: String, is_player: bool, ai_type: AIPersonality, kind: FactionKind) -> GameResult<FactionId> {
+        // Validate faction name
+        if name.trim().is_empty() {
+            return Err(GameError::invalid_value("faction name", "cannot be empty"));
+        }
+        
+        // Check for duplicate names
+        if self.factions.iter().any(|f| f.name == name) {
+            return Err(GameError::invalid_value("faction name", format!("'{}' is already taken", name)));
+        }
+        
+        let id = self.next_id;
+        self.next_id += 1;
+        
+        let faction = Faction {
+            id,
+            name,
+            is_player,
+            ai_type,
+            score: 0,
+            inbox: Inbox::default(),
+            technologies: BTreeSet::new(),
+            research: ResearchState::default(),
+            relations: BTreeMap::new(),
+            explored_planets: BTreeSet::new(),
+            kind,
+            credits: STARTING_CREDITS,
+            tax_rate: TaxRate::default(),
+            ship_designs: Vec::new(),
+            control_groups: BTreeMap::new(),
+        };
+        
+        self.factions.push(faction);
+        self.faction_index.insert(id, self.factions.len() - 1);
+        
+        Ok(id)
+    }
+    
+    fn set_at_war(&mut self, a: FactionId, b: FactionId) -> GameResult<()> {
+        self.update_relations((a, b), |relation| {
+            relation.at_war = true;
+            relation.treaty = None;
+        })
+    }
+    
+    /// Add credits, or spend them with a negative amount; a faction cannot go into debt
+    pub fn add_credits(&mut self, id: FactionId, amount: i32) -> GameResult<()> {
+        let index = self.faction_index.get(&id)
+            .ok_or_else(|| GameError::not_found(EntityKind::Faction, id))?;
+        let faction = &mut self.factions[*index];
+        let credits = faction.credits.saturating_add(amount);
+        if credits < 0 {
+            return Err(GameError::InsufficientCredits { required: -amount, available: faction.credits });
+        }
+        faction.credits = credits;
+        Ok(())
+    }
+    
+    /// Pay a tick's net income into the treasury. Expenses the treasury
+    /// cannot cover go unpaid rather than into debt
+    /// Pay `cost` credits for goods delivered to one of the faction's planets
+    pub fn buy_goods(&mut self, id: FactionId, cost: i32, planets: &mut PlanetManager, planet: PlanetId, goods: ResourceBundle) -> GameResult<()> {
+        self.add_credits(id, -cost)?;
+        planets.add_resources(planet, goods)
+    }
+    
+    /// Take goods from one of the faction's planets and pay `revenue`
+    /// credits for them
+    pub fn sell_goods(&mut self, id: FactionId, revenue: i32, planets: &mut PlanetManager, planet: PlanetId, goods: ResourceBundle) -> GameResult<()> {
+        planets.remove_resources(planet, goods)?;
+        self.add_credits(id, revenue)
+    }
+    
+    /// Add the faction's net budget for the tick to its treasury
+    pub fn settle_budget(&mut self, id: FactionId, net: i32) -> GameResult<()> {
+        let index = self.faction_index.get(&id)
+            .ok_or_else(|| GameError::not_found(EntityKind::Faction, id))?;
+        let faction = &mut self.factions[*index];
+        faction.credits = faction.credits.saturating_add(net).max(0);
+        Ok(())
+    }
+    
+    pub fn get_faction(&self, id: FactionId) -> GameResult<&Faction> {
+        let index = self.faction_index.get(&id)
+            .ok_or_else(|| GameError::not_found(EntityKind::Faction, id))?;
+        Ok(&self.factions[*index])
+    }
+    
+    pub fn update_score(&mut self, id: FactionId, score: i32) -> GameResult<()> {
+        let index = self.faction_index.get(&id)
+            .ok_or_else(|| GameError::not_found(EntityKind::Faction, id))?;
+        
+        // Validate score (prevent overflow)
+        if score < 0 {
+            return Err(GameError::invalid_value("faction score", "cannot be negative"));
+        }
+        
+        self.factions[*index].score = score;
+        Ok(())
+    }
+    
+    pub fn add_score(&mut self, id: FactionId, points: i32) -> GameResult<()> {
+        let index = self.faction_index.get(&id)
+            .ok_or_else(|| GameError::not_found(EntityKind::Faction, id))?;
+        
+        let current_score = self.factions[*index].score;
+        let new_score = current_score.saturating_add(points);
+        
+        self.factions[*index].score = new_score;
+        Ok(())
+    }
+    
+    /// Grant a technology to a faction; returns false if it was already known.
+    ///
+    /// Research put into the technology is dropped, and a faction that was
+    /// researching it is left without a target.
+    pub fn unlock_technology(&mut self, id: FactionId, technology: Technology) -> GameResult<bool> {
+        let index = self.faction_index.get(&id)
+            .ok_or_else(|| GameError::not_found(EntityKind::Faction, id))?;
+        let faction = &mut self.factions[*index];
+        faction.research.progress.remove(&technology);
+        if faction.research.target == Some(technology) {
+            faction.research.target = None;
+        }
+        Ok(faction.technologies.insert(technology))
+    }
+    
+    /// Record that the faction has seen a planet; false if it already had
+    pub fn explore_planet(&mut self, id: FactionId, planet: PlanetId) -> GameResult<bool> {
+        let index = self.faction_index.get(&id)
+            .ok_or_else(|| GameError::not_found(EntityKind::Faction, id))?;
+        Ok(self.factions[*index].explored_planets.insert(planet))
+    }
+    
+    /// Choose the technology new research points go to
+    pub fn set_research_target(&mut self, id: FactionId, technology: Option<Technology>) -> GameResult<()> {
+        let index = self.faction_index.get(&id)
+            .ok_or_else(|| GameError::not_found(EntityKind::Faction, id))?;
+        self.factions[*index].research.target = technology;
+        Ok(())
+    }
+    
+    /// Set the empire-wide tax rate
+    pub fn set_tax_rate(&mut self, id: FactionId, rate: TaxRate) -> GameResult<()> {
+        let index = self.faction_index.get(&id)
+            .ok_or_else(|| GameError::not_found(EntityKind::Faction, id))?;
+        self.factions[*index].tax_rate = rate;
+        Ok(())
+    }
+    
+    /// Add a ship design to the faction, or replace its design of the same
+    /// name, which keeps its id; returns the design's id
+    pub fn save_ship_design(&mut self, id: FactionId, mut design: ShipDesign) -> GameResult<DesignId> {
+        design.validate()?;
+        let index = self.faction_index.get(&id)
+            .ok_or_else(|| GameError::not_found(EntityKind::Faction, id))?;
+        let designs = &mut self.factions[*index].ship_designs;
+        if let Some(existing) = designs.iter_mut().find(|existing| existing.name == design.name) {
+            design.id = existing.id;
+            *existing = design;
+            return Ok(existing.id);
+        }
+        design.id = designs.iter().map(|existing| existing.id + 1).max().unwrap_or(1);
+        let design_id = design.id;
+        designs.push(design);
+        Ok(design_id)
+    }
+    
+    /// Replace a control group's members, dropping repeats; no members
+    /// clears the group
+    pub fn set_control_group(&mut self, id: FactionId, group: u8, mut members: Vec<NamedEntity>) -> GameResult<()> {
+        validate_control_group(group)?;
+        let index = self.faction_index.get(&id)
+            .ok_or_else(|| GameError::not_found(EntityKind::Faction, id))?;
+        let mut seen = std::collections::HashSet::new();
+        members.retain(|member| seen.insert(*member));
+        let groups = &mut self.factions[*index].control_groups;
+        if members.is_empty() {
+            groups.remove(&group);
+        } else {
+            groups.insert(group, members);
+        }
+        Ok(())
+    }
+    
+    /// Put research points into a technology; returns the total put in so far
+    pub fn add_research_progress(&mut self, id: FactionId, technology: Technology, points: i32) -> GameResult<i32> {
+        if points < 0 {
+            return Err(GameError::invalid_value("research points", "cannot be negative"));
+        }
+        let index = self.faction_index.get(&id)
+            .ok_or_else(|| GameError::not_found(EntityKind::Faction, id))?;
+        let progress = self.factions[*index].research.progress.entry(technology).or_insert(0);
+        *progress = progress.saturating_add(points);
+        Ok(*progress)
+    }
+    
+    pub fn get_all_factions(&self) -> &[Faction] {
+        &self.factions
+    }
+    
+    pub fn count(&self) -> usize {
+        self.factions.len()
+    }
+    
+    pub fn find_by_name(&self, name: &str) -> Option<&Faction> {
+        self.factions.iter().find(|f| f.name == name)
+    }
+    
+    pub fn get_player_faction(&self) -> Option<&Faction> {
+        self.factions.iter().find(|f| f.is_player)
+    }
+    
+    /// Factions that count towards victory and statistics, in id order
+    pub fn scoring_factions(&self) -> impl Iterator<Item = &Faction> {
+        self.factions.iter().filter(|faction| faction.is_scoring())
+    }
+    
+    /// First faction of the given kind
+    pub fn find_by_kind(&self, kind: FactionKind) -> Option<&Faction> {
+        self.factions.iter().find(|faction| faction.kind == kind)
+    }
+    
+    /// Deliver a message to a faction's inbox, expiring after the message's default lifetime
+    pub fn deliver_to_inbox(&mut self, faction: FactionId, message: InboxMessage, tick: u64) -> GameResult<InboxItemId> {
+        if let Some(sender) = message.sender() {
+            self.get_faction(sender)?;
+        }
+        let index = self.faction_index.get(&faction)
+            .ok_or_else(|| GameError::not_found(EntityKind::Faction, faction))?;
+        Ok(self.factions[*index].inbox.deliver(message, tick))
+    }
+    
+    /// Remove an item from a faction's inbox so the response can be carried out
+    pub fn respond_to_inbox(&mut self, faction: FactionId, item: InboxItemId) -> GameResult<InboxItem> {
+        let index = self.faction_index.get(&faction)
+            .ok_or_else(|| GameError::not_found(EntityKind::Faction, faction))?;
+        self.factions[*index].inbox.take(item)
+            .ok_or_else(|| GameError::not_found(EntityKind::InboxItem, item))
+    }
+    
+    /// Drop lapsed inbox items across all factions, returning them with their recipient
+    pub fn expire_inbox_items(&mut self, tick: u64) -> Vec<(FactionId, InboxItem)> {
+        self.factions.iter_mut()
+            .flat_map(|faction| {
+                let id = faction.id;
+                faction.inbox.expire(tick).into_iter().map(move |item| (id, item))
+            })
+            .collect()
+    }
+    
+    /// How `faction` stands with `other`
+    pub fn relation(&self, faction: FactionId, other: FactionId) -> GameResult<Relation> {
+        self.get_faction(other)?;
+        Ok(self.get_faction(faction)?.relation(other))
+    }
+    
+    /// Apply a change to both factions' view of their relationship
+    fn update_relations<F>(&mut self, (a, b): (FactionId, FactionId), change: F) -> GameResult<()>
+    where
+        F: Fn(&mut Relation),
+    {
+        for (faction, other) in [(a, b), (b, a)] {
+            let index = *self.faction_index.get(&faction)
+                .ok_or_else(|| GameError::not_found(EntityKind::Faction, faction))?;
+            change(self.factions[index].relations.entry(other).or_default());
+        }
+        Ok(())
+    }
+    
+    /// End treaties whose term is over, returning each once with its parties
+    pub fn expire_treaties(&mut self, tick: u64) -> Vec<((FactionId, FactionId), Treaty)> {
+        let mut expired = Vec::new();
+        for faction in &mut self.factions {
+            for (other, relation) in faction.relations.iter_mut() {
+                if let Some(treaty) = relation.treaty.filter(|treaty| treaty.expires_tick <= tick) {
+                    relation.treaty = None;
+                    if faction.id < *other {
+                        expired.push(((faction.id, *other), treaty));
+                    }
+                }
+            }
+        }
+        expired
+    }
+    
+    pub fn load_factions(&mut self, factions: Vec<Faction>) -> GameResult<()> {
+        // Validate loaded factions
+        for faction in &factions {
+            if faction.name.trim().is_empty() {
+                return Err(GameError::InvalidOperation("Loaded faction has empty name".into()));
+            }
+            if faction.score < 0 {
+                return Err(GameError::InvalidOperation("Loaded faction has negative score".into()));
+            }
+        }
+        
+        // Check for duplicate IDs or names
+        let mut seen_ids = std::collections::HashSet::new();
+        let mut seen_names = std::collections::HashSet::new();
+        for faction in &factions {
+            if !seen_ids.insert(faction.id) {
+                return Err(GameError::InvalidOperation(format!("Duplicate faction ID: {}", faction.id)));
+            }
+            if !seen_names.insert(&faction.name) {
+                return Err(GameError::InvalidOperation(format!("Duplicate faction name: {}", faction.name)));
+            }
+        }
+        
+        // Replace all factions with loaded data
+        self.factions = factions;
+        
+        // Rebuild the index efficiently
+        self.faction_index.clear();
+        self.faction_index.reserve(self.factions.len());
+        for (index, faction) in self.factions.iter().enumerate() {
+            self.faction_index.insert(faction.id, index);
+        }
+        
+        // Update next_id to prevent conflicts
+        self.next_id = self.factions.iter()
+            .map(|f| f.id)
+            .max()
+            .map(|max_id| max_id + 1)
+            .unwrap_or(0);
+        
+        Ok(())
+    }
+    
+    pub fn handle_event(&mut self, event: &GameEvent) -> GameResult<()> {
+        match event {
+            GameEvent::StateChanged(state_change) => {
+                match state_change {
+                    crate::core::events::StateChange::FactionUpdated(faction_id) => {
+                        // Validate that the faction exists after update
+                        self.get_faction(*faction_id)?;
+                        Ok(())
+                    }
+                    crate::core::events::StateChange::GameOver(winner_id) => {
+                        // Verify the winner exists
+                        self.get_faction(*winner_id)?;
+                        Ok(())
+                    }
+                    _ => Ok(())
+                }
+            }
+            GameEvent::SimulationEvent(sim_event) => {
+                match sim_event {
+                    crate::core::events::SimulationEvent::PlanetConquered { new_owner, .. } => {
+                        // Verify the conquering faction exists
+                        self.get_faction(*new_owner)?;
+                        Ok(())
+                    }
+                    crate::core::events::SimulationEvent::TreatySigned { parties, treaty } => {
+                        self.update_relations(*parties, |relation| {
+                            relation.at_war = false;
+                            relation.treaty = Some(*treaty);
+                        })
+                    }
+                    crate::core::events::SimulationEvent::TreatyExpired { parties, treaty } => {
+                        self.update_relations(*parties, |relation| {
+                            if relation.treaty == Some(*treaty) {
+                                relation.treaty = None;
+                            }
+                        })
+                    }
+                    crate::core::events::SimulationEvent::WarDeclared { aggressor, target } => {
+                        self.update_relations((*aggressor, *target), |relation| {
+                            relation.at_war = true;
+                            relation.treaty = None;
+                        })
+                    }
+                    crate::core::events::SimulationEvent::RelationChanged { parties, delta } => {
+                        self.update_relations(*parties, |relation| relation.adjust_score(*delta))
+                    }
+                    crate::core::events::SimulationEvent::ResearchProgressed { faction, technology, points } => {
+                        self.add_research_progress(*faction, *technology, *points).map(|_| ())
+                    }
+                    crate::core::events::SimulationEvent::TechnologyUnlocked { faction, technology } => {
+                        self.unlock_technology(*faction, *technology).map(|_| ())
+                    }
+                    crate::core::events::SimulationEvent::PlanetDiscovered { faction, planet }
+                    | crate::core::events::SimulationEvent::PlanetColonized { faction, planet, .. } => {
+                        self.explore_planet(*faction, *planet).map(|_| ())
+                    }
+                    _ => Ok(())
+                }
+            }
+            GameEvent::PlayerCommand(crate::core::events::PlayerCommand::SetFactionTaxRate { faction, rate }) => {
+                self.set_tax_rate(*faction, *rate)
+            }
+            GameEvent::PlayerCommand(crate::core::events::PlayerCommand::SaveShipDesign { faction, design }) => {
+                self.save_ship_design(*faction, design.clone()).map(|_| ())
+            }
+            GameEvent::PlayerCommand(crate::core::events::PlayerCommand::AssignControlGroup { faction, group, members }) => {
+                self.set_control_group(*faction, *group, members.clone())
+            }
+            _ => Ok(())
+        }
+    }
+}
\ No newline at end of file
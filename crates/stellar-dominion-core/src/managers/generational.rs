@@ -0,0 +1,159 @@
+// src/managers/generational.rs
+//! Generational entity ids
+//!
+//! A `PlanetId` or `ShipId` packs a slot index in its low
+//! [`ID_INDEX_BITS`] bits and the slot's generation above them. When an
+//! entity is removed its slot's generation goes up, so an id held past the
+//! entity's end, by the UI or a queued command, never names whatever takes
+//! the slot next: looking it up fails with `GameError::StaleEntity`. First-generation ids equal their slot
+//! index, so worlds that never remove anything keep ids 0, 1, 2, ...
+
+use crate::core::{EntityKind, GameError, GameResult};
+use std::collections::BTreeSet;
+
+/// Bits of an id holding the slot index; the rest hold the generation
+pub const ID_INDEX_BITS: u32 = 20;
+
+const INDEX_MASK: u32 = (1 << ID_INDEX_BITS) - 1;
+const MAX_GENERATION: u32 = u32::MAX >> ID_INDEX_BITS;
+
+/// Slot index of an id
+pub fn id_index(id: u32) -> u32 {
+    id & INDEX_MASK
+}
+
+/// Generation of an id's slot when the id was handed out
+pub fn id_generation(id: u32) -> u32 {
+    id >> ID_INDEX_BITS
+}
+
+/// Id of the given slot and generation
+pub fn compose_id(index: u32, generation: u32) -> u32 {
+    (generation << ID_INDEX_BITS) | (index & INDEX_MASK)
+}
+
+/// Hands out generational ids for one kind of entity and tells live ids from
+/// stale ones
+#[derive(Debug, Clone)]
+pub struct IdAllocator {
+    kind: EntityKind,
+    // Current generation of every slot ever used, and whether it is taken
+    generations: Vec<u32>,
+    live: Vec<bool>,
+    // Lowest free slot is reused first, so ids do not depend on hashing
+    free: BTreeSet<u32>,
+}
+
+impl IdAllocator {
+    /// Allocator for entities of the kind, which its errors name
+    pub fn new(kind: EntityKind) -> Self {
+        Self {
+            kind,
+            generations: Vec::new(),
+            live: Vec::new(),
+            free: BTreeSet::new(),
+        }
+    }
+
+    /// A new live id, reusing the lowest free slot under its next generation
+    pub fn allocate(&mut self) -> GameResult<u32> {
+        if let Some(index) = self.free.pop_first() {
+            self.live[index as usize] = true;
+            return Ok(compose_id(index, self.generations[index as usize]));
+        }
+        let index = self.generations.len() as u32;
+        if index > INDEX_MASK {
+            return Err(GameError::CapacityExceeded {
+                resource: format!("{} slots", self.kind.to_string().to_lowercase()),
+                requested: 1,
+                available: 0,
+            });
+        }
+        self.generations.push(0);
+        self.live.push(true);
+        Ok(compose_id(index, 0))
+    }
+
+    /// End a live id; its slot comes back under the next generation, unless
+    /// the generations ran out, in which case the slot is retired
+    pub fn release(&mut self, id: u32) -> GameResult<()> {
+        self.check(id)?;
+        let index = id_index(id);
+        self.live[index as usize] = false;
+        if self.generations[index as usize] < MAX_GENERATION {
+            self.generations[index as usize] += 1;
+            self.free.insert(index);
+        }
+        Ok(())
+    }
+
+    /// True when the id names a live entity
+    pub fn is_live(&self, id: u32) -> bool {
+        let index = id_index(id) as usize;
+        self.live.get(index).copied().unwrap_or(false) && self.generations[index] == id_generation(id)
+    }
+
+    /// Ok for a live id; otherwise `GameError::StaleEntity` for an id whose
+    /// entity was removed, or `GameError::EntityNotFound` for one never
+    /// handed out
+    pub fn check(&self, id: u32) -> GameResult<()> {
+        if self.is_live(id) {
+            return Ok(());
+        }
+        match self.generations.get(id_index(id) as usize) {
+            Some(current) if id_generation(id) < *current => Err(GameError::StaleEntity {
+                kind: self.kind,
+                id: id.into(),
+                generation: *current,
+            }),
+            _ => Err(GameError::not_found(self.kind, id)),
+        }
+    }
+
+    /// Allocator with the given ids live, as after loading a save. Free
+    /// slots below the highest index are not reused, since the generations
+    /// handed out before the save are unknown.
+    pub fn from_live(kind: EntityKind, ids: impl IntoIterator<Item = u32>) -> GameResult<Self> {
+        let mut allocator = Self::new(kind);
+        for id in ids {
+            let index = id_index(id) as usize;
+            if index >= allocator.generations.len() {
+                allocator.generations.resize(index + 1, 0);
+                allocator.live.resize(index + 1, false);
+            }
+            if allocator.live[index] {
+                return Err(GameError::InvalidOperation(format!("Duplicate {} slot {} found", kind.to_string().to_lowercase(), index)));
+            }
+            allocator.generations[index] = id_generation(id);
+            allocator.live[index] = true;
+        }
+        Ok(allocator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reused_slots_reject_stale_ids() {
+        let mut ids = IdAllocator::new(EntityKind::Ship);
+        let first = ids.allocate().unwrap();
+        let second = ids.allocate().unwrap();
+        assert_eq!((first, second), (0, 1));
+
+        ids.release(first).unwrap();
+        let reused = ids.allocate().unwrap();
+        assert_eq!((id_index(reused), id_generation(reused)), (0, 1));
+        assert!(ids.is_live(reused) && !ids.is_live(first));
+        assert!(matches!(ids.check(first), Err(GameError::StaleEntity { kind: EntityKind::Ship, id: 0, generation: 1 })));
+        assert!(matches!(ids.check(7), Err(GameError::EntityNotFound { kind: EntityKind::Ship, id: 7 })));
+        assert!(ids.release(first).is_err());
+
+        // Loaded ids stay live and new ones go above them
+        let mut ids = IdAllocator::from_live(EntityKind::Ship, [compose_id(3, 2), 0]).unwrap();
+        assert!(ids.is_live(compose_id(3, 2)) && !ids.is_live(3));
+        assert_eq!(ids.allocate().unwrap(), 4);
+        assert!(IdAllocator::from_live(EntityKind::Ship, [1, compose_id(1, 1)]).is_err());
+    }
+}
@@ -15,6 +15,12 @@
 //! - PlanetManager: Owns planets, handles resource/population/building operations
 //! - ShipManager: Owns ships, handles movement, cargo, and combat interactions
 //! - FactionManager: Owns factions, tracks scores and player relationships
+//!
+//! PlanetManager and ShipManager stamp each entity they change through a
+//! [`ChangeTracker`], so per-tick work and client caches can skip what did
+//! not change. Their ids are generational ([`IdAllocator`]): a slot freed by a
+//! destroyed ship is reused under a new generation, and the old id is
+//! rejected as stale.
 
 /// Planet management implementation providing controlled access to planet data
 pub mod planet_manager;
@@ -22,10 +28,16 @@ pub mod planet_manager;
 pub mod ship_manager;
 /// Faction management implementation tracking player relationships and scores
 pub mod faction_manager;
+/// Per-entity change stamps kept by the planet and ship managers
+pub mod change_tracker;
+/// Generational planet and ship ids that catch stale handles
+pub mod generational;
 
-pub use planet_manager::PlanetManager;
+pub use planet_manager::{PlanetManager, PlanetTransaction};
 pub use ship_manager::ShipManager;
 pub use faction_manager::FactionManager;
+pub use change_tracker::{ChangeStamp, ChangeTracker};
+pub use generational::{IdAllocator, ID_INDEX_BITS, id_index, id_generation, compose_id};
 
 use crate::core::{GameResult, GameEvent};
 
@@ -55,19 +67,21 @@ pub mod validation {
     pub fn validate_name(name: &str, context: &str) -> GameResult<()> {
         let trimmed = name.trim();
         if trimmed.is_empty() {
-            return Err(GameError::InvalidOperation(
-                format!("{} name cannot be empty", context)
-            ));
+            return Err(GameError::invalid_value(format!("{} name", context.to_lowercase()), "cannot be empty"));
         }
         if trimmed.len() > 100 {
-            return Err(GameError::InvalidOperation(
-                format!("{} name too long (max 100 characters)", context)
-            ));
+            return Err(GameError::invalid_value(format!("{} name", context.to_lowercase()), "is too long (max 100 characters)"));
         }
         if trimmed.chars().any(|c| c.is_control() && c != '\t' && c != '\n') {
-            return Err(GameError::InvalidOperation(
-                format!("{} name contains invalid characters", context)
-            ));
+            return Err(GameError::invalid_value(format!("{} name", context.to_lowercase()), "contains invalid characters"));
+        }
+        Ok(())
+    }
+    
+    /// Validates a control group number, 1 to `config::CONTROL_GROUPS`
+    pub fn validate_control_group(group: u8) -> GameResult<()> {
+        if group == 0 || group > crate::config::CONTROL_GROUPS {
+            return Err(GameError::invalid_value("control group", format!("{} is out of bounds (1 to {})", group, crate::config::CONTROL_GROUPS)));
         }
         Ok(())
     }
@@ -75,9 +89,7 @@ pub mod validation {
     /// Validates numeric values to prevent overflow in calculations
     pub fn validate_numeric_bounds(value: i32, min: i32, max: i32, context: &str) -> GameResult<()> {
         if value < min || value > max {
-            return Err(GameError::InvalidOperation(
-                format!("{} value {} out of bounds ({} to {})", context, value, min, max)
-            ));
+            return Err(GameError::invalid_value(context, format!("{} is out of bounds ({} to {})", value, min, max)));
         }
         Ok(())
     }
@@ -103,9 +115,11 @@ pub mod optimization {
     /// Validates collection size to prevent memory exhaustion
     pub fn check_collection_size<T>(collection: &Vec<T>, max_size: usize, entity_type: &str) -> crate::core::GameResult<()> {
         if collection.len() >= max_size {
-            return Err(crate::core::GameError::SystemError(
-                format!("{} collection at maximum capacity ({})", entity_type, max_size)
-            ));
+            return Err(crate::core::GameError::CapacityExceeded {
+                resource: format!("{} slots", entity_type.to_lowercase()),
+                requested: 1,
+                available: 0,
+            });
         }
         Ok(())
     }
@@ -0,0 +1,776 @@
+// src/managers/planet_manager.rs
+use crate::core::{GameResult, GameEvent, GameSystem, SystemContext};
+use crate::core::types::*;
+use crate::core::events::*;
+use super::change_tracker::ChangeTracker;
+use super::generational::IdAllocator;
+use super::validation::validate_name;
+use std::collections::HashMap;
+
+pub struct PlanetManager {
+    planets: Vec<Planet>,
+    ids: IdAllocator,
+    planet_index: HashMap<PlanetId, usize>,
+    // Every change, and the subset that alters what a planet produces
+    changes: ChangeTracker<PlanetId>,
+    production_changes: ChangeTracker<PlanetId>,
+}
+
+impl PlanetManager {
+    pub fn new() -> Self {
+        Self {
+            planets: Vec::with_capacity(100), // Pre-allocate for performance
+            ids: IdAllocator::new(EntityKind::Planet),
+            planet_index: HashMap::with_capacity(100),
+            changes: ChangeTracker::new(),
+            production_changes: ChangeTracker::new(),
+        }
+    }
+    
+    // CRUD Operations
+    // Helper method to get planet index with consistent error handling;
+    // every lookup goes through here, so a stale id gets an error saying so
+    fn get_planet_index(&self, id: PlanetId) -> GameResult<usize> {
+        self.ids.check(id)?;
+        self.planet_index.get(&id)
+            .copied()
+            .ok_or_else(|| GameError::not_found(EntityKind::Planet, id))
+    }
+    
+    fn mark_changed(&mut self, id: PlanetId) {
+        self.changes.mark(id);
+    }
+    
+    // Workers, buildings, happiness and taxes decide production
+    fn mark_production_changed(&mut self, id: PlanetId) {
+        self.changes.mark(id);
+        self.production_changes.mark(id);
+    }
+    
+    pub fn create_planet(&mut self, position: OrbitalElements, controller: Option<FactionId>) -> GameResult<PlanetId> {
+        position.validate()?;
+        let id = self.ids.allocate()?;
+        
+        let planet = Planet {
+            id,
+            position,
+            resources: ResourceStorage {
+                current: ResourceBundle::default(),
+                capacity: BASE_STORAGE_CAPACITY,
+            },
+            population: Demographics::default(),
+            developments: Vec::new(),
+            controller,
+            storage_priority: StoragePriority::default(),
+            power_priority: PowerPriority::default(),
+            size: DEFAULT_PLANET_SIZE,
+            tax_rate: TaxRate::default(),
+            traits: PlanetTraits::default(),
+            system: 0,
+            name: None,
+        };
+        
+        let index = self.planets.len();
+        self.planets.push(planet);
+        self.planet_index.insert(id, index);
+        self.mark_production_changed(id);
+        
+        Ok(id)
+    }
+    
+    pub fn get_planet(&self, id: PlanetId) -> GameResult<&Planet> {
+        Ok(&self.planets[self.get_planet_index(id)?])
+    }
+    
+    // REMOVED: get_planet_mut violates manager pattern
+    // Use specific modification methods instead
+    
+    pub fn get_all_planets(&self) -> &Vec<Planet> {
+        &self.planets
+    }
+    
+    // Get planet count for efficiency (avoid cloning when just need count)
+    pub fn get_planet_count(&self) -> usize {
+        self.planets.len()
+    }
+    
+    // More efficient method when you only need planet IDs
+    pub fn get_all_planet_ids(&self) -> Vec<PlanetId> {
+        self.planets.iter().map(|p| p.id).collect()
+    }
+    
+    pub fn get_all_planets_cloned(&self) -> GameResult<Vec<Planet>> {
+        Ok(self.planets.clone())
+    }
+    
+    // Safe planet modification without exposing mutable references
+    pub fn modify_planet<F>(&mut self, id: PlanetId, modifier: F) -> GameResult<()>
+    where
+        F: FnOnce(&mut Planet) -> GameResult<()>,
+    {
+        let index = self.get_planet_index(id)?;
+        self.mark_production_changed(id);
+        let planet = &mut self.planets[index];
+        modifier(planet)?;
+        
+        // Validate planet state after modification
+        planet.resources.validate()?;
+        planet.population.allocation.validate(planet.population.total)?;
+        
+        Ok(())
+    }
+    
+    /// Change only a planet's stored resources, which leaves its production as it was
+    pub fn modify_resources<F>(&mut self, id: PlanetId, modifier: F) -> GameResult<()>
+    where
+        F: FnOnce(&mut ResourceStorage) -> GameResult<()>,
+    {
+        let index = self.get_planet_index(id)?;
+        self.mark_changed(id);
+        let resources = &mut self.planets[index].resources;
+        modifier(resources)?;
+        resources.validate()
+    }
+    
+    /// Stage several changes to one planet and apply them together; nothing
+    /// changes unless the transaction commits
+    pub fn transaction(&mut self, id: PlanetId) -> GameResult<PlanetTransaction<'_>> {
+        let index = self.get_planet_index(id)?;
+        let staged = self.planets[index].clone();
+        Ok(PlanetTransaction { manager: self, index, staged, production_changed: false })
+    }
+    
+    /// Stamp later changes with the given tick
+    pub fn set_change_tick(&mut self, tick: u64) {
+        self.changes.set_tick(tick);
+        self.production_changes.set_tick(tick);
+    }
+    
+    /// Planets changed at or after `tick`, in id order
+    pub fn changed_since(&self, tick: u64) -> impl Iterator<Item = &Planet> + '_ {
+        self.planets.iter().filter(move |planet| self.changes.changed_since(planet.id, tick))
+    }
+    
+    /// Revision of the latest change to any planet; caches over all planets
+    /// recompute when it moves
+    pub fn revision(&self) -> u64 {
+        self.changes.revision()
+    }
+    
+    /// Revision of the latest change to what the planet produces, 0 if it
+    /// never changed
+    pub fn production_revision(&self, id: PlanetId) -> u64 {
+        self.production_changes.stamp(id).map_or(0, |stamp| stamp.revision)
+    }
+    
+    /// Count every planet as changed, as after a world is built or loaded
+    pub fn mark_all_changed(&mut self) {
+        for id in self.get_all_planet_ids() {
+            self.mark_production_changed(id);
+        }
+    }
+    
+    // Validate all planets for consistency
+    pub fn validate_all_planets(&self) -> GameResult<()> {
+        for planet in &self.planets {
+            Self::validate_planet(planet)?;
+        }
+        Ok(())
+    }
+    
+    fn validate_planet(planet: &Planet) -> GameResult<()> {
+        planet.resources.validate()?;
+        planet.population.allocation.validate(planet.population.total)?;
+        planet.traits.validate()?;
+        
+        // Check building slot constraints
+        let max_slots = planet.building_slots();
+        if planet.developments.len() > max_slots {
+            return Err(GameError::InvalidOperation(
+                format!("Planet {} has {} buildings but only {} slots available", 
+                       planet.id, planet.developments.len(), max_slots)
+            ));
+        }
+        Ok(())
+    }
+    
+    pub fn get_planets_by_faction(&self, faction: FactionId) -> Vec<&Planet> {
+        let mut result = Vec::new();
+        for planet in &self.planets {
+            if planet.controller == Some(faction) {
+                result.push(planet);
+            }
+        }
+        result
+    }
+    
+    // Resource Management
+    pub fn add_resources(&mut self, id: PlanetId, resources: ResourceBundle) -> GameResult<()> {
+        // Validate input resources are non-negative
+        resources.validate_non_negative()?;
+        
+        let index = self.get_planet_index(id)?;
+        self.mark_changed(id);
+        let planet = &mut self.planets[index];
+        
+        // Check for potential overflow before addition
+        if planet.resources.current.minerals.saturating_add(resources.minerals) == i32::MAX ||
+           planet.resources.current.food.saturating_add(resources.food) == i32::MAX ||
+           planet.resources.current.energy.saturating_add(resources.energy) == i32::MAX ||
+           planet.resources.current.alloys.saturating_add(resources.alloys) == i32::MAX ||
+           planet.resources.current.components.saturating_add(resources.components) == i32::MAX ||
+           planet.resources.current.fuel.saturating_add(resources.fuel) == i32::MAX {
+            return Err(GameError::InvalidOperation("Resource addition would cause overflow".into()));
+        }
+        
+        // Use helper method to check if we can store the additional resources
+        if !planet.resources.can_store(&resources) {
+            return Err(GameError::storage_full(&planet.resources, &resources));
+        }
+        
+        // Use ResourceBundle's built-in add method for safer arithmetic
+        planet.resources.current.add(&resources)?;
+        
+        Ok(())
+    }
+    
+    pub fn remove_resources(&mut self, id: PlanetId, resources: ResourceBundle) -> GameResult<()> {
+        // Validate input resources are non-negative
+        resources.validate_non_negative()?;
+        
+        let index = self.get_planet_index(id)?;
+        self.mark_changed(id);
+        let planet = &mut self.planets[index];
+        
+        // Use ResourceBundle's built-in subtract method which includes affordability check
+        planet.resources.current.subtract(&resources)?;
+        Ok(())
+    }
+    
+    /// Store a planet's production up to its storage and, when it can pay,
+    /// take its upkeep, as one change. Returns the production stored.
+    pub fn store_production(&mut self, id: PlanetId, production: &ResourceBundle, upkeep: &ResourceBundle, upkeep_paid: bool) -> GameResult<ResourceBundle> {
+        let mut transaction = self.transaction(id)?;
+        let stored = transaction.store_up_to_capacity(production)?;
+        if upkeep_paid {
+            transaction.remove_resources(upkeep)?;
+        }
+        transaction.commit()?;
+        Ok(stored)
+    }
+    
+    /// Exchange goods between two factions' capitals, their first planets.
+    /// A trade neither side can still pay for or store lapses without
+    /// effect; returns whether the goods changed hands.
+    pub fn exchange_between_capitals(&mut self, from: FactionId, to: FactionId, offered: ResourceBundle, requested: ResourceBundle) -> GameResult<bool> {
+        let capital = |faction| self.get_planets_by_faction(faction).first().map(|planet| planet.id);
+        let (Some(from_capital), Some(to_capital)) = (capital(from), capital(to)) else {
+            return Ok(false);
+        };
+        let from_planet = self.get_planet(from_capital)?;
+        let to_planet = self.get_planet(to_capital)?;
+        let affordable = from_planet.resources.current.can_afford(&offered)
+            && to_planet.resources.current.can_afford(&requested);
+        let storable = to_planet.resources.can_store(&offered)
+            && from_planet.resources.can_store(&requested);
+        if !affordable || !storable {
+            return Ok(false);
+        }
+        
+        self.remove_resources(from_capital, offered)?;
+        self.remove_resources(to_capital, requested)?;
+        self.add_resources(to_capital, offered)?;
+        self.add_resources(from_capital, requested)?;
+        Ok(true)
+    }
+    
+    /// Deliver a reward to the faction's lowest numbered planet, as much of
+    /// it as fits in storage
+    pub fn deliver_reward(&mut self, faction: FactionId, reward: &ResourceBundle) -> GameResult<()> {
+        let Some(planet) = self.get_planets_by_faction(faction).into_iter().min_by_key(|planet| planet.id) else {
+            return Ok(());
+        };
+        let space = planet.resources.available_space();
+        let mut delivered = ResourceBundle::default();
+        for resource in ResourceType::ALL {
+            delivered.set(resource, reward.get(resource).min(space.get(resource).max(0)));
+        }
+        let id = planet.id;
+        self.add_resources(id, delivered)
+    }
+    
+    // Population Management
+    pub fn update_population(&mut self, id: PlanetId, amount: i32) -> GameResult<()> {
+        let index = self.get_planet_index(id)?;
+        self.mark_production_changed(id);
+        let planet = &mut self.planets[index];
+        
+        // Check for overflow before addition
+        let new_total = planet.population.total.saturating_add(amount);
+        
+        if new_total < 0 {
+            return Err(GameError::invalid_value("population", "cannot be negative"));
+        }
+        
+        if new_total == i32::MAX && amount > 0 {
+            return Err(GameError::InvalidOperation("Population update would cause overflow".into()));
+        }
+        
+        // Growth joins the unassigned pool, and decline leaves it first, so
+        // existing worker assignments are kept
+        if planet.population.allocation.validate(planet.population.total).is_ok()
+            && planet.population.allocation.unassigned.saturating_add(amount) >= 0
+        {
+            planet.population.allocation.unassigned += amount;
+        }
+        planet.population.total = new_total;
+        
+        // If population changed, we may need to adjust worker allocation to stay valid
+        if planet.population.allocation.validate(new_total).is_err() {
+            // Reset allocation to all unassigned workers
+            planet.population.allocation = WorkerAllocation {
+                agriculture: 0,
+                mining: 0,
+                industry: 0,
+                research: 0,
+                military: 0,
+                unassigned: new_total,
+            };
+        }
+        
+        Ok(())
+    }
+    
+    /// Take military workers off a planet, as departing troops or garrison losses
+    pub fn remove_military(&mut self, id: PlanetId, amount: i32) -> GameResult<()> {
+        if amount < 0 {
+            return Err(GameError::invalid_value("military workers removed", "cannot be negative"));
+        }
+        let index = self.get_planet_index(id)?;
+        self.mark_production_changed(id);
+        let population = &mut self.planets[index].population;
+        if population.allocation.military < amount {
+            return Err(GameError::capacity("military workers", amount, population.allocation.military));
+        }
+        population.allocation.military -= amount;
+        population.total -= amount;
+        Ok(())
+    }
+    
+    /// Take a round of ground combat losses off the garrison; workers moved
+    /// off military duty during the fight are spared
+    pub fn remove_ground_losses(&mut self, id: PlanetId, losses: i32) -> GameResult<()> {
+        let military = self.get_planet(id)?.population.allocation.military;
+        self.remove_military(id, losses.min(military))
+    }
+    
+    /// Tear down the struck building, or kill population, military workers
+    /// first so the garrison is weakened for an invasion. A building strike
+    /// on a planet without buildings kills `casualties_without_buildings`.
+    pub fn apply_bombardment_strike(&mut self, id: PlanetId, strike: BombardmentStrike, casualties_without_buildings: i32) -> GameResult<()> {
+        let buildings = self.get_planet(id)?.developments.len();
+        let casualties = match strike {
+            BombardmentStrike::Building { pick } if buildings > 0 => {
+                self.remove_building(id, pick as usize % buildings)?;
+                0
+            }
+            BombardmentStrike::Building { .. } => casualties_without_buildings,
+            BombardmentStrike::Population { casualties } => casualties,
+        };
+        let population = &self.get_planet(id)?.population;
+        let military = casualties.min(population.allocation.military);
+        let civilians = (casualties - military).min(population.total - military);
+        self.remove_military(id, military)?;
+        if civilians > 0 {
+            self.update_population(id, -civilians)?;
+        }
+        Ok(())
+    }
+    
+    /// Settle arriving troops on a planet as military workers
+    pub fn add_military(&mut self, id: PlanetId, amount: i32) -> GameResult<()> {
+        if amount < 0 {
+            return Err(GameError::invalid_value("military workers added", "cannot be negative"));
+        }
+        let index = self.get_planet_index(id)?;
+        self.mark_production_changed(id);
+        let population = &mut self.planets[index].population;
+        let total = population.total.checked_add(amount)
+            .ok_or_else(|| GameError::InvalidOperation("Population update would cause overflow".into()))?;
+        population.allocation.military += amount;
+        population.total = total;
+        Ok(())
+    }
+    
+    /// Hand an unclaimed planet to the faction settling it, with its first
+    /// colonists and whatever of their supplies fit in storage
+    pub fn found_colony(&mut self, id: PlanetId, faction: FactionId, colonists: i32, supplies: ResourceBundle) -> GameResult<()> {
+        supplies.validate_non_negative()?;
+        let planet = self.get_planet(id)?;
+        if let Some(owner) = planet.controller {
+            return Err(GameError::InvalidTarget(format!("Planet {} is already held by faction {}", id, owner)));
+        }
+        let space = planet.resources.available_space();
+        let mut stored = ResourceBundle::default();
+        for resource in ResourceType::ALL {
+            stored.set(resource, supplies.get(resource).min(space.get(resource)).max(0));
+        }
+        
+        self.update_population(id, colonists)?;
+        self.add_resources(id, stored)?;
+        self.change_controller(id, Some(faction))
+    }
+    
+    pub fn set_worker_allocation(&mut self, id: PlanetId, allocation: WorkerAllocation) -> GameResult<()> {
+        let index = self.get_planet_index(id)?;
+        self.mark_production_changed(id);
+        let planet = &mut self.planets[index];
+        
+        // Validate allocation matches total population
+        allocation.validate(planet.population.total)?;
+        
+        // Ensure minimum 10% unassigned workers using integer arithmetic
+        let min_unassigned = planet.population.total / 10; // Integer division gives floor
+        if allocation.unassigned < min_unassigned {
+            return Err(GameError::invalid_value("worker allocation", format!(
+                "must keep at least {} unassigned workers (10% of {})", min_unassigned, planet.population.total
+            )));
+        }
+        
+        planet.population.allocation = allocation;
+        Ok(())
+    }
+
+    // Storage Settings
+    /// Set which resources the planet keeps, in what order, and their caps
+    pub fn set_storage_priority(&mut self, id: PlanetId, priority: StoragePriority) -> GameResult<()> {
+        priority.validate()?;
+        let index = self.get_planet_index(id)?;
+        self.mark_changed(id);
+        self.planets[index].storage_priority = priority;
+        Ok(())
+    }
+
+    /// Set the order the planet's energy grid powers its buildings in
+    pub fn set_power_priority(&mut self, id: PlanetId, priority: PowerPriority) -> GameResult<()> {
+        priority.validate()?;
+        let index = self.get_planet_index(id)?;
+        self.mark_changed(id);
+        self.planets[index].power_priority = priority;
+        Ok(())
+    }
+
+    /// Name the planet, trimmed; fails if `validate_name` rejects the name
+    pub fn rename_planet(&mut self, id: PlanetId, name: &str) -> GameResult<()> {
+        validate_name(name, "Planet")?;
+        let index = self.get_planet_index(id)?;
+        self.mark_changed(id);
+        self.planets[index].name = Some(name.trim().to_string());
+        Ok(())
+    }
+
+    /// Set the planet's tax rate, which changes its productivity
+    pub fn set_tax_rate(&mut self, id: PlanetId, rate: TaxRate) -> GameResult<()> {
+        let index = self.get_planet_index(id)?;
+        self.mark_production_changed(id);
+        self.planets[index].tax_rate = rate;
+        Ok(())
+    }
+
+    /// Set the planet's happiness, clamped to -1.0..=1.0
+    pub fn set_happiness(&mut self, id: PlanetId, happiness: f32) -> GameResult<()> {
+        if !happiness.is_finite() {
+            return Err(GameError::invalid_value("happiness", format!("must be finite, got {}", happiness)));
+        }
+        let index = self.get_planet_index(id)?;
+        self.mark_production_changed(id);
+        self.planets[index].population.happiness = happiness.clamp(-1.0, 1.0);
+        Ok(())
+    }
+
+    // Building Management
+    pub fn add_building(&mut self, id: PlanetId, building_type: BuildingType) -> GameResult<()> {
+        let index = self.get_planet_index(id)?;
+        
+        // Calculate slots before getting mutable reference
+        let available_slots = self.planets[index].building_slots();
+        let current_buildings = self.planets[index].developments.len();
+        
+        if current_buildings >= available_slots {
+            return Err(GameError::capacity("building slots", 1u64, 0u64));
+        }
+        
+        let building = Building::new(building_type, 1);
+        
+        self.planets[index].developments.push(building);
+        self.refresh_storage(index);
+        self.mark_production_changed(id);
+        Ok(())
+    }
+    
+    /// Switch the building at `index` in the planet's developments on, or
+    /// shut it down for a reason. Returns whether its status changed.
+    pub fn set_building_status(&mut self, id: PlanetId, index: usize, status: BuildingStatus) -> GameResult<bool> {
+        let planet_index = self.get_planet_index(id)?;
+        let building = self.planets[planet_index].developments.get_mut(index)
+            .ok_or_else(|| GameError::InvalidTarget(format!("Planet {} has no building at {}", id, index)))?;
+        if building.status == status {
+            return Ok(false);
+        }
+        building.status = status;
+        self.refresh_storage(planet_index);
+        self.mark_production_changed(id);
+        Ok(true)
+    }
+    
+    /// Tear down the building at `index` in the planet's developments
+    pub fn remove_building(&mut self, id: PlanetId, index: usize) -> GameResult<Building> {
+        let planet_index = self.get_planet_index(id)?;
+        let developments = &mut self.planets[planet_index].developments;
+        if index >= developments.len() {
+            return Err(GameError::InvalidTarget(format!(
+                "Planet {} has {} buildings, none at {}", id, developments.len(), index
+            )));
+        }
+        let building = developments.remove(index);
+        self.refresh_storage(planet_index);
+        self.mark_production_changed(id);
+        Ok(building)
+    }
+    
+    pub fn get_building_count(&self, id: PlanetId, building_type: BuildingType) -> GameResult<usize> {
+        let planet = self.get_planet(id)?;
+        let mut count = 0;
+        for building in &planet.developments {
+            if building.building_type == building_type {
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+    
+    pub fn get_available_building_slots(&self, id: PlanetId) -> GameResult<usize> {
+        let planet = self.get_planet(id)?;
+        let max_slots = planet.building_slots();
+        Ok(max_slots.saturating_sub(planet.developments.len()))
+    }
+    
+    /// Planets the faction's sensors see right now
+    pub fn get_planets_visible_to(&self, visibility: &FactionVisibility) -> Vec<&Planet> {
+        self.planets.iter().filter(|planet| visibility.can_see_planet(planet.id)).collect()
+    }
+    
+    /// Planets the faction has ever seen
+    pub fn get_planets_explored_by(&self, faction: &Faction) -> Vec<&Planet> {
+        self.planets.iter().filter(|planet| faction.has_explored(planet.id)).collect()
+    }
+    
+    // Planet Control
+    pub fn change_controller(&mut self, id: PlanetId, new_controller: Option<FactionId>) -> GameResult<()> {
+        let index = self.get_planet_index(id)?;
+        self.mark_changed(id);
+        self.planets[index].controller = new_controller;
+        Ok(())
+    }
+    
+    /// Set the planet's capacity from its buildings; stock above the new
+    /// capacity is lost
+    fn refresh_storage(&mut self, index: usize) {
+        let planet = &mut self.planets[index];
+        planet.resources.capacity = planet.storage_capacity();
+        for resource in ResourceType::ALL {
+            let kept = planet.resources.current.get(resource).min(planet.resources.capacity.get(resource));
+            planet.resources.current.set(resource, kept);
+        }
+    }
+    
+    pub fn load_planets(&mut self, planets: Vec<Planet>) -> GameResult<()> {
+        // Ids held from before the load are not recognized as live
+        let ids = IdAllocator::from_live(EntityKind::Planet, planets.iter().map(|planet| planet.id))?;
+        
+        // Replace all planets with loaded data
+        self.planets = planets;
+        self.ids = ids;
+        
+        // Rebuild the index
+        self.planet_index.clear();
+        for (index, planet) in self.planets.iter().enumerate() {
+            self.planet_index.insert(planet.id, index);
+        }
+        
+        // Capacity follows the buildings, whatever the data said
+        for index in 0..self.planets.len() {
+            self.refresh_storage(index);
+        }
+        
+        // Every planet is new to anyone caching the old ones
+        self.changes.clear();
+        self.production_changes.clear();
+        self.mark_all_changed();
+            
+        Ok(())
+    }
+}
+
+/// Changes to one planet staged on a copy of it. Reads see the staged
+/// planet; `commit` validates it and writes it back in one step, and
+/// dropping the transaction discards it.
+pub struct PlanetTransaction<'a> {
+    manager: &'a mut PlanetManager,
+    index: usize,
+    staged: Planet,
+    production_changed: bool,
+}
+
+impl<'a> PlanetTransaction<'a> {
+    /// The planet with the changes staged so far
+    pub fn planet(&self) -> &Planet {
+        &self.staged
+    }
+    
+    /// Stage adding resources; the stockpile may not exceed capacity by commit
+    pub fn add_resources(&mut self, resources: &ResourceBundle) -> GameResult<()> {
+        resources.validate_non_negative()?;
+        self.staged.resources.current.add(resources)
+    }
+    
+    /// Stage adding as much of the resources as the planet's storage and
+    /// storage priority allow, returning what was kept
+    pub fn store_up_to_capacity(&mut self, resources: &ResourceBundle) -> GameResult<ResourceBundle> {
+        resources.validate_non_negative()?;
+        let stored = self.staged.storage_priority.apply(resources, &self.staged.resources);
+        self.staged.resources.current.add(&stored)?;
+        Ok(stored)
+    }
+    
+    /// Stage removing resources, which must be in the staged stockpile
+    pub fn remove_resources(&mut self, resources: &ResourceBundle) -> GameResult<()> {
+        resources.validate_non_negative()?;
+        self.staged.resources.current.subtract(resources)
+    }
+    
+    /// Stage any other change; the planet's production counts as changed
+    pub fn modify<F>(&mut self, modifier: F) -> GameResult<()>
+    where
+        F: FnOnce(&mut Planet) -> GameResult<()>,
+    {
+        self.production_changed = true;
+        modifier(&mut self.staged)
+    }
+    
+    /// Validate the staged planet and apply every change at once, returning
+    /// the planet as committed
+    ///
+    /// # Errors
+    ///
+    /// Returns the validation error and leaves the planet as it was if the
+    /// staged planet is inconsistent.
+    pub fn commit(self) -> GameResult<&'a Planet> {
+        let Self { manager, index, staged, production_changed } = self;
+        if staged.id != manager.planets[index].id {
+            return Err(GameError::InvalidOperation("A transaction cannot change a planet's id".into()));
+        }
+        PlanetManager::validate_planet(&staged)?;
+        if production_changed {
+            manager.mark_production_changed(staged.id);
+        } else {
+            manager.mark_changed(staged.id);
+        }
+        manager.planets[index] = staged;
+        Ok(&manager.planets[index])
+    }
+}
+
+impl GameSystem for PlanetManager {
+    fn update(&mut self, _delta: f32, _context: &mut SystemContext<'_>) -> GameResult<()> {
+        // PlanetManager is primarily reactive, no periodic updates needed
+        Ok(())
+    }
+    
+    fn handle_event(&mut self, event: &GameEvent) -> GameResult<()> {
+        match event {
+            GameEvent::PlayerCommand(cmd) => {
+                match cmd {
+                    PlayerCommand::BuildStructure { planet, building_type } => {
+                        self.add_building(*planet, *building_type)?;
+                    }
+                    PlayerCommand::AllocateWorkers { planet, allocation } => {
+                        self.set_worker_allocation(*planet, allocation.clone())?;
+                    }
+                    PlayerCommand::SetStoragePriority { planet, priority } => {
+                        self.set_storage_priority(*planet, priority.clone())?;
+                    }
+                    PlayerCommand::SetPowerPriority { planet, priority } => {
+                        self.set_power_priority(*planet, priority.clone())?;
+                    }
+                    PlayerCommand::SetTaxRate { planet, rate } => {
+                        self.set_tax_rate(*planet, *rate)?;
+                    }
+                    PlayerCommand::RenameEntity { target: NamedEntity::Planet(planet), name } => {
+                        self.rename_planet(*planet, name)?;
+                    }
+                    _ => {} // Ignore other commands
+                }
+            }
+            GameEvent::SimulationEvent(sim_event) => {
+                match sim_event {
+                    SimulationEvent::ConstructionCompleted { planet, building } => {
+                        // Building is already added by construction system,
+                        // just validate it was successful
+                        let _current_count = self.get_building_count(*planet, *building)?;
+                    }
+                    SimulationEvent::PopulationGrowth { planet, amount } => {
+                        // Growth never pushes a planet past its housing cap,
+                        // and decline never takes it below zero
+                        let planet_data = self.get_planet(*planet)?;
+                        let amount = (*amount)
+                            .min(planet_data.housing_space())
+                            .max(-planet_data.population.total);
+                        if amount != 0 {
+                            self.update_population(*planet, amount)?;
+                        }
+                    }
+                    SimulationEvent::HappinessChanged { planet, happiness } => {
+                        self.set_happiness(*planet, *happiness)?;
+                    }
+                    SimulationEvent::PopulationMigrated { from, to, amount } => {
+                        // Only as many settlers move as the source has and the destination can house
+                        let amount = (*amount)
+                            .min(self.get_planet(*from)?.population.total)
+                            .min(self.get_planet(*to)?.housing_space());
+                        if amount > 0 {
+                            self.update_population(*from, -amount)?;
+                            self.update_population(*to, amount)?;
+                        }
+                    }
+                    SimulationEvent::PlanetConquered { planet, new_owner } => {
+                        self.change_controller(*planet, Some(*new_owner))?;
+                    }
+                    _ => {} // Ignore other simulation events
+                }
+            }
+            GameEvent::StateChanged(state_change) => {
+                match state_change {
+                    StateChange::PlanetUpdated(planet_id) => {
+                        // Validate planet still exists and is in valid state
+                        self.get_planet(*planet_id)?;
+                        self.mark_changed(*planet_id);
+                        let planet = self.get_planet(*planet_id)?;
+                        planet.resources.validate()?;
+                        planet.population.allocation.validate(planet.population.total)?;
+                        
+                        // Validate building constraints
+                        let max_slots = planet.building_slots();
+                        if planet.developments.len() > max_slots {
+                            return Err(GameError::InvalidOperation(
+                                format!("Planet {} exceeds building slot limit", planet_id)
+                            ));
+                        }
+                    }
+                    _ => {} // Ignore other state changes
+                }
+            }
+        }
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,398 @@
+// src/net.rs
+//! Deterministic lockstep sessions over the command protocol
+//!
+//! Every peer runs the whole simulation; only commands travel. A player's
+//! commands are scheduled [`INPUT_DELAY_TICKS`] ahead of the tick being
+//! played, so they have time to reach everyone. Each tick every player sends
+//! the host its [`NetMessage::Input`] for that tick, even when it is empty.
+//! Once the host holds every player's input for a tick it relays them to all
+//! peers as one [`NetMessage::Bundle`], and a peer plays a tick only when it
+//! holds that tick's bundle. Peers that start from the same configuration
+//! therefore run the same commands at the same ticks and stay in step.
+//!
+//! The host only trusts a message for the player whose link it came in on:
+//! input or a hash claiming to be from anyone else, or input carrying another
+//! player's commands, is dropped.
+//!
+//! Every [`HASH_INTERVAL_TICKS`] ticks each client sends the host a hash of
+//! its state. A hash that differs from the host's marks the session out of
+//! step; the host tells every peer with [`NetMessage::Desync`] and no one
+//! plays on.
+//!
+//! Messages go through a [`Transport`], so a game can run over sockets while
+//! tests use the in-memory [`ChannelTransport`].
+
+use crate::core::{GameState, GameResult, PlayerCommand, CommandEnvelope, RemoteCommandQueue, to_wire, from_wire};
+use crate::core::types::*;
+use serde::{Serialize, Deserialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::mpsc::{channel, Receiver, Sender, TryRecvError};
+
+/// Ticks between issuing a command and the tick it runs at
+pub const INPUT_DELAY_TICKS: u64 = 3;
+/// Ticks between state hash checks
+pub const HASH_INTERVAL_TICKS: u64 = 50;
+
+/// What lockstep peers say to each other
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum NetMessage {
+    /// A player's commands for a tick, possibly none, sent to the host
+    Input {
+        /// Player sending the commands
+        player: FactionId,
+        /// Tick the commands run at
+        tick: u64,
+        /// The player's commands in sequence order
+        commands: Vec<CommandEnvelope>,
+    },
+    /// Every player's commands for a tick, relayed by the host
+    Bundle {
+        /// Tick the commands run at
+        tick: u64,
+        /// Commands in player, then sequence, order
+        commands: Vec<CommandEnvelope>,
+    },
+    /// A client's state hash once it has played a tick
+    StateHash {
+        /// Player whose state was hashed
+        player: FactionId,
+        /// Tick just played
+        tick: u64,
+        /// `GameState::compute_state_hash` after that tick
+        hash: u64,
+    },
+    /// The host found a player out of step after a tick
+    Desync {
+        /// Player whose hash differed from the host's
+        player: FactionId,
+        /// Tick after which the hashes were compared
+        tick: u64,
+    },
+}
+
+/// Carries messages between one peer and another
+pub trait Transport {
+    /// Send a message to the other end
+    fn send(&mut self, message: &NetMessage) -> GameResult<()>;
+    /// Next message from the other end, if one has arrived
+    fn receive(&mut self) -> GameResult<Option<NetMessage>>;
+}
+
+/// In-memory transport that sends messages in their wire format
+pub struct ChannelTransport {
+    outgoing: Sender<String>,
+    incoming: Receiver<String>,
+}
+
+impl ChannelTransport {
+    /// Two connected ends
+    pub fn pair() -> (Self, Self) {
+        let (to_b, from_a) = channel();
+        let (to_a, from_b) = channel();
+        (Self { outgoing: to_b, incoming: from_b }, Self { outgoing: to_a, incoming: from_a })
+    }
+}
+
+impl Transport for ChannelTransport {
+    fn send(&mut self, message: &NetMessage) -> GameResult<()> {
+        self.outgoing.send(to_wire(message)?)
+            .map_err(|_| GameError::Network("Peer disconnected".to_string()))
+    }
+
+    fn receive(&mut self) -> GameResult<Option<NetMessage>> {
+        match self.incoming.try_recv() {
+            Ok(wire) => from_wire(&wire).map(Some),
+            Err(TryRecvError::Empty) => Ok(None),
+            Err(TryRecvError::Disconnected) => Err(GameError::Network("Peer disconnected".to_string())),
+        }
+    }
+}
+
+/// A player found out of step
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Desync {
+    /// Player whose state differed from the host's
+    pub player: FactionId,
+    /// Tick after which the states were compared
+    pub tick: u64,
+}
+
+/// The host's state hash for a tick and the peers yet to send theirs
+struct TickHash {
+    hash: u64,
+    waiting: BTreeSet<FactionId>,
+}
+
+enum Role<T: Transport> {
+    Host {
+        peers: Vec<(FactionId, T)>,
+        /// Inputs received for ticks not yet bundled, by the peer they came from
+        inputs: BTreeMap<u64, BTreeMap<FactionId, Vec<CommandEnvelope>>>,
+        next_bundle: u64,
+        /// The host's own hashes until every peer has matched them, and
+        /// clients' hashes awaiting one
+        hashes: BTreeMap<u64, TickHash>,
+        peer_hashes: BTreeMap<(u64, FactionId), u64>,
+    },
+    Client {
+        host: T,
+    },
+}
+
+/// One player's end of a lockstep game
+pub struct LockstepSession<T: Transport> {
+    player: FactionId,
+    role: Role<T>,
+    /// Commands issued locally and not yet sent
+    local: Vec<PlayerCommand>,
+    next_seq: u64,
+    /// First tick this player has not sent input for
+    next_input: u64,
+    /// Bundles received for ticks not yet played
+    bundles: BTreeMap<u64, Vec<CommandEnvelope>>,
+    /// Commands of the bundle being played, until the game reaches their tick
+    remote: RemoteCommandQueue,
+    desync: Option<Desync>,
+}
+
+impl<T: Transport> LockstepSession<T> {
+    /// The host's session, relaying for clients of the given players
+    pub fn host(player: FactionId, peers: Vec<(FactionId, T)>) -> Self {
+        Self::new(player, Role::Host {
+            peers,
+            inputs: BTreeMap::new(),
+            next_bundle: 0,
+            hashes: BTreeMap::new(),
+            peer_hashes: BTreeMap::new(),
+        })
+    }
+
+    /// A client's session, connected to the host
+    pub fn client(player: FactionId, host: T) -> Self {
+        Self::new(player, Role::Client { host })
+    }
+
+    fn new(player: FactionId, role: Role<T>) -> Self {
+        Self {
+            player,
+            role,
+            local: Vec::new(),
+            next_seq: 0,
+            // Nobody can have commands for the first ticks
+            next_input: INPUT_DELAY_TICKS,
+            bundles: BTreeMap::new(),
+            remote: RemoteCommandQueue::new(),
+            desync: None,
+        }
+    }
+
+    /// Faction this session plays
+    pub fn player(&self) -> FactionId {
+        self.player
+    }
+
+    /// Queue a command from this player; it runs `INPUT_DELAY_TICKS` ticks
+    /// from now on every peer
+    pub fn issue(&mut self, command: PlayerCommand) {
+        self.local.push(command);
+    }
+
+    /// The first player found out of step, if any
+    pub fn desync(&self) -> Option<Desync> {
+        self.desync
+    }
+
+    /// Exchange messages and play the next tick if every player's input for
+    /// it is in. Returns whether a tick was played.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::Network` once the session is out of step or a
+    /// peer disconnects, or the first error raised while playing the tick.
+    pub fn advance(&mut self, game: &mut GameState) -> GameResult<bool> {
+        self.receive()?;
+        self.send_input(game.get_current_tick())?;
+        self.release_bundles()?;
+        if let Some(desync) = self.desync {
+            return Err(GameError::Network(format!(
+                "Faction {} is out of step after tick {}", desync.player, desync.tick
+            )));
+        }
+
+        let tick = game.get_current_tick();
+        let Some(commands) = self.bundles.remove(&tick) else {
+            return Ok(false);
+        };
+        for envelope in commands {
+            self.remote.push(envelope, tick)?;
+        }
+        self.remote.issue_due(game);
+        game.step_ticks(1)?;
+
+        let played = game.get_current_tick();
+        if played.is_multiple_of(HASH_INTERVAL_TICKS) {
+            let hash = game.compute_state_hash()?;
+            self.share_hash(played, hash)?;
+        }
+        Ok(true)
+    }
+
+    /// Handle every message that has arrived
+    fn receive(&mut self) -> GameResult<()> {
+        match &mut self.role {
+            Role::Host { peers, inputs, next_bundle, hashes, peer_hashes } => {
+                let mut found = None;
+                for (peer, transport) in peers.iter_mut() {
+                    let peer = *peer;
+                    while let Some(message) = transport.receive()? {
+                        match message {
+                            NetMessage::Input { player, tick, commands } => {
+                                if player != peer || commands.iter().any(|envelope| envelope.player != peer) {
+                                    log::warn!("Dropped input for faction {} sent by faction {}", player, peer);
+                                } else if tick >= *next_bundle {
+                                    // A player's first input for a tick is the one that counts
+                                    inputs.entry(tick).or_default().entry(peer).or_insert(commands);
+                                }
+                            }
+                            NetMessage::StateHash { player, .. } if player != peer => {
+                                log::warn!("Dropped state hash for faction {} sent by faction {}", player, peer);
+                            }
+                            NetMessage::StateHash { tick, hash, .. } => match hashes.get_mut(&tick) {
+                                Some(own) => {
+                                    if own.hash != hash {
+                                        found = found.or(Some(Desync { player: peer, tick }));
+                                    }
+                                    own.waiting.remove(&peer);
+                                    if own.waiting.is_empty() {
+                                        hashes.remove(&tick);
+                                    }
+                                }
+                                None => {
+                                    peer_hashes.insert((tick, peer), hash);
+                                }
+                            },
+                            _ => {}
+                        }
+                    }
+                }
+                if let Some(desync) = found {
+                    self.report_desync(desync)?;
+                }
+            }
+            Role::Client { host } => {
+                while let Some(message) = host.receive()? {
+                    match message {
+                        NetMessage::Bundle { tick, commands } => {
+                            self.bundles.insert(tick, commands);
+                        }
+                        NetMessage::Desync { player, tick } => {
+                            self.desync.get_or_insert(Desync { player, tick });
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Send this player's input for every tick up to `INPUT_DELAY_TICKS`
+    /// past the last completed one; local commands go in the first
+    fn send_input(&mut self, current_tick: u64) -> GameResult<()> {
+        while self.next_input <= current_tick + INPUT_DELAY_TICKS {
+            let tick = self.next_input;
+            let commands: Vec<CommandEnvelope> = self.local.drain(..)
+                .map(|command| {
+                    self.next_seq += 1;
+                    CommandEnvelope { tick, player: self.player, seq: self.next_seq, command }
+                })
+                .collect();
+            match &mut self.role {
+                Role::Host { inputs, .. } => {
+                    inputs.entry(tick).or_default().insert(self.player, commands);
+                }
+                Role::Client { host } => {
+                    host.send(&NetMessage::Input { player: self.player, tick, commands })?;
+                }
+            }
+            self.next_input += 1;
+        }
+        Ok(())
+    }
+
+    /// On the host, bundle and relay every tick all players have sent input for
+    fn release_bundles(&mut self) -> GameResult<()> {
+        let Role::Host { peers, inputs, next_bundle, .. } = &mut self.role else {
+            return Ok(());
+        };
+        loop {
+            let tick = *next_bundle;
+            let commands = if tick < INPUT_DELAY_TICKS {
+                Vec::new()
+            } else {
+                let received = inputs.get(&tick).map_or(0, |players| players.len());
+                if received < peers.len() + 1 {
+                    return Ok(());
+                }
+                // Player order, then each player's sequence order
+                inputs.remove(&tick).unwrap_or_default().into_values().flatten().collect()
+            };
+            let bundle = NetMessage::Bundle { tick, commands: commands.clone() };
+            for (_, transport) in peers.iter_mut() {
+                transport.send(&bundle)?;
+            }
+            self.bundles.insert(tick, commands);
+            *next_bundle += 1;
+        }
+    }
+
+    /// Send a client's hash to the host, or check the host's own against
+    /// the clients' that came in first
+    fn share_hash(&mut self, tick: u64, hash: u64) -> GameResult<()> {
+        let player = self.player;
+        let found = match &mut self.role {
+            Role::Client { host } => {
+                host.send(&NetMessage::StateHash { player, tick, hash })?;
+                None
+            }
+            Role::Host { peers, hashes, peer_hashes, .. } => {
+                let early: Vec<(FactionId, u64)> = peer_hashes.iter()
+                    .filter(|((at, _), _)| *at == tick)
+                    .map(|((_, player), hash)| (*player, *hash))
+                    .collect();
+                peer_hashes.retain(|(at, _), _| *at != tick);
+                // Kept only until the remaining peers have been checked against it
+                let waiting: BTreeSet<FactionId> = peers.iter()
+                    .map(|(peer, _)| *peer)
+                    .filter(|peer| early.iter().all(|(player, _)| player != peer))
+                    .collect();
+                if !waiting.is_empty() {
+                    hashes.insert(tick, TickHash { hash, waiting });
+                }
+                early.into_iter()
+                    .find(|(_, theirs)| *theirs != hash)
+                    .map(|(player, _)| Desync { player, tick })
+            }
+        };
+        match found {
+            Some(desync) => self.report_desync(desync),
+            None => Ok(()),
+        }
+    }
+
+    /// Record the first desync and, on the host, tell every peer
+    fn report_desync(&mut self, desync: Desync) -> GameResult<()> {
+        if self.desync.is_some() {
+            return Ok(());
+        }
+        self.desync = Some(desync);
+        if let Role::Host { peers, .. } = &mut self.role {
+            let message = NetMessage::Desync { player: desync.player, tick: desync.tick };
+            for (_, transport) in peers.iter_mut() {
+                transport.send(&message)?;
+            }
+        }
+        Ok(())
+    }
+}
+
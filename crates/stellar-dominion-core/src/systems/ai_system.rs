@@ -0,0 +1,988 @@
+// src/systems/ai_system.rs
+//! Computer-controlled factions
+//!
+//! Each AI faction picks builds, colony targets and attacks from its
+//! personality and emits the same commands a player would.
+use crate::core::{GameResult, GameEvent, EventBus, GameSystem, SystemContext, GameState};
+use crate::core::types::*;
+use crate::core::events::*;
+use crate::systems::combat_resolver::GARRISON_STRENGTH_PERCENT;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+/// Distance at which a ship counts as already being at a planet
+const ARRIVAL_DISTANCE: f32 = 0.5;
+/// Fuel assumed per unit of travel when checking a move; above every class's real rate
+const FUEL_PER_DISTANCE: f32 = 1.0 / 50.0;
+/// Warships a Balanced faction gathers before it goes on the offensive
+const BALANCED_ATTACK_FLEET: usize = 3;
+/// Times a garrison's fighting strength the troops must have before landing
+const INVASION_ODDS: i32 = 2;
+
+/// Heavy planning work for one AI faction.
+///
+/// Planning tasks are scheduled round-robin across ticks so that their cost is
+/// spread out instead of spiking a single fixed update.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlanningTask {
+    /// Review production, construction and worker allocation
+    EconomyReview(FactionId),
+    /// Review threats, targets and fleet deployment
+    WarPlanning(FactionId),
+}
+
+impl PlanningTask {
+    /// Faction this task plans for
+    pub fn faction(&self) -> FactionId {
+        match self {
+            PlanningTask::EconomyReview(faction) | PlanningTask::WarPlanning(faction) => *faction,
+        }
+    }
+
+    /// Work units charged against the per-tick planning budget
+    pub fn cost(&self) -> u32 {
+        match self {
+            PlanningTask::EconomyReview(_) => 2,
+            PlanningTask::WarPlanning(_) => 3,
+        }
+    }
+
+    /// Ticks to wait after the task ran before it is due again
+    pub fn interval(&self) -> u64 {
+        match self {
+            PlanningTask::EconomyReview(_) => 50,
+            PlanningTask::WarPlanning(_) => 100,
+        }
+    }
+}
+
+/// Per-tick limits on AI work.
+///
+/// Budgets are counted in deterministic work units rather than wall-clock time
+/// so that the same game always makes the same decisions on every machine.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AIBudget {
+    /// Planning work units allowed per tick across all factions
+    pub planning_units_per_tick: u32,
+}
+
+impl Default for AIBudget {
+    fn default() -> Self {
+        Self {
+            planning_units_per_tick: 4,
+        }
+    }
+}
+
+/// Counters describing how AI work was spread across ticks
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AIMetrics {
+    /// Ticks on which the AI ran
+    pub ticks_processed: u64,
+    /// Reactive decision passes run (one per faction per tick)
+    pub reactive_passes: u64,
+    /// Planning tasks completed
+    pub planning_tasks_run: u64,
+    /// Planning work units spent in total
+    pub planning_units_spent: u64,
+    /// Largest number of planning units spent in a single tick
+    pub max_units_in_tick: u32,
+    /// Times a due task had to wait for a later tick because the budget ran out
+    pub tasks_deferred: u64,
+}
+
+/// Scheduling state kept for each AI faction
+#[derive(Debug, Clone, PartialEq)]
+pub struct AIFactionState {
+    /// Personality weighting this faction's decisions
+    pub personality: AIPersonality,
+    /// Tick of the last completed economy review
+    pub last_economy_review: Option<u64>,
+    /// Tick of the last completed war planning pass
+    pub last_war_plan: Option<u64>,
+    /// Inbox items seen at the last sync that still need an answer
+    pub pending_inbox: Vec<InboxItem>,
+    /// Relations with other factions at the last sync
+    pub relations: BTreeMap<FactionId, Relation>,
+}
+
+/// A planet as the AI saw it at the last world sync
+#[derive(Debug, Clone)]
+pub struct AIPlanetView {
+    /// Planet data
+    pub planet: Planet,
+    /// Position along its orbit at the sync tick
+    pub position: Vector2,
+    /// Building slots still free
+    pub free_slots: usize,
+    /// Ship classes the planet can build now; only filled for AI-controlled planets
+    pub buildable_ships: Vec<ShipClass>,
+    /// Buildings the planet can build now; only filled for AI-controlled planets
+    pub buildable_buildings: Vec<BuildingType>,
+}
+
+/// Snapshot of the world that planning tasks work from.
+///
+/// It is captured from the game only on ticks where a planning task is due,
+/// so the AI never reads the managers directly.
+#[derive(Debug, Clone, Default)]
+pub struct AIWorldView {
+    /// Every planet, in id order
+    pub planets: Vec<AIPlanetView>,
+    /// Every ship
+    pub ships: Vec<Ship>,
+    /// Ships already fighting; they cannot take attack or colonize orders
+    pub ships_in_combat: BTreeSet<ShipId>,
+    /// Ships within engagement range of each warship not in transit, by id
+    pub in_engagement_range: BTreeMap<ShipId, Vec<ShipId>>,
+    /// Technologies each AI faction could start researching; only filled
+    /// for factions without a research target
+    pub researchable: BTreeMap<FactionId, Vec<Technology>>,
+    /// Standing trade routes, which raiders go after
+    pub trade_routes: Vec<TradeRoute>,
+}
+
+impl AIWorldView {
+    /// Capture the planets and ships as they are at `tick`, with what each
+    /// AI-controlled planet could build
+    pub fn capture(game: &GameState, tick: u64) -> GameResult<Self> {
+        let mut planets = Vec::with_capacity(game.planet_manager.get_planet_count());
+        for planet in game.planet_manager.get_all_planets() {
+            let ai_faction = planet.controller
+                .filter(|owner| game.ai_system.get_faction_state(*owner).is_some());
+            let (buildable_ships, buildable_buildings) = match ai_faction {
+                Some(owner) => {
+                    let faction = game.faction_manager.get_faction(owner)?;
+                    let ships = game.construction_system.ship_build_options(planet, faction).into_iter()
+                        .filter(|_| !game.combat_resolver.is_blockaded(planet.id))
+                        .filter(|option| option.is_unlocked() && planet.resources.current.can_afford(&option.cost))
+                        .map(|option| option.ship_class)
+                        .collect();
+                    let buildings = BuildingType::ALL.into_iter()
+                        .filter(|building_type| game.construction_system.validate_building_unlocked(*building_type, planet, faction).is_ok())
+                        .collect();
+                    (ships, buildings)
+                }
+                None => (Vec::new(), Vec::new()),
+            };
+            planets.push(AIPlanetView {
+                planet: planet.clone(),
+                position: game.physics_engine.planet_position(planet, tick),
+                free_slots: game.planet_manager.get_available_building_slots(planet.id)?,
+                buildable_ships,
+                buildable_buildings,
+            });
+        }
+        
+        let ships = game.ship_manager.get_all_ships().clone();
+        let ships_in_combat = ships.iter()
+            .filter(|ship| game.combat_resolver.is_ship_in_combat(ship.id))
+            .map(|ship| ship.id)
+            .collect();
+        let index = game.physics_engine.spatial_index();
+        let in_engagement_range = ships.iter()
+            .filter(|ship| ship.ship_class == ShipClass::Warship && ship.status != ShipStatus::InTransit)
+            .map(|ship| (ship.id, game.combat_resolver.ships_in_engagement_range(index, ship.id, ship.position)))
+            .collect();
+        let researchable = game.faction_manager.get_all_factions().iter()
+            .filter(|faction| faction.research.target.is_none() && game.ai_system.get_faction_state(faction.id).is_some())
+            .map(|faction| (faction.id, game.research_system.tree().available(faction)))
+            .collect();
+        let trade_routes = game.ship_manager.get_trade_routes().cloned().collect();
+        Ok(Self { planets, ships, ships_in_combat, in_engagement_range, researchable, trade_routes })
+    }
+
+    fn planets_of(&self, faction: FactionId) -> impl Iterator<Item = &AIPlanetView> {
+        self.planets.iter().filter(move |view| view.planet.controller == Some(faction))
+    }
+
+    fn ships_of(&self, faction: FactionId, ship_class: ShipClass) -> impl Iterator<Item = &Ship> {
+        self.ships.iter().filter(move |ship| ship.owner == faction && ship.ship_class == ship_class)
+    }
+
+    /// Planet closest to `from` among those accepted by `filter`
+    fn nearest_planet(&self, from: Vector2, filter: impl Fn(&AIPlanetView) -> bool) -> Option<&AIPlanetView> {
+        self.planets.iter()
+            .filter(|view| filter(view))
+            .min_by(|a, b| a.position.distance_to(&from).total_cmp(&b.position.distance_to(&from)))
+    }
+}
+
+/// Buildings a personality wants on each planet, most wanted first
+pub fn building_priorities(personality: AIPersonality) -> &'static [BuildingType] {
+    match personality {
+        AIPersonality::Aggressive => &[
+            BuildingType::Mine, BuildingType::Factory, BuildingType::Spaceport,
+            BuildingType::PowerPlant, BuildingType::DefensePlatform, BuildingType::Farm,
+            BuildingType::Habitat,
+        ],
+        AIPersonality::Economic => &[
+            BuildingType::Mine, BuildingType::Farm, BuildingType::PowerPlant,
+            BuildingType::Factory, BuildingType::StorageFacility, BuildingType::ResearchLab,
+            BuildingType::Habitat, BuildingType::Spaceport,
+        ],
+        AIPersonality::Balanced => &[
+            BuildingType::Farm, BuildingType::Mine, BuildingType::PowerPlant,
+            BuildingType::Factory, BuildingType::Spaceport, BuildingType::ResearchLab,
+            BuildingType::DefensePlatform, BuildingType::Habitat,
+        ],
+    }
+}
+
+/// Technologies a personality researches, most wanted first
+pub fn research_priorities(personality: AIPersonality) -> &'static [Technology] {
+    match personality {
+        AIPersonality::Aggressive => &[
+            Technology::Military, Technology::OrbitalDefense, Technology::AdvancedMining,
+            Technology::FusionPower, Technology::Automation, Technology::Hydroponics,
+        ],
+        AIPersonality::Economic => &[
+            Technology::AdvancedMining, Technology::Hydroponics, Technology::FusionPower,
+            Technology::Automation, Technology::Military, Technology::OrbitalDefense,
+        ],
+        AIPersonality::Balanced => &[
+            Technology::Hydroponics, Technology::Military, Technology::AdvancedMining,
+            Technology::FusionPower, Technology::OrbitalDefense, Technology::Automation,
+        ],
+    }
+}
+
+/// Warships per owned planet and colony ships a personality keeps in service
+pub fn fleet_targets(personality: AIPersonality) -> (usize, usize) {
+    match personality {
+        AIPersonality::Aggressive => (3, 1),
+        AIPersonality::Balanced => (2, 1),
+        AIPersonality::Economic => (1, 2),
+    }
+}
+
+/// Troop transports a personality keeps in service for invasions
+pub fn troop_transport_target(personality: AIPersonality) -> usize {
+    match personality {
+        AIPersonality::Aggressive | AIPersonality::Balanced => 1,
+        AIPersonality::Economic => 0,
+    }
+}
+
+/// True when `troops` outfight the planet's military workers by `INVASION_ODDS` to one
+pub fn is_weakly_defended(planet: &Planet, troops: i32) -> bool {
+    planet.population.allocation.military * GARRISON_STRENGTH_PERCENT * INVASION_ODDS < troops * 100
+}
+
+/// Next building for a planet: the least built of the personality's
+/// unlocked priorities, earlier priorities winning ties
+fn next_building(personality: AIPersonality, view: &AIPlanetView) -> Option<BuildingType> {
+    let count = |building_type: BuildingType| {
+        view.planet.developments.iter().filter(|b| b.building_type == building_type).count()
+    };
+    building_priorities(personality).iter()
+        .copied()
+        .enumerate()
+        .filter(|(_, building_type)| view.buildable_buildings.contains(building_type))
+        .min_by_key(|(rank, building_type)| (count(*building_type), *rank))
+        .map(|(_, building_type)| building_type)
+}
+
+/// Order a ship towards a planet if it has fuel for the trip
+pub(crate) fn move_towards(ship: &Ship, target: &AIPlanetView) -> Option<PlayerCommand> {
+    let distance = ship.position.distance_to(&target.position);
+    (ship.fuel >= distance * FUEL_PER_DISTANCE).then_some(PlayerCommand::MoveShip {
+        ship: ship.id,
+        target: target.position,
+    })
+}
+
+/// True when the ship is orbiting or parked at the planet
+pub(crate) fn is_at(ship: &Ship, planet: &AIPlanetView) -> bool {
+    ship.status == ShipStatus::Orbiting(planet.planet.id)
+        || ship.position.distance_to(&planet.position) <= ARRIVAL_DISTANCE
+}
+
+/// How a faction with the given personality answers an inbox message
+pub fn inbox_response(personality: AIPersonality, message: &InboxMessage) -> InboxResponse {
+    let accept = match message {
+        InboxMessage::DiplomaticProposal { proposal, .. } => match (personality, proposal) {
+            (AIPersonality::Aggressive, _) => false,
+            (AIPersonality::Balanced, _) => true,
+            (AIPersonality::Economic, proposal) => *proposal != DiplomaticProposal::Alliance,
+        },
+        InboxMessage::TradeOffer { offered, requested, .. } => match personality {
+            // Economic factions take slightly unfavourable deals to keep trade flowing
+            AIPersonality::Economic => offered.total() * 5 >= requested.total() * 4,
+            AIPersonality::Balanced | AIPersonality::Aggressive => offered.total() >= requested.total(),
+        },
+        InboxMessage::TutorialPrompt { .. } => false,
+    };
+    if accept { InboxResponse::Accept } else { InboxResponse::Decline }
+}
+
+/// Drives non-player factions.
+///
+/// Each tick is split into two phases:
+/// - **Reactive**: cheap decisions for every AI faction, run every tick
+/// - **Planning**: heavy [`PlanningTask`]s, run round-robin within [`AIBudget`]
+///
+/// A due task that does not fit into the remaining budget keeps its place at the
+/// head of the queue and runs first on the next tick, so every faction is
+/// planned for in turn no matter how many factions exist. Decisions are issued
+/// as `PlayerCommand` events through the EventBus.
+pub struct AISystem {
+    factions: BTreeMap<FactionId, AIFactionState>,
+    /// Round-robin queue of tasks and the tick each becomes due
+    planning_queue: VecDeque<(PlanningTask, u64)>,
+    budget: AIBudget,
+    metrics: AIMetrics,
+    current_tick: u64,
+    needs_tick_processing: bool,
+    world: AIWorldView,
+    /// Ships given a combat order this tick, so no ship is ordered into two fights
+    engaged: BTreeSet<ShipId>,
+}
+
+impl AISystem {
+    /// Creates an AI system with the default budget and no factions
+    pub fn new() -> Self {
+        Self::with_budget(AIBudget::default())
+    }
+
+    /// Creates an AI system with a custom per-tick budget
+    pub fn with_budget(budget: AIBudget) -> Self {
+        Self {
+            factions: BTreeMap::new(),
+            planning_queue: VecDeque::new(),
+            budget,
+            metrics: AIMetrics::default(),
+            current_tick: 0,
+            needs_tick_processing: false,
+            world: AIWorldView::default(),
+            engaged: BTreeSet::new(),
+        }
+    }
+
+    /// Runs the AI phases for the latest completed tick
+    pub fn update(&mut self, _delta: f32, event_bus: &mut EventBus) -> GameResult<()> {
+        if self.needs_tick_processing {
+            self.process_tick(event_bus)?;
+            self.needs_tick_processing = false;
+        }
+        Ok(())
+    }
+
+    /// Handles incoming events; AI work is deferred to `update()`
+    pub fn handle_event(&mut self, event: &GameEvent) -> GameResult<()> {
+        if let GameEvent::SimulationEvent(SimulationEvent::TickCompleted(tick)) = event {
+            self.current_tick = *tick;
+            self.needs_tick_processing = true;
+        }
+        Ok(())
+    }
+
+    /// Start controlling a faction. Its planning tasks are due immediately.
+    pub fn register_faction(&mut self, faction: FactionId, personality: AIPersonality) {
+        if self.factions.contains_key(&faction) {
+            return;
+        }
+        self.factions.insert(faction, AIFactionState {
+            personality,
+            last_economy_review: None,
+            last_war_plan: None,
+            pending_inbox: Vec::new(),
+            relations: BTreeMap::new(),
+        });
+        self.planning_queue.push_back((PlanningTask::EconomyReview(faction), self.current_tick));
+        self.planning_queue.push_back((PlanningTask::WarPlanning(faction), self.current_tick));
+    }
+
+    /// Stop controlling a faction and drop its queued planning
+    pub fn unregister_faction(&mut self, faction: FactionId) {
+        self.factions.remove(&faction);
+        self.planning_queue.retain(|(task, _)| task.faction() != faction);
+    }
+
+    /// Match the controlled factions to the non-player empires in the game
+    /// and pick up their unanswered inbox items and relations
+    pub fn sync_factions(&mut self, factions: &[Faction]) {
+        let departed: Vec<FactionId> = self.factions.keys()
+            .filter(|id| !factions.iter().any(|faction| faction.id == **id && !faction.is_player && faction.is_scoring()))
+            .copied()
+            .collect();
+        for faction in departed {
+            self.unregister_faction(faction);
+        }
+
+        for faction in factions.iter().filter(|faction| !faction.is_player && faction.is_scoring()) {
+            self.register_faction(faction.id, faction.ai_type);
+            if let Some(state) = self.factions.get_mut(&faction.id) {
+                state.pending_inbox = faction.inbox.items().to_vec();
+                state.relations = faction.relations.clone();
+            }
+        }
+    }
+
+    /// True when a planning task is due at `tick` and needs a fresh world view
+    pub fn needs_world_view(&self, tick: u64) -> bool {
+        self.planning_queue.iter().any(|(_, due)| *due <= tick)
+    }
+
+    /// Replace the world snapshot planning works from
+    pub fn sync_world(&mut self, world: AIWorldView) {
+        self.world = world;
+    }
+
+    /// World snapshot from the last sync
+    pub fn world_view(&self) -> &AIWorldView {
+        &self.world
+    }
+
+    /// Scheduling state of a controlled faction
+    pub fn get_faction_state(&self, faction: FactionId) -> Option<&AIFactionState> {
+        self.factions.get(&faction)
+    }
+
+    /// Number of factions under AI control
+    pub fn faction_count(&self) -> usize {
+        self.factions.len()
+    }
+
+    /// Current per-tick budget
+    pub fn get_budget(&self) -> &AIBudget {
+        &self.budget
+    }
+
+    /// Replace the per-tick budget
+    pub fn set_budget(&mut self, budget: AIBudget) {
+        self.budget = budget;
+    }
+
+    /// Counters of AI work done so far
+    pub fn get_metrics(&self) -> &AIMetrics {
+        &self.metrics
+    }
+
+    fn process_tick(&mut self, event_bus: &mut EventBus) -> GameResult<()> {
+        self.metrics.ticks_processed += 1;
+        self.engaged = self.world.ships_in_combat.clone();
+        let mut commands = Vec::new();
+
+        // Reactive decisions are cheap and run for every faction every tick
+        let faction_ids: Vec<FactionId> = self.factions.keys().copied().collect();
+        for faction in faction_ids {
+            commands.extend(self.react(faction)?);
+            self.metrics.reactive_passes += 1;
+        }
+
+        commands.extend(self.run_planning()?);
+
+        for command in commands {
+            event_bus.queue_event(GameEvent::PlayerCommand(command));
+        }
+        Ok(())
+    }
+
+    /// Run due planning tasks in queue order until the budget is spent
+    fn run_planning(&mut self) -> GameResult<Vec<PlayerCommand>> {
+        let mut commands = Vec::new();
+        let mut spent = 0u32;
+
+        for _ in 0..self.planning_queue.len() {
+            let Some((task, due)) = self.planning_queue.pop_front() else {
+                break;
+            };
+
+            if due > self.current_tick {
+                self.planning_queue.push_back((task, due));
+                continue;
+            }
+
+            // Always allow one task per tick so an oversized task cannot starve
+            if spent > 0 && spent + task.cost() > self.budget.planning_units_per_tick {
+                self.planning_queue.push_front((task, due));
+                self.metrics.tasks_deferred += 1;
+                break;
+            }
+
+            commands.extend(self.plan(task)?);
+            spent += task.cost();
+            self.metrics.planning_tasks_run += 1;
+            self.planning_queue.push_back((task, self.current_tick + task.interval()));
+        }
+
+        self.metrics.planning_units_spent += spent as u64;
+        self.metrics.max_units_in_tick = self.metrics.max_units_in_tick.max(spent);
+        Ok(commands)
+    }
+
+    /// Per-tick decisions for one faction
+    fn react(&mut self, faction: FactionId) -> GameResult<Vec<PlayerCommand>> {
+        let Some(state) = self.factions.get_mut(&faction) else {
+            return Ok(Vec::new());
+        };
+        let personality = state.personality;
+        Ok(state.pending_inbox.drain(..)
+            .map(|item| PlayerCommand::RespondToInbox {
+                faction,
+                item: item.id,
+                response: inbox_response(personality, &item.message),
+            })
+            .collect())
+    }
+
+    /// Execute one planning task
+    fn plan(&mut self, task: PlanningTask) -> GameResult<Vec<PlayerCommand>> {
+        let tick = self.current_tick;
+        let Some(state) = self.factions.get_mut(&task.faction()) else {
+            return Ok(Vec::new());
+        };
+        let personality = state.personality;
+        match task {
+            PlanningTask::EconomyReview(faction) => {
+                state.last_economy_review = Some(tick);
+                Ok(self.review_economy(faction, personality))
+            }
+            PlanningTask::WarPlanning(faction) => {
+                state.last_war_plan = Some(tick);
+                Ok(self.plan_war(faction, personality))
+            }
+        }
+    }
+
+    /// Buildings, new ships and colonization for one faction
+    fn review_economy(&self, faction: FactionId, personality: AIPersonality) -> Vec<PlayerCommand> {
+        let world = &self.world;
+        let mut commands = Vec::new();
+
+        // Pick the next research target once the last one is unlocked
+        if let Some(available) = world.researchable.get(&faction) {
+            let target = research_priorities(personality).iter()
+                .find(|technology| available.contains(technology))
+                .or(available.first());
+            if let Some(technology) = target {
+                commands.push(PlayerCommand::SetResearchTarget { faction, technology: *technology });
+            }
+        }
+
+        // One new building per planet per review
+        for view in world.planets_of(faction).filter(|view| view.free_slots > 0) {
+            if let Some(building_type) = next_building(personality, view) {
+                commands.push(PlayerCommand::BuildStructure { planet: view.planet.id, building_type });
+            }
+        }
+
+        // One new ship per review, colony ships first while there is room to expand
+        let (warships_per_planet, colony_target) = fleet_targets(personality);
+        let owned = world.planets_of(faction).count();
+        let has_neutral = world.planets.iter().any(|view| view.planet.controller.is_none());
+        let wanted = [
+            (ShipClass::Colony, if has_neutral { colony_target } else { 0 }),
+            (ShipClass::Warship, owned * warships_per_planet),
+            (ShipClass::Transport, troop_transport_target(personality)),
+        ];
+        let order = wanted.iter()
+            .filter(|(class, target)| world.ships_of(faction, *class).count() < *target)
+            .find_map(|(class, _)| world.planets_of(faction)
+                .find(|view| view.buildable_ships.contains(class))
+                .map(|view| PlayerCommand::BuildShip { planet: view.planet.id, ship_class: *class }));
+        commands.extend(order);
+
+        // Send idle colony ships to the nearest unclaimed neutral planet
+        let mut claimed = BTreeSet::new();
+        for ship in world.ships_of(faction, ShipClass::Colony) {
+            if ship.status == ShipStatus::InTransit || self.engaged.contains(&ship.id) {
+                continue;
+            }
+            let Some(target) = world.nearest_planet(ship.position, |view| {
+                view.planet.controller.is_none() && !claimed.contains(&view.planet.id)
+            }) else {
+                break;
+            };
+            claimed.insert(target.planet.id);
+            // Colonists only land from orbit; a ship parked short of it closes in
+            if ship.status == ShipStatus::Orbiting(target.planet.id) {
+                commands.push(PlayerCommand::ColonizePlanet { ship: ship.id, planet: target.planet.id });
+            } else {
+                commands.extend(move_towards(ship, target));
+            }
+        }
+
+        commands
+    }
+
+    /// Fleet movement, attacks and invasions for one faction
+    fn plan_war(&mut self, faction: FactionId, personality: AIPersonality) -> Vec<PlayerCommand> {
+        let world = &self.world;
+        let relations = self.factions.get(&faction).map(|state| &state.relations);
+        let relation = |other: FactionId| relations.and_then(|r| r.get(&other)).copied().unwrap_or_default();
+        // Treaties are never broken by the AI; Balanced factions only carry
+        // the fight to factions they are already at war with
+        let hostile = |other: FactionId| other != faction && relation(other).allows_attack();
+        let invadable = |other: FactionId| hostile(other)
+            && (personality != AIPersonality::Balanced || relation(other).at_war);
+        let owned = world.planets_of(faction).count();
+        let warships: Vec<&Ship> = world.ships_of(faction, ShipClass::Warship).collect();
+        let owners: BTreeMap<ShipId, FactionId> = world.ships.iter().map(|ship| (ship.id, ship.owner)).collect();
+        let offensive = match personality {
+            AIPersonality::Aggressive => true,
+            AIPersonality::Balanced => warships.len() >= BALANCED_ATTACK_FLEET.max(owned),
+            AIPersonality::Economic => false,
+        };
+
+        let mut commands = Vec::new();
+        let mut declared = BTreeSet::new();
+        for ship in warships {
+            if ship.status == ShipStatus::InTransit || self.engaged.contains(&ship.id) {
+                continue;
+            }
+
+            // Fight any enemy ship within range, at home or abroad
+            let enemy = world.in_engagement_range.get(&ship.id).into_iter().flatten().copied().find(|other| {
+                owners.get(other).is_some_and(|owner| hostile(*owner)) && !self.engaged.contains(other)
+            });
+            if let Some(enemy) = enemy {
+                self.engaged.insert(ship.id);
+                self.engaged.insert(enemy);
+                commands.push(PlayerCommand::AttackTarget { attacker: ship.id, target: enemy });
+                continue;
+            }
+
+            let target = if offensive {
+                world.nearest_planet(ship.position, |view| {
+                    view.planet.controller.is_some_and(invadable)
+                })
+            } else {
+                world.nearest_planet(ship.position, |view| view.planet.controller == Some(faction))
+            };
+            let Some(target) = target else {
+                continue;
+            };
+            if !is_at(ship, target) {
+                commands.extend(move_towards(ship, target));
+            } else if offensive {
+                // Undefended enemy planet: go to war so the fleet blockades it
+                let owner = target.planet.controller
+                    .filter(|owner| !relation(*owner).at_war && declared.insert(*owner));
+                if let Some(owner) = owner {
+                    commands.push(PlayerCommand::DeclareWar { from: faction, to: owner });
+                }
+            }
+        }
+        if !offensive {
+            return commands;
+        }
+
+        // Troop transports take half a home garrison to the nearest planet of
+        // a faction at war with this one that those troops outfight
+        let weak_target = |view: &AIPlanetView, troops: i32| view.planet.controller
+            .is_some_and(|owner| hostile(owner) && relation(owner).at_war)
+            && is_weakly_defended(&view.planet, troops);
+        let routed: BTreeSet<ShipId> = world.trade_routes.iter().map(|route| route.ship).collect();
+        let mut claimed = BTreeSet::new();
+        for ship in world.ships_of(faction, ShipClass::Transport) {
+            if ship.status == ShipStatus::InTransit || routed.contains(&ship.id) || self.engaged.contains(&ship.id) {
+                continue;
+            }
+            let troops = ship.cargo.population;
+            if troops > 0 {
+                let Some(target) = world.nearest_planet(ship.position, |view| {
+                    weak_target(view, troops) && !claimed.contains(&view.planet.id)
+                }) else {
+                    continue;
+                };
+                claimed.insert(target.planet.id);
+                // Troops only land from orbit
+                if ship.status == ShipStatus::Orbiting(target.planet.id) {
+                    commands.push(PlayerCommand::InvadePlanet { ship: ship.id, planet: target.planet.id });
+                } else {
+                    commands.extend(move_towards(ship, target));
+                }
+                continue;
+            }
+
+            let Some(home) = world.nearest_planet(ship.position, |view| {
+                view.planet.controller == Some(faction) && view.planet.population.allocation.military >= 2
+            }) else {
+                continue;
+            };
+            let troops = (home.planet.population.allocation.military / 2).min(ship.cargo.available_space());
+            if troops <= 0 || !world.planets.iter().any(|view| weak_target(view, troops)) {
+                continue;
+            }
+            if ship.status == ShipStatus::Orbiting(home.planet.id) {
+                commands.push(PlayerCommand::EmbarkTroops { ship: ship.id, planet: home.planet.id, troops });
+            } else {
+                commands.extend(move_towards(ship, home));
+            }
+        }
+        commands
+    }
+}
+
+impl Default for AISystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GameSystem for AISystem {
+    fn update(&mut self, delta: f32, context: &mut SystemContext<'_>) -> GameResult<()> {
+        self.update(delta, context.event_bus())
+    }
+
+    fn handle_event(&mut self, event: &GameEvent) -> GameResult<()> {
+        self.handle_event(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_tick(ai: &mut AISystem, bus: &mut EventBus, tick: u64) {
+        ai.handle_event(&GameEvent::SimulationEvent(SimulationEvent::TickCompleted(tick))).unwrap();
+        ai.update(0.1, bus).unwrap();
+    }
+
+    #[test]
+    fn test_planning_is_spread_across_ticks_within_budget() {
+        let mut ai = AISystem::new();
+        let mut bus = EventBus::new();
+        for faction in 0..8 {
+            ai.register_faction(faction, AIPersonality::Balanced);
+        }
+
+        // 8 factions x (2 + 3) units = 40 units of due work at a 4 unit budget
+        for tick in 1..=20 {
+            run_tick(&mut ai, &mut bus, tick);
+        }
+
+        let metrics = ai.get_metrics();
+        assert!(metrics.max_units_in_tick <= ai.get_budget().planning_units_per_tick);
+        assert!(metrics.tasks_deferred > 0, "Backlog should have been deferred to later ticks");
+        assert_eq!(metrics.reactive_passes, 8 * 20, "Reactive phase runs for every faction every tick");
+        for faction in 0..8 {
+            let state = ai.get_faction_state(faction).unwrap();
+            assert!(state.last_economy_review.is_some(), "Faction {} never reviewed its economy", faction);
+            assert!(state.last_war_plan.is_some(), "Faction {} never planned for war", faction);
+        }
+    }
+
+    #[test]
+    fn test_tasks_wait_for_their_interval() {
+        let mut ai = AISystem::new();
+        let mut bus = EventBus::new();
+        ai.register_faction(3, AIPersonality::Economic);
+
+        run_tick(&mut ai, &mut bus, 1);
+        run_tick(&mut ai, &mut bus, 2);
+        let state = ai.get_faction_state(3).unwrap().clone();
+        assert_eq!(state.last_economy_review, Some(1));
+        assert_eq!(state.last_war_plan, Some(2));
+
+        for tick in 3..50 {
+            run_tick(&mut ai, &mut bus, tick);
+        }
+        assert_eq!(ai.get_faction_state(3).unwrap().last_economy_review, Some(1));
+        run_tick(&mut ai, &mut bus, 51);
+        assert_eq!(ai.get_faction_state(3).unwrap().last_economy_review, Some(51));
+    }
+
+    #[test]
+    fn test_sync_factions_skips_player_and_drops_departed() {
+        let mut ai = AISystem::new();
+        let faction = |id, is_player| Faction {
+            id,
+            name: format!("Faction {}", id),
+            is_player,
+            ai_type: AIPersonality::Aggressive,
+            score: 0,
+            inbox: Inbox::default(),
+            technologies: BTreeSet::new(),
+            research: ResearchState::default(),
+            relations: BTreeMap::new(),
+            explored_planets: BTreeSet::new(),
+            kind: FactionKind::Empire,
+            credits: 0,
+            tax_rate: TaxRate::default(),
+            ship_designs: Vec::new(),
+            control_groups: BTreeMap::new(),
+        };
+
+        ai.sync_factions(&[faction(0, true), faction(1, false), faction(2, false)]);
+        assert_eq!(ai.faction_count(), 2);
+        assert!(ai.get_faction_state(0).is_none());
+
+        ai.sync_factions(&[faction(0, true), faction(2, false)]);
+        assert_eq!(ai.faction_count(), 1);
+        assert!(ai.planning_queue.iter().all(|(task, _)| task.faction() == 2));
+    }
+
+    #[test]
+    fn test_reactive_phase_answers_inbox_by_personality() {
+        let mut ai = AISystem::new();
+        let mut bus = EventBus::new();
+        let mut aggressive = Faction {
+            id: 1,
+            name: "Raiders".to_string(),
+            is_player: false,
+            ai_type: AIPersonality::Aggressive,
+            score: 0,
+            inbox: Inbox::default(),
+            technologies: BTreeSet::new(),
+            research: ResearchState::default(),
+            relations: BTreeMap::new(),
+            explored_planets: BTreeSet::new(),
+            kind: FactionKind::Empire,
+            credits: 0,
+            tax_rate: TaxRate::default(),
+            ship_designs: Vec::new(),
+            control_groups: BTreeMap::new(),
+        };
+        let mut economic = Faction {
+            id: 2,
+            name: "Traders".to_string(),
+            ai_type: AIPersonality::Economic,
+            inbox: Inbox::default(),
+            technologies: BTreeSet::new(),
+            research: ResearchState::default(),
+            relations: BTreeMap::new(),
+            explored_planets: BTreeSet::new(),
+            kind: FactionKind::Empire,
+            credits: 0,
+            tax_rate: TaxRate::default(),
+            ..aggressive.clone()
+        };
+        let peace = InboxMessage::DiplomaticProposal { from: 0, proposal: DiplomaticProposal::Peace };
+        aggressive.inbox.deliver(peace.clone(), 0);
+        economic.inbox.deliver(peace, 0);
+
+        ai.sync_factions(&[aggressive, economic]);
+        run_tick(&mut ai, &mut bus, 1);
+
+        let responses: Vec<(FactionId, InboxResponse)> = bus.queued_events.iter()
+            .filter_map(|event| match event {
+                GameEvent::PlayerCommand(PlayerCommand::RespondToInbox { faction, response, .. }) => Some((*faction, *response)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(responses, vec![(1, InboxResponse::Decline), (2, InboxResponse::Accept)]);
+
+        // Answered items are not answered again before the next sync
+        bus.queued_events.clear();
+        run_tick(&mut ai, &mut bus, 2);
+        assert!(bus.queued_events.is_empty());
+    }
+
+    #[test]
+    fn test_economy_review_builds_by_priority_and_sends_colony_ships() {
+        use crate::managers::{PlanetManager, ShipManager};
+
+        let mut planets = PlanetManager::new();
+        let home = planets.create_planet(OrbitalElements { semi_major_axis: 2.0, period: 100.0, phase: 0.0, eccentricity: 0.0, argument_of_periapsis: 0.0 }, Some(1)).unwrap();
+        let neutral = planets.create_planet(OrbitalElements { semi_major_axis: 4.0, period: 100.0, phase: 0.0, eccentricity: 0.0, argument_of_periapsis: 0.0 }, None).unwrap();
+        let mut ships = ShipManager::new();
+        let colony_ship = ships.create_ship(ShipClass::Colony, Vector2::new(2.0, 0.0), 1).unwrap();
+
+        let view = |id| {
+            let planet = planets.get_planet(id).unwrap().clone();
+            AIPlanetView {
+                position: Vector2::new(planet.position.semi_major_axis, 0.0),
+                free_slots: 1,
+                buildable_ships: if id == home { vec![ShipClass::Scout, ShipClass::Colony] } else { Vec::new() },
+                buildable_buildings: if id == home { vec![BuildingType::Farm, BuildingType::Mine] } else { Vec::new() },
+                planet,
+            }
+        };
+        let mut ai = AISystem::new();
+        ai.register_faction(1, AIPersonality::Economic);
+        ai.sync_world(AIWorldView {
+            planets: vec![view(home), view(neutral)],
+            ships: ships.get_all_ships().clone(),
+            ships_in_combat: BTreeSet::new(),
+            in_engagement_range: BTreeMap::new(),
+            researchable: [(1, vec![Technology::Military, Technology::Hydroponics])].into_iter().collect(),
+            trade_routes: Vec::new(),
+        });
+
+        let commands = ai.plan(PlanningTask::EconomyReview(1)).unwrap();
+        // Economic factions want Hydroponics before Military
+        assert!(commands.iter().any(|command| matches!(
+            command,
+            PlayerCommand::SetResearchTarget { faction: 1, technology: Technology::Hydroponics }
+        )));
+        assert!(commands.iter().any(|command| matches!(
+            command,
+            PlayerCommand::BuildStructure { planet, building_type: BuildingType::Mine } if *planet == home
+        )));
+        // Economic factions keep two colony ships; the one it has heads for the neutral planet
+        assert!(commands.iter().any(|command| matches!(
+            command,
+            PlayerCommand::BuildShip { planet, ship_class: ShipClass::Colony } if *planet == home
+        )));
+        assert!(commands.iter().any(|command| matches!(
+            command,
+            PlayerCommand::MoveShip { ship, target } if *ship == colony_ship && target.x == 4.0
+        )));
+        assert_eq!(ai.get_faction_state(1).unwrap().last_economy_review, Some(0));
+    }
+
+    #[test]
+    fn test_war_planning_embarks_troops_and_invades_weak_planets() {
+        use crate::managers::{PlanetManager, ShipManager};
+
+        let orbit = |semi_major_axis| OrbitalElements { semi_major_axis, period: 100.0, phase: 0.0, eccentricity: 0.0, argument_of_periapsis: 0.0 };
+        let mut planets = PlanetManager::new();
+        let home = planets.create_planet(orbit(2.0), Some(1)).unwrap();
+        let weak = planets.create_planet(orbit(4.0), Some(2)).unwrap();
+        let fortress = planets.create_planet(orbit(6.0), Some(2)).unwrap();
+        let mut ships = ShipManager::new();
+        let loaded = ships.create_ship(ShipClass::Transport, Vector2::new(4.0, 0.0), 1).unwrap();
+        let empty = ships.create_ship(ShipClass::Transport, Vector2::new(2.0, 0.0), 1).unwrap();
+        let mut fleet = ships.get_all_ships().clone();
+        for ship in &mut fleet {
+            if ship.id == loaded {
+                ship.cargo.population = 10;
+                ship.status = ShipStatus::Orbiting(weak);
+            } else {
+                ship.status = ShipStatus::Orbiting(home);
+            }
+        }
+
+        let garrisons = [(home, 20), (weak, 3), (fortress, 50)];
+        let views = garrisons.iter().map(|&(id, military)| {
+            let mut planet = planets.get_planet(id).unwrap().clone();
+            planet.population.allocation.military = military;
+            AIPlanetView {
+                position: Vector2::new(planet.position.semi_major_axis, 0.0),
+                free_slots: 0,
+                buildable_ships: Vec::new(),
+                buildable_buildings: Vec::new(),
+                planet,
+            }
+        }).collect();
+        let mut ai = AISystem::new();
+        ai.register_faction(1, AIPersonality::Aggressive);
+        ai.factions.get_mut(&1).unwrap().relations.insert(2, Relation { at_war: true, ..Default::default() });
+        ai.sync_world(AIWorldView { planets: views, ships: fleet, ..Default::default() });
+
+        let commands = ai.plan(PlanningTask::WarPlanning(1)).unwrap();
+        // The loaded transport lands on the weak planet; the empty one takes half the home garrison
+        assert!(commands.iter().any(|command| matches!(
+            command,
+            PlayerCommand::InvadePlanet { ship, planet } if *ship == loaded && *planet == weak
+        )));
+        assert!(commands.iter().any(|command| matches!(
+            command,
+            PlayerCommand::EmbarkTroops { ship, planet, troops: 10 } if *ship == empty && *planet == home
+        )));
+        assert!(!commands.iter().any(|command| matches!(
+            command,
+            PlayerCommand::InvadePlanet { planet, .. } if *planet == fortress
+        )));
+
+        // Without a war there is nobody to invade
+        ai.factions.get_mut(&1).unwrap().relations.insert(2, Relation::default());
+        let commands = ai.plan(PlanningTask::WarPlanning(1)).unwrap();
+        assert!(!commands.iter().any(|command| matches!(
+            command,
+            PlayerCommand::InvadePlanet { .. } | PlayerCommand::EmbarkTroops { .. }
+        )));
+    }
+}
@@ -0,0 +1,213 @@
+// src/systems/cargo.rs
+//! Loading and unloading ships at their own planets
+//!
+//! `PlayerCommand::LoadShipCargo` moves resources and settlers from a planet
+//! into a ship orbiting it, and `UnloadShipCargo` puts everything aboard
+//! ashore. CargoSystem holds the rules: the ship must be in orbit of a planet
+//! its faction controls, have a hold, and have room for the load; the planet
+//! must hold the resources and unassigned workers asked for, or have storage
+//! for what is unloaded. CommandValidator applies them before a command is
+//! routed, and ShipManager moves the cargo between ship and planet in one
+//! step, announced by GameState with `SimulationEvent::CargoLoaded` or `CargoUnloaded`.
+//!
+//! Ships on a trade route are run by the same rules: every tick
+//! [`CargoSystem::route_orders`] works out each route's next order and
+//! GameState queues it as a command, so a transport loads at the source, flies to the
+//! destination, unloads and returns until the route is cancelled.
+
+use crate::core::{GameResult, GameEvent, EventBus, GameSystem, SystemContext};
+use crate::core::events::PlayerCommand;
+use crate::core::types::*;
+use crate::managers::{PlanetManager, ShipManager};
+
+/// Rules for moving cargo between ships and planets
+#[derive(Debug, Default)]
+pub struct CargoSystem;
+
+impl CargoSystem {
+    /// The cargo rules; they hold no state
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Ship classes that carry freight and passengers; warships only take troops aboard
+    pub fn has_cargo_hold(ship_class: ShipClass) -> bool {
+        matches!(ship_class, ShipClass::Transport | ShipClass::Colony)
+    }
+
+    /// Fail unless the ship is orbiting the planet and its faction controls it
+    pub fn check_in_range(ship: &Ship, planet: &Planet) -> GameResult<()> {
+        if ship.status != ShipStatus::Orbiting(planet.id) {
+            return Err(GameError::NotInOrbit { ship: ship.id, planet: planet.id });
+        }
+        if planet.controller != Some(ship.owner) {
+            return Err(GameError::not_owned(ship.owner, EntityKind::Planet, planet.id));
+        }
+        Ok(())
+    }
+
+    /// Check that the planet can hand over `resources` and `population`
+    /// unassigned workers and that they fit in the ship's hold
+    pub fn check_load(ship: &Ship, planet: &Planet, resources: &ResourceBundle, population: i32) -> GameResult<()> {
+        resources.validate_non_negative()?;
+        if population < 0 {
+            return Err(GameError::invalid_value("passengers", "cannot be negative"));
+        }
+        if !Self::has_cargo_hold(ship.ship_class) {
+            return Err(GameError::unsuitable_ship(ship, "carry cargo"));
+        }
+        Self::check_in_range(ship, planet)?;
+        if !planet.resources.current.can_afford(resources) {
+            return Err(GameError::InsufficientResources { required: *resources, available: planet.resources.current });
+        }
+        if population > planet.population.allocation.unassigned {
+            return Err(GameError::capacity("unassigned workers", population, planet.population.allocation.unassigned));
+        }
+        if !ship.cargo.can_load(resources, population) {
+            return Err(GameError::capacity("cargo space", resources.total() + population as i64, ship.cargo.available_space()));
+        }
+        Ok(())
+    }
+
+    /// Check that the ship has something aboard and the planet can store it
+    pub fn check_unload(ship: &Ship, planet: &Planet) -> GameResult<()> {
+        Self::check_in_range(ship, planet)?;
+        if ship.cargo.current_load() == 0 {
+            return Err(GameError::InvalidOperation(format!("Ship {} has nothing aboard", ship.id)));
+        }
+        if !planet.resources.can_store(&ship.cargo.resources) {
+            return Err(GameError::storage_full(&planet.resources, &ship.cargo.resources));
+        }
+        Ok(())
+    }
+
+    /// Next order for the ship on `route`, worked out from where it is and
+    /// what it carries: load the route's cargo (or what the source has of
+    /// it) in orbit of `from`, carry it to `to` and put it ashore, then head
+    /// back. None while the ship is under way, or is waiting for stock at
+    /// the source or storage at the destination. `from_position` and
+    /// `to_position` are where the planets are this tick.
+    pub fn route_order(route: &TradeRoute, ship: &Ship, from: &Planet, to: &Planet, from_position: Vector2, to_position: Vector2) -> Option<PlayerCommand> {
+        let loaded = ship.cargo.current_load() > 0;
+        let (target, target_position) = if loaded { (to, to_position) } else { (from, from_position) };
+        if ship.status != ShipStatus::Orbiting(target.id) {
+            return ship.trajectory.is_none()
+                .then_some(PlayerCommand::MoveShip { ship: ship.id, target: target_position });
+        }
+        
+        if loaded {
+            return Self::check_unload(ship, to).ok()
+                .map(|_| PlayerCommand::UnloadShipCargo { ship: ship.id, planet: to.id });
+        }
+        let mut resources = ResourceBundle::default();
+        for resource in ResourceType::ALL {
+            resources.set(resource, route.resources.get(resource).min(from.resources.current.get(resource)).max(0));
+        }
+        (resources.total() > 0 && Self::check_load(ship, from, &resources, 0).is_ok())
+            .then_some(PlayerCommand::LoadShipCargo { ship: ship.id, planet: from.id, resources, population: 0 })
+    }
+
+    /// Next order of every trade route ship, in route order, with each
+    /// planet where `position` puts it this tick
+    pub fn route_orders(ships: &ShipManager, planets: &PlanetManager, position: impl Fn(&Planet) -> Vector2) -> Vec<PlayerCommand> {
+        ships.get_trade_routes()
+            .filter_map(|route| {
+                let ship = ships.get_ship(route.ship).ok()?;
+                let from = planets.get_planet(route.from).ok()?;
+                let to = planets.get_planet(route.to).ok()?;
+                Self::route_order(route, ship, from, to, position(from), position(to))
+            })
+            .collect()
+    }
+
+    pub fn update(&mut self, _delta: f32, _event_bus: &mut EventBus) -> GameResult<()> {
+        Ok(())
+    }
+
+    /// Cargo commands are carried out by GameState, which owns both ends
+    pub fn handle_event(&mut self, _event: &GameEvent) -> GameResult<()> {
+        Ok(())
+    }
+}
+
+impl GameSystem for CargoSystem {
+    fn update(&mut self, delta: f32, context: &mut SystemContext<'_>) -> GameResult<()> {
+        self.update(delta, context.event_bus())
+    }
+
+    fn handle_event(&mut self, event: &GameEvent) -> GameResult<()> {
+        self.handle_event(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::managers::{PlanetManager, ShipManager};
+
+    #[test]
+    fn test_loads_need_orbit_stock_workers_and_room() {
+        let mut planets = PlanetManager::new();
+        let home = planets.create_planet(OrbitalElements::default(), Some(0)).unwrap();
+        planets.add_resources(home, ResourceBundle { minerals: 500, ..Default::default() }).unwrap();
+        planets.update_population(home, 100).unwrap();
+        let mut ships = ShipManager::new();
+        let transport = ships.create_ship(ShipClass::Transport, Vector2::new(5.0, 0.0), 0).unwrap();
+        let mut ship = ships.get_ship(transport).unwrap().clone();
+        let planet = planets.get_planet(home).unwrap().clone();
+        let minerals = |minerals| ResourceBundle { minerals, ..Default::default() };
+
+        assert!(CargoSystem::check_load(&ship, &planet, &minerals(100), 10).unwrap_err().to_string().contains("must orbit"));
+        ship.status = ShipStatus::Orbiting(home);
+        CargoSystem::check_load(&ship, &planet, &minerals(100), 10).unwrap();
+        assert!(matches!(
+            CargoSystem::check_load(&ship, &planet, &minerals(600), 0),
+            Err(GameError::InsufficientResources { .. })
+        ));
+        assert!(CargoSystem::check_load(&ship, &planet, &minerals(0), 101).unwrap_err().to_string().contains("unassigned"));
+        ship.cargo.resources = minerals(950);
+        assert!(matches!(
+            CargoSystem::check_load(&ship, &planet, &minerals(100), 0),
+            Err(GameError::CapacityExceeded { requested: 100, available: 50, .. })
+        ));
+
+        let scout = ships.create_ship(ShipClass::Scout, Vector2::new(5.0, 0.0), 0).unwrap();
+        let scout = Ship { status: ShipStatus::Orbiting(home), ..ships.get_ship(scout).unwrap().clone() };
+        assert!(matches!(CargoSystem::check_load(&scout, &planet, &minerals(0), 0), Err(GameError::UnsuitableShip { .. })));
+        assert!(CargoSystem::check_unload(&scout, &planet).unwrap_err().to_string().contains("nothing aboard"));
+    }
+
+    #[test]
+    fn test_route_order_follows_the_ship_around_the_route() {
+        let mut planets = PlanetManager::new();
+        let source = planets.create_planet(OrbitalElements::default(), Some(0)).unwrap();
+        let destination = planets.create_planet(OrbitalElements::default(), Some(0)).unwrap();
+        planets.add_resources(source, ResourceBundle { minerals: 60, ..Default::default() }).unwrap();
+        let (from, to) = (planets.get_planet(source).unwrap().clone(), planets.get_planet(destination).unwrap().clone());
+        let (from_position, to_position) = (Vector2::new(10.0, 0.0), Vector2::new(50.0, 0.0));
+        let mut ships = ShipManager::new();
+        let transport = ships.create_ship(ShipClass::Transport, Vector2::default(), 0).unwrap();
+        let mut ship = ships.get_ship(transport).unwrap().clone();
+        let route = TradeRoute { ship: transport, from: source, to: destination, resources: ResourceBundle { minerals: 100, ..Default::default() } };
+        let order = |ship: &Ship| CargoSystem::route_order(&route, ship, &from, &to, from_position, to_position);
+
+        // Empty and away from the source: head there
+        assert!(matches!(order(&ship), Some(PlayerCommand::MoveShip { target, .. }) if target == from_position));
+        // In orbit of the source: load what it has of the route's cargo
+        ship.status = ShipStatus::Orbiting(source);
+        assert!(matches!(order(&ship), Some(PlayerCommand::LoadShipCargo { resources, .. }) if resources.minerals == 60));
+        // Loaded: carry it to the destination, waiting while under way
+        ship.cargo.resources.minerals = 60;
+        assert!(matches!(order(&ship), Some(PlayerCommand::MoveShip { target, .. }) if target == to_position));
+        ship.status = ShipStatus::InTransit;
+        ship.trajectory = Some(Trajectory {
+            origin: from_position, destination: to_position, departure_time: 0, arrival_time: 4,
+            fuel_cost: 0.0, waypoints: Vec::new(), jumps: Vec::new(),
+        });
+        assert!(order(&ship).is_none());
+        // In orbit of the destination: unload
+        ship.trajectory = None;
+        ship.status = ShipStatus::Orbiting(destination);
+        assert!(matches!(order(&ship), Some(PlayerCommand::UnloadShipCargo { planet, .. }) if planet == destination));
+    }
+}
@@ -0,0 +1,1546 @@
+// src/systems/combat_resolver.rs
+use crate::core::{GameResult, GameError, GameEvent, EventBus, GameSystem, SystemContext, CombatStats};
+use crate::core::types::*;
+use crate::core::events::*;
+use crate::core::rng::{next_seed, unit, RngStream};
+use crate::managers::{PlanetManager, ShipManager, FactionManager};
+use super::spatial_index::SpatialIndex;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// Rounds after which an undecided ship battle breaks off
+pub const MAX_BATTLE_ROUNDS: u32 = 20;
+/// Ships left with less than this share of their full hull retreat
+pub const RETREAT_HULL_PERCENT: i32 = 25;
+/// Hull points repaired per tick while orbiting a planet of the ship's faction
+pub const HULL_REPAIR_PER_TICK: i32 = 2;
+/// Distance in AU within which two ships can open fire on each other
+pub const ENGAGEMENT_RANGE: f32 = 0.5;
+/// Rounds between a ship's missile launches, the first in its first round
+pub const MISSILE_RELOAD_ROUNDS: u32 = 3;
+/// Distance in AU a missile covers per tick
+pub const MISSILE_SPEED: f32 = 0.15;
+/// Ticks a missile flies before it burns out short of its target
+pub const MISSILE_FLIGHT_TICKS: u64 = 20;
+/// Radius in AU around an impact within which other fighting ships are caught
+pub const MISSILE_BLAST_RADIUS: f32 = 0.3;
+/// Share of the warhead, in percent, dealt to the other ships caught in the blast
+pub const MISSILE_SPLASH_PERCENT: i32 = 50;
+/// Ticks an impact or interception is kept for the renderer's effects
+pub const MISSILE_EFFECT_TICKS: u64 = 5;
+
+/// A ship's fighting state in a battle
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Combatant {
+    /// Ship fighting
+    pub ship: ShipId,
+    /// Faction that owns the ship
+    pub faction: FactionId,
+    /// The ship's combat stats
+    pub stats: CombatStats,
+    /// Hull points left
+    pub hull: i32,
+    /// Shield points left in this battle
+    pub shields: i32,
+}
+
+impl Combatant {
+    /// The ship going into battle with fresh shields and the hull damage it already carries
+    pub fn new(ship: &Ship, stats: CombatStats) -> Self {
+        Self {
+            ship: ship.id,
+            faction: ship.owner,
+            stats,
+            hull: (stats.hull - ship.damage).max(1),
+            shields: stats.shields,
+        }
+    }
+    
+    /// True once the hull is gone
+    pub fn is_destroyed(&self) -> bool {
+        self.hull <= 0
+    }
+    
+    /// Too badly damaged to keep fighting, but still in one piece
+    pub fn should_retreat(&self) -> bool {
+        !self.is_destroyed() && self.hull * 100 < self.stats.hull * RETREAT_HULL_PERCENT
+    }
+    
+    /// Out of the fight, destroyed or retreating
+    fn is_out(&self) -> bool {
+        self.is_destroyed() || self.should_retreat()
+    }
+    
+    /// Shields soak up damage before the hull; returns the hull damage taken
+    fn take_damage(&mut self, damage: i32) -> i32 {
+        let absorbed = damage.min(self.shields);
+        self.shields -= absorbed;
+        let hull_damage = (damage - absorbed).min(self.hull);
+        self.hull -= hull_damage;
+        hull_damage
+    }
+}
+
+/// Two ships trading fire, one round per tick, until one is destroyed or
+/// retreats or [`MAX_BATTLE_ROUNDS`] have been fought
+#[derive(Debug, Clone)]
+pub struct ShipBattle {
+    /// Ship that opened fire
+    pub attacker: Combatant,
+    /// Ship fired upon
+    pub defender: Combatant,
+    /// Where the attacker opened fire
+    pub location: Vector2,
+    /// Tick the battle began
+    pub start_tick: u64,
+    /// Rounds fought so far
+    pub rounds: u32,
+    /// Hull damage the attacker has taken so far
+    pub attacker_damage: i32,
+    /// Hull damage the defender has taken so far
+    pub defender_damage: i32,
+    last_round_tick: u64,
+    /// Damage roll state
+    rng: u64,
+}
+
+impl ShipBattle {
+    /// Both ships fire at once; returns the hull damage (attacker, defender) took
+    fn fight_round(&mut self, attacker_modifier: f32, defender_modifier: f32) -> (i32, i32) {
+        let attacker_hit = self.roll_damage(self.attacker.stats.attack, attacker_modifier);
+        let defender_hit = self.roll_damage(self.defender.stats.attack, defender_modifier);
+        let defender_damage = self.defender.take_damage(attacker_hit);
+        let attacker_damage = self.attacker.take_damage(defender_hit);
+        self.rounds += 1;
+        self.attacker_damage += attacker_damage;
+        self.defender_damage += defender_damage;
+        (attacker_damage, defender_damage)
+    }
+    
+    /// Between half and one and a half times the attack, scaled by the faction's modifier
+    fn roll_damage(&mut self, attack: i32, modifier: f32) -> i32 {
+        let roll = 0.5 + unit(&mut self.rng);
+        (attack as f32 * modifier * roll).round() as i32
+    }
+    
+    /// How the battle ended, or None while both ships fight on
+    fn outcome(&self) -> Option<CombatOutcome> {
+        let (attacker, defender) = (&self.attacker, &self.defender);
+        if !attacker.is_out() && !defender.is_out() && self.rounds < MAX_BATTLE_ROUNDS {
+            return None;
+        }
+        let losses = |combatant: &Combatant| if combatant.is_destroyed() { vec![combatant.ship] } else { Vec::new() };
+        // The defender holds the field unless it alone was driven off
+        let winner = if defender.is_out() && !attacker.is_out() { attacker.faction } else { defender.faction };
+        Some(CombatOutcome {
+            winner,
+            attacker_losses: losses(attacker),
+            defender_losses: losses(defender),
+            retreated: [attacker, defender].into_iter()
+                .filter(|combatant| combatant.should_retreat())
+                .map(|combatant| combatant.ship)
+                .collect(),
+            rounds: self.rounds,
+            attacker_damage: self.attacker_damage,
+            defender_damage: self.defender_damage,
+        })
+    }
+}
+
+/// A missile in flight toward a ship fighting a battle
+#[derive(Debug, Clone, PartialEq)]
+pub struct Missile {
+    /// Id reported in the missile events
+    pub id: MissileId,
+    /// Ship that fired it
+    pub launcher: ShipId,
+    /// Faction of the launcher, whose ships the blast spares
+    pub faction: FactionId,
+    /// Ship it flies toward
+    pub target: ShipId,
+    /// Current position in the galaxy
+    pub position: Vector2,
+    /// Position a tick earlier, to draw the missile between ticks
+    pub previous_position: Vector2,
+    /// Warhead damage
+    pub damage: i32,
+    /// Chance in percent of the target shooting the missile down
+    pub point_defense: i32,
+    /// Tick it was fired; it burns out `MISSILE_FLIGHT_TICKS` later
+    pub launch_tick: u64,
+    /// Interception roll state
+    rng: u64,
+}
+
+/// Where a missile struck or was shot down, kept briefly for the renderer
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MissileBlast {
+    /// Where it happened
+    pub position: Vector2,
+    /// Tick it happened
+    pub tick: u64,
+    /// Shot down by point defense rather than a hit
+    pub intercepted: bool,
+}
+
+/// Represents an active combat engagement against a planet
+#[derive(Debug, Clone)]
+pub struct Battle {
+    /// ID of the attacking ship
+    pub attacker: ShipId,
+    /// ID of the defending ship (None for planetary invasions)
+    pub defender: Option<ShipId>,
+    /// Location where the battle is taking place
+    pub location: Vector2,
+    /// Game tick when the battle started
+    pub start_tick: u64,
+    /// ID of the planet being invaded (for planetary invasions only)
+    pub planet_id: Option<PlanetId>,
+    /// Faction ID of the attacker
+    pub attacker_faction: FactionId,
+    /// Faction ID of the defender
+    pub defender_faction: FactionId,
+}
+
+/// Share of the opposing force each side puts out of action per tick of ground combat
+pub const GROUND_COMBAT_CASUALTY_PERCENT: i32 = 25;
+/// Fighting strength of a planet's garrison relative to landed troops
+pub const GARRISON_STRENGTH_PERCENT: i32 = 150;
+/// Chance in percent that a tick of bombardment strikes a building rather than the population
+pub const BOMBARDMENT_BUILDING_PERCENT: i32 = 25;
+/// Population killed by a tick of bombardment that misses the buildings
+pub const BOMBARDMENT_CASUALTIES: i32 = 3;
+/// Relation the bombarded faction loses with the attacker for every strike
+pub const BOMBARDMENT_RELATION_PENALTY: i32 = -2;
+
+/// Troops fighting a planet's garrison on the ground
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Invasion {
+    /// Planet being invaded
+    pub planet: PlanetId,
+    /// Faction landing the troops
+    pub invader: FactionId,
+    /// Faction holding the planet
+    pub defender: FactionId,
+    /// Invading troops still fighting
+    pub troops: i32,
+    /// Defending military workers still fighting
+    pub garrison: i32,
+    /// Tick of the latest round; the first round is fought the tick after landing
+    pub last_round_tick: u64,
+}
+
+impl Invasion {
+    /// Losses of (attacker, defender) in the next round, from the counts before it
+    pub fn round_losses(&self) -> (i32, i32) {
+        // Strength is in hundredths of a soldier; each side puts the casualty
+        // share of its strength out of action, rounding up
+        let casualties = |soldiers: i32, percent: i32| {
+            (soldiers.max(0) as u64 * percent as u64 * GROUND_COMBAT_CASUALTY_PERCENT as u64).div_ceil(100 * 100) as i32
+        };
+        let attacker_losses = casualties(self.garrison, GARRISON_STRENGTH_PERCENT);
+        let defender_losses = casualties(self.troops, 100);
+        (attacker_losses.min(self.troops), defender_losses.min(self.garrison))
+    }
+}
+
+/// A warship striking the planet it orbits once per tick
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bombardment {
+    /// The bombarding warship
+    pub ship: ShipId,
+    /// Faction of the warship
+    pub attacker: FactionId,
+    /// The planet under fire
+    pub planet: PlanetId,
+    /// Controller of the planet when the bombardment began
+    pub defender: FactionId,
+    /// Tick of the latest strike; the first falls the tick after the order
+    pub last_strike_tick: u64,
+    /// Strike roll state
+    rng: u64,
+}
+
+impl Bombardment {
+    /// Roll what the next strike hits
+    fn roll_strike(&mut self) -> BombardmentStrike {
+        if unit(&mut self.rng) * 100.0 < BOMBARDMENT_BUILDING_PERCENT as f32 {
+            self.rng = next_seed(self.rng);
+            BombardmentStrike::Building { pick: (self.rng >> 32) as u32 }
+        } else {
+            BombardmentStrike::Population { casualties: BOMBARDMENT_CASUALTIES }
+        }
+    }
+}
+
+/// Warships holding orbit around one planet, split by side
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OrbitalPresence {
+    /// Warships of factions at war with the planet's controller
+    pub hostile_warships: usize,
+    /// Warships of the planet's controller
+    pub defending_warships: usize,
+}
+
+impl OrbitalPresence {
+    /// Hostile warships in orbit with no defending fleet to contest them
+    pub fn is_blockade(&self) -> bool {
+        self.hostile_warships > 0 && self.defending_warships == 0
+    }
+}
+
+/// System responsible for resolving combat between ships and planetary invasions
+/// Operates on a delayed resolution model for deterministic gameplay
+///
+/// Ship battles are fought one round per tick from the tick after the
+/// attack. [`CombatResolver::begin_ordered_combat`] starts them with each
+/// ship's [`Combatant`] state, read from ShipManager. Every round emits
+/// `SimulationEvent::ShipCombat` with the hull damage dealt; a ship whose
+/// hull falls below [`RETREAT_HULL_PERCENT`] breaks off with
+/// `SimulationEvent::ShipRetreated`, and the end of a battle is announced
+/// with `SimulationEvent::CombatResolved`. Each battle's damage rolls follow
+/// from a seed drawn from the combat stream of the game's
+/// `SimRng`, so a replayed game fights the same battles.
+///
+/// With missiles enabled, ships whose combat stats carry a warhead also
+/// launch a missile at their opponent in their first round and every
+/// [`MISSILE_RELOAD_ROUNDS`] rounds after. Missiles fly [`MISSILE_SPEED`] AU
+/// a tick toward where the target was last tracked, so they take a
+/// few ticks to arrive. The target's point defense may shoot each one down;
+/// otherwise the warhead hits the target's shields and hull and
+/// [`MISSILE_SPLASH_PERCENT`] of it every other fighting ship of another
+/// faction within [`MISSILE_BLAST_RADIUS`]. Missiles whose target is no
+/// longer fighting burn out. Launches, interceptions and impacts are
+/// announced as simulation events, and a battle whose ship a missile
+/// destroyed ends without another round.
+///
+/// Warships ordered to bombard the planet they orbit strike it once per
+/// tick from the tick after the order, hitting a building
+/// [`BOMBARDMENT_BUILDING_PERCENT`] of the time and the population
+/// otherwise, and every strike costs relation with the planet's faction.
+/// PlanetManager applies the strikes, and [`CombatResolver::check_bombardments`]
+/// ends bombardments whose ship has left orbit or whose planet changed hands.
+///
+/// Also maintains the set of blockaded planets, surveyed from the warships
+/// orbiting each planet once per tick; a blockaded planet cannot
+/// build ships or take part in trade routes, and its population declines.
+pub struct CombatResolver {
+    active_battles: Vec<Battle>,
+    ship_battles: Vec<ShipBattle>,
+    combat_modifiers: HashMap<FactionId, f32>,
+    current_tick: u64,
+    /// Planetary battle results as (attacker, defending ship or 0, outcome)
+    pending_battle_results: Vec<(ShipId, ShipId, CombatOutcome)>,
+    blockades: BTreeSet<PlanetId>,
+    pending_state_changes: Vec<StateChange>,
+    invasions: Vec<Invasion>,
+    bombardments: Vec<Bombardment>,
+    /// Ground and ship combat events to emit on the next update
+    pending_combat_events: Vec<SimulationEvent>,
+    missiles_enabled: bool,
+    missiles: Vec<Missile>,
+    next_missile_id: MissileId,
+    /// Ship positions tracked for the current tick
+    ship_positions: BTreeMap<ShipId, Vector2>,
+    blasts: Vec<MissileBlast>,
+}
+
+impl CombatResolver {
+    /// Creates a new CombatResolver instance
+    pub fn new() -> Self {
+        Self {
+            active_battles: Vec::new(),
+            ship_battles: Vec::new(),
+            combat_modifiers: HashMap::new(),
+            current_tick: 0,
+            pending_battle_results: Vec::new(),
+            blockades: BTreeSet::new(),
+            pending_state_changes: Vec::new(),
+            invasions: Vec::new(),
+            bombardments: Vec::new(),
+            pending_combat_events: Vec::new(),
+            missiles_enabled: false,
+            missiles: Vec::new(),
+            next_missile_id: 0,
+            ship_positions: BTreeMap::new(),
+            blasts: Vec::new(),
+        }
+    }
+    
+    /// Updates the combat resolver, processing active battles and emitting results
+    pub fn update(&mut self, _delta: f32, event_bus: &mut EventBus) -> GameResult<()> {
+        // Emit any pending combat results from previous tick
+        self.emit_pending_results(event_bus)?;
+        
+        // Process battles that are ready to resolve (1 tick delay for deterministic combat)
+        let mut battles_to_resolve = Vec::new();
+        self.active_battles.retain(|battle| {
+            if self.current_tick > battle.start_tick {
+                battles_to_resolve.push(battle.clone());
+                false // Remove from active battles
+            } else {
+                true // Keep in active battles
+            }
+        });
+        
+        for battle in battles_to_resolve {
+            self.resolve_battle(&battle)?;
+        }
+        
+        self.fly_missiles();
+        self.fight_ship_rounds();
+        self.fight_ground_rounds();
+        self.strike_bombarded_planets();
+        for event in self.pending_combat_events.drain(..) {
+            event_bus.queue_event(GameEvent::SimulationEvent(event));
+        }
+        
+        Ok(())
+    }
+    
+    /// Handles incoming events, processing simulation updates
+    pub fn handle_event(&mut self, event: &GameEvent) -> GameResult<()> {
+        // Ship battles are started through begin_ordered_combat, since the
+        // ships' stats live with ShipManager
+        if let GameEvent::SimulationEvent(sim_event) = event {
+            match sim_event {
+                SimulationEvent::TickCompleted(tick) => {
+                    self.current_tick = *tick;
+                }
+                SimulationEvent::ShipArrived { ship, destination } => {
+                    self.check_for_automatic_combat(*ship, *destination)?
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+    
+    /// Start a battle between two ships; the first round is fought the tick
+    /// after. The damage rolls follow from `seed`.
+    pub fn begin_ship_combat(&mut self, attacker: Combatant, defender: Combatant, location: Vector2, seed: u64) -> GameResult<()> {
+        if attacker.ship == defender.ship {
+            return Err(GameError::InvalidOperation("Ship cannot attack itself".into()));
+        }
+        for ship in [attacker.ship, defender.ship] {
+            if self.is_ship_in_combat(ship) {
+                return Err(GameError::InvalidOperation(
+                    format!("Ship {} is already in combat", ship)
+                ));
+            }
+        }
+        
+        self.ship_battles.push(ShipBattle {
+            attacker,
+            defender,
+            location,
+            start_tick: self.current_tick,
+            rounds: 0,
+            attacker_damage: 0,
+            defender_damage: 0,
+            last_round_tick: self.current_tick,
+            rng: next_seed(seed),
+        });
+        Ok(())
+    }
+    
+    /// Fight one round of every ship battle that has not yet fought this tick,
+    /// and settle the battles that are over
+    fn fight_ship_rounds(&mut self) {
+        let current_tick = self.current_tick;
+        let modifiers = &self.combat_modifiers;
+        let modifier = |faction| modifiers.get(&faction).copied().unwrap_or(1.0);
+        let mut events = Vec::new();
+        let mut launches = Vec::new();
+        self.ship_battles.retain_mut(|battle| {
+            if current_tick <= battle.last_round_tick {
+                return true;
+            }
+            battle.last_round_tick = current_tick;
+            
+            // A ship already put out of the fight by a missile fires no more
+            if !battle.attacker.is_out() && !battle.defender.is_out() {
+                let (attacker_damage, defender_damage) = battle.fight_round(
+                    modifier(battle.attacker.faction),
+                    modifier(battle.defender.faction),
+                );
+                events.push(SimulationEvent::ShipCombat {
+                    attacker: battle.attacker.ship,
+                    defender: battle.defender.ship,
+                    attacker_damage,
+                    defender_damage,
+                });
+                if (battle.rounds - 1) % MISSILE_RELOAD_ROUNDS == 0 {
+                    for (shooter, target) in [(battle.attacker, battle.defender), (battle.defender, battle.attacker)] {
+                        if shooter.stats.missiles > 0 && !shooter.is_out() && !target.is_out() {
+                            launches.push((shooter, target, battle.location, next_seed(battle.rng)));
+                        }
+                    }
+                }
+            }
+            
+            let Some(outcome) = battle.outcome() else {
+                return true;
+            };
+            events.extend(outcome.retreated.iter().map(|ship| SimulationEvent::ShipRetreated { ship: *ship }));
+            events.push(SimulationEvent::CombatResolved {
+                attacker: battle.attacker.ship,
+                defender: battle.defender.ship,
+                outcome,
+            });
+            false
+        });
+        self.pending_combat_events.extend(events);
+        if self.missiles_enabled {
+            for (shooter, target, location, seed) in launches {
+                self.launch_missile(shooter, target, location, seed);
+            }
+        }
+    }
+    
+    /// Fire a missile from the shooter's reported position, or the battle's
+    /// location if it has none
+    fn launch_missile(&mut self, shooter: Combatant, target: Combatant, location: Vector2, seed: u64) {
+        let id = self.next_missile_id;
+        self.next_missile_id += 1;
+        let position = self.ship_positions.get(&shooter.ship).copied().unwrap_or(location);
+        self.missiles.push(Missile {
+            id,
+            launcher: shooter.ship,
+            faction: shooter.faction,
+            target: target.ship,
+            position,
+            previous_position: position,
+            damage: shooter.stats.missiles,
+            point_defense: target.stats.point_defense,
+            launch_tick: self.current_tick,
+            rng: next_seed(seed ^ id as u64),
+        });
+        self.pending_combat_events.push(SimulationEvent::MissileLaunched { missile: id, launcher: shooter.ship, target: target.ship });
+    }
+    
+    /// Move every missile launched before this tick toward its target and
+    /// settle those that reach it
+    fn fly_missiles(&mut self) {
+        let current_tick = self.current_tick;
+        self.blasts.retain(|blast| current_tick < blast.tick + MISSILE_EFFECT_TICKS);
+        let missiles = std::mem::take(&mut self.missiles);
+        for mut missile in missiles {
+            if current_tick <= missile.launch_tick {
+                self.missiles.push(missile);
+                continue;
+            }
+            if !self.is_ship_in_combat(missile.target) || current_tick > missile.launch_tick + MISSILE_FLIGHT_TICKS {
+                continue;
+            }
+            let target = self.ship_positions.get(&missile.target).copied().unwrap_or(missile.position);
+            missile.previous_position = missile.position;
+            let distance = missile.position.distance_to(&target);
+            if distance > MISSILE_SPEED {
+                let step = MISSILE_SPEED / distance;
+                missile.position = Vector2::new(
+                    missile.position.x + (target.x - missile.position.x) * step,
+                    missile.position.y + (target.y - missile.position.y) * step,
+                );
+                self.missiles.push(missile);
+                continue;
+            }
+            missile.position = target;
+            self.detonate(missile);
+        }
+    }
+    
+    /// Roll the target's point defense, then hit the target and the other
+    /// fighting ships in the blast
+    fn detonate(&mut self, mut missile: Missile) {
+        let intercepted = unit(&mut missile.rng) * 100.0 < missile.point_defense as f32;
+        self.blasts.push(MissileBlast { position: missile.position, tick: self.current_tick, intercepted });
+        if intercepted {
+            self.pending_combat_events.push(SimulationEvent::MissileIntercepted { missile: missile.id, target: missile.target });
+            return;
+        }
+        
+        let positions = &self.ship_positions;
+        let mut hits = Vec::new();
+        for battle in &mut self.ship_battles {
+            let sides = [(&mut battle.attacker, &mut battle.attacker_damage), (&mut battle.defender, &mut battle.defender_damage)];
+            for (combatant, damage_taken) in sides {
+                let caught = combatant.ship == missile.target || (combatant.faction != missile.faction
+                    && positions.get(&combatant.ship).is_some_and(|position| position.distance_to(&missile.position) <= MISSILE_BLAST_RADIUS));
+                if !caught || combatant.is_destroyed() {
+                    continue;
+                }
+                let damage = if combatant.ship == missile.target { missile.damage } else { missile.damage * MISSILE_SPLASH_PERCENT / 100 };
+                let hull_damage = combatant.take_damage(damage);
+                *damage_taken += hull_damage;
+                if hull_damage > 0 {
+                    hits.push((combatant.ship, hull_damage));
+                }
+            }
+        }
+        self.pending_combat_events.push(SimulationEvent::MissileImpact {
+            missile: missile.id,
+            target: missile.target,
+            position: missile.position,
+            hits,
+        });
+    }
+    
+    /// Fire missiles in ship battles from now on, or stop; missiles already
+    /// in flight fly on
+    pub fn set_missiles_enabled(&mut self, enabled: bool) {
+        self.missiles_enabled = enabled;
+    }
+    
+    /// True when ship battles are fought with missiles
+    pub fn missiles_enabled(&self) -> bool {
+        self.missiles_enabled
+    }
+    
+    /// Where the ships are this tick, for aiming and flying missiles
+    pub fn set_ship_positions(&mut self, positions: BTreeMap<ShipId, Vector2>) {
+        self.ship_positions = positions;
+    }
+    
+    /// Missiles in flight, in launch order
+    pub fn missiles(&self) -> &[Missile] {
+        &self.missiles
+    }
+    
+    /// Impacts and interceptions of the last [`MISSILE_EFFECT_TICKS`] ticks
+    pub fn recent_blasts(&self) -> &[MissileBlast] {
+        &self.blasts
+    }
+    
+    /// Ship battles under way, in the order they began
+    pub fn get_ship_battles(&self) -> &[ShipBattle] {
+        &self.ship_battles
+    }
+    
+    fn check_for_automatic_combat(&mut self, ship_id: ShipId, location: Vector2) -> GameResult<()> {
+        // Validate input parameters
+        if !location.x.is_finite() || !location.y.is_finite() {
+            return Err(GameError::InvalidOperation(
+                "Invalid location coordinates for combat check".into()
+            ));
+        }
+        
+        // Check if ship is already in combat
+        if self.is_ship_in_combat(ship_id) {
+            return Ok(()); // Already fighting, don't start another battle
+        }
+        
+        // Combat detection requires access to ShipManager and PlanetManager
+        // This will be handled by the GameState which can access both managers
+        // For now, just log that a ship has arrived
+        
+        Ok(())
+    }
+    
+    fn resolve_battle(&mut self, battle: &Battle) -> GameResult<()> {
+        // Ship battles are fought round by round in fight_ship_rounds
+        let outcome = self.resolve_planetary_combat(battle)?;
+        
+        // Store result to be emitted next update cycle
+        self.pending_battle_results.push((battle.attacker, battle.defender.unwrap_or_default(), outcome));
+        
+        Ok(())
+    }
+    
+    fn resolve_planetary_combat(&self, battle: &Battle) -> GameResult<CombatOutcome> {
+        // Planetary invasion logic
+        // For now, make planetary defenses strong
+        let planet_wins = true; // Simplified logic
+        
+        let (attacker_losses, defender_losses) = if planet_wins {
+            (vec![battle.attacker], Vec::new())
+        } else {
+            (Vec::new(), Vec::new()) // No ships destroyed in successful invasion
+        };
+        
+        let winner = if planet_wins {
+            battle.defender_faction
+        } else {
+            battle.attacker_faction
+        };
+        
+        Ok(CombatOutcome {
+            winner,
+            attacker_losses,
+            defender_losses,
+            retreated: Vec::new(),
+            rounds: 1,
+            attacker_damage: 0,
+            defender_damage: 0,
+        })
+    }
+    
+    fn emit_pending_results(&mut self, event_bus: &mut EventBus) -> GameResult<()> {
+        for change in self.pending_state_changes.drain(..) {
+            event_bus.queue_event(GameEvent::StateChanged(change));
+        }
+        for (attacker, defender, outcome) in self.pending_battle_results.drain(..) {
+            event_bus.queue_event(GameEvent::SimulationEvent(
+                SimulationEvent::CombatResolved { attacker, defender, outcome }
+            ));
+        }
+        Ok(())
+    }
+    
+    /// Ships other than `ship` within [`ENGAGEMENT_RANGE`] of `position`,
+    /// by id, according to the PhysicsEngine's index
+    pub fn ships_in_engagement_range(&self, index: &SpatialIndex, ship: ShipId, position: Vector2) -> Vec<ShipId> {
+        index.ships_within(position, ENGAGEMENT_RANGE).into_iter()
+            .map(|(other, _)| other)
+            .filter(|other| *other != ship)
+            .collect()
+    }
+    
+    /// Check if a ship is currently engaged in combat
+    pub fn is_ship_in_combat(&self, ship_id: ShipId) -> bool {
+        self.ship_battles.iter().any(|battle| battle.attacker.ship == ship_id || battle.defender.ship == ship_id)
+            || self.active_battles.iter().any(|battle| {
+                battle.attacker == ship_id || 
+                battle.defender.is_some_and(|defender| defender == ship_id)
+            })
+    }
+    
+    /// Calculate combat strength for a ship based on its class
+    pub fn calculate_ship_strength(&self, ship_class: ShipClass) -> f32 {
+        match ship_class {
+            ShipClass::Scout => 1.0,
+            ShipClass::Transport => 0.5,
+            ShipClass::Warship => 5.0,
+            ShipClass::Colony => 0.1,
+        }
+    }
+    
+    /// Calculate total combat strength for a fleet
+    #[allow(dead_code)]
+    fn calculate_fleet_strength(&self, ships: &[ShipClass], faction_modifier: f32) -> f32 {
+        let base_strength: f32 = ships.iter()
+            .map(|ship_class| self.calculate_ship_strength(*ship_class))
+            .sum();
+        
+        base_strength * faction_modifier
+    }
+    
+    /// Determine combat outcome based on strength comparison
+    #[allow(dead_code)]
+    fn determine_outcome(&self, attacker_strength: f32, defender_strength: f32, is_planetary: bool) -> (bool, f32, f32) {
+        let effective_defender_strength = if is_planetary {
+            defender_strength * 2.0 // Planetary defense multiplies by 2
+        } else {
+            defender_strength
+        };
+        
+        let attacker_wins = attacker_strength >= effective_defender_strength * 1.5;
+        
+        let (attacker_losses, defender_losses) = if attacker_wins {
+            (0.3, 0.5) // Attacker 30%, Defender 50% losses
+        } else {
+            (0.5, 0.3) // Attacker 50%, Defender 30% losses
+        };
+        
+        (attacker_wins, attacker_losses, defender_losses)
+    }
+    
+    /// Set combat modifier for a faction
+    pub fn set_combat_modifier(&mut self, faction: FactionId, modifier: f32) {
+        self.combat_modifiers.insert(faction, modifier);
+    }
+    
+    /// Get combat modifier for a faction (defaults to 1.0)
+    pub fn get_combat_modifier(&self, faction: FactionId) -> f32 {
+        self.combat_modifiers.get(&faction).copied().unwrap_or(1.0)
+    }
+    
+    /// Initiate planetary invasion
+    pub fn initiate_planetary_invasion(&mut self, attacker: ShipId, planet: PlanetId, location: Vector2, attacker_faction: FactionId, defender_faction: FactionId) -> GameResult<()> {
+        // Validate input parameters
+        if !location.x.is_finite() || !location.y.is_finite() {
+            return Err(GameError::InvalidOperation(
+                "Invalid location coordinates for planetary invasion".into()
+            ));
+        }
+        
+        // Check if ship is already in combat
+        if self.is_ship_in_combat(attacker) {
+            return Err(GameError::InvalidOperation(
+                format!("Ship {} is already in combat", attacker)
+            ));
+        }
+        
+        let battle = Battle {
+            attacker,
+            defender: None, // No defending ship for planetary invasion
+            location,
+            start_tick: self.current_tick,
+            planet_id: Some(planet),
+            attacker_faction,
+            defender_faction,
+        };
+        
+        self.active_battles.push(battle);
+        Ok(())
+    }
+    
+    /// Check if there are any active battles
+    pub fn has_active_battles(&self) -> bool {
+        !self.active_battles.is_empty() || !self.ship_battles.is_empty()
+    }
+    
+    /// Get number of active battles, ship battles included
+    pub fn get_active_battle_count(&self) -> usize {
+        self.active_battles.len() + self.ship_battles.len()
+    }
+    
+    /// Land `troops` on a planet held by `garrison` defending military
+    /// workers. Ground combat runs one round per tick from the next tick
+    /// until one side has no one left standing.
+    pub fn begin_invasion(&mut self, planet: PlanetId, invader: FactionId, defender: FactionId, troops: i32, garrison: i32) -> GameResult<()> {
+        if troops <= 0 {
+            return Err(GameError::InvalidOperation("An invasion needs troops".into()));
+        }
+        if garrison < 0 {
+            return Err(GameError::InvalidOperation("Garrison cannot be negative".into()));
+        }
+        if self.is_planet_invaded(planet) {
+            return Err(GameError::InvalidOperation(
+                format!("Planet {} is already being invaded", planet)
+            ));
+        }
+        
+        self.invasions.push(Invasion {
+            planet,
+            invader,
+            defender,
+            troops,
+            garrison,
+            last_round_tick: self.current_tick,
+        });
+        Ok(())
+    }
+    
+    /// Fight one round of every invasion that has not yet fought this tick
+    fn fight_ground_rounds(&mut self) {
+        let current_tick = self.current_tick;
+        let mut events = Vec::new();
+        self.invasions.retain_mut(|invasion| {
+            if current_tick <= invasion.last_round_tick {
+                return true;
+            }
+            invasion.last_round_tick = current_tick;
+            
+            let (attacker_losses, defender_losses) = invasion.round_losses();
+            invasion.troops -= attacker_losses;
+            invasion.garrison -= defender_losses;
+            if attacker_losses > 0 || defender_losses > 0 {
+                events.push(SimulationEvent::GroundCombat { planet: invasion.planet, attacker_losses, defender_losses });
+            }
+            
+            // The garrison holds if both sides fall in the same round
+            if invasion.troops <= 0 {
+                events.push(SimulationEvent::InvasionRepelled { planet: invasion.planet, invader: invasion.invader });
+                false
+            } else if invasion.garrison <= 0 {
+                events.push(SimulationEvent::PlanetInvaded {
+                    planet: invasion.planet,
+                    invader: invasion.invader,
+                    survivors: invasion.troops,
+                });
+                events.push(SimulationEvent::PlanetConquered { planet: invasion.planet, new_owner: invasion.invader });
+                false
+            } else {
+                true
+            }
+        });
+        self.pending_combat_events.extend(events);
+    }
+    
+    /// Check if ground combat is under way on a planet
+    pub fn is_planet_invaded(&self, planet: PlanetId) -> bool {
+        self.invasions.iter().any(|invasion| invasion.planet == planet)
+    }
+    
+    /// Invasions under way, in the order they began
+    pub fn get_invasions(&self) -> &[Invasion] {
+        &self.invasions
+    }
+    
+    /// Have a warship bombard an enemy planet; the first strike falls the
+    /// tick after and the strike rolls follow from `seed`
+    pub fn begin_bombardment(&mut self, ship: ShipId, attacker: FactionId, planet: PlanetId, defender: FactionId, seed: u64) -> GameResult<()> {
+        if attacker == defender {
+            return Err(GameError::InvalidTarget(format!("Faction {} cannot bombard its own planet {}", attacker, planet)));
+        }
+        if self.is_ship_bombarding(ship) {
+            return Err(GameError::InvalidOperation(format!("Ship {} is already bombarding a planet", ship)));
+        }
+        self.bombardments.push(Bombardment {
+            ship,
+            attacker,
+            planet,
+            defender,
+            last_strike_tick: self.current_tick,
+            rng: next_seed(seed),
+        });
+        Ok(())
+    }
+    
+    /// Stop the ship's bombardment; returns false if it was not bombarding
+    pub fn end_bombardment(&mut self, ship: ShipId) -> bool {
+        let before = self.bombardments.len();
+        self.bombardments.retain(|bombardment| bombardment.ship != ship);
+        self.bombardments.len() != before
+    }
+    
+    /// Strike every bombarded planet that has not been struck this tick
+    fn strike_bombarded_planets(&mut self) {
+        let current_tick = self.current_tick;
+        for bombardment in &mut self.bombardments {
+            if current_tick <= bombardment.last_strike_tick {
+                continue;
+            }
+            bombardment.last_strike_tick = current_tick;
+            let strike = bombardment.roll_strike();
+            self.pending_combat_events.push(SimulationEvent::PlanetBombarded {
+                planet: bombardment.planet,
+                ship: bombardment.ship,
+                strike,
+            });
+            self.pending_combat_events.push(SimulationEvent::RelationChanged {
+                parties: (bombardment.attacker, bombardment.defender),
+                delta: BOMBARDMENT_RELATION_PENALTY,
+            });
+        }
+    }
+    
+    /// Check if a ship is bombarding a planet
+    pub fn is_ship_bombarding(&self, ship: ShipId) -> bool {
+        self.bombardments.iter().any(|bombardment| bombardment.ship == ship)
+    }
+    
+    /// Bombardments under way, in the order they began
+    pub fn get_bombardments(&self) -> &[Bombardment] {
+        &self.bombardments
+    }
+    
+    /// Recompute blockades from the warships orbiting each planet.
+    ///
+    /// Planets missing from `presence` have no warships in orbit. Blockades
+    /// that begin or end are announced as state changes on the next update.
+    pub fn update_blockades(&mut self, presence: &BTreeMap<PlanetId, OrbitalPresence>) {
+        let blockades: BTreeSet<PlanetId> = presence.iter()
+            .filter(|(_, presence)| presence.is_blockade())
+            .map(|(planet, _)| *planet)
+            .collect();
+        
+        for planet in blockades.difference(&self.blockades) {
+            self.pending_state_changes.push(StateChange::PlanetBlockaded(*planet));
+        }
+        for planet in self.blockades.difference(&blockades) {
+            self.pending_state_changes.push(StateChange::BlockadeLifted(*planet));
+        }
+        self.blockades = blockades;
+    }
+    
+    /// Check if hostile warships currently blockade a planet
+    pub fn is_blockaded(&self, planet: PlanetId) -> bool {
+        self.blockades.contains(&planet)
+    }
+    
+    /// Blockaded planets in ID order
+    pub fn get_blockaded_planets(&self) -> impl Iterator<Item = PlanetId> + '_ {
+        self.blockades.iter().copied()
+    }
+    
+    /// Refuse orders a blockaded planet cannot carry out
+    pub fn check_not_blockaded(&self, planet: PlanetId) -> GameResult<()> {
+        if self.is_blockaded(planet) {
+            return Err(GameError::InvalidOperation(format!("Planet {} is blockaded", planet)));
+        }
+        Ok(())
+    }
+    
+    /// Count the warships orbiting each controlled planet by side and
+    /// recompute the blockades from them
+    pub fn survey_orbits(&mut self, planets: &PlanetManager, ships: &ShipManager, factions: &FactionManager) -> GameResult<()> {
+        let mut presence: BTreeMap<PlanetId, OrbitalPresence> = BTreeMap::new();
+        for ship in ships.get_all_ships().iter().filter(|ship| ship.ship_class == ShipClass::Warship) {
+            let ShipStatus::Orbiting(planet_id) = ship.status else {
+                continue;
+            };
+            let Some(controller) = planets.get_planet(planet_id)?.controller else {
+                continue;
+            };
+            let orbit = presence.entry(planet_id).or_default();
+            if ship.owner == controller {
+                orbit.defending_warships += 1;
+            } else if factions.get_faction(controller).is_ok_and(|faction| faction.is_at_war_with(ship.owner)) {
+                orbit.hostile_warships += 1;
+            }
+        }
+        self.update_blockades(&presence);
+        Ok(())
+    }
+    
+    /// End bombardments whose warship has left orbit, or whose planet changed
+    /// hands or is now held by a treaty partner
+    pub fn check_bombardments(&mut self, planets: &PlanetManager, ships: &ShipManager, factions: &FactionManager) {
+        let ended: Vec<ShipId> = self.bombardments.iter()
+            .filter(|bombardment| {
+                let in_orbit = ships.get_ship(bombardment.ship)
+                    .is_ok_and(|ship| ship.status == ShipStatus::Orbiting(bombardment.planet));
+                let held = planets.get_planet(bombardment.planet)
+                    .is_ok_and(|planet| planet.controller == Some(bombardment.defender));
+                let hostile = factions.relation(bombardment.attacker, bombardment.defender)
+                    .is_ok_and(|relation| relation.allows_attack());
+                !(in_orbit && held && hostile)
+            })
+            .map(|bombardment| bombardment.ship)
+            .collect();
+        for ship in ended {
+            self.end_bombardment(ship);
+        }
+    }
+    
+    /// Fight with missiles or not, and while battles are under way or
+    /// missiles in flight, follow where the ships are
+    pub fn track_missile_targets(&mut self, enabled: bool, ships: &ShipManager) {
+        self.set_missiles_enabled(enabled);
+        let in_use = (enabled && self.has_active_battles()) || !self.missiles.is_empty();
+        let positions = if in_use {
+            ships.get_all_ships().iter().map(|ship| (ship.id, ship.position)).collect()
+        } else {
+            BTreeMap::new()
+        };
+        self.set_ship_positions(positions);
+    }
+    
+    /// The faction's warships orbiting an enemy planet that are free to bombard it
+    pub fn bombardment_ships(&self, planet: &Planet, faction: FactionId, ships: &ShipManager) -> Vec<ShipId> {
+        if planet.controller.is_none_or(|controller| controller == faction) {
+            return Vec::new();
+        }
+        ships.get_all_ships().iter()
+            .filter(|ship| ship.owner == faction && ship.ship_class == ShipClass::Warship)
+            .filter(|ship| ship.status == ShipStatus::Orbiting(planet.id) && !self.is_ship_bombarding(ship.id))
+            .map(|ship| ship.id)
+            .collect()
+    }
+    
+    /// Check an attack, invasion or bombardment order against the treaties
+    /// of the factions involved and start the fighting it calls for. Ships
+    /// attack at the supply `effectiveness` of each, in percent.
+    ///
+    /// Fighting a faction without a treaty declares war on it. Troops are
+    /// counted aboard the invading ship; ShipManager puts them ashore
+    /// afterwards.
+    pub fn begin_ordered_combat(&mut self, event: &GameEvent, effectiveness: impl Fn(ShipId) -> i32, context: &mut SystemContext<'_>) -> GameResult<()> {
+        let GameEvent::PlayerCommand(command) = event else {
+            return Ok(());
+        };
+        Self::validate_hostilities(command, context)?;
+        let (ships, planets) = (context.ships(), context.planets());
+        match command {
+            PlayerCommand::InvadePlanet { ship, planet } => {
+                let ship_data = ships.get_ship(*ship)?;
+                let planet_data = planets.get_planet(*planet)?;
+                if !matches!(ship_data.ship_class, ShipClass::Warship | ShipClass::Transport) {
+                    return Err(GameError::unsuitable_ship(ship_data, "carry troops"));
+                }
+                if ship_data.status != ShipStatus::Orbiting(*planet) {
+                    return Err(GameError::NotInOrbit { ship: *ship, planet: *planet });
+                }
+                let defender = planet_data.controller
+                    .filter(|controller| *controller != ship_data.owner)
+                    .ok_or_else(|| GameError::InvalidTarget(format!("Planet {} has no enemy garrison to invade", planet)))?;
+                if ship_data.cargo.population <= 0 {
+                    return Err(GameError::InvalidTarget(format!("Ship {} has no troops aboard", ship)));
+                }
+                
+                self.begin_invasion(
+                    *planet,
+                    ship_data.owner,
+                    defender,
+                    ship_data.cargo.population,
+                    planet_data.population.allocation.military,
+                )
+            }
+            // CommandValidator has checked the warship is in orbit of an enemy planet
+            PlayerCommand::BombardPlanet { ship, planet } => {
+                let attacker = ships.get_ship(*ship)?.owner;
+                let defender = planets.get_planet(*planet)?.controller
+                    .filter(|controller| *controller != attacker)
+                    .ok_or_else(|| GameError::InvalidTarget(format!("Planet {} has no enemy to bombard", planet)))?;
+                let seed = context.event_bus().rng.next_u64(RngStream::Combat);
+                self.begin_bombardment(*ship, attacker, *planet, defender, seed)
+            }
+            PlayerCommand::AttackTarget { attacker, target } => {
+                // Ships out of supply fight at their reduced effectiveness
+                let combatant = |ship_id: ShipId| -> GameResult<Combatant> {
+                    let ship = ships.get_ship(ship_id)?;
+                    let stats = ships.ship_stats(ship)
+                        .ok_or_else(|| GameError::InvalidTarget(format!("No game data for {:?}", ship.ship_class)))?;
+                    let mut combat = stats.combat;
+                    combat.attack = combat.attack * effectiveness(ship_id) / 100;
+                    Ok(Combatant::new(ship, combat))
+                };
+                let (attacker, defender) = (combatant(*attacker)?, combatant(*target)?);
+                let location = ships.get_ship(attacker.ship)?.position;
+                let seed = context.event_bus().rng.next_u64(RngStream::Combat);
+                self.begin_ship_combat(attacker, defender, location, seed)
+            }
+            _ => Ok(()),
+        }
+    }
+    
+    /// Refuse attacks and invasions against treaty partners; fighting a
+    /// faction without a treaty declares war on it
+    fn validate_hostilities(command: &PlayerCommand, context: &mut SystemContext<'_>) -> GameResult<()> {
+        let (ships, planets) = (context.ships(), context.planets());
+        let (attacker, defender) = match command {
+            PlayerCommand::AttackTarget { attacker, target } => {
+                let attacker = ships.get_ship(*attacker)?.owner;
+                (attacker, ships.get_ship(*target)?.owner)
+            }
+            PlayerCommand::InvadePlanet { ship, planet }
+            | PlayerCommand::BombardPlanet { ship, planet } => {
+                let attacker = ships.get_ship(*ship)?.owner;
+                match planets.get_planet(*planet)?.controller {
+                    Some(owner) => (attacker, owner),
+                    None => return Ok(()),
+                }
+            }
+            _ => return Ok(()),
+        };
+        if attacker == defender {
+            return Ok(());
+        }
+        
+        let relation = context.factions().relation(attacker, defender)?;
+        if !relation.allows_attack() {
+            return Err(GameError::InvalidOperation(format!(
+                "Faction {} has a treaty with faction {}; declare war first", attacker, defender
+            )));
+        }
+        if !relation.at_war {
+            context.queue_event(GameEvent::PlayerCommand(
+                PlayerCommand::DeclareWar { from: attacker, to: defender }
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl GameSystem for CombatResolver {
+    fn update(&mut self, delta: f32, context: &mut SystemContext<'_>) -> GameResult<()> {
+        self.update(delta, context.event_bus())
+    }
+    
+    fn handle_event(&mut self, event: &GameEvent) -> GameResult<()> {
+        self.handle_event(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn combatant(ship: ShipId, faction: FactionId, hull: i32, shields: i32, attack: i32) -> Combatant {
+        Combatant { ship, faction, stats: CombatStats { hull, shields, attack, ..Default::default() }, hull, shields }
+    }
+
+    /// Two default warships of factions 1 and 2
+    fn initiate_ship_combat(resolver: &mut CombatResolver, attacker: ShipId, defender: ShipId) -> GameResult<()> {
+        let location = Vector2 { x: 0.0, y: 0.0 };
+        resolver.begin_ship_combat(combatant(attacker, 1, 200, 40, 25), combatant(defender, 2, 200, 40, 25), location, 7)
+    }
+
+    /// Update tick by tick from `from` until no battle is left, collecting the events
+    fn fight_until_resolved(resolver: &mut CombatResolver, from: u64) -> Vec<SimulationEvent> {
+        let mut event_bus = EventBus::new();
+        let mut events = Vec::new();
+        for tick in from..from + MAX_BATTLE_ROUNDS as u64 + 2 {
+            resolver.current_tick = tick;
+            resolver.update(0.1, &mut event_bus).unwrap();
+            events.extend(event_bus.queued_events.drain(..).filter_map(|event| match event {
+                GameEvent::SimulationEvent(event) => Some(event),
+                _ => None,
+            }));
+        }
+        assert!(!resolver.has_active_battles());
+        events
+    }
+
+    #[test]
+    fn test_create_combat_resolver() {
+        let resolver = CombatResolver::new();
+        assert_eq!(resolver.get_active_battle_count(), 0);
+        assert!(!resolver.has_active_battles());
+    }
+
+    #[test]
+    fn test_ship_strength_calculation() {
+        let resolver = CombatResolver::new();
+        
+        assert_eq!(resolver.calculate_ship_strength(ShipClass::Scout), 1.0);
+        assert_eq!(resolver.calculate_ship_strength(ShipClass::Transport), 0.5);
+        assert_eq!(resolver.calculate_ship_strength(ShipClass::Warship), 5.0);
+        assert_eq!(resolver.calculate_ship_strength(ShipClass::Colony), 0.1);
+    }
+
+    #[test]
+    fn test_fleet_strength_calculation() {
+        let resolver = CombatResolver::new();
+        let fleet = vec![ShipClass::Warship, ShipClass::Scout, ShipClass::Transport];
+        
+        // Base strength: 5.0 + 1.0 + 0.5 = 6.5
+        let expected_strength = 6.5 * 1.2; // With 1.2 modifier
+        assert_eq!(resolver.calculate_fleet_strength(&fleet, 1.2), expected_strength);
+    }
+
+    #[test]
+    fn test_combat_outcome_determination() {
+        let resolver = CombatResolver::new();
+        
+        // Test attacker victory in space
+        let (attacker_wins, attacker_losses, defender_losses) = 
+            resolver.determine_outcome(15.0, 10.0, false);
+        assert!(attacker_wins); // 15.0 >= 10.0 * 1.5
+        assert_eq!(attacker_losses, 0.3);
+        assert_eq!(defender_losses, 0.5);
+        
+        // Test defender victory in space
+        let (attacker_wins, attacker_losses, defender_losses) = 
+            resolver.determine_outcome(10.0, 10.0, false);
+        assert!(!attacker_wins); // 10.0 < 10.0 * 1.5
+        assert_eq!(attacker_losses, 0.5);
+        assert_eq!(defender_losses, 0.3);
+        
+        // Test planetary defense bonus
+        let (attacker_wins, _, _) = 
+            resolver.determine_outcome(15.0, 10.0, true);
+        assert!(!attacker_wins); // 15.0 < (10.0 * 2.0) * 1.5 = 30.0
+    }
+
+    #[test]
+    fn test_combat_modifier() {
+        let mut resolver = CombatResolver::new();
+        
+        // Default modifier should be 1.0
+        assert_eq!(resolver.get_combat_modifier(1), 1.0);
+        
+        // Set and get custom modifier
+        resolver.set_combat_modifier(1, 1.5);
+        assert_eq!(resolver.get_combat_modifier(1), 1.5);
+    }
+
+    #[test]
+    fn test_initiate_combat() {
+        let mut resolver = CombatResolver::new();
+        
+        assert_eq!(resolver.get_active_battle_count(), 0);
+        
+        initiate_ship_combat(&mut resolver, 1, 2).unwrap();
+        assert_eq!(resolver.get_active_battle_count(), 1);
+        assert!(resolver.has_active_battles());
+        
+        // Test validation - ship cannot attack itself
+        assert!(initiate_ship_combat(&mut resolver, 3, 3).is_err());
+        
+        // Test ship already in combat
+        assert!(initiate_ship_combat(&mut resolver, 4, 1).is_err()); // Ship 1 already fighting
+    }
+
+    #[test]
+    fn test_planetary_invasion() {
+        let mut resolver = CombatResolver::new();
+        
+        let location = Vector2 { x: 100.0, y: 200.0 };
+        resolver.initiate_planetary_invasion(1, 5, location, 1, 2).unwrap();
+        
+        assert_eq!(resolver.get_active_battle_count(), 1);
+        assert!(resolver.has_active_battles());
+        
+        // Test validation - invalid coordinates
+        let invalid_location = Vector2 { x: f32::NAN, y: 200.0 };
+        assert!(resolver.initiate_planetary_invasion(2, 6, invalid_location, 1, 2).is_err());
+        
+        // Test ship already in combat
+        assert!(resolver.initiate_planetary_invasion(1, 7, location, 1, 2).is_err());
+    }
+
+    #[test]
+    fn test_resolve_battles_clears_active() {
+        let mut resolver = CombatResolver::new();
+        let mut event_bus = EventBus::new();
+        
+        // Set current tick to 4; a warship against an unarmed, flimsy ship
+        resolver.current_tick = 4;
+        let location = Vector2 { x: 0.0, y: 0.0 };
+        resolver.begin_ship_combat(combatant(1, 1, 200, 40, 25), combatant(2, 2, 10, 0, 0), location, 7).unwrap();
+        assert_eq!(resolver.get_active_battle_count(), 1);
+        
+        // The first round, at tick 5, destroys the defender
+        resolver.current_tick = 5;
+        resolver.update(0.1, &mut event_bus).unwrap();
+        assert_eq!(resolver.get_active_battle_count(), 0);
+        
+        // Should have emitted the round and the result
+        assert!(matches!(
+            event_bus.queued_events.front(),
+            Some(GameEvent::SimulationEvent(SimulationEvent::ShipCombat { attacker: 1, defender: 2, attacker_damage: 0, defender_damage: 10 }))
+        ));
+        
+        // Verify it's the correct event type
+        if let Some(GameEvent::SimulationEvent(SimulationEvent::CombatResolved { attacker: 1, defender: 2, outcome })) = event_bus.queued_events.back() {
+            // Verify outcome has expected structure
+            assert_eq!(outcome.defender_losses, vec![2]);
+            assert!(outcome.attacker_losses.is_empty() && outcome.retreated.is_empty());
+            assert_eq!((outcome.winner, outcome.rounds, outcome.defender_damage), (1, 1, 10));
+        } else {
+            panic!("Expected CombatResolved event");
+        }
+    }
+    
+    #[test]
+    fn test_battle_timing() {
+        let mut resolver = CombatResolver::new();
+        resolver.current_tick = 10;
+        
+        // Add a battle
+        initiate_ship_combat(&mut resolver, 1, 2).unwrap();
+        
+        // Battle should start at current tick
+        assert_eq!(resolver.get_ship_battles()[0].start_tick, 10);
+        
+        // No round is fought until the tick advances
+        let mut event_bus = EventBus::new();
+        resolver.update(0.1, &mut event_bus).unwrap();
+        assert_eq!(resolver.get_ship_battles()[0].rounds, 0);
+        
+        // One round per tick; evenly matched warships need several
+        resolver.current_tick = 11;
+        resolver.update(0.1, &mut event_bus).unwrap();
+        resolver.update(0.1, &mut event_bus).unwrap();
+        assert_eq!(resolver.get_ship_battles()[0].rounds, 1);
+        
+        let events = fight_until_resolved(&mut resolver, 12);
+        let rounds = events.iter().filter(|event| matches!(event, SimulationEvent::ShipCombat { .. })).count();
+        assert!(rounds > 1 && rounds < MAX_BATTLE_ROUNDS as usize);
+    }
+
+    #[test]
+    fn test_shields_soak_up_damage_before_the_hull() {
+        let mut ship = combatant(1, 1, 100, 30, 0);
+        assert_eq!(ship.take_damage(20), 0);
+        assert_eq!(ship.take_damage(20), 10);
+        assert_eq!((ship.hull, ship.shields), (90, 0));
+        assert_eq!(ship.take_damage(500), 90, "The hull cannot lose more than it has");
+        assert!(ship.is_destroyed() && !ship.should_retreat());
+    }
+
+    #[test]
+    fn test_damaged_ships_retreat_and_battles_replay_identically() {
+        let battle = |seed| {
+            let mut resolver = CombatResolver::new();
+            let location = Vector2 { x: 0.0, y: 0.0 };
+            resolver.begin_ship_combat(combatant(1, 1, 200, 40, 25), combatant(2, 2, 200, 40, 25), location, seed).unwrap();
+            fight_until_resolved(&mut resolver, 1)
+        };
+        let events = battle(42);
+        assert_eq!(format!("{:?}", events), format!("{:?}", battle(42)), "Same seed, same battle");
+        assert_ne!(format!("{:?}", events), format!("{:?}", battle(43)));
+        
+        let Some(SimulationEvent::CombatResolved { outcome, .. }) = events.last() else {
+            panic!("Expected CombatResolved, got {:?}", events.last());
+        };
+        assert!(outcome.attacker_losses.is_empty() && outcome.defender_losses.is_empty());
+        assert!(!outcome.retreated.is_empty(), "A warship is worn down below the retreat threshold first");
+        let retreats = events.iter().filter(|event| matches!(event, SimulationEvent::ShipRetreated { .. })).count();
+        assert_eq!(retreats, outcome.retreated.len());
+        let dealt: i32 = events.iter().map(|event| match event {
+            SimulationEvent::ShipCombat { attacker_damage, .. } => *attacker_damage,
+            _ => 0,
+        }).sum();
+        assert_eq!(dealt, outcome.attacker_damage);
+        
+        // Ships that cannot hurt each other break off eventually
+        let mut resolver = CombatResolver::new();
+        let location = Vector2 { x: 0.0, y: 0.0 };
+        resolver.begin_ship_combat(combatant(1, 1, 80, 0, 0), combatant(2, 2, 80, 0, 0), location, 42).unwrap();
+        let events = fight_until_resolved(&mut resolver, 1);
+        assert!(matches!(
+            events.last(),
+            Some(SimulationEvent::CombatResolved { outcome: CombatOutcome { winner: 2, rounds: MAX_BATTLE_ROUNDS, .. }, .. })
+        ));
+    }
+    
+    #[test]
+    fn test_ship_in_combat_detection() {
+        let mut resolver = CombatResolver::new();
+        
+        // Initially no ships in combat
+        assert!(!resolver.is_ship_in_combat(1));
+        assert!(!resolver.is_ship_in_combat(2));
+        
+        // Start combat between ships 1 and 2
+        initiate_ship_combat(&mut resolver, 1, 2).unwrap();
+        
+        // Both ships should now be in combat
+        assert!(resolver.is_ship_in_combat(1));
+        assert!(resolver.is_ship_in_combat(2));
+        
+        // Other ships should not be in combat
+        assert!(!resolver.is_ship_in_combat(3));
+    }
+    
+    #[test]
+    fn test_blockades_start_and_lift_with_orbital_presence() {
+        let mut resolver = CombatResolver::new();
+        let mut presence = BTreeMap::new();
+        presence.insert(1, OrbitalPresence { hostile_warships: 2, defending_warships: 0 });
+        presence.insert(2, OrbitalPresence { hostile_warships: 1, defending_warships: 1 });
+        resolver.update_blockades(&presence);
+        assert!(resolver.is_blockaded(1));
+        assert!(!resolver.is_blockaded(2), "A defending fleet contests the orbit");
+        
+        // A defender arriving lifts the blockade; the changes go out on update
+        presence.insert(1, OrbitalPresence { hostile_warships: 2, defending_warships: 1 });
+        presence.remove(&2);
+        resolver.update_blockades(&presence);
+        assert_eq!(resolver.get_blockaded_planets().count(), 0);
+        
+        let mut event_bus = EventBus::new();
+        resolver.update(0.1, &mut event_bus).unwrap();
+        assert!(matches!(
+            event_bus.queued_events.make_contiguous(),
+            [
+                GameEvent::StateChanged(StateChange::PlanetBlockaded(1)),
+                GameEvent::StateChanged(StateChange::BlockadeLifted(1)),
+            ]
+        ));
+    }
+    
+    #[test]
+    fn test_invasions_fight_one_round_per_tick_until_a_side_falls() {
+        let mut resolver = CombatResolver::new();
+        resolver.begin_invasion(1, 2, 3, 40, 20).unwrap();
+        resolver.begin_invasion(4, 2, 3, 5, 40).unwrap();
+        assert!(resolver.begin_invasion(1, 2, 3, 10, 10).is_err(), "One invasion per planet");
+        assert!(resolver.begin_invasion(5, 2, 3, 0, 10).is_err());
+        
+        let mut event_bus = EventBus::new();
+        resolver.update(0.1, &mut event_bus).unwrap();
+        assert!(event_bus.queued_events.is_empty(), "Troops fight from the tick after landing");
+        
+        let mut conquered = None;
+        let mut repelled = None;
+        for tick in 1..=20 {
+            resolver.current_tick = tick;
+            resolver.update(0.1, &mut event_bus).unwrap();
+            for event in event_bus.queued_events.drain(..) {
+                match event {
+                    GameEvent::SimulationEvent(SimulationEvent::PlanetConquered { planet, new_owner: 2 }) => conquered = Some((planet, tick)),
+                    GameEvent::SimulationEvent(SimulationEvent::InvasionRepelled { planet, invader: 2 }) => repelled = Some((planet, tick)),
+                    _ => {}
+                }
+            }
+        }
+        let (planet, tick) = conquered.unwrap();
+        assert_eq!(planet, 1);
+        assert!(tick > 1, "Conquest takes several rounds");
+        assert_eq!(repelled.map(|(planet, _)| planet), Some(4));
+        assert!(!resolver.is_planet_invaded(1) && resolver.get_invasions().is_empty());
+    }
+    
+    #[test]
+    fn test_bombardments_strike_once_per_tick_with_repeatable_rolls() {
+        let strikes = |seed: u64| {
+            let mut resolver = CombatResolver::new();
+            resolver.begin_bombardment(7, 1, 3, 2, seed).unwrap();
+            assert!(resolver.begin_bombardment(7, 1, 4, 2, seed).is_err(), "One bombardment per ship");
+            assert!(resolver.begin_bombardment(8, 2, 3, 2, seed).is_err(), "Nobody bombards their own planet");
+            
+            let mut event_bus = EventBus::new();
+            resolver.update(0.1, &mut event_bus).unwrap();
+            assert!(event_bus.queued_events.is_empty(), "The first strike falls the tick after the order");
+            let mut strikes = Vec::new();
+            for tick in 1..=40 {
+                resolver.current_tick = tick;
+                resolver.update(0.1, &mut event_bus).unwrap();
+                resolver.update(0.1, &mut event_bus).unwrap();
+                for event in event_bus.queued_events.drain(..) {
+                    match event {
+                        GameEvent::SimulationEvent(SimulationEvent::PlanetBombarded { planet: 3, ship: 7, strike }) => strikes.push(strike),
+                        GameEvent::SimulationEvent(SimulationEvent::RelationChanged { parties: (1, 2), delta }) => {
+                            assert_eq!(delta, BOMBARDMENT_RELATION_PENALTY);
+                        }
+                        other => panic!("Unexpected {:?}", other),
+                    }
+                }
+            }
+            assert!(resolver.end_bombardment(7) && !resolver.is_ship_bombarding(7));
+            strikes
+        };
+        let rolled = strikes(11);
+        assert_eq!(rolled.len(), 40);
+        assert_eq!(rolled, strikes(11));
+        let hits = rolled.iter().filter(|strike| matches!(strike, BombardmentStrike::Building { .. })).count();
+        assert!(hits > 0 && hits < 40, "Some strikes hit buildings and some the population");
+        assert!(rolled.contains(&BombardmentStrike::Population { casualties: BOMBARDMENT_CASUALTIES }));
+    }
+    
+    #[test]
+    fn test_missiles_fly_to_their_target_and_hit_the_ships_nearby() {
+        let mut resolver = CombatResolver::new();
+        resolver.set_missiles_enabled(true);
+        let mut launcher = combatant(1, 1, 500, 0, 0);
+        launcher.stats.missiles = 60;
+        let at = |x| Vector2 { x, y: 0.0 };
+        resolver.begin_ship_combat(launcher, combatant(2, 2, 500, 0, 0), at(0.0), 7).unwrap();
+        resolver.begin_ship_combat(combatant(3, 2, 500, 0, 0), combatant(4, 1, 500, 0, 0), at(0.5), 7).unwrap();
+        resolver.set_ship_positions(BTreeMap::from([(1, at(0.0)), (2, at(0.4)), (3, at(0.5)), (4, at(0.45))]));
+        
+        let mut event_bus = EventBus::new();
+        let mut events = Vec::new();
+        for tick in 1..=4 {
+            resolver.current_tick = tick;
+            resolver.update(0.1, &mut event_bus).unwrap();
+            events.extend(event_bus.queued_events.drain(..).filter_map(|event| match event {
+                GameEvent::SimulationEvent(event) => Some(event),
+                _ => None,
+            }));
+            if tick == 2 {
+                let missile = &resolver.missiles()[0];
+                assert_eq!((missile.previous_position, missile.position), (at(0.0), at(MISSILE_SPEED)));
+            }
+        }
+        assert!(matches!(events.iter().find(|event| matches!(event, SimulationEvent::MissileLaunched { .. })),
+            Some(SimulationEvent::MissileLaunched { missile: 0, launcher: 1, target: 2 })));
+        let hits = events.iter().find_map(|event| match event {
+            SimulationEvent::MissileImpact { target: 2, hits, .. } => Some(hits.clone()),
+            _ => None,
+        });
+        assert_eq!(hits, Some(vec![(2, 60), (3, 30)]), "The blast spares the launcher's own side");
+        assert_eq!(resolver.get_ship_battles()[0].defender.hull, 440);
+        assert!(resolver.recent_blasts().iter().any(|blast| !blast.intercepted));
+        
+        // Point defense that never misses stops every missile
+        let mut resolver = CombatResolver::new();
+        resolver.set_missiles_enabled(true);
+        let mut defender = combatant(2, 2, 500, 0, 0);
+        defender.stats.point_defense = 100;
+        resolver.begin_ship_combat(launcher, defender, at(0.0), 7).unwrap();
+        resolver.set_ship_positions(BTreeMap::from([(1, at(0.0)), (2, at(0.1))]));
+        for tick in 1..=2 {
+            resolver.current_tick = tick;
+            resolver.update(0.1, &mut event_bus).unwrap();
+        }
+        assert!(event_bus.queued_events.iter().any(|event| matches!(
+            event, GameEvent::SimulationEvent(SimulationEvent::MissileIntercepted { target: 2, .. })
+        )));
+        assert_eq!(resolver.get_ship_battles()[0].defender.hull, 500);
+    }
+}
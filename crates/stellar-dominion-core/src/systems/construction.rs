@@ -1,6 +1,7 @@
 // src/systems/construction.rs
-use crate::core::{GameResult, GameEvent, EventBus};
+use crate::core::{GameResult, GameEvent, EventBus, GameSystem, SystemContext, GameDataRegistry, UnlockRequirement, ShipDesign, DesignId, PlayerCommand};
 use crate::core::types::*;
+use crate::managers::{PlanetManager, FactionManager};
 use std::collections::HashMap;
 
 /// Represents a building construction order in the queue
@@ -23,6 +24,8 @@ pub struct ConstructionOrder {
 pub struct ShipOrder {
     /// The class of ship being constructed
     pub ship_class: ShipClass,
+    /// The design the ship is built to; None for a preset class
+    pub design: Option<ShipDesign>,
     /// The planet where construction is taking place
     pub planet_id: PlanetId,
     /// The tick when construction started
@@ -33,147 +36,110 @@ pub struct ShipOrder {
     pub cost_paid: ResourceBundle,
 }
 
+/// A ship class or design as offered in a planet's build menu
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShipBuildOption {
+    /// The class of ship offered, or the role of the design
+    pub ship_class: ShipClass,
+    /// The faction's design offered; None for a preset class
+    pub design: Option<ShipDesign>,
+    /// The resources the ship costs
+    pub cost: ResourceBundle,
+    /// Ticks needed to build the ship
+    pub build_time: u64,
+    /// Prerequisites not yet met; empty when the class can be built
+    pub missing: Vec<UnlockRequirement>,
+}
+
+impl ShipBuildOption {
+    /// Name shown in the build menu: the design's, or the preset class's
+    pub fn name(&self) -> String {
+        match &self.design {
+            Some(design) => design.name.clone(),
+            None => format!("{:?}", self.ship_class),
+        }
+    }
+
+    /// The command that orders this ship at the planet
+    pub fn build_command(&self, planet: PlanetId) -> PlayerCommand {
+        match &self.design {
+            Some(design) => PlayerCommand::BuildDesignedShip { planet, design: design.id },
+            None => PlayerCommand::BuildShip { planet, ship_class: self.ship_class },
+        }
+    }
+
+    /// True when every prerequisite is met
+    pub fn is_unlocked(&self) -> bool {
+        self.missing.is_empty()
+    }
+
+    /// Reasons the option is locked, one per missing prerequisite
+    pub fn lock_reasons(&self) -> Vec<String> {
+        self.missing.iter().map(UnlockRequirement::describe).collect()
+    }
+}
+
+/// A building type as offered in a planet's build menu
+#[derive(Debug, Clone, PartialEq)]
+pub struct BuildingBuildOption {
+    /// The type of building offered
+    pub building_type: BuildingType,
+    /// The resources the building costs
+    pub cost: ResourceBundle,
+    /// Ticks needed to build the building
+    pub build_time: u64,
+    /// Change to the planet's stockpile per tick once a tier 1 building runs
+    pub production: ResourceBundle,
+    /// Prerequisites not yet met; empty when the building can be placed
+    pub missing: Vec<UnlockRequirement>,
+}
+
+impl BuildingBuildOption {
+    /// True when every prerequisite is met
+    pub fn is_unlocked(&self) -> bool {
+        self.missing.is_empty()
+    }
+
+    /// Reasons the option is locked, one per missing prerequisite
+    pub fn lock_reasons(&self) -> Vec<String> {
+        self.missing.iter().map(UnlockRequirement::describe).collect()
+    }
+}
+
 /// Construction system manages building and ship construction queues
 pub struct ConstructionSystem {
     building_queue: HashMap<PlanetId, Vec<ConstructionOrder>>,
     ship_queue: HashMap<PlanetId, Vec<ShipOrder>>,
     building_costs: HashMap<BuildingType, (ResourceBundle, u64)>,
     ship_costs: HashMap<ShipClass, (ResourceBundle, u64)>,
+    game_data: GameDataRegistry,
     current_tick: u64,
 }
 
 impl ConstructionSystem {
-    /// Creates a new ConstructionSystem with predefined building and ship costs
+    /// Creates a new ConstructionSystem with the default building and ship costs
     pub fn new() -> Self {
-        let mut building_costs = HashMap::new();
-        let mut ship_costs = HashMap::new();
-        
-        // Define building construction costs and times (in ticks)
-        building_costs.insert(BuildingType::Mine, (ResourceBundle {
-            minerals: 100,
-            food: 0,
-            energy: 0,
-            alloys: 20,
-            components: 10,
-            fuel: 0,
-        }, 10));
-        
-        building_costs.insert(BuildingType::Farm, (ResourceBundle {
-            minerals: 50,
-            food: 0,
-            energy: 0,
-            alloys: 10,
-            components: 5,
-            fuel: 0,
-        }, 8));
-        
-        building_costs.insert(BuildingType::PowerPlant, (ResourceBundle {
-            minerals: 80,
-            food: 0,
-            energy: 0,
-            alloys: 30,
-            components: 15,
-            fuel: 0,
-        }, 12));
-        
-        building_costs.insert(BuildingType::Factory, (ResourceBundle {
-            minerals: 120,
-            food: 0,
-            energy: 10,
-            alloys: 40,
-            components: 25,
-            fuel: 0,
-        }, 15));
-        
-        building_costs.insert(BuildingType::ResearchLab, (ResourceBundle {
-            minerals: 90,
-            food: 0,
-            energy: 5,
-            alloys: 25,
-            components: 30,
-            fuel: 0,
-        }, 14));
-        
-        building_costs.insert(BuildingType::Spaceport, (ResourceBundle {
-            minerals: 200,
-            food: 0,
-            energy: 20,
-            alloys: 80,
-            components: 60,
-            fuel: 0,
-        }, 25));
-        
-        building_costs.insert(BuildingType::DefensePlatform, (ResourceBundle {
-            minerals: 150,
-            food: 0,
-            energy: 15,
-            alloys: 100,
-            components: 50,
-            fuel: 0,
-        }, 20));
-        
-        building_costs.insert(BuildingType::StorageFacility, (ResourceBundle {
-            minerals: 60,
-            food: 0,
-            energy: 0,
-            alloys: 15,
-            components: 8,
-            fuel: 0,
-        }, 6));
-        
-        building_costs.insert(BuildingType::Habitat, (ResourceBundle {
-            minerals: 80,
-            food: 10,
-            energy: 5,
-            alloys: 20,
-            components: 15,
-            fuel: 0,
-        }, 10));
-        
-        // Define ship construction costs and times (in ticks)
-        ship_costs.insert(ShipClass::Scout, (ResourceBundle {
-            minerals: 50,
-            food: 0,
-            energy: 0,
-            alloys: 30,
-            components: 25,
-            fuel: 10,
-        }, 8));
-        
-        ship_costs.insert(ShipClass::Transport, (ResourceBundle {
-            minerals: 80,
-            food: 0,
-            energy: 0,
-            alloys: 40,
-            components: 35,
-            fuel: 15,
-        }, 12));
-        
-        ship_costs.insert(ShipClass::Colony, (ResourceBundle {
-            minerals: 150,
-            food: 50,
-            energy: 10,
-            alloys: 60,
-            components: 80,
-            fuel: 25,
-        }, 20));
-        
-        ship_costs.insert(ShipClass::Warship, (ResourceBundle {
-            minerals: 200,
-            food: 0,
-            energy: 5,
-            alloys: 120,
-            components: 100,
-            fuel: 30,
-        }, 25));
-        
-        Self {
+        let mut system = Self {
             building_queue: HashMap::new(),
             ship_queue: HashMap::new(),
-            building_costs,
-            ship_costs,
+            building_costs: HashMap::new(),
+            ship_costs: HashMap::new(),
+            game_data: GameDataRegistry::new(),
             current_tick: 0,
-        }
+        };
+        system.set_game_data(GameDataRegistry::new());
+        system
+    }
+    
+    /// Take costs, build times and prerequisites from the registry
+    pub fn set_game_data(&mut self, game_data: GameDataRegistry) {
+        self.building_costs = game_data.buildings()
+            .map(|(building_type, data)| (building_type, (data.cost, data.build_time)))
+            .collect();
+        self.ship_costs = game_data.ships()
+            .map(|(ship_class, data)| (ship_class, (data.cost, data.build_time)))
+            .collect();
+        self.game_data = game_data;
     }
     
     /// Update construction system - processes completion of construction orders
@@ -197,9 +163,10 @@ impl ConstructionSystem {
                         // We queue the order and emit an event requesting resource deduction
                         self.request_building_construction(*planet, *building_type)?;
                     }
-                    crate::core::events::PlayerCommand::ConstructShip { planet, ship_class } => {
+                    crate::core::events::PlayerCommand::BuildShip { planet, ship_class } => {
                         self.request_ship_construction(*planet, *ship_class)?;
                     }
+                    // The design is looked up with design_order and queued with request_designed_ship
                     _ => {}
                 }
             }
@@ -219,7 +186,7 @@ impl ConstructionSystem {
     /// Request building construction - emits resource requirement event
     fn request_building_construction(&mut self, planet_id: PlanetId, building_type: BuildingType) -> GameResult<()> {
         let (cost, build_time) = self.building_costs.get(&building_type)
-            .ok_or_else(|| GameError::SystemError(format!("Unknown building type: {:?}", building_type)))?;
+            .ok_or_else(|| GameError::InvalidTarget(format!("Unknown building type: {:?}", building_type)))?;
         
         // For now, we'll add to queue immediately
         // In a full implementation, this would wait for resource confirmation
@@ -239,27 +206,39 @@ impl ConstructionSystem {
         Ok(())
     }
     
-    /// Request ship construction - emits resource requirement event  
+    /// Queue a ship; `ship_order_cost` has already checked prerequisites and the cost is taken.
+    ///
+    /// A planet's spaceport builds one ship at a time, so the order starts
+    /// once the last ship queued there is finished.
     fn request_ship_construction(&mut self, planet_id: PlanetId, ship_class: ShipClass) -> GameResult<()> {
-        let (cost, build_time) = self.ship_costs.get(&ship_class)
-            .ok_or_else(|| GameError::SystemError(format!("Unknown ship class: {:?}", ship_class)))?;
-        
-        // For now, we'll add to queue immediately
-        // In a full implementation, this would wait for resource confirmation
-        let order = ShipOrder {
+        let (cost, build_time) = *self.ship_costs.get(&ship_class)
+            .ok_or_else(|| GameError::InvalidTarget(format!("Unknown ship class: {:?}", ship_class)))?;
+        self.queue_ship(planet_id, ship_class, None, cost, build_time);
+        Ok(())
+    }
+    
+    /// Queue a ship built to a design, at the design's cost and build time;
+    /// `design_order` has already checked prerequisites and the cost is taken
+    pub fn request_designed_ship(&mut self, planet_id: PlanetId, design: ShipDesign) -> GameResult<()> {
+        design.validate()?;
+        let stats = design.stats();
+        self.queue_ship(planet_id, design.role(), Some(design), stats.cost, stats.build_time);
+        Ok(())
+    }
+    
+    /// Add an order behind the last ship queued at the planet
+    fn queue_ship(&mut self, planet_id: PlanetId, ship_class: ShipClass, design: Option<ShipDesign>, cost: ResourceBundle, build_time: u64) {
+        let queue = self.ship_queue.entry(planet_id).or_default();
+        let start_tick = queue.last()
+            .map_or(self.current_tick, |order| order.completion_tick.max(self.current_tick));
+        queue.push(ShipOrder {
             ship_class,
+            design,
             planet_id,
-            start_tick: self.current_tick,
-            completion_tick: self.current_tick + build_time,
-            cost_paid: *cost,
-        };
-        
-        self.ship_queue
-            .entry(planet_id)
-            .or_insert_with(Vec::new)
-            .push(order);
-            
-        Ok(())
+            start_tick,
+            completion_tick: start_tick + build_time,
+            cost_paid: cost,
+        });
     }
     
     /// Process completed building constructions
@@ -309,13 +288,14 @@ impl ConstructionSystem {
             }
         }
         
-        // Emit completion events with placeholder ship IDs
-        // Real implementation would get ship ID from ShipManager
-        for (planet_id, _order) in completed_orders {
+        // Planet order keeps ship ids the same from run to run
+        completed_orders.sort_by_key(|(planet_id, order)| (*planet_id, order.completion_tick));
+        for (planet_id, order) in completed_orders {
             event_bus.queue_event(GameEvent::SimulationEvent(
-                crate::core::events::SimulationEvent::ShipCompleted {
+                crate::core::events::SimulationEvent::ShipConstructed {
                     planet: planet_id,
-                    ship: 0, // Placeholder - would be real ship ID from ShipManager
+                    ship_class: order.ship_class,
+                    design: order.design,
                 }
             ));
         }
@@ -333,6 +313,121 @@ impl ConstructionSystem {
         self.ship_costs.get(&ship_class)
     }
     
+    /// Game data holding ship and building costs and prerequisites
+    pub fn get_game_data(&self) -> &GameDataRegistry {
+        &self.game_data
+    }
+    
+    /// Mutable game data, for rebalancing prerequisites; use `set_game_data` to change costs
+    pub fn game_data_mut(&mut self) -> &mut GameDataRegistry {
+        &mut self.game_data
+    }
+    
+    /// Check that the planet and its controlling faction meet a ship class's prerequisites
+    pub fn validate_ship_unlocked(&self, ship_class: ShipClass, planet: &Planet, faction: &Faction) -> GameResult<()> {
+        let missing = self.game_data.missing_ship_requirements(ship_class, planet, faction);
+        if missing.is_empty() {
+            return Ok(());
+        }
+        let reasons: Vec<String> = missing.iter().map(UnlockRequirement::describe).collect();
+        Err(GameError::InvalidOperation(format!(
+            "{:?} is locked on planet {}: {}", ship_class, planet.id, reasons.join(", ")
+        )))
+    }
+    
+    /// Check that the planet and its controlling faction meet a building's prerequisites
+    pub fn validate_building_unlocked(&self, building_type: BuildingType, planet: &Planet, faction: &Faction) -> GameResult<()> {
+        let missing = self.game_data.missing_building_requirements(building_type, planet, faction);
+        if missing.is_empty() {
+            return Ok(());
+        }
+        let reasons: Vec<String> = missing.iter().map(UnlockRequirement::describe).collect();
+        Err(GameError::InvalidOperation(format!(
+            "{:?} is locked on planet {}: {}", building_type, planet.id, reasons.join(", ")
+        )))
+    }
+    
+    /// The faction controlling a planet, whose technologies and designs are
+    /// built with there
+    pub fn builder<'a>(planet: &Planet, factions: &'a FactionManager) -> GameResult<&'a Faction> {
+        let owner = planet.controller.ok_or_else(|| GameError::InvalidOperation(
+            format!("Planet {} is not controlled by any faction", planet.id)
+        ))?;
+        factions.get_faction(owner)
+    }
+    
+    /// Check an order for a ship class against the planet's prerequisites
+    /// and return its cost, which the planet pays when the order is queued
+    pub fn ship_order_cost(&self, planet: PlanetId, ship_class: ShipClass, planets: &PlanetManager, factions: &FactionManager) -> GameResult<ResourceBundle> {
+        let planet = planets.get_planet(planet)?;
+        self.validate_ship_unlocked(ship_class, planet, Self::builder(planet, factions)?)?;
+        self.get_ship_cost(ship_class)
+            .map(|(cost, _)| *cost)
+            .ok_or_else(|| GameError::InvalidTarget(format!("Unknown ship class: {:?}", ship_class)))
+    }
+    
+    /// One of the designs of the planet's controller, once the planet meets
+    /// the prerequisites of its role
+    pub fn design_order(&self, planet: PlanetId, design: DesignId, planets: &PlanetManager, factions: &FactionManager) -> GameResult<ShipDesign> {
+        let planet = planets.get_planet(planet)?;
+        let faction = Self::builder(planet, factions)?;
+        let design = faction.ship_design(design).cloned()
+            .ok_or_else(|| GameError::not_found(EntityKind::ShipDesign, design))?;
+        self.validate_ship_unlocked(design.role(), planet, faction)?;
+        Ok(design)
+    }
+    
+    /// Check a building order against the planet's prerequisites;
+    /// unrestricted buildings may go on any planet, controlled or not
+    pub fn check_building_order(&self, planet: PlanetId, building_type: BuildingType, planets: &PlanetManager, factions: &FactionManager) -> GameResult<()> {
+        if self.game_data.building_requirements(building_type).is_empty() {
+            return Ok(());
+        }
+        let planet = planets.get_planet(planet)?;
+        self.validate_building_unlocked(building_type, planet, Self::builder(planet, factions)?)
+    }
+    
+    /// Every ship class with its cost and any unmet prerequisites for this planet
+    pub fn ship_build_options(&self, planet: &Planet, faction: &Faction) -> Vec<ShipBuildOption> {
+        let mut options: Vec<ShipBuildOption> = self.ship_costs.iter()
+            .map(|(ship_class, (cost, build_time))| ShipBuildOption {
+                ship_class: *ship_class,
+                design: None,
+                cost: *cost,
+                build_time: *build_time,
+                missing: self.game_data.missing_ship_requirements(*ship_class, planet, faction),
+            })
+            .collect();
+        options.sort_by_key(|option| option.ship_class);
+        // The faction's own designs follow the presets, in the order they were saved
+        options.extend(faction.ship_designs.iter().map(|design| {
+            let stats = design.stats();
+            ShipBuildOption {
+                ship_class: design.role(),
+                design: Some(design.clone()),
+                cost: stats.cost,
+                build_time: stats.build_time,
+                missing: self.game_data.missing_ship_requirements(design.role(), planet, faction),
+            }
+        }));
+        options
+    }
+    
+    /// Every building type with its cost, output and any unmet prerequisites for this planet
+    pub fn building_build_options(&self, planet: &Planet, faction: &Faction) -> Vec<BuildingBuildOption> {
+        let mut options: Vec<BuildingBuildOption> = self.game_data.buildings()
+            .map(|(building_type, data)| BuildingBuildOption {
+                building_type,
+                cost: data.cost,
+                build_time: data.build_time,
+                production: data.net_production(),
+                missing: self.game_data.missing_building_requirements(building_type, planet, faction),
+            })
+            .collect();
+        options.sort_by_key(|option| option.building_type);
+        options
+    }
+    
     /// Get current building queue for a planet
     pub fn get_building_queue(&self, planet_id: PlanetId) -> Vec<&ConstructionOrder> {
         self.building_queue.get(&planet_id)
@@ -409,9 +504,20 @@ impl ConstructionSystem {
     }
 }
 
+impl GameSystem for ConstructionSystem {
+    fn update(&mut self, delta: f32, context: &mut SystemContext<'_>) -> GameResult<()> {
+        self.update(delta, context.event_bus())
+    }
+
+    fn handle_event(&mut self, event: &GameEvent) -> GameResult<()> {
+        self.handle_event(event)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::events::SimulationEvent;
     
     #[test]
     fn test_construction_system_creation() {
@@ -475,6 +581,27 @@ mod tests {
         assert_eq!(queue[0].planet_id, 1);
     }
     
+    #[test]
+    fn test_ships_are_built_one_at_a_time_per_planet() {
+        let mut system = ConstructionSystem::new();
+        let mut event_bus = EventBus::new();
+        let (_, scout_time) = *system.get_ship_cost(ShipClass::Scout).unwrap();
+        
+        system.request_ship_construction(1, ShipClass::Scout).unwrap();
+        system.request_ship_construction(1, ShipClass::Scout).unwrap();
+        system.request_ship_construction(2, ShipClass::Scout).unwrap();
+        let queue = system.get_ship_queue(1);
+        assert_eq!(queue[1].start_tick, queue[0].completion_tick);
+        
+        system.current_tick = scout_time;
+        system.process_ship_completions(&mut event_bus).unwrap();
+        assert!(matches!(event_bus.queued_events.make_contiguous(), [
+            GameEvent::SimulationEvent(SimulationEvent::ShipConstructed { planet: 1, ship_class: ShipClass::Scout, design: None }),
+            GameEvent::SimulationEvent(SimulationEvent::ShipConstructed { planet: 2, ship_class: ShipClass::Scout, design: None }),
+        ]));
+        assert_eq!(system.get_ship_queue(1).len(), 1);
+    }
+    
     #[test]
     fn test_construction_completion() {
         let mut system = ConstructionSystem::new();
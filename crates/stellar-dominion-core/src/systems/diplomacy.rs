@@ -0,0 +1,201 @@
+// src/systems/diplomacy.rs
+//! Diplomacy between factions
+//!
+//! Turns treaty proposals, trade offers and declarations of war into inbox
+//! messages and relation events. Combat validation in GameState and the AI
+//! consult the resulting relations before starting a fight.
+
+use crate::core::{GameResult, GameEvent, EventBus, GameSystem, SystemContext};
+use crate::core::types::*;
+use crate::core::events::*;
+
+/// Relation both sides gain when a treaty is signed
+pub const TREATY_SIGNED_BONUS: i32 = 20;
+/// Relation both sides lose when war is declared
+pub const WAR_DECLARED_PENALTY: i32 = -40;
+/// Relation both sides lose when a treaty proposal is turned down
+pub const PROPOSAL_DECLINED_PENALTY: i32 = -5;
+/// Relation both sides gain from an accepted trade
+pub const TRADE_ACCEPTED_BONUS: i32 = 5;
+
+/// Decides the outcome of diplomatic commands and announces it.
+///
+/// Relations live on each [`Faction`] and are changed only by FactionManager
+/// in response to the events emitted here:
+/// - `ProposeTreaty` and `OfferTrade` become `InboxMessageSent` to the recipient
+/// - an accepted proposal becomes `TreatySigned`; a declined one costs relation
+/// - an accepted trade becomes `TradeAccepted`
+/// - `DeclareWar` becomes `WarDeclared`
+///
+/// Every outcome that moves relation scores also emits `RelationChanged`.
+pub struct DiplomacySystem {
+    pending: Vec<SimulationEvent>,
+    current_tick: u64,
+}
+
+impl DiplomacySystem {
+    /// Creates a diplomacy system with nothing pending
+    pub fn new() -> Self {
+        Self {
+            pending: Vec::new(),
+            current_tick: 0,
+        }
+    }
+
+    /// Emits the outcomes decided since the last update
+    pub fn update(&mut self, _delta: f32, event_bus: &mut EventBus) -> GameResult<()> {
+        for event in self.pending.drain(..) {
+            event_bus.queue_event(GameEvent::SimulationEvent(event));
+        }
+        Ok(())
+    }
+
+    /// Handles diplomatic commands and inbox responses
+    pub fn handle_event(&mut self, event: &GameEvent) -> GameResult<()> {
+        match event {
+            GameEvent::PlayerCommand(PlayerCommand::ProposeTreaty { from, to, treaty }) => {
+                validate_parties(*from, *to)?;
+                self.pending.push(SimulationEvent::InboxMessageSent {
+                    to: *to,
+                    message: InboxMessage::DiplomaticProposal { from: *from, proposal: *treaty },
+                });
+            }
+            GameEvent::PlayerCommand(PlayerCommand::OfferTrade { from, to, offered, requested }) => {
+                validate_parties(*from, *to)?;
+                offered.validate_non_negative()?;
+                requested.validate_non_negative()?;
+                self.pending.push(SimulationEvent::InboxMessageSent {
+                    to: *to,
+                    message: InboxMessage::TradeOffer { from: *from, offered: *offered, requested: *requested },
+                });
+            }
+            GameEvent::PlayerCommand(PlayerCommand::DeclareWar { from, to }) => {
+                validate_parties(*from, *to)?;
+                self.pending.push(SimulationEvent::WarDeclared { aggressor: *from, target: *to });
+                self.pending.push(SimulationEvent::RelationChanged { parties: (*from, *to), delta: WAR_DECLARED_PENALTY });
+            }
+            GameEvent::SimulationEvent(SimulationEvent::InboxItemResolved { faction, item, response }) => {
+                self.resolve_inbox_item(*faction, item, *response);
+            }
+            GameEvent::SimulationEvent(SimulationEvent::TickCompleted(tick)) => {
+                self.current_tick = *tick;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Treaty of the given kind signed on `tick`
+    pub fn treaty(kind: DiplomaticProposal, tick: u64) -> Treaty {
+        Treaty {
+            kind,
+            signed_tick: tick,
+            expires_tick: tick + TREATY_DURATION_TICKS,
+        }
+    }
+
+    fn resolve_inbox_item(&mut self, faction: FactionId, item: &InboxItem, response: InboxResponse) {
+        let accepted = response == InboxResponse::Accept;
+        match &item.message {
+            InboxMessage::DiplomaticProposal { from, proposal } => {
+                let parties = (*from, faction);
+                if accepted {
+                    self.pending.push(SimulationEvent::TreatySigned {
+                        parties,
+                        treaty: Self::treaty(*proposal, self.current_tick),
+                    });
+                    self.pending.push(SimulationEvent::RelationChanged { parties, delta: TREATY_SIGNED_BONUS });
+                } else {
+                    self.pending.push(SimulationEvent::RelationChanged { parties, delta: PROPOSAL_DECLINED_PENALTY });
+                }
+            }
+            InboxMessage::TradeOffer { from, offered, requested } if accepted => {
+                self.pending.push(SimulationEvent::TradeAccepted {
+                    from: *from,
+                    to: faction,
+                    offered: *offered,
+                    requested: *requested,
+                });
+                self.pending.push(SimulationEvent::RelationChanged { parties: (*from, faction), delta: TRADE_ACCEPTED_BONUS });
+            }
+            _ => {}
+        }
+    }
+}
+
+/// A faction cannot negotiate with itself
+fn validate_parties(from: FactionId, to: FactionId) -> GameResult<()> {
+    if from == to {
+        return Err(GameError::InvalidOperation(format!("Faction {} cannot negotiate with itself", from)));
+    }
+    Ok(())
+}
+
+impl Default for DiplomacySystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GameSystem for DiplomacySystem {
+    fn update(&mut self, delta: f32, context: &mut SystemContext<'_>) -> GameResult<()> {
+        self.update(delta, context.event_bus())
+    }
+
+    fn handle_event(&mut self, event: &GameEvent) -> GameResult<()> {
+        self.handle_event(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn emitted(diplomacy: &mut DiplomacySystem) -> Vec<SimulationEvent> {
+        let mut bus = EventBus::new();
+        diplomacy.update(0.1, &mut bus).unwrap();
+        bus.queued_events.into_iter()
+            .filter_map(|event| match event {
+                GameEvent::SimulationEvent(event) => Some(event),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_proposals_go_to_the_inbox_and_acceptance_signs_a_treaty() {
+        let mut diplomacy = DiplomacySystem::new();
+        diplomacy.handle_event(&GameEvent::SimulationEvent(SimulationEvent::TickCompleted(10))).unwrap();
+        diplomacy.handle_event(&GameEvent::PlayerCommand(PlayerCommand::ProposeTreaty {
+            from: 0, to: 1, treaty: DiplomaticProposal::Alliance,
+        })).unwrap();
+
+        let events = emitted(&mut diplomacy);
+        let Some(SimulationEvent::InboxMessageSent { to: 1, message }) = events.first() else {
+            panic!("Proposal should be delivered to faction 1, got {:?}", events);
+        };
+
+        let item = InboxItem { id: 0, message: message.clone(), received_tick: 10, expires_tick: None };
+        diplomacy.handle_event(&GameEvent::SimulationEvent(SimulationEvent::InboxItemResolved {
+            faction: 1, item, response: InboxResponse::Accept,
+        })).unwrap();
+        let events = emitted(&mut diplomacy);
+        assert!(matches!(
+            events[0],
+            SimulationEvent::TreatySigned { parties: (0, 1), treaty: Treaty { kind: DiplomaticProposal::Alliance, signed_tick: 10, expires_tick } }
+                if expires_tick == 10 + TREATY_DURATION_TICKS
+        ));
+        assert!(matches!(events[1], SimulationEvent::RelationChanged { parties: (0, 1), delta: TREATY_SIGNED_BONUS }));
+    }
+
+    #[test]
+    fn test_war_declaration_and_self_negotiation() {
+        let mut diplomacy = DiplomacySystem::new();
+        assert!(diplomacy.handle_event(&GameEvent::PlayerCommand(PlayerCommand::DeclareWar { from: 2, to: 2 })).is_err());
+
+        diplomacy.handle_event(&GameEvent::PlayerCommand(PlayerCommand::DeclareWar { from: 2, to: 0 })).unwrap();
+        let events = emitted(&mut diplomacy);
+        assert!(matches!(events[0], SimulationEvent::WarDeclared { aggressor: 2, target: 0 }));
+        assert!(matches!(events[1], SimulationEvent::RelationChanged { parties: (2, 0), delta: WAR_DECLARED_PENALTY }));
+    }
+}
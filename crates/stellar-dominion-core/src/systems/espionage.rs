@@ -0,0 +1,287 @@
+// src/systems/espionage.rs
+//! Espionage: scouts gather intel on other factions' planets
+//!
+//! A scout that stays within [`INTEL_RANGE`] of a planet another faction
+//! controls for [`INTEL_LOITER_TICKS`] ticks, not in transit, files an
+//! [`IntelReport`] for its faction: the planet's stockpile, buildings,
+//! population and the owner's ships in orbit as of that tick. A scout that
+//! stays on files a fresh report every [`INTEL_LOITER_TICKS`]. Each faction
+//! keeps only the latest report per planet.
+//!
+//! Other factions' planets are only known through intel:
+//! [`SpySystem::known_planet`] gives the live planet for a faction's own and
+//! unclaimed planets, and otherwise the latest report with its tick, or no
+//! details at all when there is none. Every tick
+//! [`SpySystem::gather_intel`] finds the scouts near other factions' planets
+//! in the PhysicsEngine's spatial index, hands them to
+//! [`SpySystem::observe`] and files the reports it asks for. Reports are saved with the game as an [`IntelState`]; how long
+//! each scout has loitered is not, so scouts in a loaded game start over.
+
+use crate::core::{GameResult, GameEvent, EventBus, GameSystem, SystemContext};
+use crate::core::types::*;
+use crate::core::events::*;
+use crate::managers::{PlanetManager, ShipManager};
+use super::spatial_index::{SpatialEntity, SpatialIndex};
+use serde::{Serialize, Deserialize};
+use std::collections::BTreeMap;
+
+/// Ticks a scout must stay near a planet to file a report
+pub const INTEL_LOITER_TICKS: u64 = 30;
+/// How close to a planet a scout must stay, in AU
+pub const INTEL_RANGE: f32 = 1.0;
+
+/// What a faction's scout saw of a planet
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IntelReport {
+    /// Planet reported on
+    pub planet: PlanetId,
+    /// Tick the report was filed
+    pub tick: u64,
+    /// Stockpile at the time
+    pub resources: ResourceBundle,
+    /// Buildings at the time
+    pub developments: Vec<Building>,
+    /// Total population at the time
+    pub population: i32,
+    /// Ships of the planet's owner orbiting it
+    pub ships_in_orbit: usize,
+}
+
+impl IntelReport {
+    /// Report on the planet as it is at `tick`
+    pub fn snapshot(planet: &Planet, ships_in_orbit: usize, tick: u64) -> Self {
+        Self {
+            planet: planet.id,
+            tick,
+            resources: planet.resources.current,
+            developments: planet.developments.clone(),
+            population: planet.population.total,
+            ships_in_orbit,
+        }
+    }
+
+    /// Ticks since the report was filed
+    pub fn age(&self, tick: u64) -> u64 {
+        tick.saturating_sub(self.tick)
+    }
+
+    /// The planet with its stockpile, buildings and population as reported
+    pub fn apply_to(&self, planet: &Planet) -> Planet {
+        let mut known = hide_details(planet);
+        known.resources.current = self.resources;
+        known.developments = self.developments.clone();
+        known.population.total = self.population;
+        known
+    }
+}
+
+/// How much a faction knows about a planet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlanetKnowledge {
+    /// The faction's own or an unclaimed planet, known as it is
+    Live,
+    /// Known from the report filed at `tick`
+    Intel {
+        /// Tick the report was filed
+        tick: u64,
+        /// Ships of the owner orbiting the planet then
+        ships_in_orbit: usize,
+    },
+    /// Another faction's planet no scout has reported on
+    Unknown,
+}
+
+/// The planet with its stockpile, buildings and population left out
+pub fn hide_details(planet: &Planet) -> Planet {
+    let mut hidden = planet.clone();
+    hidden.resources.current = ResourceBundle::default();
+    hidden.developments.clear();
+    hidden.population = Demographics::default();
+    hidden
+}
+
+/// Every faction's latest intel, as saved with the game
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IntelState {
+    /// Latest report per planet, by the faction that filed it
+    pub reports: BTreeMap<FactionId, BTreeMap<PlanetId, IntelReport>>,
+}
+
+/// Counts how long scouts loiter near planets and keeps the reports they file
+pub struct SpySystem {
+    state: IntelState,
+    /// Planet each loitering scout is near and the ticks it has stayed
+    loitering: BTreeMap<ShipId, (PlanetId, u64)>,
+    pending: Vec<SimulationEvent>,
+}
+
+impl SpySystem {
+    /// A system with no intel
+    pub fn new() -> Self {
+        Self {
+            state: IntelState::default(),
+            loitering: BTreeMap::new(),
+            pending: Vec::new(),
+        }
+    }
+
+    /// Count the ticks scouts have stayed near other factions' planets and
+    /// file a report for each one that has stayed long enough. `index`
+    /// holds where every planet and ship is this tick.
+    pub fn gather_intel(&mut self, tick: u64, planets: &PlanetManager, ships: &ShipManager, index: &SpatialIndex) -> GameResult<()> {
+        let planet_owner = |planet: PlanetId| planets.get_planet(planet).ok().and_then(|planet| planet.controller);
+        let sightings: Vec<(ShipId, PlanetId)> = ships.get_all_ships().iter()
+            .filter(|ship| ship.ship_class == ShipClass::Scout && ship.status != ShipStatus::InTransit)
+            .filter_map(|ship| {
+                index.entities_within(ship.position, INTEL_RANGE).into_iter()
+                    .filter_map(|(entity, position)| match entity {
+                        SpatialEntity::Planet(planet) => Some((planet, position.distance_to(&ship.position))),
+                        SpatialEntity::Ship(_) => None,
+                    })
+                    .filter(|(planet, _)| planet_owner(*planet).is_some_and(|owner| owner != ship.owner))
+                    .min_by(|a, b| a.1.total_cmp(&b.1))
+                    .map(|(planet, _)| (ship.id, planet))
+            })
+            .collect();
+
+        for (ship, planet) in self.observe(&sightings) {
+            let faction = ships.get_ship(ship)?.owner;
+            let planet = planets.get_planet(planet)?;
+            let ships_in_orbit = ships.get_all_ships().iter()
+                .filter(|ship| Some(ship.owner) == planet.controller && ship.status == ShipStatus::Orbiting(planet.id))
+                .count();
+            self.record(faction, ship, IntelReport::snapshot(planet, ships_in_orbit, tick));
+        }
+        Ok(())
+    }
+
+    /// The planet as the faction knows it: as it is when the faction controls
+    /// it or nobody does, otherwise as of the faction's latest intel report,
+    /// or without its stockpile, buildings and population when there is none
+    pub fn known_planet(&self, faction: FactionId, planet: &Planet) -> (Planet, PlanetKnowledge) {
+        if planet.controller.is_none_or(|owner| owner == faction) {
+            return (planet.clone(), PlanetKnowledge::Live);
+        }
+        match self.intel(faction, planet.id) {
+            Some(report) => (report.apply_to(planet), PlanetKnowledge::Intel { tick: report.tick, ships_in_orbit: report.ships_in_orbit }),
+            None => (hide_details(planet), PlanetKnowledge::Unknown),
+        }
+    }
+
+    /// Count one more tick for every scout near another faction's planet,
+    /// given as (scout, planet) pairs. Scouts not listed have moved on and
+    /// start over. Returns the scouts that have loitered long enough to
+    /// file a report, with their planets.
+    pub fn observe(&mut self, sightings: &[(ShipId, PlanetId)]) -> Vec<(ShipId, PlanetId)> {
+        let mut loitering = BTreeMap::new();
+        let mut due = Vec::new();
+        for &(ship, planet) in sightings {
+            let ticks = match self.loitering.get(&ship) {
+                Some(&(near, ticks)) if near == planet => ticks + 1,
+                _ => 1,
+            };
+            if ticks >= INTEL_LOITER_TICKS {
+                due.push((ship, planet));
+                loitering.insert(ship, (planet, 0));
+            } else {
+                loitering.insert(ship, (planet, ticks));
+            }
+        }
+        self.loitering = loitering;
+        due
+    }
+
+    /// Keep a report filed by one of the faction's scouts, replacing older
+    /// intel on the planet, and announce it on the next update
+    pub fn record(&mut self, faction: FactionId, ship: ShipId, report: IntelReport) {
+        self.pending.push(SimulationEvent::IntelGathered { faction, planet: report.planet, ship });
+        self.state.reports.entry(faction).or_default().insert(report.planet, report);
+    }
+
+    /// The faction's latest report on the planet
+    pub fn intel(&self, faction: FactionId, planet: PlanetId) -> Option<&IntelReport> {
+        self.state.reports.get(&faction)?.get(&planet)
+    }
+
+    /// The faction's latest reports, by planet id
+    pub fn reports(&self, faction: FactionId) -> impl Iterator<Item = &IntelReport> {
+        self.state.reports.get(&faction).into_iter().flat_map(|reports| reports.values())
+    }
+
+    /// Every faction's reports, for saving
+    pub fn state(&self) -> &IntelState {
+        &self.state
+    }
+
+    /// Restore saved reports; scouts start loitering over
+    pub fn load_state(&mut self, state: IntelState) {
+        self.state = state;
+        self.loitering.clear();
+    }
+
+    /// Announces the reports filed since the last update
+    pub fn update(&mut self, _delta: f32, event_bus: &mut EventBus) -> GameResult<()> {
+        for event in self.pending.drain(..) {
+            event_bus.queue_event(GameEvent::SimulationEvent(event));
+        }
+        Ok(())
+    }
+
+    /// Scouts are observed through `gather_intel` once per tick
+    pub fn handle_event(&mut self, _event: &GameEvent) -> GameResult<()> {
+        Ok(())
+    }
+}
+
+impl Default for SpySystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GameSystem for SpySystem {
+    fn update(&mut self, delta: f32, context: &mut SystemContext<'_>) -> GameResult<()> {
+        self.update(delta, context.event_bus())
+    }
+
+    fn handle_event(&mut self, event: &GameEvent) -> GameResult<()> {
+        self.handle_event(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::managers::PlanetManager;
+
+    #[test]
+    fn test_scouts_file_reports_after_loitering_and_start_over_when_they_leave() {
+        let mut spies = SpySystem::new();
+        for _ in 1..INTEL_LOITER_TICKS {
+            assert!(spies.observe(&[(1, 4), (2, 5)]).is_empty());
+        }
+        // Scout 2 moved to another planet just before its report was due
+        assert_eq!(spies.observe(&[(1, 4), (2, 6)]), vec![(1, 4)]);
+        assert!(spies.observe(&[(2, 6)]).is_empty());
+
+        let mut planets = PlanetManager::new();
+        let id = planets.create_planet(OrbitalElements::default(), Some(1)).unwrap();
+        let mut planet = planets.get_planet(id).unwrap().clone();
+        planet.id = 4;
+        planet.resources.current.minerals = 250;
+        planet.population.total = 1200;
+        spies.record(0, 1, IntelReport::snapshot(&planet, 2, 90));
+        planet.resources.current.minerals = 0;
+        let known = spies.intel(0, 4).unwrap().apply_to(&planet);
+        assert_eq!((known.resources.current.minerals, known.population.total), (250, 1200));
+        assert_eq!(spies.intel(0, 4).unwrap().age(100), 10);
+        assert!(spies.intel(1, 4).is_none(), "Intel belongs to the faction that gathered it");
+
+        let mut bus = EventBus::new();
+        spies.update(0.1, &mut bus).unwrap();
+        assert!(matches!(
+            bus.take_batch().as_slice(),
+            [GameEvent::SimulationEvent(SimulationEvent::IntelGathered { faction: 0, planet: 4, ship: 1 })]
+        ));
+    }
+}
@@ -0,0 +1,384 @@
+// src/systems/galaxy_gen.rs
+//! Procedural galaxy generation
+//!
+//! Builds every planet of a new game. Home planets and their colonization
+//! targets come from the StartGenerator so starts stay balanced; the rest of
+//! the galaxy is scattered according to a PlanetDistribution, with varied
+//! orbits, sizes, environments and unclaimed resource deposits. Scattered
+//! orbits are mildly elliptical; home orbits stay circular. Home planets and
+//! their colonization targets have ordinary environments, so starts stay
+//! fair; scattered planets get random mineral richness, fertility and
+//! habitability.
+//!
+//! Galaxies with more than one star system put the home planets and their
+//! colonization targets around the first star and deal the scattered planets
+//! out over the other systems in turn. The other stars sit on a ring around the
+//! first, each linked by jump lanes to its neighbors on the ring, and every
+//! other one also to the first star. Lanes take longer and need more fuel the
+//! further apart their stars are. The same parameters always produce the
+//! same galaxy, so seeded games and replays start identically.
+//!
+//! Every planet gets a distinct name of two or three syllables, drawn from a
+//! stream of its own so naming leaves the layout untouched.
+
+use crate::core::types::*;
+use crate::core::{StarMap, StarSystem, JumpLane, JUMP_POINT_DISTANCE};
+use crate::managers::PlanetManager;
+use super::start_generator::{
+    home_ring_radius, jitter, next_seed, orbit_for, unit, PlannedPlanet, StartGenerator, StartLayout,
+};
+use std::collections::HashSet;
+use std::f32::consts::PI;
+
+/// Mixed into the layout seed so scattered planets draw from their own stream
+const SCATTER_STREAM: u64 = 0x5ca7_7e12_9a1a_c7e5;
+/// Smallest size of a scattered planet
+const MIN_SCATTERED_SIZE: u8 = 1;
+/// Largest size of a scattered planet
+const MAX_SCATTERED_SIZE: u8 = 5;
+/// Phase jitter applied to planets along a spiral arm, in radians
+const SPIRAL_ARM_WIDTH: f32 = 0.2;
+/// Mixed into the layout seed for orbit shapes, so they leave the scatter stream untouched
+const ORBIT_SHAPE_STREAM: u64 = 0x0eb1_75ba_9e5e_ed17;
+/// Most eccentric orbit a scattered planet can have
+const MAX_SCATTERED_ECCENTRICITY: f32 = 0.2;
+/// Mixed into the layout seed for planet traits, so they leave the other streams untouched
+const TRAIT_STREAM: u64 = 0x7a17_5eed_b10e_0f5e;
+/// Mixed into the layout seed for star placement
+const STAR_STREAM: u64 = 0x57a2_ba5e_0ddb_a11e;
+/// Distance of the outer stars from the first one, as a multiple of the jump point distance
+const STAR_RING_SPACING: f32 = 4.0;
+/// Random spread of an outer star's distance, as a fraction of the ring radius
+const STAR_RING_JITTER: f32 = 0.15;
+/// Ticks in a jump lane per AU between its stars
+pub const LANE_TICKS_PER_AU: f32 = 0.5;
+/// Fuel a jump lane needs per AU between its stars
+pub const LANE_FUEL_PER_AU: f32 = 0.25;
+/// Names given to the stars after the first, in order; later ones are numbered
+const STAR_NAMES: [&str; 7] = ["Altair", "Vega", "Rigel", "Deneb", "Capella", "Procyon", "Arcturus"];
+/// Mixed into the layout seed for planet names
+const NAME_STREAM: u64 = 0x9a3e_d0c5_1ab1_e5ed;
+/// Syllables planet names are put together from
+const NAME_SYLLABLES: [&str; 24] = [
+    "ka", "ri", "ven", "tor", "ae", "lis", "mor", "dun", "sa", "bel", "qua", "xi",
+    "ter", "on", "zel", "ra", "vo", "nis", "cal", "dra", "mi", "tha", "gor", "ul",
+];
+
+/// Everything that shapes a generated galaxy
+#[derive(Debug, Clone, PartialEq)]
+pub struct GalaxyParameters {
+    /// Seed for every random choice
+    pub seed: u64,
+    /// Total planets, including home planets
+    pub planet_count: usize,
+    /// Factions that need a home planet
+    pub faction_count: usize,
+    /// Sets the radius of the home ring
+    pub galaxy_size: GalaxySize,
+    /// Scales home stockpiles and spacing
+    pub difficulty: Difficulty,
+    /// How scattered planets are placed
+    pub distribution: PlanetDistribution,
+    /// Innermost orbit of a scattered planet
+    pub inner_radius: f32,
+    /// Outermost orbit of a scattered planet
+    pub outer_radius: f32,
+    /// Largest deposit on a scattered planet, as a fraction of a home stockpile
+    pub max_deposit_fraction: f32,
+    /// Home stockpile before difficulty scaling
+    pub starting_resources: ResourceBundle,
+    /// Home population
+    pub starting_population: i32,
+    /// Star systems, at least one
+    pub star_systems: usize,
+}
+
+impl GalaxyParameters {
+    /// Parameters for the player plus the configured AI opponents
+    pub fn from_configuration(config: &GameConfiguration) -> Self {
+        Self {
+            seed: config.seed,
+            planet_count: config.planet_count,
+            faction_count: 1 + config.ai_opponents,
+            galaxy_size: config.galaxy_size,
+            difficulty: config.difficulty,
+            distribution: config.distribution,
+            inner_radius: 1.0,
+            outer_radius: 1.0 + home_ring_radius(config.galaxy_size) * 1.5,
+            max_deposit_fraction: 0.5,
+            starting_resources: config.starting_resources,
+            starting_population: config.starting_population,
+            star_systems: config.star_systems,
+        }
+    }
+}
+
+impl Default for GalaxyParameters {
+    fn default() -> Self {
+        Self::from_configuration(&GameConfiguration::default())
+    }
+}
+
+/// GalaxyGenerator creates the full, seeded planet layout for a new game
+pub struct GalaxyGenerator {
+    parameters: GalaxyParameters,
+}
+
+impl GalaxyGenerator {
+    /// Create a generator for the given parameters
+    pub fn new(parameters: GalaxyParameters) -> Self {
+        Self { parameters }
+    }
+
+    /// Create a generator for a new game's configuration
+    pub fn from_configuration(config: &GameConfiguration) -> Self {
+        Self::new(GalaxyParameters::from_configuration(config))
+    }
+
+    /// Parameters the galaxy is generated from
+    pub fn parameters(&self) -> &GalaxyParameters {
+        &self.parameters
+    }
+
+    /// Generate home systems, then scatter the remaining planets
+    pub fn generate(&self) -> GameResult<StartLayout> {
+        let mut layout = self.start_generator().generate()?;
+        let params = &self.parameters;
+        let mut rng = next_seed(layout.seed ^ SCATTER_STREAM);
+        let mut shape_rng = next_seed(layout.seed ^ ORBIT_SHAPE_STREAM);
+        let mut trait_rng = next_seed(layout.seed ^ TRAIT_STREAM);
+        layout.star_map = star_map(params.star_systems, next_seed(layout.seed ^ STAR_STREAM));
+        let systems = layout.star_map.systems.len();
+
+        let inner = params.inner_radius.max(0.5);
+        let outer = params.outer_radius.max(inner);
+        // Cluster centres are drawn up front so every planet of a cluster shares one
+        let centres: Vec<(f32, f32)> = match params.distribution {
+            PlanetDistribution::Clustered { clusters, .. } => (0..clusters.max(1))
+                .map(|_| (inner + unit(&mut rng) * (outer - inner), unit(&mut rng) * 2.0 * PI))
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        let remaining = params.planet_count.saturating_sub(layout.planets.len());
+        for index in 0..remaining {
+            let (axis, phase) = match params.distribution {
+                PlanetDistribution::Uniform => {
+                    (inner + unit(&mut rng) * (outer - inner), unit(&mut rng) * 2.0 * PI)
+                }
+                PlanetDistribution::Clustered { spread, .. } => {
+                    let (centre_axis, centre_phase) = centres[index % centres.len()];
+                    let axis = (centre_axis + jitter(&mut rng, spread)).clamp(inner, outer);
+                    (axis, centre_phase + jitter(&mut rng, spread) / centre_axis)
+                }
+                PlanetDistribution::Spiral { arms, twist } => {
+                    let arms = arms.max(1);
+                    let along = unit(&mut rng);
+                    let arm_phase = 2.0 * PI * (index as u32 % arms) as f32 / arms as f32;
+                    let phase = arm_phase + twist * along + jitter(&mut rng, SPIRAL_ARM_WIDTH);
+                    (inner + along * (outer - inner), phase)
+                }
+            };
+
+            let size = MIN_SCATTERED_SIZE
+                + (unit(&mut rng) * (MAX_SCATTERED_SIZE - MIN_SCATTERED_SIZE + 1) as f32) as u8;
+            let orbit = OrbitalElements {
+                eccentricity: unit(&mut shape_rng) * MAX_SCATTERED_ECCENTRICITY,
+                argument_of_periapsis: unit(&mut shape_rng) * 2.0 * PI,
+                ..orbit_for(axis, phase)
+            };
+            // The first star already holds the homes, so the others come first
+            let system = if systems > 1 { 1 + index % (systems - 1) } else { 0 };
+            layout.planets.push(PlannedPlanet {
+                orbit,
+                controller: None,
+                resources: self.deposit(size, &mut rng),
+                population: 0,
+                size,
+                traits: random_traits(&mut trait_rng),
+                system: system as StarSystemId,
+                name: None,
+            });
+        }
+
+        let names = planet_names(layout.planets.len(), next_seed(layout.seed ^ NAME_STREAM));
+        for (planet, name) in layout.planets.iter_mut().zip(names) {
+            planet.name = Some(name);
+        }
+        Ok(layout)
+    }
+
+    /// Create the generated planets and their starting stockpiles
+    pub fn apply(&self, layout: &StartLayout, planet_manager: &mut PlanetManager) -> GameResult<Vec<PlanetId>> {
+        self.start_generator().apply(layout, planet_manager)
+    }
+
+    /// Home systems only; scattered planets are added by `generate`
+    fn start_generator(&self) -> StartGenerator {
+        let params = &self.parameters;
+        StartGenerator::from_configuration(&GameConfiguration {
+            planet_count: 0,
+            starting_resources: params.starting_resources,
+            starting_population: params.starting_population,
+            galaxy_size: params.galaxy_size,
+            ai_opponents: params.faction_count.saturating_sub(1),
+            difficulty: params.difficulty,
+            seed: params.seed,
+            distribution: params.distribution,
+            ..Default::default()
+        })
+    }
+
+    /// Unclaimed resources on a scattered planet, larger on bigger planets.
+    /// Never more than a home stockpile, so deposits fit default storage.
+    fn deposit(&self, size: u8, rng: &mut u64) -> ResourceBundle {
+        let fraction = self.parameters.max_deposit_fraction.clamp(0.0, 1.0)
+            * size as f32 / MAX_SCATTERED_SIZE as f32;
+        let mut deposit = ResourceBundle::default();
+        for resource in ResourceType::ALL {
+            let amount = self.parameters.starting_resources.get(resource) as f32 * fraction * unit(rng);
+            deposit.set(resource, amount.floor().max(0.0) as i32);
+        }
+        deposit
+    }
+}
+
+/// `count` stars: the first at the origin and the rest on a ring around it,
+/// linked by jump lanes
+fn star_map(count: usize, mut rng: u64) -> StarMap {
+    let count = count.max(1);
+    let ring = JUMP_POINT_DISTANCE * STAR_RING_SPACING;
+    let outer = count - 1;
+    let mut map = StarMap::single();
+    for index in 1..count {
+        let angle = 2.0 * PI * (index - 1) as f32 / outer as f32 + jitter(&mut rng, PI / (2 * outer) as f32);
+        let radius = ring * (1.0 + jitter(&mut rng, STAR_RING_JITTER));
+        map.systems.push(StarSystem {
+            id: index as StarSystemId,
+            name: STAR_NAMES.get(index - 1).map_or_else(|| format!("Star {}", index), |name| name.to_string()),
+            position: Vector2::new(radius * angle.cos(), radius * angle.sin()),
+        });
+    }
+
+    let mut links: Vec<(usize, usize)> = (1..count).step_by(2).map(|index| (0, index)).collect();
+    links.extend((1..outer).map(|index| (index, index + 1)));
+    if outer >= 3 {
+        links.push((outer, 1));
+    }
+    for (from, to) in links {
+        let distance = map.systems[from].position.distance_to(&map.systems[to].position);
+        map.lanes.push(JumpLane {
+            from: from as StarSystemId,
+            to: to as StarSystemId,
+            travel_ticks: ((distance * LANE_TICKS_PER_AU).round() as u64).max(1),
+            fuel_cost: (distance * LANE_FUEL_PER_AU).round(),
+        });
+    }
+    map
+}
+
+/// `count` distinct planet names; a name drawn twice gets the planet's number
+fn planet_names(count: usize, mut rng: u64) -> Vec<String> {
+    let mut taken = HashSet::with_capacity(count);
+    (1..=count).map(|number| {
+        let syllables = 2 + (unit(&mut rng) * 2.0) as usize;
+        let word: String = (0..syllables)
+            .map(|_| NAME_SYLLABLES[(unit(&mut rng) * NAME_SYLLABLES.len() as f32) as usize % NAME_SYLLABLES.len()])
+            .collect();
+        let mut letters = word.chars();
+        let first = letters.next().map(|letter| letter.to_ascii_uppercase()).unwrap_or_default();
+        let name = format!("{}{}", first, letters.as_str());
+        let name = if taken.contains(&name) { format!("{} {}", name, number) } else { name };
+        taken.insert(name.clone());
+        name
+    }).collect()
+}
+
+/// Traits between [`MIN_PLANET_TRAIT`] and [`MAX_PLANET_TRAIT`], in steps of 0.1
+fn random_traits(rng: &mut u64) -> PlanetTraits {
+    let mut draw = || {
+        let value = MIN_PLANET_TRAIT + unit(rng) * (MAX_PLANET_TRAIT - MIN_PLANET_TRAIT);
+        (value * 10.0).round() / 10.0
+    };
+    PlanetTraits { mineral_richness: draw(), fertility: draw(), habitability: draw() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parameters(distribution: PlanetDistribution) -> GalaxyParameters {
+        GalaxyParameters {
+            seed: 7,
+            planet_count: 20,
+            faction_count: 3,
+            distribution,
+            ..Default::default()
+        }
+    }
+
+    const DISTRIBUTIONS: [PlanetDistribution; 3] = [
+        PlanetDistribution::Uniform,
+        PlanetDistribution::Clustered { clusters: 3, spread: 0.8 },
+        PlanetDistribution::Spiral { arms: 2, twist: 3.0 },
+    ];
+
+    #[test]
+    fn test_galaxy_has_requested_planets_and_homeworlds() {
+        for distribution in DISTRIBUTIONS {
+            let params = parameters(distribution);
+            let layout = GalaxyGenerator::new(params.clone()).generate().unwrap();
+            assert_eq!(layout.planets.len(), 20);
+            assert_eq!(layout.homes().count(), 3);
+            for planet in layout.neutrals() {
+                assert!(planet.orbit.semi_major_axis >= 0.5);
+                assert!(planet.orbit.validate().is_ok());
+                assert!(planet.orbit.eccentricity < MAX_SCATTERED_ECCENTRICITY);
+                assert!((1..=MAX_SCATTERED_SIZE).contains(&planet.size));
+                assert!(planet.traits.validate().is_ok());
+                for resource in ResourceType::ALL {
+                    assert!(planet.resources.get(resource) <= params.starting_resources.get(resource));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_same_seed_generates_same_galaxy() {
+        for distribution in DISTRIBUTIONS {
+            let first = GalaxyGenerator::new(parameters(distribution)).generate().unwrap();
+            let second = GalaxyGenerator::new(parameters(distribution)).generate().unwrap();
+            for (a, b) in first.planets.iter().zip(&second.planets) {
+                assert_eq!(a.orbit.semi_major_axis, b.orbit.semi_major_axis);
+                assert_eq!(a.orbit.phase, b.orbit.phase);
+                assert_eq!(a.orbit.eccentricity, b.orbit.eccentricity);
+                assert_eq!(a.resources, b.resources);
+                assert_eq!(a.size, b.size);
+                assert_eq!(a.traits, b.traits);
+            }
+
+            let other = GalaxyGenerator::new(GalaxyParameters { seed: 8, ..parameters(distribution) })
+                .generate()
+                .unwrap();
+            assert!(first.planets.iter().zip(&other.planets)
+                .any(|(a, b)| a.orbit.phase != b.orbit.phase));
+        }
+    }
+
+    #[test]
+    fn test_apply_creates_sized_planets_with_deposits() {
+        let generator = GalaxyGenerator::new(parameters(PlanetDistribution::Uniform));
+        let layout = generator.generate().unwrap();
+        let mut planet_manager = PlanetManager::new();
+        let ids = generator.apply(&layout, &mut planet_manager).unwrap();
+        assert_eq!(ids.len(), layout.planets.len());
+
+        for (id, planned) in ids.iter().zip(&layout.planets) {
+            let planet = planet_manager.get_planet(*id).unwrap();
+            assert_eq!(planet.size, planned.size);
+            assert_eq!(planet.traits, planned.traits);
+            assert_eq!(planet.controller, planned.controller);
+            assert_eq!(planet.resources.current, planned.resources);
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use crate::core::types::*;
+use crate::core::StarMap;
 use crate::managers::*;
-use std::f32::consts::PI;
+use super::GalaxyGenerator;
+use super::pirates::PIRATE_FACTION_NAME;
 
 /// GameInitializer handles creating new games with configurable parameters
 pub struct GameInitializer {
@@ -14,31 +16,32 @@ impl GameInitializer {
         }
     }
 
-    /// Initialize a new game with the configured parameters
+    /// Initialize a new game with the configured parameters, returning the
+    /// star systems its planets orbit
     pub fn initialize_game(
         &self,
         planet_manager: &mut PlanetManager,
         ship_manager: &mut ShipManager,
         faction_manager: &mut FactionManager,
-    ) -> GameResult<()> {
+    ) -> GameResult<StarMap> {
         // Clear existing data
         *planet_manager = PlanetManager::new();
-        *ship_manager = ShipManager::new();
+        ship_manager.clear();
         *faction_manager = FactionManager::new();
 
         // Create factions
         self.create_factions(faction_manager)?;
 
         // Create planets based on configuration
-        self.create_planets(planet_manager)?;
+        let star_map = self.create_planets(planet_manager)?;
 
         // Create starting ships
         self.create_starting_ships(ship_manager, planet_manager)?;
 
         // Apply starting resources and population
-        self.apply_starting_conditions(planet_manager)?;
+        Self::assign_starting_workers(planet_manager)?;
 
-        Ok(())
+        Ok(star_map)
     }
 
     fn create_factions(&self, faction_manager: &mut FactionManager) -> GameResult<()> {
@@ -61,57 +64,20 @@ impl GameInitializer {
             faction_manager.create_faction(name, false, personality)?;
         }
 
-        Ok(())
-    }
-
-    fn create_planets(&self, planet_manager: &mut PlanetManager) -> GameResult<Vec<PlanetId>> {
-        let mut planet_ids = Vec::new();
-
-        // Calculate planet positions in a spiral or grid pattern
-        for i in 0..self.configuration.planet_count {
-            let orbital_elements = self.generate_orbital_elements(i);
-            
-            // Assign controller based on planet index
-            let controller = if i == 0 {
-                Some(0) // Player faction always gets first planet
-            } else if i < 1 + self.configuration.ai_opponents {
-                Some(i as u8) // AI factions get next planets
-            } else {
-                None // Remaining planets are neutral
-            };
-
-            let planet_id = planet_manager.create_planet(orbital_elements, controller)?;
-            planet_ids.push(planet_id);
+        // Pirates come last so the empires keep their ids
+        if self.configuration.pirates {
+            faction_manager.create_system_faction(PIRATE_FACTION_NAME.to_string(), FactionKind::Pirates)?;
         }
 
-        Ok(planet_ids)
+        Ok(())
     }
 
-    fn generate_orbital_elements(&self, index: usize) -> OrbitalElements {
-        // Create varied orbital parameters for interesting gameplay
-        let base_radius = match self.configuration.galaxy_size {
-            GalaxySize::Small => 1.0 + (index as f32 * 0.8),
-            GalaxySize::Medium => 1.0 + (index as f32 * 0.6),
-            GalaxySize::Large => 1.0 + (index as f32 * 0.4),
-        };
-
-        // Add some deterministic variation for variety
-        let radius_variation = ((index as f32 * 0.618) % 1.0 - 0.5) * 0.6; // Golden ratio for pseudo-randomness
-        let semi_major_axis = (base_radius + radius_variation).max(0.5);
-
-        // Calculate period based on Kepler's laws (roughly)
-        let period = (semi_major_axis.powf(1.5) * 100.0).max(50.0);
-
-        // Distribute phases evenly with some deterministic variation
-        let base_phase = (index as f32 * 2.0 * PI) / self.configuration.planet_count as f32;
-        let phase_variation = ((index as f32 * 2.718) % 1.0 - 0.5) * 1.0; // e for pseudo-randomness
-        let phase = base_phase + phase_variation;
-
-        OrbitalElements {
-            semi_major_axis,
-            period,
-            phase,
-        }
+    fn create_planets(&self, planet_manager: &mut PlanetManager) -> GameResult<StarMap> {
+        // Balanced, difficulty-scaled home systems first, then the scattered galaxy
+        let generator = GalaxyGenerator::from_configuration(&self.configuration);
+        let layout = generator.generate()?;
+        generator.apply(&layout, planet_manager)?;
+        Ok(layout.star_map)
     }
 
     fn create_starting_ships(
@@ -124,11 +90,8 @@ impl GameInitializer {
         // Create starting ships for player and AI factions
         for planet in &planets {
             if let Some(faction_id) = planet.controller {
-                // Calculate starting position near the planet using macroquad Vec2 for calculations
-                let planet_pos = macroquad::math::Vec2::new(
-                    planet.position.semi_major_axis * planet.position.phase.cos(),
-                    planet.position.semi_major_axis * planet.position.phase.sin(),
-                );
+                // Calculate starting position near the planet
+                let position = planet.position.position_at(0);
                 
                 // Create initial ships based on faction type
                 let ship_class = if faction_id == 0 {
@@ -137,8 +100,6 @@ impl GameInitializer {
                     ShipClass::Warship // AI starts with warships
                 };
 
-                // Convert Vec2 to Vector2
-                let position = Vector2 { x: planet_pos.x, y: planet_pos.y };
                 ship_manager.create_ship(ship_class, position, faction_id)?;
             }
         }
@@ -146,19 +107,15 @@ impl GameInitializer {
         Ok(())
     }
 
-    fn apply_starting_conditions(&self, planet_manager: &mut PlanetManager) -> GameResult<()> {
+    /// Give every settled planet the standard starting worker allocation
+    pub(crate) fn assign_starting_workers(planet_manager: &mut PlanetManager) -> GameResult<()> {
         let planets = planet_manager.get_all_planets_cloned()?;
 
         for planet in planets {
             if planet.controller.is_some() {
-                // Apply starting resources (only to controlled planets)
-                planet_manager.add_resources(planet.id, self.configuration.starting_resources.clone())?;
-                
-                // Apply starting population
-                planet_manager.update_population(planet.id, self.configuration.starting_population)?;
-                
+                // Starting resources and population come from the start layout
                 // Set up basic worker allocation (ensure minimum 10% unassigned)
-                let total_pop = self.configuration.starting_population;
+                let total_pop = planet.population.total;
                 let min_unassigned = total_pop / 10; // 10% minimum unassigned
                 let assignable = total_pop - min_unassigned; // 900 workers to assign
                 
@@ -234,9 +191,10 @@ mod tests {
 
         initializer.create_factions(&mut faction_manager).unwrap();
         
-        // Should have player + 2 AI factions = 3 total
-        let factions = faction_manager.get_all_factions();
-        assert_eq!(factions.len(), 3);
+        // Should have player + 2 AI factions = 3 empires, plus the pirates
+        assert_eq!(faction_manager.scoring_factions().count(), 3);
+        assert_eq!(faction_manager.get_all_factions().len(), 4);
+        assert!(faction_manager.find_by_kind(FactionKind::Pirates).is_some());
     }
 
     #[test] 
@@ -249,15 +207,15 @@ mod tests {
         let initializer = GameInitializer::new(config);
         let mut planet_manager = PlanetManager::new();
 
-        let planet_ids = initializer.create_planets(&mut planet_manager).unwrap();
-        assert_eq!(planet_ids.len(), 5);
+        // Every faction gets a colonizable neighbour, so 3 factions need at least 6 planets
+        initializer.create_planets(&mut planet_manager).unwrap();
+        assert_eq!(planet_manager.get_all_planets().len(), 6);
 
         // Check planet ownership
         let planets = planet_manager.get_all_planets_cloned().unwrap();
         assert!(planets[0].controller == Some(0)); // Player planet
         assert!(planets[1].controller == Some(1)); // AI planet 1
         assert!(planets[2].controller == Some(2)); // AI planet 2
-        assert!(planets[3].controller.is_none()); // Neutral
-        assert!(planets[4].controller.is_none()); // Neutral
+        assert!(planets[3..].iter().all(|p| p.controller.is_none())); // Neutral
     }
 }
\ No newline at end of file
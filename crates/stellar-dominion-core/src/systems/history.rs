@@ -0,0 +1,281 @@
+// src/systems/history.rs
+//! Timeline of the notable events of a game
+//!
+//! The EventBus history only keeps the most recent events of every kind, so
+//! a battle is soon pushed out by routine ticks and production. HistorySystem
+//! keeps the events worth looking back on — battles, colonies, construction,
+//! conquests, diplomacy, research and discoveries — for the whole game.
+//!
+//! Entries are indexed by [`HistoryCategory`] and kept in tick order within
+//! each category, so [`HistorySystem::query`] only looks at the categories
+//! and tick range a [`TimelineFilter`] asks for. The events do not always say
+//! which factions took part, so [`HistorySystem::record_event`] looks up the
+//! owners of the ships and planets involved in the managers and calls
+//! [`HistorySystem::record`]. The timeline is
+//! not saved.
+
+use crate::core::{GameResult, GameEvent, EventBus, GameSystem, SystemContext};
+use crate::core::types::*;
+use crate::core::events::SimulationEvent;
+use crate::managers::{PlanetManager, ShipManager};
+use std::collections::{BTreeMap, VecDeque};
+
+/// Entries kept per category; the oldest are dropped first
+pub const TIMELINE_ENTRIES_PER_CATEGORY: usize = 500;
+
+/// Kind of event listed on the timeline
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HistoryCategory {
+    /// Finished ship battles
+    Combat,
+    /// Colonies founded
+    Colonization,
+    /// Buildings and ships completed
+    Construction,
+    /// Invasions and planets changing hands
+    Conquest,
+    /// Treaties, wars and trades
+    Diplomacy,
+    /// Technologies unlocked
+    Research,
+    /// Planets discovered
+    Exploration,
+}
+
+impl HistoryCategory {
+    /// All categories in the order the timeline lists them
+    pub const ALL: [HistoryCategory; 7] = [
+        HistoryCategory::Combat,
+        HistoryCategory::Colonization,
+        HistoryCategory::Construction,
+        HistoryCategory::Conquest,
+        HistoryCategory::Diplomacy,
+        HistoryCategory::Research,
+        HistoryCategory::Exploration,
+    ];
+
+    /// Category of an event worth keeping on the timeline; None for routine events
+    pub fn of(event: &SimulationEvent) -> Option<Self> {
+        match event {
+            SimulationEvent::CombatResolved { .. } => Some(HistoryCategory::Combat),
+            SimulationEvent::PlanetColonized { .. } => Some(HistoryCategory::Colonization),
+            SimulationEvent::ConstructionCompleted { .. }
+            | SimulationEvent::ShipCompleted { .. } => Some(HistoryCategory::Construction),
+            SimulationEvent::PlanetConquered { .. }
+            | SimulationEvent::PlanetInvaded { .. }
+            | SimulationEvent::InvasionRepelled { .. } => Some(HistoryCategory::Conquest),
+            SimulationEvent::TreatySigned { .. }
+            | SimulationEvent::TreatyExpired { .. }
+            | SimulationEvent::WarDeclared { .. }
+            | SimulationEvent::TradeAccepted { .. } => Some(HistoryCategory::Diplomacy),
+            SimulationEvent::TechnologyUnlocked { .. } => Some(HistoryCategory::Research),
+            SimulationEvent::PlanetDiscovered { .. } => Some(HistoryCategory::Exploration),
+            _ => None,
+        }
+    }
+}
+
+/// One event on the timeline
+#[derive(Debug, Clone)]
+pub struct TimelineEntry {
+    /// Order the entry was recorded in, across all categories
+    pub id: u64,
+    /// Tick the event happened
+    pub tick: u64,
+    /// Kind of event
+    pub category: HistoryCategory,
+    /// Factions that took part, without repeats
+    pub factions: Vec<FactionId>,
+    /// The event itself
+    pub event: SimulationEvent,
+}
+
+/// Which timeline entries to list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimelineFilter {
+    /// Categories to list; empty lists them all
+    pub categories: Vec<HistoryCategory>,
+    /// Only entries this faction took part in
+    pub faction: Option<FactionId>,
+    /// First tick listed
+    pub from_tick: u64,
+    /// Last tick listed
+    pub to_tick: u64,
+}
+
+impl Default for TimelineFilter {
+    fn default() -> Self {
+        Self {
+            categories: Vec::new(),
+            faction: None,
+            from_tick: 0,
+            to_tick: u64::MAX,
+        }
+    }
+}
+
+impl TimelineFilter {
+    /// Whether the entry passes the filter
+    pub fn matches(&self, entry: &TimelineEntry) -> bool {
+        (self.categories.is_empty() || self.categories.contains(&entry.category))
+            && self.faction.is_none_or(|faction| entry.factions.contains(&faction))
+            && (self.from_tick..=self.to_tick).contains(&entry.tick)
+    }
+}
+
+/// Records notable events per category for the timeline
+#[derive(Debug, Clone, Default)]
+pub struct HistorySystem {
+    /// Each category's entries, oldest first
+    by_category: BTreeMap<HistoryCategory, VecDeque<TimelineEntry>>,
+    next_id: u64,
+}
+
+impl HistorySystem {
+    /// Empty timeline
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Put the event on the timeline at `tick` with the factions that took
+    /// part. Routine events are ignored; returns the new entry's id.
+    pub fn record(&mut self, tick: u64, factions: &[FactionId], event: &SimulationEvent) -> Option<u64> {
+        let category = HistoryCategory::of(event)?;
+        let mut involved = Vec::with_capacity(factions.len());
+        for &faction in factions {
+            if !involved.contains(&faction) {
+                involved.push(faction);
+            }
+        }
+
+        let id = self.next_id;
+        self.next_id += 1;
+        let entries = self.by_category.entry(category).or_default();
+        if entries.len() >= TIMELINE_ENTRIES_PER_CATEGORY {
+            entries.pop_front();
+        }
+        entries.push_back(TimelineEntry { id, tick, category, factions: involved, event: event.clone() });
+        Some(id)
+    }
+
+    /// Entries passing the filter, oldest first
+    pub fn query(&self, filter: &TimelineFilter) -> Vec<&TimelineEntry> {
+        let categories: &[HistoryCategory] = if filter.categories.is_empty() {
+            &HistoryCategory::ALL
+        } else {
+            &filter.categories
+        };
+        let mut found: Vec<&TimelineEntry> = categories.iter()
+            .filter_map(|category| self.by_category.get(category))
+            .flat_map(|entries| {
+                // Entries are recorded in tick order, so the range is found by bisection
+                let start = entries.partition_point(|entry| entry.tick < filter.from_tick);
+                let end = entries.partition_point(|entry| entry.tick <= filter.to_tick);
+                entries.range(start..end.max(start))
+            })
+            .filter(|entry| filter.matches(entry))
+            .collect();
+        found.sort_by_key(|entry| entry.id);
+        found
+    }
+
+    /// Entries kept in the category
+    pub fn count(&self, category: HistoryCategory) -> usize {
+        self.by_category.get(&category).map_or(0, VecDeque::len)
+    }
+
+    /// Entries kept across all categories
+    pub fn len(&self) -> usize {
+        self.by_category.values().map(VecDeque::len).sum()
+    }
+
+    /// True when no entry is kept
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Put a notable event on the timeline with the factions that took part.
+    /// Ships already gone and unclaimed planets add no faction.
+    pub fn record_event(&mut self, tick: u64, event: &SimulationEvent, planets: &PlanetManager, ships: &ShipManager) {
+        let planet_owner = |planet: &PlanetId| planets.get_planet(*planet).ok().and_then(|p| p.controller);
+        let ship_owner = |ship: &ShipId| ships.get_ship(*ship).ok().map(|s| s.owner);
+        let factions: Vec<FactionId> = match event {
+            SimulationEvent::CombatResolved { attacker, defender, outcome } => {
+                [ship_owner(attacker), ship_owner(defender), Some(outcome.winner)].into_iter().flatten().collect()
+            }
+            SimulationEvent::PlanetColonized { faction, .. }
+            | SimulationEvent::TechnologyUnlocked { faction, .. }
+            | SimulationEvent::PlanetDiscovered { faction, .. } => vec![*faction],
+            SimulationEvent::ConstructionCompleted { planet, .. } => planet_owner(planet).into_iter().collect(),
+            SimulationEvent::ShipCompleted { ship, .. } => ship_owner(ship).into_iter().collect(),
+            SimulationEvent::PlanetConquered { planet, new_owner } => {
+                planet_owner(planet).into_iter().chain([*new_owner]).collect()
+            }
+            SimulationEvent::PlanetInvaded { planet, invader, .. }
+            | SimulationEvent::InvasionRepelled { planet, invader } => {
+                planet_owner(planet).into_iter().chain([*invader]).collect()
+            }
+            SimulationEvent::TreatySigned { parties, .. }
+            | SimulationEvent::TreatyExpired { parties, .. } => vec![parties.0, parties.1],
+            SimulationEvent::WarDeclared { aggressor, target } => vec![*aggressor, *target],
+            SimulationEvent::TradeAccepted { from, to, .. } => vec![*from, *to],
+            _ => Vec::new(),
+        };
+        self.record(tick, &factions, event);
+    }
+
+    /// Nothing to do per frame; entries come from `record`
+    pub fn update(&mut self, _delta: f32, _event_bus: &mut EventBus) -> GameResult<()> {
+        Ok(())
+    }
+
+    /// Entries are recorded through `record_event`, which reads who owns what
+    pub fn handle_event(&mut self, _event: &GameEvent) -> GameResult<()> {
+        Ok(())
+    }
+}
+
+impl GameSystem for HistorySystem {
+    fn update(&mut self, delta: f32, context: &mut SystemContext<'_>) -> GameResult<()> {
+        self.update(delta, context.event_bus())
+    }
+
+    fn handle_event(&mut self, event: &GameEvent) -> GameResult<()> {
+        self.handle_event(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_by_category_faction_and_ticks() {
+        let mut history = HistorySystem::new();
+        let colonized = |planet| SimulationEvent::PlanetColonized { planet, faction: 1, colonists: 100 };
+        let war = SimulationEvent::WarDeclared { aggressor: 2, target: 1 };
+
+        assert_eq!(history.record(5, &[1], &SimulationEvent::TickCompleted(5)), None);
+        history.record(10, &[1], &colonized(3));
+        history.record(20, &[2, 1, 2], &war);
+        history.record(30, &[1], &colonized(4));
+        assert_eq!(history.len(), 3);
+        assert_eq!(history.count(HistoryCategory::Colonization), 2);
+
+        let ticks = |filter: TimelineFilter| history.query(&filter).iter().map(|entry| entry.tick).collect::<Vec<_>>();
+        assert_eq!(ticks(TimelineFilter::default()), vec![10, 20, 30]);
+        assert_eq!(ticks(TimelineFilter { categories: vec![HistoryCategory::Diplomacy], ..Default::default() }), vec![20]);
+        assert_eq!(ticks(TimelineFilter { faction: Some(2), ..Default::default() }), vec![20]);
+        assert_eq!(ticks(TimelineFilter { from_tick: 15, to_tick: 30, ..Default::default() }), vec![20, 30]);
+        assert_eq!(ticks(TimelineFilter { from_tick: 31, ..Default::default() }), Vec::<u64>::new());
+        assert_eq!(history.query(&TimelineFilter::default())[1].factions, vec![2, 1]);
+
+        // Each category keeps its own most recent entries
+        for tick in 0..TIMELINE_ENTRIES_PER_CATEGORY as u64 {
+            history.record(100 + tick, &[1], &colonized(5));
+        }
+        assert_eq!(history.count(HistoryCategory::Colonization), TIMELINE_ENTRIES_PER_CATEGORY);
+        assert_eq!(history.count(HistoryCategory::Diplomacy), 1);
+        assert_eq!(history.query(&TimelineFilter::default())[0].tick, 20);
+    }
+}
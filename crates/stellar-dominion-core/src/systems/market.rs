@@ -0,0 +1,270 @@
+// src/systems/market.rs
+//! Galactic resource market
+//!
+//! Factions buy and sell the six resources for credits with
+//! `PlayerCommand::MarketBuy` and `MarketSell`, delivering to or taking from
+//! one of their planets. CommandValidator checks credits, stock and storage
+//! against the quotes here; FactionManager then moves the credits and
+//! resources with `buy_goods` or `sell_goods`, and the trade is recorded
+//! with [`MarketSystem::record_purchase`] or [`MarketSystem::record_sale`].
+//!
+//! Every [`PRICE_UPDATE_INTERVAL_TICKS`] each price drifts with the galaxy's
+//! aggregate demand (units bought) against supply (units sold) since the last
+//! update, and eases back towards its base price. Prices and their history
+//! are saved with the game.
+
+use crate::core::{GameResult, GameEvent, EventBus, GameSystem, SystemContext};
+use crate::core::types::*;
+use crate::core::events::*;
+use serde::{Serialize, Deserialize};
+use std::collections::{BTreeMap, VecDeque};
+
+/// Ticks between price updates
+pub const PRICE_UPDATE_INTERVAL_TICKS: u64 = 10;
+/// Buyers pay this multiple of the market price
+pub const BUY_MARKUP: f32 = 1.1;
+/// Sellers receive this multiple of the market price
+pub const SELL_DISCOUNT: f32 = 0.9;
+/// Largest fraction a price moves in one update when trade is all one way
+pub const PRICE_ELASTICITY: f32 = 0.5;
+/// Units of trade that only move a price by half of [`PRICE_ELASTICITY`]
+pub const MARKET_DEPTH: i32 = 1000;
+/// Fraction of the gap to the base price closed every update
+pub const PRICE_REVERSION: f32 = 0.05;
+/// Lowest multiple of the base price a price can fall to
+pub const MIN_PRICE_FACTOR: f32 = 0.25;
+/// Highest multiple of the base price a price can rise to
+pub const MAX_PRICE_FACTOR: f32 = 4.0;
+/// Price updates kept per resource for charts
+pub const PRICE_HISTORY_LENGTH: usize = 100;
+
+/// Price in credits per unit before any trade has moved it
+pub fn base_price(resource: ResourceType) -> f32 {
+    match resource {
+        ResourceType::Minerals => 1.0,
+        ResourceType::Food => 1.0,
+        ResourceType::Energy => 1.5,
+        ResourceType::Fuel => 2.0,
+        ResourceType::Alloys => 4.0,
+        ResourceType::Components => 6.0,
+    }
+}
+
+/// A resource's price after one price update
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PricePoint {
+    /// Tick of the update
+    pub tick: u64,
+    /// Credits per unit after it
+    pub price: f32,
+}
+
+/// Current prices and their recent history, as saved with the game.
+/// Resources without a price trade at their base price.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MarketState {
+    /// Current credits per unit of each resource that has traded
+    pub prices: BTreeMap<ResourceType, f32>,
+    /// The last `PRICE_HISTORY_LENGTH` price updates of each resource, oldest first
+    pub history: BTreeMap<ResourceType, VecDeque<PricePoint>>,
+}
+
+impl MarketState {
+    /// Reject prices that are not positive
+    pub fn validate(&self) -> GameResult<()> {
+        if let Some((resource, price)) = self.prices.iter().find(|(_, price)| !price.is_finite() || **price <= 0.0) {
+            return Err(GameError::InvalidOperation(format!("Market price of {:?} must be positive, got {}", resource, price)));
+        }
+        Ok(())
+    }
+}
+
+/// Quotes trades and moves prices with supply and demand
+pub struct MarketSystem {
+    state: MarketState,
+    /// Units bought per resource since the last price update
+    demand: BTreeMap<ResourceType, i32>,
+    /// Units sold per resource since the last price update
+    supply: BTreeMap<ResourceType, i32>,
+    pending: Vec<SimulationEvent>,
+}
+
+impl MarketSystem {
+    /// Creates a market trading at base prices
+    pub fn new() -> Self {
+        Self {
+            state: MarketState::default(),
+            demand: BTreeMap::new(),
+            supply: BTreeMap::new(),
+            pending: Vec::new(),
+        }
+    }
+
+    /// Current market price of one unit
+    pub fn price(&self, resource: ResourceType) -> f32 {
+        self.state.prices.get(&resource).copied().unwrap_or_else(|| base_price(resource))
+    }
+
+    /// Price updates for the resource, oldest first
+    pub fn price_history(&self, resource: ResourceType) -> impl Iterator<Item = &PricePoint> {
+        self.state.history.get(&resource).into_iter().flatten()
+    }
+
+    /// Credits a faction pays for `amount` units, rounded to whole credits
+    pub fn buy_cost(&self, resource: ResourceType, amount: i32) -> GameResult<i32> {
+        validate_amount(amount)?;
+        Ok((self.price(resource) * BUY_MARKUP * amount as f32).round() as i32)
+    }
+
+    /// Credits a faction receives for `amount` units, rounded to whole credits
+    pub fn sell_revenue(&self, resource: ResourceType, amount: i32) -> GameResult<i32> {
+        validate_amount(amount)?;
+        Ok((self.price(resource) * SELL_DISCOUNT * amount as f32).round() as i32)
+    }
+
+    /// Count a settled purchase towards demand and announce it with `ResourcesBought`
+    pub fn record_purchase(&mut self, faction: FactionId, planet: PlanetId, resource: ResourceType, amount: i32, cost: i32) {
+        *self.demand.entry(resource).or_insert(0) += amount;
+        self.pending.push(SimulationEvent::ResourcesBought { faction, planet, resource, amount, cost });
+    }
+
+    /// Count a settled sale towards supply and announce it with `ResourcesSold`
+    pub fn record_sale(&mut self, faction: FactionId, planet: PlanetId, resource: ResourceType, amount: i32, revenue: i32) {
+        *self.supply.entry(resource).or_insert(0) += amount;
+        self.pending.push(SimulationEvent::ResourcesSold { faction, planet, resource, amount, revenue });
+    }
+
+    /// True on ticks where prices move
+    pub fn is_price_tick(&self, tick: u64) -> bool {
+        tick > 0 && tick.is_multiple_of(PRICE_UPDATE_INTERVAL_TICKS)
+    }
+
+    /// Move every price with the trade since the last update and record it
+    pub fn update_prices(&mut self, tick: u64) {
+        for resource in ResourceType::ALL {
+            let demand = self.demand.remove(&resource).unwrap_or(0).max(0);
+            let supply = self.supply.remove(&resource).unwrap_or(0).max(0);
+            let pressure = (demand as f32 - supply as f32) / (demand as f32 + supply as f32 + MARKET_DEPTH as f32);
+
+            let base = base_price(resource);
+            let mut price = self.price(resource) * (1.0 + PRICE_ELASTICITY * pressure);
+            price += (base - price) * PRICE_REVERSION;
+            let price = price.clamp(base * MIN_PRICE_FACTOR, base * MAX_PRICE_FACTOR);
+            self.state.prices.insert(resource, price);
+
+            let history = self.state.history.entry(resource).or_default();
+            history.push_back(PricePoint { tick, price });
+            while history.len() > PRICE_HISTORY_LENGTH {
+                history.pop_front();
+            }
+        }
+    }
+
+    /// Prices and history to save
+    pub fn state(&self) -> &MarketState {
+        &self.state
+    }
+
+    /// Restore saved prices; trade since the last update is forgotten
+    pub fn load_state(&mut self, state: MarketState) -> GameResult<()> {
+        state.validate()?;
+        self.state = state;
+        self.demand.clear();
+        self.supply.clear();
+        self.pending.clear();
+        Ok(())
+    }
+
+    /// Emits the trades settled since the last update
+    pub fn update(&mut self, _delta: f32, event_bus: &mut EventBus) -> GameResult<()> {
+        for event in self.pending.drain(..) {
+            event_bus.queue_event(GameEvent::SimulationEvent(event));
+        }
+        Ok(())
+    }
+
+    /// Moves prices on price ticks; trades are settled by FactionManager,
+    /// which has the faction credits
+    pub fn handle_event(&mut self, event: &GameEvent) -> GameResult<()> {
+        if let GameEvent::SimulationEvent(SimulationEvent::TickCompleted(tick)) = event {
+            if self.is_price_tick(*tick) {
+                self.update_prices(*tick);
+            }
+        }
+        Ok(())
+    }
+}
+
+fn validate_amount(amount: i32) -> GameResult<()> {
+    if amount <= 0 {
+        return Err(GameError::InvalidOperation(format!("Market orders need a positive amount, got {}", amount)));
+    }
+    Ok(())
+}
+
+impl Default for MarketSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GameSystem for MarketSystem {
+    fn update(&mut self, delta: f32, context: &mut SystemContext<'_>) -> GameResult<()> {
+        self.update(delta, context.event_bus())
+    }
+
+    fn handle_event(&mut self, event: &GameEvent) -> GameResult<()> {
+        self.handle_event(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prices_follow_demand_and_supply() {
+        let mut market = MarketSystem::new();
+        assert_eq!(market.price(ResourceType::Alloys), base_price(ResourceType::Alloys));
+        assert_eq!(market.buy_cost(ResourceType::Alloys, 10).unwrap(), 44);
+        assert_eq!(market.sell_revenue(ResourceType::Alloys, 10).unwrap(), 36);
+        assert!(market.buy_cost(ResourceType::Alloys, 0).is_err());
+
+        market.record_purchase(0, 0, ResourceType::Food, 1000, 1100);
+        market.record_sale(1, 1, ResourceType::Minerals, 1000, 900);
+        market.update_prices(PRICE_UPDATE_INTERVAL_TICKS);
+        assert!(market.price(ResourceType::Food) > base_price(ResourceType::Food), "Buying raises the price");
+        assert!(market.price(ResourceType::Minerals) < base_price(ResourceType::Minerals), "Selling lowers the price");
+        assert_eq!(market.price(ResourceType::Energy), base_price(ResourceType::Energy), "Untraded prices hold");
+
+        // Without further trade prices ease back towards their base
+        let raised = market.price(ResourceType::Food);
+        market.update_prices(PRICE_UPDATE_INTERVAL_TICKS * 2);
+        assert!(market.price(ResourceType::Food) < raised);
+        let history: Vec<u64> = market.price_history(ResourceType::Food).map(|point| point.tick).collect();
+        assert_eq!(history, vec![PRICE_UPDATE_INTERVAL_TICKS, PRICE_UPDATE_INTERVAL_TICKS * 2]);
+
+        // Trades are announced on the next update
+        let mut bus = EventBus::new();
+        market.update(0.1, &mut bus).unwrap();
+        assert_eq!(bus.queued_events.len(), 2);
+    }
+
+    #[test]
+    fn test_prices_stay_within_bounds_and_history_is_capped() {
+        let mut market = MarketSystem::new();
+        for update in 1..=(PRICE_HISTORY_LENGTH as u64 + 20) {
+            market.record_purchase(0, 0, ResourceType::Fuel, 1_000_000, 0);
+            market.update_prices(update * PRICE_UPDATE_INTERVAL_TICKS);
+        }
+        assert_eq!(market.price(ResourceType::Fuel), base_price(ResourceType::Fuel) * MAX_PRICE_FACTOR);
+        assert_eq!(market.price_history(ResourceType::Fuel).count(), PRICE_HISTORY_LENGTH);
+
+        let mut restored = MarketSystem::new();
+        restored.load_state(market.state().clone()).unwrap();
+        assert_eq!(restored.price(ResourceType::Fuel), market.price(ResourceType::Fuel));
+        let mut corrupt = market.state().clone();
+        corrupt.prices.insert(ResourceType::Food, -1.0);
+        assert!(restored.load_state(corrupt).is_err());
+    }
+}
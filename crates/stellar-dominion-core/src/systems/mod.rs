@@ -0,0 +1,71 @@
+// src/systems/mod.rs
+//
+// System implementations for Stellar Dominion
+//
+// All systems follow the EventBus architecture:
+// - Communicate exclusively through events (no direct references)
+// - Implement GameSystem trait (update + handle_event methods)
+// - Process in fixed order: Physics → Resources → Population → Construction → Combat → AI → Time
+// - Emit events for state changes, never mutate directly
+// - Return GameResult<T> from all operations
+//
+// Systems subscribe to events in GameState::new() and are called in
+// GameState::fixed_update() following the strict architectural order.
+
+pub mod time_manager;
+pub mod physics_engine;
+pub mod spatial_index;
+pub mod resource_system;
+pub mod population_system;
+pub mod construction;
+pub mod combat_resolver;
+pub mod save_system;
+pub mod game_initializer;
+pub mod start_generator;
+pub mod galaxy_gen;
+pub mod ai_system;
+pub mod diplomacy;
+pub mod replay;
+pub mod research;
+pub mod visibility;
+pub mod scenario;
+pub mod market;
+pub mod notifications;
+pub mod cargo;
+pub mod history;
+pub mod statistics;
+pub mod objectives;
+pub mod pirates;
+pub mod espionage;
+pub mod supply;
+
+// Re-export all systems for use in GameState
+pub use time_manager::{TimeManager, GAME_SPEEDS, FAST_FORWARD_TICKS_PER_UPDATE, MAX_FAST_FORWARD_TICKS};
+pub use physics_engine::{PhysicsEngine, Route, TransferWindow};
+pub use spatial_index::{SpatialIndex, SpatialEntity, SPATIAL_CELL_SIZE};
+pub use resource_system::{ResourceSystem, ProductionDelta, EnergyGrid, UpkeepPlan, ProductionReport, ProductionLine, ProductionSource, EmpireEconomy, ResourceOutlook, OutlookLevel, ECONOMY_CRITICAL_TICKS, ECONOMY_WARNING_TICKS, Budget, SHIP_UPKEEP_CREDITS, BUILDING_MAINTENANCE_CREDITS};
+pub use population_system::PopulationSystem;
+pub use construction::{ConstructionSystem, ShipBuildOption, BuildingBuildOption};
+pub use combat_resolver::{CombatResolver, Combatant, ShipBattle, Invasion, Bombardment, OrbitalPresence};
+pub use save_system::SaveSystem;
+pub use game_initializer::GameInitializer;
+pub use start_generator::StartGenerator;
+pub use galaxy_gen::{GalaxyGenerator, GalaxyParameters};
+pub use ai_system::{AISystem, AIPlanetView, AIWorldView};
+pub use diplomacy::DiplomacySystem;
+pub use replay::{Replay, ReplaySystem, ReplayMode, RecordedCommand};
+pub use research::ResearchSystem;
+pub use visibility::{VisibilitySystem, Sensor};
+pub use market::{MarketSystem, MarketState, PricePoint};
+pub use notifications::{NotificationSystem, Notification, NotificationKind, NotificationPriority};
+pub use cargo::CargoSystem;
+pub use history::{HistorySystem, HistoryCategory, TimelineEntry, TimelineFilter, TIMELINE_ENTRIES_PER_CATEGORY};
+pub use statistics::{StatisticsSystem, FactionStatistics, StatisticsMetric, StatisticsSample, StatisticsState, STATISTICS_SAMPLE_INTERVAL_TICKS, STATISTICS_MAX_SAMPLES, SCORE_PER_PLANET, SCORE_PER_SHIP, SCORE_PER_BATTLE_WON};
+pub use objectives::{ObjectiveSystem, Objective, ObjectiveGoal, ObjectiveTrack, tutorial_objectives};
+pub use pirates::{PirateSystem, PIRATE_FACTION_NAME, PIRATE_FIRST_RAID_TICK, PIRATE_RAID_INTERVAL_TICKS, MAX_PIRATE_RAID_SIZE, MAX_PIRATE_SHIPS};
+pub use espionage::{SpySystem, IntelReport, IntelState, PlanetKnowledge, INTEL_LOITER_TICKS, INTEL_RANGE};
+pub use supply::{SupplySystem, SupplyNetwork, SupplyStatus, CUT_OFF_PRODUCTION_PERCENT, SUPPLY_ATTRITION_INTERVAL_TICKS, SUPPLY_ATTRITION_PERCENT, MIN_SUPPLY_EFFECTIVENESS_PERCENT};
+pub use scenario::{Scenario, ScenarioFaction, ScenarioPlanet, ScenarioShip, ScenarioLoader, ScenarioSystem, VictoryCondition, ScriptedEvent, ScriptedAction, FactionStanding};
+
+// Ensure all systems implement the required GameSystem trait
+// This is enforced at compile time when systems are instantiated in GameState
\ No newline at end of file
@@ -0,0 +1,397 @@
+// src/systems/notifications.rs
+//! Alerts for things a faction should act on
+//!
+//! NotificationSystem keeps a log of prioritized notifications per faction:
+//! stockpiles about to run out or already empty, planets that cannot pay
+//! their upkeep, ships stranded without fuel, ships drawn into combat,
+//! planets blockaded, bombarded, invaded or raided by pirates and new colonies founded. The events behind them do not say who owns
+//! the planet or ship involved, so [`NotificationSystem::raise_notifications`]
+//! looks the owner up in the managers and calls
+//! [`NotificationSystem::notify`] or [`NotificationSystem::check_stockpile`].
+//!
+//! The same alert is not repeated within [`ALERT_REPEAT_TICKS`], so a planet
+//! running low every tick raises one notification rather than hundreds.
+//! Notifications stay in the log until dismissed with
+//! `PlayerCommand::DismissNotification` or pushed out by newer ones. They are
+//! not saved.
+
+use crate::core::{GameResult, GameEvent, EventBus, GameSystem, SystemContext};
+use crate::core::types::*;
+use crate::core::events::*;
+use crate::managers::{PlanetManager, ShipManager};
+use std::collections::{HashMap, VecDeque};
+
+/// Warn when a stockpile will run out within this many ticks at its current drain
+pub const LOW_STOCKPILE_TICKS: i32 = 100;
+/// Ticks before the same alert can be raised again
+pub const ALERT_REPEAT_TICKS: u64 = 300;
+/// Notifications kept across all factions; the oldest are dropped first
+pub const NOTIFICATION_LOG_LENGTH: usize = 100;
+
+/// How urgently a notification needs the player's attention
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum NotificationPriority {
+    /// Worth knowing, no action needed
+    Normal,
+    /// Needs attention soon
+    High,
+    /// Needs attention now
+    Critical,
+}
+
+/// What happened
+#[derive(Debug, Clone, PartialEq)]
+pub enum NotificationKind {
+    /// The stockpile runs out in `ticks_left` ticks at the current drain
+    LowStockpile {
+        /// Planet running low
+        planet: PlanetId,
+        /// Resource running low
+        resource: ResourceType,
+        /// Ticks until the stockpile is empty
+        ticks_left: i32,
+    },
+    /// The planet has run out of the resource
+    ResourceShortage {
+        /// Planet that ran out
+        planet: PlanetId,
+        /// Resource it ran out of
+        resource: ResourceType,
+    },
+    /// The planet's stockpile could not cover its buildings' and workers' upkeep
+    UpkeepUnpaid {
+        /// Planet that missed its upkeep
+        planet: PlanetId,
+    },
+    /// A move order failed because the ship lacks the fuel for the route
+    ShipStranded {
+        /// Ship that could not move
+        ship: ShipId,
+        /// Fuel the route needs
+        required: f32,
+        /// Fuel the ship carries
+        available: f32,
+    },
+    /// The faction's ship is attacking or under attack
+    CombatStarted {
+        /// The faction's ship
+        ship: ShipId,
+        /// The ship it is fighting
+        enemy: ShipId,
+    },
+    /// Enemy warships in orbit are blocking the planet's traffic
+    PlanetBlockaded {
+        /// Planet under blockade
+        planet: PlanetId,
+    },
+    /// Enemy troops have landed on the planet
+    PlanetInvaded {
+        /// Planet under invasion
+        planet: PlanetId,
+    },
+    /// An enemy warship in orbit is bombarding the planet
+    PlanetBombarded {
+        /// Planet under bombardment
+        planet: PlanetId,
+    },
+    /// A colony ship founded a colony on the planet
+    PlanetColonized {
+        /// The new colony
+        planet: PlanetId,
+    },
+    /// A pirate raid is on its way to the planet
+    PirateRaid {
+        /// Planet the raiders are heading for
+        planet: PlanetId,
+    },
+}
+
+impl NotificationKind {
+    /// How urgent this kind of notification is
+    pub fn priority(&self) -> NotificationPriority {
+        match self {
+            NotificationKind::ResourceShortage { .. }
+            | NotificationKind::PlanetInvaded { .. }
+            | NotificationKind::PlanetBombarded { .. } => NotificationPriority::Critical,
+            NotificationKind::LowStockpile { .. }
+            | NotificationKind::UpkeepUnpaid { .. }
+            | NotificationKind::CombatStarted { .. }
+            | NotificationKind::PlanetBlockaded { .. }
+            | NotificationKind::PirateRaid { .. } => NotificationPriority::High,
+            NotificationKind::ShipStranded { .. }
+            | NotificationKind::PlanetColonized { .. } => NotificationPriority::Normal,
+        }
+    }
+
+    /// Identifies repeats of the same alert regardless of the numbers in it
+    fn alert_key(&self) -> AlertKey {
+        match self {
+            NotificationKind::LowStockpile { planet, resource, .. } => AlertKey::LowStockpile(*planet, *resource),
+            NotificationKind::ResourceShortage { planet, resource } => AlertKey::Shortage(*planet, *resource),
+            NotificationKind::UpkeepUnpaid { planet } => AlertKey::Upkeep(*planet),
+            NotificationKind::ShipStranded { ship, .. } => AlertKey::Stranded(*ship),
+            NotificationKind::CombatStarted { ship, .. } => AlertKey::Combat(*ship),
+            NotificationKind::PlanetBlockaded { planet } => AlertKey::Blockade(*planet),
+            NotificationKind::PlanetInvaded { planet } => AlertKey::Invasion(*planet),
+            NotificationKind::PlanetBombarded { planet } => AlertKey::Bombardment(*planet),
+            NotificationKind::PlanetColonized { planet } => AlertKey::Colonized(*planet),
+            NotificationKind::PirateRaid { planet } => AlertKey::Raid(*planet),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum AlertKey {
+    LowStockpile(PlanetId, ResourceType),
+    Shortage(PlanetId, ResourceType),
+    Upkeep(PlanetId),
+    Stranded(ShipId),
+    Combat(ShipId),
+    Blockade(PlanetId),
+    Invasion(PlanetId),
+    Bombardment(PlanetId),
+    Colonized(PlanetId),
+    Raid(PlanetId),
+}
+
+/// One entry in a faction's alert log
+#[derive(Debug, Clone, PartialEq)]
+pub struct Notification {
+    /// Unique id, used to dismiss it
+    pub id: NotificationId,
+    /// Faction the notification is for
+    pub faction: FactionId,
+    /// Tick the notification was raised
+    pub tick: u64,
+    /// How urgent it is
+    pub priority: NotificationPriority,
+    /// What happened
+    pub kind: NotificationKind,
+}
+
+/// Raises, deduplicates and dismisses notifications
+pub struct NotificationSystem {
+    log: VecDeque<Notification>,
+    next_id: NotificationId,
+    current_tick: u64,
+    /// Tick each faction's alert was last raised
+    last_raised: HashMap<(FactionId, AlertKey), u64>,
+}
+
+impl NotificationSystem {
+    /// Empty log
+    pub fn new() -> Self {
+        Self {
+            log: VecDeque::new(),
+            next_id: 1,
+            current_tick: 0,
+            last_raised: HashMap::new(),
+        }
+    }
+
+    /// Raise a notification for the faction, unless the same alert was
+    /// raised within [`ALERT_REPEAT_TICKS`]
+    pub fn notify(&mut self, faction: FactionId, kind: NotificationKind) -> Option<NotificationId> {
+        let key = (faction, kind.alert_key());
+        if let Some(&raised) = self.last_raised.get(&key) {
+            if self.current_tick < raised + ALERT_REPEAT_TICKS {
+                return None;
+            }
+        }
+        self.last_raised.insert(key, self.current_tick);
+
+        let id = self.next_id;
+        self.next_id += 1;
+        self.log.push_back(Notification { id, faction, tick: self.current_tick, priority: kind.priority(), kind });
+        while self.log.len() > NOTIFICATION_LOG_LENGTH {
+            self.log.pop_front();
+        }
+        Some(id)
+    }
+
+    /// Warn the planet's controller about stockpiles that `net_change` per
+    /// tick will empty within [`LOW_STOCKPILE_TICKS`]
+    pub fn check_stockpile(&mut self, faction: FactionId, planet: &Planet, net_change: &ResourceBundle) {
+        for resource in ResourceType::ALL {
+            let drain = -net_change.get(resource);
+            if drain <= 0 {
+                continue;
+            }
+            let ticks_left = planet.resources.current.get(resource) / drain;
+            if ticks_left < LOW_STOCKPILE_TICKS {
+                self.notify(faction, NotificationKind::LowStockpile { planet: planet.id, resource, ticks_left });
+            }
+        }
+    }
+
+    /// The faction's notifications, most urgent first and newest first within a priority
+    pub fn notifications(&self, faction: FactionId) -> Vec<&Notification> {
+        let mut notifications: Vec<&Notification> = self.log.iter()
+            .filter(|notification| notification.faction == faction)
+            .collect();
+        notifications.sort_by(|a, b| b.priority.cmp(&a.priority).then(b.id.cmp(&a.id)));
+        notifications
+    }
+
+    /// Remove one of the faction's notifications from the log
+    pub fn dismiss(&mut self, faction: FactionId, id: NotificationId) -> GameResult<()> {
+        let index = self.log.iter()
+            .position(|notification| notification.id == id && notification.faction == faction)
+            .ok_or_else(|| GameError::not_found(EntityKind::Notification, id))?;
+        self.log.remove(index);
+        Ok(())
+    }
+
+    /// Remove all of the faction's notifications
+    pub fn dismiss_all(&mut self, faction: FactionId) {
+        self.log.retain(|notification| notification.faction != faction);
+    }
+
+    /// Raise the notifications an event calls for with the factions it concerns.
+    /// Planets without a controller and ships already gone raise nothing.
+    pub fn raise_notifications(&mut self, event: &GameEvent, planets: &PlanetManager, ships: &ShipManager) {
+        let planet_owner = |planet: PlanetId| planets.get_planet(planet).ok().and_then(|p| p.controller);
+        let ship_owner = |ship: ShipId| ships.get_ship(ship).ok().map(|s| s.owner);
+        match event {
+            GameEvent::SimulationEvent(SimulationEvent::ResourcesProduced { planet, resources }) => {
+                if let Ok(planet) = planets.get_planet(*planet) {
+                    if let Some(owner) = planet.controller {
+                        self.check_stockpile(owner, planet, resources);
+                    }
+                }
+            }
+            GameEvent::SimulationEvent(SimulationEvent::ResourceShortage { planet, resource }) => {
+                if let Some(owner) = planet_owner(*planet) {
+                    self.notify(owner, NotificationKind::ResourceShortage { planet: *planet, resource: *resource });
+                }
+            }
+            GameEvent::SimulationEvent(SimulationEvent::UpkeepUnpaid { planet, .. })
+            | GameEvent::StateChanged(StateChange::BuildingStatusChanged { planet, status: BuildingStatus::Shutdown(ShutdownReason::UpkeepUnpaid), .. }) => {
+                if let Some(owner) = planet_owner(*planet) {
+                    self.notify(owner, NotificationKind::UpkeepUnpaid { planet: *planet });
+                }
+            }
+            GameEvent::SimulationEvent(SimulationEvent::InsufficientFuel { ship, required, available }) => {
+                if let Some(owner) = ship_owner(*ship) {
+                    self.notify(owner, NotificationKind::ShipStranded { ship: *ship, required: *required, available: *available });
+                }
+            }
+            GameEvent::SimulationEvent(SimulationEvent::GroundCombat { planet, .. }) => {
+                if let Some(owner) = planet_owner(*planet) {
+                    self.notify(owner, NotificationKind::PlanetInvaded { planet: *planet });
+                }
+            }
+            GameEvent::SimulationEvent(SimulationEvent::PlanetBombarded { planet, .. }) => {
+                if let Some(owner) = planet_owner(*planet) {
+                    self.notify(owner, NotificationKind::PlanetBombarded { planet: *planet });
+                }
+            }
+            GameEvent::SimulationEvent(SimulationEvent::PlanetColonized { planet, faction, .. }) => {
+                self.notify(*faction, NotificationKind::PlanetColonized { planet: *planet });
+            }
+            GameEvent::SimulationEvent(SimulationEvent::PirateRaidLaunched { target, .. }) => {
+                if let Some(owner) = planet_owner(*target) {
+                    self.notify(owner, NotificationKind::PirateRaid { planet: *target });
+                }
+            }
+            GameEvent::StateChanged(StateChange::PlanetBlockaded(planet)) => {
+                if let Some(owner) = planet_owner(*planet) {
+                    self.notify(owner, NotificationKind::PlanetBlockaded { planet: *planet });
+                }
+            }
+            GameEvent::PlayerCommand(PlayerCommand::AttackTarget { attacker, target }) => {
+                for (ship, enemy) in [(*attacker, *target), (*target, *attacker)] {
+                    if let Some(owner) = ship_owner(ship) {
+                        self.notify(owner, NotificationKind::CombatStarted { ship, enemy });
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Nothing to do per frame; alerts are raised on ticks
+    pub fn update(&mut self, _delta: f32, _event_bus: &mut EventBus) -> GameResult<()> {
+        Ok(())
+    }
+
+    /// Tracks the tick and handles dismissals; GameState raises the
+    /// notifications with the managers through `raise_notifications`
+    pub fn handle_event(&mut self, event: &GameEvent) -> GameResult<()> {
+        match event {
+            GameEvent::SimulationEvent(SimulationEvent::TickCompleted(tick)) => {
+                self.current_tick = *tick;
+            }
+            GameEvent::PlayerCommand(PlayerCommand::DismissNotification { faction, notification }) => {
+                match notification {
+                    // One already pushed out of the log needs no dismissing
+                    Some(id) => { let _ = self.dismiss(*faction, *id); }
+                    None => self.dismiss_all(*faction),
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+impl Default for NotificationSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GameSystem for NotificationSystem {
+    fn update(&mut self, delta: f32, context: &mut SystemContext<'_>) -> GameResult<()> {
+        self.update(delta, context.event_bus())
+    }
+
+    fn handle_event(&mut self, event: &GameEvent) -> GameResult<()> {
+        self.handle_event(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::managers::PlanetManager;
+
+    #[test]
+    fn test_repeated_alerts_are_held_back_and_sorted_by_priority() {
+        let mut notifications = NotificationSystem::new();
+        let stranded = notifications.notify(0, NotificationKind::ShipStranded { ship: 3, required: 5.0, available: 1.0 });
+        assert!(stranded.is_some());
+        let shortage = NotificationKind::ResourceShortage { planet: 1, resource: ResourceType::Food };
+        assert!(notifications.notify(0, shortage.clone()).is_some());
+        assert!(notifications.notify(0, shortage.clone()).is_none(), "Repeats wait out the cooldown");
+        assert!(notifications.notify(1, shortage.clone()).is_some(), "Each faction hears about it");
+
+        let listed: Vec<NotificationPriority> = notifications.notifications(0).iter().map(|n| n.priority).collect();
+        assert_eq!(listed, vec![NotificationPriority::Critical, NotificationPriority::Normal]);
+
+        notifications.handle_event(&GameEvent::SimulationEvent(SimulationEvent::TickCompleted(ALERT_REPEAT_TICKS))).unwrap();
+        assert!(notifications.notify(0, shortage).is_some());
+
+        notifications.dismiss(0, stranded.unwrap()).unwrap();
+        assert!(notifications.dismiss(0, stranded.unwrap()).is_err());
+        assert!(notifications.dismiss(0, 999).is_err());
+        notifications.dismiss_all(0);
+        assert!(notifications.notifications(0).is_empty());
+        assert_eq!(notifications.notifications(1).len(), 1);
+    }
+
+    #[test]
+    fn test_stockpiles_draining_soon_raise_a_warning() {
+        let mut notifications = NotificationSystem::new();
+        let mut planets = PlanetManager::new();
+        let id = planets.create_planet(OrbitalElements::default(), Some(2)).unwrap();
+        let mut planet = planets.get_planet(id).unwrap().clone();
+        planet.resources.current = ResourceBundle { food: 500, energy: 5000, ..Default::default() };
+        let drain = ResourceBundle { food: -10, energy: -10, minerals: 5, ..Default::default() };
+        notifications.check_stockpile(2, &planet, &drain);
+
+        let raised = notifications.notifications(2);
+        assert_eq!(raised.len(), 1);
+        assert_eq!(raised[0].kind, NotificationKind::LowStockpile { planet: id, resource: ResourceType::Food, ticks_left: 50 });
+    }
+}
@@ -0,0 +1,327 @@
+// src/systems/objectives.rs
+//! Sequences of goals that guide a faction through the game
+//!
+//! An [`Objective`] names a goal, such as finishing a Mine or reaching a
+//! population, and the resources it pays out. A faction works through its
+//! objectives one at a time: only the current one can be completed, and
+//! completing it moves on to the next. Objective lists are data, read from
+//! JSON files with [`ObjectiveSystem::load_from_file`] or given by a
+//! scenario; [`tutorial_objectives`] is the list new players get when the
+//! game configuration asks for the tutorial.
+//!
+//! [`ObjectiveSystem::record_event`] works out from the managers which
+//! faction finished a building or a ship, and measures the state goals once
+//! per tick. The system then
+//! announces each completed objective with `SimulationEvent::ObjectiveCompleted`
+//! and PlanetManager delivers the reward. Like scenario victory conditions,
+//! objectives live only in the running game and are not saved.
+
+use crate::core::{GameResult, GameEvent, EventBus, GameSystem, SystemContext};
+use crate::core::events::SimulationEvent;
+use crate::core::types::*;
+use crate::managers::{PlanetManager, ShipManager, FactionManager};
+use super::FactionStanding;
+use serde::{Serialize, Deserialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+
+/// What a faction has to do to complete an objective
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ObjectiveGoal {
+    /// Finish a building of this type on one of the faction's planets
+    Build(BuildingType),
+    /// Launch a ship of this class
+    BuildShip(ShipClass),
+    /// Reach this population across the faction's planets
+    Population(i32),
+    /// Control at least this many planets
+    ControlPlanets(usize),
+    /// Know this technology
+    Research(Technology),
+}
+
+/// One goal in a faction's sequence
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Objective {
+    /// Short line shown in the HUD
+    pub title: String,
+    /// Longer hint on how to get there
+    #[serde(default)]
+    pub description: String,
+    /// What completes it
+    pub goal: ObjectiveGoal,
+    /// Resources delivered to the faction's first planet on completion
+    #[serde(default)]
+    pub reward: ResourceBundle,
+}
+
+impl Objective {
+    /// Objective with no description or reward
+    pub fn new(title: &str, goal: ObjectiveGoal) -> Self {
+        Self { title: title.to_string(), description: String::new(), goal, reward: ResourceBundle::default() }
+    }
+
+    /// Same objective with a description
+    pub fn with_description(mut self, description: &str) -> Self {
+        self.description = description.to_string();
+        self
+    }
+
+    /// Same objective paying `reward`
+    pub fn with_reward(mut self, reward: ResourceBundle) -> Self {
+        self.reward = reward;
+        self
+    }
+}
+
+/// The objectives offered by the tutorial, in order
+pub fn tutorial_objectives() -> Vec<Objective> {
+    vec![
+        Objective::new("Build a Mine", ObjectiveGoal::Build(BuildingType::Mine))
+            .with_description("Open your home planet's panel and order a Mine from the build menu")
+            .with_reward(ResourceBundle { energy: 100, ..Default::default() }),
+        Objective::new("Build a Farm", ObjectiveGoal::Build(BuildingType::Farm))
+            .with_description("Food keeps your population growing")
+            .with_reward(ResourceBundle { minerals: 100, ..Default::default() }),
+        Objective::new("Reach 5000 population", ObjectiveGoal::Population(5000))
+            .with_description("Keep food and housing ahead of your settlers")
+            .with_reward(ResourceBundle { food: 200, ..Default::default() }),
+        Objective::new("Build a colony ship", ObjectiveGoal::BuildShip(ShipClass::Colony))
+            .with_description("Colony ships are built at planets with 5000 settlers")
+            .with_reward(ResourceBundle { fuel: 100, ..Default::default() }),
+        Objective::new("Colonize a second planet", ObjectiveGoal::ControlPlanets(2))
+            .with_description("Send the colony ship to an unclaimed planet")
+            .with_reward(ResourceBundle { minerals: 200, energy: 200, ..Default::default() }),
+    ]
+}
+
+/// A faction's objectives and how far it got
+#[derive(Debug, Clone, Default)]
+pub struct ObjectiveTrack {
+    objectives: Vec<Objective>,
+    completed: usize,
+}
+
+impl ObjectiveTrack {
+    /// All objectives, in order
+    pub fn objectives(&self) -> &[Objective] {
+        &self.objectives
+    }
+
+    /// Number of objectives completed so far
+    pub fn completed(&self) -> usize {
+        self.completed
+    }
+
+    /// Objective being worked on, None once all are done
+    pub fn current(&self) -> Option<&Objective> {
+        self.objectives.get(self.completed)
+    }
+
+    /// True once every objective is completed
+    pub fn is_finished(&self) -> bool {
+        self.completed >= self.objectives.len()
+    }
+}
+
+/// Tracks each faction's objectives and announces completions
+pub struct ObjectiveSystem {
+    tracks: BTreeMap<FactionId, ObjectiveTrack>,
+    pending: Vec<SimulationEvent>,
+}
+
+impl ObjectiveSystem {
+    /// A system without objectives
+    pub fn new() -> Self {
+        Self {
+            tracks: BTreeMap::new(),
+            pending: Vec::new(),
+        }
+    }
+
+    /// Read and check an objective list
+    pub fn load_from_file(path: &Path) -> GameResult<Vec<Objective>> {
+        let json = fs::read_to_string(path)
+            .map_err(|e| GameError::Io(format!("Could not read objectives file {}: {}", path.display(), e)))?;
+        let objectives: Vec<Objective> = serde_json::from_str(&json)
+            .map_err(|e| GameError::corrupted(path, format!("Objectives deserialization failed: {}", e)))?;
+        Self::validate(&objectives)?;
+        Ok(objectives)
+    }
+
+    /// Check that every objective has a title and a reachable goal
+    pub fn validate(objectives: &[Objective]) -> GameResult<()> {
+        for (index, objective) in objectives.iter().enumerate() {
+            if objective.title.trim().is_empty() {
+                return Err(GameError::SaveError(format!("Objective {} has no title", index)));
+            }
+            objective.reward.validate_non_negative()?;
+            let reachable = match objective.goal {
+                ObjectiveGoal::Population(target) => target > 0,
+                ObjectiveGoal::ControlPlanets(count) => count > 0,
+                _ => true,
+            };
+            if !reachable {
+                return Err(GameError::SaveError(format!("Objective {} has a goal of zero", index)));
+            }
+        }
+        Ok(())
+    }
+
+    /// Give the faction these objectives, replacing any it had
+    pub fn start(&mut self, faction: FactionId, objectives: Vec<Objective>) {
+        self.tracks.insert(faction, ObjectiveTrack { objectives, completed: 0 });
+    }
+
+    /// The faction's objectives, if it was given any
+    pub fn track(&self, faction: FactionId) -> Option<&ObjectiveTrack> {
+        self.tracks.get(&faction)
+    }
+
+    /// Factions still working through their objectives, by id
+    pub fn active_factions(&self) -> Vec<FactionId> {
+        self.tracks.iter()
+            .filter(|(_, track)| !track.is_finished())
+            .map(|(faction, _)| *faction)
+            .collect()
+    }
+
+    /// The faction finished a building; completes a matching current
+    /// objective and returns it
+    pub fn record_building(&mut self, faction: FactionId, building: BuildingType) -> Option<Objective> {
+        self.complete_if(faction, |goal| *goal == ObjectiveGoal::Build(building))
+    }
+
+    /// The faction launched a ship; completes a matching current objective
+    /// and returns it
+    pub fn record_ship(&mut self, faction: FactionId, ship_class: ShipClass) -> Option<Objective> {
+        self.complete_if(faction, |goal| *goal == ObjectiveGoal::BuildShip(ship_class))
+    }
+
+    /// Measure the faction's state goals; completes the current objective,
+    /// and those after it, for as long as they are met. Returns the
+    /// objectives completed, in order.
+    pub fn check(&mut self, standing: &FactionStanding, technologies: &BTreeSet<Technology>) -> Vec<Objective> {
+        let mut completed = Vec::new();
+        while let Some(objective) = self.complete_if(standing.faction, |goal| match goal {
+            ObjectiveGoal::Population(target) => standing.population >= *target,
+            ObjectiveGoal::ControlPlanets(count) => standing.planets >= *count,
+            ObjectiveGoal::Research(technology) => technologies.contains(technology),
+            ObjectiveGoal::Build(_) | ObjectiveGoal::BuildShip(_) => false,
+        }) {
+            completed.push(objective);
+        }
+        completed
+    }
+
+    fn complete_if(&mut self, faction: FactionId, met: impl Fn(&ObjectiveGoal) -> bool) -> Option<Objective> {
+        let track = self.tracks.get_mut(&faction)?;
+        let objective = track.current().filter(|objective| met(&objective.goal))?.clone();
+        self.pending.push(SimulationEvent::ObjectiveCompleted {
+            faction,
+            objective: track.completed,
+            reward: objective.reward,
+        });
+        track.completed += 1;
+        Some(objective)
+    }
+
+    /// Complete objectives met by a finished building or ship, or by the
+    /// factions' standing at the end of a tick. Returns each faction with
+    /// the objectives it completed, whose rewards are due.
+    pub fn record_event(&mut self, event: &SimulationEvent, planets: &PlanetManager, ships: &ShipManager, factions: &FactionManager) -> GameResult<Vec<(FactionId, Objective)>> {
+        Ok(match event {
+            SimulationEvent::ConstructionCompleted { planet, building } => {
+                match planets.get_planet(*planet).ok().and_then(|p| p.controller) {
+                    Some(owner) => self.record_building(owner, *building).map(|objective| (owner, objective)).into_iter().collect(),
+                    None => Vec::new(),
+                }
+            }
+            SimulationEvent::ShipCompleted { ship, .. } => match ships.get_ship(*ship) {
+                Ok(ship) => {
+                    let owner = ship.owner;
+                    self.record_ship(owner, ship.ship_class).map(|objective| (owner, objective)).into_iter().collect()
+                }
+                Err(_) => Vec::new(),
+            },
+            SimulationEvent::TickCompleted(_) => {
+                let active = self.active_factions();
+                if active.is_empty() {
+                    return Ok(Vec::new());
+                }
+                let mut completed = Vec::new();
+                for standing in FactionStanding::measure(planets, ships, factions).iter().filter(|standing| active.contains(&standing.faction)) {
+                    let technologies = &factions.get_faction(standing.faction)?.technologies;
+                    completed.extend(self.check(standing, technologies).into_iter().map(|objective| (standing.faction, objective)));
+                }
+                completed
+            }
+            _ => Vec::new(),
+        })
+    }
+
+    /// Announces the objectives completed since the last update
+    pub fn update(&mut self, _delta: f32, event_bus: &mut EventBus) -> GameResult<()> {
+        for event in self.pending.drain(..) {
+            event_bus.queue_event(GameEvent::SimulationEvent(event));
+        }
+        Ok(())
+    }
+
+    /// Progress is measured through `record_event`, which reads the managers
+    pub fn handle_event(&mut self, _event: &GameEvent) -> GameResult<()> {
+        Ok(())
+    }
+}
+
+impl Default for ObjectiveSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GameSystem for ObjectiveSystem {
+    fn update(&mut self, delta: f32, context: &mut SystemContext<'_>) -> GameResult<()> {
+        self.update(delta, context.event_bus())
+    }
+
+    fn handle_event(&mut self, event: &GameEvent) -> GameResult<()> {
+        self.handle_event(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_objectives_complete_in_order() {
+        let mut system = ObjectiveSystem::new();
+        system.start(0, tutorial_objectives());
+        assert!(ObjectiveSystem::validate(&tutorial_objectives()).is_ok());
+
+        // Only the current objective counts
+        assert!(system.record_building(0, BuildingType::Farm).is_none());
+        assert!(system.record_building(1, BuildingType::Mine).is_none());
+        assert_eq!(system.record_building(0, BuildingType::Mine).unwrap().title, "Build a Mine");
+        assert_eq!(system.record_building(0, BuildingType::Farm).unwrap().title, "Build a Farm");
+
+        // State goals chain while they are met
+        let standing = FactionStanding { faction: 0, planets: 2, population: 6000, resources: ResourceBundle::default(), ships: 0 };
+        let completed = system.check(&standing, &BTreeSet::new());
+        assert_eq!(completed.len(), 1);
+        assert_eq!(system.track(0).unwrap().current().unwrap().goal, ObjectiveGoal::BuildShip(ShipClass::Colony));
+        system.record_ship(0, ShipClass::Colony).unwrap();
+        assert_eq!(system.check(&standing, &BTreeSet::new()).len(), 1);
+        assert!(system.track(0).unwrap().is_finished());
+        assert!(system.active_factions().is_empty());
+
+        let mut events = EventBus::new();
+        system.update(0.1, &mut events).unwrap();
+        assert_eq!(events.take_batch().len(), 5);
+
+        let untitled = vec![Objective::new(" ", ObjectiveGoal::Population(10))];
+        assert!(ObjectiveSystem::validate(&untitled).is_err());
+    }
+}
@@ -0,0 +1,1160 @@
+// src/systems/physics_engine.rs
+use crate::core::{GameResult, GameEvent, EventBus, GameSystem, SystemContext, StarMap};
+use crate::core::types::*;
+use crate::core::events::*;
+use crate::managers::{PlanetManager, ShipManager};
+use super::spatial_index::{SpatialEntity, SpatialIndex};
+use super::supply::SupplyStatus;
+use std::collections::HashMap;
+
+pub use crate::config::SHIP_BASE_SPEED;
+/// Points placed around each avoided gravity well when routing
+const ROUTE_POINTS_PER_WELL: usize = 8;
+/// Distance of the routing points from a well's center, as a multiple of its radius.
+/// Must exceed 1 / cos(PI / ROUTE_POINTS_PER_WELL) so legs between them clear the well.
+const ROUTE_POINT_MARGIN: f32 = 1.2;
+
+/// Longest stretch of ticks searched for transfer windows in one call
+pub const MAX_TRANSFER_SEARCH_TICKS: u64 = 10_000;
+
+/// A departure tick for a transfer between two orbits and what the trip costs
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TransferWindow {
+    /// Tick the ship leaves
+    pub departure_tick: u64,
+    /// Tick the ship arrives
+    pub arrival_tick: u64,
+    /// Straight-line distance flown; fuel burned scales with it
+    pub distance: f32,
+}
+
+/// Path from a ship's position to its destination
+#[derive(Debug, Clone, PartialEq)]
+pub struct Route {
+    /// Points to pass, in order, before the destination
+    pub waypoints: Vec<Vector2>,
+    /// Total length of the path flown, leaving out jump lanes
+    pub distance: f32,
+    /// Jump lanes crossed on the way, each entered at one of the waypoints
+    pub jumps: Vec<LaneJump>,
+    /// Fuel needed for the jump lanes, on top of what the distance flown burns
+    pub lane_fuel: f32,
+}
+
+/// PhysicsEngine handles orbital mechanics, ship trajectories, and spatial physics
+/// in the game world. It operates on a deterministic fixed timestep system.
+/// 
+/// Key responsibilities:
+/// - Calculate orbital positions for all planets based on their OrbitalElements
+/// - Manage ship trajectories and detect arrivals
+/// - Calculate transfer windows between planets for optimal ship movement
+/// - Provide spatial position queries for rendering and game logic, through
+///   a [`SpatialIndex`] of where planets and ships were at the latest tick
+pub struct PhysicsEngine {
+    /// Cache of calculated orbital positions to avoid redundant calculations
+    orbital_cache: HashMap<PlanetId, Vector2>,
+    /// Active transfer windows with their expiration times
+    transfer_windows: HashMap<(PlanetId, PlanetId), u64>,
+    /// Ship trajectories indexed by ship ID
+    trajectories: HashMap<ShipId, Trajectory>,
+    /// Current simulation tick for deterministic calculations
+    current_tick: u64,
+    /// Flag to defer event emission until update() phase
+    needs_tick_processing: bool,
+    /// Cached distances between planets for performance
+    #[allow(dead_code)]
+    planet_distances: HashMap<(PlanetId, PlanetId), f32>,
+    /// Maximum number of planets to avoid unbounded iterations
+    max_planets: u32,
+    /// Planet and ship positions for proximity queries, rebuilt by GameState
+    spatial_index: SpatialIndex,
+    /// Star systems the planets orbit and the jump lanes between them
+    star_map: StarMap,
+}
+
+impl PhysicsEngine {
+    /// Creates a new PhysicsEngine with default configuration.
+    /// 
+    /// Initializes all internal data structures and sets safe defaults
+    /// for orbital calculations and trajectory management.
+    pub fn new() -> Self {
+        Self {
+            orbital_cache: HashMap::with_capacity(64), // Pre-allocate for performance
+            transfer_windows: HashMap::with_capacity(32),
+            trajectories: HashMap::with_capacity(128),
+            current_tick: 0,
+            needs_tick_processing: false,
+            planet_distances: HashMap::with_capacity(64),
+            max_planets: 100, // Safety limit to prevent unbounded operations
+            spatial_index: SpatialIndex::default(),
+            star_map: StarMap::single(),
+        }
+    }
+    
+    /// Updates the physics engine state and processes any pending calculations.
+    /// 
+    /// This method should be called once per frame to handle deferred event emission
+    /// and maintain physics state consistency.
+    /// 
+    /// # Arguments
+    /// * `_delta` - Time elapsed since last update (unused in fixed timestep system)
+    /// * `event_bus` - EventBus for emitting physics-related events
+    pub fn update(&mut self, _delta: f32, event_bus: &mut EventBus) -> GameResult<()> {
+        // Process any pending tick processing
+        if self.needs_tick_processing {
+            self.process_tick(event_bus)?;
+            self.needs_tick_processing = false;
+        }
+        Ok(())
+    }
+    
+    /// Handles incoming game events and updates physics state accordingly.
+    /// 
+    /// Processes tick events, ship movement commands, and trajectory completions
+    /// to maintain accurate physics simulation state.
+    /// 
+    /// # Arguments
+    /// * `event` - The game event to process
+    pub fn handle_event(&mut self, event: &GameEvent) -> GameResult<()> {
+        match event {
+            GameEvent::SimulationEvent(SimulationEvent::TickCompleted(tick)) => {
+                // Validate tick progression to ensure deterministic behavior
+                if *tick < self.current_tick {
+                    return Err(GameError::InvalidOperation(
+                        format!("Tick went backwards: {} < {}", tick, self.current_tick)
+                    ));
+                }
+                self.current_tick = *tick;
+                // Store that we need to process a tick, but emit events in update()
+                self.needs_tick_processing = true;
+            }
+            GameEvent::PlayerCommand(PlayerCommand::MoveShip { ship, target }) => {
+                self.plan_trajectory(*ship, *target)?;
+            }
+            GameEvent::SimulationEvent(SimulationEvent::ShipArrived { ship, destination: _ }) => {
+                // Clean up completed trajectory when ship arrives
+                self.trajectories.remove(ship);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+    
+    fn process_tick(&mut self, event_bus: &mut EventBus) -> GameResult<()> {
+        // Update orbital positions based on current tick
+        self.update_orbital_positions(event_bus)?;
+        
+        // Update ship trajectories and check arrivals
+        self.update_ship_trajectories(event_bus)?;
+        
+        // Check for transfer windows
+        self.check_transfer_windows(event_bus)?;
+        
+        Ok(())
+    }
+    
+    fn update_orbital_positions(&mut self, event_bus: &mut EventBus) -> GameResult<()> {
+        // Clear previous cache to ensure fresh calculations
+        self.orbital_cache.clear();
+        
+        // Note: In actual implementation, we'd get planet data from PlanetManager
+        // For now this calculates orbital positions for demonstration planets
+        // Each planet follows: position = (radius * cos(phase + time/period), radius * sin(phase + time/period))
+        
+        // TODO: Get actual planet count from PlanetManager via events  
+        // For now, only process planets if they actually exist to avoid crashes
+        // Check with PlanetManager how many planets exist and only process those
+        let planet_limit = 0; // Will be set below based on actual planet count
+        for planet_id in 0..planet_limit {
+            
+            let orbital_elements = self.get_demo_orbital_elements(planet_id);
+            let position = self.calculate_orbital_position(&orbital_elements, self.current_tick);
+            
+            self.orbital_cache.insert(planet_id, position);
+            
+            // Emit position update event for other systems
+            event_bus.queue_event(GameEvent::StateChanged(
+                StateChange::PlanetUpdated(planet_id)
+            ));
+        }
+        
+        Ok(())
+    }
+    
+    /// Get demonstration orbital elements for a planet ID
+    /// In production, this would come from PlanetManager
+    fn get_demo_orbital_elements(&self, planet_id: PlanetId) -> OrbitalElements {
+        match planet_id {
+            0 => OrbitalElements { semi_major_axis: 1.0, period: 100.0, phase: 0.0, eccentricity: 0.0, argument_of_periapsis: 0.0 },
+            1 => OrbitalElements { semi_major_axis: 1.5, period: 150.0, phase: 0.5, eccentricity: 0.0, argument_of_periapsis: 0.0 },
+            2 => OrbitalElements { semi_major_axis: 2.0, period: 200.0, phase: 1.0, eccentricity: 0.0, argument_of_periapsis: 0.0 },
+            3 => OrbitalElements { semi_major_axis: 2.5, period: 300.0, phase: 1.5, eccentricity: 0.0, argument_of_periapsis: 0.0 },
+            4 => OrbitalElements { semi_major_axis: 3.0, period: 400.0, phase: 2.0, eccentricity: 0.0, argument_of_periapsis: 0.0 },
+            _ => OrbitalElements::default(),
+        }
+    }
+    
+    /// Calculates the orbital position of a celestial body at a given time.
+    /// 
+    /// Follows the body's elliptical orbit (see [`OrbitalElements::position_at`])
+    /// based on its orbital elements and the current simulation tick.
+    /// 
+    /// # Arguments
+    /// * `orbital_elements` - The orbital parameters of the body
+    /// * `tick` - Current simulation tick
+    /// 
+    /// # Returns
+    /// The calculated position vector, or origin for invalid orbital elements
+    pub fn calculate_orbital_position(&self, orbital_elements: &OrbitalElements, tick: u64) -> Vector2 {
+        // Return origin for invalid elements, such as a zero period or an open orbit
+        if orbital_elements.validate().is_err() {
+            return Vector2::default();
+        }
+        
+        orbital_elements.position_at(tick)
+    }
+    
+    /// Position of a planet in the galaxy at the given tick: its orbital
+    /// position around the star of its system
+    pub fn planet_position(&self, planet: &Planet, tick: u64) -> Vector2 {
+        let star = self.star_map.star_position(planet.system);
+        let orbit = self.calculate_orbital_position(&planet.position, tick);
+        Vector2::new(star.x + orbit.x, star.y + orbit.y)
+    }
+    
+    /// Every planet's position at the given tick, in planet order
+    pub fn planet_positions(&self, planets: &[Planet], tick: u64) -> Vec<(PlanetId, Vector2)> {
+        planets.iter().map(|planet| (planet.id, self.planet_position(planet, tick))).collect()
+    }
+    
+    /// Position of the planet nearest the ship that its faction holds, to
+    /// fall back on after breaking off from battle or when recalled. None
+    /// for a ship already lost or a faction without planets.
+    pub fn nearest_haven(&self, ship: ShipId, ships: &ShipManager, planets: &PlanetManager, tick: u64) -> Option<Vector2> {
+        let ship = ships.get_ship(ship).ok()?;
+        planets.get_all_planets().iter()
+            .filter(|planet| planet.controller == Some(ship.owner))
+            .map(|planet| self.planet_position(planet, tick))
+            .min_by(|a, b| ship.position.distance_to(a).total_cmp(&ship.position.distance_to(b)))
+    }
+    
+    /// Route a ship to `target` around the gravity wells of planets held by
+    /// factions its owner is at war with, through the jump lanes to the
+    /// target's star system, and work out the fuel for it at the ship's
+    /// `supply`; out of supply, every move needs more fuel as effectiveness
+    /// drops, which is added to the route's lane fuel.
+    ///
+    /// Returns None when the ship is already there or cannot go: a ship
+    /// without the fuel stays put and `InsufficientFuel` is announced, and
+    /// when no lanes lead to the target's system the order is rejected.
+    pub fn route_ship(&self, ship_id: ShipId, target: Vector2, supply: SupplyStatus, context: &mut SystemContext<'_>) -> GameResult<Option<Route>> {
+        if !target.x.is_finite() || !target.y.is_finite() {
+            return Err(GameError::InvalidOperation("Target position must have finite coordinates".into()));
+        }
+        let tick = context.tick();
+        let ship = context.ships().get_ship(ship_id)?;
+        let owner = context.factions().get_faction(ship.owner).ok();
+        let hazards: Vec<Vector2> = context.planets().get_all_planets().iter()
+            .filter(|planet| planet.controller.zip(owner).is_some_and(|(controller, owner)| owner.is_at_war_with(controller)))
+            .map(|planet| self.planet_position(planet, tick))
+            .collect();
+        let Some(mut route) = self.plan_lane_route(ship.position, target, &hazards) else {
+            let (from, to) = (self.star_map.system_at(ship.position), self.star_map.system_at(target));
+            context.queue_event(GameEvent::StateChanged(StateChange::CommandRejected {
+                command: PlayerCommand::MoveShip { ship: ship_id, target },
+                reason: GameError::InvalidTarget(format!("No jump lanes lead from star system {} to {}", from, to)).to_string(),
+            }));
+            return Ok(None);
+        };
+        
+        // Already at the destination
+        if route.distance < 0.1 && route.jumps.is_empty() {
+            return Ok(None);
+        }
+        let base_fuel = context.ships().fuel_cost(ship, route.distance) + route.lane_fuel;
+        let surcharge = base_fuel * (100.0 / supply.effectiveness() as f32 - 1.0);
+        let required = base_fuel + surcharge;
+        if ship.fuel < required {
+            let available = ship.fuel;
+            context.queue_event(GameEvent::SimulationEvent(
+                SimulationEvent::InsufficientFuel { ship: ship_id, required, available }
+            ));
+            return Ok(None);
+        }
+        route.lane_fuel += surcharge;
+        Ok(Some(route))
+    }
+    
+    /// Use a new galaxy of star systems and jump lanes
+    pub fn set_star_map(&mut self, star_map: StarMap) {
+        self.star_map = star_map;
+    }
+    
+    /// Star systems and jump lanes of the galaxy
+    pub fn star_map(&self) -> &StarMap {
+        &self.star_map
+    }
+    
+    fn update_ship_trajectories(&mut self, event_bus: &mut EventBus) -> GameResult<()> {
+        let mut completed_trajectories = Vec::new();
+        
+        for (ship_id, trajectory) in &self.trajectories {
+            if self.current_tick >= trajectory.arrival_time {
+                // Ship has arrived
+                event_bus.queue_event(GameEvent::SimulationEvent(
+                    SimulationEvent::ShipArrived {
+                        ship: *ship_id,
+                        destination: trajectory.destination,
+                    }
+                ));
+                completed_trajectories.push(*ship_id);
+            }
+        }
+        
+        // Remove completed trajectories
+        for ship_id in completed_trajectories {
+            self.trajectories.remove(&ship_id);
+        }
+        
+        Ok(())
+    }
+    
+    /// Get the current interpolated position of a ship
+    /// Returns the ship's position along its trajectory, or base_position if not moving
+    pub fn get_ship_position(&self, ship_id: ShipId, base_position: Vector2) -> Vector2 {
+        if let Some(trajectory) = self.trajectories.get(&ship_id) {
+            self.interpolate_trajectory_position(trajectory, self.current_tick)
+        } else {
+            base_position
+        }
+    }
+    
+    /// Advance a ship by one tick: move it along its trajectory, keep orbiting
+    /// ships on their parking orbit, and capture ships that come to rest within
+    /// [`ORBIT_CAPTURE_RANGE`] of a planet.
+    /// 
+    /// # Arguments
+    /// * `ship` - The ship to advance
+    /// * `planet_positions` - Positions of all planets at `tick`
+    /// * `tick` - The tick being simulated
+    pub fn advance_ship(&self, ship: &Ship, planet_positions: &[(PlanetId, Vector2)], tick: u64) -> ShipMotion {
+        let mut motion = ShipMotion {
+            position: ship.position,
+            status: ship.status,
+            orbit_phase: ship.orbit_phase,
+            trajectory: ship.trajectory.clone(),
+            entered_orbit: None,
+        };
+        
+        if let Some(trajectory) = &ship.trajectory {
+            let entered = |jump: &&LaneJump| jump.entry == ship.position && trajectory.waypoints.first() != Some(&jump.entry);
+            if let Some(jump) = trajectory.jumps.first().filter(entered) {
+                // In a jump lane; the ship comes out at the far jump point once its time is up
+                if let Some(trajectory) = &mut motion.trajectory {
+                    if jump.ticks > 1 {
+                        trajectory.jumps[0].ticks -= 1;
+                    } else {
+                        motion.position = jump.exit;
+                        trajectory.jumps.remove(0);
+                    }
+                }
+                motion.status = ShipStatus::InTransit;
+                return motion;
+            }
+            let next = trajectory.next_point();
+            let remaining = self.calculate_distance(ship.position, next);
+            let speed = ship.speed();
+            if remaining > speed {
+                let progress = speed / remaining;
+                motion.position = Vector2 {
+                    x: ship.position.x + (next.x - ship.position.x) * progress,
+                    y: ship.position.y + (next.y - ship.position.y) * progress,
+                };
+                motion.status = ShipStatus::InTransit;
+                return motion;
+            }
+            if !trajectory.waypoints.is_empty() {
+                // Reached a waypoint; the next leg starts on the following tick
+                motion.position = next;
+                if let Some(trajectory) = &mut motion.trajectory {
+                    trajectory.waypoints.remove(0);
+                }
+                motion.status = ShipStatus::InTransit;
+                return motion;
+            }
+            // Arrived this tick; fall through to orbit capture
+            motion.position = trajectory.destination;
+            motion.trajectory = None;
+            motion.status = ShipStatus::Idle;
+        } else if let ShipStatus::Orbiting(planet_id) = ship.status {
+            match planet_positions.iter().find(|(id, _)| *id == planet_id) {
+                Some((_, center)) => {
+                    motion.position = Self::parking_orbit_position(*center, ship.orbit_phase, tick);
+                }
+                None => motion.status = ShipStatus::Idle, // Planet no longer exists
+            }
+            return motion;
+        }
+        
+        // Capture into orbit around the nearest planet within range
+        let nearest = planet_positions.iter()
+            .map(|(id, position)| (*id, *position, self.calculate_distance(motion.position, *position)))
+            .filter(|(_, _, distance)| *distance <= ORBIT_CAPTURE_RANGE)
+            .min_by(|a, b| a.2.total_cmp(&b.2).then(a.0.cmp(&b.0)));
+        
+        if let Some((planet_id, center, _)) = nearest {
+            // Enter orbit at the ship's current bearing from the planet
+            let bearing = (motion.position.y - center.y).atan2(motion.position.x - center.x);
+            motion.orbit_phase = bearing - Self::parking_orbit_angle(tick);
+            motion.position = Self::parking_orbit_position(center, motion.orbit_phase, tick);
+            motion.status = ShipStatus::Orbiting(planet_id);
+            motion.entered_orbit = Some(planet_id);
+        }
+        
+        motion
+    }
+    
+    /// Shortest path from `from` to `to` that stays out of the gravity wells
+    /// centered on `hazards`.
+    /// 
+    /// Wells containing either end of the trip are ignored, so ships can still
+    /// leave or approach a hostile planet. Detours run through points spaced
+    /// around each well; when no detour exists the route is the straight line.
+    /// 
+    /// # Arguments
+    /// * `from` - Where the ship starts
+    /// * `to` - The ship's destination
+    /// * `hazards` - Centers of the gravity wells to avoid
+    pub fn plan_route(&self, from: Vector2, to: Vector2, hazards: &[Vector2]) -> Route {
+        let wells: Vec<Vector2> = hazards.iter()
+            .copied()
+            .filter(|center| self.calculate_distance(from, *center) > GRAVITY_WELL_RADIUS
+                && self.calculate_distance(to, *center) > GRAVITY_WELL_RADIUS)
+            .collect();
+        let direct = Route { waypoints: Vec::new(), distance: self.calculate_distance(from, to), jumps: Vec::new(), lane_fuel: 0.0 };
+        if self.is_leg_clear(from, to, &wells) {
+            return direct;
+        }
+        
+        // Visibility graph: start, destination, then the routing points around each well
+        let mut nodes = vec![from, to];
+        for center in &wells {
+            for i in 0..ROUTE_POINTS_PER_WELL {
+                let angle = 2.0 * std::f32::consts::PI * i as f32 / ROUTE_POINTS_PER_WELL as f32;
+                let point = Vector2 {
+                    x: center.x + GRAVITY_WELL_RADIUS * ROUTE_POINT_MARGIN * angle.cos(),
+                    y: center.y + GRAVITY_WELL_RADIUS * ROUTE_POINT_MARGIN * angle.sin(),
+                };
+                if wells.iter().all(|other| self.calculate_distance(point, *other) > GRAVITY_WELL_RADIUS) {
+                    nodes.push(point);
+                }
+            }
+        }
+        
+        // Dijkstra from the start; lower node indices win ties so routes are deterministic
+        let mut distance = vec![f32::INFINITY; nodes.len()];
+        let mut previous: Vec<Option<usize>> = vec![None; nodes.len()];
+        let mut visited = vec![false; nodes.len()];
+        distance[0] = 0.0;
+        while let Some(current) = (0..nodes.len())
+            .filter(|i| !visited[*i] && distance[*i].is_finite())
+            .min_by(|a, b| distance[*a].total_cmp(&distance[*b]).then(a.cmp(b)))
+        {
+            if current == 1 {
+                break;
+            }
+            visited[current] = true;
+            for next in 0..nodes.len() {
+                if visited[next] || !self.is_leg_clear(nodes[current], nodes[next], &wells) {
+                    continue;
+                }
+                let candidate = distance[current] + self.calculate_distance(nodes[current], nodes[next]);
+                if candidate < distance[next] {
+                    distance[next] = candidate;
+                    previous[next] = Some(current);
+                }
+            }
+        }
+        if !distance[1].is_finite() {
+            return direct;
+        }
+        
+        let mut waypoints = Vec::new();
+        let mut node = previous[1];
+        while let Some(index) = node.filter(|index| *index != 0) {
+            waypoints.push(nodes[index]);
+            node = previous[index];
+        }
+        waypoints.reverse();
+        Route { waypoints, distance: distance[1], jumps: Vec::new(), lane_fuel: 0.0 }
+    }
+    
+    /// Path from `from` to `to` through the jump lanes between their star
+    /// systems, flying around the gravity wells of `hazards` within each
+    /// system as [`PhysicsEngine::plan_route`] does.
+    /// 
+    /// Each system is the one whose star is nearest. Ships fly to the jump
+    /// point of each lane in turn, which becomes one of the route's waypoints,
+    /// and continue from the far one. Returns None when no lanes lead to the
+    /// destination's system.
+    pub fn plan_lane_route(&self, from: Vector2, to: Vector2, hazards: &[Vector2]) -> Option<Route> {
+        let lanes = self.star_map.lane_path(self.star_map.system_at(from), self.star_map.system_at(to))?;
+        let mut route = Route { waypoints: Vec::new(), distance: 0.0, jumps: Vec::new(), lane_fuel: 0.0 };
+        let mut position = from;
+        for lane in lanes {
+            let entry = self.star_map.jump_point(lane.from, lane.to);
+            let exit = self.star_map.jump_point(lane.to, lane.from);
+            let leg = self.plan_route(position, entry, hazards);
+            route.waypoints.extend(leg.waypoints);
+            route.waypoints.push(entry);
+            route.distance += leg.distance;
+            route.jumps.push(LaneJump { entry, exit, ticks: lane.travel_ticks });
+            route.lane_fuel += lane.fuel_cost;
+            position = exit;
+        }
+        let last = self.plan_route(position, to, hazards);
+        route.waypoints.extend(last.waypoints);
+        route.distance += last.distance;
+        Some(route)
+    }
+    
+    /// True when the straight leg between two points stays out of every well
+    fn is_leg_clear(&self, from: Vector2, to: Vector2, wells: &[Vector2]) -> bool {
+        let leg = Vector2 { x: to.x - from.x, y: to.y - from.y };
+        let length_squared = leg.x * leg.x + leg.y * leg.y;
+        wells.iter().all(|center| {
+            // Closest point of the leg to the well's center
+            let t = if length_squared > 0.0 {
+                (((center.x - from.x) * leg.x + (center.y - from.y) * leg.y) / length_squared).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            let closest = Vector2 { x: from.x + leg.x * t, y: from.y + leg.y * t };
+            self.calculate_distance(closest, *center) > GRAVITY_WELL_RADIUS
+        })
+    }
+    
+    /// Position on a parking orbit around `center` at the given tick
+    pub fn parking_orbit_position(center: Vector2, orbit_phase: f32, tick: u64) -> Vector2 {
+        let angle = orbit_phase + Self::parking_orbit_angle(tick);
+        Vector2 {
+            x: center.x + PARKING_ORBIT_RADIUS * angle.cos(),
+            y: center.y + PARKING_ORBIT_RADIUS * angle.sin(),
+        }
+    }
+    
+    fn parking_orbit_angle(tick: u64) -> f32 {
+        // Reduce the tick first so the angle stays precise in long games
+        let lap_progress = (tick % PARKING_ORBIT_PERIOD_TICKS) as f32 / PARKING_ORBIT_PERIOD_TICKS as f32;
+        2.0 * std::f32::consts::PI * lap_progress
+    }
+    
+    fn interpolate_trajectory_position(&self, trajectory: &Trajectory, current_tick: u64) -> Vector2 {
+        // Handle edge cases for trajectory timing
+        if current_tick <= trajectory.departure_time {
+            return trajectory.origin;
+        }
+        if current_tick >= trajectory.arrival_time {
+            return trajectory.destination;
+        }
+        
+        let total_time = trajectory.arrival_time - trajectory.departure_time;
+        if total_time == 0 {
+            // Instant travel edge case
+            return trajectory.destination;
+        }
+        
+        let elapsed_time = current_tick - trajectory.departure_time;
+        let progress = (elapsed_time as f32) / (total_time as f32);
+        
+        // Clamp progress to [0, 1] for safety
+        let progress = progress.max(0.0).min(1.0);
+        
+        // Linear interpolation between origin and destination
+        Vector2 {
+            x: trajectory.origin.x + (trajectory.destination.x - trajectory.origin.x) * progress,
+            y: trajectory.origin.y + (trajectory.destination.y - trajectory.origin.y) * progress,
+        }
+    }
+    
+    fn check_transfer_windows(&mut self, event_bus: &mut EventBus) -> GameResult<()> {
+        // Calculate transfer windows between planets based on orbital positions
+        // Transfer windows occur when planets are properly aligned for efficient travel
+        
+        // Clean up expired transfer windows first
+        self.transfer_windows.retain(|_, &mut expiry_tick| expiry_tick > self.current_tick);
+        
+        // Check for new transfer windows every 25 ticks for better responsiveness
+        if self.current_tick % 25 == 0 {
+            let max_planets = 2.min(self.max_planets); // Reduced back to 2 to fix load game issue
+            
+            for planet_a in 0..max_planets {
+                for planet_b in (planet_a + 1)..max_planets {
+                    // Check if this pair already has an active transfer window
+                    let pair = (planet_a, planet_b);
+                    if self.transfer_windows.contains_key(&pair) {
+                        continue;
+                    }
+                    
+                    // Calculate if planets are aligned for efficient transfer
+                    if self.are_planets_aligned_for_transfer(planet_a, planet_b) {
+                        let window_duration = 30; // 30 tick window
+                        self.transfer_windows.insert(pair, self.current_tick + window_duration);
+                        
+                        event_bus.queue_event(GameEvent::SimulationEvent(
+                            SimulationEvent::TransferWindowOpen {
+                                from: planet_a,
+                                to: planet_b,
+                            }
+                        ));
+                    }
+                }
+            }
+        }
+        
+        Ok(())
+    }
+    
+    /// Check if two planets are aligned for an efficient transfer window
+    fn are_planets_aligned_for_transfer(&self, planet_a: PlanetId, planet_b: PlanetId) -> bool {
+        // Get orbital positions from cache
+        let pos_a = self.orbital_cache.get(&planet_a);
+        let pos_b = self.orbital_cache.get(&planet_b);
+        
+        match (pos_a, pos_b) {
+            (Some(pos_a), Some(pos_b)) => {
+                // Calculate angular separation
+                let angle_a = pos_a.y.atan2(pos_a.x);
+                let angle_b = pos_b.y.atan2(pos_b.x);
+                let angular_diff = (angle_a - angle_b).abs();
+                
+                // Normalize to [0, PI]
+                let normalized_diff = if angular_diff > std::f32::consts::PI {
+                    2.0 * std::f32::consts::PI - angular_diff
+                } else {
+                    angular_diff
+                };
+                
+                // Transfer window occurs when planets are somewhat aligned (within 45 degrees)
+                normalized_diff < std::f32::consts::PI / 4.0
+            }
+            _ => false, // Can't determine alignment without positions
+        }
+    }
+    
+    fn plan_trajectory(&mut self, ship_id: ShipId, target: Vector2) -> GameResult<()> {
+        // Validate input parameters
+        if target.x.is_nan() || target.y.is_nan() || target.x.is_infinite() || target.y.is_infinite() {
+            return Err(GameError::InvalidTarget(
+                "Target position contains invalid coordinates".to_string()
+            ));
+        }
+        
+        // For now, using placeholder positions - in real implementation we'd get from ShipManager
+        let origin = Vector2 { x: 0.0, y: 0.0 }; // Would get from ship's current position
+        let distance = self.calculate_distance(origin, target);
+        
+        // Validate distance is reasonable
+        if distance <= 0.0 {
+            return Err(GameError::InvalidOperation(
+                "Cannot plan trajectory with zero distance".to_string()
+            ));
+        }
+        
+        if distance > 1000.0 { // Maximum reasonable travel distance
+            return Err(GameError::InvalidTarget(
+                "Target is too far away".to_string()
+            ));
+        }
+        
+        // Calculate travel time based on distance
+        let travel_time = (distance / SHIP_BASE_SPEED).ceil() as u64;
+        
+        // Ensure minimum travel time of 1 tick
+        let travel_time = travel_time.max(1);
+        
+        // Calculate fuel cost
+        let fuel_cost = self.calculate_fuel_cost(origin, target, ship_id);
+        
+        // Validate fuel cost is reasonable
+        if fuel_cost < 0.0 {
+            return Err(GameError::SystemError(
+                "Fuel cost calculation resulted in negative value".to_string()
+            ));
+        }
+        
+        let trajectory = Trajectory {
+            origin,
+            destination: target,
+            departure_time: self.current_tick,
+            arrival_time: self.current_tick + travel_time,
+            fuel_cost,
+            waypoints: Vec::new(),
+            jumps: Vec::new(),
+        };
+        
+        // Store the trajectory
+        self.trajectories.insert(ship_id, trajectory);
+        
+        Ok(())
+    }
+    
+    fn calculate_distance(&self, from: Vector2, to: Vector2) -> f32 {
+        let dx = to.x - from.x;
+        let dy = to.y - from.y;
+        (dx * dx + dy * dy).sqrt()
+    }
+    
+    fn calculate_fuel_cost(&self, from: Vector2, to: Vector2, _ship_id: ShipId) -> f32 {
+        let distance = self.calculate_distance(from, to);
+        let base_cost = distance / 100.0; // Base fuel consumption: 1 fuel per 100 units
+        
+        // Check if there's an active transfer window that reduces cost
+        let window_modifier = if self.is_transfer_window_active(from, to) {
+            0.5 // 50% fuel reduction during transfer window
+        } else {
+            1.0
+        };
+        
+        // Apply ship-specific modifiers (placeholder for now)
+        let ship_modifier = 1.0; // Would vary by ship class in full implementation
+        
+        // Ensure minimum fuel cost to prevent zero-cost travel
+        (base_cost * window_modifier * ship_modifier).max(0.1)
+    }
+    
+    fn is_transfer_window_active(&self, _from: Vector2, _to: Vector2) -> bool {
+        // Check if current positions are within a transfer window
+        // This would involve more complex orbital mechanics calculations
+        // For now, return false as placeholder
+        false
+    }
+    
+    /// Get the cached orbital position of a planet
+    /// Returns None if the planet position hasn't been calculated this tick
+    pub fn get_orbital_position(&self, planet_id: PlanetId) -> Option<Vector2> {
+        self.orbital_cache.get(&planet_id).copied()
+    }
+    
+    /// Index the positions of every planet and ship at `tick`, replacing
+    /// the previous ones
+    pub fn rebuild_spatial_index(&mut self, tick: u64, planets: &[(PlanetId, Vector2)], ships: &[(ShipId, Vector2)]) {
+        self.spatial_index.rebuild(tick, planets, ships);
+    }
+    
+    /// Planet and ship positions as of the last rebuild
+    pub fn spatial_index(&self) -> &SpatialIndex {
+        &self.spatial_index
+    }
+    
+    /// Planets and ships within `radius` of `position` at the last rebuild,
+    /// sorted by entity
+    pub fn entities_within(&self, position: Vector2, radius: f32) -> Vec<(SpatialEntity, Vector2)> {
+        self.spatial_index.entities_within(position, radius)
+    }
+    
+    /// Get all active ship trajectories (for debugging/UI)
+    pub fn get_active_trajectories(&self) -> &HashMap<ShipId, Trajectory> {
+        &self.trajectories
+    }
+    
+    /// Get the number of active transfer windows
+    pub fn get_transfer_window_count(&self) -> usize {
+        self.transfer_windows.len()
+    }
+    
+    /// Check if a specific transfer window is active
+    pub fn is_transfer_window_open(&self, from: PlanetId, to: PlanetId) -> bool {
+        let pair = (from.min(to), from.max(to)); // Normalize pair order
+        self.transfer_windows.get(&pair)
+            .map(|&expiry| expiry > self.current_tick)
+            .unwrap_or(false)
+    }
+    
+    /// Calculate estimated travel time between two points
+    pub fn estimate_travel_time(&self, from: Vector2, to: Vector2) -> u64 {
+        let distance = self.calculate_distance(from, to);
+        let base_speed = 10.0;
+        (distance / base_speed).ceil() as u64
+    }
+    
+    /// Ticks between repeats of the same alignment of two orbits, or None
+    /// when the periods match and the alignment never changes
+    pub fn synodic_period(&self, a: &OrbitalElements, b: &OrbitalElements) -> Option<f32> {
+        let beat = (1.0 / a.period - 1.0 / b.period).abs();
+        (beat > f32::EPSILON).then(|| 1.0 / beat)
+    }
+    
+    /// Cost of leaving `from` at `departure_tick` and flying straight to where
+    /// `to` will be on arrival
+    pub fn transfer_at(&self, from: &OrbitalElements, to: &OrbitalElements, departure_tick: u64) -> TransferWindow {
+        let origin = self.calculate_orbital_position(from, departure_tick);
+        // The target keeps moving during the flight; a few refinements settle the intercept
+        let mut travel_time = 1;
+        for _ in 0..3 {
+            let target = self.calculate_orbital_position(to, departure_tick + travel_time);
+            travel_time = self.estimate_travel_time(origin, target).max(1);
+        }
+        let distance = self.calculate_distance(origin, self.calculate_orbital_position(to, departure_tick + travel_time));
+        TransferWindow {
+            departure_tick,
+            arrival_tick: departure_tick + travel_time,
+            distance,
+        }
+    }
+    
+    /// Cheapest departures from one orbit to another within `horizon` ticks of `start_tick`.
+    /// 
+    /// A window is a departure tick whose trip is shorter than on the ticks
+    /// around it; departing at `start_tick` counts if it beats the next tick.
+    /// Windows come back cheapest first, earlier departures winning ties.
+    /// The horizon is capped at [`MAX_TRANSFER_SEARCH_TICKS`]; a
+    /// [`synodic_period`](Self::synodic_period) covers every alignment.
+    pub fn transfer_windows(&self, from: &OrbitalElements, to: &OrbitalElements, start_tick: u64, horizon: u64) -> Vec<TransferWindow> {
+        let transfers: Vec<TransferWindow> = (0..=horizon.min(MAX_TRANSFER_SEARCH_TICKS))
+            .map(|offset| self.transfer_at(from, to, start_tick + offset))
+            .collect();
+        let mut windows: Vec<TransferWindow> = transfers.iter()
+            .enumerate()
+            .filter(|(i, transfer)| {
+                let before = i.checked_sub(1).is_none_or(|j| transfer.distance <= transfers[j].distance);
+                let after = transfers.get(i + 1).map_or(*i == 0, |next| transfer.distance < next.distance);
+                before && after
+            })
+            .map(|(_, transfer)| *transfer)
+            .collect();
+        windows.sort_by(|a, b| a.distance.total_cmp(&b.distance).then(a.departure_tick.cmp(&b.departure_tick)));
+        windows
+    }
+    
+    /// Cheapest departure within `horizon` ticks of `start_tick`
+    pub fn cheapest_transfer_window(&self, from: &OrbitalElements, to: &OrbitalElements, start_tick: u64, horizon: u64) -> Option<TransferWindow> {
+        self.transfer_windows(from, to, start_tick, horizon).into_iter().next()
+    }
+}
+
+// Include unit tests
+
+impl GameSystem for PhysicsEngine {
+    fn update(&mut self, delta: f32, context: &mut SystemContext<'_>) -> GameResult<()> {
+        self.update(delta, context.event_bus())
+    }
+
+    fn handle_event(&mut self, event: &GameEvent) -> GameResult<()> {
+        self.handle_event(event)
+    }
+}
+
+#[cfg(test)]
+mod physics_unit_tests {
+    use super::{PhysicsEngine, SHIP_BASE_SPEED};
+    use crate::core::types::*;
+    use crate::core::events::*;
+
+    #[test]
+    fn test_new_physics_engine() {
+        let physics = PhysicsEngine::new();
+        assert_eq!(physics.get_transfer_window_count(), 0);
+        assert_eq!(physics.get_active_trajectories().len(), 0);
+    }
+
+    #[test]
+    fn test_orbital_calculation_with_validation() {
+        let physics = PhysicsEngine::new();
+        
+        // Test normal orbital elements
+        let normal_orbit = OrbitalElements {
+            semi_major_axis: 5.0,
+            period: 100.0,
+            phase: 0.0,
+            eccentricity: 0.0,
+            argument_of_periapsis: 0.0,
+        };
+        let pos = physics.calculate_orbital_position(&normal_orbit, 0);
+        assert!((pos.x - 5.0).abs() < 0.01);
+        assert!((pos.y - 0.0).abs() < 0.01);
+
+        // Test zero period (should return origin)
+        let zero_period_orbit = OrbitalElements {
+            semi_major_axis: 5.0,
+            period: 0.0,
+            phase: 0.0,
+            eccentricity: 0.0,
+            argument_of_periapsis: 0.0,
+        };
+        let pos_zero = physics.calculate_orbital_position(&zero_period_orbit, 50);
+        assert_eq!(pos_zero.x, 0.0);
+        assert_eq!(pos_zero.y, 0.0);
+
+        // Test negative radius (should return origin)
+        let negative_radius_orbit = OrbitalElements {
+            semi_major_axis: -3.0,
+            period: 100.0,
+            phase: 0.0,
+            eccentricity: 0.0,
+            argument_of_periapsis: 0.0,
+        };
+        let pos_negative = physics.calculate_orbital_position(&negative_radius_orbit, 50);
+        assert_eq!(pos_negative.x, 0.0);
+        assert_eq!(pos_negative.y, 0.0);
+    }
+
+    #[test]
+    fn test_error_handling_for_invalid_coordinates() {
+        let mut physics = PhysicsEngine::new();
+        
+        // Test NaN coordinates
+        let nan_target = Vector2 { x: f32::NAN, y: 50.0 };
+        let move_event = GameEvent::PlayerCommand(PlayerCommand::MoveShip {
+            ship: 123,
+            target: nan_target,
+        });
+        assert!(physics.handle_event(&move_event).is_err());
+
+        // Test infinite coordinates
+        let inf_target = Vector2 { x: f32::INFINITY, y: 50.0 };
+        let move_event2 = GameEvent::PlayerCommand(PlayerCommand::MoveShip {
+            ship: 456,
+            target: inf_target,
+        });
+        assert!(physics.handle_event(&move_event2).is_err());
+    }
+
+    #[test]
+    fn test_tick_progression_validation() {
+        let mut physics = PhysicsEngine::new();
+        
+        // Set initial tick
+        let tick1 = GameEvent::SimulationEvent(SimulationEvent::TickCompleted(100));
+        assert!(physics.handle_event(&tick1).is_ok());
+
+        // Backwards tick should be rejected
+        let backwards_tick = GameEvent::SimulationEvent(SimulationEvent::TickCompleted(50));
+        assert!(physics.handle_event(&backwards_tick).is_err());
+
+        // Forward tick should be accepted
+        let forward_tick = GameEvent::SimulationEvent(SimulationEvent::TickCompleted(150));
+        assert!(physics.handle_event(&forward_tick).is_ok());
+    }
+
+    #[test]
+    fn test_distance_validation_in_trajectory_planning() {
+        let mut physics = PhysicsEngine::new();
+        
+        // Test too far target
+        let far_target = Vector2 { x: 2000.0, y: 0.0 };
+        let move_event = GameEvent::PlayerCommand(PlayerCommand::MoveShip {
+            ship: 123,
+            target: far_target,
+        });
+        assert!(physics.handle_event(&move_event).is_err());
+
+        // Test same position (zero distance)
+        let same_target = Vector2 { x: 0.0, y: 0.0 };
+        let move_event2 = GameEvent::PlayerCommand(PlayerCommand::MoveShip {
+            ship: 456,
+            target: same_target,
+        });
+        assert!(physics.handle_event(&move_event2).is_err());
+
+        // Test valid distance
+        let valid_target = Vector2 { x: 100.0, y: 100.0 };
+        let move_event3 = GameEvent::PlayerCommand(PlayerCommand::MoveShip {
+            ship: 789,
+            target: valid_target,
+        });
+        assert!(physics.handle_event(&move_event3).is_ok());
+    }
+
+    #[test]
+    fn test_elliptical_orbits_swing_between_periapsis_and_apoapsis() {
+        let physics = PhysicsEngine::new();
+        let orbit = OrbitalElements {
+            semi_major_axis: 4.0,
+            period: 100.0,
+            phase: 0.0,
+            eccentricity: 0.5,
+            argument_of_periapsis: std::f32::consts::FRAC_PI_2,
+        };
+        
+        // Periapsis at tick 0, rotated onto +y; apoapsis half a period later
+        let periapsis = physics.calculate_orbital_position(&orbit, 0);
+        assert!(periapsis.x.abs() < 1e-4 && (periapsis.y - 2.0).abs() < 1e-4);
+        let apoapsis = physics.calculate_orbital_position(&orbit, 50);
+        assert!((apoapsis.magnitude() - 6.0).abs() < 1e-3);
+        
+        // The planet covers more of its orbit near periapsis
+        let angle = |tick| {
+            let position = physics.calculate_orbital_position(&orbit, tick);
+            position.y.atan2(position.x)
+        };
+        assert!((angle(5) - angle(0)).abs() > (angle(55) - angle(50)).abs());
+        
+        let open = OrbitalElements { eccentricity: 1.0, ..orbit };
+        assert!(open.validate().is_err());
+        assert_eq!(physics.calculate_orbital_position(&open, 10), Vector2::default());
+    }
+    
+    #[test]
+    fn test_transfer_windows_are_the_cheapest_departures() {
+        let physics = PhysicsEngine::new();
+        let inner = OrbitalElements { semi_major_axis: 2.0, period: 100.0, phase: 0.0, eccentricity: 0.1, argument_of_periapsis: 0.0 };
+        let outer = OrbitalElements { semi_major_axis: 6.0, period: 400.0, phase: 2.0, eccentricity: 0.0, argument_of_periapsis: 0.0 };
+        let synodic = physics.synodic_period(&inner, &outer).unwrap();
+        assert!((synodic - 400.0 / 3.0).abs() < 1e-2);
+        assert!(physics.synodic_period(&inner, &inner).is_none());
+        
+        let horizon = synodic.ceil() as u64;
+        let windows = physics.transfer_windows(&inner, &outer, 0, horizon);
+        assert!(!windows.is_empty());
+        assert!(windows.windows(2).all(|pair| pair[0].distance <= pair[1].distance));
+        let best = physics.cheapest_transfer_window(&inner, &outer, 0, horizon).unwrap();
+        assert_eq!(best, windows[0]);
+        assert!(best.arrival_tick > best.departure_tick);
+        for tick in 0..=horizon {
+            assert!(physics.transfer_at(&inner, &outer, tick).distance >= best.distance);
+        }
+        // Near alignment the trip is close to the gap between the orbits
+        assert!(best.distance < 4.5);
+    }
+    
+    #[test]
+    fn test_deterministic_orbital_calculations() {
+        let physics1 = PhysicsEngine::new();
+        let physics2 = PhysicsEngine::new();
+        
+        let orbital_elements = OrbitalElements {
+            semi_major_axis: 7.5,
+            period: 365.0,
+            phase: 1.57,
+            eccentricity: 0.0,
+            argument_of_periapsis: 0.0,
+        };
+        
+        // Same inputs should produce identical outputs
+        for &tick in &[0, 100, 1000, 10000] {
+            let pos1 = physics1.calculate_orbital_position(&orbital_elements, tick);
+            let pos2 = physics2.calculate_orbital_position(&orbital_elements, tick);
+            
+            assert!((pos1.x - pos2.x).abs() < f32::EPSILON);
+            assert!((pos1.y - pos2.y).abs() < f32::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_advance_ship_transit_capture_and_lost_planet() {
+        let physics = PhysicsEngine::new();
+        let mut ship = Ship {
+            id: 1,
+            ship_class: ShipClass::Scout,
+            position: Vector2 { x: 0.0, y: 0.0 },
+            trajectory: Some(Trajectory {
+                origin: Vector2 { x: 0.0, y: 0.0 },
+                destination: Vector2 { x: 25.0, y: 0.0 },
+                departure_time: 0,
+                arrival_time: 0,
+                fuel_cost: 0.1,
+                waypoints: Vec::new(),
+                jumps: Vec::new(),
+            }),
+            cargo: CargoHold::default(),
+            fuel: 100.0,
+            owner: 0,
+            status: ShipStatus::Idle,
+            orbit_phase: 0.0,
+            damage: 0,
+            design: None,
+            name: None,
+        };
+        let planets = [(7, Vector2 { x: 25.1, y: 0.0 })];
+        
+        // Still far away: moves one step at base speed
+        let motion = physics.advance_ship(&ship, &planets, 1);
+        assert_eq!(motion.status, ShipStatus::InTransit);
+        assert!((motion.position.x - SHIP_BASE_SPEED).abs() < 1e-4);
+        assert!(motion.entered_orbit.is_none());
+        
+        // Final step lands within capture range and enters orbit
+        ship.position = Vector2 { x: 20.0, y: 0.0 };
+        let motion = physics.advance_ship(&ship, &planets, 2);
+        assert_eq!(motion.status, ShipStatus::Orbiting(7));
+        assert_eq!(motion.entered_orbit, Some(7));
+        assert!(motion.trajectory.is_none());
+        assert!((motion.position.distance_to(&planets[0].1) - PARKING_ORBIT_RADIUS).abs() < 1e-4);
+        
+        // An orbiting ship whose planet disappeared drops back to idle
+        ship.trajectory = None;
+        ship.status = ShipStatus::Orbiting(3);
+        let motion = physics.advance_ship(&ship, &planets, 3);
+        assert_eq!(motion.status, ShipStatus::Idle);
+    }
+    
+    #[test]
+    fn test_plan_route_detours_around_hostile_wells() {
+        let physics = PhysicsEngine::new();
+        let from = Vector2 { x: -3.0, y: 0.0 };
+        let to = Vector2 { x: 3.0, y: 0.0 };
+        let well = Vector2 { x: 0.0, y: 0.0 };
+        
+        let direct = physics.plan_route(from, to, &[]);
+        assert!(direct.waypoints.is_empty());
+        assert!((direct.distance - 6.0).abs() < 1e-4);
+        
+        let detour = physics.plan_route(from, to, &[well]);
+        assert!(!detour.waypoints.is_empty());
+        assert!(detour.distance > direct.distance);
+        let mut points = vec![from];
+        points.extend(detour.waypoints.iter().copied());
+        points.push(to);
+        for leg in points.windows(2) {
+            // Sample each leg; none of it may enter the well
+            for step in 0..=20 {
+                let t = step as f32 / 20.0;
+                let point = Vector2 { x: leg[0].x + (leg[1].x - leg[0].x) * t, y: leg[0].y + (leg[1].y - leg[0].y) * t };
+                assert!(point.distance_to(&well) > GRAVITY_WELL_RADIUS);
+            }
+        }
+        assert_eq!(physics.plan_route(from, to, &[well]), detour, "Routing is deterministic");
+        
+        // A ship heading into the hostile well itself flies straight in
+        let attack = physics.plan_route(from, Vector2 { x: 0.2, y: 0.0 }, &[well]);
+        assert!(attack.waypoints.is_empty());
+    }
+    
+    #[test]
+    fn test_advance_ship_stops_at_each_waypoint() {
+        let physics = PhysicsEngine::new();
+        let ship = Ship {
+            id: 1,
+            ship_class: ShipClass::Scout,
+            position: Vector2 { x: 0.0, y: 0.0 },
+            trajectory: Some(Trajectory {
+                origin: Vector2 { x: 0.0, y: 0.0 },
+                destination: Vector2 { x: 2.0, y: 0.0 },
+                departure_time: 0,
+                arrival_time: 0,
+                fuel_cost: 0.1,
+                waypoints: vec![Vector2 { x: 1.0, y: 1.0 }],
+                jumps: Vec::new(),
+            }),
+            cargo: CargoHold::default(),
+            fuel: 100.0,
+            owner: 0,
+            status: ShipStatus::Idle,
+            orbit_phase: 0.0,
+            damage: 0,
+            design: None,
+            name: None,
+        };
+        
+        let motion = physics.advance_ship(&ship, &[], 1);
+        assert_eq!(motion.position, Vector2 { x: 1.0, y: 1.0 });
+        assert_eq!(motion.status, ShipStatus::InTransit);
+        assert!(motion.trajectory.as_ref().unwrap().waypoints.is_empty());
+        
+        let ship = Ship { position: motion.position, trajectory: motion.trajectory, status: motion.status, ..ship };
+        let motion = physics.advance_ship(&ship, &[], 2);
+        assert_eq!(motion.position, Vector2 { x: 2.0, y: 0.0 });
+        assert!(motion.trajectory.is_none());
+    }
+}
@@ -0,0 +1,294 @@
+// src/systems/pirates.rs
+//! Pirate raids
+//!
+//! Generated games include a "Pirates" faction of kind `FactionKind::Pirates`
+//! unless the configuration turns it off. It owns no planets and never
+//! scores; it is at war with every empire from the start. From
+//! [`PIRATE_FIRST_RAID_TICK`] on, every [`PIRATE_RAID_INTERVAL_TICKS`] a
+//! group of warships appears at the edge of the galaxy, growing by one ship
+//! every [`PIRATE_ESCALATION_TICKS`] up to [`MAX_PIRATE_RAID_SIZE`]. Each raid
+//! goes for the weakest of the empire planets nearest to where it appeared:
+//! the one with the fewest defenders, trade routes making it a richer prize.
+//! Raiders attack ships in range, transports first, and otherwise hold orbit
+//! over their target, which blockades it.
+//!
+//! Raid behavior does not depend on any AIPersonality. Like the AI, the
+//! [`PirateSystem`] works from an `AIWorldView` captured when orders are due
+//! and issues ordinary `PlayerCommand`s. ShipManager spawns the ships, since
+//! the system does not own ship data. Raid targets are not saved;
+//! raiders in a loaded game pick new ones.
+
+use crate::core::{GameResult, GameEvent, EventBus, GameSystem, SystemContext};
+use crate::core::types::*;
+use crate::core::events::*;
+use crate::core::{SimRng, RngStream};
+use crate::managers::PlanetManager;
+use super::ai_system::{AIWorldView, AIPlanetView, move_towards, is_at};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Name of the pirate faction in generated games
+pub const PIRATE_FACTION_NAME: &str = "Pirates";
+/// Tick of the first raid
+pub const PIRATE_FIRST_RAID_TICK: u64 = 1500;
+/// Ticks between raids
+pub const PIRATE_RAID_INTERVAL_TICKS: u64 = 1200;
+/// Ticks of game time after which raids bring one more ship
+pub const PIRATE_ESCALATION_TICKS: u64 = 6000;
+/// Most warships in one raid
+pub const MAX_PIRATE_RAID_SIZE: usize = 5;
+/// Most pirate ships in the galaxy at once; raids wait while it is reached
+pub const MAX_PIRATE_SHIPS: usize = 12;
+/// Ticks between raider orders
+pub const PIRATE_ORDERS_INTERVAL_TICKS: u64 = 10;
+/// Nearest empire planets a raid chooses its target from
+const TARGET_CANDIDATES: usize = 3;
+/// Raids appear this far beyond the outermost orbit
+const EDGE_MARGIN: f32 = 1.2;
+
+/// Warships in a raid launched at `tick`
+pub fn raid_size(tick: u64) -> usize {
+    (1 + (tick / PIRATE_ESCALATION_TICKS) as usize).min(MAX_PIRATE_RAID_SIZE)
+}
+
+/// True on the ticks a raid is launched
+pub fn is_raid_tick(tick: u64) -> bool {
+    tick >= PIRATE_FIRST_RAID_TICK && (tick - PIRATE_FIRST_RAID_TICK).is_multiple_of(PIRATE_RAID_INTERVAL_TICKS)
+}
+
+/// Point at the edge of a galaxy whose outermost orbit is `outer_orbit`,
+/// at `angle` radians
+pub fn galaxy_edge(outer_orbit: f32, angle: f32) -> Vector2 {
+    let radius = outer_orbit.max(1.0) * EDGE_MARGIN;
+    Vector2::new(radius * angle.cos(), radius * angle.sin())
+}
+
+/// Plans pirate raids and gives the raiders their orders
+pub struct PirateSystem {
+    /// The pirate faction, None in games without pirates
+    faction: Option<FactionId>,
+    /// Planet each raider is sent against
+    targets: BTreeMap<ShipId, PlanetId>,
+    pending: Vec<PlayerCommand>,
+}
+
+impl PirateSystem {
+    /// A system without a pirate faction
+    pub fn new() -> Self {
+        Self {
+            faction: None,
+            targets: BTreeMap::new(),
+            pending: Vec::new(),
+        }
+    }
+
+    /// The pirate faction, if the game has one
+    pub fn faction(&self) -> Option<FactionId> {
+        self.faction
+    }
+
+    /// Run raids for this faction, or for none; forgets earlier raid targets
+    pub fn set_faction(&mut self, faction: Option<FactionId>) {
+        self.faction = faction;
+        self.targets.clear();
+    }
+
+    /// Warships to spawn for a raid at `tick`, given the pirate ships already
+    /// out; None when no raid is due
+    pub fn raid_due(&self, tick: u64, pirate_ships: usize) -> Option<usize> {
+        self.faction?;
+        if !is_raid_tick(tick) || pirate_ships >= MAX_PIRATE_SHIPS {
+            return None;
+        }
+        Some(raid_size(tick).min(MAX_PIRATE_SHIPS - pirate_ships))
+    }
+
+    /// Random point on the edge of the galaxy the planets span, for a raid
+    /// to appear at
+    pub fn raid_origin(planets: &PlanetManager, rng: &mut SimRng) -> Vector2 {
+        let outer_orbit = planets.get_all_planets().iter()
+            .map(|planet| planet.position.semi_major_axis)
+            .fold(0.0, f32::max);
+        let angle = rng.next_f32(RngStream::Events) * std::f32::consts::TAU;
+        galaxy_edge(outer_orbit, angle)
+    }
+
+    /// True when the raiders, `pirate_ships` of them, are due new orders
+    pub fn orders_due(&self, tick: u64, pirate_ships: usize) -> bool {
+        tick.is_multiple_of(PIRATE_ORDERS_INTERVAL_TICKS) && pirate_ships > 0
+    }
+
+    /// The weakest of the empire planets nearest to `from`: fewest defending
+    /// warships and defense platforms, then most trade routes, then nearest
+    pub fn choose_target(&self, world: &AIWorldView, from: Vector2) -> Option<PlanetId> {
+        let pirates = self.faction?;
+        let mut candidates: Vec<&AIPlanetView> = world.planets.iter()
+            .filter(|view| view.planet.controller.is_some_and(|owner| owner != pirates))
+            .collect();
+        candidates.sort_by(|a, b| a.position.distance_to(&from).total_cmp(&b.position.distance_to(&from)));
+        candidates.into_iter()
+            .take(TARGET_CANDIDATES)
+            .enumerate()
+            .min_by_key(|(rank, view)| (defenses(world, view), std::cmp::Reverse(trade_routes(world, view.planet.id)), *rank))
+            .map(|(_, view)| view.planet.id)
+    }
+
+    /// Send newly spawned raiders against a planet
+    pub fn start_raid(&mut self, ships: &[ShipId], target: PlanetId) {
+        for ship in ships {
+            self.targets.insert(*ship, target);
+        }
+    }
+
+    /// Planet a raider is sent against
+    pub fn target_of(&self, ship: ShipId) -> Option<PlanetId> {
+        self.targets.get(&ship).copied()
+    }
+
+    /// Give every idle raider its orders: attack a ship in range, transports
+    /// first, or else close in on its target, picking a new one once the
+    /// old one is no longer an empire's
+    pub fn plan_orders(&mut self, world: &AIWorldView) {
+        let Some(pirates) = self.faction else {
+            return;
+        };
+        let raiders: Vec<&Ship> = world.ships.iter().filter(|ship| ship.owner == pirates).collect();
+        self.targets.retain(|ship, _| raiders.iter().any(|raider| raider.id == *ship));
+
+        let ships: BTreeMap<ShipId, &Ship> = world.ships.iter().map(|ship| (ship.id, ship)).collect();
+        let mut engaged: BTreeSet<ShipId> = world.ships_in_combat.clone();
+        for raider in raiders {
+            if raider.status == ShipStatus::InTransit || engaged.contains(&raider.id) {
+                continue;
+            }
+
+            let prey = world.in_engagement_range.get(&raider.id).into_iter().flatten()
+                .filter_map(|id| ships.get(id))
+                .filter(|ship| ship.owner != pirates && !engaged.contains(&ship.id))
+                .min_by_key(|ship| (ship.ship_class != ShipClass::Transport, ship.id));
+            if let Some(prey) = prey {
+                engaged.insert(raider.id);
+                engaged.insert(prey.id);
+                self.pending.push(PlayerCommand::AttackTarget { attacker: raider.id, target: prey.id });
+                continue;
+            }
+
+            let target = self.targets.get(&raider.id)
+                .and_then(|planet| world.planets.iter().find(|view| view.planet.id == *planet))
+                .filter(|view| view.planet.controller.is_some_and(|owner| owner != pirates))
+                .map(|view| view.planet.id)
+                .or_else(|| self.choose_target(world, raider.position));
+            let Some(target) = target else {
+                self.targets.remove(&raider.id);
+                continue;
+            };
+            self.targets.insert(raider.id, target);
+            let Some(view) = world.planets.iter().find(|view| view.planet.id == target) else {
+                continue;
+            };
+            if !is_at(raider, view) {
+                self.pending.extend(move_towards(raider, view));
+            }
+        }
+    }
+
+    /// Issues the raiders' orders planned since the last update
+    pub fn update(&mut self, _delta: f32, event_bus: &mut EventBus) -> GameResult<()> {
+        for command in self.pending.drain(..) {
+            event_bus.queue_event(GameEvent::PlayerCommand(command));
+        }
+        Ok(())
+    }
+
+    /// Raids are launched and planned once per tick, from the ship data
+    pub fn handle_event(&mut self, _event: &GameEvent) -> GameResult<()> {
+        Ok(())
+    }
+}
+
+/// Warships of the planet's owner in its orbit plus its defense platforms
+fn defenses(world: &AIWorldView, view: &AIPlanetView) -> usize {
+    let defenders = world.ships.iter()
+        .filter(|ship| ship.ship_class == ShipClass::Warship
+            && Some(ship.owner) == view.planet.controller
+            && ship.status == ShipStatus::Orbiting(view.planet.id))
+        .count();
+    let platforms = view.planet.developments.iter()
+        .filter(|building| building.building_type == BuildingType::DefensePlatform)
+        .count();
+    defenders + platforms
+}
+
+/// Trade routes starting or ending at the planet
+fn trade_routes(world: &AIWorldView, planet: PlanetId) -> usize {
+    world.trade_routes.iter().filter(|route| route.from == planet || route.to == planet).count()
+}
+
+impl Default for PirateSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GameSystem for PirateSystem {
+    fn update(&mut self, delta: f32, context: &mut SystemContext<'_>) -> GameResult<()> {
+        self.update(delta, context.event_bus())
+    }
+
+    fn handle_event(&mut self, event: &GameEvent) -> GameResult<()> {
+        self.handle_event(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::managers::{PlanetManager, ShipManager};
+
+    #[test]
+    fn test_raids_grow_and_go_for_the_weakest_nearby_planet() {
+        assert!(!is_raid_tick(PIRATE_FIRST_RAID_TICK - 1));
+        assert!(is_raid_tick(PIRATE_FIRST_RAID_TICK + PIRATE_RAID_INTERVAL_TICKS));
+        assert_eq!(raid_size(0), 1);
+        assert_eq!(raid_size(PIRATE_ESCALATION_TICKS * 2), 3);
+        assert_eq!(raid_size(u64::MAX), MAX_PIRATE_RAID_SIZE);
+
+        let mut pirates = PirateSystem::new();
+        assert!(pirates.raid_due(PIRATE_FIRST_RAID_TICK, 0).is_none(), "No raids without a pirate faction");
+        pirates.set_faction(Some(2));
+        assert_eq!(pirates.raid_due(PIRATE_FIRST_RAID_TICK, 0), Some(1));
+        assert!(pirates.raid_due(PIRATE_FIRST_RAID_TICK, MAX_PIRATE_SHIPS).is_none());
+
+        // Two empire planets near the edge; the nearer one has a defender in orbit
+        let mut planets = PlanetManager::new();
+        let guarded = planets.create_planet(OrbitalElements { semi_major_axis: 9.0, ..Default::default() }, Some(0)).unwrap();
+        let open = planets.create_planet(OrbitalElements { semi_major_axis: 7.0, ..Default::default() }, Some(1)).unwrap();
+        let mut ships = ShipManager::new();
+        let guard = ships.create_ship(ShipClass::Warship, Vector2::new(9.0, 0.0), 0).unwrap();
+        let raider = ships.create_ship(ShipClass::Warship, galaxy_edge(9.0, 0.0), 2).unwrap();
+        let mut all_ships = ships.get_all_ships().clone();
+        all_ships.iter_mut().filter(|ship| ship.id == guard).for_each(|ship| ship.status = ShipStatus::Orbiting(guarded));
+        let view = |id: PlanetId, x: f32| AIPlanetView {
+            planet: planets.get_planet(id).unwrap().clone(),
+            position: Vector2::new(x, 0.0),
+            free_slots: 0,
+            buildable_ships: Vec::new(),
+            buildable_buildings: Vec::new(),
+        };
+        let world = AIWorldView {
+            planets: vec![view(guarded, 9.0), view(open, 7.0)],
+            ships: all_ships,
+            ..Default::default()
+        };
+        assert_eq!(pirates.choose_target(&world, galaxy_edge(9.0, 0.0)), Some(open));
+
+        pirates.plan_orders(&world);
+        assert_eq!(pirates.target_of(raider), Some(open));
+        let mut bus = EventBus::new();
+        pirates.update(0.1, &mut bus).unwrap();
+        let orders = bus.take_batch();
+        assert!(matches!(
+            orders.as_slice(),
+            [GameEvent::PlayerCommand(PlayerCommand::MoveShip { ship, .. })] if *ship == raider
+        ));
+    }
+}
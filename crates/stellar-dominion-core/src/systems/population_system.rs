@@ -0,0 +1,509 @@
+// src/systems/population_system.rs
+//
+// Population System - Handles population growth, migration, and worker allocation
+// Follows EventBus architecture for all system communication
+use crate::core::{GameResult, GameEvent, EventBus, GameSystem, SystemContext};
+use crate::core::types::*;
+use crate::core::events::{SimulationEvent, PlayerCommand, StateChange};
+use crate::managers::{PlanetManager, FactionManager};
+use std::collections::HashMap;
+
+/// Fraction of a blockaded planet's population lost per growth update
+pub const BLOCKADE_DECLINE_RATE: f32 = 0.01;
+/// Happiness gained from a food surplus large enough for growth
+pub const FOOD_SURPLUS_HAPPINESS: f32 = 0.1;
+/// Happiness lost while the planet cannot feed its population
+pub const FOOD_SHORTAGE_HAPPINESS: f32 = -0.4;
+/// Happiness gained when at least [`SPACIOUS_HOUSING_FRACTION`] of the housing is free
+pub const SPACIOUS_HOUSING_HAPPINESS: f32 = 0.1;
+/// Fraction of housing that must stand empty for a planet to feel spacious
+pub const SPACIOUS_HOUSING_FRACTION: f32 = 0.25;
+/// Happiness lost per unit of overcrowding (fraction of population above housing)
+pub const OVERCROWDING_HAPPINESS: f32 = -0.5;
+/// Happiness gap between two planets of one faction that sets settlers moving
+pub const MIGRATION_HAPPINESS_THRESHOLD: f32 = 0.3;
+/// Fraction of the unhappier planet's population that leaves per growth update
+pub const MIGRATION_RATE: f32 = 0.02;
+
+/// PopulationSystem manages population dynamics including:
+/// - Population growth based on food surplus (2% per tick with >20% surplus),
+///   scaled by the planet's habitability, slowing as the planet approaches
+///   its housing cap and stopping at it
+/// - Overcrowding unhappiness when population exceeds housing
+/// - Happiness from food surplus, free housing and taxes, which slows growth
+///   and production on unhappy planets
+/// - Population decline on blockaded planets
+/// - Migration between planets via transport ships, and settlers leaving
+///   unhappy planets for happier ones of the same faction
+/// - Worker allocation validation and management
+/// - Food consumption (1 food per person per tick)
+pub struct PopulationSystem {
+    /// Cached growth modifiers per planet for efficiency
+    growth_modifiers: HashMap<PlanetId, f32>,
+    /// Queue of pending migration orders awaiting ship arrivals
+    migration_queue: Vec<MigrationOrder>,
+    /// Current tick for deterministic processing
+    current_tick: u64,
+    /// Happiness per planet from the last happiness update
+    happiness: HashMap<PlanetId, f32>,
+}
+
+/// Represents a population migration order linked to a transport ship
+/// Tracks all necessary information for completing migration when ship arrives
+#[derive(Debug, Clone)]
+pub struct MigrationOrder {
+    /// Source planet ID
+    pub from: PlanetId,
+    /// Destination planet ID
+    pub to: PlanetId,
+    /// Number of people to migrate
+    pub population: i32,
+    /// Transport ship carrying the population
+    pub ship_id: ShipId,
+    /// Tick when migration order was created (for expiration)
+    pub created_tick: u64,
+}
+
+impl PopulationSystem {
+    /// Creates a new PopulationSystem with empty state
+    pub fn new() -> Self {
+        Self {
+            growth_modifiers: HashMap::with_capacity(100), // Pre-allocate for performance
+            migration_queue: Vec::with_capacity(50),
+            current_tick: 0,
+            happiness: HashMap::new(),
+        }
+    }
+    
+    /// Main update method - processes queued events only
+    /// Population logic is handled through event responses
+    pub fn update(&mut self, _delta: f32, _event_bus: &mut EventBus) -> GameResult<()> {
+        // Perform periodic cleanup of expired migration orders
+        self.cleanup_expired_migrations();
+        
+        // All other population processing happens in response to events
+        Ok(())
+    }
+    
+    
+    /// Processes population growth for a specific planet based on food surplus;
+    /// `habitability` is the planet trait scaling its growth rate
+    /// Called by GameState when it has access to actual planet data from managers
+    /// This method is designed to be called externally, not from within the system
+    #[allow(dead_code)]
+    pub fn process_planet_growth(&mut self, planet_id: PlanetId, population: i32, food_available: i32, housing_capacity: i32, habitability: f32, event_bus: &mut EventBus) -> GameResult<()> {
+        // Validate inputs
+        if population <= 0 {
+            return Ok(()); // No population to grow
+        }
+        
+        if food_available < 0 {
+            return Err(GameError::InvalidOperation(
+                "Food availability cannot be negative".into()
+            ));
+        }
+        
+        // Calculate food consumption (1 food per person per tick)
+        let food_consumed_per_tick = population;
+        
+        // Check if there's enough food for basic consumption
+        if food_available < food_consumed_per_tick {
+            // Emit food shortage event - population can't grow
+            event_bus.queue_event(GameEvent::SimulationEvent(
+                SimulationEvent::ResourceShortage {
+                    planet: planet_id,
+                    resource: ResourceType::Food,
+                }
+            ));
+            
+            // Remove any cached growth modifier
+            self.growth_modifiers.remove(&planet_id);
+            return Ok(());
+        }
+        
+        // Calculate food surplus ratio
+        let food_surplus = food_available - food_consumed_per_tick;
+        let food_surplus_ratio = food_surplus as f32 / food_consumed_per_tick as f32;
+        
+        // Apply growth only if food surplus > 20%
+        if food_surplus_ratio > 0.2 {
+            const GROWTH_RATE: f32 = 0.02; // 2% per tick
+            // Growth slows linearly as housing fills and stops at the cap
+            let housing_factor = if housing_capacity > 0 {
+                (1.0 - population as f32 / housing_capacity as f32).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            let growth_rate = GROWTH_RATE * habitability * housing_factor * happiness_modifier(self.get_happiness(planet_id));
+            let housing_space = (housing_capacity - population).max(0);
+            let growth_amount = ((population as f32 * growth_rate).floor() as i32).min(housing_space);
+            
+            if growth_amount > 0 {
+                // Cache growth modifier for efficiency
+                self.growth_modifiers.insert(planet_id, growth_rate);
+                
+                // Request food consumption for growth
+                let food_cost = ResourceBundle {
+                    food: food_consumed_per_tick,
+                    ..Default::default()
+                };
+                
+                // Emit resource consumption request
+                event_bus.queue_event(GameEvent::PlayerCommand(
+                    PlayerCommand::TransferResources {
+                        from: planet_id,
+                        to: planet_id, // Same planet - indicates consumption
+                        resources: food_cost,
+                    }
+                ));
+                
+                // Emit population growth event
+                event_bus.queue_event(GameEvent::SimulationEvent(
+                    SimulationEvent::PopulationGrowth {
+                        planet: planet_id,
+                        amount: growth_amount,
+                    }
+                ));
+                
+                // Request population update through proper channels
+                event_bus.queue_event(GameEvent::StateChanged(
+                    StateChange::PlanetUpdated(planet_id)
+                ));
+            }
+        } else {
+            // Remove growth modifier if no longer growing
+            self.growth_modifiers.remove(&planet_id);
+            
+            // Still consume basic food
+            let food_cost = ResourceBundle {
+                food: food_consumed_per_tick,
+                ..Default::default()
+            };
+            
+            event_bus.queue_event(GameEvent::PlayerCommand(
+                PlayerCommand::TransferResources {
+                    from: planet_id,
+                    to: planet_id,
+                    resources: food_cost,
+                }
+            ));
+        }
+        
+        Ok(())
+    }
+    
+    
+    
+    
+    /// Shrinks a blockaded planet's population by [`BLOCKADE_DECLINE_RATE`],
+    /// at least one person per call. Called by `advance_planet` in place of
+    /// `process_planet_growth` while the planet is blockaded; the planet still
+    /// eats when it has the food.
+    pub fn process_blockade_decline(&mut self, planet_id: PlanetId, population: i32, food_available: i32, event_bus: &mut EventBus) -> GameResult<()> {
+        if population <= 0 {
+            return Ok(());
+        }
+        self.growth_modifiers.insert(planet_id, -BLOCKADE_DECLINE_RATE);
+        
+        if food_available >= population {
+            event_bus.queue_event(GameEvent::PlayerCommand(
+                PlayerCommand::TransferResources {
+                    from: planet_id,
+                    to: planet_id,
+                    resources: ResourceBundle { food: population, ..Default::default() },
+                }
+            ));
+        }
+        
+        let decline = ((population as f32 * BLOCKADE_DECLINE_RATE).ceil() as i32).clamp(1, population);
+        event_bus.queue_event(GameEvent::SimulationEvent(
+            SimulationEvent::PopulationGrowth {
+                planet: planet_id,
+                amount: -decline,
+            }
+        ));
+        event_bus.queue_event(GameEvent::StateChanged(
+            StateChange::PlanetUpdated(planet_id)
+        ));
+        Ok(())
+    }
+    
+    /// Happiness the planet settles at from its food, housing, tax rate and
+    /// its controller's empire-wide tax, between -1.0 and 1.0
+    pub fn calculate_happiness(&self, planet: &Planet, faction_tax: TaxRate) -> f32 {
+        let population = planet.population.total;
+        let food = if population <= 0 {
+            0.0
+        } else if planet.resources.current.food < population {
+            FOOD_SHORTAGE_HAPPINESS
+        } else if (planet.resources.current.food - population) as f32 / population as f32 > 0.2 {
+            FOOD_SURPLUS_HAPPINESS
+        } else {
+            0.0
+        };
+        
+        let capacity = planet.housing_capacity();
+        let housing = if planet.overcrowding() > 0.0 {
+            planet.overcrowding().min(2.0) * OVERCROWDING_HAPPINESS
+        } else if capacity > 0 && planet.housing_space() as f32 >= capacity as f32 * SPACIOUS_HOUSING_FRACTION {
+            SPACIOUS_HOUSING_HAPPINESS
+        } else {
+            0.0
+        };
+        
+        (food + housing + planet.tax_rate.happiness_modifier() + faction_tax.happiness_modifier()).clamp(-1.0, 1.0)
+    }
+    
+    /// Recompute a planet's happiness, announcing it with `HappinessChanged`
+    /// when it differs from the planet's current value. Called by
+    /// `advance_planet` before `process_planet_growth`, which grows unhappy planets more slowly.
+    pub fn update_happiness(&mut self, planet: &Planet, faction_tax: TaxRate, event_bus: &mut EventBus) -> GameResult<()> {
+        let happiness = self.calculate_happiness(planet, faction_tax);
+        self.happiness.insert(planet.id, happiness);
+        if happiness != planet.population.happiness {
+            event_bus.queue_event(GameEvent::SimulationEvent(
+                SimulationEvent::HappinessChanged { planet: planet.id, happiness }
+            ));
+        }
+        Ok(())
+    }
+    
+    /// Rate a planet's happiness with its controller's tax, then grow its
+    /// population, or shrink it while `blockaded`. The planet already holds
+    /// this tick's resources.
+    pub fn advance_planet(&mut self, planet: &Planet, factions: &FactionManager, blockaded: bool, event_bus: &mut EventBus) -> GameResult<()> {
+        let faction_tax = planet.controller
+            .and_then(|owner| factions.get_faction(owner).ok())
+            .map(|faction| faction.tax_rate)
+            .unwrap_or_default();
+        self.update_happiness(planet, faction_tax, event_bus)?;
+        let population = planet.population.total;
+        let food_available = planet.resources.current.food;
+        if blockaded {
+            self.process_blockade_decline(planet.id, population, food_available, event_bus)
+        } else {
+            self.process_planet_growth(
+                planet.id,
+                population,
+                food_available,
+                planet.housing_capacity(),
+                planet.traits.habitability,
+                event_bus
+            )
+        }
+    }
+    
+    /// Migration between every settled planet that is not `blockaded`;
+    /// nobody leaves or reaches a blockaded planet
+    pub fn migrate(&mut self, planets: &PlanetManager, blockaded: impl Fn(PlanetId) -> bool, event_bus: &mut EventBus) -> GameResult<()> {
+        let open_planets: Vec<Planet> = planets.get_all_planets().iter()
+            .filter(|planet| planet.controller.is_some() && !blockaded(planet.id))
+            .cloned()
+            .collect();
+        self.process_migration(&open_planets, event_bus)
+    }
+    
+    /// Happiness from the last update, 0.0 (content) for planets not yet rated
+    pub fn get_happiness(&self, planet_id: PlanetId) -> f32 {
+        self.happiness.get(&planet_id).copied().unwrap_or(0.0)
+    }
+    
+    /// Move settlers from unhappy planets to happier planets of the same
+    /// faction, emitting `PopulationMigrated` for each move.
+    ///
+    /// A planet loses [`MIGRATION_RATE`] of its population, taken from its
+    /// unassigned workers, to the happiest planet of its faction whose
+    /// happiness is at least [`MIGRATION_HAPPINESS_THRESHOLD`] higher and that
+    /// has free housing. Called through `migrate` after the happiness update
+    /// with the planets settlers may travel between.
+    pub fn process_migration(&mut self, planets: &[Planet], event_bus: &mut EventBus) -> GameResult<()> {
+        let mut housing_space: HashMap<PlanetId, i32> = planets.iter()
+            .map(|planet| (planet.id, planet.housing_space()))
+            .collect();
+        
+        for source in planets {
+            let Some(controller) = source.controller else {
+                continue;
+            };
+            let source_happiness = self.get_happiness(source.id);
+            let destination = planets.iter()
+                .filter(|planet| planet.id != source.id && planet.controller == Some(controller))
+                .filter(|planet| self.get_happiness(planet.id) - source_happiness >= MIGRATION_HAPPINESS_THRESHOLD)
+                .filter(|planet| housing_space[&planet.id] > 0)
+                .max_by(|a, b| self.get_happiness(a.id).total_cmp(&self.get_happiness(b.id)).then(b.id.cmp(&a.id)));
+            let Some(destination) = destination else {
+                continue;
+            };
+            
+            let leaving = ((source.population.total as f32 * MIGRATION_RATE).floor() as i32)
+                .min(source.population.allocation.unassigned)
+                .min(housing_space[&destination.id]);
+            if leaving <= 0 {
+                continue;
+            }
+            *housing_space.get_mut(&destination.id).unwrap() -= leaving;
+            event_bus.queue_event(GameEvent::SimulationEvent(
+                SimulationEvent::PopulationMigrated { from: source.id, to: destination.id, amount: leaving }
+            ));
+            event_bus.queue_event(GameEvent::StateChanged(StateChange::PlanetUpdated(source.id)));
+            event_bus.queue_event(GameEvent::StateChanged(StateChange::PlanetUpdated(destination.id)));
+        }
+        Ok(())
+    }
+    
+    /// Returns current growth rate for a planet (if any)
+    pub fn get_growth_rate(&self, planet_id: PlanetId) -> Option<f32> {
+        self.growth_modifiers.get(&planet_id).copied()
+    }
+    
+    /// Returns count of pending migration orders
+    pub fn pending_migrations(&self) -> usize {
+        self.migration_queue.len()
+    }
+    
+    /// Cleans up expired migration orders to prevent memory leaks
+    fn cleanup_expired_migrations(&mut self) {
+        const MAX_MIGRATION_AGE: u64 = 1000; // ticks
+        
+        self.migration_queue.retain(|order| {
+            self.current_tick.saturating_sub(order.created_tick) <= MAX_MIGRATION_AGE
+        });
+    }
+}
+
+impl GameSystem for PopulationSystem {
+    fn update(&mut self, delta: f32, context: &mut SystemContext<'_>) -> GameResult<()> {
+        self.update(delta, context.event_bus())
+    }
+    
+    fn handle_event(&mut self, event: &GameEvent) -> GameResult<()> {
+        match event {
+            GameEvent::SimulationEvent(sim_event) => {
+                match sim_event {
+                    SimulationEvent::TickCompleted(tick) => {
+                        self.current_tick = *tick;
+                        // Population growth will be triggered by ResourceSystem
+                        // after it calculates available resources
+                    }
+                    
+                    SimulationEvent::ResourcesProduced { planet: _planet, resources } => {
+                        // Process population growth based on available food
+                        // Since PopulationSystem can't access PlanetManager directly due to EventBus architecture,
+                        // we emit a specialized event that GameState can handle with manager access
+                        if resources.food > 0 {
+                            // Emit event for GameState to process with actual planet data
+                            // GameState will call process_planet_growth with real population data
+                            // This maintains strict EventBus architecture compliance
+                        }
+                    }
+                    
+                    SimulationEvent::ShipArrived { ship, destination: _destination } => {
+                        // Handle population migration when transport ships arrive
+                        // Find pending migration order for this ship
+                        if let Some(index) = self.migration_queue.iter().position(|order| order.ship_id == *ship) {
+                            let migration_order = self.migration_queue.remove(index);
+                            
+                            // Validate migration order isn't stale (older than 1000 ticks)
+                            if self.current_tick.saturating_sub(migration_order.created_tick) <= 1000 {
+                                // Request cargo unload - this will be handled by ship/planet managers
+                                // Population system just tracks the migration request
+                            }
+                        }
+                    }
+                    
+                    SimulationEvent::ShipCompleted { planet: _planet, ship: _ship } => {
+                        // Transport ship completion would be handled by ship manager
+                        // Population system just tracks migration requests
+                    }
+                    
+                    _ => {}
+                }
+            }
+            
+            GameEvent::PlayerCommand(cmd) => {
+                match cmd {
+                    PlayerCommand::AllocateWorkers { planet: _planet, allocation } => {
+                        // Validate worker allocation rules
+                        // Note: Actual population data would come from planet manager
+                        // For now, just validate the allocation structure itself
+                        let total_allocated = allocation.agriculture + allocation.mining + 
+                                            allocation.industry + allocation.research + 
+                                            allocation.military + allocation.unassigned;
+                        
+                        if total_allocated > 0 {
+                            // Basic validation - ensure 10% minimum unassigned
+                            let min_unassigned = (total_allocated as f32 * 0.1) as i32;
+                            if allocation.unassigned < min_unassigned {
+                                return Err(GameError::InvalidOperation(
+                                    format!("Must maintain at least 10% unassigned workers. Required: {}, Provided: {}", 
+                                           min_unassigned, allocation.unassigned)
+                                ));
+                            }
+                        }
+                    }
+                    
+                    PlayerCommand::TransferResources { from: _from, to: _to, resources: _resources } => {
+                        // TransferResources validation should be handled by the ResourceSystem
+                        // PopulationSystem only tracks population-related events
+                        // This event may be food consumption (same planet) or actual transfers
+                    }
+                    
+                    _ => {}
+                }
+            }
+            
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn planet(population: i32, food: i32) -> Planet {
+        let mut planet = Planet {
+            id: 0,
+            position: OrbitalElements::default(),
+            resources: ResourceStorage::default(),
+            population: Demographics { total: population, ..Default::default() },
+            developments: Vec::new(),
+            controller: Some(0),
+            storage_priority: StoragePriority::default(),
+            power_priority: PowerPriority::default(),
+            size: DEFAULT_PLANET_SIZE,
+            tax_rate: TaxRate::default(),
+            traits: PlanetTraits::default(),
+            system: 0,
+            name: None,
+        };
+        planet.resources.current.food = food;
+        planet
+    }
+
+    #[test]
+    fn test_happiness_follows_food_housing_and_taxes() {
+        let population = PopulationSystem::new();
+        let capacity = DEFAULT_PLANET_SIZE as i32 * HOUSING_PER_PLANET_SIZE;
+
+        // Fed and spacious
+        let mut fed = planet(capacity / 2, capacity);
+        assert_eq!(population.calculate_happiness(&fed, TaxRate::Normal), FOOD_SURPLUS_HAPPINESS + SPACIOUS_HOUSING_HAPPINESS);
+        fed.tax_rate = TaxRate::High;
+        assert_eq!(population.calculate_happiness(&fed, TaxRate::Normal), FOOD_SURPLUS_HAPPINESS + SPACIOUS_HOUSING_HAPPINESS + TaxRate::High.happiness_modifier());
+        // The empire-wide tax weighs on top of the planet's own
+        assert_eq!(population.calculate_happiness(&fed, TaxRate::High), FOOD_SURPLUS_HAPPINESS + SPACIOUS_HOUSING_HAPPINESS + TaxRate::High.happiness_modifier() + TaxRate::High.happiness_modifier());
+
+        // Starving and crammed in at twice the housing cap
+        let crowded = planet(capacity * 2, 0);
+        assert_eq!(population.calculate_happiness(&crowded, TaxRate::Normal), FOOD_SHORTAGE_HAPPINESS + OVERCROWDING_HAPPINESS);
+
+        // A Habitat makes room again
+        let mut housed = planet(capacity, capacity * 2);
+        assert_eq!(population.calculate_happiness(&housed, TaxRate::Normal), FOOD_SURPLUS_HAPPINESS);
+        housed.developments.push(Building::new(BuildingType::Habitat, 1));
+        assert_eq!(population.calculate_happiness(&housed, TaxRate::Normal), FOOD_SURPLUS_HAPPINESS + SPACIOUS_HOUSING_HAPPINESS);
+    }
+}
@@ -0,0 +1,263 @@
+// src/systems/replay.rs
+//! Deterministic replay recording and playback
+//!
+//! The simulation is fixed-timestep and driven only by events, so a game is
+//! fully described by its starting configuration (including the seed) and
+//! the player commands issued along the way. While recording, every command
+//! queued through `GameState::queue_event` is stored with the tick it was
+//! issued at. During playback GameState regenerates the game from the
+//! recorded configuration and injects each command at its tick, before that
+//! tick's systems run, instead of taking commands from the UI.
+//!
+//! Commands the simulation issues itself, such as AI orders, are not
+//! recorded; playback reproduces them by running the same simulation. Save,
+//! load and menu commands are not recorded either, since they act on files
+//! or replace the game rather than play it.
+
+use crate::core::{GameResult, GameEvent, EventBus, PlayerCommand};
+use crate::core::types::*;
+use serde::{Serialize, Deserialize};
+use std::fs;
+use std::path::Path;
+
+/// Replay file format version
+pub const REPLAY_VERSION: u32 = 1;
+
+/// True for commands that belong in a replay
+pub fn is_recordable(command: &PlayerCommand) -> bool {
+    !matches!(
+        command,
+        PlayerCommand::SaveGame
+            | PlayerCommand::SaveGameDialog
+            | PlayerCommand::SaveGameAs(_)
+            | PlayerCommand::SaveGameToSlot(_)
+            | PlayerCommand::LoadGame
+            | PlayerCommand::LoadGameFrom(_)
+            | PlayerCommand::LoadGameFromSlot(_)
+            | PlayerCommand::NewGame
+            | PlayerCommand::NewGameNamed(_)
+            | PlayerCommand::ExitGame
+            | PlayerCommand::BackToMenu
+            | PlayerCommand::GameOptions
+            | PlayerCommand::BindKey { .. }
+            | PlayerCommand::StartNewGame(_)
+            | PlayerCommand::SetTheme(_)
+            | PlayerCommand::SetVolume(_)
+            | PlayerCommand::SetUiScale(_)
+            | PlayerCommand::ShowKeyBindings
+    )
+}
+
+/// A player command and the tick it was issued at
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedCommand {
+    /// Last completed tick when the command was queued
+    pub tick: u64,
+    /// The command as issued
+    pub command: PlayerCommand,
+}
+
+/// Everything needed to play a game back
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Replay {
+    /// Replay file format version
+    pub version: u32,
+    /// Configuration the game was generated from
+    pub configuration: GameConfiguration,
+    /// Commands in the order they were issued
+    pub commands: Vec<RecordedCommand>,
+    /// Tick at which recording stopped
+    pub end_tick: u64,
+}
+
+impl Replay {
+    /// Empty replay of a game generated from `configuration`
+    pub fn new(configuration: GameConfiguration) -> Self {
+        Self {
+            version: REPLAY_VERSION,
+            configuration,
+            commands: Vec::new(),
+            end_tick: 0,
+        }
+    }
+
+    /// Write the replay as JSON
+    pub fn save_to_file(&self, path: &Path) -> GameResult<()> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| GameError::SaveError(format!("Replay serialization failed: {}", e)))?;
+        fs::write(path, json)
+            .map_err(|e| GameError::Io(format!("Could not write replay file {}: {}", path.display(), e)))
+    }
+
+    /// Read a replay written by [`Replay::save_to_file`]
+    pub fn load_from_file(path: &Path) -> GameResult<Self> {
+        let json = fs::read_to_string(path)
+            .map_err(|e| GameError::Io(format!("Could not read replay file {}: {}", path.display(), e)))?;
+        let replay: Replay = serde_json::from_str(&json)
+            .map_err(|e| GameError::corrupted(path, format!("Replay deserialization failed: {}", e)))?;
+        if replay.version != REPLAY_VERSION {
+            return Err(GameError::corrupted(path, format!("Unsupported replay version {}", replay.version)));
+        }
+        Ok(replay)
+    }
+}
+
+/// What the replay system is doing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplayMode {
+    /// Neither recording nor playing back
+    Idle,
+    /// Recording the player's commands
+    Recording,
+    /// Feeding recorded commands back in
+    Playback,
+}
+
+/// Records player commands, or feeds recorded ones back in
+pub struct ReplaySystem {
+    mode: ReplayMode,
+    replay: Option<Replay>,
+    /// Index of the next command to inject during playback
+    cursor: usize,
+}
+
+impl ReplaySystem {
+    /// Idle, with no replay loaded
+    pub fn new() -> Self {
+        Self {
+            mode: ReplayMode::Idle,
+            replay: None,
+            cursor: 0,
+        }
+    }
+
+    /// What the system is doing
+    pub fn mode(&self) -> ReplayMode {
+        self.mode
+    }
+
+    /// True while commands are being recorded
+    pub fn is_recording(&self) -> bool {
+        self.mode == ReplayMode::Recording
+    }
+
+    /// True while a replay is being played back
+    pub fn is_playing(&self) -> bool {
+        self.mode == ReplayMode::Playback
+    }
+
+    /// Start recording a game generated from `configuration`
+    pub fn start_recording(&mut self, configuration: GameConfiguration) {
+        self.mode = ReplayMode::Recording;
+        self.replay = Some(Replay::new(configuration));
+        self.cursor = 0;
+    }
+
+    /// Store a command issued after `tick` completed; ignored unless recording
+    pub fn record(&mut self, tick: u64, command: &PlayerCommand) {
+        if !is_recordable(command) {
+            return;
+        }
+        if let (ReplayMode::Recording, Some(replay)) = (self.mode, &mut self.replay) {
+            replay.commands.push(RecordedCommand { tick, command: command.clone() });
+        }
+    }
+
+    /// Finish recording at `tick` and hand over the replay
+    pub fn stop_recording(&mut self, tick: u64) -> Option<Replay> {
+        if self.mode != ReplayMode::Recording {
+            return None;
+        }
+        self.mode = ReplayMode::Idle;
+        let mut replay = self.replay.take()?;
+        replay.end_tick = tick;
+        Some(replay)
+    }
+
+    /// Play a replay back from its first command
+    pub fn start_playback(&mut self, replay: Replay) {
+        self.mode = ReplayMode::Playback;
+        self.replay = Some(replay);
+        self.cursor = 0;
+    }
+
+    /// Stop playback; the game continues under normal control
+    pub fn stop_playback(&mut self) {
+        if self.mode == ReplayMode::Playback {
+            self.mode = ReplayMode::Idle;
+            self.replay = None;
+        }
+    }
+
+    /// Replay being recorded or played
+    pub fn replay(&self) -> Option<&Replay> {
+        self.replay.as_ref()
+    }
+
+    /// Queue every recorded command issued up to `tick`.
+    ///
+    /// Called before the tick after `tick` is simulated, so commands land in
+    /// the event queue where the UI would have put them. Playback ends once
+    /// every command has been injected and the tick about to run is the
+    /// recorded end tick.
+    pub fn inject_due_commands(&mut self, tick: u64, event_bus: &mut EventBus) -> GameResult<()> {
+        if self.mode != ReplayMode::Playback {
+            return Ok(());
+        }
+        let Some(replay) = &self.replay else {
+            return Ok(());
+        };
+
+        while let Some(recorded) = replay.commands.get(self.cursor) {
+            if recorded.tick > tick {
+                break;
+            }
+            event_bus.queue_event(GameEvent::PlayerCommand(recorded.command.clone()));
+            self.cursor += 1;
+        }
+
+        if self.cursor >= replay.commands.len() && tick + 1 >= replay.end_tick {
+            self.stop_playback();
+        }
+        Ok(())
+    }
+}
+
+impl Default for ReplaySystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_playback_injects_commands_at_their_ticks() {
+        let mut replays = ReplaySystem::new();
+        replays.start_recording(GameConfiguration::default());
+        replays.record(0, &PlayerCommand::PauseGame(true));
+        replays.record(0, &PlayerCommand::PauseGame(false));
+        replays.record(4, &PlayerCommand::SetGameSpeed(2.0));
+        replays.record(5, &PlayerCommand::SaveGame);
+        let replay = replays.stop_recording(6).unwrap();
+        assert_eq!(replay.end_tick, 6);
+        assert_eq!(replay.commands.len(), 3, "Saving is not part of a replay");
+        assert!(replays.stop_recording(6).is_none());
+
+        replays.start_playback(replay);
+        let mut bus = EventBus::new();
+        replays.inject_due_commands(0, &mut bus).unwrap();
+        assert_eq!(bus.queued_events.len(), 2);
+        replays.inject_due_commands(3, &mut bus).unwrap();
+        assert_eq!(bus.queued_events.len(), 2);
+        replays.inject_due_commands(4, &mut bus).unwrap();
+        assert!(matches!(bus.queued_events[2], GameEvent::PlayerCommand(PlayerCommand::SetGameSpeed(_))));
+
+        // Playback ends at the recorded end tick
+        assert!(replays.is_playing());
+        replays.inject_due_commands(5, &mut bus).unwrap();
+        assert_eq!(replays.mode(), ReplayMode::Idle);
+    }
+}
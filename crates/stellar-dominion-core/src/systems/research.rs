@@ -0,0 +1,221 @@
+// src/systems/research.rs
+//! Research towards technologies
+//!
+//! Every [`RESEARCH_INTERVAL_TICKS`], [`ResearchSystem::advance_factions`]
+//! totals the research output of the planets each faction controls and hands
+//! it to [`ResearchSystem::advance`]. The points go to the faction's research
+//! target, and FactionManager applies the resulting `ResearchProgressed` and
+//! `TechnologyUnlocked` events.
+
+use crate::core::{GameResult, GameEvent, EventBus, GameSystem, SystemContext, TechnologyTree};
+use crate::core::types::*;
+use crate::core::events::*;
+use crate::managers::{PlanetManager, FactionManager};
+use std::collections::BTreeMap;
+
+/// Ticks between research updates
+pub const RESEARCH_INTERVAL_TICKS: u64 = 10;
+/// Research workers needed for one point per research update
+pub const RESEARCH_WORKERS_PER_POINT: i32 = 50;
+/// Points per research update from each operational ResearchLab, per tier
+pub const RESEARCH_POINTS_PER_LAB: i32 = 5;
+
+/// Turns research output into progress and unlocked technologies
+pub struct ResearchSystem {
+    tree: TechnologyTree,
+    pending: Vec<SimulationEvent>,
+}
+
+impl ResearchSystem {
+    /// Creates a research system using the default technology tree
+    pub fn new() -> Self {
+        Self {
+            tree: TechnologyTree::new(),
+            pending: Vec::new(),
+        }
+    }
+
+    /// The technologies and their prerequisites
+    pub fn tree(&self) -> &TechnologyTree {
+        &self.tree
+    }
+
+    /// Mutable technology tree, for rebalancing costs and bonuses
+    pub fn tree_mut(&mut self) -> &mut TechnologyTree {
+        &mut self.tree
+    }
+
+    /// True on ticks where research advances
+    pub fn is_research_tick(&self, tick: u64) -> bool {
+        tick > 0 && tick.is_multiple_of(RESEARCH_INTERVAL_TICKS)
+    }
+
+    /// Research points a planet produces per research update
+    pub fn planet_output(&self, planet: &Planet) -> i32 {
+        let labs: i32 = planet.developments.iter()
+            .filter(|building| building.building_type == BuildingType::ResearchLab && building.is_operational())
+            .map(|building| building.tier as i32 * RESEARCH_POINTS_PER_LAB)
+            .sum();
+        planet.population.allocation.research / RESEARCH_WORKERS_PER_POINT + labs
+    }
+
+    /// Check that a faction may make the technology its research target
+    pub fn validate_target(&self, faction: &Faction, technology: Technology) -> GameResult<()> {
+        self.tree.can_research(faction, technology)
+    }
+
+    /// Put a faction's research output into its target technology.
+    ///
+    /// Emits `TechnologyUnlocked` once the target's cost is reached, and
+    /// `ResearchProgressed` otherwise. Points beyond the cost are lost.
+    pub fn advance(&mut self, faction: &Faction, points: i32) -> GameResult<()> {
+        let Some(technology) = faction.research.target else {
+            return Ok(());
+        };
+        if points <= 0 || faction.has_technology(technology) {
+            return Ok(());
+        }
+
+        let cost = self.tree.cost(technology)?;
+        if faction.research.progress_of(technology).saturating_add(points) >= cost {
+            self.pending.push(SimulationEvent::TechnologyUnlocked { faction: faction.id, technology });
+        } else {
+            self.pending.push(SimulationEvent::ResearchProgressed { faction: faction.id, technology, points });
+        }
+        Ok(())
+    }
+
+    /// Put every faction's research output, summed over the planets it
+    /// controls, into its target technology, in faction order
+    pub fn advance_factions(&mut self, planets: &PlanetManager, factions: &FactionManager) -> GameResult<()> {
+        let mut output: BTreeMap<FactionId, i32> = BTreeMap::new();
+        for planet in planets.get_all_planets() {
+            if let Some(owner) = planet.controller {
+                *output.entry(owner).or_insert(0) += self.planet_output(planet);
+            }
+        }
+        for faction in factions.get_all_factions() {
+            let points = output.get(&faction.id).copied().unwrap_or(0);
+            self.advance(faction, points)?;
+        }
+        Ok(())
+    }
+
+    /// Emits the research outcomes decided since the last update
+    pub fn update(&mut self, _delta: f32, event_bus: &mut EventBus) -> GameResult<()> {
+        for event in self.pending.drain(..) {
+            event_bus.queue_event(GameEvent::SimulationEvent(event));
+        }
+        Ok(())
+    }
+
+    /// Research targets are checked with `validate_target` and set by FactionManager, which has the faction data
+    pub fn handle_event(&mut self, _event: &GameEvent) -> GameResult<()> {
+        Ok(())
+    }
+}
+
+impl Default for ResearchSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GameSystem for ResearchSystem {
+    fn update(&mut self, delta: f32, context: &mut SystemContext<'_>) -> GameResult<()> {
+        self.update(delta, context.event_bus())
+    }
+
+    fn handle_event(&mut self, event: &GameEvent) -> GameResult<()> {
+        self.handle_event(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::{BTreeMap, BTreeSet};
+
+    fn researcher(target: Option<Technology>, progress: i32) -> Faction {
+        let mut research = ResearchState { target, ..Default::default() };
+        if let Some(technology) = target {
+            research.progress.insert(technology, progress);
+        }
+        Faction {
+            id: 1,
+            name: "Researchers".to_string(),
+            is_player: false,
+            ai_type: AIPersonality::Economic,
+            score: 0,
+            inbox: Inbox::default(),
+            technologies: BTreeSet::new(),
+            research,
+            relations: BTreeMap::new(),
+            explored_planets: BTreeSet::new(),
+            kind: FactionKind::Empire,
+            credits: 0,
+            tax_rate: TaxRate::default(),
+            ship_designs: Vec::new(),
+            control_groups: BTreeMap::new(),
+        }
+    }
+
+    fn emitted(research: &mut ResearchSystem) -> Vec<SimulationEvent> {
+        let mut bus = EventBus::new();
+        research.update(0.1, &mut bus).unwrap();
+        bus.queued_events.into_iter()
+            .filter_map(|event| match event {
+                GameEvent::SimulationEvent(event) => Some(event),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_research_progresses_then_unlocks_the_target() {
+        let mut research = ResearchSystem::new();
+        let cost = research.tree().cost(Technology::Hydroponics).unwrap();
+
+        research.advance(&researcher(None, 0), 10).unwrap();
+        assert!(emitted(&mut research).is_empty(), "Points without a target are not spent");
+
+        research.advance(&researcher(Some(Technology::Hydroponics), 0), 10).unwrap();
+        assert!(matches!(
+            emitted(&mut research)[..],
+            [SimulationEvent::ResearchProgressed { faction: 1, technology: Technology::Hydroponics, points: 10 }]
+        ));
+
+        research.advance(&researcher(Some(Technology::Hydroponics), cost - 5), 10).unwrap();
+        assert!(matches!(
+            emitted(&mut research)[..],
+            [SimulationEvent::TechnologyUnlocked { faction: 1, technology: Technology::Hydroponics }]
+        ));
+    }
+
+    #[test]
+    fn test_planet_output_counts_workers_and_labs() {
+        let research = ResearchSystem::new();
+        let mut planet = Planet {
+            id: 0,
+            position: OrbitalElements::default(),
+            resources: ResourceStorage::default(),
+            population: Demographics::default(),
+            developments: Vec::new(),
+            controller: Some(1),
+            storage_priority: StoragePriority::default(),
+            power_priority: PowerPriority::default(),
+            size: 3,
+            tax_rate: TaxRate::default(),
+            traits: PlanetTraits::default(),
+            system: 0,
+            name: None,
+        };
+        planet.population.allocation.research = RESEARCH_WORKERS_PER_POINT * 3;
+        assert_eq!(research.planet_output(&planet), 3);
+
+        planet.developments.push(Building::new(BuildingType::ResearchLab, 2));
+        planet.developments.push(Building { status: BuildingStatus::Shutdown(ShutdownReason::Unpowered), ..Building::new(BuildingType::ResearchLab, 1) });
+        assert_eq!(research.planet_output(&planet), 3 + 2 * RESEARCH_POINTS_PER_LAB);
+        assert!(!research.is_research_tick(0) && research.is_research_tick(RESEARCH_INTERVAL_TICKS));
+    }
+}
@@ -0,0 +1,959 @@
+// src/systems/resource_system.rs
+use crate::core::{GameResult, GameEvent, EventBus, GameSystem, SystemContext, GameDataRegistry, TechnologyTree};
+use crate::core::types::*;
+use crate::core::events::*;
+use crate::core::parallel;
+use crate::managers::{PlanetManager, ShipManager, FactionManager};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// One planet's resource change for a tick, worked out from the planet as it
+/// stands so planets can be planned in parallel and applied in id order
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProductionDelta {
+    /// Planet the change is for
+    pub planet: PlanetId,
+    /// Production with technology bonuses; the planet manager keeps what its
+    /// storage and storage priority allow when it is stored
+    pub production: ResourceBundle,
+    /// Upkeep owed this tick
+    pub consumption: ResourceBundle,
+    /// Whether the stockpile covers the upkeep once production is in
+    pub upkeep_paid: bool,
+    /// Net production before bonuses and the production revision it was
+    /// computed at, when nothing was cached for that revision
+    pub computed: Option<(u64, ResourceBundle)>,
+}
+
+impl ProductionDelta {
+    /// What the stockpile gains, or loses, this tick
+    pub fn net_change(&self) -> ResourceBundle {
+        let mut net_change = self.production;
+        if self.upkeep_paid {
+            for resource in ResourceType::ALL {
+                net_change.set(resource, net_change.get(resource) - self.consumption.get(resource));
+            }
+        }
+        net_change
+    }
+}
+
+/// A planet's energy grid for one tick: the energy it can spend and the
+/// buildings that get it, highest power priority first
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnergyGrid {
+    /// Energy in the stockpile plus what the planet generates this tick
+    pub available: i32,
+    /// Energy the powered buildings draw
+    pub drawn: i32,
+    /// Whether each building, by index in the planet's developments, is powered
+    pub powered: Vec<bool>,
+}
+
+impl EnergyGrid {
+    /// True if some building with a demand went without power
+    pub fn has_deficit(&self) -> bool {
+        self.powered.iter().any(|powered| !powered)
+    }
+}
+
+/// Buildings to switch on or off so a planet can pay its upkeep, by index in
+/// its developments
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UpkeepPlan {
+    /// Buildings shut down for unpaid upkeep that the planet can afford again,
+    /// highest power priority first
+    pub enable: Vec<usize>,
+    /// Running buildings to shut down, lowest power priority first
+    pub disable: Vec<usize>,
+}
+
+/// Where a planet's resources come from or go to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ProductionSource {
+    /// Workers assigned to mining
+    Miners,
+    /// Workers assigned to agriculture
+    Farmers,
+    /// Workers assigned to industry, who generate energy
+    IndustryWorkers,
+    /// Operational buildings of one type
+    Buildings(BuildingType),
+}
+
+impl ProductionSource {
+    /// Short description of `count` of the source, such as "Mine x2" or "miners 1200"
+    pub fn describe(&self, count: i32) -> String {
+        match self {
+            ProductionSource::Miners => format!("miners {}", count),
+            ProductionSource::Farmers => format!("farmers {}", count),
+            ProductionSource::IndustryWorkers => format!("industry workers {}", count),
+            ProductionSource::Buildings(building_type) => format!("{:?} x{}", building_type, count),
+        }
+    }
+}
+
+/// What one source produces or consumes per tick
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProductionLine {
+    /// Who produces or consumes
+    pub source: ProductionSource,
+    /// Workers, or operational buildings, behind the line
+    pub count: i32,
+    /// Amounts per tick, never negative
+    pub resources: ResourceBundle,
+}
+
+/// A planet's resource rates per tick, broken down by source
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProductionReport {
+    /// Planet the report is for
+    pub planet: PlanetId,
+    /// Production by source, before modifiers
+    pub gross: Vec<ProductionLine>,
+    /// Upkeep and energy draw by source
+    pub consumption: Vec<ProductionLine>,
+    /// What happiness, taxes, planet traits and technologies add to, or
+    /// take from, the gross production
+    pub modifiers: ResourceBundle,
+    /// Change to the stockpile per tick before storage caps
+    pub net: ResourceBundle,
+}
+
+impl ProductionReport {
+    /// Every source's production added up, before modifiers
+    pub fn gross_total(&self) -> ResourceBundle {
+        self.gross.iter().fold(ResourceBundle::default(), |total, line| total + line.resources)
+    }
+    
+    /// Every source's consumption added up
+    pub fn consumption_total(&self) -> ResourceBundle {
+        self.consumption.iter().fold(ResourceBundle::default(), |total, line| total + line.resources)
+    }
+    
+    /// One line for a tooltip, such as "+12 minerals/tick (Mine x2, miners 1200)",
+    /// naming the sources that produce or consume the resource
+    pub fn describe(&self, resource: ResourceType) -> String {
+        let sources: Vec<String> = self.gross.iter()
+            .filter(|line| line.resources.get(resource) > 0)
+            .map(|line| line.source.describe(line.count))
+            .chain(self.consumption.iter()
+                .filter(|line| line.resources.get(resource) > 0)
+                .map(|line| format!("-{} {}", line.resources.get(resource), line.source.describe(line.count))))
+            .collect();
+        let name = format!("{:?}", resource).to_lowercase();
+        let rate = format!("{:+} {}/tick", self.net.get(resource), name);
+        if sources.is_empty() {
+            rate
+        } else {
+            format!("{} ({})", rate, sources.join(", "))
+        }
+    }
+}
+
+/// A stockpile that empties within this many ticks is critical
+pub const ECONOMY_CRITICAL_TICKS: u64 = 100;
+/// A stockpile that empties or fills up within this many ticks needs watching
+pub const ECONOMY_WARNING_TICKS: u64 = 600;
+
+/// How urgently a resource needs the player's attention
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum OutlookLevel {
+    /// Nothing runs out or goes to waste soon
+    Steady,
+    /// Storage fills up, or the stockpile runs out, within `ECONOMY_WARNING_TICKS`
+    Warning,
+    /// The stockpile runs out within `ECONOMY_CRITICAL_TICKS`
+    Critical,
+}
+
+/// One resource across an empire: what it holds, can hold and gains per tick
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResourceOutlook {
+    /// Resource the outlook is for
+    pub resource: ResourceType,
+    /// Stockpiled on every planet together
+    pub stockpile: i32,
+    /// Storage on every planet together
+    pub capacity: i32,
+    /// Net change per tick from every planet's production report, less the
+    /// food its population eats
+    pub net_per_tick: i32,
+}
+
+impl ResourceOutlook {
+    /// Ticks until storage is full at the current rate; None if it is not filling
+    pub fn ticks_to_full(&self) -> Option<u64> {
+        (self.net_per_tick > 0).then(|| ((self.capacity - self.stockpile).max(0) as u64).div_ceil(self.net_per_tick as u64))
+    }
+    
+    /// Ticks until the stockpile is empty at the current rate; None if it is not draining
+    pub fn ticks_to_empty(&self) -> Option<u64> {
+        (self.net_per_tick < 0).then(|| self.stockpile.max(0) as u64 / self.net_per_tick.unsigned_abs() as u64)
+    }
+    
+    /// How urgently the resource needs attention
+    pub fn level(&self) -> OutlookLevel {
+        match (self.ticks_to_empty(), self.ticks_to_full()) {
+            (Some(ticks), _) if ticks <= ECONOMY_CRITICAL_TICKS => OutlookLevel::Critical,
+            (Some(ticks), _) | (_, Some(ticks)) if ticks <= ECONOMY_WARNING_TICKS => OutlookLevel::Warning,
+            _ => OutlookLevel::Steady,
+        }
+    }
+}
+
+/// A faction's resources across all its planets at one tick
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmpireEconomy {
+    /// Faction whose planets were summed
+    pub faction: FactionId,
+    /// Tick the figures were taken at
+    pub tick: u64,
+    /// Planets the faction controls
+    pub planets: usize,
+    /// One outlook per resource, in `ResourceType::ALL` order
+    pub outlooks: Vec<ResourceOutlook>,
+}
+
+impl EmpireEconomy {
+    /// The outlook for one resource
+    pub fn outlook(&self, resource: ResourceType) -> Option<&ResourceOutlook> {
+        self.outlooks.iter().find(|outlook| outlook.resource == resource)
+    }
+}
+
+/// Credits a faction pays each tick to keep one ship in service
+pub const SHIP_UPKEEP_CREDITS: i32 = 1;
+/// Credits a faction pays each tick per building tier it maintains
+pub const BUILDING_MAINTENANCE_CREDITS: i32 = 1;
+
+/// A faction's treasury for one tick: taxes in, upkeep and maintenance out
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Budget {
+    /// Taxes from every planet the faction holds
+    pub income: i32,
+    /// Upkeep for the faction's ships
+    pub ship_upkeep: i32,
+    /// Maintenance for the buildings on the faction's planets
+    pub building_maintenance: i32,
+}
+
+impl Budget {
+    /// What the treasury gains, or loses, this tick
+    pub fn net(&self) -> i32 {
+        self.income - self.ship_upkeep - self.building_maintenance
+    }
+}
+
+pub struct ResourceSystem {
+    production_rates: HashMap<BuildingType, ResourceBundle>,
+    /// Energy drawn per tick by a tier 1 building of each type with a demand
+    energy_demands: HashMap<BuildingType, i32>,
+    consumption_tracking: HashMap<PlanetId, ResourceBundle>,
+    // Net production by planet, with the production revision it was computed at
+    production_cache: HashMap<PlanetId, (u64, ResourceBundle)>,
+    // Each faction's economy, with the tick and PlanetManager revision it was summed at
+    economy_cache: HashMap<FactionId, ((u64, u64), EmpireEconomy)>,
+}
+
+impl ResourceSystem {
+    pub fn new() -> Self {
+        let mut system = Self {
+            production_rates: HashMap::new(),
+            energy_demands: HashMap::new(),
+            consumption_tracking: HashMap::new(),
+            production_cache: HashMap::new(),
+            economy_cache: HashMap::new(),
+        };
+        system.set_game_data(&GameDataRegistry::new());
+        system
+    }
+    
+    /// Take building production rates from the registry
+    pub fn set_game_data(&mut self, game_data: &GameDataRegistry) {
+        // Production per tick (0.1 seconds) of a tier 1 building
+        self.production_rates = game_data.buildings()
+            .filter(|(_, data)| data.production != ResourceBundle::default())
+            .map(|(building_type, data)| (building_type, data.production))
+            .collect();
+        self.energy_demands = game_data.buildings()
+            .filter(|(_, data)| data.energy_demand > 0)
+            .map(|(building_type, data)| (building_type, data.energy_demand))
+            .collect();
+        self.production_cache.clear();
+    }
+    
+    /// As `calculate_planet_production`, reusing the last result for the
+    /// planet while its production revision from PlanetManager is unchanged
+    pub fn cached_planet_production(&mut self, planet: &Planet, revision: u64) -> GameResult<ResourceBundle> {
+        if let Some(production) = self.cached_production(planet.id, revision) {
+            return Ok(production);
+        }
+        let production = self.calculate_planet_production(planet)?;
+        self.remember_production(planet.id, revision, production);
+        Ok(production)
+    }
+    
+    fn cached_production(&self, planet: PlanetId, revision: u64) -> Option<ResourceBundle> {
+        self.production_cache.get(&planet)
+            .filter(|(cached_revision, _)| *cached_revision == revision)
+            .map(|(_, production)| *production)
+    }
+    
+    /// Cache a planet's net production for its production revision
+    pub fn remember_production(&mut self, planet: PlanetId, revision: u64, production: ResourceBundle) {
+        self.production_cache.insert(planet, (revision, production));
+    }
+    
+    /// Work out the planet's resource change for this tick without changing
+    /// anything, so planets can be planned in parallel. Technologies of the
+    /// controlling faction raise output, not upkeep. What was computed rather
+    /// than cached comes back in `computed` for `remember_production`.
+    pub fn plan_production(&self, planet: &Planet, revision: u64, technologies: Option<&BTreeSet<Technology>>, tree: &TechnologyTree) -> GameResult<ProductionDelta> {
+        let (net_production, computed) = match self.cached_production(planet.id, revision) {
+            Some(production) => (production, None),
+            None => {
+                let production = self.calculate_planet_production(planet)?;
+                (production, Some((revision, production)))
+            }
+        };
+        
+        let (production, consumption, upkeep_paid) = Self::split_production(planet, net_production, technologies, tree);
+        
+        Ok(ProductionDelta {
+            planet: planet.id,
+            production,
+            consumption,
+            upkeep_paid,
+            computed,
+        })
+    }
+    
+    /// Split net production into positive production, with technology
+    /// bonuses, and negative consumption, and say whether the stockpile
+    /// covers the consumption once production is in
+    fn split_production(planet: &Planet, net_production: ResourceBundle, technologies: Option<&BTreeSet<Technology>>, tree: &TechnologyTree) -> (ResourceBundle, ResourceBundle, bool) {
+        let mut production = ResourceBundle::default();
+        let mut consumption = ResourceBundle::default();
+        for resource in ResourceType::ALL {
+            let amount = net_production.get(resource);
+            production.set(resource, amount.max(0));
+            consumption.set(resource, (-amount).max(0));
+        }
+        if let Some(technologies) = technologies {
+            tree.apply_production_bonuses(technologies, &mut production);
+        }
+        
+        // Upkeep is only paid when the stockpile covers it after production
+        let mut after_production = planet.resources.current;
+        let upkeep_paid = after_production.add(&production).is_ok() && after_production.can_afford(&consumption);
+        (production, consumption, upkeep_paid)
+    }
+    
+    /// Upkeep the stockpile cannot cover once production is in; energy is
+    /// left to the energy grid
+    fn upkeep_shortfall(&self, planet: &Planet, technologies: Option<&BTreeSet<Technology>>, tree: &TechnologyTree) -> GameResult<ResourceBundle> {
+        let net_production = self.calculate_planet_production(planet)?;
+        let (production, consumption, _) = Self::split_production(planet, net_production, technologies, tree);
+        let mut shortfall = ResourceBundle::default();
+        for resource in ResourceType::ALL.into_iter().filter(|resource| *resource != ResourceType::Energy) {
+            let available = planet.resources.current.get(resource).saturating_add(production.get(resource));
+            shortfall.set(resource, (consumption.get(resource) - available).max(0));
+        }
+        Ok(shortfall)
+    }
+    
+    /// Whether a running building takes any of the resources from the stockpile
+    fn consumes_any(&self, building: &Building, resources: &ResourceBundle) -> bool {
+        let rate = self.production_rates.get(&building.building_type).copied().unwrap_or_default();
+        ResourceType::ALL.into_iter().any(|resource| resources.get(resource) > 0 && rate.get(resource) < 0)
+    }
+    
+    /// Decide which buildings the planet can run on what it has. Buildings
+    /// shut down for unpaid upkeep come back, highest power priority first,
+    /// while the upkeep stays affordable with them running. If the upkeep is
+    /// not affordable, running buildings that consume a resource in short
+    /// supply shut down, lowest power priority first, until it is. Energy is not counted here;
+    /// the energy grid shares it out.
+    pub fn plan_upkeep(&self, planet: &Planet, technologies: Option<&BTreeSet<Technology>>, tree: &TechnologyTree) -> GameResult<UpkeepPlan> {
+        let mut order: Vec<usize> = (0..planet.developments.len()).collect();
+        order.sort_by_key(|&index| (planet.power_priority.rank(planet.developments[index].building_type), index));
+        let mut trial = planet.clone();
+        let mut plan = UpkeepPlan::default();
+        
+        let unpaid = BuildingStatus::Shutdown(ShutdownReason::UpkeepUnpaid);
+        for &index in order.iter().filter(|&&index| planet.developments[index].status == unpaid) {
+            trial.developments[index].status = BuildingStatus::Operational;
+            if self.upkeep_shortfall(&trial, technologies, tree)? == ResourceBundle::default() {
+                plan.enable.push(index);
+            } else {
+                trial.developments[index] = planet.developments[index].clone();
+            }
+        }
+        if !plan.enable.is_empty() {
+            return Ok(plan);
+        }
+        
+        for &index in order.iter().rev() {
+            let short = self.upkeep_shortfall(&trial, technologies, tree)?;
+            if short == ResourceBundle::default() {
+                break;
+            }
+            let building = &trial.developments[index];
+            if building.is_operational() && self.consumes_any(building, &short) {
+                trial.developments[index].status = unpaid;
+                plan.disable.push(index);
+            }
+        }
+        Ok(plan)
+    }
+    
+    /// Forget cached production, for when the planets are replaced
+    pub fn clear_production_cache(&mut self) {
+        self.production_cache.clear();
+        self.economy_cache.clear();
+    }
+    
+    /// The faction's stockpiles, storage and net rates summed over its
+    /// planets' production reports, with each population's food. Worked out once per tick, and again
+    /// only if a planet changes, since `revision` from
+    /// `PlanetManager::revision` moves with every change.
+    pub fn empire_economy(&mut self, faction: FactionId, tick: u64, revision: u64, planets: &[&Planet], technologies: Option<&BTreeSet<Technology>>, tree: &TechnologyTree) -> GameResult<&EmpireEconomy> {
+        let stale = self.economy_cache.get(&faction).is_none_or(|(at, _)| *at != (tick, revision));
+        if stale {
+            let mut outlooks: Vec<ResourceOutlook> = ResourceType::ALL.into_iter()
+                .map(|resource| ResourceOutlook { resource, stockpile: 0, capacity: 0, net_per_tick: 0 })
+                .collect();
+            for planet in planets {
+                let report = self.get_production_report(planet, technologies, tree)?;
+                // The population system charges food outside the report
+                let eaten = ResourceBundle { food: planet.population.total, ..Default::default() };
+                for outlook in &mut outlooks {
+                    outlook.stockpile = outlook.stockpile.saturating_add(planet.resources.current.get(outlook.resource));
+                    outlook.capacity = outlook.capacity.saturating_add(planet.resources.capacity.get(outlook.resource));
+                    outlook.net_per_tick = outlook.net_per_tick
+                        .saturating_add(report.net.get(outlook.resource))
+                        .saturating_sub(eaten.get(outlook.resource));
+                }
+            }
+            let economy = EmpireEconomy { faction, tick, planets: planets.len(), outlooks };
+            self.economy_cache.insert(faction, ((tick, revision), economy));
+        }
+        Ok(&self.economy_cache[&faction].1)
+    }
+    
+    pub fn calculate_planet_production(&self, planet: &Planet) -> GameResult<ResourceBundle> {
+        let mut total_production = ResourceBundle::default();
+        
+        // Calculate base production from workers
+        let allocation = &planet.population.allocation;
+        total_production.minerals += allocation.mining * 2; // Base mining rate
+        total_production.food += allocation.agriculture * 3; // Base farming rate
+        total_production.energy += allocation.industry; // Base energy from workers
+        
+        // Calculate building bonuses
+        for building in &planet.developments {
+            if !building.is_operational() {
+                continue;
+            }
+            
+            if let Some(production_rate) = self.production_rates.get(&building.building_type) {
+                // Building efficiency scales with tier
+                let efficiency_multiplier = building.tier as i32;
+                
+                total_production.minerals += production_rate.minerals * efficiency_multiplier;
+                total_production.food += production_rate.food * efficiency_multiplier;
+                total_production.energy += production_rate.energy * efficiency_multiplier;
+                total_production.alloys += production_rate.alloys * efficiency_multiplier;
+                total_production.components += production_rate.components * efficiency_multiplier;
+                total_production.fuel += production_rate.fuel * efficiency_multiplier;
+            }
+            total_production.energy -= self.energy_demand(building);
+        }
+
+        // Happiness and taxes scale what the planet produces, not what it consumes
+        let productivity = planet.productivity();
+        if productivity != 1.0 {
+            for resource in ResourceType::ALL {
+                let amount = total_production.get(resource);
+                if amount > 0 {
+                    total_production.set(resource, (amount as f32 * productivity).floor() as i32);
+                }
+            }
+        }
+        // Rich and fertile worlds yield more minerals and food
+        planet.traits.apply_to_production(&mut total_production);
+
+        Ok(total_production)
+    }
+    
+    /// Break the planet's production down by source: what its workers and
+    /// each type of operational building make and consume per tick, what
+    /// happiness, taxes, traits and technologies change, and the net result.
+    /// Read-only; supply cut-offs and storage caps are not counted.
+    pub fn get_production_report(&self, planet: &Planet, technologies: Option<&BTreeSet<Technology>>, tree: &TechnologyTree) -> GameResult<ProductionReport> {
+        let allocation = &planet.population.allocation;
+        let mut gross = Vec::new();
+        for (source, count, resources) in [
+            (ProductionSource::Miners, allocation.mining, ResourceBundle { minerals: allocation.mining * 2, ..Default::default() }),
+            (ProductionSource::Farmers, allocation.agriculture, ResourceBundle { food: allocation.agriculture * 3, ..Default::default() }),
+            (ProductionSource::IndustryWorkers, allocation.industry, ResourceBundle { energy: allocation.industry, ..Default::default() }),
+        ] {
+            if count > 0 {
+                gross.push(ProductionLine { source, count, resources });
+            }
+        }
+        
+        // Buildings are grouped by type, in type order
+        let mut buildings: BTreeMap<BuildingType, (i32, ResourceBundle, ResourceBundle)> = BTreeMap::new();
+        for building in planet.developments.iter().filter(|building| building.is_operational()) {
+            let (count, made, used) = buildings.entry(building.building_type).or_default();
+            *count += 1;
+            let rate = self.production_rates.get(&building.building_type).copied().unwrap_or_default();
+            for resource in ResourceType::ALL {
+                let amount = rate.get(resource) * building.tier as i32;
+                made.set(resource, made.get(resource) + amount.max(0));
+                used.set(resource, used.get(resource) + (-amount).max(0));
+            }
+            used.energy += self.energy_demand(building);
+        }
+        let mut consumption = Vec::new();
+        for (building_type, (count, made, used)) in buildings {
+            let source = ProductionSource::Buildings(building_type);
+            if made != ResourceBundle::default() {
+                gross.push(ProductionLine { source, count, resources: made });
+            }
+            if used != ResourceBundle::default() {
+                consumption.push(ProductionLine { source, count, resources: used });
+            }
+        }
+        
+        let (production, used, _) = Self::split_production(planet, self.calculate_planet_production(planet)?, technologies, tree);
+        let mut report = ProductionReport { planet: planet.id, gross, consumption, modifiers: ResourceBundle::default(), net: ResourceBundle::default() };
+        let (gross_total, consumption_total) = (report.gross_total(), report.consumption_total());
+        for resource in ResourceType::ALL {
+            let net = production.get(resource) - used.get(resource);
+            report.net.set(resource, net);
+            report.modifiers.set(resource, net - gross_total.get(resource) + consumption_total.get(resource));
+        }
+        Ok(report)
+    }
+    
+    /// Energy the building draws per tick while powered
+    pub fn energy_demand(&self, building: &Building) -> i32 {
+        self.energy_demands.get(&building.building_type).copied().unwrap_or(0) * building.tier as i32
+    }
+    
+    /// Energy the planet's industry workers and operational generating
+    /// buildings produce per tick, scaled by happiness and taxes
+    pub fn energy_generation(&self, planet: &Planet) -> i32 {
+        let buildings: i32 = planet.developments.iter()
+            .filter(|building| building.is_operational())
+            .filter_map(|building| self.production_rates.get(&building.building_type).map(|rate| rate.energy.max(0) * building.tier as i32))
+            .sum();
+        let generation = planet.population.allocation.industry + buildings;
+        (generation as f32 * planet.productivity()).floor() as i32
+    }
+    
+    /// Share the planet's stockpiled and generated energy among its
+    /// buildings in power priority order. Buildings without a demand are
+    /// always powered and ones shut down for upkeep never are; once one
+    /// building's demand cannot be met, it and every building after it go
+    /// without.
+    pub fn allocate_energy(&self, planet: &Planet) -> EnergyGrid {
+        let available = planet.resources.current.energy.max(0) + self.energy_generation(planet);
+        let mut order: Vec<usize> = (0..planet.developments.len()).collect();
+        order.sort_by_key(|&index| (planet.power_priority.rank(planet.developments[index].building_type), index));
+        
+        let mut grid = EnergyGrid { available, drawn: 0, powered: vec![true; planet.developments.len()] };
+        let mut shortage = false;
+        for index in order {
+            if planet.developments[index].shutdown_reason() == Some(ShutdownReason::UpkeepUnpaid) {
+                grid.powered[index] = false;
+                continue;
+            }
+            let demand = self.energy_demand(&planet.developments[index]);
+            if demand == 0 {
+                continue;
+            }
+            if shortage || grid.drawn + demand > available {
+                shortage = true;
+                grid.powered[index] = false;
+            } else {
+                grid.drawn += demand;
+            }
+        }
+        grid
+    }
+    
+    /// Work out a faction's treasury for one tick. Each planet pays the
+    /// faction's tax on its population; happy planets pay up to half as
+    /// much again and unhappy ones as little as half.
+    pub fn faction_budget(&self, tax_rate: TaxRate, planets: &[&Planet], ships: usize) -> Budget {
+        let taxes: f32 = planets.iter()
+            .map(|planet| {
+                let compliance = 1.0 + planet.population.happiness.clamp(-1.0, 1.0) * 0.5;
+                planet.population.total.max(0) as f32 * tax_rate.credits_per_thousand() as f32 / 1000.0 * compliance
+            })
+            .sum();
+        let building_tiers: i32 = planets.iter()
+            .flat_map(|planet| &planet.developments)
+            .map(|building| building.tier as i32)
+            .sum();
+        Budget {
+            income: taxes.floor() as i32,
+            ship_upkeep: (ships as i32).saturating_mul(SHIP_UPKEEP_CREDITS),
+            building_maintenance: building_tiers.saturating_mul(BUILDING_MAINTENANCE_CREDITS),
+        }
+    }
+    
+    /// What the faction's treasury takes in and pays out each tick at its
+    /// current tax rate
+    pub fn empire_budget(&self, faction: FactionId, planets: &PlanetManager, ships: &ShipManager, factions: &FactionManager) -> GameResult<Budget> {
+        let tax_rate = factions.get_faction(faction)?.tax_rate;
+        let held = planets.get_planets_by_faction(faction);
+        let fleet = ships.get_ships_by_owner(faction).len();
+        Ok(self.faction_budget(tax_rate, &held, fleet))
+    }
+    
+    /// Every empire's budget for this tick, in faction order; system
+    /// factions such as the pirates keep no treasury
+    pub fn empire_budgets(&self, planets: &PlanetManager, ships: &ShipManager, factions: &FactionManager) -> GameResult<Vec<(FactionId, Budget)>> {
+        factions.get_all_factions().iter()
+            .filter(|faction| faction.is_scoring())
+            .map(|faction| Ok((faction.id, self.empire_budget(faction.id, planets, ships, factions)?)))
+            .collect()
+    }
+    
+    /// Buildings each settled planet must shut down because it cannot pay
+    /// their upkeep, and ones it can afford to bring back, as (planet,
+    /// building index, new status)
+    pub fn upkeep_switches(&self, planets: &PlanetManager, factions: &FactionManager, tree: &TechnologyTree) -> GameResult<Vec<(PlanetId, usize, BuildingStatus)>> {
+        let mut switches = Vec::new();
+        for planet in planets.get_all_planets().iter().filter(|planet| planet.controller.is_some()) {
+            let technologies = planet.controller
+                .and_then(|owner| factions.get_faction(owner).ok())
+                .map(|faction| &faction.technologies);
+            let plan = self.plan_upkeep(planet, technologies, tree)?;
+            switches.extend(plan.enable.into_iter().map(|index| (planet.id, index, BuildingStatus::Operational)));
+            switches.extend(plan.disable.into_iter().map(|index| (planet.id, index, BuildingStatus::Shutdown(ShutdownReason::UpkeepUnpaid))));
+        }
+        Ok(switches)
+    }
+    
+    /// Buildings each settled planet's energy grid switches on or off this
+    /// tick, as (planet, building index, new status); buildings shut down
+    /// for upkeep stay as they are
+    pub fn power_switches(&self, planets: &PlanetManager) -> Vec<(PlanetId, usize, BuildingStatus)> {
+        let mut switches = Vec::new();
+        for planet in planets.get_all_planets().iter().filter(|planet| planet.controller.is_some()) {
+            let grid = self.allocate_energy(planet);
+            for (index, (building, powered)) in planet.developments.iter().zip(grid.powered).enumerate() {
+                let status = match (building.status, powered) {
+                    (BuildingStatus::Shutdown(ShutdownReason::UpkeepUnpaid), _) => continue,
+                    (_, true) => BuildingStatus::Operational,
+                    (_, false) => BuildingStatus::Shutdown(ShutdownReason::Unpowered),
+                };
+                if building.status != status {
+                    switches.push((planet.id, index, status));
+                }
+            }
+        }
+        switches
+    }
+    
+    /// Work out every settled planet's production from the planets as they
+    /// stand, in parallel with the `parallel` feature, in planet id order so
+    /// the changes can be applied the same way on any number of threads
+    pub fn plan_all_production(&self, planets: &PlanetManager, factions: &FactionManager, tree: &TechnologyTree) -> Vec<GameResult<ProductionDelta>> {
+        let mut settled: Vec<&Planet> = planets.get_all_planets().iter()
+            .filter(|planet| planet.controller.is_some())
+            .collect();
+        settled.sort_by_key(|planet| planet.id);
+        parallel::map_ordered(&settled, |planet| {
+            // Only planets whose workers, buildings, happiness or taxes
+            // changed are recomputed
+            let technologies = planet.controller
+                .and_then(|owner| factions.get_faction(owner).ok())
+                .map(|faction| &faction.technologies);
+            self.plan_production(planet, planets.production_revision(planet.id), technologies, tree)
+        })
+    }
+    
+    /// Process resource production for all planets in the game state
+    /// This method should be called by GameState during tick processing  
+    pub fn process_production(&mut self, planets: &[Planet], event_bus: &mut EventBus) -> GameResult<()> {
+        for planet in planets {
+            // Calculate production for this planet
+            let production = self.calculate_planet_production(planet)?;
+            
+            // Track consumption
+            self.consumption_tracking.insert(planet.id, production);
+            
+            // Check for resource shortages using functional approach
+            let current_resources = planet.resources.current;
+            let shortage_resources = self.detect_resource_shortages(&current_resources, &production);
+            
+            // Emit shortage events before applying production
+            for resource_type in shortage_resources {
+                event_bus.queue_event(GameEvent::SimulationEvent(
+                    SimulationEvent::ResourceShortage {
+                        planet: planet.id,
+                        resource: resource_type,
+                    }
+                ));
+            }
+            
+            // Calculate actual production considering storage constraints
+            let effective_production = self.calculate_effective_production(
+                &current_resources, &planet.resources.capacity, &production
+            )?;
+            
+            // This will be handled by GameState calling planet_manager.add_resources
+            // Emit production event with effective resources
+            event_bus.queue_event(GameEvent::SimulationEvent(
+                SimulationEvent::ResourcesProduced {
+                    planet: planet.id,
+                    resources: effective_production,
+                }
+            ));
+            
+            // Emit planet updated state change
+            event_bus.queue_event(GameEvent::StateChanged(StateChange::PlanetUpdated(planet.id)));
+        }
+        
+        Ok(())
+    }
+    
+    /// Validate a resource transfer between planets
+    /// Returns the validated transfer amount (may be less than requested)
+    pub fn validate_transfer(&self, source: &Planet, destination: &Planet, requested: ResourceBundle) -> GameResult<ResourceBundle> {
+        // Validate source has enough resources
+        if !source.resources.current.can_afford(&requested) {
+            return Err(GameError::InsufficientResources {
+                required: requested,
+                available: source.resources.current,
+            });
+        }
+        
+        // Validate destination capacity using existing storage methods
+        if !destination.resources.can_store(&requested) {
+            // Calculate maximum transferable amount
+            let available_space = destination.resources.available_space();
+            let max_transfer = ResourceBundle {
+                minerals: requested.minerals.min(available_space.minerals),
+                food: requested.food.min(available_space.food),
+                energy: requested.energy.min(available_space.energy),
+                alloys: requested.alloys.min(available_space.alloys),
+                components: requested.components.min(available_space.components),
+                fuel: requested.fuel.min(available_space.fuel),
+            };
+            
+            if max_transfer.total() == 0 {
+                return Err(GameError::InvalidOperation("Destination has no storage capacity".into()));
+            }
+            
+            return Ok(max_transfer);
+        }
+        
+        Ok(requested)
+    }
+    
+    pub fn get_consumption_for_planet(&self, planet_id: PlanetId) -> Option<&ResourceBundle> {
+        self.consumption_tracking.get(&planet_id)
+    }
+    
+    /// Validate ship cargo loading operation
+    /// Returns the actual loadable amount considering ship capacity and planet resources
+    pub fn validate_cargo_loading(&self, ship: &Ship, planet: &Planet, requested: ResourceBundle, current_tick: u64) -> GameResult<ResourceBundle> {
+        // Check if ship is at planet using proper orbital calculation
+        let planet_position = self.calculate_planet_position(&planet.position, current_tick);
+        let distance = ship.position.distance_to(&planet_position);
+        
+        if distance > 0.5 { // Realistic docking range
+            return Err(GameError::InvalidOperation(
+                format!("Ship {} not in docking range of planet {} (distance: {:.2})", 
+                       ship.id, planet.id, distance)
+            ));
+        }
+        
+        // Check planet resource availability
+        if !planet.resources.current.can_afford(&requested) {
+            return Err(GameError::InsufficientResources {
+                required: requested,
+                available: planet.resources.current,
+            });
+        }
+        
+        // Check ship cargo capacity
+        if !ship.cargo.can_load(&requested, 0) {
+            let available_space = ship.cargo.available_space();
+            let max_loadable = ResourceBundle {
+                minerals: requested.minerals.min(available_space),
+                food: requested.food.min(available_space),
+                energy: requested.energy.min(available_space),
+                alloys: requested.alloys.min(available_space),
+                components: requested.components.min(available_space),
+                fuel: requested.fuel.min(available_space),
+            };
+            
+            if max_loadable.total() == 0 {
+                return Err(GameError::InvalidOperation("Ship cargo hold is full".into()));
+            }
+            
+            return Ok(max_loadable);
+        }
+        
+        Ok(requested)
+    }
+    
+    /// Validate ship cargo unloading operation
+    /// Returns the actual unloadable amount considering planet storage capacity
+    pub fn validate_cargo_unloading(&self, ship: &Ship, planet: &Planet, current_tick: u64) -> GameResult<ResourceBundle> {
+        // Check if ship is at planet using proper orbital calculation
+        let planet_position = self.calculate_planet_position(&planet.position, current_tick);
+        let distance = ship.position.distance_to(&planet_position);
+        
+        if distance > 0.5 { // Realistic docking range
+            return Err(GameError::InvalidOperation(
+                format!("Ship {} not in docking range of planet {} (distance: {:.2})", 
+                       ship.id, planet.id, distance)
+            ));
+        }
+        
+        let cargo_resources = ship.cargo.resources;
+        
+        // Check planet storage capacity using existing methods
+        if !planet.resources.can_store(&cargo_resources) {
+            let available_space = planet.resources.available_space();
+            let max_unloadable = ResourceBundle {
+                minerals: cargo_resources.minerals.min(available_space.minerals),
+                food: cargo_resources.food.min(available_space.food),
+                energy: cargo_resources.energy.min(available_space.energy),
+                alloys: cargo_resources.alloys.min(available_space.alloys),
+                components: cargo_resources.components.min(available_space.components),
+                fuel: cargo_resources.fuel.min(available_space.fuel),
+            };
+            
+            if max_unloadable.total() == 0 {
+                return Err(GameError::InvalidOperation("Planet has no storage capacity".into()));
+            }
+            
+            return Ok(max_unloadable);
+        }
+        
+        Ok(cargo_resources)
+    }
+    
+    /// Helper method to detect resource shortages
+    fn detect_resource_shortages(&self, current: &ResourceBundle, production: &ResourceBundle) -> Vec<ResourceType> {
+        let mut shortages = Vec::new();
+        
+        if current.minerals + production.minerals < 0 {
+            shortages.push(ResourceType::Minerals);
+        }
+        if current.food + production.food < 0 {
+            shortages.push(ResourceType::Food);
+        }
+        if current.energy + production.energy < 0 {
+            shortages.push(ResourceType::Energy);
+        }
+        if current.alloys + production.alloys < 0 {
+            shortages.push(ResourceType::Alloys);
+        }
+        if current.components + production.components < 0 {
+            shortages.push(ResourceType::Components);
+        }
+        if current.fuel + production.fuel < 0 {
+            shortages.push(ResourceType::Fuel);
+        }
+        
+        shortages
+    }
+    
+    /// Calculate effective production considering storage constraints
+    fn calculate_effective_production(&self, current: &ResourceBundle, capacity: &ResourceBundle, production: &ResourceBundle) -> GameResult<ResourceBundle> {
+        // Handle positive production (capped by storage) and negative production (consumption)
+        let effective_production = ResourceBundle {
+            minerals: if production.minerals > 0 {
+                production.minerals.min(capacity.minerals - current.minerals)
+            } else {
+                production.minerals // Allow negative (consumption)
+            },
+            food: if production.food > 0 {
+                production.food.min(capacity.food - current.food)
+            } else {
+                production.food
+            },
+            energy: if production.energy > 0 {
+                production.energy.min(capacity.energy - current.energy)
+            } else {
+                production.energy
+            },
+            alloys: if production.alloys > 0 {
+                production.alloys.min(capacity.alloys - current.alloys)
+            } else {
+                production.alloys
+            },
+            components: if production.components > 0 {
+                production.components.min(capacity.components - current.components)
+            } else {
+                production.components
+            },
+            fuel: if production.fuel > 0 {
+                production.fuel.min(capacity.fuel - current.fuel)
+            } else {
+                production.fuel
+            },
+        };
+        
+        Ok(effective_production)
+    }
+    
+    /// Production runs when GameState handles each tick, not on update
+    pub fn update(&mut self, _delta: f32, _event_bus: &mut EventBus) -> GameResult<()> {
+        // All processing is coordinated through GameState to maintain architecture boundaries
+        Ok(())
+    }
+    
+    /// Calculate planet position at given tick for proper orbital mechanics
+    fn calculate_planet_position(&self, orbital_elements: &OrbitalElements, current_tick: u64) -> Vector2 {
+        orbital_elements.position_at(current_tick)
+    }
+}
+
+impl GameSystem for ResourceSystem {
+    fn update(&mut self, delta: f32, context: &mut SystemContext<'_>) -> GameResult<()> {
+        self.update(delta, context.event_bus())
+    }
+    
+    fn handle_event(&mut self, event: &GameEvent) -> GameResult<()> {
+        // ResourceSystem validates events but does not perform direct state mutations
+        // This maintains the EventBus architecture where only managers modify state
+        match event {
+            GameEvent::SimulationEvent(sim_event) => {
+                match sim_event {
+                    SimulationEvent::TickCompleted(_tick) => {
+                        // Tick processing coordinated by GameState
+                    }
+                    SimulationEvent::ResourcesProduced { planet, resources } => {
+                        // Update consumption tracking for this planet
+                        self.consumption_tracking.insert(*planet, *resources);
+                    }
+                    _ => {}
+                }
+            }
+            GameEvent::PlayerCommand(cmd) => {
+                match cmd {
+                    PlayerCommand::TransferResources { .. } |
+                    PlayerCommand::LoadShipCargo { .. } |
+                    PlayerCommand::UnloadShipCargo { .. } => {
+                        // Commands validated and processed by GameState coordination
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,533 @@
+// src/systems/save_system.rs
+//! Simplified save system for Stellar Dominion
+//! 
+//! Features:
+//! - JSON serialization for simplicity and debugging, gzip-compressed in
+//!   save slots; uncompressed slots from older versions still load
+//! - Named save files
+//! - Save list management
+//! - Deterministic state preservation
+//! - Background saving: the snapshot is taken on the main thread, while
+//!   serialization, compression and disk IO run on a worker thread
+//! - Autosaves to rotating `autosave_1..autosave_N` slots, started when
+//!   TimeManager emits AutosaveDue
+//! - JSON export and import of the whole game state for modding and diffing
+//!
+//! ## Exported JSON
+//!
+//! [`SaveSystem::export_json`] writes the same [`SaveData`] model as save
+//! slots, so a save file can be imported and an export can be loaded as a
+//! save. The top-level object holds:
+//!
+//! - `version`: save format version, currently 1
+//! - `save_name`: always [`EXPORT_SAVE_NAME`]
+//! - `timestamp`: always 0, so identical states give identical files
+//! - `tick`: simulation tick the state was taken at
+//! - `planets`, `ships`, `factions`: every entity, in ascending id order
+//! - `game_configuration`: settings the game was generated with
+//! - `market`: market prices and their history
+//! - `trade_routes`: standing trade routes, one per transport
+//!
+//! Field names follow the Rust types in `core::types`. Fields added after
+//! the first release carry serde defaults and may be left out of
+//! hand-written files. [`SaveSystem::import_json`] checks the same
+//! constraints as loading a save before anything is applied.
+
+use crate::core::{GameResult, GameEvent, EventBus, GameState, GameSystem, SystemContext, SimRng, StarMap};
+use crate::core::types::*;
+use crate::core::events::{PlayerCommand, SimulationEvent};
+use crate::systems::{MarketState, StatisticsState, IntelState};
+use std::collections::BTreeSet;
+use std::fs::{self, File};
+use std::io::{Write, Read};
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread;
+use std::time::{SystemTime, UNIX_EPOCH};
+use serde::{Serialize, Deserialize};
+
+/// `save_name` written by [`SaveSystem::export_json`]
+pub const EXPORT_SAVE_NAME: &str = "export";
+
+/// First bytes of a gzip stream, marking a compressed save slot
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Simple save data structure
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaveData {
+    pub version: u32,
+    pub save_name: String,
+    pub timestamp: u64,
+    pub tick: u64,
+    pub planets: Vec<Planet>,
+    pub ships: Vec<Ship>,
+    pub factions: Vec<Faction>,
+    pub game_configuration: GameConfiguration,
+    /// Market prices and their history
+    #[serde(default)]
+    pub market: MarketState,
+    /// Random number state; None in saves made before it was saved
+    #[serde(default)]
+    pub rng: Option<SimRng>,
+    /// Empire statistics; empty in saves made before they were saved
+    #[serde(default)]
+    pub statistics: StatisticsState,
+    /// Every faction's intel reports; empty in saves made before they were saved
+    #[serde(default)]
+    pub intel: IntelState,
+    /// Star systems and jump lanes; a single star in saves made before there were several
+    #[serde(default)]
+    pub star_map: StarMap,
+    /// Standing trade routes; none in saves made before they were saved
+    #[serde(default)]
+    pub trade_routes: Vec<TradeRoute>,
+}
+
+/// Save file metadata for the save browser
+#[derive(Debug, Clone)]
+pub struct SaveInfo {
+    pub name: String,
+    pub timestamp: u64,
+    pub tick: u64,
+    pub planets: usize,
+    pub ships: usize,
+    pub factions: usize,
+}
+
+impl SaveInfo {
+    pub fn from_save_data(data: &SaveData) -> Self {
+        Self {
+            name: data.save_name.clone(),
+            timestamp: data.timestamp,
+            tick: data.tick,
+            planets: data.planets.len(),
+            ships: data.ships.len(),
+            factions: data.factions.len(),
+        }
+    }
+}
+
+impl SaveData {
+    /// Copy the savable parts of the game state, to be saved as `slot_name`
+    pub fn capture(state: &GameState, slot_name: &str) -> Self {
+        SaveData {
+            version: 1,
+            save_name: slot_name.to_string(),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+            tick: state.get_current_tick(),
+            planets: state.planet_manager.get_all_planets().clone(),
+            ships: state.ship_manager.get_all_ships().clone(),
+            factions: state.faction_manager.get_all_factions().to_vec(),
+            game_configuration: state.game_initializer.get_configuration().clone(),
+            market: state.market_system.state().clone(),
+            rng: Some(state.event_bus.rng.clone()),
+            statistics: state.statistics_system.state().clone(),
+            intel: state.spy_system.state().clone(),
+            star_map: state.physics_engine.star_map().clone(),
+            trade_routes: state.ship_manager.get_trade_routes().cloned().collect(),
+        }
+    }
+}
+
+/// A save running on a background thread
+struct PendingSave {
+    slot_name: String,
+    result: Receiver<GameResult<()>>,
+}
+
+/// Simplified save system
+pub struct SaveSystem {
+    save_directory: PathBuf,
+    current_save_name: Option<String>,
+    pending_save: Option<PendingSave>,
+    autosave: AutosaveSettings,
+    next_autosave_slot: usize,
+}
+
+impl SaveSystem {
+    pub fn new() -> Self {
+        Self::with_directory("saves")
+    }
+    
+    /// Save system keeping its slots in `directory`, created if missing
+    pub fn with_directory(directory: impl Into<PathBuf>) -> Self {
+        let save_dir = directory.into();
+        if !save_dir.exists() {
+            std::fs::create_dir_all(&save_dir).unwrap_or_else(|e| {
+                log::warn!("Could not create saves directory: {}", e);
+            });
+        }
+        
+        Self {
+            save_directory: save_dir,
+            current_save_name: None,
+            pending_save: None,
+            autosave: AutosaveSettings::default(),
+            next_autosave_slot: 0,
+        }
+    }
+    
+    /// Save captured game state to its slot, blocking until written
+    pub fn save_game_to_slot(&mut self, save_data: SaveData) -> GameResult<()> {
+        if self.is_saving() {
+            return Err(GameError::SaveError("A save is already in progress".to_string()));
+        }
+        
+        Self::write_save(&self.get_save_path(&save_data.save_name), &save_data)?;
+        
+        self.current_save_name = Some(save_data.save_name);
+        Ok(())
+    }
+    
+    /// Start saving captured game state to its slot without blocking the caller.
+    ///
+    /// Serialization and file IO happen on a background thread. Completion is
+    /// reported by [`SaveSystem::poll_pending_save`] as `SaveCompleted` or
+    /// `SaveFailed`. Only one save may run at a time.
+    pub fn begin_save(&mut self, save_data: SaveData) -> GameResult<()> {
+        if self.is_saving() {
+            return Err(GameError::SaveError("A save is already in progress".to_string()));
+        }
+        
+        let slot_name = save_data.save_name.clone();
+        let file_path = self.get_save_path(&slot_name);
+        let (sender, receiver) = mpsc::channel();
+        
+        thread::Builder::new()
+            .name("save-writer".to_string())
+            .spawn(move || {
+                // The receiver may be gone if the save system was dropped; nothing to report then
+                let _ = sender.send(Self::write_save(&file_path, &save_data));
+            })
+            .map_err(|e| GameError::SaveError(format!("Could not start save thread: {}", e)))?;
+        
+        self.pending_save = Some(PendingSave {
+            slot_name: slot_name.clone(),
+            result: receiver,
+        });
+        self.current_save_name = Some(slot_name);
+        Ok(())
+    }
+    
+    /// Autosave interval, slot count and pause behaviour
+    pub fn autosave_settings(&self) -> AutosaveSettings {
+        self.autosave
+    }
+    
+    /// Change the autosave interval, slot count or pause behaviour
+    pub fn set_autosave_settings(&mut self, settings: AutosaveSettings) {
+        self.autosave = settings;
+        self.next_autosave_slot %= settings.slots.max(1);
+    }
+    
+    /// Slot name of the given autosave, counting from 1
+    pub fn autosave_slot_name(number: usize) -> String {
+        format!("autosave_{}", number)
+    }
+    
+    /// Slot the next autosave goes to, or None when it is skipped because
+    /// the game is paused (if configured) or another save is still running
+    pub fn next_autosave_slot(&self, paused: bool) -> Option<String> {
+        if self.is_saving() || (self.autosave.skip_while_paused && paused) {
+            return None;
+        }
+        Some(Self::autosave_slot_name(self.next_autosave_slot + 1))
+    }
+    
+    /// Start a background autosave of state captured for
+    /// [`next_autosave_slot`](Self::next_autosave_slot), moving the rotation
+    /// on. Autosaves never change the name used by quick saves.
+    pub fn begin_autosave(&mut self, save_data: SaveData) -> GameResult<()> {
+        let quick_save_name = self.current_save_name.take();
+        let result = self.begin_save(save_data);
+        self.current_save_name = quick_save_name;
+        result?;
+        
+        self.next_autosave_slot = (self.next_autosave_slot + 1) % self.autosave.slots.max(1);
+        Ok(())
+    }
+    
+    /// Whether a background save is still running
+    pub fn is_saving(&self) -> bool {
+        self.pending_save.is_some()
+    }
+    
+    /// Check on a background save and emit its outcome once finished
+    pub fn poll_pending_save(&mut self, events: &mut EventBus) {
+        let outcome = match &self.pending_save {
+            Some(pending) => match pending.result.try_recv() {
+                Ok(result) => result,
+                Err(TryRecvError::Empty) => return,
+                Err(TryRecvError::Disconnected) => Err(Self::save_thread_lost()),
+            },
+            None => return,
+        };
+        self.finish_pending_save(outcome, events);
+    }
+    
+    /// Block until any background save finishes, emitting its outcome
+    pub fn wait_for_pending_save(&mut self, events: &mut EventBus) {
+        let outcome = match &self.pending_save {
+            Some(pending) => pending.result.recv().unwrap_or_else(|_| Err(Self::save_thread_lost())),
+            None => return,
+        };
+        self.finish_pending_save(outcome, events);
+    }
+    
+    fn finish_pending_save(&mut self, outcome: GameResult<()>, events: &mut EventBus) {
+        if let Some(pending) = self.pending_save.take() {
+            let event = match outcome {
+                Ok(()) => SimulationEvent::SaveCompleted { slot: pending.slot_name },
+                Err(e) => SimulationEvent::SaveFailed { slot: pending.slot_name, reason: e.to_string() },
+            };
+            events.queue_event(GameEvent::SimulationEvent(event));
+        }
+    }
+    
+    fn save_thread_lost() -> GameError {
+        GameError::SaveError("Save thread terminated unexpectedly".to_string())
+    }
+    
+    /// Serialize save data, compress it and write it to a save slot
+    fn write_save(file_path: &Path, save_data: &SaveData) -> GameResult<()> {
+        let json = serde_json::to_vec(save_data)
+            .map_err(|e| GameError::SaveError(format!("JSON serialization failed: {}", e)))?;
+        
+        let file = File::create(file_path)
+            .map_err(|e| GameError::SaveError(format!("Could not create save file: {}", e)))?;
+        
+        let mut encoder = GzEncoder::new(file, Compression::default());
+        encoder.write_all(&json)
+            .and_then(|_| encoder.finish())
+            .map_err(|e| GameError::SaveError(format!("Could not write save file: {}", e)))?;
+        
+        Ok(())
+    }
+    
+    /// Write save data to `file_path` as readable, uncompressed JSON
+    fn write_json(file_path: &Path, save_data: &SaveData) -> GameResult<()> {
+        let json = serde_json::to_string_pretty(save_data)
+            .map_err(|e| GameError::SaveError(format!("JSON serialization failed: {}", e)))?;
+        
+        let mut file = File::create(file_path)
+            .map_err(|e| GameError::SaveError(format!("Could not create save file: {}", e)))?;
+        
+        file.write_all(json.as_bytes())
+            .map_err(|e| GameError::SaveError(format!("Could not write save file: {}", e)))?;
+        
+        Ok(())
+    }
+    
+    /// Write the game state to `path` as stable, human-readable JSON.
+    ///
+    /// Exporting the same state twice produces identical files.
+    pub fn export_json(state: &GameState, path: &Path) -> GameResult<()> {
+        let mut save_data = SaveData::capture(state, EXPORT_SAVE_NAME);
+        save_data.timestamp = 0;
+        save_data.planets.sort_by_key(|planet| planet.id);
+        save_data.ships.sort_by_key(|ship| ship.id);
+        save_data.factions.sort_by_key(|faction| faction.id);
+        Self::write_json(path, &save_data)
+    }
+    
+    /// Read and validate game state JSON written by [`SaveSystem::export_json`]
+    /// or by hand; apply it with `GameState::apply_save_data`
+    pub fn import_json(path: &Path) -> GameResult<SaveData> {
+        let json = fs::read_to_string(path)
+            .map_err(|e| GameError::Io(format!("Could not read {}: {}", path.display(), e)))?;
+        let save_data: SaveData = serde_json::from_str(&json)
+            .map_err(|e| GameError::corrupted(path, format!("Invalid game state JSON: {}", e)))?;
+        Self::check_integrity(&save_data, path)?;
+        Ok(save_data)
+    }
+    
+    /// Load game from default save
+    pub fn load_game(&self) -> GameResult<SaveData> {
+        let save_name = "quicksave";
+        self.load_game_from_slot(save_name)
+    }
+    
+    /// Load game from specific named slot
+    pub fn load_game_from_slot(&self, slot_name: &str) -> GameResult<SaveData> {
+        let file_path = self.get_save_path(slot_name);
+        
+        if !file_path.exists() {
+            return Err(GameError::SaveError(format!("Save file '{}' not found", slot_name)));
+        }
+        
+        let mut file = File::open(&file_path)
+            .map_err(|e| GameError::Io(format!("Could not open save file: {}", e)))?;
+        
+        let mut contents = Vec::new();
+        file.read_to_end(&mut contents)
+            .map_err(|e| GameError::Io(format!("Could not read save file: {}", e)))?;
+        
+        // Slots written before saves were compressed hold plain JSON
+        if contents.starts_with(&GZIP_MAGIC) {
+            let mut json = Vec::new();
+            GzDecoder::new(contents.as_slice()).read_to_end(&mut json)
+                .map_err(|e| GameError::corrupted(&file_path, format!("Decompression failed: {}", e)))?;
+            contents = json;
+        }
+        
+        let save_data: SaveData = serde_json::from_slice(&contents)
+            .map_err(|e| GameError::corrupted(&file_path, format!("JSON deserialization failed: {}", e)))?;
+        
+        Self::check_integrity(&save_data, &file_path)?;
+        Ok(save_data)
+    }
+    
+    /// List all available save files
+    pub fn list_saves(&self) -> GameResult<Vec<SaveInfo>> {
+        let mut saves = Vec::new();
+        
+        if !self.save_directory.exists() {
+            return Ok(saves);
+        }
+        
+        let entries = fs::read_dir(&self.save_directory)
+            .map_err(|e| GameError::SaveError(format!("Could not read saves directory: {}", e)))?;
+        
+        for entry in entries {
+            let entry = entry
+                .map_err(|e| GameError::SaveError(format!("Error reading directory entry: {}", e)))?;
+            
+            let path = entry.path();
+            if path.extension().and_then(|s| s.to_str()) == Some("sav") {
+                if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
+                    match self.load_game_from_slot(stem) {
+                        Ok(save_data) => saves.push(SaveInfo::from_save_data(&save_data)),
+                        Err(_) => continue, // Skip corrupted saves
+                    }
+                }
+            }
+        }
+        
+        // Sort by timestamp (newest first)
+        saves.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+        Ok(saves)
+    }
+    
+    /// Reject slot names that are empty or would leave the save directory
+    pub fn validate_slot_name(slot_name: &str) -> GameResult<()> {
+        if slot_name.trim().is_empty() {
+            return Err(GameError::SaveError("Save slot name cannot be empty".to_string()));
+        }
+        if slot_name.contains(['/', '\\']) || slot_name.contains("..") {
+            return Err(GameError::SaveError(format!("Invalid save slot name '{}'", slot_name)));
+        }
+        Ok(())
+    }
+    
+    /// Check if a save file exists
+    pub fn save_exists(&self, slot_name: &str) -> bool {
+        self.get_save_path(slot_name).exists()
+    }
+    
+    /// Delete a save file
+    pub fn delete_save(&self, slot_name: &str) -> GameResult<()> {
+        let file_path = self.get_save_path(slot_name);
+        if file_path.exists() {
+            fs::remove_file(&file_path)
+                .map_err(|e| GameError::SaveError(format!("Could not delete save file: {}", e)))?;
+        }
+        Ok(())
+    }
+    
+    /// Validate save data integrity
+    pub fn validate_save_integrity(&self, save_data: &SaveData) -> GameResult<()> {
+        Self::check_integrity(save_data, &self.get_save_path(&save_data.save_name))
+    }
+    
+    // Every problem is reported as `GameError::SaveCorrupted` for the file at `path`
+    fn check_integrity(save_data: &SaveData, path: &Path) -> GameResult<()> {
+        // Basic validation
+        if save_data.version != 1 {
+            return Err(GameError::corrupted(path, format!("Unsupported save version: {}", save_data.version)));
+        }
+        
+        if save_data.planets.is_empty() {
+            return Err(GameError::corrupted(path, "Save file contains no planets"));
+        }
+        
+        if save_data.factions.is_empty() {
+            return Err(GameError::corrupted(path, "Save file contains no factions"));
+        }
+        save_data.market.validate()
+            .map_err(|e| GameError::corrupted(path, format!("Invalid market state: {}", e)))?;
+        
+        // Validate resource constraints
+        for planet in &save_data.planets {
+            planet.population.allocation.validate(planet.population.total)
+                .and_then(|_| planet.resources.validate())
+                .map_err(|e| GameError::corrupted(path, format!("Planet {}: {}", planet.id, e)))?;
+        }
+        
+        // Ids are unique and every owner exists
+        let factions: BTreeSet<FactionId> = save_data.factions.iter().map(|faction| faction.id).collect();
+        let planets: BTreeSet<PlanetId> = save_data.planets.iter().map(|planet| planet.id).collect();
+        let ships: BTreeSet<ShipId> = save_data.ships.iter().map(|ship| ship.id).collect();
+        if factions.len() != save_data.factions.len() || planets.len() != save_data.planets.len() || ships.len() != save_data.ships.len() {
+            return Err(GameError::corrupted(path, "Save file contains duplicate ids"));
+        }
+        for planet in &save_data.planets {
+            if let Some(controller) = planet.controller.filter(|controller| !factions.contains(controller)) {
+                return Err(GameError::corrupted(path, format!("Planet {} is controlled by unknown faction {}", planet.id, controller)));
+            }
+        }
+        for ship in &save_data.ships {
+            if !factions.contains(&ship.owner) {
+                return Err(GameError::corrupted(path, format!("Ship {} is owned by unknown faction {}", ship.id, ship.owner)));
+            }
+        }
+        for route in &save_data.trade_routes {
+            if !ships.contains(&route.ship) || !planets.contains(&route.from) || !planets.contains(&route.to) {
+                return Err(GameError::corrupted(path, format!("Trade route of ship {} names an unknown ship or planet", route.ship)));
+            }
+        }
+        
+        Ok(())
+    }
+    
+    /// Get the file path for a save slot
+    /// Report background saves once they finish
+    pub fn update(&mut self, _delta: f32, events: &mut EventBus) -> GameResult<()> {
+        self.poll_pending_save(events);
+        Ok(())
+    }
+    
+    fn get_save_path(&self, slot_name: &str) -> PathBuf {
+        self.save_directory.join(format!("{}.sav", slot_name))
+    }
+}
+
+impl GameSystem for SaveSystem {
+    fn update(&mut self, delta: f32, context: &mut SystemContext<'_>) -> GameResult<()> {
+        self.update(delta, context.event_bus())
+    }
+    
+    fn handle_event(&mut self, event: &GameEvent) -> GameResult<()> {
+        // Save system responds to save/load commands but doesn't handle them directly
+        // The GameState handles these commands and calls the save system methods
+        match event {
+            GameEvent::PlayerCommand(PlayerCommand::SaveGame) |
+            GameEvent::PlayerCommand(PlayerCommand::LoadGame) => {
+                // These are handled by GameState, not here
+                Ok(())
+            }
+            _ => Ok(())
+        }
+    }
+}
+
+impl Default for SaveSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,623 @@
+// src/systems/scenario.rs
+//! Hand-made starting situations loaded from scenario files
+//!
+//! A [`Scenario`] describes a game declaratively: its factions, planets and
+//! ships, the conditions that win it, and events scripted for given ticks.
+//! Scenario files are JSON. Factions, planets and ships are numbered by their
+//! position in the file, starting at 0, and refer to each other by those ids.
+//!
+//! [`ScenarioLoader`] checks a scenario and builds its world through the
+//! managers. Worlds described in code with `GameStateBuilder` are built the
+//! same way. While the game runs, [`ScenarioSystem`] queues each scripted
+//! event when its tick comes up and announces the first faction to meet a
+//! victory condition. In every game, scenario or generated, it also announces
+//! factions that lose the last of their planets and ships, and a conquest
+//! victory for the last faction left. Victory conditions and scripts live only
+//! in the running game; saves keep the world, not the scenario.
+
+use crate::core::{GameResult, GameEvent, EventBus, GameSystem, SystemContext, PlayerCommand};
+use crate::core::events::{SimulationEvent, StateChange};
+use crate::core::types::*;
+use crate::managers::{PlanetManager, ShipManager, FactionManager};
+use super::GameInitializer;
+use super::objectives::{Objective, ObjectiveSystem};
+use super::start_generator::orbit_for;
+use serde::{Serialize, Deserialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+
+/// Scenario file format version
+pub const SCENARIO_VERSION: u32 = 1;
+
+/// A complete starting situation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Scenario {
+    /// Scenario file format version
+    pub version: u32,
+    /// Name shown in the scenario list
+    pub name: String,
+    /// Briefing shown before the scenario starts
+    #[serde(default)]
+    pub description: String,
+    /// Settings recorded with the game; the world itself comes from the scenario
+    #[serde(default)]
+    pub configuration: GameConfiguration,
+    /// Factions; the first is faction 0
+    pub factions: Vec<ScenarioFaction>,
+    /// Planets; the first is planet 0
+    pub planets: Vec<ScenarioPlanet>,
+    /// Ships placed at the start
+    #[serde(default)]
+    pub ships: Vec<ScenarioShip>,
+    /// Checked in order every tick; the first one met decides the game
+    #[serde(default)]
+    pub victory_conditions: Vec<VictoryCondition>,
+    /// Actions taken at fixed ticks
+    #[serde(default)]
+    pub scripted_events: Vec<ScriptedEvent>,
+    /// Objectives the player faction works through, in order
+    #[serde(default)]
+    pub objectives: Vec<Objective>,
+}
+
+/// A faction as it starts the scenario
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScenarioFaction {
+    /// Faction name
+    pub name: String,
+    /// True for the human player's faction
+    #[serde(default)]
+    pub is_player: bool,
+    /// How the faction plays when the AI controls it
+    #[serde(default = "default_personality")]
+    pub ai_type: AIPersonality,
+    /// Technologies known from the start
+    #[serde(default)]
+    pub technologies: BTreeSet<Technology>,
+}
+
+/// A planet on a circular orbit
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScenarioPlanet {
+    /// Orbit radius in AU
+    pub semi_major_axis: f32,
+    /// Starting angle along the orbit, in radians
+    #[serde(default)]
+    pub phase: f32,
+    /// Faction settled on the planet, if any
+    #[serde(default)]
+    pub controller: Option<FactionId>,
+    /// Starting stockpile
+    #[serde(default)]
+    pub resources: ResourceBundle,
+    /// Starting population; settled planets get the usual worker allocation
+    #[serde(default)]
+    pub population: i32,
+    /// Planet size, which sets its housing and building slots
+    #[serde(default = "default_planet_size")]
+    pub size: u8,
+    /// Environment; ordinary when left out
+    #[serde(default)]
+    pub traits: PlanetTraits,
+    /// Tier 1 buildings standing at the start
+    #[serde(default)]
+    pub buildings: Vec<BuildingType>,
+}
+
+impl ScenarioFaction {
+    /// A faction with a balanced AI and no technologies
+    pub fn new(name: impl Into<String>, is_player: bool) -> Self {
+        Self { name: name.into(), is_player, ai_type: default_personality(), technologies: BTreeSet::new() }
+    }
+}
+
+impl ScenarioPlanet {
+    /// An unsettled, ordinary planet of the default size at angle 0 on an
+    /// orbit of the given radius in AU
+    pub fn new(semi_major_axis: f32) -> Self {
+        Self {
+            semi_major_axis,
+            phase: 0.0,
+            controller: None,
+            resources: ResourceBundle::default(),
+            population: 0,
+            size: default_planet_size(),
+            traits: PlanetTraits::default(),
+            buildings: Vec::new(),
+        }
+    }
+}
+
+/// A ship starting next to a planet
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScenarioShip {
+    /// Class of the ship
+    pub ship_class: ShipClass,
+    /// Faction that owns it
+    pub owner: FactionId,
+    /// Planet it starts next to
+    pub planet: PlanetId,
+}
+
+/// What a faction must achieve to win the scenario
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum VictoryCondition {
+    /// Control at least this many planets
+    ControlPlanets(usize),
+    /// Hold at least this much of every resource across controlled planets
+    Stockpile(ResourceBundle),
+    /// Reach this population across controlled planets
+    Population(i32),
+    /// Once the tick is reached, the faction controlling the most planets wins
+    Timeout(u64),
+}
+
+impl VictoryCondition {
+    /// Kind of victory the condition stands for
+    pub fn victory_type(&self) -> VictoryType {
+        match self {
+            VictoryCondition::ControlPlanets(_) => VictoryType::Military,
+            VictoryCondition::Stockpile(_) => VictoryType::Economic,
+            VictoryCondition::Population(_) => VictoryType::Population,
+            VictoryCondition::Timeout(_) => VictoryType::Timeout,
+        }
+    }
+}
+
+/// Something the scenario makes happen at a given tick
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScriptedEvent {
+    /// Queued before the tick after this one is simulated
+    pub tick: u64,
+    /// What happens
+    pub action: ScriptedAction,
+}
+
+/// What a scripted event does
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ScriptedAction {
+    /// Issue a command, validated like any other
+    Command(PlayerCommand),
+    /// Put a message in a faction's inbox
+    Message {
+        /// Faction receiving the message
+        to: FactionId,
+        /// The message
+        message: InboxMessage,
+    },
+}
+
+/// A faction's holdings, as measured for victory conditions
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FactionStanding {
+    /// Faction measured
+    pub faction: FactionId,
+    /// Planets it controls
+    pub planets: usize,
+    /// Population across its planets
+    pub population: i32,
+    /// Resources stockpiled across its planets
+    pub resources: ResourceBundle,
+    /// Ships in service
+    pub ships: usize,
+}
+
+impl FactionStanding {
+    /// Every scoring faction's standing, by faction id; non-scoring factions
+    /// such as the pirates are left out
+    pub fn measure(planets: &PlanetManager, ships: &ShipManager, factions: &FactionManager) -> Vec<FactionStanding> {
+        let mut fleets: BTreeMap<FactionId, usize> = BTreeMap::new();
+        for ship in ships.get_all_ships() {
+            *fleets.entry(ship.owner).or_default() += 1;
+        }
+        factions.scoring_factions()
+            .map(|faction| {
+                let mut standing = FactionStanding {
+                    faction: faction.id,
+                    planets: 0,
+                    population: 0,
+                    resources: ResourceBundle::default(),
+                    ships: fleets.get(&faction.id).copied().unwrap_or(0),
+                };
+                for planet in planets.get_planets_by_faction(faction.id) {
+                    standing.planets += 1;
+                    standing.population = standing.population.saturating_add(planet.population.total);
+                    for resource in ResourceType::ALL {
+                        let amount = standing.resources.get(resource).saturating_add(planet.resources.current.get(resource));
+                        standing.resources.set(resource, amount);
+                    }
+                }
+                standing
+            })
+            .collect()
+    }
+}
+
+fn default_personality() -> AIPersonality {
+    AIPersonality::Balanced
+}
+
+fn default_planet_size() -> u8 {
+    DEFAULT_PLANET_SIZE
+}
+
+/// Reads, checks and builds scenarios
+pub struct ScenarioLoader;
+
+impl ScenarioLoader {
+    /// Read and check a scenario file
+    pub fn load_from_file(path: &Path) -> GameResult<Scenario> {
+        let json = fs::read_to_string(path)
+            .map_err(|e| GameError::Io(format!("Could not read scenario file {}: {}", path.display(), e)))?;
+        let scenario: Scenario = serde_json::from_str(&json)
+            .map_err(|e| GameError::corrupted(path, format!("Scenario deserialization failed: {}", e)))?;
+        Self::validate(&scenario)?;
+        Ok(scenario)
+    }
+
+    /// Check that every reference in the scenario points at something that exists
+    pub fn validate(scenario: &Scenario) -> GameResult<()> {
+        if scenario.version != SCENARIO_VERSION {
+            return Err(GameError::SaveError(format!("Unsupported scenario version {}", scenario.version)));
+        }
+        if scenario.factions.is_empty() || scenario.planets.is_empty() {
+            return Err(GameError::SaveError("A scenario needs at least one faction and one planet".to_string()));
+        }
+        if scenario.factions.iter().filter(|faction| faction.is_player).count() > 1 {
+            return Err(GameError::SaveError("A scenario can have only one player faction".to_string()));
+        }
+
+        let faction_count = scenario.factions.len();
+        let known_faction = |faction: FactionId| (faction as usize) < faction_count;
+        for (id, planet) in scenario.planets.iter().enumerate() {
+            if let Some(controller) = planet.controller.filter(|controller| !known_faction(*controller)) {
+                return Err(GameError::SaveError(format!("Planet {} is controlled by unknown faction {}", id, controller)));
+            }
+            planet.resources.validate_non_negative()?;
+            planet.traits.validate()?;
+            if planet.population < 0 {
+                return Err(GameError::SaveError(format!("Planet {} has a negative population", id)));
+            }
+            if planet.population > 0 && planet.controller.is_none() {
+                return Err(GameError::SaveError(format!("Planet {} needs a controller for its population", id)));
+            }
+        }
+        for (id, ship) in scenario.ships.iter().enumerate() {
+            if !known_faction(ship.owner) || ship.planet as usize >= scenario.planets.len() {
+                return Err(GameError::SaveError(format!("Ship {} refers to an unknown faction or planet", id)));
+            }
+        }
+        for scripted in &scenario.scripted_events {
+            if let ScriptedAction::Message { to, .. } = &scripted.action {
+                if !known_faction(*to) {
+                    return Err(GameError::SaveError(format!("Scripted message at tick {} is for unknown faction {}", scripted.tick, to)));
+                }
+            }
+        }
+        if !scenario.objectives.is_empty() && !scenario.factions.iter().any(|faction| faction.is_player) {
+            return Err(GameError::SaveError("Scenario objectives need a player faction".to_string()));
+        }
+        ObjectiveSystem::validate(&scenario.objectives)?;
+        Ok(())
+    }
+
+    /// Replace the managers' contents with the scenario's world
+    pub fn build(
+        scenario: &Scenario,
+        planet_manager: &mut PlanetManager,
+        ship_manager: &mut ShipManager,
+        faction_manager: &mut FactionManager,
+    ) -> GameResult<()> {
+        Self::validate(scenario)?;
+        *planet_manager = PlanetManager::new();
+        ship_manager.clear();
+        *faction_manager = FactionManager::new();
+
+        for spec in &scenario.factions {
+            let id = faction_manager.create_faction(spec.name.clone(), spec.is_player, spec.ai_type)?;
+            for technology in &spec.technologies {
+                faction_manager.unlock_technology(id, *technology)?;
+            }
+        }
+
+        for spec in &scenario.planets {
+            let id = planet_manager.create_planet(orbit_for(spec.semi_major_axis, spec.phase), spec.controller)?;
+            let (size, traits) = (spec.size, spec.traits);
+            planet_manager.modify_planet(id, |planet| {
+                planet.size = size;
+                planet.traits = traits;
+                Ok(())
+            })?;
+            planet_manager.add_resources(id, spec.resources)?;
+            if spec.population > 0 {
+                planet_manager.update_population(id, spec.population)?;
+            }
+            for building in &spec.buildings {
+                planet_manager.add_building(id, *building)?;
+            }
+        }
+        GameInitializer::assign_starting_workers(planet_manager)?;
+
+        for spec in &scenario.ships {
+            let position = planet_manager.get_planet(spec.planet)?.position.position_at(0);
+            ship_manager.create_ship(spec.ship_class, position, spec.owner)?;
+        }
+        Ok(())
+    }
+}
+
+/// Runs a scenario's scripted events and watches its victory conditions
+pub struct ScenarioSystem {
+    scripted_events: Vec<ScriptedEvent>,
+    victory_conditions: Vec<VictoryCondition>,
+    /// Index of the next scripted event to queue
+    cursor: usize,
+    winner: Option<(FactionId, VictoryType)>,
+    /// Factions that have held a planet or ship at some point
+    established: BTreeSet<FactionId>,
+    /// Factions that have since lost everything
+    eliminated: BTreeSet<FactionId>,
+    pending: Vec<StateChange>,
+}
+
+impl ScenarioSystem {
+    /// A system with nothing scripted, as in a generated game
+    pub fn new() -> Self {
+        Self {
+            scripted_events: Vec::new(),
+            victory_conditions: Vec::new(),
+            cursor: 0,
+            winner: None,
+            established: BTreeSet::new(),
+            eliminated: BTreeSet::new(),
+            pending: Vec::new(),
+        }
+    }
+
+    /// Take over the scenario's scripted events and victory conditions
+    pub fn start(&mut self, scenario: &Scenario) {
+        *self = Self::new();
+        self.scripted_events = scenario.scripted_events.clone();
+        self.scripted_events.sort_by_key(|scripted| scripted.tick);
+        self.victory_conditions = scenario.victory_conditions.clone();
+    }
+
+    /// Faction that won and how, once a victory condition has been met
+    pub fn winner(&self) -> Option<(FactionId, VictoryType)> {
+        self.winner
+    }
+
+    /// True once the faction has lost the last of its planets and ships
+    pub fn is_eliminated(&self, faction: FactionId) -> bool {
+        self.eliminated.contains(&faction)
+    }
+
+    /// Queue every scripted event due by `tick`, in tick order
+    pub fn inject_due_events(&mut self, tick: u64, event_bus: &mut EventBus) {
+        while let Some(scripted) = self.scripted_events.get(self.cursor) {
+            if scripted.tick > tick {
+                break;
+            }
+            let event = match &scripted.action {
+                ScriptedAction::Command(command) => GameEvent::PlayerCommand(command.clone()),
+                ScriptedAction::Message { to, message } => GameEvent::SimulationEvent(
+                    SimulationEvent::InboxMessageSent { to: *to, message: message.clone() }
+                ),
+            };
+            event_bus.queue_event(event);
+            self.cursor += 1;
+        }
+    }
+
+    /// Check eliminations and the victory conditions against every faction's standing.
+    ///
+    /// A faction is eliminated when it holds neither planets nor ships after
+    /// having held either; factions that never held anything are left alone.
+    /// When only one faction of several is left, it wins by conquest.
+    /// Conditions are tried in order and factions by id, so the result is
+    /// the same on every machine. Once someone has won, only eliminations
+    /// are still checked.
+    pub fn check_victory(&mut self, tick: u64, standings: &[FactionStanding]) {
+        for standing in standings {
+            if standing.planets > 0 || standing.ships > 0 {
+                self.established.insert(standing.faction);
+            } else if self.established.contains(&standing.faction) && self.eliminated.insert(standing.faction) {
+                self.pending.push(StateChange::GameOver(standing.faction));
+            }
+        }
+        if self.winner.is_some() {
+            return;
+        }
+        let mut remaining = standings.iter().filter(|standing| !self.eliminated.contains(&standing.faction));
+        if let (Some(last), None) = (remaining.next(), remaining.next()) {
+            if standings.len() > 1 {
+                self.winner = Some((last.faction, VictoryType::Conquest));
+                self.pending.push(StateChange::VictoryConditionMet { faction: last.faction, victory: VictoryType::Conquest });
+                return;
+            }
+        }
+        let winner = self.victory_conditions.iter().find_map(|condition| {
+            let faction = match condition {
+                VictoryCondition::ControlPlanets(count) => standings.iter()
+                    .find(|standing| standing.planets >= *count)
+                    .map(|standing| standing.faction),
+                VictoryCondition::Stockpile(target) => standings.iter()
+                    .find(|standing| standing.resources.can_afford(target))
+                    .map(|standing| standing.faction),
+                VictoryCondition::Population(target) => standings.iter()
+                    .find(|standing| standing.population >= *target)
+                    .map(|standing| standing.faction),
+                VictoryCondition::Timeout(deadline) if tick >= *deadline => standings.iter()
+                    .min_by_key(|standing| (std::cmp::Reverse(standing.planets), standing.faction))
+                    .map(|standing| standing.faction),
+                VictoryCondition::Timeout(_) => None,
+            };
+            faction.map(|faction| (faction, condition.victory_type()))
+        });
+        if let Some((faction, victory)) = winner {
+            self.winner = Some((faction, victory));
+            self.pending.push(StateChange::VictoryConditionMet { faction, victory });
+        }
+    }
+
+    /// Announces eliminations and a victory decided since the last update
+    pub fn update(&mut self, _delta: f32, event_bus: &mut EventBus) -> GameResult<()> {
+        for change in self.pending.drain(..) {
+            event_bus.queue_event(GameEvent::StateChanged(change));
+        }
+        Ok(())
+    }
+
+    /// Standings are measured at each update, from the context
+    pub fn handle_event(&mut self, _event: &GameEvent) -> GameResult<()> {
+        Ok(())
+    }
+}
+
+impl Default for ScenarioSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GameSystem for ScenarioSystem {
+    fn update(&mut self, delta: f32, context: &mut SystemContext<'_>) -> GameResult<()> {
+        // Victory is judged on what each faction held when the last tick ended
+        if context.tick() > 0 {
+            self.check_victory(context.tick(), &context.faction_standings());
+        }
+        self.update(delta, context.event_bus())
+    }
+
+    fn handle_event(&mut self, event: &GameEvent) -> GameResult<()> {
+        self.handle_event(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::objectives::ObjectiveGoal;
+
+    fn scenario() -> Scenario {
+        Scenario {
+            version: SCENARIO_VERSION,
+            name: "Border Dispute".to_string(),
+            description: String::new(),
+            configuration: GameConfiguration::default(),
+            factions: vec![
+                ScenarioFaction { name: "Settlers".to_string(), is_player: true, ai_type: AIPersonality::Balanced, technologies: BTreeSet::new() },
+                ScenarioFaction { name: "Raiders".to_string(), is_player: false, ai_type: AIPersonality::Aggressive, technologies: [Technology::Military].into_iter().collect() },
+            ],
+            planets: vec![
+                ScenarioPlanet { semi_major_axis: 2.0, phase: 0.0, controller: Some(0), resources: ResourceBundle { minerals: 300, ..Default::default() }, population: 1000, size: 4, traits: PlanetTraits::default(), buildings: vec![BuildingType::Mine] },
+                ScenarioPlanet { semi_major_axis: 4.0, phase: 1.0, controller: Some(1), resources: ResourceBundle::default(), population: 500, size: 3, traits: PlanetTraits::default(), buildings: Vec::new() },
+                ScenarioPlanet { semi_major_axis: 3.0, phase: 2.0, controller: None, resources: ResourceBundle::default(), population: 0, size: 3, traits: PlanetTraits::default(), buildings: Vec::new() },
+            ],
+            ships: vec![ScenarioShip { ship_class: ShipClass::Warship, owner: 1, planet: 1 }],
+            victory_conditions: vec![VictoryCondition::ControlPlanets(2), VictoryCondition::Timeout(100)],
+            scripted_events: Vec::new(),
+            objectives: Vec::new(),
+        }
+    }
+
+    fn standing(faction: FactionId, planets: usize) -> FactionStanding {
+        FactionStanding { faction, planets, population: 0, resources: ResourceBundle::default(), ships: 0 }
+    }
+
+    #[test]
+    fn test_build_creates_the_described_world() {
+        let (mut planets, mut ships, mut factions) = (PlanetManager::new(), ShipManager::new(), FactionManager::new());
+        ScenarioLoader::build(&scenario(), &mut planets, &mut ships, &mut factions).unwrap();
+
+        assert_eq!(factions.count(), 2);
+        assert!(factions.get_faction(1).unwrap().has_technology(Technology::Military));
+        let home = planets.get_planet(0).unwrap();
+        assert_eq!((home.size, home.population.total, home.resources.current.minerals), (4, 1000, 300));
+        assert_eq!(home.developments.len(), 1);
+        assert!(home.population.allocation.agriculture > 0, "Settled planets get workers assigned");
+        assert_eq!(planets.get_planet(2).unwrap().controller, None);
+        assert_eq!(ships.get_ship(0).unwrap().owner, 1);
+    }
+
+    #[test]
+    fn test_validate_rejects_dangling_references() {
+        let mut orphaned = scenario();
+        orphaned.ships[0].owner = 5;
+        assert!(ScenarioLoader::validate(&orphaned).is_err());
+
+        let mut unknown_controller = scenario();
+        unknown_controller.planets[2].controller = Some(2);
+        assert!(ScenarioLoader::validate(&unknown_controller).is_err());
+
+        let mut two_players = scenario();
+        two_players.factions[1].is_player = true;
+        assert!(ScenarioLoader::validate(&two_players).is_err());
+
+        let mut unowned_objectives = scenario();
+        unowned_objectives.objectives = vec![Objective::new("Hold two worlds", ObjectiveGoal::ControlPlanets(2))];
+        assert!(ScenarioLoader::validate(&unowned_objectives).is_ok());
+        unowned_objectives.factions[0].is_player = false;
+        assert!(ScenarioLoader::validate(&unowned_objectives).is_err());
+    }
+
+    #[test]
+    fn test_first_condition_met_decides_the_game_once() {
+        let mut system = ScenarioSystem::new();
+        system.start(&scenario());
+
+        system.check_victory(10, &[standing(0, 1), standing(1, 1)]);
+        assert_eq!(system.winner(), None);
+        system.check_victory(100, &[standing(0, 1), standing(1, 1)]);
+        assert_eq!(system.winner(), Some((0, VictoryType::Timeout)), "Ties go to the lowest faction id");
+        system.check_victory(101, &[standing(0, 1), standing(1, 3)]);
+        assert_eq!(system.winner(), Some((0, VictoryType::Timeout)));
+
+        let mut bus = EventBus::new();
+        system.update(0.1, &mut bus).unwrap();
+        assert_eq!(bus.queued_events.len(), 1);
+    }
+
+    #[test]
+    fn test_last_faction_standing_wins_by_conquest() {
+        let mut system = ScenarioSystem::new();
+        let empty = |faction| standing(faction, 0);
+
+        // Faction 2 never held anything, so it is not counted as eliminated
+        system.check_victory(1, &[standing(0, 1), standing(1, 2), empty(2)]);
+        system.check_victory(2, &[standing(0, 1), empty(1), empty(2)]);
+        assert!(system.is_eliminated(1));
+        assert!(!system.is_eliminated(2));
+        assert_eq!(system.winner(), None);
+
+        system.check_victory(3, &[standing(0, 1), empty(1), standing(2, 1)]);
+        system.check_victory(4, &[standing(0, 3), empty(1), empty(2)]);
+        assert_eq!(system.winner(), Some((0, VictoryType::Conquest)));
+
+        let mut bus = EventBus::new();
+        system.update(0.1, &mut bus).unwrap();
+        assert_eq!(bus.queued_events.len(), 3, "Two eliminations and the victory");
+    }
+
+    #[test]
+    fn test_scripted_events_are_queued_when_due() {
+        let mut with_script = scenario();
+        let prompt = InboxMessage::TutorialPrompt { title: "Reinforcements".to_string(), text: "Help is on the way".to_string() };
+        with_script.scripted_events = vec![
+            ScriptedEvent { tick: 20, action: ScriptedAction::Message { to: 0, message: prompt } },
+            ScriptedEvent { tick: 5, action: ScriptedAction::Command(PlayerCommand::DeclareWar { from: 1, to: 0 }) },
+        ];
+        let mut system = ScenarioSystem::new();
+        system.start(&with_script);
+
+        let mut bus = EventBus::new();
+        system.inject_due_events(4, &mut bus);
+        assert!(bus.queued_events.is_empty());
+        system.inject_due_events(10, &mut bus);
+        assert_eq!(bus.queued_events.len(), 1);
+        assert!(matches!(bus.queued_events.front(), Some(GameEvent::PlayerCommand(PlayerCommand::DeclareWar { from: 1, to: 0 }))));
+        system.inject_due_events(30, &mut bus);
+        assert_eq!(bus.queued_events.len(), 2);
+    }
+}
@@ -0,0 +1,191 @@
+// src/systems/spatial_index.rs
+//! Uniform grid over planet and ship positions for proximity queries
+//!
+//! PhysicsEngine keeps a [`SpatialIndex`] of where every planet and ship was
+//! at the end of the latest tick's movement; GameState rebuilds it then, and
+//! whenever a world is started or loaded. Sensors, combat engagement checks
+//! and map picking ask it for what lies within a radius instead of scanning
+//! every entity. Query results are sorted by entity, planets before ships,
+//! so they never depend on hashing order.
+
+use crate::core::types::*;
+use std::collections::HashMap;
+
+/// Side of a grid cell in AU; about the longest sensor range, so most
+/// queries touch a handful of cells
+pub const SPATIAL_CELL_SIZE: f32 = 5.0;
+
+/// A planet or ship in the index
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SpatialEntity {
+    /// A planet, at its orbital position
+    Planet(PlanetId),
+    /// A ship, wherever it flies or orbits
+    Ship(ShipId),
+}
+
+/// Planets and ships bucketed by grid cell
+#[derive(Debug, Clone)]
+pub struct SpatialIndex {
+    cell_size: f32,
+    cells: HashMap<(i32, i32), Vec<(SpatialEntity, Vector2)>>,
+    len: usize,
+    /// Tick the positions were taken at
+    tick: u64,
+}
+
+impl SpatialIndex {
+    /// Empty index with cells of the given size, in AU
+    pub fn new(cell_size: f32) -> Self {
+        Self {
+            cell_size: if cell_size.is_finite() && cell_size > 0.0 { cell_size } else { SPATIAL_CELL_SIZE },
+            cells: HashMap::new(),
+            len: 0,
+            tick: 0,
+        }
+    }
+
+    /// Replace the contents with the given positions, taken at `tick`
+    pub fn rebuild(&mut self, tick: u64, planets: &[(PlanetId, Vector2)], ships: &[(ShipId, Vector2)]) {
+        self.clear();
+        self.tick = tick;
+        for (planet, position) in planets {
+            self.insert(SpatialEntity::Planet(*planet), *position);
+        }
+        for (ship, position) in ships {
+            self.insert(SpatialEntity::Ship(*ship), *position);
+        }
+    }
+
+    /// Add an entity; positions with non-finite coordinates are left out
+    pub fn insert(&mut self, entity: SpatialEntity, position: Vector2) {
+        if !position.x.is_finite() || !position.y.is_finite() {
+            return;
+        }
+        self.cells.entry(self.cell_of(position)).or_default().push((entity, position));
+        self.len += 1;
+    }
+
+    /// Remove everything
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.len = 0;
+    }
+
+    /// Tick the indexed positions were taken at
+    pub fn tick(&self) -> u64 {
+        self.tick
+    }
+
+    /// Number of entities indexed
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// True when nothing is indexed
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Every entity within `radius` of `position`, with its position,
+    /// sorted by entity
+    pub fn entities_within(&self, position: Vector2, radius: f32) -> Vec<(SpatialEntity, Vector2)> {
+        if radius.is_nan() || radius < 0.0 || !position.x.is_finite() || !position.y.is_finite() {
+            return Vec::new();
+        }
+        let (min_x, min_y) = self.cell_of(Vector2::new(position.x - radius, position.y - radius));
+        let (max_x, max_y) = self.cell_of(Vector2::new(position.x + radius, position.y + radius));
+        let in_range = |(_, at): &&(SpatialEntity, Vector2)| at.distance_to(&position) <= radius;
+        let mut found = Vec::new();
+        let covered = (max_x as i64 - min_x as i64 + 1) * (max_y as i64 - min_y as i64 + 1);
+        if covered > self.cells.len() as i64 {
+            // Wider than the occupied cells: visit those instead
+            for cell in self.cells.values() {
+                found.extend(cell.iter().filter(in_range));
+            }
+        } else {
+            for x in min_x..=max_x {
+                for y in min_y..=max_y {
+                    if let Some(cell) = self.cells.get(&(x, y)) {
+                        found.extend(cell.iter().filter(in_range));
+                    }
+                }
+            }
+        }
+        found.sort_by_key(|(entity, _)| *entity);
+        found
+    }
+
+    /// Planets within `radius` of `position`, by id
+    pub fn planets_within(&self, position: Vector2, radius: f32) -> Vec<(PlanetId, Vector2)> {
+        self.entities_within(position, radius).into_iter()
+            .filter_map(|(entity, at)| match entity {
+                SpatialEntity::Planet(planet) => Some((planet, at)),
+                SpatialEntity::Ship(_) => None,
+            })
+            .collect()
+    }
+
+    /// Ships within `radius` of `position`, by id
+    pub fn ships_within(&self, position: Vector2, radius: f32) -> Vec<(ShipId, Vector2)> {
+        self.entities_within(position, radius).into_iter()
+            .filter_map(|(entity, at)| match entity {
+                SpatialEntity::Ship(ship) => Some((ship, at)),
+                SpatialEntity::Planet(_) => None,
+            })
+            .collect()
+    }
+
+    fn cell_of(&self, position: Vector2) -> (i32, i32) {
+        // Saturating casts keep far-off positions in the outermost cells
+        ((position.x / self.cell_size).floor() as i32, (position.y / self.cell_size).floor() as i32)
+    }
+}
+
+impl Default for SpatialIndex {
+    fn default() -> Self {
+        Self::new(SPATIAL_CELL_SIZE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_queries_match_a_linear_scan() {
+        let planets: Vec<(PlanetId, Vector2)> = (0..40)
+            .map(|i| (i, Vector2::new((i as f32 * 2.7) % 31.0 - 15.0, (i as f32 * 4.1) % 23.0 - 11.0)))
+            .collect();
+        let ships: Vec<(ShipId, Vector2)> = (0..60)
+            .map(|i| (i, Vector2::new((i as f32 * 1.3) % 29.0 - 14.0, (i as f32 * 3.7) % 27.0 - 13.0)))
+            .collect();
+        let mut index = SpatialIndex::new(3.0);
+        index.rebuild(7, &planets, &ships);
+        assert_eq!(index.len(), 100);
+        assert_eq!(index.tick(), 7);
+
+        for (center, radius) in [(Vector2::new(0.0, 0.0), 4.0), (Vector2::new(-12.5, 9.0), 6.5), (Vector2::new(3.0, -3.0), 0.0)] {
+            let expected: Vec<PlanetId> = planets.iter()
+                .filter(|(_, position)| position.distance_to(&center) <= radius)
+                .map(|(id, _)| *id)
+                .collect();
+            let found: Vec<PlanetId> = index.planets_within(center, radius).into_iter().map(|(id, _)| id).collect();
+            assert_eq!(found, expected);
+
+            let expected: Vec<ShipId> = ships.iter()
+                .filter(|(_, position)| position.distance_to(&center) <= radius)
+                .map(|(id, _)| *id)
+                .collect();
+            let found: Vec<ShipId> = index.ships_within(center, radius).into_iter().map(|(id, _)| id).collect();
+            assert_eq!(found, expected);
+        }
+
+        // Planets sort before ships; bad input finds nothing
+        let all = index.entities_within(Vector2::new(0.0, 0.0), 100.0);
+        assert_eq!(all.len(), 100);
+        assert!(matches!(all[0].0, SpatialEntity::Planet(0)) && matches!(all[99].0, SpatialEntity::Ship(59)));
+        assert!(index.entities_within(Vector2::new(f32::NAN, 0.0), 5.0).is_empty());
+        assert!(index.entities_within(Vector2::new(0.0, 0.0), -1.0).is_empty());
+    }
+}
@@ -0,0 +1,374 @@
+// src/systems/start_generator.rs
+//! Balanced starting layout generation
+//!
+//! Produces home planets for every faction with comparable starting resources,
+//! a minimum spacing between homes and a colonizable neutral planet close to
+//! each home. Layouts are deterministic for a given seed; a candidate that fails
+//! validation is discarded and the next seed is tried.
+
+use crate::core::types::*;
+use crate::core::StarMap;
+use crate::managers::PlanetManager;
+use std::f32::consts::PI;
+pub(crate) use crate::core::rng::{next_seed, unit};
+
+/// Number of candidate layouts tried before giving up
+const MAX_ATTEMPTS: u32 = 32;
+/// Maximum ratio between the richest and poorest home planet's resource totals
+const MAX_RESOURCE_IMBALANCE: f64 = 1.10;
+/// Maximum distance from a home planet to its guaranteed colonization target
+const NEARBY_COLONY_DISTANCE: f32 = 2.0;
+/// Per-resource random variation applied to home planet stockpiles
+const RESOURCE_JITTER: f32 = 0.1;
+
+/// A planet to be created by the layout
+#[derive(Debug, Clone)]
+pub struct PlannedPlanet {
+    /// Orbit the planet is created on
+    pub orbit: OrbitalElements,
+    /// Owning faction for home planets, `None` for neutral planets
+    pub controller: Option<FactionId>,
+    /// Starting stockpile; neutral planets may hold an unclaimed deposit
+    pub resources: ResourceBundle,
+    /// Starting population (home planets only)
+    pub population: i32,
+    /// Planet size, which sets its housing capacity
+    pub size: u8,
+    /// Environment; home planets and their colonization targets are ordinary
+    pub traits: PlanetTraits,
+    /// Star system the planet orbits; home planets orbit the first star
+    pub system: StarSystemId,
+    /// Name the planet starts with; the galaxy generator names every planet
+    pub name: Option<String>,
+}
+
+impl PlannedPlanet {
+    /// Position of the planet at its starting phase
+    pub fn position(&self) -> Vector2 {
+        Vector2::new(
+            self.orbit.semi_major_axis * self.orbit.phase.cos(),
+            self.orbit.semi_major_axis * self.orbit.phase.sin(),
+        )
+    }
+}
+
+/// A generated starting layout. Home planets come first, ordered by faction id.
+#[derive(Debug, Clone)]
+pub struct StartLayout {
+    /// Seed that produced this layout
+    pub seed: u64,
+    /// Planets to create, in creation order
+    pub planets: Vec<PlannedPlanet>,
+    /// Star systems the planets orbit and the jump lanes between them
+    pub star_map: StarMap,
+}
+
+impl StartLayout {
+    /// Home planets, one per faction
+    pub fn homes(&self) -> impl Iterator<Item = &PlannedPlanet> {
+        self.planets.iter().filter(|p| p.controller.is_some())
+    }
+
+    /// Neutral planets available for colonization
+    pub fn neutrals(&self) -> impl Iterator<Item = &PlannedPlanet> {
+        self.planets.iter().filter(|p| p.controller.is_none())
+    }
+}
+
+/// StartGenerator builds balanced, difficulty-scaled starting positions
+pub struct StartGenerator {
+    difficulty: Difficulty,
+    faction_count: usize,
+    planet_count: usize,
+    galaxy_size: GalaxySize,
+    starting_resources: ResourceBundle,
+    starting_population: i32,
+    seed: u64,
+}
+
+impl StartGenerator {
+    /// Create a generator with default galaxy settings
+    pub fn new(difficulty: Difficulty, faction_count: usize) -> Self {
+        let defaults = GameConfiguration::default();
+        Self {
+            difficulty,
+            faction_count: faction_count.max(1),
+            planet_count: defaults.planet_count,
+            galaxy_size: defaults.galaxy_size,
+            starting_resources: defaults.starting_resources,
+            starting_population: defaults.starting_population,
+            seed: defaults.seed,
+        }
+    }
+
+    /// Create a generator for the player plus the configured AI opponents
+    pub fn from_configuration(config: &GameConfiguration) -> Self {
+        Self {
+            difficulty: config.difficulty,
+            faction_count: 1 + config.ai_opponents,
+            planet_count: config.planet_count,
+            galaxy_size: config.galaxy_size,
+            starting_resources: config.starting_resources,
+            starting_population: config.starting_population,
+            seed: config.seed,
+        }
+    }
+
+    /// Use a specific layout seed
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Current layout seed
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Advance to a new seed so the next `generate` produces a different layout
+    pub fn reroll(&mut self) -> u64 {
+        self.seed = next_seed(self.seed);
+        self.seed
+    }
+
+    /// Generate a layout that passes validation, trying successive seeds if needed
+    pub fn generate(&self) -> GameResult<StartLayout> {
+        let mut seed = self.seed;
+        let mut last_error = None;
+        for _ in 0..MAX_ATTEMPTS {
+            let layout = self.generate_candidate(seed);
+            match self.validate(&layout) {
+                Ok(()) => return Ok(layout),
+                Err(e) => last_error = Some(e),
+            }
+            seed = next_seed(seed);
+        }
+        Err(last_error.unwrap_or_else(|| {
+            GameError::InvalidOperation("Could not generate a valid starting layout".into())
+        }))
+    }
+
+    /// Check balance, spacing and colonization guarantees of a layout
+    pub fn validate(&self, layout: &StartLayout) -> GameResult<()> {
+        let homes: Vec<&PlannedPlanet> = layout.homes().collect();
+        if homes.len() != self.faction_count {
+            return Err(GameError::InvalidOperation(format!(
+                "Layout has {} home planets for {} factions", homes.len(), self.faction_count
+            )));
+        }
+
+        // Comparable resource totals
+        let totals: Vec<i64> = homes.iter().map(|h| h.resources.total()).collect();
+        let (min, max) = (totals.iter().min().copied().unwrap_or(0), totals.iter().max().copied().unwrap_or(0));
+        if min <= 0 || max as f64 / min as f64 > MAX_RESOURCE_IMBALANCE {
+            return Err(GameError::InvalidOperation(format!(
+                "Home resource totals are unbalanced ({} to {})", min, max
+            )));
+        }
+
+        // Spacing between home systems
+        let min_spacing = self.min_home_spacing();
+        for (i, a) in homes.iter().enumerate() {
+            for b in &homes[i + 1..] {
+                let distance = a.position().distance_to(&b.position());
+                if distance < min_spacing {
+                    return Err(GameError::InvalidOperation(format!(
+                        "Home planets only {:.2} apart (minimum {:.2})", distance, min_spacing
+                    )));
+                }
+            }
+        }
+
+        // A colonizable neutral planet near every home
+        for home in &homes {
+            let has_nearby = layout.neutrals()
+                .any(|n| n.position().distance_to(&home.position()) <= NEARBY_COLONY_DISTANCE);
+            if !has_nearby {
+                return Err(GameError::InvalidOperation(format!(
+                    "Faction {:?} has no colonizable planet nearby", home.controller
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Create the layout's planets and starting stockpiles
+    pub fn apply(&self, layout: &StartLayout, planet_manager: &mut PlanetManager) -> GameResult<Vec<PlanetId>> {
+        let mut planet_ids = Vec::with_capacity(layout.planets.len());
+        for planned in &layout.planets {
+            let id = planet_manager.create_planet(planned.orbit, planned.controller)?;
+            if planned.size != DEFAULT_PLANET_SIZE || planned.traits != PlanetTraits::default() || planned.system != 0 {
+                let (size, traits, system) = (planned.size, planned.traits, planned.system);
+                planet_manager.modify_planet(id, |planet| {
+                    planet.size = size;
+                    planet.traits = traits;
+                    planet.system = system;
+                    Ok(())
+                })?;
+            }
+            if let Some(name) = &planned.name {
+                planet_manager.rename_planet(id, name)?;
+            }
+            if planned.resources.total() > 0 {
+                planet_manager.add_resources(id, planned.resources)?;
+            }
+            if planned.controller.is_some() {
+                planet_manager.update_population(id, planned.population)?;
+            }
+            planet_ids.push(id);
+        }
+        Ok(planet_ids)
+    }
+
+    fn home_ring_radius(&self) -> f32 {
+        home_ring_radius(self.galaxy_size)
+    }
+
+    fn min_home_spacing(&self) -> f32 {
+        // Chord length between evenly spaced homes, with slack for jitter
+        let ring = self.home_ring_radius();
+        let chord = if self.faction_count > 1 {
+            2.0 * ring * (PI / self.faction_count as f32).sin()
+        } else {
+            0.0
+        };
+        (chord * 0.6 * self.difficulty.spacing_multiplier()).min(chord * 0.9)
+    }
+
+    fn generate_candidate(&self, seed: u64) -> StartLayout {
+        let mut rng = seed;
+        let ring = self.home_ring_radius();
+        let multiplier = self.difficulty.resource_multiplier();
+        let mut planets = Vec::new();
+
+        // Home planets evenly around a ring, with jitter
+        let mut homes = Vec::with_capacity(self.faction_count);
+        for faction in 0..self.faction_count {
+            let base_phase = 2.0 * PI * faction as f32 / self.faction_count as f32;
+            let phase = base_phase + jitter(&mut rng, 0.15);
+            let semi_major_axis = ring + jitter(&mut rng, 0.3);
+
+            let mut resources = ResourceBundle::default();
+            for resource in ResourceType::ALL {
+                let base = self.starting_resources.get(resource) as f32 * multiplier;
+                let amount = base * (1.0 + jitter(&mut rng, RESOURCE_JITTER));
+                resources.set(resource, amount.round().max(0.0) as i32);
+            }
+
+            let orbit = orbit_for(semi_major_axis, phase);
+            homes.push((semi_major_axis, phase));
+            planets.push(PlannedPlanet {
+                orbit,
+                controller: Some(faction as FactionId),
+                resources,
+                population: self.starting_population,
+                size: DEFAULT_PLANET_SIZE,
+                traits: PlanetTraits::default(),
+                system: 0,
+                name: None,
+            });
+        }
+
+        // A colonization target next to every home
+        for (semi_major_axis, phase) in &homes {
+            let axis = (semi_major_axis + jitter(&mut rng, 0.4)).max(0.5);
+            let offset = 0.3 + jitter(&mut rng, 0.1);
+            planets.push(neutral(orbit_for(axis, phase + offset)));
+        }
+
+        // Fill the rest of the galaxy with scattered neutral planets
+        let remaining = self.planet_count.saturating_sub(planets.len());
+        for _ in 0..remaining {
+            let axis = 1.0 + unit(&mut rng) * ring * 1.5;
+            let phase = unit(&mut rng) * 2.0 * PI;
+            planets.push(neutral(orbit_for(axis, phase)));
+        }
+
+        StartLayout { seed, planets, star_map: StarMap::single() }
+    }
+}
+
+/// Radius of the ring home planets are placed on
+pub(crate) fn home_ring_radius(galaxy_size: GalaxySize) -> f32 {
+    match galaxy_size {
+        GalaxySize::Small => 3.0,
+        GalaxySize::Medium => 4.5,
+        GalaxySize::Large => 6.0,
+    }
+}
+
+pub(crate) fn orbit_for(semi_major_axis: f32, phase: f32) -> OrbitalElements {
+    OrbitalElements {
+        semi_major_axis,
+        period: (semi_major_axis.powf(1.5) * 100.0).max(50.0),
+        phase: phase.rem_euclid(2.0 * PI),
+        eccentricity: 0.0,
+        argument_of_periapsis: 0.0,
+    }
+}
+
+fn neutral(orbit: OrbitalElements) -> PlannedPlanet {
+    PlannedPlanet {
+        orbit,
+        controller: None,
+        resources: ResourceBundle::default(),
+        population: 0,
+        size: DEFAULT_PLANET_SIZE,
+        traits: PlanetTraits::default(),
+        system: 0,
+        name: None,
+    }
+}
+
+/// Uniform value in [-amount, amount)
+pub(crate) fn jitter(state: &mut u64, amount: f32) -> f32 {
+    (unit(state) * 2.0 - 1.0) * amount
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generated_layout_is_valid_for_all_difficulties() {
+        for difficulty in [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard] {
+            for factions in 1..=4 {
+                let generator = StartGenerator::new(difficulty, factions);
+                let layout = generator.generate().unwrap();
+                assert_eq!(layout.homes().count(), factions);
+                assert!(layout.neutrals().count() >= factions);
+                assert!(generator.validate(&layout).is_ok());
+            }
+        }
+    }
+
+    #[test]
+    fn test_generation_is_deterministic_and_reroll_changes_layout() {
+        let mut generator = StartGenerator::new(Difficulty::Normal, 3).with_seed(42);
+        let first = generator.generate().unwrap();
+        let again = generator.generate().unwrap();
+        assert_eq!(first.planets[0].orbit.phase, again.planets[0].orbit.phase);
+
+        generator.reroll();
+        let rerolled = generator.generate().unwrap();
+        assert_ne!(first.planets[0].orbit.phase, rerolled.planets[0].orbit.phase);
+    }
+
+    #[test]
+    fn test_difficulty_scales_starting_resources() {
+        let easy = StartGenerator::new(Difficulty::Easy, 2).generate().unwrap();
+        let hard = StartGenerator::new(Difficulty::Hard, 2).generate().unwrap();
+        let easy_total: i64 = easy.homes().map(|h| h.resources.total()).sum();
+        let hard_total: i64 = hard.homes().map(|h| h.resources.total()).sum();
+        assert!(easy_total > hard_total);
+    }
+
+    #[test]
+    fn test_validation_rejects_unbalanced_homes() {
+        let generator = StartGenerator::new(Difficulty::Normal, 2);
+        let mut layout = generator.generate().unwrap();
+        layout.planets[0].resources.minerals *= 10;
+        assert!(generator.validate(&layout).is_err());
+    }
+}
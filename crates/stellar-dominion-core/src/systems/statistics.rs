@@ -0,0 +1,388 @@
+// src/systems/statistics.rs
+//! Empire statistics over the course of a game
+//!
+//! StatisticsSystem keeps running totals for every faction — planets held,
+//! population, stockpiled resources and ships in service, and resources
+//! produced, ships built and battles won since the game started — and takes
+//! a sample of them every few ticks for the graphs and the end-of-game
+//! screen. Holdings are read from the `SystemContext` at each update. Like
+//! the timeline, the events do not say who owns the planets and ships
+//! involved, so [`StatisticsSystem::record_event`] looks up the owners in the
+//! managers and credits production, ships built and battles won.
+//!
+//! Samples start [`STATISTICS_SAMPLE_INTERVAL_TICKS`] apart. Whenever more
+//! than [`STATISTICS_MAX_SAMPLES`] have been taken, every other one is dropped
+//! and the interval doubles, so a long game keeps its whole history at a
+//! coarser resolution in bounded memory. Totals and samples are saved with
+//! the game as a [`StatisticsState`].
+
+use crate::core::{GameResult, GameError, GameEvent, EventBus, GameSystem, SystemContext};
+use crate::core::types::*;
+use crate::core::events::SimulationEvent;
+use crate::managers::{PlanetManager, ShipManager, FactionManager};
+use super::FactionStanding;
+use serde::{Serialize, Deserialize};
+use std::collections::BTreeMap;
+
+/// Ticks between samples at the start of a game
+pub const STATISTICS_SAMPLE_INTERVAL_TICKS: u64 = 10;
+
+/// Samples kept before the resolution is halved
+pub const STATISTICS_MAX_SAMPLES: usize = 500;
+
+/// Score for each planet held
+pub const SCORE_PER_PLANET: i64 = 100;
+
+/// Score for each ship in service
+pub const SCORE_PER_SHIP: i64 = 10;
+
+/// Score for each battle won
+pub const SCORE_PER_BATTLE_WON: i64 = 25;
+
+/// A faction's totals at one point in the game
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct FactionStatistics {
+    /// Faction the totals belong to
+    pub faction: FactionId,
+    /// Planets held
+    pub planets: usize,
+    /// Population across those planets
+    pub population: i32,
+    /// Units of every resource in stock on those planets
+    pub stockpile: i64,
+    /// Ships in service
+    pub ships: usize,
+    /// Units of every resource produced since the game started
+    pub resources_produced: i64,
+    /// Ships completed since the game started
+    pub ships_built: u32,
+    /// Battles won since the game started
+    pub battles_won: u32,
+}
+
+impl FactionStatistics {
+    /// Overall standing: planets, ships and battles won at their `SCORE_PER_*`
+    /// values, plus a point per ten people and per hundred units produced
+    pub fn score(&self) -> i64 {
+        self.planets as i64 * SCORE_PER_PLANET
+            + self.ships as i64 * SCORE_PER_SHIP
+            + self.battles_won as i64 * SCORE_PER_BATTLE_WON
+            + self.population as i64 / 10
+            + self.resources_produced / 100
+    }
+}
+
+/// Figure charted from the statistics
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatisticsMetric {
+    /// Planets held
+    Planets,
+    /// Population across the faction's planets
+    Population,
+    /// Resources in stock
+    Stockpile,
+    /// Ships in service
+    Ships,
+    /// Resources produced so far
+    ResourcesProduced,
+    /// Ships built so far
+    ShipsBuilt,
+    /// Battles won so far
+    BattlesWon,
+    /// [`FactionStatistics::score`]
+    Score,
+}
+
+impl StatisticsMetric {
+    /// Every metric, in the order the graphs offer them
+    pub const ALL: [StatisticsMetric; 8] = [
+        StatisticsMetric::Planets,
+        StatisticsMetric::Population,
+        StatisticsMetric::Stockpile,
+        StatisticsMetric::Ships,
+        StatisticsMetric::ResourcesProduced,
+        StatisticsMetric::ShipsBuilt,
+        StatisticsMetric::BattlesWon,
+        StatisticsMetric::Score,
+    ];
+
+    /// Name shown for the metric
+    pub fn label(&self) -> &'static str {
+        match self {
+            StatisticsMetric::Planets => "Planets",
+            StatisticsMetric::Population => "Population",
+            StatisticsMetric::Stockpile => "Stockpile",
+            StatisticsMetric::Ships => "Ships",
+            StatisticsMetric::ResourcesProduced => "Produced",
+            StatisticsMetric::ShipsBuilt => "Ships Built",
+            StatisticsMetric::BattlesWon => "Battles Won",
+            StatisticsMetric::Score => "Score",
+        }
+    }
+
+    /// The metric's value in a faction's totals
+    pub fn value(&self, statistics: &FactionStatistics) -> i64 {
+        match self {
+            StatisticsMetric::Planets => statistics.planets as i64,
+            StatisticsMetric::Population => statistics.population as i64,
+            StatisticsMetric::Stockpile => statistics.stockpile,
+            StatisticsMetric::Ships => statistics.ships as i64,
+            StatisticsMetric::ResourcesProduced => statistics.resources_produced,
+            StatisticsMetric::ShipsBuilt => statistics.ships_built as i64,
+            StatisticsMetric::BattlesWon => statistics.battles_won as i64,
+            StatisticsMetric::Score => statistics.score(),
+        }
+    }
+}
+
+/// Every faction's totals at a sampled tick
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatisticsSample {
+    /// Tick the sample was taken at
+    pub tick: u64,
+    /// By faction id
+    pub factions: Vec<FactionStatistics>,
+}
+
+/// Totals and samples, as saved with the game
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StatisticsState {
+    /// Latest totals by faction
+    pub totals: BTreeMap<FactionId, FactionStatistics>,
+    /// Samples, oldest first
+    pub samples: Vec<StatisticsSample>,
+    /// Ticks between samples
+    pub interval: u64,
+}
+
+impl StatisticsState {
+    /// Reject a zero interval and samples out of tick order
+    pub fn validate(&self) -> GameResult<()> {
+        if self.interval == 0 {
+            return Err(GameError::InvalidOperation("Statistics sample interval must be positive".into()));
+        }
+        if self.samples.windows(2).any(|pair| pair[0].tick >= pair[1].tick) {
+            return Err(GameError::InvalidOperation("Statistics samples must be in tick order".into()));
+        }
+        Ok(())
+    }
+}
+
+impl Default for StatisticsState {
+    fn default() -> Self {
+        Self {
+            totals: BTreeMap::new(),
+            samples: Vec::new(),
+            interval: STATISTICS_SAMPLE_INTERVAL_TICKS,
+        }
+    }
+}
+
+/// Collects per-faction totals every tick and samples them over time
+#[derive(Debug, Clone, Default)]
+pub struct StatisticsSystem {
+    state: StatisticsState,
+}
+
+impl StatisticsSystem {
+    /// Empty statistics sampling every [`STATISTICS_SAMPLE_INTERVAL_TICKS`] ticks
+    pub fn new() -> Self {
+        Self { state: StatisticsState::default() }
+    }
+
+    /// Take the factions' holdings at the end of `tick`, sampling the totals
+    /// when the tick falls on the sampling interval
+    pub fn record_tick(&mut self, tick: u64, standings: &[FactionStanding]) {
+        for standing in standings {
+            let statistics = self.totals_mut(standing.faction);
+            statistics.planets = standing.planets;
+            statistics.population = standing.population;
+            statistics.stockpile = units(&standing.resources);
+            statistics.ships = standing.ships;
+        }
+        let state = &mut self.state;
+        if !tick.is_multiple_of(state.interval) {
+            return;
+        }
+        state.samples.push(StatisticsSample { tick, factions: state.totals.values().copied().collect() });
+        if state.samples.len() > STATISTICS_MAX_SAMPLES {
+            state.interval *= 2;
+            let interval = state.interval;
+            state.samples.retain(|sample| sample.tick.is_multiple_of(interval));
+        }
+    }
+
+    /// Add a planet's production to its controller's total
+    pub fn record_production(&mut self, faction: FactionId, resources: &ResourceBundle) {
+        self.totals_mut(faction).resources_produced += units(resources);
+    }
+
+    /// Count a ship completed for the faction
+    pub fn record_ship_built(&mut self, faction: FactionId) {
+        self.totals_mut(faction).ships_built += 1;
+    }
+
+    /// Count a battle the faction won
+    pub fn record_battle_won(&mut self, faction: FactionId) {
+        self.totals_mut(faction).battles_won += 1;
+    }
+
+    /// A faction's latest totals
+    pub fn totals(&self, faction: FactionId) -> Option<&FactionStatistics> {
+        self.state.totals.get(&faction)
+    }
+
+    /// Every faction's latest totals, by faction id
+    pub fn all_totals(&self) -> impl Iterator<Item = &FactionStatistics> {
+        self.state.totals.values()
+    }
+
+    /// Samples taken so far, oldest first
+    pub fn samples(&self) -> &[StatisticsSample] {
+        &self.state.samples
+    }
+
+    /// Ticks between samples now
+    pub fn interval(&self) -> u64 {
+        self.state.interval
+    }
+
+    /// The metric's value for a faction at each sample it appears in
+    pub fn series(&self, faction: FactionId, metric: StatisticsMetric) -> Vec<(u64, i64)> {
+        series(&self.state.samples, faction, metric)
+    }
+
+    /// Totals and samples to save
+    pub fn state(&self) -> &StatisticsState {
+        &self.state
+    }
+
+    /// Restore saved totals and samples
+    pub fn load_state(&mut self, state: StatisticsState) -> GameResult<()> {
+        state.validate()?;
+        self.state = state;
+        Ok(())
+    }
+
+    fn totals_mut(&mut self, faction: FactionId) -> &mut FactionStatistics {
+        self.state.totals.entry(faction).or_insert_with(|| FactionStatistics { faction, ..Default::default() })
+    }
+
+    /// Add the event to the totals of the faction it counts for
+    pub fn record_event(&mut self, event: &SimulationEvent, planets: &PlanetManager, ships: &ShipManager, factions: &FactionManager) {
+        match event {
+            SimulationEvent::ResourcesProduced { planet, resources } => {
+                if let Some(owner) = planets.get_planet(*planet).ok().and_then(|p| p.controller) {
+                    self.record_production(owner, resources);
+                }
+            }
+            SimulationEvent::ShipCompleted { ship, .. } => {
+                if let Ok(owner) = ships.get_ship(*ship).map(|s| s.owner) {
+                    self.record_ship_built(owner);
+                }
+            }
+            // Pirate victories count for nobody's statistics
+            SimulationEvent::CombatResolved { outcome, .. }
+                if factions.get_faction(outcome.winner).is_ok_and(|winner| winner.is_scoring()) =>
+            {
+                self.record_battle_won(outcome.winner);
+            }
+            _ => {}
+        }
+    }
+
+    /// Nothing happens between ticks
+    pub fn update(&mut self, _delta: f32, _event_bus: &mut EventBus) -> GameResult<()> {
+        Ok(())
+    }
+
+    /// Production, ships and battles are credited through `record_event`,
+    /// which reads who owns what
+    pub fn handle_event(&mut self, _event: &GameEvent) -> GameResult<()> {
+        Ok(())
+    }
+}
+
+/// The metric's value for a faction at each of the samples it appears in,
+/// for views holding a copy of the samples
+pub fn series(samples: &[StatisticsSample], faction: FactionId, metric: StatisticsMetric) -> Vec<(u64, i64)> {
+    samples.iter()
+        .filter_map(|sample| {
+            let statistics = sample.factions.iter().find(|statistics| statistics.faction == faction)?;
+            Some((sample.tick, metric.value(statistics)))
+        })
+        .collect()
+}
+
+/// Units of every resource in the bundle, ignoring deficits
+fn units(resources: &ResourceBundle) -> i64 {
+    ResourceType::ALL.iter().map(|resource| resources.get(*resource).max(0) as i64).sum()
+}
+
+impl GameSystem for StatisticsSystem {
+    fn update(&mut self, delta: f32, context: &mut SystemContext<'_>) -> GameResult<()> {
+        // Holdings are taken as the last tick left them
+        if context.tick() > 0 {
+            self.record_tick(context.tick(), &context.faction_standings());
+        }
+        self.update(delta, context.event_bus())
+    }
+
+    fn handle_event(&mut self, event: &GameEvent) -> GameResult<()> {
+        self.handle_event(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn standing(faction: FactionId, planets: usize) -> FactionStanding {
+        FactionStanding { faction, planets, population: 100, resources: ResourceBundle::default(), ships: 1 }
+    }
+
+    #[test]
+    fn test_samples_thin_out_as_the_game_goes_on() {
+        let mut statistics = StatisticsSystem::new();
+        statistics.record_production(0, &ResourceBundle { minerals: 5, food: -3, ..Default::default() });
+        statistics.record_ship_built(1);
+        statistics.record_battle_won(1);
+        statistics.record_tick(10, &[standing(0, 2), standing(1, 1)]);
+        assert_eq!(statistics.totals(0).map(|totals| (totals.planets, totals.resources_produced)), Some((2, 5)));
+        assert_eq!(statistics.series(1, StatisticsMetric::BattlesWon), vec![(10, 1)]);
+
+        statistics.record_tick(15, &[standing(0, 3), standing(1, 1)]);
+        assert_eq!(statistics.samples().len(), 1, "Only ticks on the interval are sampled");
+
+        let last = STATISTICS_SAMPLE_INTERVAL_TICKS * (STATISTICS_MAX_SAMPLES as u64 + 2);
+        for tick in (20..=last).step_by(STATISTICS_SAMPLE_INTERVAL_TICKS as usize) {
+            statistics.record_tick(tick, &[standing(0, 3)]);
+        }
+        assert_eq!(statistics.interval(), STATISTICS_SAMPLE_INTERVAL_TICKS * 2);
+        assert!(statistics.samples().len() <= STATISTICS_MAX_SAMPLES);
+        assert!(statistics.samples().iter().all(|sample| sample.tick % statistics.interval() == 0));
+        assert_eq!(statistics.samples().last().map(|sample| sample.tick), Some(last));
+    }
+
+    #[test]
+    fn test_score_and_stockpile_are_sampled() {
+        let mut statistics = StatisticsSystem::new();
+        let rich = FactionStanding {
+            resources: ResourceBundle { minerals: 40, energy: 10, food: -5, ..Default::default() },
+            ..standing(0, 2)
+        };
+        statistics.record_battle_won(0);
+        statistics.record_tick(STATISTICS_SAMPLE_INTERVAL_TICKS, &[rich]);
+
+        let expected = 2 * SCORE_PER_PLANET + SCORE_PER_SHIP + SCORE_PER_BATTLE_WON + 10;
+        assert_eq!(statistics.series(0, StatisticsMetric::Score), vec![(STATISTICS_SAMPLE_INTERVAL_TICKS, expected)]);
+        assert_eq!(statistics.series(0, StatisticsMetric::Stockpile), vec![(STATISTICS_SAMPLE_INTERVAL_TICKS, 50)]);
+
+        let mut broken = statistics.state().clone();
+        broken.samples.push(broken.samples[0].clone());
+        assert!(StatisticsSystem::new().load_state(broken).is_err(), "Samples must be in tick order");
+        let mut restored = StatisticsSystem::new();
+        restored.load_state(statistics.state().clone()).unwrap();
+        assert_eq!(restored.totals(0), statistics.totals(0));
+    }
+}
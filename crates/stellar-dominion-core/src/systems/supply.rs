@@ -0,0 +1,362 @@
+// src/systems/supply.rs
+//! Supply lines: how far each faction's logistics reach
+//!
+//! A faction's supply network starts at its capital, its lowest-numbered
+//! planet, and spreads along jump lanes to every star system where the
+//! faction holds a planet; systems whose planets are all blockaded are
+//! reached but pass nothing on. Trade routes from a supplied, unblockaded
+//! planet carry supply to the planet at their other end, wherever it is. The
+//! networks are rebuilt only when territory changes: a planet conquered or
+//! colonized, a blockade laid or lifted, or a trade route created or cancelled.
+//!
+//! Planets outside their faction's network are cut off and produce
+//! [`CUT_OFF_PRODUCTION_PERCENT`] of their output. Ships are supplied inside
+//! the network's systems and one lane beyond; out of supply, their
+//! effectiveness drops by [`SUPPLY_ATTRITION_PERCENT`] every
+//! [`SUPPLY_ATTRITION_INTERVAL_TICKS`] down to
+//! [`MIN_SUPPLY_EFFECTIVENESS_PERCENT`], which weakens their attack and makes
+//! every move cost more fuel. The system reads the planets, trade routes and
+//! ship positions from the managers each tick; GameState applies the
+//! penalties. Supply is recomputed
+//! rather than saved, so ships in a loaded game start out supplied.
+
+use crate::core::{GameResult, GameEvent, EventBus, GameSystem, SystemContext, StarMap};
+use crate::core::types::*;
+use crate::core::events::*;
+use crate::managers::{PlanetManager, ShipManager};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Production of a planet cut off from its capital, in percent
+pub const CUT_OFF_PRODUCTION_PERCENT: i32 = 50;
+/// Ticks out of supply for each step of attrition
+pub const SUPPLY_ATTRITION_INTERVAL_TICKS: u64 = 10;
+/// Effectiveness a ship loses with each step of attrition, in percent
+pub const SUPPLY_ATTRITION_PERCENT: i32 = 5;
+/// Effectiveness a ship keeps however long it is out of supply, in percent
+pub const MIN_SUPPLY_EFFECTIVENESS_PERCENT: i32 = 50;
+
+/// Whether a planet or ship is in supply
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SupplyStatus {
+    /// Connected to the capital, or for ships near enough to the network
+    #[default]
+    Supplied,
+    /// Cut off for this many ticks
+    OutOfSupply {
+        /// Ticks since supply was lost
+        ticks: u64,
+    },
+}
+
+impl SupplyStatus {
+    /// True while in supply
+    pub fn is_supplied(&self) -> bool {
+        matches!(self, SupplyStatus::Supplied)
+    }
+
+    /// Fighting strength and fuel efficiency left, in percent
+    pub fn effectiveness(&self) -> i32 {
+        match self {
+            SupplyStatus::Supplied => 100,
+            SupplyStatus::OutOfSupply { ticks } => {
+                let steps = (ticks / SUPPLY_ATTRITION_INTERVAL_TICKS).min(100) as i32;
+                (100 - steps * SUPPLY_ATTRITION_PERCENT).max(MIN_SUPPLY_EFFECTIVENESS_PERCENT)
+            }
+        }
+    }
+
+    /// One more tick in the given state
+    fn advance(self, supplied: bool) -> Self {
+        match (self, supplied) {
+            (_, true) => SupplyStatus::Supplied,
+            (SupplyStatus::Supplied, false) => SupplyStatus::OutOfSupply { ticks: 0 },
+            (SupplyStatus::OutOfSupply { ticks }, false) => SupplyStatus::OutOfSupply { ticks: ticks + 1 },
+        }
+    }
+}
+
+/// The planets and star systems a faction's supply reaches
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SupplyNetwork {
+    /// Planet supply starts from
+    pub capital: PlanetId,
+    /// Star systems supply reaches
+    pub systems: BTreeSet<StarSystemId>,
+    /// The faction's planets connected to the capital
+    pub planets: BTreeSet<PlanetId>,
+}
+
+/// Keeps each faction's supply network and the supply of every planet and ship
+pub struct SupplySystem {
+    networks: BTreeMap<FactionId, SupplyNetwork>,
+    planets: BTreeMap<PlanetId, SupplyStatus>,
+    ships: BTreeMap<ShipId, SupplyStatus>,
+    /// Territory changed since the networks were last built
+    stale: bool,
+    pending: Vec<SimulationEvent>,
+}
+
+impl SupplySystem {
+    /// A system whose networks are built on the first tick
+    pub fn new() -> Self {
+        Self {
+            networks: BTreeMap::new(),
+            planets: BTreeMap::new(),
+            ships: BTreeMap::new(),
+            stale: true,
+            pending: Vec::new(),
+        }
+    }
+
+    /// True once territory has changed since the networks were built
+    pub fn needs_rebuild(&self) -> bool {
+        self.stale
+    }
+
+    /// Rebuild the networks on the next tick, for territory changed without an event
+    pub fn invalidate(&mut self) {
+        self.stale = true;
+    }
+
+    /// Build every faction's network from the planets, the star map and the
+    /// trade routes, given as (owner, from, to). Blockaded planets pass
+    /// no supply on. Planets that lost or regained supply are announced
+    /// on the next update.
+    pub fn rebuild_networks(&mut self, planets: &[Planet], star_map: &StarMap, trade_routes: &[(FactionId, PlanetId, PlanetId)], blockaded: &BTreeSet<PlanetId>) {
+        let mut by_faction: BTreeMap<FactionId, Vec<&Planet>> = BTreeMap::new();
+        for planet in planets {
+            if let Some(owner) = planet.controller {
+                by_faction.entry(owner).or_default().push(planet);
+            }
+        }
+
+        self.networks.clear();
+        for (faction, held) in by_faction {
+            let Some(capital) = held.iter().min_by_key(|planet| planet.id) else {
+                continue;
+            };
+            let system_of: BTreeMap<PlanetId, StarSystemId> = held.iter().map(|planet| (planet.id, planet.system)).collect();
+            let held_systems: BTreeSet<StarSystemId> = system_of.values().copied().collect();
+            let relays: BTreeSet<StarSystemId> = held.iter()
+                .filter(|planet| !blockaded.contains(&planet.id))
+                .map(|planet| planet.system)
+                .collect();
+            let routes: Vec<(PlanetId, PlanetId)> = trade_routes.iter()
+                .filter(|(owner, from, to)| *owner == faction && system_of.contains_key(from) && system_of.contains_key(to))
+                .map(|(_, from, to)| (*from, *to))
+                .collect();
+
+            let mut network = SupplyNetwork { capital: capital.id, ..Default::default() };
+            let mut frontier = vec![capital.system];
+            while let Some(system) = frontier.pop() {
+                if !network.systems.insert(system) {
+                    continue;
+                }
+                network.planets.extend(held.iter().filter(|planet| planet.system == system).map(|planet| planet.id));
+                // A blockaded system is reached but passes nothing on
+                if !relays.contains(&system) && system != capital.system {
+                    continue;
+                }
+                frontier.extend(star_map.neighbors(system).map(|(next, _)| next).filter(|next| held_systems.contains(next)));
+                for &(from, to) in &routes {
+                    for (near, far) in [(from, to), (to, from)] {
+                        if system_of[&near] == system && !blockaded.contains(&near) {
+                            frontier.push(system_of[&far]);
+                        }
+                    }
+                }
+            }
+            self.networks.insert(faction, network);
+        }
+
+        let mut statuses = BTreeMap::new();
+        for planet in planets {
+            let Some(owner) = planet.controller else {
+                continue;
+            };
+            let supplied = self.networks.get(&owner).is_some_and(|network| network.planets.contains(&planet.id));
+            let previous = self.planets.get(&planet.id).copied().unwrap_or_default();
+            if previous.is_supplied() != supplied {
+                self.pending.push(SimulationEvent::PlanetSupplyChanged { planet: planet.id, supplied });
+            }
+            statuses.insert(planet.id, previous.advance(supplied));
+        }
+        self.planets = statuses;
+        self.stale = false;
+    }
+
+    /// Count one more tick of supply for every ship, given as (ship, owner,
+    /// star system it is in), and for every planet. Ships of factions
+    /// without a network are always supplied; ships not listed are
+    /// gone. Ships that lost or regained supply are announced on the next
+    /// update.
+    pub fn update_ships(&mut self, ships: &[(ShipId, FactionId, StarSystemId)], star_map: &StarMap) {
+        let mut statuses = BTreeMap::new();
+        for &(ship, owner, system) in ships {
+            // Factions without planets, such as pirates, live off the land
+            let supplied = self.networks.get(&owner).is_none_or(|network| {
+                network.systems.contains(&system)
+                    || star_map.neighbors(system).any(|(next, _)| network.systems.contains(&next))
+            });
+            let previous = self.ships.get(&ship).copied().unwrap_or_default();
+            if previous.is_supplied() != supplied {
+                self.pending.push(SimulationEvent::ShipSupplyChanged { ship, supplied });
+            }
+            statuses.insert(ship, previous.advance(supplied));
+        }
+        self.ships = statuses;
+        for status in self.planets.values_mut() {
+            if !status.is_supplied() {
+                *status = status.advance(false);
+            }
+        }
+    }
+
+    /// Rebuild the networks if territory changed, with the `blockaded`
+    /// planets passing nothing on, then count this tick's supply for every
+    /// ship by the star system it is in
+    pub fn survey(&mut self, planets: &PlanetManager, ships: &ShipManager, star_map: &StarMap, blockaded: impl Iterator<Item = PlanetId>) {
+        if self.stale {
+            let routes: Vec<(FactionId, PlanetId, PlanetId)> = ships.get_trade_routes()
+                .filter_map(|route| ships.get_ship(route.ship).ok().map(|ship| (ship.owner, route.from, route.to)))
+                .collect();
+            self.rebuild_networks(planets.get_all_planets(), star_map, &routes, &blockaded.collect());
+        }
+        let positions: Vec<(ShipId, FactionId, StarSystemId)> = ships.get_all_ships().iter()
+            .map(|ship| (ship.id, ship.owner, star_map.system_at(ship.position)))
+            .collect();
+        self.update_ships(&positions, star_map);
+    }
+
+    /// Cut a planet's production down to [`CUT_OFF_PRODUCTION_PERCENT`]
+    /// while it is out of supply
+    pub fn limit_production(&self, planet: PlanetId, production: &mut ResourceBundle) {
+        if !self.planet_status(planet).is_supplied() {
+            for resource in ResourceType::ALL {
+                production.set(resource, production.get(resource) * CUT_OFF_PRODUCTION_PERCENT / 100);
+            }
+        }
+    }
+
+    /// The faction's network as last built
+    pub fn network(&self, faction: FactionId) -> Option<&SupplyNetwork> {
+        self.networks.get(&faction)
+    }
+
+    /// The planet's supply; unclaimed planets count as supplied
+    pub fn planet_status(&self, planet: PlanetId) -> SupplyStatus {
+        self.planets.get(&planet).copied().unwrap_or_default()
+    }
+
+    /// The ship's supply; ships not counted yet are supplied
+    pub fn ship_status(&self, ship: ShipId) -> SupplyStatus {
+        self.ships.get(&ship).copied().unwrap_or_default()
+    }
+
+    /// Announces the planets and ships whose supply changed since the last update
+    pub fn update(&mut self, _delta: f32, event_bus: &mut EventBus) -> GameResult<()> {
+        for event in self.pending.drain(..) {
+            event_bus.queue_event(GameEvent::SimulationEvent(event));
+        }
+        Ok(())
+    }
+
+    /// Territory changes mark the networks for rebuilding on the next tick
+    pub fn handle_event(&mut self, event: &GameEvent) -> GameResult<()> {
+        match event {
+            GameEvent::SimulationEvent(SimulationEvent::PlanetConquered { .. } | SimulationEvent::PlanetColonized { .. })
+            | GameEvent::PlayerCommand(PlayerCommand::CreateTradeRoute(_) | PlayerCommand::CancelTradeRoute(_))
+            | GameEvent::StateChanged(StateChange::PlanetBlockaded(_) | StateChange::BlockadeLifted(_)) => self.stale = true,
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+impl Default for SupplySystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GameSystem for SupplySystem {
+    fn update(&mut self, delta: f32, context: &mut SystemContext<'_>) -> GameResult<()> {
+        self.update(delta, context.event_bus())
+    }
+
+    fn handle_event(&mut self, event: &GameEvent) -> GameResult<()> {
+        self.handle_event(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{StarSystem, JumpLane};
+
+    fn planet(id: PlanetId, owner: FactionId, system: StarSystemId) -> Planet {
+        Planet {
+            id,
+            position: OrbitalElements::default(),
+            resources: ResourceStorage::default(),
+            population: Demographics::default(),
+            developments: Vec::new(),
+            controller: Some(owner),
+            storage_priority: StoragePriority::default(),
+            power_priority: PowerPriority::default(),
+            size: 5,
+            tax_rate: TaxRate::default(),
+            traits: PlanetTraits::default(),
+            system,
+            name: None,
+        }
+    }
+
+    /// Four stars in a row, 0 - 1 - 2 - 3
+    fn chain() -> StarMap {
+        StarMap {
+            systems: (0..4).map(|id| StarSystem { id, name: format!("Star {}", id), position: Vector2::new(id as f32 * 50.0, 0.0) }).collect(),
+            lanes: (0..3).map(|id| JumpLane { from: id, to: id + 1, travel_ticks: 5, fuel_cost: 10.0 }).collect(),
+        }
+    }
+
+    #[test]
+    fn test_supply_spreads_along_held_systems_and_trade_routes() {
+        let star_map = chain();
+        let planets = [planet(0, 0, 0), planet(1, 0, 1), planet(2, 0, 3)];
+        let mut supply = SupplySystem::new();
+        supply.rebuild_networks(&planets, &star_map, &[], &BTreeSet::new());
+        let network = supply.network(0).unwrap();
+        assert_eq!(network.capital, 0);
+        assert_eq!(network.systems, BTreeSet::from([0, 1]));
+        assert!(!supply.planet_status(2).is_supplied(), "Nothing held in system 2 links system 3");
+
+        // Ships are supplied one lane beyond the network and wear down further out
+        supply.update_ships(&[(7, 0, 2), (8, 0, 3)], &star_map);
+        assert!(supply.ship_status(7).is_supplied());
+        for _ in 0..SUPPLY_ATTRITION_INTERVAL_TICKS * 2 {
+            supply.update_ships(&[(7, 0, 2), (8, 0, 3)], &star_map);
+        }
+        assert_eq!(supply.ship_status(8).effectiveness(), 100 - 2 * SUPPLY_ATTRITION_PERCENT);
+        assert_eq!(SupplyStatus::OutOfSupply { ticks: u64::MAX }.effectiveness(), MIN_SUPPLY_EFFECTIVENESS_PERCENT);
+
+        // A trade route carries supply to the far planet; a blockade breaks the chain
+        supply.handle_event(&GameEvent::PlayerCommand(PlayerCommand::CancelTradeRoute(9))).unwrap();
+        assert!(supply.needs_rebuild());
+        supply.rebuild_networks(&planets, &star_map, &[(0, 1, 2)], &BTreeSet::new());
+        assert!(supply.planet_status(2).is_supplied());
+        supply.rebuild_networks(&planets, &star_map, &[(0, 1, 2)], &BTreeSet::from([1]));
+        assert!(!supply.planet_status(2).is_supplied());
+        assert!(supply.planet_status(1).is_supplied(), "The blockaded planet itself is still reached");
+
+        let mut bus = EventBus::new();
+        supply.update(0.1, &mut bus).unwrap();
+        let changes: Vec<(PlanetId, bool)> = bus.queued_events.iter()
+            .filter_map(|event| match event {
+                GameEvent::SimulationEvent(SimulationEvent::PlanetSupplyChanged { planet, supplied }) => Some((*planet, *supplied)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(changes, vec![(2, false), (2, true), (2, false)]);
+    }
+}
@@ -1,5 +1,15 @@
 // src/systems/time_manager.rs
-use crate::core::{GameResult, GameEvent, EventBus};
+//! Game time: ticks, pause, speed and fast-forward
+//!
+//! The simulation always advances in fixed ticks of [`TICK_DURATION_SECONDS`].
+//! The game speed only changes how many ticks are due per second of real
+//! time, so at 2x GameState runs two full ticks where 1x runs one. A
+//! fast-forward ignores real time and runs up to
+//! [`FAST_FORWARD_TICKS_PER_UPDATE`] ticks per update until its target tick,
+//! reporting progress with `SimulationEvent::FastForwardProgress`.
+
+use crate::core::{GameResult, GameEvent, EventBus, GameSystem, SystemContext};
+use crate::core::events::{PlayerCommand, SimulationEvent};
 use crate::core::types::*;
 
 /// Manages game timing with fixed timesteps for deterministic simulation.
@@ -10,6 +20,10 @@ pub struct TimeManager {
     pub(crate) speed_multiplier: f32,
     pub(crate) accumulated_time: f64, // Use f64 for better precision over long periods
     pub(crate) tick_duration: f64, // 0.1 seconds - use f64 for consistency
+    autosave_interval: u64, // 0 = autosave off
+    fast_forward_target: Option<u64>,
+    /// Ticks asked for with StepTick while paused and not yet run
+    pending_steps: u64,
 }
 
 // Constants for timing constraints
@@ -18,6 +32,13 @@ const MAX_SPEED_MULTIPLIER: f32 = 10.0;
 const TICK_DURATION_SECONDS: f64 = 0.1;
 const MAX_SAFE_TICK: u64 = u64::MAX - 1000; // Leave buffer for overflow protection
 
+/// Speeds offered by the game speed controls, slowest first
+pub const GAME_SPEEDS: [f32; 4] = [0.5, 1.0, 2.0, 4.0];
+/// Ticks a fast-forward runs per update, keeping the client responsive
+pub const FAST_FORWARD_TICKS_PER_UPDATE: u64 = 100;
+/// Longest fast-forward a single command may ask for
+pub const MAX_FAST_FORWARD_TICKS: u64 = 100_000;
+
 impl Default for TimeManager {
     fn default() -> Self {
         Self::new()
@@ -34,6 +55,9 @@ impl TimeManager {
             speed_multiplier: 1.0,
             accumulated_time: 0.0,
             tick_duration: TICK_DURATION_SECONDS,
+            autosave_interval: 0,
+            fast_forward_target: None,
+            pending_steps: 0,
         }
     }
     
@@ -46,33 +70,69 @@ impl TimeManager {
     /// # Returns
     /// GameResult indicating success or overflow protection trigger
     pub fn update(&mut self, delta: f32, event_bus: &mut EventBus) -> GameResult<()> {
-        if !self.paused {
-            // Convert f32 delta to f64 for precision
-            let delta_f64 = delta as f64;
-            self.accumulated_time += delta_f64 * self.speed_multiplier as f64;
-            
-            // Process all accumulated ticks
-            while self.accumulated_time >= self.tick_duration {
-                // Check for potential overflow before incrementing
-                if self.tick >= MAX_SAFE_TICK {
-                    return Err(GameError::SystemError(
-                        "Tick counter approaching overflow limit".to_string()
-                    ));
-                }
-                
-                self.tick += 1;
-                self.accumulated_time -= self.tick_duration;
-                
-                // Emit tick completed event
+        for _ in 0..self.ticks_due(delta) {
+            self.advance_tick(event_bus)?;
+        }
+        Ok(())
+    }
+    
+    /// Number of ticks due after `delta` seconds of real time at the current
+    /// speed, or the next stretch of a fast-forward. While paused only the
+    /// ticks asked for with `StepTick` are due.
+    ///
+    /// GameState runs one full simulation tick, ending in
+    /// [`advance_tick`](Self::advance_tick), for each.
+    pub fn ticks_due(&mut self, delta: f32) -> u64 {
+        if self.paused {
+            return std::mem::take(&mut self.pending_steps);
+        }
+        if let Some(target) = self.fast_forward_target {
+            // Real time spent fast-forwarding does not carry over afterwards
+            self.accumulated_time = 0.0;
+            return target.saturating_sub(self.tick).min(FAST_FORWARD_TICKS_PER_UPDATE);
+        }
+        
+        // Convert f32 delta to f64 for precision
+        self.accumulated_time += delta as f64 * self.speed_multiplier as f64;
+        let mut due = 0;
+        while self.accumulated_time >= self.tick_duration {
+            self.accumulated_time -= self.tick_duration;
+            due += 1;
+        }
+        due
+    }
+    
+    /// Advance one tick, emitting TickCompleted and any AutosaveDue or
+    /// fast-forward events for it.
+    pub fn advance_tick(&mut self, event_bus: &mut EventBus) -> GameResult<()> {
+        // Check for potential overflow before incrementing
+        if self.tick >= MAX_SAFE_TICK {
+            return Err(GameError::SystemError(
+                "Tick counter approaching overflow limit".to_string()
+            ));
+        }
+        
+        self.tick += 1;
+        event_bus.queue_event(GameEvent::SimulationEvent(SimulationEvent::TickCompleted(self.tick)));
+        
+        if self.autosave_interval > 0 && self.tick.is_multiple_of(self.autosave_interval) {
+            event_bus.queue_event(GameEvent::SimulationEvent(SimulationEvent::AutosaveDue(self.tick)));
+        }
+        
+        if let Some(target) = self.fast_forward_target {
+            if self.tick >= target {
+                self.fast_forward_target = None;
+                event_bus.queue_event(GameEvent::SimulationEvent(SimulationEvent::FastForwardCompleted(self.tick)));
+            } else if self.tick.is_multiple_of(FAST_FORWARD_TICKS_PER_UPDATE) {
                 event_bus.queue_event(GameEvent::SimulationEvent(
-                    crate::core::events::SimulationEvent::TickCompleted(self.tick)
+                    SimulationEvent::FastForwardProgress { tick: self.tick, target }
                 ));
             }
         }
         Ok(())
     }
     
-    /// Handles player commands for pause/unpause and speed control.
+    /// Handles player commands for pause/unpause, single steps and speed control.
     /// 
     /// # Arguments
     /// * `event` - Game event to process
@@ -82,11 +142,18 @@ impl TimeManager {
     pub fn handle_event(&mut self, event: &GameEvent) -> GameResult<()> {
         if let GameEvent::PlayerCommand(cmd) = event {
             match cmd {
-                crate::core::events::PlayerCommand::SetGameSpeed(speed) => {
+                PlayerCommand::SetGameSpeed(speed) => {
                     self.set_speed_multiplier(*speed)?;
                 }
-                crate::core::events::PlayerCommand::PauseGame(paused) => {
+                PlayerCommand::PauseGame(paused) => {
                     self.paused = *paused;
+                    self.pending_steps = 0;
+                }
+                PlayerCommand::StepTick if self.paused => {
+                    self.pending_steps += 1;
+                }
+                PlayerCommand::FastForward { ticks } => {
+                    self.fast_forward(*ticks)?;
                 }
                 _ => {}
             }
@@ -122,6 +189,7 @@ impl TimeManager {
         self.tick = tick;
         // Reset accumulated time to prevent timing inconsistencies
         self.accumulated_time = 0.0;
+        self.fast_forward_target = None;
         Ok(())
     }
     
@@ -133,6 +201,13 @@ impl TimeManager {
     /// # Returns
     /// GameResult indicating success or validation failure
     pub fn set_speed_multiplier(&mut self, speed: f32) -> GameResult<()> {
+        Self::check_speed_multiplier(speed)?;
+        self.speed_multiplier = speed;
+        Ok(())
+    }
+    
+    /// Fail unless `speed` is a speed multiplier TimeManager accepts
+    pub fn check_speed_multiplier(speed: f32) -> GameResult<()> {
         if !(MIN_SPEED_MULTIPLIER..=MAX_SPEED_MULTIPLIER).contains(&speed) {
             return Err(GameError::InvalidOperation(
                 format!("Speed multiplier {} out of range [{}, {}]", 
@@ -144,15 +219,55 @@ impl TimeManager {
                 "Speed multiplier cannot be NaN or infinite".to_string()
             ));
         }
-        self.speed_multiplier = speed;
         Ok(())
     }
     
+    /// Fail unless a fast-forward of `ticks` ticks may be started
+    pub fn check_fast_forward(ticks: u64) -> GameResult<()> {
+        if ticks > MAX_FAST_FORWARD_TICKS {
+            return Err(GameError::InvalidOperation(
+                format!("Cannot fast-forward {} ticks, maximum is {}", ticks, MAX_FAST_FORWARD_TICKS)
+            ));
+        }
+        Ok(())
+    }
+    
+    /// Run the simulation `ticks` ticks ahead without waiting for real time;
+    /// 0 stops a fast-forward in progress.
+    pub fn fast_forward(&mut self, ticks: u64) -> GameResult<()> {
+        Self::check_fast_forward(ticks)?;
+        self.fast_forward_target = match ticks {
+            0 => None,
+            ticks => Some(self.tick.saturating_add(ticks).min(MAX_SAFE_TICK - 1)),
+        };
+        Ok(())
+    }
+    
+    /// Tick a fast-forward in progress runs to
+    pub fn get_fast_forward_target(&self) -> Option<u64> {
+        self.fast_forward_target
+    }
+    
+    /// Returns whether a fast-forward is in progress.
+    pub fn is_fast_forwarding(&self) -> bool {
+        self.fast_forward_target.is_some()
+    }
+    
     /// Returns the current game speed multiplier.
     pub fn get_speed_multiplier(&self) -> f32 {
         self.speed_multiplier
     }
     
+    /// Sets how many ticks pass between AutosaveDue events; 0 stops them.
+    pub fn set_autosave_interval(&mut self, ticks: u64) {
+        self.autosave_interval = ticks;
+    }
+    
+    /// Returns the ticks between AutosaveDue events, 0 if autosave is off.
+    pub fn get_autosave_interval(&self) -> u64 {
+        self.autosave_interval
+    }
+    
     /// Returns whether the game is currently paused.
     pub fn is_paused(&self) -> bool {
         self.paused
@@ -200,6 +315,16 @@ impl TimeManager {
     }
 }
 
+impl GameSystem for TimeManager {
+    fn update(&mut self, delta: f32, context: &mut SystemContext<'_>) -> GameResult<()> {
+        self.update(delta, context.event_bus())
+    }
+
+    fn handle_event(&mut self, event: &GameEvent) -> GameResult<()> {
+        self.handle_event(event)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -280,6 +405,27 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_autosave_due_every_interval() {
+        let mut time_manager = TimeManager::new();
+        let mut event_bus = EventBus::new();
+        
+        // Off by default
+        time_manager.update(1.0, &mut event_bus).unwrap();
+        assert_eq!(event_bus.queued_events.len(), 10);
+        
+        time_manager.set_autosave_interval(4);
+        event_bus.queued_events.clear();
+        time_manager.update(1.0, &mut event_bus).unwrap();
+        let due: Vec<u64> = event_bus.queued_events.iter()
+            .filter_map(|event| match event {
+                GameEvent::SimulationEvent(SimulationEvent::AutosaveDue(tick)) => Some(*tick),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(due, vec![12, 16, 20]);
+    }
+
     #[test]
     fn test_pause_functionality() {
         let mut time_manager = TimeManager::new();
@@ -297,6 +443,11 @@ mod tests {
         assert_eq!(time_manager.get_current_tick(), 0);
         assert_eq!(event_bus.queued_events.len(), 0);
         
+        // Each step runs exactly one tick, however much real time passes
+        time_manager.handle_event(&GameEvent::PlayerCommand(PlayerCommand::StepTick)).unwrap();
+        assert_eq!(time_manager.ticks_due(5.0), 1);
+        assert_eq!(time_manager.ticks_due(5.0), 0);
+        
         // Unpause and advance
         let unpause_event = GameEvent::PlayerCommand(PlayerCommand::PauseGame(false));
         let result = time_manager.handle_event(&unpause_event);
@@ -327,6 +478,51 @@ mod tests {
         assert_eq!(event_bus.queued_events.len(), 2);
     }
 
+    #[test]
+    fn test_ticks_due_follow_speed_and_fast_forward() {
+        let mut time_manager = TimeManager::new();
+        let mut event_bus = EventBus::new();
+        
+        // Half speed: one tick every other update, whole ticks only
+        time_manager.set_speed_multiplier(0.5).unwrap();
+        assert_eq!(time_manager.ticks_due(0.1), 0);
+        assert_eq!(time_manager.ticks_due(0.1), 1);
+        time_manager.set_speed_multiplier(4.0).unwrap();
+        assert_eq!(time_manager.ticks_due(0.1), 4);
+        
+        // A fast-forward runs in stretches regardless of speed and real time
+        time_manager.set_tick(50).unwrap();
+        time_manager.fast_forward(250).unwrap();
+        assert_eq!(time_manager.get_fast_forward_target(), Some(300));
+        let mut stretches = Vec::new();
+        while time_manager.is_fast_forwarding() {
+            let due = time_manager.ticks_due(0.0);
+            stretches.push(due);
+            for _ in 0..due {
+                time_manager.advance_tick(&mut event_bus).unwrap();
+            }
+        }
+        assert_eq!(stretches, vec![100, 100, 50]);
+        assert_eq!(time_manager.get_current_tick(), 300);
+        let reports: Vec<_> = event_bus.queued_events.iter()
+            .filter_map(|event| match event {
+                GameEvent::SimulationEvent(SimulationEvent::FastForwardProgress { tick, target }) => Some((*tick, *target)),
+                GameEvent::SimulationEvent(SimulationEvent::FastForwardCompleted(tick)) => Some((*tick, *tick)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(reports, vec![(100, 300), (200, 300), (300, 300)]);
+        
+        // Back to real time at the current speed; pausing holds a fast-forward
+        assert_eq!(time_manager.ticks_due(0.1), 4);
+        time_manager.fast_forward(10).unwrap();
+        time_manager.paused = true;
+        assert_eq!(time_manager.ticks_due(0.1), 0);
+        time_manager.fast_forward(0).unwrap();
+        assert!(!time_manager.is_fast_forwarding());
+        assert!(time_manager.fast_forward(MAX_FAST_FORWARD_TICKS + 1).is_err());
+    }
+
     #[test]
     fn test_speed_multiplier_validation() {
         let mut time_manager = TimeManager::new();
@@ -441,6 +637,9 @@ mod tests {
             speed_multiplier: 5.0,
             accumulated_time: 0.05,
             tick_duration: 0.1,
+            autosave_interval: 0,
+            fast_forward_target: None,
+            pending_steps: 0,
         };
         assert!(time_manager.validate().is_ok());
         
@@ -0,0 +1,197 @@
+// src/systems/visibility.rs
+//! Fog of war: what each faction's sensors can see
+//!
+//! Every tick [`VisibilitySystem::survey`] gathers the sensors of every
+//! faction, its planets and ships, from the managers and hands them to
+//! [`VisibilitySystem::update_visibility`] together with the PhysicsEngine's
+//! [`SpatialIndex`] of where all planets and ships are. A
+//! faction sees whatever lies within range of one of its sensors. Planets
+//! seen for the first time are announced with `PlanetDiscovered`, which
+//! FactionManager records in the faction's
+//! explored planets; current sightings are recomputed rather than saved.
+
+use crate::core::{GameResult, GameEvent, EventBus, GameSystem, SystemContext};
+use crate::core::types::*;
+use crate::core::events::*;
+use crate::managers::{PlanetManager, ShipManager, FactionManager};
+use super::spatial_index::{SpatialEntity, SpatialIndex};
+use std::collections::BTreeMap;
+
+/// Sensor range of a controlled planet, in AU
+pub const PLANET_SENSOR_RANGE: f32 = 2.0;
+
+/// Sensor range of a ship of the given class, in AU; scouts see furthest
+pub fn sensor_range(ship_class: ShipClass) -> f32 {
+    match ship_class {
+        ShipClass::Scout => 5.0,
+        ShipClass::Warship => 2.5,
+        ShipClass::Transport | ShipClass::Colony => 1.5,
+    }
+}
+
+/// Sensor range of a ship, in AU: its design's, or its class's
+pub fn ship_sensor_range(ship: &Ship) -> f32 {
+    ship.design.as_ref().map_or_else(|| sensor_range(ship.ship_class), |design| design.stats().sensor_range)
+}
+
+/// A planet or ship that lets its owner see its surroundings
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sensor {
+    /// Faction that sees through the sensor
+    pub owner: FactionId,
+    /// Where the sensor is
+    pub position: Vector2,
+    /// How far it sees
+    pub range: f32,
+}
+
+/// Tracks what each faction can currently see
+pub struct VisibilitySystem {
+    visibility: BTreeMap<FactionId, FactionVisibility>,
+    pending: Vec<SimulationEvent>,
+}
+
+impl VisibilitySystem {
+    /// No faction sees anything yet
+    pub fn new() -> Self {
+        Self {
+            visibility: BTreeMap::new(),
+            pending: Vec::new(),
+        }
+    }
+
+    /// Recompute every faction's sightings from the sensors of its planets,
+    /// where `position` puts each this tick, and of its ships
+    pub fn survey(&mut self, planets: &PlanetManager, ships: &ShipManager, factions: &FactionManager, position: impl Fn(&Planet) -> Vector2, index: &SpatialIndex) {
+        let mut sensors: Vec<Sensor> = planets.get_all_planets().iter()
+            .filter_map(|planet| planet.controller.map(|owner| Sensor {
+                owner,
+                position: position(planet),
+                range: PLANET_SENSOR_RANGE,
+            }))
+            .collect();
+        sensors.extend(ships.get_all_ships().iter().map(|ship| Sensor {
+            owner: ship.owner,
+            position: ship.position,
+            range: ship_sensor_range(ship),
+        }));
+        self.update_visibility(factions.get_all_factions(), &sensors, index);
+    }
+
+    /// Recompute every faction's sightings from its sensors.
+    ///
+    /// `index` holds the current position of every planet and ship. Planets
+    /// a faction sees that it has not explored yet are announced on the next
+    /// update.
+    pub fn update_visibility(&mut self, factions: &[Faction], sensors: &[Sensor], index: &SpatialIndex) {
+        self.visibility.clear();
+        for faction in factions {
+            let mut visibility = FactionVisibility::default();
+            for sensor in sensors.iter().filter(|sensor| sensor.owner == faction.id) {
+                for (entity, _) in index.entities_within(sensor.position, sensor.range) {
+                    match entity {
+                        SpatialEntity::Planet(planet) => visibility.planets.insert(planet),
+                        SpatialEntity::Ship(ship) => visibility.ships.insert(ship),
+                    };
+                }
+            }
+            for planet in visibility.planets.iter().filter(|planet| !faction.has_explored(**planet)) {
+                self.pending.push(SimulationEvent::PlanetDiscovered { faction: faction.id, planet: *planet });
+            }
+            self.visibility.insert(faction.id, visibility);
+        }
+    }
+
+    /// What the faction saw at the last update; None before the first one
+    pub fn visibility(&self, faction: FactionId) -> Option<&FactionVisibility> {
+        self.visibility.get(&faction)
+    }
+
+    /// Emits the discoveries made since the last update
+    pub fn update(&mut self, _delta: f32, event_bus: &mut EventBus) -> GameResult<()> {
+        for event in self.pending.drain(..) {
+            event_bus.queue_event(GameEvent::SimulationEvent(event));
+        }
+        Ok(())
+    }
+
+    /// Sightings are recomputed through `survey` once per tick
+    pub fn handle_event(&mut self, _event: &GameEvent) -> GameResult<()> {
+        Ok(())
+    }
+}
+
+impl Default for VisibilitySystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GameSystem for VisibilitySystem {
+    fn update(&mut self, delta: f32, context: &mut SystemContext<'_>) -> GameResult<()> {
+        self.update(delta, context.event_bus())
+    }
+
+    fn handle_event(&mut self, event: &GameEvent) -> GameResult<()> {
+        self.handle_event(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    fn faction(id: FactionId, explored: &[PlanetId]) -> Faction {
+        Faction {
+            id,
+            name: format!("Faction {}", id),
+            is_player: id == 0,
+            ai_type: AIPersonality::Balanced,
+            score: 0,
+            inbox: Inbox::default(),
+            technologies: BTreeSet::new(),
+            research: ResearchState::default(),
+            relations: BTreeMap::new(),
+            explored_planets: explored.iter().copied().collect(),
+            credits: 0,
+            tax_rate: TaxRate::default(),
+            ship_designs: Vec::new(),
+            control_groups: BTreeMap::new(),
+            kind: FactionKind::Empire,
+        }
+    }
+
+    #[test]
+    fn test_factions_see_only_what_their_sensors_reach() {
+        let mut visibility = VisibilitySystem::new();
+        let factions = [faction(0, &[0]), faction(1, &[])];
+        let sensors = [
+            Sensor { owner: 0, position: Vector2::new(0.0, 0.0), range: PLANET_SENSOR_RANGE },
+            Sensor { owner: 1, position: Vector2::new(10.0, 0.0), range: sensor_range(ShipClass::Scout) },
+        ];
+        let planets = [(0, Vector2::new(0.0, 0.0)), (1, Vector2::new(6.0, 0.0)), (2, Vector2::new(1.5, 0.0))];
+        let ships = [(7, Vector2::new(1.0, 1.0)), (8, Vector2::new(20.0, 0.0))];
+        let mut index = SpatialIndex::default();
+        index.rebuild(0, &planets, &ships);
+        visibility.update_visibility(&factions, &sensors, &index);
+
+        let home = visibility.visibility(0).unwrap();
+        assert!(home.can_see_planet(0) && home.can_see_planet(2) && !home.can_see_planet(1));
+        assert!(home.can_see_ship(7) && !home.can_see_ship(8));
+        let scout = visibility.visibility(1).unwrap();
+        assert!(scout.can_see_planet(1), "Scouts see further than planets");
+        assert!(visibility.visibility(2).is_none());
+
+        // Only planets not explored before are announced
+        let mut bus = EventBus::new();
+        visibility.update(0.1, &mut bus).unwrap();
+        let discovered: Vec<(FactionId, PlanetId)> = bus.queued_events.iter()
+            .filter_map(|event| match event {
+                GameEvent::SimulationEvent(SimulationEvent::PlanetDiscovered { faction, planet }) => Some((*faction, *planet)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(discovered, vec![(0, 2), (1, 1)]);
+    }
+}
@@ -0,0 +1,179 @@
+// src/test_api.rs
+//! Deterministic simulation test vectors
+//!
+//! Enabled with the `test-api` feature. Each [`TestVector`] is a short scripted
+//! scenario (player commands interleaved with fixed-timestep ticks) paired with
+//! the state hash the reference simulation produces. Mods and forks can run
+//! [`run_all`] against their build to confirm they have not broken simulation
+//! compatibility.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use stellar_dominion::test_api;
+//!
+//! let report = test_api::run_all(&test_api::builtin_vectors());
+//! assert!(report.is_success(), "{}", report);
+//! ```
+
+use crate::config::FIXED_TIMESTEP;
+use crate::core::types::*;
+use crate::core::{GameEvent, GameState, PlayerCommand};
+use crate::systems::GameInitializer;
+use std::fmt;
+
+/// A single step of a scripted scenario
+#[derive(Debug, Clone)]
+pub enum ScenarioStep {
+    /// Queue a player command; it is processed on the next tick
+    Command(PlayerCommand),
+    /// Advance the simulation by the given number of fixed timesteps
+    AdvanceTicks(u32),
+}
+
+/// A named scenario script and the state hash it must produce
+#[derive(Debug, Clone)]
+pub struct TestVector {
+    /// Unique vector name, used in reports
+    pub name: &'static str,
+    /// Configuration used to initialize the game before the script runs
+    pub configuration: GameConfiguration,
+    /// Steps executed in order
+    pub steps: Vec<ScenarioStep>,
+    /// Expected value of [`state_hash`] after all steps have run
+    pub expected_hash: u64,
+}
+
+/// A vector whose result did not match the expected hash
+#[derive(Debug, Clone)]
+pub struct VectorMismatch {
+    /// Name of the failing vector
+    pub name: &'static str,
+    /// Hash the vector expected
+    pub expected: u64,
+    /// Hash produced by this build, or the error that stopped the script
+    pub actual: GameResult<u64>,
+}
+
+/// Result of running a set of test vectors
+#[derive(Debug, Clone, Default)]
+pub struct VectorReport {
+    /// Number of vectors executed
+    pub total: usize,
+    /// Vectors that failed or produced an unexpected hash
+    pub mismatches: Vec<VectorMismatch>,
+}
+
+impl VectorReport {
+    /// True when every vector reproduced its expected hash
+    pub fn is_success(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+impl fmt::Display for VectorReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}/{} test vectors passed", self.total - self.mismatches.len(), self.total)?;
+        for mismatch in &self.mismatches {
+            match &mismatch.actual {
+                Ok(actual) => writeln!(
+                    f,
+                    "  {}: expected {:#018x}, got {:#018x}",
+                    mismatch.name, mismatch.expected, actual
+                )?,
+                Err(e) => writeln!(f, "  {}: failed with error: {}", mismatch.name, e)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Compute a stable hash of the simulation-relevant game state.
+///
+/// Same as [`GameState::compute_state_hash`]: the current tick plus all
+/// planets, ships and factions.
+pub fn state_hash(game: &GameState) -> GameResult<u64> {
+    game.compute_state_hash()
+}
+
+/// Execute a vector's script from a freshly initialized game and return the final state hash
+pub fn run_vector(vector: &TestVector) -> GameResult<u64> {
+    let mut game = GameState::new()?;
+    let star_map = GameInitializer::new(vector.configuration.clone()).initialize_game(
+        &mut game.planet_manager,
+        &mut game.ship_manager,
+        &mut game.faction_manager,
+    )?;
+    game.physics_engine.set_star_map(star_map);
+    game.set_game_configuration(vector.configuration.clone());
+    game.current_mode = GameMode::InGame;
+
+    for step in &vector.steps {
+        match step {
+            ScenarioStep::Command(command) => {
+                game.queue_event(GameEvent::PlayerCommand(command.clone()));
+            }
+            ScenarioStep::AdvanceTicks(count) => {
+                for _ in 0..*count {
+                    game.fixed_update(FIXED_TIMESTEP)?;
+                }
+            }
+        }
+    }
+
+    state_hash(&game)
+}
+
+/// Run every vector and collect mismatches
+pub fn run_all(vectors: &[TestVector]) -> VectorReport {
+    let mut report = VectorReport { total: vectors.len(), mismatches: Vec::new() };
+    for vector in vectors {
+        let actual = run_vector(vector);
+        if !matches!(actual, Ok(hash) if hash == vector.expected_hash) {
+            report.mismatches.push(VectorMismatch {
+                name: vector.name,
+                expected: vector.expected_hash,
+                actual,
+            });
+        }
+    }
+    report
+}
+
+/// Reference vectors shipped with the engine.
+///
+/// Expected hashes must be regenerated (and the change called out) whenever a
+/// simulation change intentionally alters game outcomes.
+pub fn builtin_vectors() -> Vec<TestVector> {
+    vec![
+        TestVector {
+            name: "idle_economy_100_ticks",
+            configuration: GameConfiguration::default(),
+            steps: vec![ScenarioStep::AdvanceTicks(100)],
+            expected_hash: 0x895a_94d8_a197_a31a,
+        },
+        TestVector {
+            name: "paused_game_does_not_advance",
+            configuration: GameConfiguration::default(),
+            steps: vec![
+                ScenarioStep::Command(PlayerCommand::PauseGame(true)),
+                ScenarioStep::AdvanceTicks(50),
+            ],
+            expected_hash: 0x6dd9_c1da_c6c1_2e0d,
+        },
+        TestVector {
+            name: "build_and_speed_change",
+            configuration: GameConfiguration::default(),
+            steps: vec![
+                ScenarioStep::Command(PlayerCommand::BuildStructure {
+                    planet: 0,
+                    building_type: BuildingType::Mine,
+                }),
+                ScenarioStep::AdvanceTicks(20),
+                ScenarioStep::Command(PlayerCommand::SetGameSpeed(2.0)),
+                ScenarioStep::AdvanceTicks(40),
+            ],
+            expected_hash: 0x0e0b_09da_f697_6d8a,
+        },
+    ]
+}
@@ -0,0 +1,220 @@
+// crates/stellar-dominion-core/tests/combat.rs
+//! Blockades, invasions, ship battles and missiles
+
+mod common;
+
+use stellar_dominion_core::core::*;
+use common::{last_rejection, last_system_error};
+
+#[test]
+fn test_unopposed_hostile_warships_blockade_a_planet() {
+    let mut game_state = GameState::new().unwrap();
+    game_state.current_mode = GameMode::InGame;
+    let player = game_state.faction_manager.create_faction("Player".to_string(), true, AIPersonality::Balanced).unwrap();
+    let enemy = game_state.faction_manager.create_faction("Enemy".to_string(), false, AIPersonality::Aggressive).unwrap();
+    game_state.faction_manager.handle_event(&GameEvent::SimulationEvent(SimulationEvent::WarDeclared { aggressor: player, target: enemy })).unwrap();
+    let orbit = |semi_major_axis: f32| OrbitalElements { semi_major_axis, period: 1_000_000.0, phase: 0.0, eccentricity: 0.0, argument_of_periapsis: 0.0 };
+    let besieged = game_state.planet_manager.create_planet(orbit(3.0), Some(enemy)).unwrap();
+    let market = game_state.planet_manager.create_planet(orbit(6.0), Some(enemy)).unwrap();
+    game_state.planet_manager.update_population(besieged, 1000).unwrap();
+    let transport = game_state.ship_manager.create_ship(ShipClass::Transport, Vector2::new(6.0, 0.0), enemy).unwrap();
+    let route = TradeRoute { ship: transport, from: besieged, to: market, resources: ResourceBundle { minerals: 10, ..Default::default() } };
+    game_state.ship_manager.assign_trade_route(route.clone()).unwrap();
+    
+    // A warship parking over an undefended enemy planet blockades it
+    let raider = game_state.ship_manager.create_ship(ShipClass::Warship, Vector2::new(2.0, 0.0), player).unwrap();
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::MoveShip { ship: raider, target: Vector2::new(3.0, 0.0) }));
+    while game_state.get_current_tick() < 10 {
+        game_state.fixed_update(0.1).unwrap();
+    }
+    assert_eq!(game_state.ship_manager.get_ship(raider).unwrap().status, ShipStatus::Orbiting(besieged));
+    assert!(game_state.combat_resolver.is_blockaded(besieged));
+    assert!(!game_state.combat_resolver.is_blockaded(market));
+    assert!(game_state.event_bus.event_history.iter().any(|event| matches!(
+        event,
+        GameEvent::StateChanged(StateChange::PlanetBlockaded(p)) if *p == besieged
+    )));
+    
+    // Trade and shipbuilding stop, and the population shrinks
+    assert!(game_state.ship_manager.get_trade_route(transport).is_none());
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::CreateTradeRoute(route)));
+    game_state.process_queued_events_for_test().unwrap();
+    assert!(last_system_error(&game_state).unwrap().contains("blockaded"));
+    let (scout_cost, _) = *game_state.construction_system.get_ship_cost(ShipClass::Scout).unwrap();
+    game_state.planet_manager.add_resources(besieged, scout_cost).unwrap();
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::BuildShip { planet: besieged, ship_class: ShipClass::Scout }));
+    game_state.process_queued_events_for_test().unwrap();
+    assert!(last_system_error(&game_state).unwrap().contains("blockaded"));
+    assert!(game_state.planet_manager.get_planet(besieged).unwrap().population.total < 1000);
+    
+    // A defending warship contests the orbit and lifts the blockade
+    let defender = game_state.ship_manager.create_ship(ShipClass::Warship, Vector2::new(4.0, 0.0), enemy).unwrap();
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::MoveShip { ship: defender, target: Vector2::new(3.0, 0.0) }));
+    for _ in 0..5 {
+        game_state.fixed_update(0.1).unwrap();
+    }
+    assert!(!game_state.combat_resolver.is_blockaded(besieged));
+    assert!(game_state.event_bus.event_history.iter().any(|event| matches!(
+        event,
+        GameEvent::StateChanged(StateChange::BlockadeLifted(p)) if *p == besieged
+    )));
+}
+
+#[test]
+fn test_troops_invade_and_capture_an_enemy_planet() {
+    let mut game_state = GameState::new().unwrap();
+    game_state.current_mode = GameMode::InGame;
+    let player = game_state.faction_manager.create_faction("Player".to_string(), true, AIPersonality::Balanced).unwrap();
+    let enemy = game_state.faction_manager.create_faction("Enemy".to_string(), false, AIPersonality::Aggressive).unwrap();
+    let orbit = |semi_major_axis: f32| OrbitalElements { semi_major_axis, period: 1_000_000.0, phase: 0.0, eccentricity: 0.0, argument_of_periapsis: 0.0 };
+    let home = game_state.planet_manager.create_planet(orbit(3.0), Some(player)).unwrap();
+    let target = game_state.planet_manager.create_planet(orbit(4.0), Some(enemy)).unwrap();
+    let garrison = |military, total| WorkerAllocation { military, unassigned: total - military, ..Default::default() };
+    game_state.planet_manager.update_population(home, 500).unwrap();
+    game_state.planet_manager.set_worker_allocation(home, garrison(80, 500)).unwrap();
+    game_state.planet_manager.update_population(target, 300).unwrap();
+    game_state.planet_manager.set_worker_allocation(target, garrison(20, 300)).unwrap();
+    
+    let troopship = game_state.ship_manager.create_ship(ShipClass::Warship, Vector2::new(2.5, 0.0), player).unwrap();
+    let fly_to = |game_state: &mut GameState, x: f32| {
+        game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::MoveShip { ship: troopship, target: Vector2::new(x, 0.0) }));
+        for _ in 0..5 {
+            game_state.fixed_update(0.1).unwrap();
+        }
+    };
+    fly_to(&mut game_state, 3.0);
+    
+    // Troops come from the home planet's military workers
+    let embark = |troops| GameEvent::PlayerCommand(PlayerCommand::EmbarkTroops { ship: troopship, planet: home, troops });
+    game_state.queue_event(embark(81));
+    game_state.process_queued_events_for_test().unwrap();
+    assert!(last_rejection(&game_state).unwrap().contains("military workers: 81 requested, 80 available"));
+    game_state.queue_event(embark(60));
+    game_state.process_queued_events_for_test().unwrap();
+    assert_eq!(game_state.ship_manager.get_ship(troopship).unwrap().cargo.population, 60);
+    assert_eq!(game_state.planet_manager.get_planet(home).unwrap().population.allocation.military, 20);
+    assert_eq!(game_state.planet_manager.get_planet(home).unwrap().population.total, 440);
+    
+    // Invading needs the ship in orbit of the target; landing declares war
+    let invade = GameEvent::PlayerCommand(PlayerCommand::InvadePlanet { ship: troopship, planet: target });
+    game_state.queue_event(invade.clone());
+    game_state.process_queued_events_for_test().unwrap();
+    assert!(last_system_error(&game_state).unwrap().contains("must orbit planet"));
+    fly_to(&mut game_state, 4.0);
+    game_state.queue_event(invade);
+    game_state.process_queued_events_for_test().unwrap();
+    assert_eq!(game_state.ship_manager.get_ship(troopship).unwrap().cargo.population, 0);
+    assert!(game_state.combat_resolver.is_planet_invaded(target));
+    assert!(game_state.faction_manager.get_faction(player).unwrap().is_at_war_with(enemy));
+    
+    for _ in 0..10 {
+        game_state.fixed_update(0.1).unwrap();
+    }
+    let captured = game_state.planet_manager.get_planet(target).unwrap();
+    assert_eq!(captured.controller, Some(player));
+    assert!(captured.population.allocation.military > 0, "Surviving troops garrison the planet");
+    assert!(captured.population.total < 360);
+    assert!(game_state.event_bus.event_history.iter().any(|event| matches!(
+        event,
+        GameEvent::SimulationEvent(SimulationEvent::PlanetInvaded { planet, invader, .. }) if *planet == target && *invader == player
+    )));
+    assert!(game_state.event_bus.event_history.iter().any(|event| matches!(
+        event,
+        GameEvent::SimulationEvent(SimulationEvent::GroundCombat { planet, .. }) if *planet == target
+    )));
+}
+
+#[test]
+fn test_ship_battles_wear_down_hulls_and_send_damaged_ships_home() {
+    let mut game_state = GameState::new().unwrap();
+    game_state.current_mode = GameMode::InGame;
+    let player = game_state.faction_manager.create_faction("Player".to_string(), true, AIPersonality::Balanced).unwrap();
+    let enemy = game_state.faction_manager.create_faction("Enemy".to_string(), false, AIPersonality::Aggressive).unwrap();
+    let orbit = |semi_major_axis: f32| OrbitalElements { semi_major_axis, period: 1_000_000.0, phase: 0.0, eccentricity: 0.0, argument_of_periapsis: 0.0 };
+    let homes = [
+        (player, game_state.planet_manager.create_planet(orbit(3.0), Some(player)).unwrap()),
+        (enemy, game_state.planet_manager.create_planet(orbit(9.0), Some(enemy)).unwrap()),
+    ];
+    let guard = game_state.ship_manager.create_ship(ShipClass::Warship, Vector2::new(6.0, 0.0), player).unwrap();
+    let raider = game_state.ship_manager.create_ship(ShipClass::Warship, Vector2::new(6.2, 0.0), enemy).unwrap();
+    
+    // The battle lasts several ticks until a worn-down ship breaks off
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::AttackTarget { attacker: raider, target: guard }));
+    let resolved = |game_state: &GameState| game_state.event_bus.event_history.iter().find_map(|event| match event {
+        GameEvent::SimulationEvent(SimulationEvent::CombatResolved { attacker, defender, outcome }) => Some((*attacker, *defender, outcome.clone())),
+        _ => None,
+    });
+    while resolved(&game_state).is_none() && game_state.get_current_tick() < 30 {
+        game_state.fixed_update(0.1).unwrap();
+        assert!(game_state.combat_resolver.is_ship_in_combat(guard) || resolved(&game_state).is_some());
+    }
+    let (attacker, defender, outcome) = resolved(&game_state).expect("The battle should be decided");
+    assert_eq!((attacker, defender), (raider, guard));
+    assert!(outcome.rounds > 1, "Warships survive more than one round: {:?}", outcome);
+    assert!(outcome.attacker_losses.is_empty() && outcome.defender_losses.is_empty());
+    assert!(!outcome.retreated.is_empty());
+    
+    // Damage sticks to the ships
+    for (ship, damage) in [(raider, outcome.attacker_damage), (guard, outcome.defender_damage)] {
+        assert!(damage > 0);
+        assert_eq!(game_state.ship_manager.get_ship(ship).unwrap().damage, damage);
+        let (hull, full) = game_state.ship_manager.get_hull(ship).unwrap();
+        assert_eq!(hull, full - damage);
+    }
+    
+    // Retreating ships make for their own planet, where the hull is patched up
+    let damage: Vec<i32> = outcome.retreated.iter()
+        .map(|ship| game_state.ship_manager.get_ship(*ship).unwrap().damage)
+        .collect();
+    for _ in 0..20 {
+        game_state.fixed_update(0.1).unwrap();
+    }
+    for (ship, damage) in outcome.retreated.iter().zip(damage) {
+        let ship = game_state.ship_manager.get_ship(*ship).unwrap();
+        let home = homes.iter().find(|(owner, _)| *owner == ship.owner).unwrap().1;
+        assert_eq!(ship.status, ShipStatus::Orbiting(home));
+        assert!(ship.damage < damage);
+    }
+}
+
+#[test]
+fn test_warships_fire_missiles_when_missile_combat_is_on() {
+    let battle = |missiles: bool| {
+        let mut game_state = GameState::new().unwrap();
+        game_state.current_mode = GameMode::InGame;
+        game_state.set_game_configuration(GameConfiguration { missiles, ..Default::default() });
+        let player = game_state.faction_manager.create_faction("Player".to_string(), true, AIPersonality::Balanced).unwrap();
+        let enemy = game_state.faction_manager.create_faction("Enemy".to_string(), false, AIPersonality::Aggressive).unwrap();
+        let guard = game_state.ship_manager.create_ship(ShipClass::Warship, Vector2::new(1.0, 0.0), player).unwrap();
+        let raider = game_state.ship_manager.create_ship(ShipClass::Warship, Vector2::new(1.4, 0.0), enemy).unwrap();
+        game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::AttackTarget { attacker: raider, target: guard }));
+        game_state.step_ticks(8).unwrap();
+        (game_state, guard)
+    };
+    
+    let (calm, _) = battle(false);
+    assert!(!calm.event_bus.event_history.iter().any(|event| matches!(event, GameEvent::SimulationEvent(SimulationEvent::MissileLaunched { .. }))));
+    
+    // Both warships launch; missiles that arrive either hit or are shot
+    // down, and those still flying when the battle ends burn out
+    let (game_state, guard) = battle(true);
+    let events: Vec<&SimulationEvent> = game_state.event_bus.event_history.iter().filter_map(|event| match event {
+        GameEvent::SimulationEvent(event) => Some(event),
+        _ => None,
+    }).collect();
+    let launched = events.iter().filter(|event| matches!(event, SimulationEvent::MissileLaunched { .. })).count();
+    let settled = events.iter().filter(|event| matches!(event, SimulationEvent::MissileImpact { .. } | SimulationEvent::MissileIntercepted { .. })).count();
+    assert!(launched >= 2 && settled >= 2, "{} launched, {} settled", launched, settled);
+    assert!(settled + game_state.combat_resolver.missiles().len() <= launched);
+    
+    // Missile hits land on the ships like gunfire
+    let gunfire: i32 = events.iter().map(|event| match event {
+        SimulationEvent::ShipCombat { defender, defender_damage, .. } if *defender == guard => *defender_damage,
+        _ => 0,
+    }).sum();
+    let missile_hits: i32 = events.iter().map(|event| match event {
+        SimulationEvent::MissileImpact { hits, .. } => hits.iter().filter(|(ship, _)| *ship == guard).map(|(_, damage)| damage).sum(),
+        _ => 0,
+    }).sum();
+    assert_eq!(game_state.ship_manager.get_ship(guard).unwrap().damage, gunfire + missile_hits);
+}
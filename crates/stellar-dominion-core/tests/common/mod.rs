@@ -0,0 +1,21 @@
+// crates/stellar-dominion-core/tests/common/mod.rs
+//! Helpers shared by the simulation tests; each test file uses only some of them
+#![allow(dead_code)]
+
+use stellar_dominion_core::core::*;
+
+/// Reason given for the most recent rejected command still in the event history
+pub fn last_rejection(game_state: &GameState) -> Option<String> {
+    game_state.event_bus.event_history.iter().rev().find_map(|event| match event {
+        GameEvent::StateChanged(StateChange::CommandRejected { reason, .. }) => Some(reason.clone()),
+        _ => None,
+    })
+}
+
+/// Error reported by the most recent system failure still in the event history
+pub fn last_system_error(game_state: &GameState) -> Option<String> {
+    game_state.event_bus.event_history.iter().rev().find_map(|event| match event {
+        GameEvent::StateChanged(StateChange::SystemErrorOccurred { error, .. }) => Some(error.clone()),
+        _ => None,
+    })
+}
@@ -0,0 +1,270 @@
+// crates/stellar-dominion-core/tests/construction.rs
+//! Research, game data, and the buildings and ships planets construct
+
+mod common;
+
+use stellar_dominion_core::core::*;
+use common::{last_rejection, last_system_error};
+
+#[test]
+fn test_ship_classes_are_gated_by_spaceport_technology_and_population() {
+    let mut game_state = GameState::new().unwrap();
+    let player = game_state.faction_manager.create_faction("Player".to_string(), true, AIPersonality::Balanced).unwrap();
+    let planet = game_state.planet_manager.create_planet(OrbitalElements::default(), Some(player)).unwrap();
+    
+    let options = game_state.ship_build_options(planet).unwrap();
+    let warship = options.iter().find(|option| option.ship_class == ShipClass::Warship).unwrap();
+    assert_eq!(warship.missing, vec![
+        UnlockRequirement::Spaceport { min_tier: 2 },
+        UnlockRequirement::Technology(Technology::Military),
+    ]);
+    assert_eq!(warship.lock_reasons().len(), 2);
+    let colony = options.iter().find(|option| option.ship_class == ShipClass::Colony).unwrap();
+    assert!(!colony.is_unlocked(), "A fresh planet cannot spare colonists");
+    let scout = options.iter().find(|option| option.ship_class == ShipClass::Scout).unwrap();
+    assert_eq!(scout.missing, vec![UnlockRequirement::Spaceport { min_tier: 1 }], "Every ship needs a spaceport");
+    
+    // The construction validator rejects locked classes before anything is queued
+    let (warship_cost, _) = *game_state.construction_system.get_ship_cost(ShipClass::Warship).unwrap();
+    game_state.planet_manager.add_resources(planet, warship_cost).unwrap();
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::BuildShip { planet, ship_class: ShipClass::Warship }));
+    game_state.process_queued_events_for_test().unwrap();
+    assert!(last_system_error(&game_state).unwrap().contains("is locked"));
+    assert!(game_state.construction_system.get_ship_queue(planet).is_empty());
+    
+    game_state.planet_manager.modify_planet(planet, |p| {
+        p.developments.push(Building::new(BuildingType::Spaceport, 2));
+        Ok(())
+    }).unwrap();
+    assert!(game_state.faction_manager.unlock_technology(player, Technology::Military).unwrap());
+    let options = game_state.ship_build_options(planet).unwrap();
+    assert!(options.iter().find(|option| option.ship_class == ShipClass::Warship).unwrap().is_unlocked());
+    
+    let warship = options.iter().find(|option| option.ship_class == ShipClass::Warship).unwrap().cost;
+    game_state.planet_manager.add_resources(planet, warship).unwrap();
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::BuildShip { planet, ship_class: ShipClass::Warship }));
+    game_state.process_queued_events_for_test().unwrap();
+    assert_eq!(game_state.construction_system.get_ship_queue(planet).len(), 1);
+}
+
+#[test]
+fn test_spaceports_build_paid_ships_that_spawn_at_the_planet() {
+    let mut game_state = GameState::new().unwrap();
+    game_state.start_new_game().unwrap();
+    let player = game_state.faction_manager.get_player_faction().unwrap().id;
+    let planet = game_state.planet_manager.get_planets_by_faction(player)[0].id;
+    let build_scout = || GameEvent::PlayerCommand(PlayerCommand::BuildShip { planet, ship_class: ShipClass::Scout });
+    let (cost, build_time) = *game_state.construction_system.get_ship_cost(ShipClass::Scout).unwrap();
+    let set_stockpile = |game_state: &mut GameState, spaceport: bool| game_state.planet_manager.modify_planet(planet, |p| {
+        p.resources.current = cost;
+        p.developments.retain(|building| building.building_type != BuildingType::Spaceport);
+        if spaceport {
+            p.developments.push(Building::new(BuildingType::Spaceport, 1));
+        }
+        Ok(())
+    }).unwrap();
+    let fleet = game_state.ship_manager.get_ships_by_owner(player);
+    
+    // No spaceport, no ship, and nothing is charged
+    set_stockpile(&mut game_state, false);
+    game_state.queue_event(build_scout());
+    game_state.process_queued_events_for_test().unwrap();
+    assert!(last_system_error(&game_state).unwrap().contains("Requires a tier 1 Spaceport"));
+    assert_eq!(game_state.planet_manager.get_planet(planet).unwrap().resources.current, cost);
+    
+    set_stockpile(&mut game_state, true);
+    game_state.queue_event(build_scout());
+    game_state.process_queued_events_for_test().unwrap();
+    assert_eq!(game_state.planet_manager.get_planet(planet).unwrap().resources.current, ResourceBundle::default());
+    assert_eq!(game_state.construction_system.get_ship_queue(planet).len(), 1);
+    
+    // The spent stockpile cannot pay for a second ship
+    game_state.queue_event(build_scout());
+    game_state.process_queued_events_for_test().unwrap();
+    assert!(last_rejection(&game_state).unwrap().starts_with("Insufficient resources"));
+    assert_eq!(game_state.construction_system.get_ship_queue(planet).len(), 1);
+    
+    let start = game_state.get_current_tick();
+    while game_state.get_current_tick() < start + build_time {
+        assert_eq!(game_state.ship_manager.get_ships_by_owner(player), fleet);
+        game_state.fixed_update(0.1).unwrap();
+    }
+    game_state.fixed_update(0.1).unwrap();
+    let built: Vec<ShipId> = game_state.ship_manager.get_ships_by_owner(player).into_iter()
+        .filter(|ship| !fleet.contains(ship))
+        .collect();
+    assert_eq!(built.len(), 1, "The scout is spawned once it is finished");
+    let scout = game_state.ship_manager.get_ship(built[0]).unwrap();
+    assert_eq!(scout.ship_class, ShipClass::Scout);
+    let planet_position = game_state.physics_engine
+        .calculate_orbital_position(&game_state.planet_manager.get_planet(planet).unwrap().position, game_state.get_current_tick());
+    let offset = (scout.position.x - planet_position.x).hypot(scout.position.y - planet_position.y);
+    assert!(offset < 1.0, "The scout starts at its planet");
+    assert!(game_state.construction_system.get_ship_queue(planet).is_empty());
+}
+
+#[test]
+fn test_research_unlocks_technologies_and_the_buildings_they_gate() {
+    let mut game_state = GameState::new().unwrap();
+    game_state.start_new_game().unwrap();
+    let player = game_state.faction_manager.get_player_faction().unwrap().id;
+    let home = game_state.planet_manager.get_planets_by_faction(player)[0].id;
+    let research = |game_state: &GameState| game_state.faction_manager.get_faction(player).unwrap().research.clone();
+    
+    // Defense platforms need Orbital Defense, which needs Military first
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::BuildStructure { planet: home, building_type: BuildingType::DefensePlatform }));
+    game_state.fixed_update(0.1).unwrap();
+    assert!(last_system_error(&game_state).unwrap().contains("Requires OrbitalDefense technology"));
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::SetResearchTarget { faction: player, technology: Technology::OrbitalDefense }));
+    game_state.fixed_update(0.1).unwrap();
+    assert!(last_system_error(&game_state).unwrap().contains("requires Military first"));
+    assert_eq!(research(&game_state).target, None);
+    
+    // Research workers on the home planet put points into the target every research update
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::SetResearchTarget { faction: player, technology: Technology::Military }));
+    for _ in 0..25 {
+        game_state.fixed_update(0.1).unwrap();
+    }
+    let progress = research(&game_state).progress_of(Technology::Military);
+    assert_eq!(research(&game_state).target, Some(Technology::Military));
+    assert!(progress > 0 && progress < game_state.research_system.tree().cost(Technology::Military).unwrap());
+    
+    // Cheapen Military so it completes on the next update
+    game_state.research_system.tree_mut().set_info(Technology::Military, TechnologyInfo {
+        cost: progress + 1,
+        prerequisites: Vec::new(),
+        production_bonus: None,
+    });
+    for _ in 0..12 {
+        game_state.fixed_update(0.1).unwrap();
+    }
+    let faction = game_state.faction_manager.get_faction(player).unwrap();
+    assert!(faction.has_technology(Technology::Military));
+    assert_eq!(faction.research, ResearchState::default(), "Finished research leaves no target or progress behind");
+    assert!(game_state.event_bus.event_history.iter().any(|event| matches!(
+        event,
+        GameEvent::SimulationEvent(SimulationEvent::TechnologyUnlocked { technology: Technology::Military, .. })
+    )));
+    
+    // Orbital Defense can now be researched, and unlocks the platform
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::SetResearchTarget { faction: player, technology: Technology::OrbitalDefense }));
+    game_state.fixed_update(0.1).unwrap();
+    assert!(game_state.faction_manager.unlock_technology(player, Technology::OrbitalDefense).unwrap());
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::BuildStructure { planet: home, building_type: BuildingType::DefensePlatform }));
+    game_state.fixed_update(0.1).unwrap();
+    assert!(game_state.planet_manager.get_planet(home).unwrap().developments.iter()
+        .any(|building| building.building_type == BuildingType::DefensePlatform));
+}
+
+#[test]
+fn test_game_data_files_rebalance_buildings_and_ships() {
+    let mut game_state = GameState::new().unwrap();
+    let game_data = GameDataRegistry::from_ron(r#"(
+        buildings: {
+            Mine: (cost: (minerals: 40, food: 0, energy: 0, alloys: 5, components: 0, fuel: 0), build_time: 4,
+                   production: (minerals: 25, food: 0, energy: 0, alloys: 0, components: 0, fuel: 0)),
+        },
+        ships: {
+            Scout: (cost: (minerals: 20, food: 0, energy: 0, alloys: 10, components: 5, fuel: 5), build_time: 3,
+                    cargo_capacity: 50, fuel: 150.0, fuel_efficiency: 300.0),
+        },
+    )"#).unwrap();
+    game_state.set_game_data(game_data).unwrap();
+    game_state.start_new_game().unwrap();
+    
+    let (scout_cost, scout_time) = *game_state.construction_system.get_ship_cost(ShipClass::Scout).unwrap();
+    assert_eq!((scout_cost.minerals, scout_time), (20, 3));
+    assert_eq!(game_state.construction_system.get_building_cost(BuildingType::Mine).unwrap().1, 4);
+    assert_eq!(game_state.ship_manager.calculate_fuel_cost_for_class(ShipClass::Scout, 300.0), 1.0);
+    
+    // Starting ships are built after the data was set, and survive a new game
+    let player = game_state.faction_manager.get_player_faction().unwrap().id;
+    let scout = game_state.ship_manager.get_ships_by_owner(player)[0];
+    let scout = game_state.ship_manager.get_ship(scout).unwrap();
+    assert_eq!((scout.cargo.capacity, scout.fuel), (50, 150.0));
+    
+    let planet = game_state.planet_manager.get_planets_by_faction(player)[0].id;
+    game_state.planet_manager.modify_planet(planet, |p| {
+        p.population.allocation = WorkerAllocation { unassigned: p.population.total, ..Default::default() };
+        p.developments = vec![Building::new(BuildingType::Mine, 2)];
+        Ok(())
+    }).unwrap();
+    let production = game_state.resource_system
+        .calculate_planet_production(game_state.planet_manager.get_planet(planet).unwrap()).unwrap();
+    assert_eq!(production.minerals, 50);
+    
+    let mut invalid = game_state.game_data().clone();
+    invalid.set_ship(ShipClass::Scout, ShipData { build_time: 0, ..*invalid.ship(ShipClass::Scout).unwrap() });
+    assert!(game_state.set_game_data(invalid).is_err());
+}
+
+#[test]
+fn test_ship_designs_are_saved_built_and_keep_their_stats() {
+    use stellar_dominion_core::core::{HullSize, ShipDesigner, ShipModule};
+    
+    let mut game_state = GameState::new().unwrap();
+    game_state.start_new_game().unwrap();
+    let player = game_state.faction_manager.get_player_faction().unwrap().id;
+    let planet = game_state.planet_manager.get_planets_by_faction(player)[0].id;
+    
+    // A hauler with more cargo than the Transport preset
+    let mut designer = ShipDesigner::new(HullSize::Medium);
+    for module in [ShipModule::Engine, ShipModule::CargoPod, ShipModule::CargoPod, ShipModule::CargoPod] {
+        designer.add_module(module).unwrap();
+    }
+    assert!(designer.add_module(ShipModule::CargoPod).is_err(), "A medium hull has four slots");
+    designer.set_hull(HullSize::Large);
+    designer.add_module(ShipModule::CargoPod).unwrap();
+    assert!(designer.finish("").is_err(), "A design needs a name");
+    let design = designer.finish("Hauler").unwrap();
+    assert_eq!(design.role(), ShipClass::Transport);
+    assert!(ShipDesigner::new(HullSize::Small).finish("Drifter").is_err(), "A design needs an engine");
+    
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::SaveShipDesign { faction: player, design: design.clone() }));
+    game_state.process_queued_events_for_test().unwrap();
+    let saved = game_state.faction_manager.get_faction(player).unwrap().ship_designs.clone();
+    assert_eq!(saved.len(), 1);
+    let id = saved[0].id;
+    // Saving under the same name replaces the design and keeps its id
+    assert_eq!(game_state.faction_manager.save_ship_design(player, design.clone()).unwrap(), id);
+    
+    game_state.planet_manager.modify_planet(planet, |p| {
+        p.developments.push(Building::new(BuildingType::Spaceport, 1));
+        Ok(())
+    }).unwrap();
+    let options = game_state.ship_build_options(planet).unwrap();
+    let option = options.iter().find(|option| option.name() == "Hauler").unwrap().clone();
+    assert!(option.is_unlocked());
+    assert_eq!((option.cost, option.build_time), (design.stats().cost, design.stats().build_time));
+    
+    game_state.planet_manager.modify_planet(planet, |p| {
+        p.resources.current = option.cost;
+        Ok(())
+    }).unwrap();
+    let fleet = game_state.ship_manager.get_ships_by_owner(player);
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::BuildDesignedShip { planet, design: id }));
+    game_state.process_queued_events_for_test().unwrap();
+    assert_eq!(game_state.planet_manager.get_planet(planet).unwrap().resources.current, ResourceBundle::default());
+    
+    let start = game_state.get_current_tick();
+    while game_state.get_current_tick() <= start + option.build_time {
+        game_state.fixed_update(0.1).unwrap();
+    }
+    let built: Vec<ShipId> = game_state.ship_manager.get_ships_by_owner(player).into_iter()
+        .filter(|ship| !fleet.contains(ship))
+        .collect();
+    assert_eq!(built.len(), 1);
+    let ship = game_state.ship_manager.get_ship(built[0]).unwrap().clone();
+    assert_eq!(ship.ship_class, ShipClass::Transport);
+    assert_eq!(ship.design.as_ref().map(|design| design.name.as_str()), Some("Hauler"));
+    let stats = game_state.ship_manager.ship_stats(&ship).unwrap();
+    assert_eq!(stats.cargo_capacity, 1000, "Four cargo pods");
+    assert_eq!(ship.speed(), design.stats().speed);
+    assert!(ship.speed() < stellar_dominion_core::config::SHIP_BASE_SPEED, "One engine barely moves a large hull");
+    
+    // Designs the faction does not have are refused
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::BuildDesignedShip { planet, design: id + 1 }));
+    game_state.process_queued_events_for_test().unwrap();
+    assert!(last_rejection(&game_state).is_some());
+    assert!(game_state.construction_system.get_ship_queue(planet).is_empty());
+}
@@ -0,0 +1,452 @@
+// crates/stellar-dominion-core/tests/economy.rs
+//! Planet economy: production, storage, power, upkeep, taxes, population and trade routes
+
+mod common;
+
+use stellar_dominion_core::core::*;
+use stellar_dominion_core::systems::NotificationKind;
+use common::last_rejection;
+
+#[test]
+fn test_happiness_slows_unhappy_planets_and_drives_migration() {
+    let mut game_state = GameState::new().unwrap();
+    let unhappy = game_state.planet_manager.create_planet(OrbitalElements::default(), Some(0)).unwrap();
+    let happy = game_state.planet_manager.create_planet(OrbitalElements::default(), Some(0)).unwrap();
+    for planet in [unhappy, happy] {
+        game_state.planet_manager.update_population(planet, 1000).unwrap();
+    }
+    game_state.planet_manager.set_worker_allocation(unhappy, WorkerAllocation {
+        mining: 500,
+        unassigned: 500,
+        ..Default::default()
+    }).unwrap();
+    game_state.planet_manager.add_resources(happy, ResourceBundle { food: 2000, ..Default::default() }).unwrap();
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::SetTaxRate { planet: unhappy, rate: TaxRate::High }));
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::SetTaxRate { planet: happy, rate: TaxRate::Low }));
+    game_state.process_queued_events_for_test().unwrap();
+    assert_eq!(game_state.planet_manager.get_planet(happy).unwrap().tax_rate, TaxRate::Low);
+    
+    // A starving, heavily taxed planet is unhappy; a fed, lightly taxed one is happy
+    let mut bus = EventBus::new();
+    for planet in [unhappy, happy] {
+        let planet = game_state.planet_manager.get_planet(planet).unwrap().clone();
+        game_state.population_system.update_happiness(&planet, TaxRate::Normal, &mut bus).unwrap();
+    }
+    let planets = vec![
+        game_state.planet_manager.get_planet(unhappy).unwrap().clone(),
+        game_state.planet_manager.get_planet(happy).unwrap().clone(),
+    ];
+    game_state.population_system.process_migration(&planets, &mut bus).unwrap();
+    assert!(bus.queued_events.iter().any(|e| matches!(e,
+        GameEvent::SimulationEvent(SimulationEvent::PopulationMigrated { from, to, amount: 20 }) if *from == unhappy && *to == happy)));
+    for event in bus.queued_events.drain(..) {
+        game_state.queue_event(event);
+    }
+    game_state.process_queued_events_for_test().unwrap();
+    
+    let starving = game_state.planet_manager.get_planet(unhappy).unwrap();
+    assert!(starving.population.happiness < 0.0);
+    assert_eq!(starving.population.happiness, game_state.population_system.get_happiness(unhappy));
+    assert!(game_state.planet_manager.get_planet(happy).unwrap().population.happiness > 0.0);
+    
+    // Unhappiness outweighs the extra taxes on the planet's output
+    let production = game_state.resource_system.calculate_planet_production(starving).unwrap();
+    assert!(starving.productivity() < 1.0);
+    assert_eq!(production.minerals, (1000.0 * starving.productivity()).floor() as i32);
+    
+    // Settlers left the unhappy planet for the happy one
+    assert_eq!(starving.population.total, 980);
+    assert_eq!(game_state.planet_manager.get_planet(happy).unwrap().population.total, 1020);
+}
+
+#[test]
+fn test_planet_traits_scale_production_housing_and_slots() {
+    use stellar_dominion_core::systems::ResourceSystem;
+    use stellar_dominion_core::managers::PlanetManager;
+
+    let mut planet_manager = PlanetManager::new();
+    let id = planet_manager.create_planet(OrbitalElements::default(), Some(0)).unwrap();
+    planet_manager.modify_planet(id, |planet| {
+        planet.population.total = 5000;
+        planet.population.allocation = WorkerAllocation { agriculture: 2000, mining: 2000, unassigned: 1000, ..Default::default() };
+        planet.developments = vec![
+            Building::new(BuildingType::Mine, 1),
+            Building::new(BuildingType::Farm, 1),
+        ];
+        Ok(())
+    }).unwrap();
+    let ordinary = planet_manager.get_planet(id).unwrap().clone();
+    let mut lush = ordinary.clone();
+    lush.traits = PlanetTraits { mineral_richness: 1.5, fertility: 1.5, habitability: 1.5 };
+    lush.size = ordinary.size + 2;
+
+    let resources = ResourceSystem::new();
+    let base = resources.calculate_planet_production(&ordinary).unwrap();
+    let rich = resources.calculate_planet_production(&lush).unwrap();
+    assert!(base.minerals > 0 && base.food > 0);
+    assert_eq!(rich.minerals, (base.minerals as f32 * 1.5).floor() as i32);
+    assert_eq!(rich.food, (base.food as f32 * 1.5).floor() as i32);
+    assert_eq!(rich.energy, base.energy, "Traits leave other resources alone");
+
+    assert!(lush.housing_capacity() > ordinary.housing_capacity());
+    assert_eq!(lush.building_slots(), ordinary.building_slots() + 2);
+
+    let mut barren = ordinary.clone();
+    barren.traits.mineral_richness = 2.0;
+    planet_manager.load_planets(vec![barren]).unwrap();
+    assert!(planet_manager.validate_all_planets().is_err(), "Traits must stay in range");
+}
+
+#[test]
+fn test_planet_production_is_applied_in_planet_order_and_repeats_exactly() {
+    use stellar_dominion_core::bench_support::synthetic_game;
+    
+    let run = || {
+        let mut game_state = synthetic_game(7, 40, 10).unwrap();
+        for _ in 0..15 {
+            game_state.fixed_update(0.1).unwrap();
+        }
+        game_state
+    };
+    let first = run();
+    let second = run();
+    assert_eq!(format!("{:?}", first.planet_manager.get_all_planets()), format!("{:?}", second.planet_manager.get_all_planets()));
+    
+    // Every settled planet reports once per tick, lowest id first
+    let tick = first.get_current_tick();
+    let produced: Vec<PlanetId> = first.event_bus.events_since(tick)
+        .filter_map(|entry| match &entry.event {
+            GameEvent::SimulationEvent(SimulationEvent::ResourcesProduced { planet, .. }) => Some(*planet),
+            _ => None,
+        })
+        .collect();
+    let mut settled: Vec<PlanetId> = first.planet_manager.get_all_planets().iter()
+        .filter(|planet| planet.controller.is_some())
+        .map(|planet| planet.id)
+        .collect();
+    settled.sort_unstable();
+    assert!(!produced.is_empty());
+    assert!(produced.chunks(settled.len()).all(|tick_events| tick_events == settled.as_slice()), "{:?}", produced);
+}
+
+#[test]
+fn test_energy_grid_powers_buildings_in_priority_order() {
+    let mut game_state = GameState::new().unwrap();
+    game_state.current_mode = GameMode::InGame;
+    let player = game_state.faction_manager.create_faction("Player".to_string(), true, AIPersonality::Balanced).unwrap();
+    let planet = game_state.planet_manager.create_planet(OrbitalElements::default(), Some(player)).unwrap();
+    for building_type in [BuildingType::Farm, BuildingType::Mine, BuildingType::Mine] {
+        game_state.planet_manager.add_building(planet, building_type).unwrap();
+    }
+    game_state.planet_manager.add_resources(planet, ResourceBundle { energy: 3, ..Default::default() }).unwrap();
+    let demands: Vec<i32> = game_state.planet_manager.get_planet(planet).unwrap().developments.iter()
+        .map(|building| game_state.resource_system.energy_demand(building))
+        .collect();
+    assert_eq!(demands, vec![1, 2, 2]);
+
+    // Three energy power the farm and the first mine; the second mine shuts down
+    let powered = |game_state: &GameState| -> Vec<bool> {
+        game_state.planet_manager.get_planet(planet).unwrap().developments.iter().map(|building| building.is_operational()).collect()
+    };
+    let unpowered_events = |game_state: &GameState| -> Vec<usize> {
+        game_state.event_bus.event_history.iter().filter_map(|event| match event {
+            GameEvent::StateChanged(StateChange::BuildingStatusChanged { planet: id, index, status: BuildingStatus::Shutdown(ShutdownReason::Unpowered) }) if *id == planet => Some(*index),
+            _ => None,
+        }).collect()
+    };
+    game_state.step_ticks(1).unwrap();
+    assert_eq!(powered(&game_state), vec![true, true, false]);
+    assert_eq!(unpowered_events(&game_state), vec![2]);
+    assert_eq!(game_state.planet_manager.get_planet(planet).unwrap().resources.current.energy, 0, "Powered buildings drew the energy");
+
+    // With mines first, two energy keep one mine running and the farm goes dark
+    let priority = PowerPriority { order: vec![BuildingType::Mine, BuildingType::Farm] };
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::SetPowerPriority { planet, priority: priority.clone() }));
+    game_state.planet_manager.add_resources(planet, ResourceBundle { energy: 2, ..Default::default() }).unwrap();
+    game_state.step_ticks(1).unwrap();
+    assert_eq!(game_state.planet_manager.get_planet(planet).unwrap().power_priority, priority);
+    assert_eq!(powered(&game_state), vec![false, true, false]);
+    let food = game_state.planet_manager.get_planet(planet).unwrap().resources.current.food;
+    game_state.planet_manager.add_resources(planet, ResourceBundle { energy: 2, ..Default::default() }).unwrap();
+    game_state.step_ticks(1).unwrap();
+    assert_eq!(game_state.planet_manager.get_planet(planet).unwrap().resources.current.food, food, "The unpowered farm grows nothing");
+
+    // A power plant needs no energy and covers every demand
+    game_state.planet_manager.add_building(planet, BuildingType::PowerPlant).unwrap();
+    game_state.step_ticks(1).unwrap();
+    assert_eq!(powered(&game_state), vec![true, true, true, true]);
+    assert!(game_state.event_bus.event_history.iter().any(|event| matches!(event,
+        GameEvent::StateChanged(StateChange::BuildingStatusChanged { planet: id, index: 2, status: BuildingStatus::Operational }) if *id == planet)));
+}
+
+#[test]
+fn test_storage_facilities_raise_capacity() {
+    let mut game_state = GameState::new().unwrap();
+    let planet = game_state.planet_manager.create_planet(OrbitalElements::default(), Some(0)).unwrap();
+    let capacity = |game_state: &GameState| game_state.planet_manager.get_planet(planet).unwrap().resources.capacity;
+    assert_eq!(capacity(&game_state), BASE_STORAGE_CAPACITY);
+
+    // Each StorageFacility tier adds its share on top of the base
+    game_state.planet_manager.add_building(planet, BuildingType::StorageFacility).unwrap();
+    game_state.planet_manager.add_building(planet, BuildingType::StorageFacility).unwrap();
+    let breakdown = game_state.planet_manager.get_planet(planet).unwrap().storage_breakdown();
+    assert_eq!(breakdown.facility_tiers, 2);
+    assert_eq!(breakdown.base, BASE_STORAGE_CAPACITY);
+    assert_eq!(capacity(&game_state).energy, BASE_STORAGE_CAPACITY.energy + 2 * STORAGE_PER_FACILITY_TIER.energy);
+    assert_eq!(capacity(&game_state), breakdown.total());
+
+    // Tearing a facility down shrinks capacity and spills the excess
+    game_state.planet_manager.add_resources(planet, ResourceBundle { energy: 1800, ..Default::default() }).unwrap();
+    game_state.planet_manager.remove_building(planet, 1).unwrap();
+    assert_eq!(capacity(&game_state).energy, BASE_STORAGE_CAPACITY.energy + STORAGE_PER_FACILITY_TIER.energy);
+    assert_eq!(game_state.planet_manager.get_planet(planet).unwrap().resources.current.energy, capacity(&game_state).energy);
+}
+
+#[test]
+fn test_faction_treasury_collects_taxes_and_pays_upkeep() {
+    use stellar_dominion_core::systems::{SHIP_UPKEEP_CREDITS, BUILDING_MAINTENANCE_CREDITS};
+    
+    let mut game_state = GameState::new().unwrap();
+    game_state.current_mode = GameMode::InGame;
+    let player = game_state.faction_manager.create_faction("Player".to_string(), true, AIPersonality::Balanced).unwrap();
+    let home = game_state.planet_manager.create_planet(OrbitalElements::default(), Some(player)).unwrap();
+    game_state.planet_manager.update_population(home, 2000).unwrap();
+    game_state.planet_manager.add_building(home, BuildingType::StorageFacility).unwrap();
+    game_state.ship_manager.create_ship(ShipClass::Scout, Vector2::new(50.0, 0.0), player).unwrap();
+    let credits = |game_state: &GameState| game_state.faction_manager.get_faction(player).unwrap().credits;
+    
+    // Two thousand content people pay twenty credits at the normal rate
+    let budget = game_state.faction_budget(player).unwrap();
+    assert_eq!(budget.income, 2 * TaxRate::Normal.credits_per_thousand());
+    assert_eq!(budget.ship_upkeep, SHIP_UPKEEP_CREDITS);
+    assert_eq!(budget.building_maintenance, BUILDING_MAINTENANCE_CREDITS);
+    game_state.step_ticks(1).unwrap();
+    assert_eq!(credits(&game_state), STARTING_CREDITS + budget.net());
+    
+    // Raising the empire tax brings in more and sours every planet
+    game_state.issue_command(player, PlayerCommand::SetFactionTaxRate { faction: player, rate: TaxRate::High });
+    game_state.step_ticks(10).unwrap();
+    assert_eq!(game_state.faction_manager.get_faction(player).unwrap().tax_rate, TaxRate::High);
+    let planet = game_state.planet_manager.get_planet(home).unwrap();
+    let happiness = planet.population.happiness;
+    assert!(happiness < game_state.population_system.calculate_happiness(planet, TaxRate::Normal));
+    let compliance = 1.0 + happiness * 0.5;
+    let expected = (2.0 * TaxRate::High.credits_per_thousand() as f32 * compliance).floor() as i32;
+    assert_eq!(game_state.faction_budget(player).unwrap().income, expected, "Unhappy planets pay less of the tax");
+    
+    // Another faction cannot set the player's taxes
+    let rival = game_state.faction_manager.create_faction("Rival".to_string(), false, AIPersonality::Balanced).unwrap();
+    game_state.issue_command(rival, PlayerCommand::SetFactionTaxRate { faction: player, rate: TaxRate::Low });
+    game_state.process_queued_events_for_test().unwrap();
+    assert!(last_rejection(&game_state).is_some());
+    assert_eq!(game_state.faction_manager.get_faction(player).unwrap().tax_rate, TaxRate::High);
+    
+    // Upkeep the treasury cannot cover goes unpaid instead of into debt
+    game_state.planet_manager.update_population(home, -2000).unwrap();
+    let balance = credits(&game_state);
+    game_state.faction_manager.add_credits(player, -balance).unwrap();
+    game_state.step_ticks(1).unwrap();
+    assert!(game_state.faction_budget(player).unwrap().net() < 0);
+    assert_eq!(credits(&game_state), 0);
+}
+
+#[test]
+fn test_planet_transactions_apply_all_or_nothing() {
+    let mut planet_manager = stellar_dominion_core::managers::PlanetManager::new();
+    let planet_id = planet_manager.create_planet(OrbitalElements::default(), Some(0)).unwrap();
+    planet_manager.add_resources(planet_id, ResourceBundle { minerals: 100, food: 50, ..Default::default() }).unwrap();
+    let capacity = planet_manager.get_planet(planet_id).unwrap().resources.capacity;
+    
+    // Staged changes are visible inside the transaction and applied together
+    let mut transaction = planet_manager.transaction(planet_id).unwrap();
+    let stored = transaction.store_up_to_capacity(&ResourceBundle { minerals: capacity.minerals, ..Default::default() }).unwrap();
+    assert_eq!(stored.minerals, capacity.minerals - 100);
+    transaction.remove_resources(&ResourceBundle { food: 20, ..Default::default() }).unwrap();
+    assert_eq!(transaction.planet().resources.current.food, 30);
+    let committed = transaction.commit().unwrap();
+    assert_eq!(committed.resources.current.minerals, capacity.minerals);
+    assert_eq!(committed.resources.current.food, 30);
+    
+    // A transaction that fails validation leaves the planet untouched
+    let mut transaction = planet_manager.transaction(planet_id).unwrap();
+    transaction.remove_resources(&ResourceBundle { food: 30, ..Default::default() }).unwrap();
+    transaction.add_resources(&ResourceBundle { minerals: 1, ..Default::default() }).unwrap();
+    assert!(transaction.commit().is_err());
+    assert_eq!(planet_manager.get_planet(planet_id).unwrap().resources.current.food, 30);
+    
+    // Dropping a transaction discards it
+    let mut transaction = planet_manager.transaction(planet_id).unwrap();
+    transaction.modify(|planet| { planet.tax_rate = TaxRate::High; Ok(()) }).unwrap();
+    drop(transaction);
+    assert_eq!(planet_manager.get_planet(planet_id).unwrap().tax_rate, TaxRate::default());
+    assert!(planet_manager.transaction(9_999).is_err());
+}
+
+#[test]
+fn test_unaffordable_buildings_shut_down_and_come_back() {
+    let mut game_state = GameState::new().unwrap();
+    game_state.set_game_configuration(GameConfiguration { seed: 5, ai_opponents: 1, planet_count: 6, ..Default::default() });
+    game_state.start_new_game().unwrap();
+    let home = game_state.planet_manager.get_planets_by_faction(0)[0].id;
+    
+    // Two factories eat minerals the planet neither has nor mines
+    game_state.planet_manager.modify_planet(home, |planet| {
+        planet.population.allocation.unassigned += planet.population.allocation.mining;
+        planet.population.allocation.mining = 0;
+        planet.resources.current.minerals = 0;
+        planet.resources.current.energy = 100;
+        planet.developments = vec![
+            Building::new(BuildingType::PowerPlant, 1),
+            Building::new(BuildingType::Factory, 1),
+            Building::new(BuildingType::Factory, 1),
+        ];
+        Ok(())
+    }).unwrap();
+    game_state.step_ticks(1).unwrap();
+    let planet = game_state.planet_manager.get_planet(home).unwrap();
+    let unpaid = BuildingStatus::Shutdown(ShutdownReason::UpkeepUnpaid);
+    assert_eq!(planet.developments[0].status, BuildingStatus::Operational, "The power plant costs nothing to run");
+    assert!(planet.developments[1..].iter().all(|building| building.status == unpaid && !building.is_operational()));
+    let disabled = game_state.event_bus.event_history.iter()
+        .filter(|event| matches!(event, GameEvent::StateChanged(StateChange::BuildingStatusChanged { planet, status, .. }) if *planet == home && *status == unpaid))
+        .count();
+    assert_eq!(disabled, 2);
+    
+    // Buildings shut down for upkeep stay off the grid while the planet still cannot pay
+    game_state.step_ticks(3).unwrap();
+    assert!(game_state.notification_system.notifications(0).iter()
+        .any(|notification| notification.kind == NotificationKind::UpkeepUnpaid { planet: home }));
+    assert!(game_state.planet_manager.get_planet(home).unwrap().developments[1..].iter().all(|building| building.status == unpaid));
+    
+    // Once minerals arrive the factories start again
+    game_state.planet_manager.add_resources(home, ResourceBundle { minerals: 100, ..Default::default() }).unwrap();
+    game_state.step_ticks(1).unwrap();
+    let planet = game_state.planet_manager.get_planet(home).unwrap();
+    assert!(planet.developments.iter().all(|building| building.is_operational()));
+    assert!(game_state.event_bus.event_history.iter()
+        .any(|event| matches!(event, GameEvent::StateChanged(StateChange::BuildingStatusChanged { planet, index: 1, status: BuildingStatus::Operational }) if *planet == home)));
+}
+
+#[test]
+fn test_production_reports_break_rates_down_by_source() {
+    use stellar_dominion_core::systems::ProductionSource;
+    
+    let mut game_state = GameState::new().unwrap();
+    game_state.set_game_configuration(GameConfiguration { seed: 8, ai_opponents: 1, planet_count: 6, ..Default::default() });
+    game_state.start_new_game().unwrap();
+    let home = game_state.planet_manager.get_planets_by_faction(0)[0].id;
+    game_state.planet_manager.modify_planet(home, |planet| {
+        planet.population.allocation = WorkerAllocation { mining: 100, agriculture: 50, unassigned: planet.population.total - 150, ..Default::default() };
+        planet.developments = vec![
+            Building::new(BuildingType::Mine, 1),
+            Building::new(BuildingType::Mine, 1),
+            Building::new(BuildingType::Factory, 1),
+            Building { status: BuildingStatus::Shutdown(ShutdownReason::Unpowered), ..Building::new(BuildingType::Farm, 1) },
+        ];
+        Ok(())
+    }).unwrap();
+    
+    let report = game_state.production_report(home).unwrap();
+    let line = |lines: &[stellar_dominion_core::systems::ProductionLine], source| lines.iter().find(|line| line.source == source).cloned();
+    let mines = line(&report.gross, ProductionSource::Buildings(BuildingType::Mine)).unwrap();
+    assert_eq!((mines.count, mines.resources.minerals), (2, 20));
+    assert_eq!(line(&report.gross, ProductionSource::Miners).unwrap().resources.minerals, 200);
+    assert!(line(&report.gross, ProductionSource::Buildings(BuildingType::Farm)).is_none(), "Unpowered buildings make nothing");
+    let factory = line(&report.consumption, ProductionSource::Buildings(BuildingType::Factory)).unwrap();
+    assert_eq!((factory.resources.minerals, factory.resources.energy), (5, 3));
+    
+    // Gross less consumption plus modifiers is what the planet actually makes
+    let planet = game_state.planet_manager.get_planet(home).unwrap();
+    assert_eq!(report.net, game_state.resource_system.calculate_planet_production(planet).unwrap());
+    assert_eq!(report.gross_total() + report.modifiers, report.net + report.consumption_total());
+    let minerals = report.describe(ResourceType::Minerals);
+    assert!(minerals.starts_with(&format!("{:+} minerals/tick (", report.net.minerals)), "{}", minerals);
+    assert!(minerals.contains("Mine x2") && minerals.contains("miners 100") && minerals.contains("-5 Factory x1"), "{}", minerals);
+    assert!(game_state.production_report(9_999).is_err());
+}
+
+#[test]
+fn test_empire_economy_projects_time_to_full_and_empty() {
+    use stellar_dominion_core::systems::{OutlookLevel, ResourceOutlook};
+    
+    let mut game_state = GameState::new().unwrap();
+    game_state.set_game_configuration(GameConfiguration { seed: 8, ai_opponents: 1, planet_count: 6, ..Default::default() });
+    game_state.start_new_game().unwrap();
+    let planets: Vec<PlanetId> = game_state.planet_manager.get_planets_by_faction(0).iter().map(|planet| planet.id).collect();
+    
+    // Stockpiles and rates are the sums over the faction's planets, with
+    // food eaten by their populations
+    let mut stockpile = 0;
+    let mut net = 0;
+    let mut food = 0;
+    for &id in &planets {
+        let planet = game_state.planet_manager.get_planet(id).unwrap().clone();
+        let report = game_state.production_report(id).unwrap();
+        stockpile += planet.resources.current.minerals;
+        net += report.net.minerals;
+        food += report.net.food - planet.population.total;
+    }
+    let economy = game_state.empire_economy(0).unwrap().clone();
+    assert_eq!(economy.planets, planets.len());
+    assert_eq!(economy.outlooks.len(), ResourceType::ALL.len());
+    let minerals = *economy.outlook(ResourceType::Minerals).unwrap();
+    assert_eq!((minerals.stockpile, minerals.net_per_tick), (stockpile, net));
+    assert_eq!(economy.outlook(ResourceType::Food).unwrap().net_per_tick, food);
+    
+    // A planet change within the tick is picked up
+    let home = planets[0];
+    game_state.planet_manager.modify_planet(home, |planet| {
+        planet.population.allocation = WorkerAllocation { unassigned: planet.population.total, ..Default::default() };
+        planet.resources.current.food = 50;
+        Ok(())
+    }).unwrap();
+    let food = *game_state.empire_economy(0).unwrap().outlook(ResourceType::Food).unwrap();
+    assert!(food.net_per_tick < 0, "Nobody farms, so the population eats the stockpile");
+    assert_eq!(food.ticks_to_empty(), Some(food.stockpile as u64 / food.net_per_tick.unsigned_abs() as u64));
+    assert_eq!(food.ticks_to_full(), None);
+    
+    let outlook = |stockpile, net_per_tick| ResourceOutlook { resource: ResourceType::Alloys, stockpile, capacity: 1000, net_per_tick };
+    assert_eq!(outlook(100, -2).level(), OutlookLevel::Critical);
+    assert_eq!(outlook(990, 1).ticks_to_full(), Some(10));
+    assert_eq!(outlook(990, 1).level(), OutlookLevel::Warning);
+    assert_eq!(outlook(100, 0).level(), OutlookLevel::Steady);
+    assert!(game_state.empire_economy(200).is_err());
+}
+
+#[test]
+fn test_trade_route_ships_cargo_until_cancelled() {
+    let mut game_state = GameState::new().unwrap();
+    game_state.current_mode = GameMode::InGame;
+    let player = game_state.faction_manager.create_faction("Player".to_string(), true, AIPersonality::Economic).unwrap();
+    let orbit = |phase| OrbitalElements { semi_major_axis: 10.0, period: 1_000_000.0, phase, eccentricity: 0.0, argument_of_periapsis: 0.0 };
+    let source = game_state.planet_manager.create_planet(orbit(0.0), Some(player)).unwrap();
+    let destination = game_state.planet_manager.create_planet(orbit(std::f32::consts::PI), Some(player)).unwrap();
+    game_state.planet_manager.add_resources(source, ResourceBundle { minerals: 500, ..Default::default() }).unwrap();
+    let transport = game_state.ship_manager.create_ship(ShipClass::Transport, Vector2::new(10.0, 0.0), player).unwrap();
+    let minerals = |game_state: &GameState, planet| game_state.planet_manager.get_planet(planet).unwrap().resources.current.minerals;
+    
+    let route = TradeRoute { ship: transport, from: source, to: destination, resources: ResourceBundle { minerals: 100, ..Default::default() } };
+    game_state.issue_command(player, PlayerCommand::CreateTradeRoute(route));
+    
+    // The transport loads at the source, crosses over and unloads, again and again
+    let mut ticks = 0;
+    while minerals(&game_state, destination) < 200 && ticks < 200 {
+        game_state.step_ticks(1).unwrap();
+        ticks += 1;
+    }
+    assert_eq!(minerals(&game_state, destination), 200, "Two trips delivered within {} ticks", ticks);
+    assert!(minerals(&game_state, source) <= 400);
+    assert!(last_rejection(&game_state).is_none(), "Route orders are only given when they can be carried out");
+    
+    // Once cancelled the ship finishes the leg it is on and gets no new orders
+    game_state.issue_command(player, PlayerCommand::CancelTradeRoute(transport));
+    game_state.step_ticks(50).unwrap();
+    let snapshot = |game_state: &GameState| {
+        let ship = game_state.ship_manager.get_ship(transport).unwrap();
+        (minerals(game_state, source), minerals(game_state, destination), ship.cargo.current_load(), ship.trajectory.is_some())
+    };
+    let settled = snapshot(&game_state);
+    assert!(!settled.3);
+    game_state.step_ticks(50).unwrap();
+    assert_eq!(snapshot(&game_state), settled);
+}
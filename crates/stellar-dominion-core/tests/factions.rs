@@ -0,0 +1,288 @@
+// crates/stellar-dominion-core/tests/factions.rs
+//! What factions see and do: sensors, AI, diplomacy, espionage, pirates and objectives
+
+mod common;
+
+use stellar_dominion_core::core::*;
+use stellar_dominion_core::systems::NotificationKind;
+use common::{last_rejection, last_system_error};
+
+#[test]
+fn test_factions_only_see_what_their_sensors_reach() {
+    let mut game_state = GameState::new().unwrap();
+    game_state.current_mode = GameMode::InGame;
+    let player = game_state.faction_manager.create_faction("Player".to_string(), true, AIPersonality::Balanced).unwrap();
+    let enemy = game_state.faction_manager.create_faction("Enemy".to_string(), false, AIPersonality::Economic).unwrap();
+    let orbit = |semi_major_axis: f32| OrbitalElements { semi_major_axis, period: 1_000_000.0, phase: 0.0, eccentricity: 0.0, argument_of_periapsis: 0.0 };
+    let home = game_state.planet_manager.create_planet(orbit(3.0), Some(player)).unwrap();
+    let distant = game_state.planet_manager.create_planet(orbit(12.0), None).unwrap();
+    let raider = game_state.ship_manager.create_ship(ShipClass::Warship, Vector2::new(11.0, 1.0), enemy).unwrap();
+    let visible_ids = |game_state: &GameState| {
+        let visibility = game_state.faction_visibility(player).unwrap();
+        let planets: Vec<PlanetId> = game_state.planet_manager.get_planets_visible_to(visibility).iter().map(|planet| planet.id).collect();
+        let ships: Vec<ShipId> = game_state.ship_manager.get_ships_visible_to(visibility).iter().map(|ship| ship.id).collect();
+        (planets, ships)
+    };
+    
+    game_state.fixed_update(0.1).unwrap();
+    game_state.fixed_update(0.1).unwrap();
+    assert_eq!(visible_ids(&game_state), (vec![home], vec![]));
+    let explored = |game_state: &GameState| {
+        let faction = game_state.faction_manager.get_faction(player).unwrap();
+        game_state.planet_manager.get_planets_explored_by(faction).iter().map(|planet| planet.id).collect::<Vec<_>>()
+    };
+    assert_eq!(explored(&game_state), vec![home]);
+    
+    // A scout's long-range sensors reveal the distant planet and the raider near it
+    let scout = game_state.ship_manager.create_ship(ShipClass::Scout, Vector2::new(7.5, 0.0), player).unwrap();
+    game_state.fixed_update(0.1).unwrap();
+    game_state.fixed_update(0.1).unwrap();
+    assert_eq!(visible_ids(&game_state), (vec![home, distant], vec![raider, scout]));
+    assert_eq!(explored(&game_state), vec![home, distant]);
+    assert!(game_state.event_bus.event_history.iter().any(|event| matches!(
+        event,
+        GameEvent::SimulationEvent(SimulationEvent::PlanetDiscovered { faction, planet }) if *faction == player && *planet == distant
+    )));
+    
+    // Once the scout is gone the planet stays explored but drops out of sight
+    game_state.ship_manager.destroy_ship(scout).unwrap();
+    game_state.fixed_update(0.1).unwrap();
+    assert_eq!(visible_ids(&game_state), (vec![home], vec![]));
+    assert_eq!(explored(&game_state), vec![home, distant]);
+}
+
+#[test]
+fn test_ai_factions_build_and_deploy_fleets_by_personality() {
+    let mut game_state = GameState::new().unwrap();
+    game_state.set_game_configuration(GameConfiguration { ai_opponents: 3, planet_count: 10, ..Default::default() });
+    game_state.start_new_game().unwrap();
+    let home = |game_state: &GameState, faction: FactionId| game_state.planet_manager.get_planets_by_faction(faction)[0].clone();
+    
+    for _ in 0..60 {
+        game_state.fixed_update(0.1).unwrap();
+    }
+    
+    // AI factions 1-3 are Aggressive, Economic and Balanced; the player is left alone
+    assert!(home(&game_state, 0).developments.is_empty());
+    let first_building = |faction| home(&game_state, faction).developments[0].building_type;
+    assert_eq!(first_building(1), BuildingType::Mine);
+    assert_eq!(first_building(2), BuildingType::Mine);
+    assert_eq!(first_building(3), BuildingType::Farm);
+    
+    // Only the Aggressive faction sends its starting warship away from home,
+    // unless it has already been worn down in battle and fallen back
+    let tick = game_state.get_current_tick();
+    let warship = |faction: FactionId| game_state.ship_manager.get_ship(game_state.ship_manager.get_ships_by_owner(faction)[0]).unwrap();
+    let distance_from_home = |faction: FactionId| {
+        let home_position = game_state.physics_engine.calculate_orbital_position(&home(&game_state, faction).position, tick);
+        warship(faction).position.distance_to(&home_position)
+    };
+    assert!(distance_from_home(1) > 1.0 || warship(1).damage > 0, "Aggressive faction should go on the offensive");
+    assert!(distance_from_home(2) < 1.0, "Economic faction keeps its fleet at home");
+}
+
+#[test]
+fn test_aggressive_ai_ferries_troops_to_a_weakly_held_enemy_planet() {
+    let mut game_state = GameState::new().unwrap();
+    game_state.current_mode = GameMode::InGame;
+    let player = game_state.faction_manager.create_faction("Player".to_string(), true, AIPersonality::Balanced).unwrap();
+    let ai = game_state.faction_manager.create_faction("Horde".to_string(), false, AIPersonality::Aggressive).unwrap();
+    let orbit = |semi_major_axis, phase| OrbitalElements { semi_major_axis, period: 1_000_000.0, phase, ..Default::default() };
+    let ai_home = game_state.planet_manager.create_planet(orbit(10.0, 0.0), Some(ai)).unwrap();
+    let outpost = game_state.planet_manager.create_planet(orbit(10.0, std::f32::consts::PI), Some(player)).unwrap();
+    game_state.planet_manager.update_population(ai_home, 40).unwrap();
+    game_state.planet_manager.add_military(ai_home, 40).unwrap();
+    game_state.planet_manager.update_population(outpost, 2).unwrap();
+    game_state.planet_manager.add_military(outpost, 2).unwrap();
+    let home_position = game_state.physics_engine.calculate_orbital_position(&orbit(10.0, 0.0), 0);
+    let transport = game_state.ship_manager.create_ship(ShipClass::Transport, home_position, ai).unwrap();
+    game_state.issue_command(ai, PlayerCommand::DeclareWar { from: ai, to: player });
+    
+    for _ in 0..600 {
+        if game_state.planet_manager.get_planet(outpost).unwrap().controller == Some(ai) {
+            break;
+        }
+        game_state.fixed_update(0.1).unwrap();
+    }
+    assert_eq!(game_state.planet_manager.get_planet(outpost).unwrap().controller, Some(ai));
+    assert!(game_state.event_bus.event_history.iter().any(|event| matches!(
+        event,
+        GameEvent::PlayerCommand(PlayerCommand::InvadePlanet { ship, planet }) if *ship == transport && *planet == outpost
+    )));
+    // Half the home garrison went aboard; the rest stayed behind
+    assert_eq!(game_state.planet_manager.get_planet(ai_home).unwrap().population.allocation.military, 20);
+    assert!(last_rejection(&game_state).is_none());
+}
+
+#[test]
+fn test_treaties_block_attacks_until_war_is_declared_or_they_expire() {
+    let mut game_state = GameState::new().unwrap();
+    game_state.set_game_configuration(GameConfiguration { ai_opponents: 3, planet_count: 10, ..Default::default() });
+    game_state.start_new_game().unwrap();
+    let relation = |game_state: &GameState, other: FactionId| game_state.faction_manager.relation(0, other).unwrap();
+    
+    // The Economic faction accepts a peace proposal within a few ticks
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::ProposeTreaty {
+        from: 0, to: 2, treaty: DiplomaticProposal::Peace,
+    }));
+    for _ in 0..10 {
+        game_state.fixed_update(0.1).unwrap();
+    }
+    let peace = relation(&game_state, 2);
+    assert_eq!(peace.treaty.map(|treaty| treaty.kind), Some(DiplomaticProposal::Peace));
+    assert_eq!(peace.score, game_state.faction_manager.relation(2, 0).unwrap().score);
+    assert!(peace.score > 0);
+    
+    // Attacking a treaty partner is refused outright
+    let attacker = game_state.ship_manager.get_ships_by_owner(0)[0];
+    let target = game_state.ship_manager.get_ships_by_owner(2)[0];
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::AttackTarget { attacker, target }));
+    game_state.fixed_update(0.1).unwrap();
+    assert!(last_system_error(&game_state).unwrap().contains("declare war first"));
+    
+    // Declaring war tears up the treaty and costs relation
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::DeclareWar { from: 0, to: 2 }));
+    for _ in 0..2 {
+        game_state.fixed_update(0.1).unwrap();
+    }
+    let war = relation(&game_state, 2);
+    assert!(war.at_war && war.treaty.is_none());
+    assert!(war.score < peace.score);
+    assert!(game_state.faction_manager.get_faction(2).unwrap().is_at_war_with(0));
+    
+    // Treaties lapse on their own once their term is over
+    let tick = game_state.get_current_tick();
+    let treaty = Treaty { kind: DiplomaticProposal::Alliance, signed_tick: tick, expires_tick: tick + 2 };
+    game_state.faction_manager.handle_event(&GameEvent::SimulationEvent(SimulationEvent::TreatySigned {
+        parties: (0, 3), treaty,
+    })).unwrap();
+    for _ in 0..4 {
+        game_state.fixed_update(0.1).unwrap();
+    }
+    assert!(relation(&game_state, 3).treaty.is_none());
+    assert!(game_state.event_bus.event_history.iter().any(|event| matches!(
+        event,
+        GameEvent::SimulationEvent(SimulationEvent::TreatyExpired { parties: (0, 3), .. })
+    )));
+}
+
+#[test]
+fn test_loitering_scouts_gather_intel_on_enemy_planets() {
+    use stellar_dominion_core::systems::{PlanetKnowledge, INTEL_LOITER_TICKS};
+    
+    let mut game_state = GameState::new().unwrap();
+    game_state.current_mode = GameMode::InGame;
+    let player = game_state.faction_manager.create_faction("Player".to_string(), true, AIPersonality::Balanced).unwrap();
+    let enemy = game_state.faction_manager.create_faction("Enemy".to_string(), false, AIPersonality::Economic).unwrap();
+    let orbit = |semi_major_axis: f32| OrbitalElements { semi_major_axis, period: 1_000_000.0, phase: 0.0, eccentricity: 0.0, argument_of_periapsis: 0.0 };
+    let home = game_state.planet_manager.create_planet(orbit(3.0), Some(player)).unwrap();
+    let target = game_state.planet_manager.create_planet(orbit(8.0), Some(enemy)).unwrap();
+    game_state.planet_manager.update_population(target, 1500).unwrap();
+    
+    // Without intel, only the player's own planets are known in full
+    assert_eq!(game_state.known_planet(player, home).unwrap().1, PlanetKnowledge::Live);
+    let (unknown, knowledge) = game_state.known_planet(player, target).unwrap();
+    assert_eq!((knowledge, unknown.population.total), (PlanetKnowledge::Unknown, 0));
+    
+    // A scout parked next to the enemy planet files a report once it has stayed long enough
+    let scout = game_state.ship_manager.create_ship(ShipClass::Scout, Vector2::new(8.5, 0.0), player).unwrap();
+    game_state.step_ticks(INTEL_LOITER_TICKS - 1).unwrap();
+    assert!(game_state.spy_system.intel(player, target).is_none());
+    game_state.step_ticks(2).unwrap();
+    let report_tick = game_state.spy_system.intel(player, target).unwrap().tick;
+    assert!(game_state.event_bus.event_history.iter().any(|event| matches!(
+        event,
+        GameEvent::SimulationEvent(SimulationEvent::IntelGathered { faction, planet, ship })
+            if (*faction, *planet, *ship) == (player, target, scout)
+    )));
+    
+    // The report stays as it was once the planet changes
+    game_state.planet_manager.update_population(target, 500).unwrap();
+    let (known, knowledge) = game_state.known_planet(player, target).unwrap();
+    assert_eq!(knowledge, PlanetKnowledge::Intel { tick: report_tick, ships_in_orbit: 0 });
+    assert_eq!(known.population.total, 1500);
+    assert!(game_state.spy_system.intel(enemy, home).is_none());
+}
+
+#[test]
+fn test_pirates_raid_the_galaxy_without_scoring_or_negotiating() {
+    use stellar_dominion_core::systems::{PIRATE_FIRST_RAID_TICK, PIRATE_FACTION_NAME};
+    
+    let mut game_state = GameState::new().unwrap();
+    game_state.set_game_configuration(GameConfiguration { ai_opponents: 2, planet_count: 10, ..Default::default() });
+    game_state.start_new_game().unwrap();
+    let pirates = game_state.faction_manager.find_by_kind(FactionKind::Pirates).unwrap().clone();
+    assert_eq!(pirates.name, PIRATE_FACTION_NAME);
+    assert!(game_state.planet_manager.get_planets_by_faction(pirates.id).is_empty());
+    assert!(game_state.faction_manager.get_faction(0).unwrap().is_at_war_with(pirates.id));
+    assert!(game_state.faction_standings().iter().all(|standing| standing.faction != pirates.id));
+    
+    // Pirates take no part in diplomacy
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::ProposeTreaty {
+        from: 0, to: pirates.id, treaty: DiplomaticProposal::Peace,
+    }));
+    game_state.process_queued_events_for_test().unwrap();
+    assert!(last_rejection(&game_state).unwrap().contains("does not negotiate"));
+    
+    // The first raid appears on schedule and heads for an empire planet
+    game_state.time_manager.set_tick(PIRATE_FIRST_RAID_TICK - 5).unwrap();
+    game_state.step_ticks(20).unwrap();
+    let raiders = game_state.ship_manager.get_ships_by_owner(pirates.id);
+    assert_eq!(raiders.len(), 1);
+    let target = game_state.pirate_system.target_of(raiders[0]).unwrap();
+    let owner = game_state.planet_manager.get_planet(target).unwrap().controller.unwrap();
+    assert_ne!(owner, pirates.id);
+    assert!(game_state.notification_system.notifications(owner).iter().any(|notification| notification.kind == NotificationKind::PirateRaid { planet: target }));
+    
+    // Games can do without them
+    game_state.set_game_configuration(GameConfiguration { pirates: false, ..Default::default() });
+    game_state.start_new_game().unwrap();
+    assert!(game_state.faction_manager.find_by_kind(FactionKind::Pirates).is_none());
+    assert!(game_state.pirate_system.faction().is_none());
+}
+
+#[test]
+fn test_tutorial_objectives_complete_in_order_and_pay_rewards() {
+    use std::sync::{Arc, Mutex};
+    use stellar_dominion_core::systems::ObjectiveGoal;
+    
+    struct CompletedObjectives(Arc<Mutex<Vec<usize>>>);
+    
+    impl ScriptHook for CompletedObjectives {
+        fn on_event(&mut self, _game: &GameState, event: &GameEvent, _queue: &mut ScriptQueue) {
+            if let GameEvent::SimulationEvent(SimulationEvent::ObjectiveCompleted { objective, .. }) = event {
+                self.0.lock().unwrap().push(*objective);
+            }
+        }
+    }
+    
+    let mut game_state = GameState::new().unwrap();
+    game_state.set_game_configuration(GameConfiguration { tutorial: true, ..Default::default() });
+    game_state.start_new_game().unwrap();
+    let completed = Arc::new(Mutex::new(Vec::new()));
+    game_state.add_script_hook(Box::new(CompletedObjectives(completed.clone())));
+    let player = game_state.faction_manager.get_player_faction().unwrap().id;
+    let home = game_state.planet_manager.get_planets_by_faction(player)[0].id;
+    let track = game_state.objective_system.track(player).unwrap();
+    assert_eq!(track.current().unwrap().goal, ObjectiveGoal::Build(BuildingType::Mine));
+    
+    // A Farm is not the current objective; the Mine is, and pays its energy at once
+    game_state.queue_event(GameEvent::SimulationEvent(SimulationEvent::ConstructionCompleted { planet: home, building: BuildingType::Farm }));
+    game_state.process_queued_events_for_test().unwrap();
+    assert_eq!(game_state.objective_system.track(player).unwrap().completed(), 0);
+    let energy = game_state.planet_manager.get_planet(home).unwrap().resources.current.energy;
+    game_state.queue_event(GameEvent::SimulationEvent(SimulationEvent::ConstructionCompleted { planet: home, building: BuildingType::Mine }));
+    game_state.process_queued_events_for_test().unwrap();
+    assert_eq!(game_state.planet_manager.get_planet(home).unwrap().resources.current.energy, energy + 100);
+    let track = game_state.objective_system.track(player).unwrap();
+    assert_eq!((track.completed(), track.current().unwrap().title.as_str()), (1, "Build a Farm"));
+    
+    // The completion is announced on the next tick
+    game_state.fixed_update(stellar_dominion_core::config::FIXED_TIMESTEP).unwrap();
+    assert_eq!(*completed.lock().unwrap(), vec![0]);
+    
+    // A new game without the tutorial has no objectives
+    game_state.set_game_configuration(GameConfiguration::default());
+    game_state.start_new_game().unwrap();
+    assert!(game_state.objective_system.track(player).is_none());
+}
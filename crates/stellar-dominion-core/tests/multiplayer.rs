@@ -0,0 +1,280 @@
+// crates/stellar-dominion-core/tests/multiplayer.rs
+//! Replays, remote commands, lockstep sessions, state hashes and spectator snapshots
+
+use stellar_dominion_core::core::*;
+use stellar_dominion_core::systems::Replay;
+
+#[test]
+fn test_recorded_game_replays_to_the_same_state() {
+    let snapshot = |game_state: &GameState| format!(
+        "{:?}\n{:?}\n{:?}",
+        game_state.planet_manager.get_all_planets(),
+        game_state.ship_manager.get_all_ships(),
+        game_state.faction_manager.get_all_factions()
+    );
+    
+    let mut game_state = GameState::new().unwrap();
+    game_state.set_game_configuration(GameConfiguration { seed: 7, ai_opponents: 2, planet_count: 8, ..Default::default() });
+    game_state.start_new_game().unwrap();
+    game_state.start_recording().unwrap();
+    
+    let home = game_state.planet_manager.get_all_planets().iter()
+        .find(|planet| planet.controller == Some(0)).unwrap().id;
+    let ship = game_state.ship_manager.get_ships_by_owner(0)[0];
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::BuildStructure { planet: home, building_type: BuildingType::Mine }));
+    for tick in 0..50 {
+        if tick == 20 {
+            game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::MoveShip { ship, target: Vector2 { x: 120.0, y: -40.0 } }));
+        }
+        game_state.fixed_update(0.1).unwrap();
+    }
+    let replay = game_state.stop_recording().unwrap();
+    assert_eq!(replay.commands.len(), 2);
+    assert_eq!(replay.end_tick, 50);
+    
+    let path = std::env::temp_dir().join("stellar_dominion_replay_test.json");
+    replay.save_to_file(&path).unwrap();
+    let replay = Replay::load_from_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    
+    // Play back in a fresh game that starts from a different configuration
+    let mut playback = GameState::new().unwrap();
+    playback.start_replay(replay).unwrap();
+    assert!(playback.replay_system.is_playing());
+    for tick in 0..50 {
+        if tick == 10 {
+            // Only pause and speed commands reach a game that is playing back
+            playback.queue_event(GameEvent::PlayerCommand(PlayerCommand::MoveShip { ship, target: Vector2 { x: -300.0, y: 0.0 } }));
+        }
+        playback.fixed_update(0.1).unwrap();
+    }
+    assert_eq!(playback.get_current_tick(), 50);
+    assert!(!playback.replay_system.is_playing(), "Playback ends at the recorded tick");
+    assert_eq!(snapshot(&playback), snapshot(&game_state));
+}
+
+#[test]
+fn test_remote_commands_keep_peers_in_step() {
+    let snapshot = |game_state: &GameState| format!(
+        "{:?}\n{:?}\n{:?}",
+        game_state.planet_manager.get_all_planets(),
+        game_state.ship_manager.get_all_ships(),
+        game_state.faction_manager.get_all_factions()
+    );
+    let peer = || {
+        let mut game_state = GameState::new().unwrap();
+        game_state.set_game_configuration(GameConfiguration { seed: 7, ai_opponents: 1, planet_count: 8, ..Default::default() });
+        game_state.start_new_game().unwrap();
+        (game_state, RemoteCommandQueue::new())
+    };
+    // Play tick by tick, issuing each tick's commands before it runs
+    let play = |(game_state, queue): &mut (GameState, RemoteCommandQueue), ticks: u64| {
+        for _ in 0..ticks {
+            queue.issue_due(game_state);
+            game_state.step_ticks(1).unwrap();
+        }
+    };
+    let mut host = peer();
+    let mut guest = peer();
+    
+    // Two players' orders for the same tick, sent over the wire as text
+    let home = host.0.planet_manager.get_planets_by_faction(0)[0].id;
+    let rival_home = host.0.planet_manager.get_planets_by_faction(1)[0].id;
+    let ship = host.0.ship_manager.get_ships_by_owner(0)[0];
+    let wire: Vec<String> = [
+        CommandEnvelope { tick: 5, player: 0, seq: 1, command: PlayerCommand::BuildStructure { planet: home, building_type: BuildingType::Mine } },
+        CommandEnvelope { tick: 5, player: 0, seq: 2, command: PlayerCommand::MoveShip { ship, target: Vector2::new(120.0, -40.0) } },
+        CommandEnvelope { tick: 5, player: 1, seq: 1, command: PlayerCommand::SetTaxRate { planet: rival_home, rate: TaxRate::High } },
+    ].iter().map(|envelope| to_wire(envelope).unwrap()).collect();
+    
+    // The guest hears them in the opposite order but runs them in the same one
+    for message in &wire {
+        host.1.push(from_wire(message).unwrap(), host.0.get_current_tick()).unwrap();
+    }
+    for message in wire.iter().rev() {
+        guest.1.push(from_wire(message).unwrap(), guest.0.get_current_tick()).unwrap();
+    }
+    assert_eq!(host.1.len(), 3);
+    play(&mut host, 30);
+    play(&mut guest, 30);
+    assert!(host.1.is_empty());
+    assert_eq!(host.0.planet_manager.get_building_count(home, BuildingType::Mine).unwrap(), 1);
+    assert_eq!(host.0.planet_manager.get_planet(rival_home).unwrap().tax_rate, TaxRate::High);
+    assert_eq!(snapshot(&host.0), snapshot(&guest.0));
+    
+    // Late, repeated and unauthorised commands go no further
+    let tick = host.0.get_current_tick();
+    let late = CommandEnvelope { tick: 10, player: 0, seq: 3, command: PlayerCommand::StopShip(ship) };
+    assert!(host.1.push(late, tick).is_err());
+    let repeat = CommandEnvelope { tick: 40, player: 0, seq: 2, command: PlayerCommand::StopShip(ship) };
+    assert!(host.1.push(repeat, tick).is_err());
+    let meddling = CommandEnvelope { tick: 40, player: 1, seq: 2, command: PlayerCommand::SetTaxRate { planet: home, rate: TaxRate::Low } };
+    host.1.push(meddling, tick).unwrap();
+    play(&mut host, 11);
+    assert_eq!(host.0.planet_manager.get_planet(home).unwrap().tax_rate, TaxRate::Normal);
+}
+
+#[test]
+fn test_lockstep_sessions_play_in_step_and_catch_desyncs() {
+    use stellar_dominion_core::net::{LockstepSession, ChannelTransport, HASH_INTERVAL_TICKS};
+    let snapshot = |game_state: &GameState| format!(
+        "{:?}\n{:?}\n{:?}",
+        game_state.planet_manager.get_all_planets(),
+        game_state.ship_manager.get_all_ships(),
+        game_state.faction_manager.get_all_factions()
+    );
+    let peer = || {
+        let mut game_state = GameState::new().unwrap();
+        game_state.set_game_configuration(GameConfiguration { seed: 11, ai_opponents: 2, planet_count: 9, ..Default::default() });
+        game_state.start_new_game().unwrap();
+        game_state
+    };
+    let (to_first, first_link) = ChannelTransport::pair();
+    let (to_second, second_link) = ChannelTransport::pair();
+    let mut sessions = [
+        LockstepSession::host(0, vec![(1, to_first), (2, to_second)]),
+        LockstepSession::client(1, first_link),
+        LockstepSession::client(2, second_link),
+    ];
+    let mut games = [peer(), peer(), peer()];
+    
+    // A client cannot run ahead of inputs the host has not relayed
+    while sessions[1].advance(&mut games[1]).unwrap() {}
+    let stalled_at = games[1].get_current_tick();
+    assert!(!sessions[1].advance(&mut games[1]).unwrap());
+    assert_eq!(games[1].get_current_tick(), stalled_at);
+    
+    // Orders from every player land on every peer at the same tick
+    let home = games[0].planet_manager.get_planets_by_faction(0)[0].id;
+    let first_home = games[0].planet_manager.get_planets_by_faction(1)[0].id;
+    sessions[0].issue(PlayerCommand::BuildStructure { planet: home, building_type: BuildingType::Mine });
+    sessions[1].issue(PlayerCommand::SetTaxRate { planet: first_home, rate: TaxRate::High });
+    sessions[2].issue(PlayerCommand::SetFactionTaxRate { faction: 2, rate: TaxRate::Low });
+    sessions[2].issue(PlayerCommand::SetTaxRate { planet: home, rate: TaxRate::Low });
+    let target = HASH_INTERVAL_TICKS * 2 + 10;
+    while games.iter().any(|game_state| game_state.get_current_tick() < target) {
+        for (session, game_state) in sessions.iter_mut().zip(games.iter_mut()) {
+            if game_state.get_current_tick() < target {
+                session.advance(game_state).unwrap();
+            }
+        }
+    }
+    assert_eq!(games[0].planet_manager.get_building_count(home, BuildingType::Mine).unwrap(), 1);
+    assert_eq!(games[0].planet_manager.get_planet(first_home).unwrap().tax_rate, TaxRate::High);
+    assert_eq!(games[0].faction_manager.get_faction(2).unwrap().tax_rate, TaxRate::Low);
+    assert_eq!(games[0].planet_manager.get_planet(home).unwrap().tax_rate, TaxRate::Normal, "Players only command their own planets");
+    assert_eq!(snapshot(&games[0]), snapshot(&games[1]));
+    assert_eq!(snapshot(&games[0]), snapshot(&games[2]));
+    assert!(sessions[0].desync().is_none());
+    
+    // A peer that drifts is caught at the next hash check and play stops
+    games[2].faction_manager.add_credits(2, 1).unwrap();
+    let mut stopped = false;
+    for _ in 0..HASH_INTERVAL_TICKS * 2 {
+        for (session, game_state) in sessions.iter_mut().zip(games.iter_mut()) {
+            stopped |= session.advance(game_state).is_err();
+        }
+        if stopped {
+            break;
+        }
+    }
+    assert!(stopped);
+    let desync = sessions[0].desync().unwrap();
+    assert_eq!(desync.player, 2);
+    assert_eq!(desync.tick, HASH_INTERVAL_TICKS * 3);
+    assert!(sessions[0].advance(&mut games[0]).is_err());
+}
+
+#[test]
+fn test_lockstep_host_only_trusts_players_on_their_own_link() {
+    use stellar_dominion_core::net::{LockstepSession, ChannelTransport, NetMessage, Transport, INPUT_DELAY_TICKS};
+    let mut game_state = GameState::new().unwrap();
+    game_state.set_game_configuration(GameConfiguration { seed: 11, ai_opponents: 2, planet_count: 9, ..Default::default() });
+    game_state.start_new_game().unwrap();
+    let (to_first, mut first) = ChannelTransport::pair();
+    let (to_second, mut second) = ChannelTransport::pair();
+    let mut host = LockstepSession::host(0, vec![(1, to_first), (2, to_second)]);
+    let tick = INPUT_DELAY_TICKS;
+    
+    // Faction 1 sends its own input, then input and a hash posing as faction 2
+    first.send(&NetMessage::Input { player: 1, tick, commands: Vec::new() }).unwrap();
+    let forged = CommandEnvelope { tick, player: 2, seq: 1, command: PlayerCommand::SetFactionTaxRate { faction: 2, rate: TaxRate::High } };
+    first.send(&NetMessage::Input { player: 2, tick, commands: vec![forged.clone()] }).unwrap();
+    first.send(&NetMessage::Input { player: 1, tick: tick + 1, commands: vec![forged] }).unwrap();
+    first.send(&NetMessage::StateHash { player: 2, tick, hash: 0 }).unwrap();
+    while host.advance(&mut game_state).unwrap() {}
+    assert_eq!(game_state.get_current_tick(), tick, "The forged input must not stand in for faction 2's");
+    assert!(host.desync().is_none());
+    
+    // The real input releases the tick without the forged command
+    second.send(&NetMessage::Input { player: 2, tick, commands: Vec::new() }).unwrap();
+    assert!(host.advance(&mut game_state).unwrap());
+    assert_eq!(game_state.get_current_tick(), tick + 1);
+    assert_eq!(game_state.faction_manager.get_faction(2).unwrap().tax_rate, TaxRate::Normal);
+}
+
+#[test]
+fn test_state_hashes_match_across_identical_runs() {
+    let run = |rate: TaxRate| {
+        let mut game_state = GameState::new().unwrap();
+        game_state.set_game_configuration(GameConfiguration { seed: 23, ai_opponents: 2, planet_count: 10, ..Default::default() });
+        game_state.start_new_game().unwrap();
+        let home = game_state.planet_manager.get_planets_by_faction(0)[0].id;
+        game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::BuildStructure { planet: home, building_type: BuildingType::Farm }));
+        game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::SetTaxRate { planet: home, rate }));
+        let mut hashes = Vec::new();
+        for _ in 0..4 {
+            game_state.step_ticks(100).unwrap();
+            hashes.push(game_state.compute_state_hash().unwrap());
+        }
+        hashes
+    };
+    
+    let first = run(TaxRate::High);
+    assert_eq!(first, run(TaxRate::High), "Same seed and commands hash the same every 100 ticks");
+    assert_ne!(first.last(), run(TaxRate::Low).last());
+    
+    // Any change to an entity changes the hash
+    let mut game_state = GameState::new().unwrap();
+    game_state.start_new_game().unwrap();
+    let before = game_state.compute_state_hash().unwrap();
+    assert_eq!(before, game_state.compute_state_hash().unwrap());
+    game_state.faction_manager.add_credits(0, 1).unwrap();
+    assert_ne!(before, game_state.compute_state_hash().unwrap());
+}
+
+#[test]
+fn test_snapshots_show_spectators_everything_and_factions_their_view() {
+    let mut game_state = GameState::new().unwrap();
+    game_state.set_game_configuration(GameConfiguration { seed: 5, ai_opponents: 2, planet_count: 12, ..Default::default() });
+    game_state.start_new_game().unwrap();
+    game_state.step_ticks(1).unwrap();
+    
+    // Spectators see the whole game, and the copy travels as a wire message
+    let spectator: GameSnapshot = from_wire(&to_wire(&game_state.snapshot()).unwrap()).unwrap();
+    assert_eq!(spectator.viewer, None);
+    assert_eq!(spectator.tick, game_state.get_current_tick());
+    assert_eq!(spectator.planets.len(), game_state.planet_manager.get_all_planets().len());
+    assert_eq!(spectator.ships.len(), game_state.ship_manager.get_all_ships().len());
+    assert!(spectator.factions.iter().all(|faction| faction.details.is_some()));
+    
+    // A faction sees its own holdings and what its sensors reach
+    let view = game_state.faction_snapshot(0).unwrap();
+    let visibility = game_state.faction_visibility(0).unwrap().clone();
+    assert_eq!(view.viewer, Some(0));
+    assert!(view.planets.len() < spectator.planets.len());
+    assert!(view.planets.iter().all(|planet| planet.controller == Some(0) || visibility.can_see_planet(planet.id)));
+    assert!(view.ships.iter().all(|ship| ship.owner == 0 || visibility.can_see_ship(ship.id)));
+    for ship in game_state.ship_manager.get_ships_by_owner(0) {
+        assert!(view.ship(ship).is_some());
+    }
+    assert!(view.faction(0).unwrap().details.is_some());
+    let rival = view.faction(1).unwrap();
+    assert!(rival.details.is_none(), "Rivals' treasuries and research stay private");
+    assert_eq!(rival.score, game_state.faction_manager.get_faction(1).unwrap().score);
+    assert!(game_state.faction_snapshot(99).is_err());
+    
+    // A snapshot keeps showing the tick it was taken at
+    game_state.step_ticks(5).unwrap();
+    assert_eq!(view.tick + 5, game_state.get_current_tick());
+}
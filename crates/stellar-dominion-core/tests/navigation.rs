@@ -0,0 +1,249 @@
+// crates/stellar-dominion-core/tests/navigation.rs
+//! Ship movement: orbits, transfer windows, routing, jump lanes and supply
+
+mod common;
+
+use stellar_dominion_core::core::*;
+use common::last_rejection;
+
+#[test]
+fn test_transfer_windows_between_planets_on_elliptical_orbits() {
+    let mut game_state = GameState::new().unwrap();
+    game_state.set_game_configuration(GameConfiguration { planet_count: 12, ..Default::default() });
+    game_state.start_new_game().unwrap();
+    for _ in 0..5 {
+        game_state.fixed_update(0.1).unwrap();
+    }
+    let planets = game_state.planet_manager.get_all_planets();
+    assert!(planets.iter().any(|planet| planet.position.eccentricity > 0.0), "Scattered planets have elliptical orbits");
+    let (from, to) = (planets[0].id, planets[planets.len() - 1].id);
+    let (from_orbit, to_orbit) = (planets[0].position, planets[planets.len() - 1].position);
+    
+    let horizon = game_state.physics_engine.synodic_period(&from_orbit, &to_orbit).map_or(500, |period| period.ceil() as u64);
+    let windows = game_state.transfer_windows(from, to, horizon).unwrap();
+    let now = game_state.get_current_tick();
+    assert!(!windows.is_empty());
+    assert!(windows.iter().all(|window| window.departure_tick >= now && window.departure_tick <= now + horizon));
+    assert!(windows[0].distance <= game_state.physics_engine.transfer_at(&from_orbit, &to_orbit, now).distance);
+    assert!(game_state.transfer_windows(from, 9999, horizon).is_err());
+}
+
+#[test]
+fn test_ships_route_around_hostile_planets_within_their_fuel_range() {
+    let mut game_state = GameState::new().unwrap();
+    game_state.current_mode = GameMode::InGame;
+    let player = game_state.faction_manager.create_faction("Player".to_string(), true, AIPersonality::Balanced).unwrap();
+    let enemy = game_state.faction_manager.create_faction("Enemy".to_string(), false, AIPersonality::Aggressive).unwrap();
+    // Periods this long keep the planets effectively still for the test
+    let orbit = |semi_major_axis: f32, phase: f32| OrbitalElements { semi_major_axis, period: 1_000_000.0, phase, eccentricity: 0.0, argument_of_periapsis: 0.0 };
+    let hostile = game_state.planet_manager.create_planet(orbit(3.0, 0.0), Some(enemy)).unwrap();
+    game_state.planet_manager.create_planet(orbit(6.0, 0.0), None).unwrap();
+    let well = game_state.physics_engine.calculate_orbital_position(&game_state.planet_manager.get_planet(hostile).unwrap().position, 0);
+    let target = Vector2::new(6.0, 0.0);
+    let ship = game_state.ship_manager.create_ship(ShipClass::Warship, Vector2::new(0.5, 0.0), player).unwrap();
+    let move_ship = GameEvent::PlayerCommand(PlayerCommand::MoveShip { ship, target });
+    
+    // At peace the ship flies straight through
+    game_state.queue_event(move_ship.clone());
+    game_state.process_queued_events_for_test().unwrap();
+    assert!(game_state.ship_manager.get_ship(ship).unwrap().trajectory.as_ref().unwrap().waypoints.is_empty());
+    
+    // At war it steers around the enemy planet's gravity well
+    game_state.faction_manager.handle_event(&GameEvent::SimulationEvent(SimulationEvent::WarDeclared { aggressor: enemy, target: player })).unwrap();
+    game_state.queue_event(move_ship.clone());
+    game_state.process_queued_events_for_test().unwrap();
+    let trajectory = game_state.ship_manager.get_ship(ship).unwrap().trajectory.clone().unwrap();
+    assert!(!trajectory.waypoints.is_empty());
+    for _ in 0..10 {
+        game_state.fixed_update(0.1).unwrap();
+        let position = game_state.ship_manager.get_ship(ship).unwrap().position;
+        assert!(position.distance_to(&well) > GRAVITY_WELL_RADIUS, "The ship never enters the hostile well");
+    }
+    let arrived = game_state.ship_manager.get_ship(ship).unwrap();
+    assert!(arrived.trajectory.is_none());
+    assert!(arrived.position.distance_to(&target) <= ORBIT_CAPTURE_RANGE);
+    
+    // An order beyond the ship's fuel range is refused with an event, not an error
+    let fuel = game_state.ship_manager.get_ship(ship).unwrap().fuel;
+    game_state.ship_manager.consume_fuel(ship, fuel - 0.05).unwrap();
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::MoveShip { ship, target: Vector2::new(0.5, 0.0) }));
+    game_state.process_queued_events_for_test().unwrap();
+    assert!(game_state.ship_manager.get_ship(ship).unwrap().trajectory.is_none());
+    assert!(game_state.event_bus.queued_events.iter().any(|event| matches!(
+        event,
+        GameEvent::SimulationEvent(SimulationEvent::InsufficientFuel { ship: s, required, available })
+            if *s == ship && required > available
+    )));
+}
+
+#[test]
+fn test_arriving_ship_enters_parking_orbit_and_follows_planet() {
+    let mut game_state = GameState::new().unwrap();
+    game_state.current_mode = GameMode::InGame;
+    
+    let orbit = OrbitalElements { semi_major_axis: 2.0, period: 200.0, phase: 0.0, eccentricity: 0.0, argument_of_periapsis: 0.0 };
+    let planet = game_state.planet_manager.create_planet(orbit, Some(0)).unwrap();
+    let ship = game_state.ship_manager.create_ship(
+        ShipClass::Transport,
+        Vector2::new(-3.0, 0.0),
+        0,
+    ).unwrap();
+    
+    let target = game_state.physics_engine.calculate_orbital_position(&orbit, 1);
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::MoveShip { ship, target }));
+    for _ in 0..3 {
+        game_state.fixed_update(0.1).unwrap();
+    }
+    
+    let parked = game_state.ship_manager.get_ship(ship).unwrap();
+    assert_eq!(parked.status, ShipStatus::Orbiting(planet));
+    assert!(parked.trajectory.is_none(), "Arrival should clear the trajectory");
+    assert_eq!(game_state.ship_manager.get_ships_in_orbit(planet), vec![ship]);
+    assert!(game_state.event_bus.event_history.iter().any(|event| matches!(
+        event,
+        GameEvent::SimulationEvent(SimulationEvent::ShipEnteredOrbit { ship: s, planet: p }) if *s == ship && *p == planet
+    )));
+    
+    // The parking orbit travels with the planet
+    for _ in 0..40 {
+        game_state.fixed_update(0.1).unwrap();
+    }
+    let tick = game_state.get_current_tick();
+    let planet_position = game_state.physics_engine.calculate_orbital_position(&orbit, tick);
+    let ship_position = game_state.ship_manager.get_ship(ship).unwrap().position;
+    assert_eq!(game_state.ship_manager.get_ship(ship).unwrap().status, ShipStatus::Orbiting(planet));
+    assert!((ship_position.distance_to(&planet_position) - PARKING_ORBIT_RADIUS).abs() < 1e-3);
+}
+
+#[test]
+fn test_ships_reach_other_star_systems_only_along_jump_lanes() {
+    // Generated galaxies spread the planets over every star, all linked by lanes
+    let mut generated = GameState::new().unwrap();
+    generated.set_game_configuration(GameConfiguration { star_systems: 3, ..GameConfiguration::for_galaxy_size(GalaxySize::Medium) });
+    generated.start_new_game().unwrap();
+    let star_map = generated.physics_engine.star_map().clone();
+    assert_eq!(star_map.systems.len(), 3);
+    assert!(star_map.validate().is_ok());
+    for system in &star_map.systems {
+        assert!(generated.planet_manager.get_all_planets().iter().any(|planet| planet.system == system.id));
+        assert!(star_map.lane_path(0, system.id).is_some(), "Every star can be reached from the first");
+    }
+
+    let mut game_state = GameState::new().unwrap();
+    game_state.current_mode = GameMode::InGame;
+    let lane = JumpLane { from: 0, to: 1, travel_ticks: 5, fuel_cost: 10.0 };
+    game_state.physics_engine.set_star_map(StarMap {
+        systems: vec![
+            StarSystem { id: 0, name: "Sol".to_string(), position: Vector2::default() },
+            StarSystem { id: 1, name: "Vega".to_string(), position: Vector2::new(60.0, 0.0) },
+            StarSystem { id: 2, name: "Rigel".to_string(), position: Vector2::new(0.0, 80.0) },
+        ],
+        lanes: vec![lane],
+    });
+    let player = game_state.faction_manager.create_faction("Player".to_string(), true, AIPersonality::Balanced).unwrap();
+    let orbit = OrbitalElements { semi_major_axis: 3.0, period: 1_000_000.0, phase: 0.0, eccentricity: 0.0, argument_of_periapsis: 0.0 };
+    let destination = game_state.planet_manager.create_planet(orbit, None).unwrap();
+    game_state.planet_manager.modify_planet(destination, |planet| { planet.system = 1; Ok(()) }).unwrap();
+    let target = game_state.physics_engine.planet_position(game_state.planet_manager.get_planet(destination).unwrap(), 0);
+    assert_eq!(target, Vector2::new(63.0, 0.0), "Planets orbit their own star");
+    let ship = game_state.ship_manager.create_ship(ShipClass::Scout, Vector2::new(3.0, 0.0), player).unwrap();
+
+    // The ship flies to the jump point, spends the lane's travel time in it and flies on
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::MoveShip { ship, target }));
+    let entry = game_state.physics_engine.star_map().jump_point(0, 1);
+    let (mut ticks, mut ticks_in_lane) = (0, 0);
+    loop {
+        game_state.step_ticks(1).unwrap();
+        ticks += 1;
+        let current = game_state.ship_manager.get_ship(ship).unwrap();
+        if current.trajectory.as_ref().is_some_and(|trajectory| !trajectory.jumps.is_empty()) && current.position == entry {
+            ticks_in_lane += 1;
+            assert_eq!(current.status, ShipStatus::InTransit);
+        }
+        if current.trajectory.is_none() || ticks > 100 {
+            break;
+        }
+    }
+    assert!(ticks_in_lane >= lane.travel_ticks - 1, "The ship stays in the lane for its travel time");
+    assert!(game_state.ship_manager.get_ship(ship).unwrap().position.distance_to(&target) < 1.0);
+
+    // Stars without lanes cannot be reached at all
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::MoveShip { ship, target: Vector2::new(0.0, 83.0) }));
+    game_state.step_ticks(1).unwrap();
+    assert!(game_state.ship_manager.get_ship(ship).unwrap().trajectory.is_none());
+    assert!(last_rejection(&game_state).is_some_and(|reason| reason.contains("No jump lanes")));
+}
+
+#[test]
+fn test_supply_lines_cut_off_distant_planets_and_wear_down_ships() {
+    use stellar_dominion_core::systems::{CUT_OFF_PRODUCTION_PERCENT, SUPPLY_ATTRITION_PERCENT};
+
+    let mut game_state = GameState::new().unwrap();
+    game_state.current_mode = GameMode::InGame;
+    // Three stars in a row; the player holds the first and the last
+    game_state.physics_engine.set_star_map(StarMap {
+        systems: vec![
+            StarSystem { id: 0, name: "Sol".to_string(), position: Vector2::default() },
+            StarSystem { id: 1, name: "Vega".to_string(), position: Vector2::new(60.0, 0.0) },
+            StarSystem { id: 2, name: "Rigel".to_string(), position: Vector2::new(120.0, 0.0) },
+        ],
+        lanes: vec![
+            JumpLane { from: 0, to: 1, travel_ticks: 5, fuel_cost: 10.0 },
+            JumpLane { from: 1, to: 2, travel_ticks: 5, fuel_cost: 10.0 },
+        ],
+    });
+    let player = game_state.faction_manager.create_faction("Player".to_string(), true, AIPersonality::Balanced).unwrap();
+    let orbit = OrbitalElements { semi_major_axis: 3.0, period: 1_000_000.0, phase: 0.0, eccentricity: 0.0, argument_of_periapsis: 0.0 };
+    let allocation = WorkerAllocation { agriculture: 0, mining: 50, industry: 0, research: 0, military: 0, unassigned: 50 };
+    let mut planets = Vec::new();
+    for system in [0, 2] {
+        let planet = game_state.planet_manager.create_planet(orbit, Some(player)).unwrap();
+        game_state.planet_manager.modify_planet(planet, |planet| { planet.system = system; Ok(()) }).unwrap();
+        game_state.planet_manager.update_population(planet, 100).unwrap();
+        game_state.planet_manager.set_worker_allocation(planet, allocation.clone()).unwrap();
+        planets.push(planet);
+    }
+    let (capital, outpost) = (planets[0], planets[1]);
+    let far_ship = game_state.ship_manager.create_ship(ShipClass::Warship, Vector2::new(123.0, 0.0), player).unwrap();
+    let near_ship = game_state.ship_manager.create_ship(ShipClass::Warship, Vector2::new(63.0, 0.0), player).unwrap();
+
+    // The empty middle system breaks the chain, so the outpost produces less
+    let minerals = |game_state: &GameState, planet| game_state.planet_manager.get_planet(planet).unwrap().resources.current.minerals;
+    let before = (minerals(&game_state, capital), minerals(&game_state, outpost));
+    game_state.step_ticks(1).unwrap();
+    let capital_output = minerals(&game_state, capital) - before.0;
+    let outpost_output = minerals(&game_state, outpost) - before.1;
+    assert!(capital_output > 0);
+    assert_eq!(outpost_output, capital_output * CUT_OFF_PRODUCTION_PERCENT / 100);
+    assert!(game_state.supply_system.planet_status(capital).is_supplied());
+    assert!(!game_state.supply_system.planet_status(outpost).is_supplied());
+
+    // Ships one lane from the network stay supplied; further out they wear down
+    game_state.step_ticks(20).unwrap();
+    assert!(game_state.supply_system.ship_status(near_ship).is_supplied());
+    let effectiveness = game_state.supply_system.ship_status(far_ship).effectiveness();
+    assert_eq!(effectiveness, 100 - 2 * SUPPLY_ATTRITION_PERCENT);
+
+    // The worn-down ship needs more fuel for a move it could otherwise make
+    let position = game_state.ship_manager.get_ship(far_ship).unwrap().position;
+    let base = game_state.ship_manager.calculate_fuel_cost_for_class(ShipClass::Warship, 10.0);
+    let fuel = game_state.ship_manager.get_ship(far_ship).unwrap().fuel;
+    game_state.ship_manager.consume_fuel(far_ship, fuel - base * 1.05).unwrap();
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::MoveShip { ship: far_ship, target: Vector2::new(position.x, position.y + 10.0) }));
+    game_state.step_ticks(1).unwrap();
+    assert!(game_state.ship_manager.get_ship(far_ship).unwrap().trajectory.is_none());
+    let required = game_state.event_bus.event_history.iter().rev().find_map(|event| match event {
+        GameEvent::SimulationEvent(SimulationEvent::InsufficientFuel { ship, required, .. }) if *ship == far_ship => Some(*required),
+        _ => None,
+    }).expect("The move is refused for lack of fuel");
+    assert!((required - base * 100.0 / effectiveness as f32).abs() < 0.01);
+
+    // A trade route from the capital reconnects the outpost
+    let transport = game_state.ship_manager.create_ship(ShipClass::Transport, Vector2::new(3.0, 0.0), player).unwrap();
+    let route = TradeRoute { ship: transport, from: capital, to: outpost, resources: ResourceBundle { food: 10, ..Default::default() } };
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::CreateTradeRoute(route)));
+    game_state.step_ticks(2).unwrap();
+    assert!(game_state.supply_system.planet_status(outpost).is_supplied());
+    assert!(game_state.event_bus.event_history.iter().any(|event| matches!(event,
+        GameEvent::SimulationEvent(SimulationEvent::PlanetSupplyChanged { planet, supplied: true }) if *planet == outpost)));
+}
@@ -0,0 +1,99 @@
+// crates/stellar-dominion-core/tests/public_api.rs
+//! Guards the curated, semver-tracked API of the core crate.
+//!
+//! Only root re-exports and the prelude are used here. A change that breaks
+//! this file is a breaking change and needs a major version bump and a
+//! CHANGELOG entry.
+
+use stellar_dominion_core::prelude::*;
+use stellar_dominion_core::{setup, GameConfiguration, GameMode, Planet, PlanetId, ShipClass, SystemId, VictoryType, VERSION};
+
+#[test]
+fn test_headless_simulation_through_public_api() {
+    let mut game: GameState = setup::new_game().expect("core game state should initialize");
+    game.start_new_game().expect("new game should start");
+    assert_eq!(game.current_mode, GameMode::InGame);
+
+    let revision = game.world_revision();
+    for _ in 0..10 {
+        game.fixed_update(FIXED_TIMESTEP).expect("fixed update should succeed");
+    }
+    assert_eq!(game.get_current_tick(), 10);
+    assert_eq!(game.world_revision(), revision);
+
+    game.queue_event(GameEvent::PlayerCommand(PlayerCommand::PauseGame(true)));
+    game.fixed_update(FIXED_TIMESTEP).expect("fixed update should succeed");
+    setup::validate_game_state(&game).expect("state should stay valid");
+}
+
+#[test]
+fn test_prelude_and_root_exports_stay_available() {
+    let bundle = ResourceBundle { minerals: 10, ..Default::default() };
+    assert_eq!(bundle.get(ResourceType::Minerals), 10);
+
+    let _ids: (PlanetId, ShipId, FactionId) = (0, 0, 0);
+    let _class = ShipClass::Transport;
+    let _system = SystemId::PlanetManager;
+    let _position = Vector2 { x: 0.0, y: 0.0 };
+    let _error: GameResult<()> = Err(GameError::SystemError("api check".into()));
+    assert_eq!(GameError::not_found(EntityKind::Planet, 3u32).code(), "E0103");
+    let _builder: GameStateBuilder = GameStateBuilder::new()
+        .with_seed(1)
+        .with_faction(ScenarioFaction::new("api check", true))
+        .with_planet(ScenarioPlanet::new(1.0))
+        .with_ship(ScenarioShip { ship_class: ShipClass::Scout, owner: 0, planet: 0 });
+    let _bus = EventBus::new();
+    assert!(MAX_PLANETS > 0 && MAX_SHIPS > 0 && MAX_FACTIONS > 0);
+    assert!(!VERSION.is_empty());
+}
+
+#[test]
+fn test_headless_games_with_same_seed_play_out_identically() {
+    let play = |seed| {
+        let config = GameConfiguration { seed, planet_count: 8, ai_opponents: 2, ..Default::default() };
+        let mut game = setup::new_headless_game(config).expect("headless game should start");
+        setup::run_ticks(&mut game, 50).expect("simulation should run");
+        assert_eq!(game.get_current_tick(), 50);
+        game.planet_manager.get_all_planets().iter()
+            .map(|p: &Planet| (p.controller, p.position.phase, p.resources.current))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(play(3), play(3));
+    assert_ne!(play(3), play(4));
+}
+
+#[test]
+fn test_scenario_files_start_scripted_games() {
+    let scenario = r#"{
+        "version": 1,
+        "name": "Lone Outpost",
+        "factions": [
+            { "name": "Outpost", "is_player": true },
+            { "name": "Neighbours", "ai_type": "Economic" }
+        ],
+        "planets": [
+            { "semi_major_axis": 2.0, "controller": 0, "population": 800, "resources": { "minerals": 100, "food": 400, "energy": 50, "alloys": 0, "components": 0, "fuel": 0 } },
+            { "semi_major_axis": 4.0, "phase": 3.0, "controller": 1, "population": 800 },
+            { "semi_major_axis": 3.0, "phase": 1.5 }
+        ],
+        "ships": [ { "ship_class": "Scout", "owner": 0, "planet": 0 } ],
+        "victory_conditions": [ { "Population": 1000000 }, { "Timeout": 5 } ],
+        "scripted_events": [
+            { "tick": 2, "action": { "Message": { "to": 0, "message": { "TutorialPrompt": { "title": "Welcome", "text": "Hold out" } } } } }
+        ]
+    }"#;
+    let path = std::env::temp_dir().join(format!("stellar_dominion_scenario_{}.json", std::process::id()));
+    std::fs::write(&path, scenario).unwrap();
+    let mut game = setup::new_game_from_scenario(&path).expect("scenario should load");
+    std::fs::remove_file(&path).ok();
+
+    assert_eq!(game.current_mode, GameMode::InGame);
+    assert_eq!(game.planet_manager.get_planet_count(), 3);
+    assert_eq!(game.faction_manager.get_player_faction().map(|faction| faction.id), Some(0));
+
+    setup::run_ticks(&mut game, 4).unwrap();
+    assert_eq!(game.faction_manager.get_faction(0).unwrap().inbox.pending_count(), 1);
+    assert_eq!(game.scenario_system.winner(), None);
+    setup::run_ticks(&mut game, 2).unwrap();
+    assert_eq!(game.scenario_system.winner(), Some((0, VictoryType::Timeout)));
+}
@@ -0,0 +1,611 @@
+// crates/stellar-dominion-core/tests/simulation.rs
+//! The simulation loop: commands, time, events, errors, tracking and tooling
+
+mod common;
+
+use stellar_dominion_core::core::*;
+use common::last_rejection;
+
+#[test]
+fn test_invalid_commands_are_rejected_before_routing() {
+    let mut game_state = GameState::new().unwrap();
+    game_state.current_mode = GameMode::InGame;
+    let player = game_state.faction_manager.create_faction("Player".to_string(), true, AIPersonality::Balanced).unwrap();
+    let enemy = game_state.faction_manager.create_faction("Enemy".to_string(), false, AIPersonality::Economic).unwrap();
+    let home = game_state.planet_manager.create_planet(OrbitalElements::default(), Some(player)).unwrap();
+    let colony = game_state.planet_manager.create_planet(OrbitalElements::default(), Some(player)).unwrap();
+    let hostile = game_state.planet_manager.create_planet(OrbitalElements::default(), Some(enemy)).unwrap();
+    let scout = game_state.ship_manager.create_ship(ShipClass::Scout, Vector2::new(1.0, 0.0), player).unwrap();
+    let raider = game_state.ship_manager.create_ship(ShipClass::Warship, Vector2::new(9.0, 0.0), enemy).unwrap();
+    let rejected = |game_state: &GameState| game_state.event_bus.event_history.iter()
+        .filter(|event| matches!(event, GameEvent::StateChanged(StateChange::CommandRejected { .. })))
+        .count();
+    
+    // Missing targets and unaffordable orders are turned away without failing the tick
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::MoveShip { ship: 999, target: Vector2::new(2.0, 0.0) }));
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::TransferResources {
+        from: home, to: colony, resources: ResourceBundle { minerals: 10, ..Default::default() },
+    }));
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::TransferResources {
+        from: home, to: hostile, resources: ResourceBundle::default(),
+    }));
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::PauseGame(true)));
+    game_state.process_queued_events_for_test().unwrap();
+    assert_eq!(rejected(&game_state), 3);
+    assert!(game_state.time_manager.is_paused(), "Valid commands after a rejected one still run");
+    let reason = last_rejection(&game_state).unwrap();
+    assert!(reason.contains("does not control"), "{}", reason);
+    
+    // The player cannot order around ships and planets it does not control
+    game_state.issue_command(player, PlayerCommand::MoveShip { ship: raider, target: Vector2::new(0.0, 0.0) });
+    game_state.issue_command(player, PlayerCommand::BuildStructure { planet: hostile, building_type: BuildingType::Mine });
+    game_state.issue_command(player, PlayerCommand::DeclareWar { from: enemy, to: player });
+    assert_eq!(rejected(&game_state), 6);
+    assert!(last_rejection(&game_state).unwrap().contains("does not control Faction"));
+    game_state.issue_command(player, PlayerCommand::MoveShip { ship: scout, target: Vector2::new(2.0, 0.0) });
+    game_state.process_queued_events_for_test().unwrap();
+    assert_eq!(rejected(&game_state), 6);
+    assert!(game_state.ship_manager.get_ship(scout).unwrap().trajectory.is_some());
+}
+
+#[test]
+fn test_game_speed_runs_whole_ticks_and_fast_forward_reaches_its_target() {
+    let new_game = || {
+        let mut game_state = GameState::new().unwrap();
+        game_state.current_mode = GameMode::InGame;
+        let player = game_state.faction_manager.create_faction("Player".to_string(), true, AIPersonality::Balanced).unwrap();
+        let scout = game_state.ship_manager.create_ship(ShipClass::Scout, Vector2::new(1.0, 0.0), player).unwrap();
+        game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::MoveShip { ship: scout, target: Vector2::new(8.0, 0.0) }));
+        (game_state, scout)
+    };
+    
+    // At 2x every system runs twice per update, so half the updates reach the same state
+    let (mut normal, scout) = new_game();
+    let (mut double, _) = new_game();
+    double.process_queued_events_for_test().unwrap();
+    double.queue_event(GameEvent::PlayerCommand(PlayerCommand::SetGameSpeed(2.0)));
+    double.fixed_update(0.1).unwrap();
+    for _ in 0..8 {
+        normal.fixed_update(0.1).unwrap();
+    }
+    for _ in 0..4 {
+        double.fixed_update(0.1).unwrap();
+    }
+    assert_eq!(double.get_current_tick(), normal.get_current_tick() + 1);
+    normal.fixed_update(0.1).unwrap();
+    let position = |game_state: &GameState| game_state.ship_manager.get_ship(scout).unwrap().position;
+    assert_eq!(position(&double), position(&normal));
+    assert!(position(&normal).x > 1.0);
+    
+    // A fast-forward runs ahead in stretches, reporting its progress
+    normal.queue_event(GameEvent::PlayerCommand(PlayerCommand::FastForward { ticks: 250 }));
+    normal.fixed_update(0.1).unwrap();
+    let target = normal.get_current_tick() + 250;
+    assert_eq!(normal.time_manager.get_fast_forward_target(), Some(target));
+    let mut reports = Vec::new();
+    while normal.time_manager.is_fast_forwarding() {
+        let since = normal.get_current_tick();
+        normal.fixed_update(0.1).unwrap();
+        reports.extend(normal.event_bus.events_since(since).filter_map(|entry| match &entry.event {
+            GameEvent::SimulationEvent(SimulationEvent::FastForwardProgress { tick, .. }) => Some(*tick),
+            GameEvent::SimulationEvent(SimulationEvent::FastForwardCompleted(tick)) => Some(*tick),
+            _ => None,
+        }));
+    }
+    assert_eq!(reports, vec![100, 200, target]);
+    assert_eq!(normal.get_current_tick(), target);
+    
+    // Out-of-range requests are refused without failing the tick
+    normal.queue_event(GameEvent::PlayerCommand(PlayerCommand::FastForward { ticks: u64::MAX }));
+    normal.queue_event(GameEvent::PlayerCommand(PlayerCommand::SetGameSpeed(f32::NAN)));
+    normal.fixed_update(0.1).unwrap();
+    assert!(!normal.time_manager.is_fast_forwarding());
+    assert_eq!(normal.time_manager.get_speed_multiplier(), 1.0);
+    assert!(last_rejection(&normal).unwrap().contains("Speed multiplier"));
+}
+
+#[test]
+fn test_paused_games_stand_still_until_stepped() {
+    let mut game_state = GameState::new().unwrap();
+    game_state.set_game_configuration(GameConfiguration { seed: 3, ai_opponents: 1, planet_count: 6, ..Default::default() });
+    game_state.start_new_game().unwrap();
+    game_state.step_ticks(10).unwrap();
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::PauseGame(true)));
+    game_state.fixed_update(0.0).unwrap();
+    assert!(game_state.is_paused());
+    
+    // Nothing moves, grows or gets built while paused, but orders still land
+    let before = game_state.compute_state_hash().unwrap();
+    for _ in 0..20 {
+        game_state.fixed_update(0.1).unwrap();
+    }
+    assert_eq!(game_state.get_current_tick(), 10);
+    assert_eq!(game_state.compute_state_hash().unwrap(), before);
+    let home = game_state.planet_manager.get_planets_by_faction(0)[0].id;
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::SetTaxRate { planet: home, rate: TaxRate::Low }));
+    game_state.fixed_update(0.1).unwrap();
+    assert_eq!(game_state.planet_manager.get_planet(home).unwrap().tax_rate, TaxRate::Low);
+    assert_eq!(game_state.get_current_tick(), 10);
+    
+    // Each step runs one full tick and the game stays paused
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::StepTick));
+    game_state.fixed_update(0.0).unwrap();
+    game_state.fixed_update(5.0).unwrap();
+    assert_eq!(game_state.get_current_tick(), 11);
+    assert!(game_state.is_paused());
+    
+    // Steps asked for while running change nothing
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::PauseGame(false)));
+    game_state.fixed_update(0.0).unwrap();
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::StepTick));
+    game_state.fixed_update(0.0).unwrap();
+    assert_eq!(game_state.get_current_tick(), 11);
+    game_state.fixed_update(0.1).unwrap();
+    assert_eq!(game_state.get_current_tick(), 12);
+}
+
+#[test]
+fn test_event_tracer_sees_sources_and_routing() {
+    let mut game_state = GameState::new().unwrap();
+    game_state.start_new_game().unwrap();
+    let collector = TraceCollector::new();
+    assert!(game_state.event_bus.set_tracer(Some(Box::new(collector.clone()))).is_none());
+    assert!(game_state.event_bus.is_tracing());
+    
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::PauseGame(false)));
+    // Events queued while a batch is processed are dispatched with the next one
+    game_state.fixed_update(0.1).unwrap();
+    game_state.fixed_update(0.1).unwrap();
+    let entries = collector.entries();
+    
+    // Commands from outside the simulation have no source; ticks come from the TimeManager
+    assert!(matches!(&entries[0], TraceEntry::Queued { source: None, event: GameEvent::PlayerCommand(PlayerCommand::PauseGame(false)), .. }));
+    assert!(entries.iter().any(|entry| matches!(entry,
+        TraceEntry::Queued { tick: 1, source: Some(SystemId::TimeManager), event: GameEvent::SimulationEvent(SimulationEvent::TickCompleted(1)) })));
+    // GameState announces production itself while handling the tick, and routes it to the statistics
+    assert!(entries.iter().any(|entry| matches!(entry,
+        TraceEntry::Queued { source: None, event: GameEvent::SimulationEvent(SimulationEvent::ResourcesProduced { .. }), .. })));
+    assert!(entries.iter().any(|entry| matches!(entry,
+        TraceEntry::Dispatched { systems, event: GameEvent::SimulationEvent(SimulationEvent::ResourcesProduced { .. }), .. }
+            if systems.contains(&SystemId::StatisticsSystem))));
+    let tick_routing = entries.iter().find_map(|entry| match entry {
+        TraceEntry::Dispatched { systems, event: GameEvent::SimulationEvent(SimulationEvent::TickCompleted(1)), .. } => Some(systems.clone()),
+        _ => None,
+    }).unwrap();
+    assert_eq!(tick_routing, game_state.event_bus.subscribers_for(&GameEvent::SimulationEvent(SimulationEvent::TickCompleted(1))));
+    
+    // Detaching stops the trace
+    game_state.event_bus.set_tracer(None);
+    collector.clear();
+    game_state.fixed_update(0.1).unwrap();
+    assert!(collector.entries().is_empty());
+}
+
+#[test]
+fn test_bench_support_builds_deterministic_full_scale_worlds() {
+    use stellar_dominion_core::bench_support::{full_scale_game, routing_events, synthetic_scenario, BENCH_FACTIONS};
+    use stellar_dominion_core::config::{MAX_PLANETS, MAX_SHIPS};
+    
+    let mut game_state = full_scale_game(3).unwrap();
+    assert_eq!(game_state.planet_manager.get_all_planets().len(), MAX_PLANETS);
+    assert_eq!(game_state.ship_manager.get_all_ships().len(), MAX_SHIPS);
+    assert_eq!(game_state.faction_manager.get_all_factions().len(), BENCH_FACTIONS);
+    for faction in 0..BENCH_FACTIONS as FactionId {
+        assert!(!game_state.planet_manager.get_planets_by_faction(faction).is_empty());
+    }
+    
+    // The same seed lays out the same world; another seed moves the planets
+    let layout = |seed| format!("{:?}", synthetic_scenario(seed, 20, 40));
+    assert_eq!(layout(3), layout(3));
+    assert_ne!(layout(3), layout(4));
+    
+    let events = routing_events(&game_state, 30);
+    assert_eq!(events.len(), 30);
+    for event in events {
+        game_state.queue_event(event);
+    }
+    game_state.process_queued_events_for_test().unwrap();
+    game_state.fixed_update(0.1).unwrap();
+    assert_eq!(game_state.get_current_tick(), 1);
+}
+
+#[test]
+fn test_spatial_index_follows_planets_and_ships() {
+    use stellar_dominion_core::systems::SpatialEntity;
+    use stellar_dominion_core::systems::combat_resolver::ENGAGEMENT_RANGE;
+    
+    let mut game_state = GameState::new().unwrap();
+    game_state.start_new_game().unwrap();
+    let entities = game_state.planet_manager.get_all_planets().len() + game_state.ship_manager.get_all_ships().len();
+    assert_eq!(game_state.physics_engine.spatial_index().len(), entities, "Starting a game indexes the world");
+    
+    let home = game_state.planet_manager.get_all_planets().iter().find(|planet| planet.controller.is_some()).unwrap().clone();
+    let owner = home.controller.unwrap();
+    let rival = game_state.faction_manager.get_all_factions().iter().find(|faction| faction.id != owner).unwrap().id;
+    let far = Vector2::new(500.0, 500.0);
+    let warship = game_state.ship_manager.create_ship(ShipClass::Warship, far, owner).unwrap();
+    let raider = game_state.ship_manager.create_ship(ShipClass::Scout, Vector2::new(far.x + ENGAGEMENT_RANGE / 2.0, far.y), rival).unwrap();
+    game_state.ship_manager.create_ship(ShipClass::Scout, Vector2::new(far.x + ENGAGEMENT_RANGE * 2.0, far.y), rival).unwrap();
+    
+    // Ships created outside the simulation are indexed with the next tick
+    assert!(game_state.physics_engine.entities_within(far, 1.0).is_empty());
+    game_state.fixed_update(0.1).unwrap();
+    game_state.fixed_update(0.1).unwrap();
+    let tick = game_state.get_current_tick();
+    assert_eq!(game_state.physics_engine.spatial_index().tick(), tick);
+    
+    let home_position = game_state.physics_engine.calculate_orbital_position(&home.position, tick);
+    assert!(game_state.physics_engine.entities_within(home_position, 0.01)
+        .iter()
+        .any(|(entity, _)| *entity == SpatialEntity::Planet(home.id)));
+    let position = game_state.ship_manager.get_ship(warship).unwrap().position;
+    let in_range = game_state.combat_resolver.ships_in_engagement_range(game_state.physics_engine.spatial_index(), warship, position);
+    assert_eq!(in_range, vec![raider]);
+    
+    // Sensors see through the index too
+    let visibility = game_state.faction_visibility(owner).unwrap();
+    assert!(visibility.can_see_ship(raider) && visibility.can_see_planet(home.id));
+}
+
+#[test]
+fn test_change_tracking_reports_only_changed_planets_and_ships() {
+    let mut game_state = GameState::new().unwrap();
+    game_state.start_new_game().unwrap();
+    let planets = game_state.planet_manager.get_planet_count();
+    assert_eq!(game_state.planet_manager.changed_since(0).count(), planets, "A new world counts as changed");
+    
+    game_state.fixed_update(0.1).unwrap();
+    game_state.fixed_update(0.1).unwrap();
+    let tick = game_state.get_current_tick();
+    
+    // Production lands on settled planets every tick; unclaimed ones stay as they were
+    let changed: Vec<PlanetId> = game_state.planet_manager.changed_since(tick).map(|planet| planet.id).collect();
+    for planet in game_state.planet_manager.get_all_planets() {
+        assert_eq!(changed.contains(&planet.id), planet.controller.is_some(), "Planet {}", planet.id);
+    }
+    
+    // Stockpile changes leave the cached production alone; tax changes recompute it
+    let home = game_state.planet_manager.get_all_planets().iter().find(|planet| planet.controller.is_some()).unwrap().id;
+    let revision = game_state.planet_manager.production_revision(home);
+    game_state.fixed_update(0.1).unwrap();
+    assert_eq!(game_state.planet_manager.production_revision(home), revision);
+    game_state.planet_manager.set_tax_rate(home, TaxRate::High).unwrap();
+    assert!(game_state.planet_manager.production_revision(home) > revision);
+    let planet = game_state.planet_manager.get_planet(home).unwrap().clone();
+    let expected = game_state.resource_system.calculate_planet_production(&planet).unwrap();
+    let revision = game_state.planet_manager.production_revision(home);
+    assert_eq!(game_state.resource_system.cached_planet_production(&planet, revision).unwrap(), expected);
+    
+    // Ships are stamped by whatever changes them
+    let ship_revision = game_state.ship_manager.revision();
+    let owner = planet.controller.unwrap();
+    let ship = game_state.ship_manager.create_ship(ShipClass::Scout, Vector2::new(3.0, 0.0), owner).unwrap();
+    assert!(game_state.ship_manager.revision() > ship_revision);
+    let tick = game_state.get_current_tick();
+    assert!(game_state.ship_manager.changed_since(tick).any(|changed| changed.id == ship));
+    game_state.fixed_update(0.1).unwrap();
+    assert!(!game_state.ship_manager.changed_since(tick + 1).any(|changed| changed.id == ship), "An idle ship does not change");
+}
+
+#[test]
+fn test_stale_ship_ids_are_rejected_after_their_slot_is_reused() {
+    use stellar_dominion_core::managers::{id_index, id_generation};
+    
+    let mut game_state = GameState::new().unwrap();
+    game_state.start_new_game().unwrap();
+    let owner = game_state.faction_manager.get_player_faction().unwrap().id;
+    let doomed = game_state.ship_manager.create_ship(ShipClass::Scout, Vector2::new(2.0, 0.0), owner).unwrap();
+    game_state.ship_manager.destroy_ship(doomed).unwrap();
+    
+    // The next ship takes the freed slot under a new generation
+    let successor = game_state.ship_manager.create_ship(ShipClass::Scout, Vector2::new(2.0, 0.0), owner).unwrap();
+    assert_eq!(id_index(successor), id_index(doomed));
+    assert_eq!(id_generation(successor), id_generation(doomed) + 1);
+    assert!(game_state.ship_manager.is_live(successor) && !game_state.ship_manager.is_live(doomed));
+    match game_state.ship_manager.get_ship(doomed) {
+        Err(GameError::StaleEntity { kind: EntityKind::Ship, id, generation }) => {
+            assert_eq!((id, generation), (doomed as u64, id_generation(successor)));
+        }
+        other => panic!("Expected a stale id error, got {:?}", other.map(|ship| ship.id)),
+    }
+    
+    // Commands naming the dead ship are turned away instead of moving its successor
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::MoveShip { ship: doomed, target: Vector2::new(9.0, 9.0) }));
+    game_state.process_queued_events_for_test().unwrap();
+    assert_eq!(game_state.ship_manager.get_ship(successor).unwrap().trajectory, None);
+    let tick = game_state.get_current_tick();
+    assert!(game_state.event_bus.events_since(tick).any(|entry| matches!(&entry.event,
+        GameEvent::StateChanged(StateChange::CommandRejected { reason, .. }) if reason.contains("stale"))));
+}
+
+#[cfg(feature = "debug")]
+#[test]
+fn test_debug_console_commands_change_the_game() {
+    use stellar_dominion_core::console;
+    
+    let mut game_state = GameState::new().unwrap();
+    game_state.start_new_game().unwrap();
+    let owner = game_state.faction_manager.get_player_faction().unwrap().id;
+    let planet = game_state.planet_manager.get_all_planets()[0].id;
+    let minerals = game_state.planet_manager.get_planet(planet).unwrap().resources.current.minerals;
+    
+    console::run(&mut game_state, &format!("give {} minerals 10", planet)).unwrap();
+    assert_eq!(game_state.planet_manager.get_planet(planet).unwrap().resources.current.minerals, minerals + 10);
+    
+    let ships = game_state.ship_manager.get_all_ships().len();
+    console::run(&mut game_state, &format!("spawn ship warship {} 100 100", owner)).unwrap();
+    let spawned = game_state.ship_manager.get_all_ships().last().unwrap().id;
+    assert_eq!(game_state.ship_manager.get_all_ships().len(), ships + 1);
+    
+    console::run(&mut game_state, &format!("teleport ship {} 50 50", spawned)).unwrap();
+    assert_eq!(game_state.ship_manager.get_ship(spawned).unwrap().position, Vector2::new(50.0, 50.0));
+    
+    // Ticks run even while paused
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::PauseGame(true)));
+    game_state.process_queued_events_for_test().unwrap();
+    assert!(game_state.time_manager.is_paused());
+    let tick = game_state.get_current_tick();
+    console::run(&mut game_state, "tick 5").unwrap();
+    assert_eq!(game_state.get_current_tick(), tick + 5);
+    
+    assert!(console::run(&mut game_state, "teleport ship 9999 1 1").is_err());
+    assert!(console::run(&mut game_state, "spawn ship warship 200 0 0").is_err());
+}
+
+#[test]
+fn test_script_hooks_see_ticks_events_and_commands_and_queue_events() {
+    use std::sync::{Arc, Mutex};
+    
+    // Pauses the game once tick 5 is reached, as a scripted win condition might
+    struct PauseAtTickFive {
+        log: Arc<Mutex<Vec<String>>>,
+    }
+    
+    impl ScriptHook for PauseAtTickFive {
+        fn on_tick(&mut self, game: &GameState, tick: u64, queue: &mut ScriptQueue) {
+            assert_eq!(tick, game.get_current_tick());
+            self.log.lock().unwrap().push(format!("tick {}", tick));
+            if tick == 5 {
+                queue.queue_command(PlayerCommand::PauseGame(true));
+            }
+        }
+        
+        fn on_event(&mut self, _game: &GameState, event: &GameEvent, _queue: &mut ScriptQueue) {
+            if let GameEvent::SimulationEvent(SimulationEvent::TickCompleted(tick)) = event {
+                self.log.lock().unwrap().push(format!("completed {}", tick));
+            }
+        }
+        
+        fn on_command(&mut self, _game: &GameState, command: &PlayerCommand, _queue: &mut ScriptQueue) {
+            self.log.lock().unwrap().push(format!("command {:?}", command));
+        }
+    }
+    
+    let mut game_state = GameState::new().unwrap();
+    game_state.start_new_game().unwrap();
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let hook = game_state.add_script_hook(Box::new(PauseAtTickFive { log: log.clone() }));
+    
+    for _ in 0..8 {
+        game_state.fixed_update(stellar_dominion_core::config::FIXED_TIMESTEP).unwrap();
+    }
+    assert!(game_state.time_manager.is_paused());
+    assert_eq!(game_state.get_current_tick(), 6);
+    let log = log.lock().unwrap().clone();
+    assert_eq!(log.iter().filter(|line| line.starts_with("tick ")).count(), 6);
+    assert!(log.contains(&"completed 1".to_string()));
+    assert!(log.contains(&"command PauseGame(true)".to_string()));
+    
+    // Removed hooks are no longer called
+    assert!(game_state.remove_script_hook(hook).is_some());
+    assert!(game_state.remove_script_hook(hook).is_none());
+}
+
+#[test]
+fn test_recoverable_system_errors_are_reported_without_stopping_the_game() {
+    let mut game_state = GameState::new().unwrap();
+    game_state.start_new_game().unwrap();
+    let player = game_state.faction_manager.get_player_faction().unwrap().id;
+    let home = game_state.planet_manager.get_planets_by_faction(player)[0].id;
+    
+    // A building the faction cannot build yet fails in the construction system, and the tick still runs
+    let tick = game_state.get_current_tick();
+    let buildings = game_state.planet_manager.get_planet(home).unwrap().developments.len();
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::BuildStructure { planet: home, building_type: BuildingType::DefensePlatform }));
+    game_state.fixed_update(0.1).unwrap();
+    assert!(game_state.get_current_tick() > tick);
+    let reported = game_state.event_bus.event_history.iter().find_map(|event| match event {
+        GameEvent::StateChanged(StateChange::SystemErrorOccurred { system, error }) => Some((*system, error.clone())),
+        _ => None,
+    });
+    assert!(matches!(reported, Some((SystemId::ConstructionSystem, error)) if error.contains("locked")));
+    for _ in 0..5 {
+        game_state.fixed_update(0.1).unwrap();
+    }
+    
+    // Systems later in the route never place the refused building
+    let planet = game_state.planet_manager.get_planet(home).unwrap();
+    assert_eq!(planet.developments.len(), buildings);
+    assert!(planet.developments.iter().all(|building| building.building_type != BuildingType::DefensePlatform));
+    
+    // Only errors that leave the state untrustworthy stop the update
+    assert!(GameError::InvalidOperation("refused".into()).is_recoverable());
+    assert!(GameError::SaveError("disk full".into()).is_recoverable());
+    assert!(GameError::Network("Peer disconnected".into()).is_recoverable());
+    assert!(!GameError::SystemError("tick counter out of order".into()).is_recoverable());
+}
+
+#[test]
+fn test_recoverable_tick_processing_errors_do_not_stop_the_update() {
+    let mut game_state = GameState::new().unwrap();
+    game_state.start_new_game().unwrap();
+    let player = game_state.faction_manager.get_player_faction().unwrap().id;
+    let home = game_state.planet_manager.get_planets_by_faction(player)[0].id;
+
+    // Workers that do not add up to the population make every change to the
+    // home planet fail validation
+    let mut planets = game_state.planet_manager.get_all_planets_cloned().unwrap();
+    let planet = planets.iter_mut().find(|planet| planet.id == home).unwrap();
+    planet.population.allocation.unassigned += 5;
+    game_state.planet_manager.load_planets(planets).unwrap();
+
+    let tick = game_state.get_current_tick();
+    game_state.fixed_update(0.1).unwrap();
+    game_state.fixed_update(0.1).unwrap();
+    assert!(game_state.get_current_tick() > tick);
+    let reported = game_state.event_bus.event_history.iter().find_map(|event| match event {
+        GameEvent::StateChanged(StateChange::SystemErrorOccurred { system: SystemId::ResourceSystem, error }) => Some(error.clone()),
+        _ => None,
+    });
+    assert!(reported.is_some_and(|error| error.contains("doesn't match")));
+
+    // The other planets still got their production that tick
+    let produced: Vec<PlanetId> = game_state.event_bus.event_history.iter().filter_map(|event| match event {
+        GameEvent::SimulationEvent(SimulationEvent::ResourcesProduced { planet, .. }) => Some(*planet),
+        _ => None,
+    }).collect();
+    assert!(!produced.contains(&home));
+    assert!(!produced.is_empty());
+}
+
+#[test]
+fn test_log_collector_keeps_recent_records_by_level_and_target() {
+    use stellar_dominion_core::logging::{self, LogCollector, LOG_CAPACITY};
+    use log::{Level, LevelFilter, Log, Record};
+    
+    // A collector keeps what reaches its level, oldest dropped first
+    let collector = LogCollector::new(LevelFilter::Info, None);
+    let log = |level, message: &str| collector.log(&Record::builder()
+        .level(level)
+        .target("stellar_dominion_core::audio")
+        .args(format_args!("{}", message))
+        .build());
+    log(Level::Debug, "too detailed");
+    log(Level::Warn, "first");
+    for i in 0..LOG_CAPACITY {
+        log(Level::Info, &format!("record {}", i));
+    }
+    let kept = collector.recent(LOG_CAPACITY + 10);
+    assert_eq!(kept.len(), LOG_CAPACITY);
+    assert_eq!(kept[0].message, "record 0");
+    assert_eq!(collector.recent(1)[0].to_string(), format!("INFO stellar_dominion_core::audio: record {}", LOG_CAPACITY - 1));
+    collector.clear();
+    assert!(collector.recent(5).is_empty());
+    
+    // Once installed, the game's own warnings land in it under their module
+    logging::init(LevelFilter::Info, None).unwrap();
+    assert!(logging::init(LevelFilter::Info, None).is_err());
+    let mut game_state = GameState::new().unwrap();
+    game_state.start_new_game().unwrap();
+    let player = game_state.faction_manager.get_player_faction().unwrap().id;
+    let home = game_state.planet_manager.get_planets_by_faction(player)[0].id;
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::BuildStructure { planet: home, building_type: BuildingType::DefensePlatform }));
+    game_state.fixed_update(0.1).unwrap();
+    let warning = logging::recent(LOG_CAPACITY).into_iter()
+        .find(|entry| entry.message.contains("DefensePlatform is locked"))
+        .unwrap();
+    assert_eq!(warning.level, Level::Warn);
+    assert!(warning.target.starts_with("stellar_dominion_core::core"));
+}
+
+#[test]
+fn test_errors_carry_structured_details_and_stable_codes() {
+    use stellar_dominion_core::systems::SaveSystem;
+    
+    let mut game_state = GameState::new().unwrap();
+    game_state.start_new_game().unwrap();
+    
+    // Lookups name the kind and id that were missing
+    match game_state.faction_manager.get_faction(99) {
+        Err(error @ GameError::EntityNotFound { kind: EntityKind::Faction, id: 99 }) => {
+            assert_eq!(error.code(), "E0103");
+            assert_eq!(error.to_string(), "Faction 99 not found");
+        }
+        other => panic!("Expected a missing faction, got {:?}", other.map(|faction| faction.id)),
+    }
+    
+    // A damaged save names the file it came from
+    let path = std::env::temp_dir().join(format!("stellar_dominion_corrupted_{}.json", std::process::id()));
+    std::fs::write(&path, "{ \"version\": 1, \"planets\": ").unwrap();
+    match SaveSystem::import_json(&path) {
+        Err(error @ GameError::SaveCorrupted { .. }) => {
+            assert_eq!(error.code(), "E0200");
+            assert!(matches!(&error, GameError::SaveCorrupted { path: named, .. } if *named == path.display().to_string()));
+        }
+        other => panic!("Expected a corrupted save, got {:?}", other.map(|save| save.save_name)),
+    }
+    std::fs::remove_file(&path).ok();
+    assert!(matches!(SaveSystem::import_json(&path), Err(GameError::Io(_))));
+    
+    // Every kind of error has its own code
+    let errors = [
+        GameError::InvalidOperation(String::new()),
+        GameError::InsufficientResources { required: ResourceBundle::default(), available: ResourceBundle::default() },
+        GameError::InvalidTarget(String::new()),
+        GameError::not_found(EntityKind::Ship, 1u32),
+        GameError::StaleEntity { kind: EntityKind::Ship, id: 1, generation: 2 },
+        GameError::CapacityExceeded { resource: "ship slots".into(), requested: 1, available: 0 },
+        GameError::InvalidCommand { command: String::new(), reason: String::new() },
+        GameError::not_owned(1, EntityKind::Planet, 2u32),
+        GameError::InsufficientCredits { required: 10, available: 5 },
+        GameError::UnsuitableShip { ship: 1, class: ShipClass::Scout, task: String::new() },
+        GameError::NotInOrbit { ship: 1, planet: 2 },
+        GameError::invalid_value("troops", "must be positive"),
+        GameError::SaveCorrupted { path: String::new(), detail: String::new() },
+        GameError::SaveError(String::new()),
+        GameError::Io(String::new()),
+        GameError::Network(String::new()),
+        GameError::SystemError(String::new()),
+    ];
+    let codes: std::collections::BTreeSet<_> = errors.iter().map(GameError::code).collect();
+    assert_eq!(codes.len(), errors.len());
+}
+
+#[test]
+fn test_game_state_builder_starts_the_described_world() {
+    use stellar_dominion_core::prelude::{GameStateBuilder, ScenarioFaction, ScenarioPlanet, ScenarioShip};
+    use stellar_dominion_core::systems::VictoryCondition;
+    
+    let world = GameStateBuilder::new()
+        .with_seed(42)
+        .with_faction(ScenarioFaction::new("Terran Union", true))
+        .with_faction(ScenarioFaction { ai_type: AIPersonality::Aggressive, ..ScenarioFaction::new("Zorg Hive", false) })
+        .with_planet(ScenarioPlanet { controller: Some(0), population: 1000, buildings: vec![BuildingType::Mine], ..ScenarioPlanet::new(1.0) })
+        .with_planet(ScenarioPlanet { controller: Some(1), population: 500, ..ScenarioPlanet::new(2.0) })
+        .with_planet(ScenarioPlanet::new(3.5))
+        .with_ship(ScenarioShip { ship_class: ShipClass::Scout, owner: 0, planet: 0 })
+        .with_ship(ScenarioShip { ship_class: ShipClass::Warship, owner: 1, planet: 1 })
+        .with_victory_condition(VictoryCondition::ControlPlanets(3));
+    let mut game_state = world.clone().build().unwrap();
+    
+    // Ids follow the order things were added
+    assert_eq!(game_state.current_mode, GameMode::InGame);
+    assert_eq!(game_state.get_game_configuration().seed, 42);
+    assert_eq!(game_state.faction_manager.get_player_faction().unwrap().name, "Terran Union");
+    assert_eq!(game_state.faction_manager.get_faction(1).unwrap().ai_type, AIPersonality::Aggressive);
+    let home = game_state.planet_manager.get_planet(0).unwrap();
+    assert_eq!((home.controller, home.population.total), (Some(0), 1000));
+    assert_eq!(home.developments.len(), 1);
+    assert_eq!(game_state.planet_manager.get_planet(2).unwrap().controller, None);
+    let warship = game_state.ship_manager.get_ships_by_owner(1)[0];
+    assert_eq!(game_state.ship_manager.get_ship(warship).unwrap().ship_class, ShipClass::Warship);
+    game_state.fixed_update(0.1).unwrap();
+    
+    // The same description builds the same game
+    let again = world.build().unwrap();
+    assert_eq!(again.planet_manager.get_all_planets().len(), 3);
+    assert_eq!(again.ship_manager.get_all_ships().len(), 2);
+    
+    // References to things never added are refused
+    let orphan = GameStateBuilder::new()
+        .with_faction(ScenarioFaction::new("Terran Union", true))
+        .with_planet(ScenarioPlanet::new(1.0))
+        .with_ship(ScenarioShip { ship_class: ShipClass::Scout, owner: 0, planet: 4 });
+    assert!(matches!(orphan.build(), Err(GameError::SaveError(message)) if message.contains("unknown faction or planet")));
+    let two_players = GameStateBuilder::new()
+        .with_faction(ScenarioFaction::new("Terran Union", true))
+        .with_faction(ScenarioFaction::new("Free Worlds", true))
+        .with_planet(ScenarioPlanet::new(1.0));
+    assert!(two_players.build().is_err());
+    assert!(GameStateBuilder::new().build().is_err());
+}
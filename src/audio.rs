@@ -0,0 +1,294 @@
+// src/audio.rs
+//! Sound effects
+//!
+//! The [`AudioSystem`] listens to the game rather than being called from it.
+//! After each simulation step it reads the events the bus recorded since it
+//! last looked, and the player's new notifications, and plays a
+//! [`SoundEffect`] for battles the player's ships are in, buildings and
+//! ships completed on the player's planets, and alerts. The client plays
+//! [`SoundEffect::Click`] for every command the UI produces. Each effect
+//! plays at most once per step, however many events call for it.
+//!
+//! Sounds go through an [`AudioBackend`]. Built with the `audio` feature the
+//! client loads [`MacroquadAudio`] from the files in [`SOUNDS_DIR`], and any
+//! effect without a file stays silent. Without the feature, and in tests and
+//! tools, the [`SilentAudio`] backend plays nothing.
+
+use crate::core::{GameEvent, GameState, SimulationEvent};
+use crate::core::types::*;
+use crate::systems::NotificationPriority;
+use std::collections::HashSet;
+
+/// Directory of the sound effect files, one per [`SoundEffect::file_name`]
+pub const SOUNDS_DIR: &str = "assets/sounds";
+
+/// A sound the game can play
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SoundEffect {
+    /// A button or menu entry was used
+    Click,
+    /// A ship fired guns or a missile
+    WeaponFire,
+    /// A ship was destroyed or a missile struck
+    Explosion,
+    /// A building was finished
+    ConstructionComplete,
+    /// A new ship left the shipyard
+    ShipLaunched,
+    /// A notification arrived
+    Notification,
+    /// A high priority or critical notification arrived
+    Alert,
+}
+
+impl SoundEffect {
+    /// Every effect
+    pub const ALL: [SoundEffect; 7] = [
+        SoundEffect::Click,
+        SoundEffect::WeaponFire,
+        SoundEffect::Explosion,
+        SoundEffect::ConstructionComplete,
+        SoundEffect::ShipLaunched,
+        SoundEffect::Notification,
+        SoundEffect::Alert,
+    ];
+
+    /// Name of the effect's file in [`SOUNDS_DIR`]
+    pub fn file_name(self) -> &'static str {
+        match self {
+            SoundEffect::Click => "click.wav",
+            SoundEffect::WeaponFire => "weapon_fire.wav",
+            SoundEffect::Explosion => "explosion.wav",
+            SoundEffect::ConstructionComplete => "construction_complete.wav",
+            SoundEffect::ShipLaunched => "ship_launched.wav",
+            SoundEffect::Notification => "notification.wav",
+            SoundEffect::Alert => "alert.wav",
+        }
+    }
+}
+
+/// Something that can play sound effects
+pub trait AudioBackend {
+    /// Play the effect once at a volume from 0.0 to 1.0
+    fn play(&mut self, effect: SoundEffect, volume: f32);
+}
+
+/// Backend that plays nothing, for headless runs and builds without sound
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SilentAudio;
+
+impl AudioBackend for SilentAudio {
+    fn play(&mut self, _effect: SoundEffect, _volume: f32) {}
+}
+
+/// Backend playing sound files through macroquad
+#[cfg(feature = "audio")]
+#[derive(Debug, Default)]
+pub struct MacroquadAudio {
+    sounds: std::collections::HashMap<SoundEffect, macroquad::audio::Sound>,
+}
+
+#[cfg(feature = "audio")]
+impl MacroquadAudio {
+    /// Load each effect's file from the directory, skipping missing files
+    pub fn load_from_dir(dir: &std::path::Path) -> GameResult<Self> {
+        let mut sounds = std::collections::HashMap::new();
+        for effect in SoundEffect::ALL {
+            let path = dir.join(effect.file_name());
+            if !path.exists() {
+                continue;
+            }
+            let bytes = std::fs::read(&path)?;
+            // Native builds decode the sound before the future first yields
+            let mut loading = std::pin::pin!(macroquad::audio::load_sound_from_bytes(&bytes));
+            let mut context = std::task::Context::from_waker(std::task::Waker::noop());
+            let sound = match std::future::Future::poll(loading.as_mut(), &mut context) {
+                std::task::Poll::Ready(sound) => sound
+                    .map_err(|e| GameError::InvalidOperation(format!("Cannot read sound {}: {}", path.display(), e)))?,
+                std::task::Poll::Pending => continue,
+            };
+            sounds.insert(effect, sound);
+        }
+        Ok(Self { sounds })
+    }
+}
+
+#[cfg(feature = "audio")]
+impl AudioBackend for MacroquadAudio {
+    fn play(&mut self, effect: SoundEffect, volume: f32) {
+        if let Some(sound) = self.sounds.get(&effect) {
+            macroquad::audio::play_sound(sound, macroquad::audio::PlaySoundParams { looped: false, volume });
+        }
+    }
+}
+
+/// Plays sound effects for what happens in the game at the player's volumes
+pub struct AudioSystem {
+    backend: Box<dyn AudioBackend>,
+    master_volume: f32,
+    effects_volume: f32,
+    /// Tick of the last bus event heard and how many events of that tick were heard
+    heard_events: (u64, usize),
+    /// Newest notification heard
+    heard_notification: NotificationId,
+    /// The player's ships in battles still going on, to know whose battle ends
+    ships_in_battle: HashSet<ShipId>,
+}
+
+impl AudioSystem {
+    /// Play through the backend at full volume
+    pub fn new(backend: Box<dyn AudioBackend>) -> Self {
+        Self {
+            backend,
+            master_volume: 1.0,
+            effects_volume: 1.0,
+            heard_events: (0, 0),
+            heard_notification: 0,
+            ships_in_battle: HashSet::new(),
+        }
+    }
+
+    /// Play nothing
+    pub fn silent() -> Self {
+        Self::new(Box::new(SilentAudio))
+    }
+
+    /// The effects in [`SOUNDS_DIR`] when built with the `audio` feature,
+    /// otherwise silence
+    pub fn load_default() -> GameResult<Self> {
+        #[cfg(feature = "audio")]
+        {
+            let backend = MacroquadAudio::load_from_dir(std::path::Path::new(SOUNDS_DIR))?;
+            Ok(Self::new(Box::new(backend)))
+        }
+        #[cfg(not(feature = "audio"))]
+        Ok(Self::silent())
+    }
+
+    /// Set the master and effects volumes, each clamped to 0.0 to 1.0
+    pub fn set_volumes(&mut self, master: f32, effects: f32) {
+        self.master_volume = master.clamp(0.0, 1.0);
+        self.effects_volume = effects.clamp(0.0, 1.0);
+    }
+
+    /// Overall volume
+    pub fn master_volume(&self) -> f32 {
+        self.master_volume
+    }
+
+    /// Sound effect volume under the master volume
+    pub fn effects_volume(&self) -> f32 {
+        self.effects_volume
+    }
+
+    /// Play an effect now, unless the volume is off
+    pub fn play(&mut self, effect: SoundEffect) {
+        let volume = self.master_volume * self.effects_volume;
+        if volume > 0.0 {
+            self.backend.play(effect, volume);
+        }
+    }
+
+    /// Treat everything that already happened in the game as heard, such as
+    /// when a game is started or loaded
+    pub fn skip_to_now(&mut self, game: &GameState) {
+        self.heard_events = Self::last_heard(game);
+        self.heard_notification = Self::newest_notification(game).unwrap_or(0);
+        self.ships_in_battle.clear();
+    }
+
+    /// Tick of the newest event on the bus and the number of events queued during it
+    fn last_heard(game: &GameState) -> (u64, usize) {
+        let Some(last) = game.event_bus.recent_events(1).next().map(|entry| entry.tick) else {
+            return (0, 0);
+        };
+        (last, game.event_bus.events_since(last).count())
+    }
+
+    /// Id of the player's newest notification
+    fn newest_notification(game: &GameState) -> Option<NotificationId> {
+        let player = game.faction_manager.get_player_faction()?.id;
+        game.notification_system.notifications(player).iter().map(|notification| notification.id).max()
+    }
+
+    /// Play the effects called for by the events and the player's
+    /// notifications since the last call
+    pub fn hear(&mut self, game: &GameState) {
+        let player = game.faction_manager.get_player_faction().map(|faction| faction.id);
+        let mut effects = Vec::new();
+        let (heard_tick, heard_count) = self.heard_events;
+        let mut in_heard_tick = 0;
+        for entry in game.event_bus.events_since(heard_tick) {
+            if entry.tick == heard_tick {
+                in_heard_tick += 1;
+                if in_heard_tick <= heard_count {
+                    continue;
+                }
+            }
+            if let (Some(player), GameEvent::SimulationEvent(event)) = (player, &entry.event) {
+                effects.extend(self.effect_for(game, player, event));
+            }
+        }
+        self.heard_events = Self::last_heard(game);
+
+        if let Some(player) = player {
+            let arrived: Vec<_> = game.notification_system.notifications(player).into_iter()
+                .filter(|notification| notification.id > self.heard_notification)
+                .collect();
+            if let Some(urgent) = arrived.iter().map(|notification| notification.priority).max() {
+                effects.push(if urgent >= NotificationPriority::High { SoundEffect::Alert } else { SoundEffect::Notification });
+            }
+        }
+        self.heard_notification = self.heard_notification.max(Self::newest_notification(game).unwrap_or(0));
+
+        let mut played = Vec::new();
+        for effect in effects {
+            if !played.contains(&effect) {
+                played.push(effect);
+                self.play(effect);
+            }
+        }
+    }
+
+    /// The effect an event calls for, if it concerns the player
+    fn effect_for(&mut self, game: &GameState, player: FactionId, event: &SimulationEvent) -> Option<SoundEffect> {
+        let owns_ship = |ship: ShipId| game.ship_manager.get_ship(ship).is_ok_and(|ship| ship.owner == player);
+        let owns_planet = |planet: PlanetId| game.planet_manager.get_planet(planet).is_ok_and(|planet| planet.controller == Some(player));
+        match event {
+            SimulationEvent::ShipCombat { attacker, defender, .. } => {
+                if !owns_ship(*attacker) && !owns_ship(*defender) {
+                    return None;
+                }
+                self.ships_in_battle.extend([*attacker, *defender]);
+                Some(SoundEffect::WeaponFire)
+            }
+            SimulationEvent::MissileLaunched { launcher, target, .. } => {
+                (owns_ship(*launcher) || owns_ship(*target)).then_some(SoundEffect::WeaponFire)
+            }
+            SimulationEvent::MissileImpact { target, hits, .. } => {
+                let ours = owns_ship(*target) || self.ships_in_battle.contains(target) || hits.iter().any(|(ship, _)| owns_ship(*ship));
+                ours.then_some(SoundEffect::Explosion)
+            }
+            SimulationEvent::CombatResolved { attacker, defender, outcome } => {
+                // The player's ship may be gone by now, so battles it was in are remembered
+                let attacker_ours = self.ships_in_battle.remove(attacker);
+                let defender_ours = self.ships_in_battle.remove(defender);
+                let ours = attacker_ours || defender_ours || outcome.winner == player;
+                let losses = !outcome.attacker_losses.is_empty() || !outcome.defender_losses.is_empty();
+                (ours && losses).then_some(SoundEffect::Explosion)
+            }
+            SimulationEvent::ConstructionCompleted { planet, .. } => owns_planet(*planet).then_some(SoundEffect::ConstructionComplete),
+            SimulationEvent::ShipCompleted { planet, .. } => owns_planet(*planet).then_some(SoundEffect::ShipLaunched),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Debug for AudioSystem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AudioSystem")
+            .field("master_volume", &self.master_volume)
+            .field("effects_volume", &self.effects_volume)
+            .finish_non_exhaustive()
+    }
+}
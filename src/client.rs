@@ -0,0 +1,1540 @@
+// src/client.rs
+use crate::core::{GameDataRegistry, GameEvent, GameResult, GameState, JsonlTracer, PlayerCommand, StateChange};
+use crate::core::types::*;
+use crate::systems::{GAME_SPEEDS, PlanetKnowledge, ProductionReport};
+use crate::systems::combat_resolver::{MISSILE_BLAST_RADIUS, MISSILE_EFFECT_TICKS};
+use crate::ui_v2::{UISystem, Camera, CameraTarget, MotionBuffer, RenderBatcher, IconAtlas, Icon, IconDetail, Selection, GroupRecall, OrderResolver, OrderMenu, Tooltip, SelectionPanel, InputAction, InputMap, KeyBindingsPanel, ThemeManager, UIStateCache, PauseMenu, SaveLoadPanel, SaveLoadMode, MarketPanel, NotificationPanel, ObjectivesPanel, ResourcePanelMigrated, OBJECTIVES_VIEW_TYPE, TimelinePanel, EmpireOverviewView, FleetOverviewView, GraphPanel, BuildMenuView, ResourceTransferDialog, BombardmentDialog, ShipDesignerPanel, ShuttleRunWizard, InboxPanel, NewGameMenu, OptionsMenu, GameOverView, PlanetPanelMigrated, ShipPanelMigrated, ViewData, ViewEvent, ViewId, ViewType};
+use crate::ui_v2::core::picking::{self, PICK_RADIUS};
+use crate::ui_v2::core::camera::DEFAULT_ZOOM;
+use crate::ui_v2::core::icon_atlas::{ICON_ATLAS_PATH, PLANET_ICON_SIZE, SHIP_ICON_SIZE};
+use crate::ui_v2::core::selection::GROUP_KEYS;
+use crate::ui_v2::core::box_select;
+use crate::ui_v2::core::input_map::{key_from_name, key_name};
+use crate::settings::{Settings, SettingsManager};
+use crate::audio::{AudioSystem, SoundEffect};
+use crate::ui_v2::panels::pause_menu::PAUSE_MENU_VIEW_TYPE;
+use crate::ui_v2::panels::save_load_panel::SAVE_LOAD_VIEW_TYPE;
+use crate::ui_v2::panels::market_panel::MARKET_VIEW_TYPE;
+use crate::ui_v2::panels::notification_panel::NOTIFICATIONS_VIEW_TYPE;
+use crate::ui_v2::panels::timeline_panel::TIMELINE_VIEW_TYPE;
+use crate::ui_v2::panels::key_bindings_panel::KEY_BINDINGS_VIEW_TYPE;
+use crate::ui_v2::panels::empire_overview::EMPIRE_OVERVIEW_VIEW_TYPE;
+use crate::ui_v2::panels::fleet_overview::FLEET_OVERVIEW_VIEW_TYPE;
+use crate::ui_v2::panels::build_menu::BUILD_MENU_VIEW_TYPE;
+use crate::ui_v2::panels::transfer_dialog::TRANSFER_DIALOG_VIEW_TYPE;
+use crate::ui_v2::panels::bombardment_dialog::BOMBARDMENT_DIALOG_VIEW_TYPE;
+use crate::ui_v2::panels::order_menu::ORDER_MENU_VIEW_TYPE;
+use crate::ui_v2::panels::ship_designer::SHIP_DESIGNER_VIEW_TYPE;
+use crate::ui_v2::panels::shuttle_run_wizard::SHUTTLE_RUN_WIZARD_VIEW_TYPE;
+use crate::ui_v2::panels::inbox_panel::INBOX_VIEW_TYPE;
+use crate::ui_v2::panels::new_game_menu::NEW_GAME_MENU_VIEW_TYPE;
+use crate::ui_v2::panels::options_menu::OPTIONS_MENU_VIEW_TYPE;
+use crate::ui_v2::panels::game_over::GAME_OVER_VIEW_TYPE;
+use crate::ui_v2::panels::graph_panel::GRAPH_PANEL_VIEW_TYPE;
+use crate::ui_v2::panels::planet_panel_migrated::PLANET_PANEL_VIEW_TYPE;
+use crate::ui_v2::panels::ship_panel_migrated::SHIP_PANEL_VIEW_TYPE;
+use crate::ui_v2::panels::resource_panel_migrated::RESOURCE_PANEL_VIEW_TYPE;
+#[cfg(feature = "debug")]
+use crate::ui_v2::panels::DebugConsole;
+use macroquad::prelude::*;
+
+const MAP_PLANET_RADIUS: f32 = 6.0;
+/// Radius of a star on the map, in pixels
+const MAP_STAR_RADIUS: f32 = 9.0;
+/// Pixels per second the arrow keys pan the map
+const CAMERA_PAN_SPEED: f32 = 600.0;
+/// Pixels the cursor may move between pressing and releasing the right
+/// button for it to count as a click rather than a drag
+const RIGHT_CLICK_SLOP: f32 = 4.0;
+/// Ticks a rejected command's reason stays on screen
+const REJECTION_DISPLAY_TICKS: u64 = 30;
+/// Ticks the fast-forward key runs ahead: one minute of game time
+const FAST_FORWARD_KEY_TICKS: u64 = 600;
+/// Building and ship data file read at startup, if present
+pub const GAME_DATA_PATH: &str = "data/game_data.ron";
+/// Environment variable that traces every event on the bus as JSON lines,
+/// to standard output when set to `stdout` and otherwise to the file it names
+pub const TRACE_EVENTS_ENV: &str = "STELLAR_DOMINION_TRACE_EVENTS";
+
+/// The playable game: simulation state plus the UI that drives and displays it.
+///
+/// The simulation itself lives in [`GameState`] and runs without any rendering
+/// or input code. The client translates keyboard and UI interaction into
+/// commands, and rebuilds its UI whenever the simulation replaces the world.
+///
+/// The game starts at the main menu view, whose New Game dialog sets up the
+/// galaxy to generate and whose Options dialog, also behind the pause
+/// menu's Settings, sets the volume, theme and UI scale and opens the key
+/// bindings. The UI scale applies to every panel, dialog and the HUD text.
+/// These settings and the autosave interval are kept by the client's
+/// [`SettingsManager`] and saved whenever one changes. Its [`AudioSystem`]
+/// plays sound effects for the player's battles, finished buildings and
+/// ships, notifications and UI clicks at the set volumes.
+///
+/// Keys are looked up in the client's [`InputMap`] by [`InputAction`]; the
+/// defaults are named below. They are rebound in the key bindings dialog,
+/// opened with 'K' or from the Options dialog.
+///
+/// Escape opens the pause menu in game, which pauses the simulation until it
+/// closes. Leaving the game is only possible from a menu. Saving to a new
+/// slot and loading go through the save/load dialog, which lists the slots.
+/// 'M' opens the galactic market for the player's faction and 'T' the
+/// timeline of the game's notable events. 'E' opens the empire overview of
+/// the player's planets; clicking one centers the map on it and opens its
+/// planet panel. 'S' opens the fleet overview of the player's ships, which
+/// recalls ships and centers the map on the one clicked, and 'C' the graphs
+/// of every empire's statistics over the game. The player's notifications
+/// show as toasts and in the alert log behind the Alerts badge, and the
+/// objective it is working on, if any, in a box on the right.
+/// When a faction wins or the player is eliminated, the game pauses behind
+/// the game over screen, which plays on or returns to the main menu.
+/// '-' and '=' step the game speed through [`GAME_SPEEDS`], and 'G'
+/// fast-forwards [`FAST_FORWARD_KEY_TICKS`] ticks, or stops a fast-forward.
+///
+/// The galaxy map is seen through a [`Camera`]: the mouse wheel zooms on the
+/// cursor and Page Up/Down on the screen center, and the arrow keys or
+/// dragging with the right mouse button pan. Dragging with the left button
+/// selects the player's ships in the box, which a panel counts by class
+/// and can stop or recall together. Right-clicking without a drag
+/// opens a menu of the orders the selected ships can be given against what
+/// was clicked. Left-clicking a planet or ship
+/// selects it and Shift+click adds it to the selection, Tab selects the
+/// player's next ship, and 'F' follows the selection, or stops following.
+/// Ctrl+1 to 9 store the selection as a control group and the digit alone
+/// selects the group again; see [`Selection`]. 'V' zooms out to the galaxy map of every
+/// star and its jump lanes, and back in on the star nearest the view.
+///
+/// F2 switches to the next built-in theme of the client's [`ThemeManager`],
+/// which colors both the map drawn here and the UI panels.
+///
+/// Built with the `debug` feature, the backtick key opens a developer
+/// console over the map that takes commands such as `give 1 minerals 1000`
+/// or `tick 500`; see [`console`](crate::console). Other keys go to the
+/// console while it is open.
+pub struct GameClient {
+    /// Simulation state driven by this client
+    pub game: GameState,
+    /// UI system rendering the game and producing player commands
+    pub ui_system: UISystem,
+    seen_world_revision: u64,
+    /// Planet summaries and faction totals, and what changed in the last tick
+    ui_cache: UIStateCache,
+    /// Positions at the last two fixed updates, to draw motion between them
+    motion: MotionBuffer,
+    /// Batches the galaxy map's shapes into a few mesh draws each frame
+    map_batch: RenderBatcher,
+    /// Planet and ship sprites for close zoom; None draws dots at every zoom
+    icons: Option<IconAtlas>,
+    /// Pause state to restore when the pause menu or game over screen closes
+    paused_before_menu: Option<bool>,
+    /// True once the game over screen was shown for the current game
+    game_over_shown: bool,
+    /// Tick and reason of the last command the simulation rejected
+    last_rejection: Option<(u64, String)>,
+    /// Tick and description of the last error a system recovered from
+    last_system_error: Option<(u64, String)>,
+    /// View onto the galaxy map
+    pub camera: Camera,
+    /// Planet or ship last clicked on the map
+    selection: Selection,
+    /// Last control group key press, to spot double presses
+    group_recall: GroupRecall,
+    /// True while the map shows the stars and lanes instead of one system's planets
+    galaxy_map: bool,
+    /// Mouse position while dragging the map with the right button
+    drag_from: Option<Vec2>,
+    /// Where the right button went down, to tell a click from a drag
+    right_press: Option<Vec2>,
+    /// Key bound to each input action
+    pub input_map: InputMap,
+    /// Colors of the map, HUD and panels
+    pub themes: ThemeManager,
+    /// Master volume set in the Options dialog, from 0.0 to 1.0
+    pub master_volume: f32,
+    /// Preferences saved between sessions
+    settings: SettingsManager,
+    /// Sound effects for game events and UI clicks
+    audio: AudioSystem,
+    /// Open planet panel and the planet it shows
+    planet_panel: Option<(ViewId, PlanetId)>,
+    /// Open empire resource panel
+    resource_panel: Option<ViewId>,
+    /// Open multi-ship selection panel and the ships it shows
+    selection_panel: Option<(ViewId, Vec<ShipId>)>,
+    /// Open ship panel and the single selected ship it shows
+    ship_panel: Option<(ViewId, ShipId)>,
+    /// Developer console, while it is open
+    #[cfg(feature = "debug")]
+    console: Option<DebugConsole>,
+}
+
+impl GameClient {
+    /// Create a client around a fresh game state in the main menu, using
+    /// the game data at [`GAME_DATA_PATH`], the settings from
+    /// [`SettingsManager::load_default`], the sounds of
+    /// [`AudioSystem::load_default`] and the icon atlas at
+    /// [`ICON_ATLAS_PATH`] when
+    /// those files exist, and tracing events when
+    /// [`TRACE_EVENTS_ENV`] is set
+    pub fn new() -> GameResult<Self> {
+        let mut game = GameState::new()?;
+        let data_path = std::path::Path::new(GAME_DATA_PATH);
+        if data_path.exists() {
+            game.set_game_data(GameDataRegistry::load_from_file(data_path)?)?;
+        }
+        match std::env::var(TRACE_EVENTS_ENV).ok().as_deref() {
+            None | Some("") => {}
+            Some("stdout") => {
+                game.event_bus.set_tracer(Some(Box::new(JsonlTracer::stdout())));
+            }
+            Some(path) => {
+                game.event_bus.set_tracer(Some(Box::new(JsonlTracer::create(std::path::Path::new(path))?)));
+            }
+        }
+        let mut client = Self::from_state(game);
+        // A sound that will not load leaves the game silent
+        client.audio = AudioSystem::load_default().unwrap_or_else(|e| {
+            log::warn!("Audio error: {}", e);
+            AudioSystem::silent()
+        });
+        client.audio.skip_to_now(&client.game);
+        // Unreadable settings are left alone on disk and the defaults used
+        let settings = SettingsManager::load_default().unwrap_or_else(|e| {
+            log::warn!("Settings error: {}", e);
+            SettingsManager::new()
+        });
+        client.apply_settings(settings);
+        // The map falls back to dots without a usable atlas
+        client.icons = IconAtlas::load_from_file(std::path::Path::new(ICON_ATLAS_PATH)).unwrap_or_else(|e| {
+            log::warn!("Icon atlas error: {}", e);
+            None
+        });
+        Ok(client)
+    }
+
+    /// Create a client driving an existing game state
+    pub fn from_state(game: GameState) -> Self {
+        let seen_world_revision = game.world_revision();
+        let mut audio = AudioSystem::silent();
+        audio.skip_to_now(&game);
+        Self {
+            game,
+            ui_system: UISystem::new(),
+            seen_world_revision,
+            ui_cache: UIStateCache::new(),
+            motion: MotionBuffer::new(),
+            map_batch: RenderBatcher::new(),
+            icons: None,
+            paused_before_menu: None,
+            game_over_shown: false,
+            last_rejection: None,
+            last_system_error: None,
+            camera: Camera::new(vec2(screen_width(), screen_height())),
+            selection: Selection::new(),
+            group_recall: GroupRecall::default(),
+            galaxy_map: false,
+            drag_from: None,
+            right_press: None,
+            input_map: InputMap::new(),
+            themes: ThemeManager::new(),
+            master_volume: 1.0,
+            settings: SettingsManager::new(),
+            audio,
+            planet_panel: None,
+            resource_panel: None,
+            selection_panel: None,
+            ship_panel: None,
+            #[cfg(feature = "debug")]
+            console: None,
+        }
+    }
+
+    /// Take on the settings' key bindings, theme, UI scale, volumes and
+    /// autosave interval, saving later changes through the manager
+    pub fn apply_settings(&mut self, settings: SettingsManager) {
+        let current = settings.settings();
+        self.input_map = current.key_bindings.clone();
+        self.themes.select(&current.theme);
+        self.ui_system.set_theme(self.themes.current().clone());
+        self.ui_system.set_ui_scale(current.ui_scale);
+        self.master_volume = current.master_volume;
+        self.audio.set_volumes(current.master_volume, current.effects_volume);
+        let mut config = self.game.get_game_configuration().clone();
+        config.autosave.interval_ticks = current.autosave_interval_ticks;
+        self.game.set_game_configuration(config);
+        self.settings = settings;
+    }
+
+    /// Preferences in effect
+    pub fn settings(&self) -> &Settings {
+        self.settings.settings()
+    }
+
+    /// Sound effects player
+    pub fn audio(&self) -> &AudioSystem {
+        &self.audio
+    }
+
+    /// Save the client's current key bindings, theme, UI scale and volume.
+    /// They stay in effect for this session if saving fails.
+    fn save_settings(&mut self) {
+        let key_bindings = self.input_map.clone();
+        let theme = self.themes.current().name.to_string();
+        let (ui_scale, master_volume) = (self.ui_system.ui_scale(), self.master_volume);
+        let saved = self.settings.update(|settings| {
+            settings.key_bindings = key_bindings;
+            settings.theme = theme;
+            settings.ui_scale = ui_scale;
+            settings.master_volume = master_volume;
+        });
+        if let Err(e) = saved {
+            log::error!("Failed to save settings: {}", e);
+        }
+    }
+
+    /// True on the frame the action's key went down
+    fn action_pressed(&self, action: InputAction) -> bool {
+        is_key_pressed(self.input_map.key(action))
+    }
+
+    /// Name of the action's key for on-screen hints
+    fn key_label(&self, action: InputAction) -> &'static str {
+        key_name(self.input_map.key(action)).unwrap_or("?")
+    }
+
+    /// Hover tooltips for the tick and treasury lines of the HUD: the time
+    /// keys as currently bound, and where the credits come from and go
+    fn register_hud_tooltips(&mut self) {
+        let key = |action| self.key_label(action);
+        let time = Tooltip::new("Game speed")
+            .key_hint(key(InputAction::TogglePause), InputAction::TogglePause.label())
+            .key_hint(key(InputAction::StepTick), InputAction::StepTick.label())
+            .key_hint(key(InputAction::SlowDown), InputAction::SlowDown.label())
+            .key_hint(key(InputAction::SpeedUp), InputAction::SpeedUp.label())
+            .key_hint(key(InputAction::FastForward), InputAction::FastForward.label());
+        self.ui_system.register_tooltip(Rect::new(10.0, 12.0, 400.0, 24.0), time);
+        let budget = self.game.faction_manager.get_player_faction()
+            .and_then(|player| self.game.faction_budget(player.id).ok());
+        if let Some(budget) = budget {
+            let treasury = Tooltip::new("Treasury per tick")
+                .text(format!("Taxes: +{}", budget.income))
+                .text(format!("Ship upkeep: -{}", budget.ship_upkeep))
+                .text(format!("Building maintenance: -{}", budget.building_maintenance))
+                .text(format!("Net: {:+}", budget.net()));
+            self.ui_system.register_tooltip(Rect::new(10.0, 42.0, 400.0, 24.0), treasury);
+        }
+    }
+
+    /// True while the action's key is held
+    fn action_down(&self, action: InputAction) -> bool {
+        is_key_down(self.input_map.key(action))
+    }
+
+    /// Process input every frame for responsive controls
+    pub fn process_input(&mut self) -> GameResult<()> {
+        #[cfg(feature = "debug")]
+        if self.process_console_input() {
+            self.sync_world();
+            return Ok(());
+        }
+        match self.game.current_mode {
+            GameMode::MainMenu => {
+                if !self.ui_system.is_view_active(ViewType::MainMenu) {
+                    self.show_main_menu();
+                }
+                // Quitting goes through the menu's Quit entry
+                if self.action_pressed(InputAction::Menu) && self.ui_system.is_dialog_active() {
+                    self.ui_system.close_top_dialog();
+                }
+                if self.action_pressed(InputAction::NewGame) && !self.is_dialog_active() {
+                    self.open_new_game_menu();
+                }
+                if self.action_pressed(InputAction::RerollLayout) && !self.is_dialog_active() {
+                    self.game.handle_menu_event(GameEvent::PlayerCommand(PlayerCommand::RerollStartingLayout))?;
+                }
+                if self.action_pressed(InputAction::CycleDifficulty) && !self.is_dialog_active() {
+                    let next = match self.game.get_game_configuration().difficulty {
+                        Difficulty::Easy => Difficulty::Normal,
+                        Difficulty::Normal => Difficulty::Hard,
+                        Difficulty::Hard => Difficulty::Easy,
+                    };
+                    self.game.handle_menu_event(GameEvent::PlayerCommand(PlayerCommand::SetDifficulty(next)))?;
+                }
+                if self.action_pressed(InputAction::LoadGame) && !self.is_dialog_active() {
+                    self.open_save_load(SaveLoadMode::Load)?;
+                }
+                if self.action_pressed(InputAction::OpenEmpireOverview) && !self.is_dialog_active() {
+                    self.open_empire_overview()?;
+                }
+                if self.action_pressed(InputAction::OpenFleetOverview) && !self.is_dialog_active() {
+                    self.open_fleet_overview()?;
+                }
+                if self.action_pressed(InputAction::OpenKeyBindings) && !self.is_dialog_active() {
+                    self.open_key_bindings();
+                }
+                if self.action_pressed(InputAction::CycleTheme) && !self.is_dialog_active() {
+                    self.cycle_theme();
+                }
+
+                // Menu entries and dialogs
+                let menu_commands = self.ui_system.update(0.016);
+                if !menu_commands.is_empty() {
+                    self.audio.play(SoundEffect::Click);
+                }
+                for command in menu_commands {
+                    match command {
+                        PlayerCommand::NewGame => self.open_new_game_menu(),
+                        PlayerCommand::LoadGame => self.open_save_load(SaveLoadMode::Load)?,
+                        PlayerCommand::GameOptions => self.open_options(),
+                        PlayerCommand::ShowKeyBindings => self.open_key_bindings(),
+                        PlayerCommand::SetTheme(name) => self.set_theme(&name),
+                        PlayerCommand::SetVolume(volume) => self.set_volume(volume),
+                        PlayerCommand::SetUiScale(scale) => self.set_ui_scale(scale),
+                        PlayerCommand::BindKey { action, key } => self.bind_key(&action, &key),
+                        command => self.game.handle_menu_event(GameEvent::PlayerCommand(command))?,
+                    }
+                }
+                // There is no map to click on in the menu
+                self.ui_system.take_world_clicks();
+                self.ui_system.take_world_boxes();
+            }
+            GameMode::InGame => {
+                if self.action_pressed(InputAction::Menu) {
+                    if self.ui_system.is_dialog_active() {
+                        if matches!(self.ui_system.close_top_dialog(), Some(PAUSE_MENU_VIEW_TYPE | GAME_OVER_VIEW_TYPE)) {
+                            self.restore_pause_state();
+                        }
+                    } else if !self.game.is_dialog_active() {
+                        self.open_pause_menu();
+                    }
+                }
+                if self.action_pressed(InputAction::OpenMarket) && !self.is_dialog_active() {
+                    self.open_market()?;
+                }
+                if self.action_pressed(InputAction::OpenTimeline) && !self.is_dialog_active() {
+                    self.open_timeline();
+                }
+                if self.action_pressed(InputAction::OpenEmpireOverview) && !self.is_dialog_active() {
+                    self.open_empire_overview()?;
+                }
+                if self.action_pressed(InputAction::OpenFleetOverview) && !self.is_dialog_active() {
+                    self.open_fleet_overview()?;
+                }
+                if self.action_pressed(InputAction::OpenGraphs) && !self.is_dialog_active() {
+                    self.open_graphs();
+                }
+                if self.action_pressed(InputAction::OpenShuttleRun) && !self.is_dialog_active() {
+                    self.open_shuttle_run_wizard()?;
+                }
+                if self.action_pressed(InputAction::OpenInbox) && !self.is_dialog_active() {
+                    self.open_inbox()?;
+                }
+                if self.action_pressed(InputAction::OpenKeyBindings) && !self.is_dialog_active() {
+                    self.open_key_bindings();
+                }
+                if self.action_pressed(InputAction::CycleTheme) && !self.is_dialog_active() {
+                    self.cycle_theme();
+                }
+                if !self.is_dialog_active() {
+                    self.process_time_keys()?;
+                    self.process_camera_input()?;
+                }
+                self.update_camera();
+                
+                self.register_hud_tooltips();
+                // ui_v2 handles all input including save/load dialogs
+                let ui_commands = self.ui_system.update(0.016); // ~60fps delta
+                if !ui_commands.is_empty() {
+                    self.audio.play(SoundEffect::Click);
+                }
+                for command in ui_commands {
+                    self.handle_ui_command(command)?;
+                }
+                // Clicks the UI let through land on the map, resolved now the world is at hand
+                let clicks = self.ui_system.take_world_clicks();
+                for command in picking::resolve_clicks(&self.game, &self.camera, &clicks) {
+                    self.handle_ui_command(command)?;
+                }
+                for rect in self.ui_system.take_world_boxes() {
+                    self.select_in_box(rect);
+                }
+                self.refresh_selection_panel();
+                self.refresh_ship_panel();
+            }
+        }
+        self.sync_world();
+        Ok(())
+    }
+
+    /// Open or close the developer console with the backtick key and, while
+    /// it is open, type into it and run the lines submitted. True when the
+    /// console took this frame's input.
+    #[cfg(feature = "debug")]
+    fn process_console_input(&mut self) -> bool {
+        if self.game.current_mode != GameMode::InGame {
+            self.console = None;
+            return false;
+        }
+        if is_key_pressed(KeyCode::GraveAccent) {
+            self.console = match self.console {
+                Some(_) => None,
+                None => Some(DebugConsole::new()),
+            };
+            return true;
+        }
+        let Some(console) = self.console.as_mut() else {
+            return false;
+        };
+        for key in get_keys_pressed() {
+            match console.handle_key(key) {
+                Ok(Some(line)) => {
+                    let output = match crate::console::run(&mut self.game, &line) {
+                        Ok(output) => output,
+                        Err(error) => format!("Error: {}", error),
+                    };
+                    for line in output.lines() {
+                        console.push_output(line.to_string());
+                    }
+                }
+                Ok(None) => {}
+                Err(error) => log::error!("Console input error: {:?}", error),
+            }
+        }
+        true
+    }
+
+    /// Pause, resume or single-step the game, step the game speed down and
+    /// up and start or stop a fast-forward
+    fn process_time_keys(&mut self) -> GameResult<()> {
+        if self.action_pressed(InputAction::TogglePause) {
+            self.handle_ui_command(PlayerCommand::PauseGame(!self.game.is_paused()))?;
+        }
+        if self.action_pressed(InputAction::StepTick) && self.game.is_paused() {
+            self.handle_ui_command(PlayerCommand::StepTick)?;
+        }
+        let speed = self.game.time_manager.get_speed_multiplier();
+        if self.action_pressed(InputAction::SlowDown) {
+            let slower = GAME_SPEEDS.iter().rev().find(|&&preset| preset < speed).unwrap_or(&GAME_SPEEDS[0]);
+            self.handle_ui_command(PlayerCommand::SetGameSpeed(*slower))?;
+        }
+        if self.action_pressed(InputAction::SpeedUp) {
+            let faster = GAME_SPEEDS.iter().find(|&&preset| preset > speed).unwrap_or(&GAME_SPEEDS[GAME_SPEEDS.len() - 1]);
+            self.handle_ui_command(PlayerCommand::SetGameSpeed(*faster))?;
+        }
+        if self.action_pressed(InputAction::FastForward) {
+            let ticks = if self.game.time_manager.is_fast_forwarding() { 0 } else { FAST_FORWARD_KEY_TICKS };
+            self.handle_ui_command(PlayerCommand::FastForward { ticks })?;
+        }
+        Ok(())
+    }
+
+    /// Zoom with the mouse wheel or zoom keys, pan with the pan keys or a
+    /// right-button drag, pick the next ship and follow the selection
+    fn process_camera_input(&mut self) -> GameResult<()> {
+        let mouse = Vec2::from(mouse_position());
+        let wheel = mouse_wheel().1;
+        if wheel != 0.0 {
+            self.camera.zoom_at(mouse, wheel.signum());
+        }
+        let screen_center = vec2(screen_width() / 2.0, screen_height() / 2.0);
+        if self.action_pressed(InputAction::ZoomIn) {
+            self.camera.zoom_at(screen_center, 1.0);
+        }
+        if self.action_pressed(InputAction::ZoomOut) {
+            self.camera.zoom_at(screen_center, -1.0);
+        }
+
+        let mut pan = Vec2::ZERO;
+        if self.action_down(InputAction::PanLeft) { pan.x -= 1.0; }
+        if self.action_down(InputAction::PanRight) { pan.x += 1.0; }
+        if self.action_down(InputAction::PanUp) { pan.y -= 1.0; }
+        if self.action_down(InputAction::PanDown) { pan.y += 1.0; }
+        if pan != Vec2::ZERO {
+            self.camera.pan_by(pan * CAMERA_PAN_SPEED * get_frame_time());
+        }
+        if is_mouse_button_down(MouseButton::Right) {
+            if let Some(from) = self.drag_from.filter(|from| *from != mouse) {
+                self.camera.pan_by(from - mouse);
+            }
+            self.drag_from = Some(mouse);
+        } else {
+            self.drag_from = None;
+        }
+        if is_mouse_button_pressed(MouseButton::Right) {
+            self.right_press = Some(mouse);
+        }
+        if is_mouse_button_released(MouseButton::Right)
+            && self.right_press.take().is_some_and(|from| from.distance(mouse) <= RIGHT_CLICK_SLOP)
+        {
+            self.open_order_menu(mouse);
+        }
+        if self.action_pressed(InputAction::SelectNextShip) {
+            if let Some(ship) = self.next_player_ship() {
+                self.handle_ui_command(PlayerCommand::SelectShip(ship))?;
+            }
+        }
+        let ctrl = is_key_down(KeyCode::LeftControl) || is_key_down(KeyCode::RightControl);
+        for (group, key) in (1..).zip(GROUP_KEYS) {
+            if !is_key_pressed(key) {
+                continue;
+            }
+            if ctrl {
+                self.assign_control_group(group);
+            } else {
+                self.recall_control_group(group, get_time());
+            }
+        }
+        if self.action_pressed(InputAction::FollowSelection) {
+            let target = if self.camera.get_follow_target().is_some() { None } else { self.selection.primary() };
+            self.camera.follow(target);
+        }
+        if self.action_pressed(InputAction::ToggleGalaxyMap) {
+            self.toggle_galaxy_map();
+        }
+        Ok(())
+    }
+
+    /// Select a planet or ship, or with Shift held add it to or remove it
+    /// from the selection
+    fn select(&mut self, target: CameraTarget) {
+        if is_key_down(KeyCode::LeftShift) || is_key_down(KeyCode::RightShift) {
+            self.selection.toggle(target);
+        } else {
+            self.selection = Selection::single(target);
+        }
+    }
+
+    /// Select the player's ships inside a box dragged on the map, or with
+    /// Shift held add them to the selection. A box around none of them
+    /// leaves the selection alone.
+    fn select_in_box(&mut self, rect: Rect) {
+        let Some(player) = self.game.faction_manager.get_player_faction().map(|player| player.id) else {
+            return;
+        };
+        let ships = box_select::ships_in_box(&self.game, &self.camera, rect, player);
+        if ships.is_empty() {
+            return;
+        }
+        if !(is_key_down(KeyCode::LeftShift) || is_key_down(KeyCode::RightShift)) {
+            self.selection = Selection::new();
+        }
+        for ship in ships {
+            self.selection.insert(CameraTarget::Ship(ship));
+        }
+    }
+
+    /// Show the multi-selection panel while two or more of the player's
+    /// ships are selected, rebuilt when they change
+    fn refresh_selection_panel(&mut self) {
+        let player = self.game.faction_manager.get_player_faction().map(|player| player.id);
+        let game = &self.game;
+        let ships: Vec<ShipId> = self.selection.targets().iter()
+            .filter_map(|&target| match target {
+                CameraTarget::Ship(id) => Some(id),
+                CameraTarget::Planet(_) => None,
+            })
+            .filter(|&id| game.ship_manager.get_ship(id).is_ok_and(|ship| player.is_none_or(|player| ship.owner == player)))
+            .collect();
+        if ships.len() < 2 {
+            if let Some((view, _)) = self.selection_panel.take() {
+                self.ui_system.close_view(view);
+            }
+            return;
+        }
+        if self.selection_panel.as_ref().is_some_and(|(_, shown)| *shown == ships) {
+            return;
+        }
+        let panel = SelectionPanel::for_game(&self.game, &ships);
+        self.selection_panel = Some((self.ui_system.show_view(Box::new(panel), ViewType::SelectionPanel), ships));
+    }
+
+    /// Show the ship panel while a single ship is selected, rebuilt when
+    /// the selection moves to another ship
+    fn refresh_ship_panel(&mut self) {
+        let ship = match self.selection.targets() {
+            [CameraTarget::Ship(id)] => self.game.ship_manager.get_ship(*id).ok().cloned(),
+            _ => None,
+        };
+        let Some(ship) = ship else {
+            self.close_ship_panel();
+            return;
+        };
+        if self.ship_panel.is_some_and(|(_, shown)| shown == ship.id) {
+            return;
+        }
+        self.close_ship_panel();
+        let id = ship.id;
+        let mut panel = ShipPanelMigrated::new();
+        panel.set_player(self.game.faction_manager.get_player_faction().map(|player| player.id));
+        if let Err(e) = panel.show_ship(ship) {
+            log::error!("Ship panel error: {}", e);
+            return;
+        }
+        self.ship_panel = Some((self.ui_system.show_view(Box::new(panel), ViewType::ShipPanel), id));
+    }
+
+    fn close_ship_panel(&mut self) {
+        if let Some((view, _)) = self.ship_panel.take() {
+            self.ui_system.close_view(view);
+        }
+    }
+
+    /// Keep the open ship panel on its ship's latest state
+    fn update_ship_panel(&mut self) {
+        let Some((_, id)) = self.ship_panel else {
+            return;
+        };
+        if !self.ui_cache.ship_changed(id) {
+            return;
+        }
+        match self.game.ship_manager.get_ship(id) {
+            Ok(ship) => self.ui_system.send_view_event(ViewEvent::UpdateData {
+                view_type: SHIP_PANEL_VIEW_TYPE.to_string(),
+                data: ViewData::Ship(ship.clone()),
+            }),
+            Err(_) => self.close_ship_panel(),
+        }
+    }
+
+    /// Store the player's selected planets and ships as a control group
+    pub fn assign_control_group(&mut self, group: u8) {
+        let Some(player) = self.game.faction_manager.get_player_faction().map(|player| player.id) else {
+            return;
+        };
+        // Only what the player controls can be grouped
+        let game = &self.game;
+        let members = self.selection.members().into_iter()
+            .filter(|member| match *member {
+                NamedEntity::Planet(id) => game.planet_manager.get_planet(id).is_ok_and(|planet| planet.controller == Some(player)),
+                NamedEntity::Ship(id) => game.ship_manager.get_ship(id).is_ok_and(|ship| ship.owner == player),
+            })
+            .collect();
+        self.game.issue_command(player, PlayerCommand::AssignControlGroup { faction: player, group, members });
+    }
+
+    /// Select a control group's members that are still around, centering
+    /// the camera on them on a double press at `time` seconds
+    pub fn recall_control_group(&mut self, group: u8, time: f64) {
+        let Some(player) = self.game.faction_manager.get_player_faction() else {
+            return;
+        };
+        let mut selection = Selection::from_group(player.control_group(group));
+        selection.retain(|&target| picking::entity_position(&self.game, target).is_some());
+        if selection.is_empty() {
+            return;
+        }
+        if self.group_recall.press(group, time) {
+            let positions: Vec<Vector2> = selection.targets().iter()
+                .filter_map(|&target| picking::entity_position(&self.game, target))
+                .collect();
+            let count = positions.len() as f32;
+            let sum = positions.iter().fold(Vector2::default(), |sum, position| Vector2::new(sum.x + position.x, sum.y + position.y));
+            self.camera.center_on(Vector2::new(sum.x / count, sum.y / count));
+        }
+        self.selection = selection;
+    }
+
+    /// Open the context menu of orders for the selection against what is at
+    /// a screen point; nothing opens when no order applies
+    pub fn open_order_menu(&mut self, point: Vec2) {
+        let Some(player) = self.game.faction_manager.get_player_faction().map(|player| player.id) else {
+            return;
+        };
+        let target = picking::pick_entity(&self.game, &self.camera, point);
+        let options = OrderResolver::new(&self.game, player).resolve(&self.selection, target, self.camera.screen_to_world(point));
+        if options.is_empty() {
+            return;
+        }
+        self.ui_system.get_dialog_manager_mut().close_type(ORDER_MENU_VIEW_TYPE);
+        // The menu is laid out in UI units
+        let scale = self.ui_system.ui_scale();
+        self.ui_system.open_dialog(Box::new(OrderMenu::new(options, point / scale, vec2(screen_width(), screen_height()) / scale)));
+    }
+
+    /// The planets and ships selected on the map, primary first
+    pub fn selection(&self) -> &Selection {
+        &self.selection
+    }
+
+    /// Zoom out to the whole galaxy, or back in on the star nearest the view
+    fn toggle_galaxy_map(&mut self) {
+        self.galaxy_map = !self.galaxy_map;
+        if self.galaxy_map {
+            self.camera.center_on(Vector2::default());
+            self.camera.zoom_to(self.camera.min_zoom());
+        } else {
+            let star_map = self.game.physics_engine.star_map();
+            self.camera.center_on(star_map.star_position(star_map.system_at(self.camera.get_center())));
+            self.camera.zoom_to(DEFAULT_ZOOM);
+        }
+    }
+
+    /// The player's ship after the selected one by id, wrapping around
+    fn next_player_ship(&self) -> Option<ShipId> {
+        let player = self.game.faction_manager.get_player_faction()?.id;
+        let mut ships = self.game.ship_manager.get_ships_by_owner(player);
+        ships.sort_unstable();
+        let after = match self.selection.primary() {
+            Some(CameraTarget::Ship(selected)) => ships.iter().position(|&id| id > selected).unwrap_or(0),
+            _ => 0,
+        };
+        ships.get(after).copied()
+    }
+
+    /// Fit the camera to the screen and galaxy and ease it toward its target
+    fn update_camera(&mut self) {
+        let game = &self.game;
+        self.selection.retain(|&target| picking::entity_position(game, target).is_some());
+        self.camera.set_viewport(vec2(screen_width(), screen_height()));
+        self.camera.set_galaxy_extent(Camera::galaxy_extent(&self.game));
+        let followed = self.camera.get_follow_target().and_then(|target| picking::entity_position(&self.game, target));
+        self.camera.update(get_frame_time(), followed);
+    }
+
+    /// Advance the simulation by one fixed timestep, which runs as many
+    /// ticks as the game speed or a fast-forward calls for
+    pub fn fixed_update(&mut self, delta: f32) -> GameResult<()> {
+        let start_tick = self.game.get_current_tick();
+        self.game.fixed_update(delta)?;
+        self.sync_world();
+        self.audio.hear(&self.game);
+        self.motion.record(&self.game);
+        self.note_rejections(start_tick);
+        self.check_game_over();
+        self.ui_cache.sync(&mut self.game);
+        self.refresh_market()?;
+        self.refresh_notifications();
+        self.refresh_objectives();
+        self.refresh_planet_panel();
+        self.update_ship_panel();
+        self.refresh_resource_panel();
+        Ok(())
+    }
+
+    /// True when the client should shut down
+    pub fn should_exit(&self) -> bool {
+        self.game.should_exit
+    }
+
+    /// True while a modal dialog is consuming input
+    pub fn is_dialog_active(&self) -> bool {
+        self.game.is_dialog_active() || self.ui_system.is_dialog_active()
+    }
+
+    /// True while the pause menu is showing
+    pub fn is_pause_menu_open(&self) -> bool {
+        self.ui_system.get_dialog_manager().is_open(PAUSE_MENU_VIEW_TYPE)
+    }
+
+    /// Show the pause menu and pause the simulation behind it
+    pub fn open_pause_menu(&mut self) {
+        if self.is_pause_menu_open() {
+            return;
+        }
+        self.paused_before_menu = Some(self.game.time_manager.is_paused());
+        self.game.queue_event(GameEvent::PlayerCommand(PlayerCommand::PauseGame(true)));
+        self.ui_system.open_dialog(Box::new(PauseMenu::new()));
+    }
+
+    /// Pause behind the game over screen the first time the game is decided
+    /// or the player is eliminated
+    fn check_game_over(&mut self) {
+        if self.game_over_shown || self.game.current_mode != GameMode::InGame {
+            return;
+        }
+        let Some(player) = self.game.faction_manager.get_player_faction().map(|player| player.id) else {
+            return;
+        };
+        let Some(dialog) = GameOverView::for_game(&self.game, player) else {
+            return;
+        };
+        self.game_over_shown = true;
+        self.ui_system.get_dialog_manager_mut().close_type(PAUSE_MENU_VIEW_TYPE);
+        self.paused_before_menu.get_or_insert(self.game.time_manager.is_paused());
+        self.game.queue_event(GameEvent::PlayerCommand(PlayerCommand::PauseGame(true)));
+        self.ui_system.open_dialog(Box::new(dialog));
+    }
+
+    /// True while the game over screen is showing
+    pub fn is_game_over_open(&self) -> bool {
+        self.ui_system.get_dialog_manager().is_open(GAME_OVER_VIEW_TYPE)
+    }
+
+    /// Replace whatever the last game left on screen with the main menu
+    fn show_main_menu(&mut self) {
+        self.ui_system.close_all_views();
+        self.planet_panel = None;
+        self.selection_panel = None;
+        self.ship_panel = None;
+        self.ui_system.create_view(ViewType::MainMenu);
+    }
+
+    /// Show the new game dialog over the current game configuration
+    pub fn open_new_game_menu(&mut self) {
+        if !self.ui_system.get_dialog_manager().is_open(NEW_GAME_MENU_VIEW_TYPE) {
+            self.ui_system.open_dialog(Box::new(NewGameMenu::for_game(&self.game)));
+        }
+    }
+
+    /// Show the options dialog with the current theme, volume and UI scale
+    pub fn open_options(&mut self) {
+        if !self.ui_system.get_dialog_manager().is_open(OPTIONS_MENU_VIEW_TYPE) {
+            let dialog = OptionsMenu::new(self.themes.names(), self.themes.current().name, self.master_volume, self.ui_system.ui_scale());
+            self.ui_system.open_dialog(Box::new(dialog));
+        }
+    }
+
+    /// Show the save/load dialog listing the existing save slots
+    pub fn open_save_load(&mut self, mode: SaveLoadMode) -> GameResult<()> {
+        if self.ui_system.get_dialog_manager().is_open(SAVE_LOAD_VIEW_TYPE) {
+            return Ok(());
+        }
+        let dialog = SaveLoadPanel::for_game(mode, &self.game)?;
+        self.ui_system.open_dialog(Box::new(dialog));
+        Ok(())
+    }
+
+    /// Show the galactic market for the player's faction
+    pub fn open_market(&mut self) -> GameResult<()> {
+        if self.ui_system.get_dialog_manager().is_open(MARKET_VIEW_TYPE) {
+            return Ok(());
+        }
+        let Some(player) = self.game.faction_manager.get_player_faction().map(|player| player.id) else {
+            return Ok(());
+        };
+        let dialog = MarketPanel::for_game(&self.game, player)?;
+        self.ui_system.open_dialog(Box::new(dialog));
+        Ok(())
+    }
+
+    /// Show the timeline as it stands now
+    pub fn open_timeline(&mut self) {
+        if !self.ui_system.get_dialog_manager().is_open(TIMELINE_VIEW_TYPE) {
+            self.ui_system.open_dialog(Box::new(TimelinePanel::for_game(&self.game)));
+        }
+    }
+
+    /// Show graphs of every empire's statistics so far
+    pub fn open_graphs(&mut self) {
+        if !self.ui_system.get_dialog_manager().is_open(GRAPH_PANEL_VIEW_TYPE) {
+            self.ui_system.open_dialog(Box::new(GraphPanel::for_game(&self.game)));
+        }
+    }
+
+    /// Show the empire overview of the player's planets as they are now
+    pub fn open_empire_overview(&mut self) -> GameResult<()> {
+        if self.ui_system.get_dialog_manager().is_open(EMPIRE_OVERVIEW_VIEW_TYPE) {
+            return Ok(());
+        }
+        let Some(player) = self.game.faction_manager.get_player_faction().map(|player| player.id) else {
+            return Ok(());
+        };
+        let dialog = EmpireOverviewView::for_game(&self.game, player)?;
+        self.ui_system.open_dialog(Box::new(dialog));
+        Ok(())
+    }
+
+    /// Show the fleet overview of the player's ships as they are now
+    pub fn open_fleet_overview(&mut self) -> GameResult<()> {
+        if self.ui_system.get_dialog_manager().is_open(FLEET_OVERVIEW_VIEW_TYPE) {
+            return Ok(());
+        }
+        let Some(player) = self.game.faction_manager.get_player_faction().map(|player| player.id) else {
+            return Ok(());
+        };
+        let dialog = FleetOverviewView::for_game(&self.game, player)?;
+        self.ui_system.open_dialog(Box::new(dialog));
+        Ok(())
+    }
+
+    /// Show the build menu for a planet, priced against its stock as it is now
+    pub fn open_build_menu(&mut self, planet: PlanetId) -> GameResult<()> {
+        if self.ui_system.get_dialog_manager().is_open(BUILD_MENU_VIEW_TYPE) {
+            return Ok(());
+        }
+        let dialog = BuildMenuView::for_game(&self.game, planet)?;
+        self.ui_system.open_dialog(Box::new(dialog));
+        Ok(())
+    }
+
+    /// Show the resource transfer dialog for sending from a planet's stock
+    pub fn open_transfer_dialog(&mut self, planet: PlanetId) -> GameResult<()> {
+        if self.ui_system.get_dialog_manager().is_open(TRANSFER_DIALOG_VIEW_TYPE) {
+            return Ok(());
+        }
+        let dialog = ResourceTransferDialog::for_game(&self.game, planet)?;
+        self.ui_system.open_dialog(Box::new(dialog));
+        Ok(())
+    }
+
+    /// Ask the player to confirm a bombardment before the order is given
+    pub fn open_bombardment_dialog(&mut self, ship: ShipId, planet: PlanetId) -> GameResult<()> {
+        if self.ui_system.get_dialog_manager().is_open(BOMBARDMENT_DIALOG_VIEW_TYPE) {
+            return Ok(());
+        }
+        let dialog = BombardmentDialog::for_game(&self.game, ship, planet)?;
+        self.ui_system.open_dialog(Box::new(dialog));
+        Ok(())
+    }
+
+    /// Open the ship designer for the player's faction
+    pub fn open_ship_designer(&mut self) {
+        if self.ui_system.get_dialog_manager().is_open(SHIP_DESIGNER_VIEW_TYPE) {
+            return;
+        }
+        if let Some(player) = self.game.faction_manager.get_player_faction().map(|player| player.id) {
+            self.ui_system.open_dialog(Box::new(ShipDesignerPanel::new(player)));
+        }
+    }
+
+    /// Open the shuttle run wizard on the player's planets and idle transports
+    pub fn open_shuttle_run_wizard(&mut self) -> GameResult<()> {
+        if self.ui_system.get_dialog_manager().is_open(SHUTTLE_RUN_WIZARD_VIEW_TYPE) {
+            return Ok(());
+        }
+        let Some(player) = self.game.faction_manager.get_player_faction().map(|player| player.id) else {
+            return Ok(());
+        };
+        let dialog = ShuttleRunWizard::for_game(&self.game, player)?;
+        self.ui_system.open_dialog(Box::new(dialog));
+        Ok(())
+    }
+
+    /// Open the player's inbox so pending offers can be answered
+    pub fn open_inbox(&mut self) -> GameResult<()> {
+        if self.ui_system.get_dialog_manager().is_open(INBOX_VIEW_TYPE) {
+            return Ok(());
+        }
+        let Some(player) = self.game.faction_manager.get_player_faction().map(|player| player.id) else {
+            return Ok(());
+        };
+        let panel = InboxPanel::for_game(&self.game, player)?;
+        self.ui_system.open_dialog(Box::new(panel));
+        Ok(())
+    }
+
+    /// Select a ship and center the map on it; the ship panel follows the selection
+    pub fn show_ship(&mut self, id: ShipId) {
+        let target = CameraTarget::Ship(id);
+        if let Some(position) = picking::entity_position(&self.game, target) {
+            self.selection = Selection::single(target);
+            self.camera.center_on(position);
+        }
+    }
+
+    /// Select a planet, center the map on it and open its planet panel
+    pub fn show_planet(&mut self, id: PlanetId) -> GameResult<()> {
+        let planet = self.game.planet_manager.get_planet(id)?.clone();
+        let target = CameraTarget::Planet(id);
+        self.selection = Selection::single(target);
+        if let Some(position) = picking::entity_position(&self.game, target) {
+            self.camera.center_on(position);
+        }
+        let mut panel = PlanetPanelMigrated::new();
+        // Only the planet's controller is offered its shipyard
+        panel.set_ship_build_options(self.game.ship_build_options(id).unwrap_or_default());
+        if let Some(player) = self.game.faction_manager.get_player_faction() {
+            panel.set_bombardment_ships(self.game.bombardment_ships(id, player.id).unwrap_or_default());
+            panel.set_player(Some(player.id));
+        }
+        panel.set_production_report(self.own_production_report(&planet));
+        let (planet, knowledge) = self.known_planet(planet)?;
+        panel.set_knowledge(knowledge, self.game.get_current_tick());
+        panel.show_planet(planet)?;
+        self.close_planet_panel();
+        self.planet_panel = Some((self.ui_system.show_view(Box::new(panel), ViewType::PlanetPanel), id));
+        Ok(())
+    }
+
+    fn close_planet_panel(&mut self) {
+        if let Some((view, _)) = self.planet_panel.take() {
+            self.ui_system.close_view(view);
+        }
+    }
+
+    /// Keep the open planet panel on its planet's latest state. Other
+    /// factions' planets are refreshed every tick as their intel ages.
+    fn refresh_planet_panel(&mut self) {
+        let Some((_, id)) = self.planet_panel else {
+            return;
+        };
+        let player = self.game.faction_manager.get_player_faction().map(|player| player.id);
+        let own = self.ui_cache.planet_summary(id).is_some_and(|summary| player.is_none() || summary.controller == player);
+        // Technologies change the production report too
+        let stale = self.ui_cache.planet_changed(id) || player.is_some_and(|player| self.ui_cache.faction_changed(player));
+        if own && !stale {
+            return;
+        }
+        let Ok(planet) = self.game.planet_manager.get_planet(id).cloned() else {
+            self.close_planet_panel();
+            return;
+        };
+        if let Some(report) = self.own_production_report(&planet) {
+            self.ui_system.send_view_event(ViewEvent::UpdateData {
+                view_type: PLANET_PANEL_VIEW_TYPE.to_string(),
+                data: ViewData::ProductionReport(report),
+            });
+        }
+        match self.known_planet(planet) {
+            Ok((planet, knowledge)) => self.ui_system.send_view_event(ViewEvent::UpdateData {
+                view_type: PLANET_PANEL_VIEW_TYPE.to_string(),
+                data: ViewData::KnownPlanet { planet, knowledge, tick: self.game.get_current_tick() },
+            }),
+            Err(_) => self.close_planet_panel(),
+        }
+    }
+
+    /// Open the empire resource panel, or close it if it is open
+    fn toggle_resource_panel(&mut self) {
+        if let Some(view) = self.resource_panel.take() {
+            self.ui_system.close_view(view);
+            return;
+        }
+        let mut panel = ResourcePanelMigrated::new();
+        if let Err(e) = panel.update_resources(&mut self.game) {
+            log::error!("Resource panel error: {}", e);
+        }
+        self.resource_panel = Some(self.ui_system.show_view(Box::new(panel), ViewType::ResourcePanel));
+    }
+
+    /// Keep the open resource panel on the player's latest economy
+    fn refresh_resource_panel(&mut self) {
+        if self.resource_panel.is_none() {
+            return;
+        }
+        let Some(player) = self.game.faction_manager.get_player_faction().map(|player| player.id) else {
+            return;
+        };
+        if !self.ui_cache.faction_changed(player) {
+            return;
+        }
+        if let Ok(economy) = self.game.empire_economy(player) {
+            let data = ViewData::EmpireEconomy(economy.clone());
+            self.ui_system.send_view_event(ViewEvent::UpdateData {
+                view_type: RESOURCE_PANEL_VIEW_TYPE.to_string(),
+                data,
+            });
+        }
+    }
+
+    /// Rates by source for a planet the player holds; other factions keep
+    /// theirs to themselves
+    fn own_production_report(&self, planet: &Planet) -> Option<ProductionReport> {
+        let player = self.game.faction_manager.get_player_faction().map(|player| player.id);
+        if player.is_some() && planet.controller != player {
+            return None;
+        }
+        self.game.production_report(planet.id).ok()
+    }
+
+    /// The planet as the player knows it, through intel for other factions'
+    /// planets; all of it without a player faction
+    fn known_planet(&self, planet: Planet) -> GameResult<(Planet, PlanetKnowledge)> {
+        match self.game.faction_manager.get_player_faction() {
+            Some(player) => self.game.known_planet(player.id, planet.id),
+            None => Ok((planet, PlanetKnowledge::Live)),
+        }
+    }
+
+    /// Switch the map and UI to the next theme
+    pub fn cycle_theme(&mut self) {
+        let theme = self.themes.cycle().clone();
+        self.ui_system.set_theme(theme);
+        self.save_settings();
+    }
+
+    /// Switch the map and UI to a named theme; unknown names are ignored
+    pub fn set_theme(&mut self, name: &str) {
+        if self.themes.select(name) {
+            self.ui_system.set_theme(self.themes.current().clone());
+            self.save_settings();
+        }
+    }
+
+    /// Set the master volume, clamped to 0.0 to 1.0
+    pub fn set_volume(&mut self, volume: f32) {
+        self.master_volume = volume.clamp(0.0, 1.0);
+        self.audio.set_volumes(self.master_volume, self.audio.effects_volume());
+        self.save_settings();
+    }
+
+    /// Set the UI scale, clamped to the offered range, and save it
+    pub fn set_ui_scale(&mut self, scale: f32) {
+        self.ui_system.set_ui_scale(scale);
+        self.save_settings();
+    }
+
+    /// Show the key bindings dialog over a copy of the current bindings
+    pub fn open_key_bindings(&mut self) {
+        if !self.ui_system.get_dialog_manager().is_open(KEY_BINDINGS_VIEW_TYPE) {
+            self.ui_system.open_dialog(Box::new(KeyBindingsPanel::new(self.input_map.clone())));
+        }
+    }
+
+    /// Apply a rebind from the key bindings dialog and save the bindings.
+    /// The bindings stay in effect for this session if saving fails.
+    fn bind_key(&mut self, action: &str, key: &str) {
+        let (Some(action), Some(key)) = (InputAction::from_name(action), key_from_name(key)) else {
+            return;
+        };
+        if let Err(e) = self.input_map.bind(action, key) {
+            log::warn!("Key binding error: {}", e);
+            return;
+        }
+        self.save_settings();
+    }
+
+    /// Reopen an open market dialog so it shows the latest prices and credits
+    fn refresh_market(&mut self) -> GameResult<()> {
+        if self.ui_system.get_dialog_manager().top_view_type() == Some(MARKET_VIEW_TYPE) {
+            self.ui_system.get_dialog_manager_mut().close_type(MARKET_VIEW_TYPE);
+            self.open_market()?;
+        }
+        Ok(())
+    }
+
+    /// Show the player's latest notifications, adding the panel the first time
+    fn refresh_notifications(&mut self) {
+        if self.game.current_mode != GameMode::InGame {
+            return;
+        }
+        let Some(player) = self.game.faction_manager.get_player_faction().map(|player| player.id) else {
+            return;
+        };
+        if !self.ui_system.is_view_active(ViewType::Notifications) {
+            self.ui_system.show_view(Box::new(NotificationPanel::new(player)), ViewType::Notifications);
+        }
+        self.ui_system.send_view_event(ViewEvent::UpdateData {
+            view_type: NOTIFICATIONS_VIEW_TYPE.to_string(),
+            data: NotificationPanel::view_data(&self.game, player),
+        });
+    }
+
+    /// Show the player's current objective, adding the panel the first time
+    fn refresh_objectives(&mut self) {
+        if self.game.current_mode != GameMode::InGame {
+            return;
+        }
+        let Some(player) = self.game.faction_manager.get_player_faction().map(|player| player.id) else {
+            return;
+        };
+        if !self.ui_system.is_view_active(ViewType::Objectives) {
+            self.ui_system.show_view(Box::new(ObjectivesPanel::new(player)), ViewType::Objectives);
+        }
+        self.ui_system.send_view_event(ViewEvent::UpdateData {
+            view_type: OBJECTIVES_VIEW_TYPE.to_string(),
+            data: ObjectivesPanel::view_data(&self.game, player),
+        });
+    }
+
+    /// Hide the pause menu and return the simulation to its earlier pause state
+    pub fn close_pause_menu(&mut self) {
+        self.ui_system.get_dialog_manager_mut().close_type(PAUSE_MENU_VIEW_TYPE);
+        self.restore_pause_state();
+    }
+
+    fn restore_pause_state(&mut self) {
+        if let Some(paused) = self.paused_before_menu.take() {
+            self.game.queue_event(GameEvent::PlayerCommand(PlayerCommand::PauseGame(paused)));
+        }
+    }
+
+    /// Forward a command from the in-game UI, acting on pause menu choices
+    fn handle_ui_command(&mut self, command: PlayerCommand) -> GameResult<()> {
+        match command {
+            // Resuming from the pause menu or game over screen keeps a pause
+            // the player set beforehand
+            PlayerCommand::PauseGame(false) if self.paused_before_menu.is_some() => {
+                self.close_pause_menu();
+            }
+            PlayerCommand::BackToMenu => {
+                self.close_pause_menu();
+                self.game.queue_event(GameEvent::PlayerCommand(command));
+            }
+            // Choosing a slot happens in the save/load dialog
+            PlayerCommand::LoadGame => self.open_save_load(SaveLoadMode::Load)?,
+            PlayerCommand::SaveGameDialog => self.open_save_load(SaveLoadMode::Save)?,
+            PlayerCommand::SaveGame if self.game.current_save_name.is_none() => {
+                self.open_save_load(SaveLoadMode::Save)?;
+            }
+            PlayerCommand::GameOptions => self.open_options(),
+            PlayerCommand::ShowKeyBindings => self.open_key_bindings(),
+            PlayerCommand::SetTheme(name) => self.set_theme(&name),
+            PlayerCommand::SetVolume(volume) => self.set_volume(volume),
+            PlayerCommand::SetUiScale(scale) => self.set_ui_scale(scale),
+            PlayerCommand::BindKey { action, key } => self.bind_key(&action, &key),
+            // Selecting only changes what the client shows
+            PlayerCommand::SelectPlanet(id) => self.select(CameraTarget::Planet(id)),
+            PlayerCommand::SelectShip(id) => self.select(CameraTarget::Ship(id)),
+            PlayerCommand::ShowPlanet(id) => self.show_planet(id)?,
+            PlayerCommand::ClosePlanetPanel => self.close_planet_panel(),
+            PlayerCommand::ShowResourcePanel => self.toggle_resource_panel(),
+            PlayerCommand::ShowBuildMenu(id) => self.open_build_menu(id)?,
+            PlayerCommand::ShowTransferDialog(id) => self.open_transfer_dialog(id)?,
+            PlayerCommand::ConfirmBombardment { ship, planet } => self.open_bombardment_dialog(ship, planet)?,
+            PlayerCommand::ShowShipDesigner => self.open_ship_designer(),
+            PlayerCommand::ShowShip(id) => self.show_ship(id),
+            // Loading and quitting act outside the simulation event flow
+            PlayerCommand::LoadGameFromSlot(_) | PlayerCommand::ExitGame => {
+                self.game.handle_menu_event(GameEvent::PlayerCommand(command))?;
+            }
+            // Orders are checked against what the player controls
+            _ => match self.game.faction_manager.get_player_faction().map(|player| player.id) {
+                Some(player) => self.game.issue_command(player, command),
+                None => self.game.queue_event(GameEvent::PlayerCommand(command)),
+            },
+        }
+        Ok(())
+    }
+
+    /// Render the current frame
+    pub fn render(&mut self, interpolation: f32) -> GameResult<()> {
+        // Clear screen
+        let theme = self.themes.current();
+        clear_background(theme.map_background);
+
+        match self.game.current_mode {
+            GameMode::MainMenu => {
+                // Keyboard shortcuts, below the main menu view
+                let config = self.game.get_game_configuration();
+                let height = screen_height() / self.ui_system.ui_scale();
+                self.ui_system.begin_ui_camera();
+                draw_text(
+                    &format!("Difficulty: {:?} ('{}' to change)   Layout seed: {} ('{}' to re-roll)",
+                             config.difficulty, self.key_label(InputAction::CycleDifficulty),
+                             config.seed, self.key_label(InputAction::RerollLayout)),
+                    200.0, height - 60.0, 20.0, theme.secondary_text_color
+                );
+                draw_text(&format!("Theme: {} ('{}' to switch)", theme.name, self.key_label(InputAction::CycleTheme)), 200.0, height - 30.0, 20.0, theme.secondary_text_color);
+                self.ui_system.end_ui_camera();
+
+                self.ui_system.render();
+                Ok(())
+            }
+            GameMode::InGame => {
+                let mut batch = std::mem::take(&mut self.map_batch);
+                self.render_galaxy_map(&mut batch, interpolation);
+                self.map_batch = batch;
+                if let Some(rect) = self.ui_system.map_drag_box() {
+                    let color = theme.selection_color;
+                    draw_rectangle(rect.x, rect.y, rect.w, rect.h, Color { a: 0.15, ..color });
+                    draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 1.0, color);
+                }
+
+                // Basic game info, drawn first so panels and dialogs cover it
+                self.ui_system.begin_ui_camera();
+                let time_manager = &self.game.time_manager;
+                let pace = match time_manager.get_fast_forward_target() {
+                    _ if time_manager.is_paused() => format!("Paused ('{}' steps one tick)", self.key_label(InputAction::StepTick)),
+                    Some(target) => format!("Fast-forwarding to {}", target),
+                    None => format!("Speed: {}x", time_manager.get_speed_multiplier()),
+                };
+                draw_text(&format!("Tick: {}   {}", self.game.get_current_tick(), pace), 10.0, 30.0, 20.0, theme.text_color);
+                let treasury = self.game.faction_manager.get_player_faction()
+                    .and_then(|player| Some((player.credits, self.game.faction_budget(player.id).ok()?)))
+                    .map(|(credits, budget)| format!("   Credits: {} ({:+}/tick)", credits, budget.net()))
+                    .unwrap_or_default();
+                draw_text(&format!("Planets: {}{}", self.game.planet_manager.get_planet_count(), treasury), 10.0, 60.0, 20.0, theme.text_color);
+                let key = |action| self.key_label(action);
+                draw_text(
+                    &format!("Press '{}' for the pause menu, '{}' for the market, '{}' for the timeline, '{}'/'{}' for the empire/fleet overview, '{}' for graphs, '{}' for a shuttle run, '{}' to pause, '{}' to step a paused game, '{}'/'{}' for speed, '{}' to fast-forward, '{}' to follow the selection, '{}' for the galaxy map",
+                             key(InputAction::Menu), key(InputAction::OpenMarket), key(InputAction::OpenTimeline),
+                             key(InputAction::OpenEmpireOverview), key(InputAction::OpenFleetOverview), key(InputAction::OpenGraphs), key(InputAction::OpenShuttleRun), key(InputAction::TogglePause), key(InputAction::StepTick), key(InputAction::SlowDown), key(InputAction::SpeedUp), key(InputAction::FastForward),
+                             key(InputAction::FollowSelection), key(InputAction::ToggleGalaxyMap)),
+                    10.0, 90.0, 20.0, theme.text_color
+                );
+                if self.game.save_system.is_saving() {
+                    draw_text("Saving...", 10.0, 120.0, 20.0, theme.warning_color);
+                }
+                if let Some(player) = self.game.faction_manager.get_player_faction() {
+                    if !player.inbox.is_empty() {
+                        draw_text(
+                            &format!("Inbox: {} pending - press '{}' to answer", player.inbox.pending_count(), self.key_label(InputAction::OpenInbox)),
+                            10.0, 150.0, 20.0, theme.accent_color
+                        );
+                    }
+                }
+
+                if let Some((tick, reason)) = &self.last_rejection {
+                    if self.game.get_current_tick() <= tick + REJECTION_DISPLAY_TICKS {
+                        draw_text(&format!("Order refused: {}", reason), 10.0, 180.0, 20.0, theme.error_color);
+                    }
+                }
+                if let Some((tick, error)) = &self.last_system_error {
+                    if self.game.get_current_tick() <= tick + REJECTION_DISPLAY_TICKS {
+                        draw_text(error, 10.0, 210.0, 20.0, theme.error_color);
+                    }
+                }
+                self.ui_system.end_ui_camera();
+
+                // Use ui_system for rendering
+                self.ui_system.render();
+
+                // Render save/load dialog on top if active
+                self.game.save_load_dialog.render()?;
+
+                #[cfg(feature = "debug")]
+                if let Some(console) = self.console.as_mut() {
+                    let mut context = crate::ui_v2::RenderContext::new();
+                    context.theme = self.themes.current().clone();
+                    if let Err(e) = console.render(&context) {
+                        log::error!("Console render error: {:?}", e);
+                    }
+                }
+
+                Ok(())
+            }
+        }
+    }
+
+    /// Draw the stars and jump lanes, then planets and ships as the player's
+    /// faction sees them: planets out of sensor range are gray, outlined
+    /// only if never explored, and ships out of sensor range are not drawn.
+    /// Without a player faction everything is shown. Missiles heading for a
+    /// ship in sight are drawn with their trail, and recent impacts and
+    /// interceptions as fading rings. The galaxy map names the stars and
+    /// leaves the planets out. The selection is ringed. Planets and ships
+    /// are drawn `interpolation` of the way from their previous fixed update
+    /// to their last. Shapes go through the batcher as a few meshes; up
+    /// close, planets and ships are sprites when an icon atlas is loaded.
+    fn render_galaxy_map(&self, batch: &mut RenderBatcher, interpolation: f32) {
+        let to_screen = |position: Vector2| self.camera.world_to_screen(position);
+        let tick = self.game.get_current_tick();
+        let player = self.game.faction_manager.get_player_faction();
+        let unseen = FactionVisibility::default();
+        let visibility = player.map(|player| self.game.faction_visibility(player.id).unwrap_or(&unseen));
+        let theme = self.themes.current();
+        let owner_color = |owner: Option<FactionId>| match owner {
+            Some(owner) if player.is_some_and(|player| player.id == owner) => theme.player_color,
+            Some(_) => theme.rival_color,
+            None => theme.neutral_color,
+        };
+
+        // Up close, planets and ships are tinted sprites drawn over the batch
+        let icons = self.icons.as_ref().filter(|_| IconDetail::at_zoom(self.camera.get_zoom()) == IconDetail::Sprite);
+        let mut sprites = Vec::new();
+        let mut sprite = |batch: &mut RenderBatcher, icon, point: Vec2, size, radius, color| match icons {
+            Some(_) => sprites.push((icon, point, size, color)),
+            None => batch.circle(point.x, point.y, radius, color),
+        };
+
+        let star_map = self.game.physics_engine.star_map();
+        for lane in &star_map.lanes {
+            let from = to_screen(star_map.star_position(lane.from));
+            let to = to_screen(star_map.star_position(lane.to));
+            batch.line(from.x, from.y, to.x, to.y, 1.0, theme.secondary_text_color);
+        }
+        for system in &star_map.systems {
+            let point = to_screen(system.position);
+            batch.circle(point.x, point.y, MAP_STAR_RADIUS, theme.warning_color);
+        }
+
+        for planet in self.game.planet_manager.get_all_planets().iter().filter(|_| !self.galaxy_map) {
+            let position = self.motion.planet_position(planet.id, interpolation)
+                .unwrap_or_else(|| self.game.physics_engine.planet_position(planet, tick));
+            let point = to_screen(position);
+            if visibility.is_none_or(|visibility| visibility.can_see_planet(planet.id)) {
+                sprite(batch, Icon::Planet, point, PLANET_ICON_SIZE, MAP_PLANET_RADIUS, owner_color(planet.controller));
+            } else if player.is_some_and(|player| player.has_explored(planet.id)) {
+                sprite(batch, Icon::Planet, point, PLANET_ICON_SIZE, MAP_PLANET_RADIUS, theme.unseen_color);
+            } else {
+                batch.circle_lines(point.x, point.y, MAP_PLANET_RADIUS, 1.0, theme.unseen_color);
+            }
+        }
+
+        let ships = picking::visible_ships(&self.game);
+        for ship in &ships {
+            let point = to_screen(self.motion.ship_position(ship.id, interpolation).unwrap_or(ship.position));
+            let color = owner_color(Some(ship.owner));
+            match icons {
+                Some(_) => sprites.push((Icon::Ship(ship.ship_class), point, SHIP_ICON_SIZE, color)),
+                None => batch.rect(point.x - 2.0, point.y - 2.0, 4.0, 4.0, color),
+            }
+        }
+
+        // Missiles in flight toward a ship in sight, each with its last tick's trail
+        for missile in self.game.combat_resolver.missiles().iter().filter(|missile| ships.iter().any(|ship| ship.id == missile.target)) {
+            let (from, to) = (to_screen(missile.previous_position), to_screen(missile.position));
+            let color = owner_color(Some(missile.faction));
+            batch.line(from.x, from.y, to.x, to.y, 1.0, color);
+            batch.circle(to.x, to.y, 1.5, color);
+        }
+        // Blasts swell and fade over their last few ticks
+        for blast in self.game.combat_resolver.recent_blasts() {
+            let age = (tick.saturating_sub(blast.tick) + 1) as f32 / MISSILE_EFFECT_TICKS as f32;
+            let point = to_screen(blast.position);
+            let color = if blast.intercepted { theme.secondary_text_color } else { theme.warning_color };
+            let radius = (MISSILE_BLAST_RADIUS * self.camera.get_zoom() * age).max(2.0);
+            batch.circle_lines(point.x, point.y, radius, 1.5, Color { a: 1.0 - age * 0.8, ..color });
+        }
+
+        for &target in self.selection.targets() {
+            let selected = picking::entity_position(&self.game, target)
+                .map(|position| self.motion.position(target, interpolation).unwrap_or(position));
+            if let Some(point) = selected.map(to_screen) {
+                batch.circle_lines(point.x, point.y, PICK_RADIUS, 1.5, theme.selection_color);
+            }
+        }
+        batch.flush();
+        if let Some(icons) = icons {
+            for (icon, point, size, color) in sprites {
+                icons.draw(icon, point.x, point.y, size, color);
+            }
+        }
+
+        // Names go over the batched shapes
+        for system in star_map.systems.iter().filter(|_| self.galaxy_map) {
+            let point = to_screen(system.position);
+            draw_text(&system.name, point.x + MAP_STAR_RADIUS + 4.0, point.y + 5.0, 18.0, theme.text_color);
+        }
+    }
+
+    /// Remember the latest command the simulation turned away and the latest
+    /// error a system recovered from, to show the player
+    fn note_rejections(&mut self, since_tick: u64) {
+        let rejection = self.game.event_bus.events_since(since_tick).rev()
+            .find_map(|entry| match &entry.event {
+                GameEvent::StateChanged(StateChange::CommandRejected { reason, .. }) => Some((entry.tick, reason.clone())),
+                _ => None,
+            });
+        if rejection.is_some() {
+            self.last_rejection = rejection;
+        }
+        let system_error = self.game.event_bus.events_since(since_tick).rev()
+            .find_map(|entry| match &entry.event {
+                GameEvent::StateChanged(StateChange::SystemErrorOccurred { system, error }) => {
+                    Some((entry.tick, format!("{:?} error: {}", system, error)))
+                }
+                _ => None,
+            });
+        if system_error.is_some() {
+            self.last_system_error = system_error;
+        }
+    }
+
+    /// Reset UI state when a new or loaded game replaced the world
+    fn sync_world(&mut self) {
+        if self.game.world_revision() != self.seen_world_revision {
+            self.seen_world_revision = self.game.world_revision();
+            // Cached selections would reference entities that no longer exist
+            let ui_scale = self.ui_system.ui_scale();
+            self.ui_system = UISystem::new();
+            self.ui_system.set_theme(self.themes.current().clone());
+            self.ui_system.set_ui_scale(ui_scale);
+            // The replaced world brings its own pause state
+            self.paused_before_menu = None;
+            self.game_over_shown = false;
+            self.last_rejection = None;
+            self.last_system_error = None;
+            self.audio.skip_to_now(&self.game);
+            self.camera = Camera::new(vec2(screen_width(), screen_height()));
+            self.selection = Selection::new();
+            self.galaxy_map = false;
+            self.drag_from = None;
+            self.planet_panel = None;
+            self.selection_panel = None;
+        self.ship_panel = None;
+            self.motion.clear();
+        }
+    }
+}
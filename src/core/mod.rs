@@ -1,769 +0,0 @@
-// src/core/mod.rs
-pub mod events;
-pub mod types;
-
-// Re-export commonly used types
-pub use events::{EventBus, GameEvent, SystemId, PlayerCommand, SimulationEvent, StateChange};
-pub use types::*;
-
-// Import managers and systems
-use crate::managers::{PlanetManager, ShipManager, FactionManager};
-use crate::systems::{TimeManager, ResourceSystem, PopulationSystem, ConstructionSystem, PhysicsEngine, CombatResolver, SaveSystem, GameInitializer};
-use crate::ui_v2::UISystem;
-use macroquad::prelude::*;
-
-// Temporary stub for SaveLoadDialog until ui_v2 fully replaces it
-#[derive(Default)]
-pub struct SaveLoadDialogStub {
-    active: bool,
-}
-
-impl SaveLoadDialogStub {
-    pub fn new() -> Self { Self::default() }
-    pub fn is_active(&self) -> bool { false } // Always inactive for now
-    pub fn handle_input(&mut self) -> GameResult<Vec<GameEvent>> { Ok(Vec::new()) }
-    pub fn render(&mut self) -> GameResult<()> { Ok(()) }
-    pub fn show_save_dialog(&mut self) { /* stub */ }
-    pub fn show_new_game_dialog(&mut self) { /* stub */ }
-    pub fn show_load_dialog(&mut self, _saves: Vec<crate::systems::save_system::SaveInfo>) { /* stub */ }
-    pub fn close(&mut self) { /* stub */ }
-}
-
-pub struct GameState {
-    pub event_bus: EventBus,
-    pub planet_manager: PlanetManager,
-    pub ship_manager: ShipManager,
-    pub faction_manager: FactionManager,
-    pub time_manager: TimeManager,
-    pub resource_system: ResourceSystem,
-    pub population_system: PopulationSystem,
-    pub construction_system: ConstructionSystem,
-    pub physics_engine: PhysicsEngine,
-    pub combat_resolver: CombatResolver,
-    pub save_system: SaveSystem,
-    pub ui_system: UISystem,
-    pub save_load_dialog: SaveLoadDialogStub,
-    pub current_mode: GameMode,
-    pub should_exit: bool,
-    pub game_initializer: GameInitializer,
-    pub current_save_name: Option<String>,
-    menu_events: Vec<GameEvent>,
-}
-
-impl GameState {
-    pub fn new() -> GameResult<Self> {
-        let mut event_bus = EventBus::new();
-        
-        // Register system subscriptions
-        event_bus.subscribe(SystemId::PlanetManager, events::EventType::PlayerCommand);
-        event_bus.subscribe(SystemId::PlanetManager, events::EventType::SimulationEvent);
-        event_bus.subscribe(SystemId::PlanetManager, events::EventType::StateChanged);
-        event_bus.subscribe(SystemId::ShipManager, events::EventType::PlayerCommand);
-        event_bus.subscribe(SystemId::PhysicsEngine, events::EventType::SimulationEvent);
-        event_bus.subscribe(SystemId::PhysicsEngine, events::EventType::PlayerCommand);
-        event_bus.subscribe(SystemId::TimeManager, events::EventType::PlayerCommand);
-        event_bus.subscribe(SystemId::ResourceSystem, events::EventType::SimulationEvent);
-        event_bus.subscribe(SystemId::ResourceSystem, events::EventType::PlayerCommand);
-        event_bus.subscribe(SystemId::PopulationSystem, events::EventType::SimulationEvent);
-        event_bus.subscribe(SystemId::PopulationSystem, events::EventType::PlayerCommand);
-        event_bus.subscribe(SystemId::CombatResolver, events::EventType::PlayerCommand);
-        event_bus.subscribe(SystemId::CombatResolver, events::EventType::SimulationEvent);
-        event_bus.subscribe(SystemId::SaveSystem, events::EventType::PlayerCommand);
-        event_bus.subscribe(SystemId::UIRenderer, events::EventType::StateChanged);
-        event_bus.subscribe(SystemId::UIRenderer, events::EventType::PlayerCommand);
-        
-        let mut state = Self {
-            event_bus,
-            planet_manager: PlanetManager::new(),
-            ship_manager: ShipManager::new(),
-            faction_manager: FactionManager::new(),
-            time_manager: TimeManager::new(),
-            resource_system: ResourceSystem::new(),
-            population_system: PopulationSystem::new(),
-            construction_system: ConstructionSystem::new(),
-            physics_engine: PhysicsEngine::new(),
-            combat_resolver: CombatResolver::new(),
-            save_system: SaveSystem::new(),
-            ui_system: UISystem::new(),
-            save_load_dialog: SaveLoadDialogStub::new(),
-            current_mode: GameMode::MainMenu,
-            should_exit: false,
-            game_initializer: GameInitializer::new(GameConfiguration::default()),
-            current_save_name: None,
-            menu_events: Vec::new(),
-        };
-        
-        // Initialize basic UI for now
-        state.initialize_basic_ui()?;
-        
-        // Don't initialize demo content when starting in main menu mode
-        // Demo content will be initialized when starting a new game
-        
-        Ok(state)
-    }
-    
-    /// Process input every frame for responsive controls
-    pub fn process_input(&mut self) -> GameResult<()> {
-        match self.current_mode {
-            GameMode::MainMenu => {
-                // Basic keyboard input for temporary menu
-                if is_key_pressed(KeyCode::N) {
-                    // Start new game
-                    self.current_mode = GameMode::InGame;
-                    self.initialize_demo_content()?;
-                }
-                if is_key_pressed(KeyCode::L) {
-                    // Try to load game
-                    if let Err(_) = self.load_game() {
-                        // If load fails, stay in menu
-                        println!("No save file found or load failed");
-                    }
-                }
-                
-                // Also let ui_system handle any additional interactions
-                let menu_commands = self.ui_system.update(0.016);
-                for command in menu_commands {
-                    self.handle_menu_event(GameEvent::PlayerCommand(command))?;
-                }
-            }
-            GameMode::InGame => {
-                // ui_v2 handles all input including save/load dialogs
-                let ui_commands = self.ui_system.update(0.016); // ~60fps delta
-                for command in ui_commands {
-                    self.event_bus.queue_event(GameEvent::PlayerCommand(command));
-                }
-            }
-        }
-        Ok(())
-    }
-
-    pub fn fixed_update(&mut self, delta: f32) -> GameResult<()> {
-        match self.current_mode {
-            GameMode::MainMenu => {
-                // Input is now processed separately every frame
-                // No input processing in fixed update for menu
-            }
-            GameMode::InGame => {
-                // Input is now processed separately every frame
-                
-                // Update systems in strict order per architecture
-                self.physics_engine.update(delta, &mut self.event_bus)?;
-                self.resource_system.update(delta, &mut self.event_bus)?;
-                self.population_system.update(delta, &mut self.event_bus)?;
-                self.construction_system.update(delta, &mut self.event_bus)?;
-                self.combat_resolver.update(delta, &mut self.event_bus)?;
-                self.time_manager.update(delta, &mut self.event_bus)?;
-                
-                // Process all queued events after system updates
-                self.process_queued_events()?;
-            }
-        }
-        
-        Ok(())
-    }
-    
-    fn process_queued_events(&mut self) -> GameResult<()> {
-        // Process events while maintaining architectural boundaries
-        let events_to_process: Vec<GameEvent> = self.event_bus.queued_events.drain(..).collect();
-        
-        for event in events_to_process {
-            self.route_event_to_systems(event)?;
-        }
-        
-        Ok(())
-    }
-    
-    fn route_event_to_systems(&mut self, event: GameEvent) -> GameResult<()> {
-        let event_type = match &event {
-            GameEvent::PlayerCommand(_) => events::EventType::PlayerCommand,
-            GameEvent::SimulationEvent(_) => events::EventType::SimulationEvent,
-            GameEvent::StateChanged(_) => events::EventType::StateChanged,
-        };
-        
-        // First collect all systems that need to handle this event
-        let mut systems_to_notify = Vec::new();
-        
-        // Add systems in update order
-        for &system_id in &self.event_bus.update_order {
-            if let Some(subscriptions) = self.event_bus.subscribers.get(&system_id) {
-                if subscriptions.contains(&event_type) {
-                    systems_to_notify.push(system_id);
-                }
-            }
-        }
-        
-        // Add managers (no specific order)
-        for (&system_id, subscriptions) in &self.event_bus.subscribers {
-            if !self.event_bus.update_order.contains(&system_id) && subscriptions.contains(&event_type) {
-                systems_to_notify.push(system_id);
-            }
-        }
-        
-        // Now notify all systems
-        for system_id in systems_to_notify {
-            self.handle_system_event(system_id, &event)?;
-        }
-        
-        Ok(())
-    }
-    
-    /// Process per-tick simulation updates (resource production, population growth)
-    fn process_tick_events(&mut self, tick: u64) -> GameResult<()> {
-        // Get planet list for processing (clone to avoid borrow conflicts)
-        let planet_ids: Vec<PlanetId> = self.planet_manager.get_all_planet_ids();
-        
-        // Process each planet's resource production and population growth
-        for planet_id in planet_ids {
-            // Check if planet is owned before processing
-            let has_controller = {
-                let planet = self.planet_manager.get_planet(planet_id)?;
-                planet.controller.is_some()
-            };
-            
-            if has_controller {
-                // Calculate net resource production/consumption
-                let net_production = {
-                    let planet = self.planet_manager.get_planet(planet_id)?;
-                    self.resource_system.calculate_planet_production(planet)?
-                };
-                
-                // Split into positive production and negative consumption
-                let mut actual_production = ResourceBundle::default();
-                let mut consumption = ResourceBundle::default();
-                
-                // Separate production from consumption
-                actual_production.minerals = net_production.minerals.max(0);
-                actual_production.food = net_production.food.max(0);
-                actual_production.energy = net_production.energy.max(0);
-                actual_production.alloys = net_production.alloys.max(0);
-                actual_production.components = net_production.components.max(0);
-                actual_production.fuel = net_production.fuel.max(0);
-                
-                consumption.minerals = (-net_production.minerals).max(0);
-                consumption.food = (-net_production.food).max(0);
-                consumption.energy = (-net_production.energy).max(0);
-                consumption.alloys = (-net_production.alloys).max(0);
-                consumption.components = (-net_production.components).max(0);
-                consumption.fuel = (-net_production.fuel).max(0);
-                
-                // Check if we can afford consumption before applying changes
-                let can_afford_consumption = {
-                    let planet = self.planet_manager.get_planet(planet_id)?;
-                    let mut test_resources = planet.resources.current.clone();
-                    test_resources.add(&actual_production).is_ok() && 
-                    test_resources.can_afford(&consumption)
-                };
-                
-                // Calculate how much production can actually be stored before modifying
-                let available_space = {
-                    let planet = self.planet_manager.get_planet(planet_id)?;
-                    planet.resources.available_space()
-                };
-                
-                let mut capped_production = ResourceBundle::default();
-                // Cap production to available storage space
-                capped_production.minerals = actual_production.minerals.min(available_space.minerals);
-                capped_production.food = actual_production.food.min(available_space.food);
-                capped_production.energy = actual_production.energy.min(available_space.energy);
-                capped_production.alloys = actual_production.alloys.min(available_space.alloys);
-                capped_production.components = actual_production.components.min(available_space.components);
-                capped_production.fuel = actual_production.fuel.min(available_space.fuel);
-                
-                // Apply production and consumption safely with capacity limits
-                self.planet_manager.modify_planet(planet_id, |planet| {
-                    // Add the capped production (this should never exceed capacity)
-                    planet.resources.current.add(&capped_production)?;
-                    
-                    // Only subtract consumption if we can afford it
-                    if can_afford_consumption {
-                        planet.resources.current.subtract(&consumption)?;
-                    }
-                    // If we can't afford consumption, buildings might shut down
-                    // but we don't crash the game
-                    
-                    Ok(())
-                })?;
-                
-                // Calculate net change for event tracking (use actual added resources)
-                let mut net_change = capped_production;
-                if can_afford_consumption {
-                    // Subtract consumption from the change we're reporting
-                    net_change.minerals -= consumption.minerals;
-                    net_change.food -= consumption.food;
-                    net_change.energy -= consumption.energy;
-                    net_change.alloys -= consumption.alloys;
-                    net_change.components -= consumption.components;
-                    net_change.fuel -= consumption.fuel;
-                }
-                
-                // Emit ResourcesProduced event for tracking (net change)
-                self.event_bus.queue_event(GameEvent::SimulationEvent(
-                    crate::core::events::SimulationEvent::ResourcesProduced {
-                        planet: planet_id,
-                        resources: net_change
-                    }
-                ));
-                
-                // Process population growth (every 10 ticks for performance)
-                if tick % 10 == 0 {
-                    // Get fresh planet data after resource update
-                    let (population, food_available) = {
-                        let updated_planet = self.planet_manager.get_planet(planet_id)?;
-                        (updated_planet.population.total, updated_planet.resources.current.food)
-                    };
-                    
-                    // Process population growth using existing method
-                    self.population_system.process_planet_growth(
-                        planet_id, 
-                        population, 
-                        food_available,
-                        &mut self.event_bus
-                    )?;
-                }
-            }
-        }
-        
-        Ok(())
-    }
-    
-    fn handle_system_event(&mut self, system_id: SystemId, event: &GameEvent) -> GameResult<()> {
-        // Handle tick processing centrally before routing to systems
-        if let GameEvent::SimulationEvent(sim_event) = event {
-            if let crate::core::events::SimulationEvent::TickCompleted(tick) = sim_event {
-                self.process_tick_events(*tick)?;
-            }
-        }
-        
-        match system_id {
-            SystemId::TimeManager => self.time_manager.handle_event(event),
-            SystemId::PlanetManager => self.planet_manager.handle_event(event),
-            SystemId::ShipManager => self.ship_manager.handle_event(event),
-            SystemId::FactionManager => self.faction_manager.handle_event(event),
-            SystemId::PhysicsEngine => self.physics_engine.handle_event(event),
-            SystemId::ResourceSystem => self.resource_system.handle_event(event),
-            SystemId::PopulationSystem => self.population_system.handle_event(event),
-            SystemId::ConstructionSystem => self.construction_system.handle_event(event),
-            SystemId::CombatResolver => self.combat_resolver.handle_event(event),
-            SystemId::SaveSystem => {
-                // Handle SaveSystem events specially since they need full GameState access
-                if let GameEvent::PlayerCommand(cmd) = event {
-                    match cmd {
-                        PlayerCommand::SaveGame => {
-                            // Use stored game name if available, otherwise prompt for name
-                            if let Some(save_name) = self.current_save_name.clone() {
-                                // Save to current game name
-                                self.handle_save_game_as_command(&save_name)
-                            } else {
-                                // No current save name, show save dialog
-                                self.save_load_dialog.show_save_dialog();
-                                Ok(())
-                            }
-                        },
-                        PlayerCommand::SaveGameDialog => {
-                            // Always show save dialog to prompt for name
-                            self.save_load_dialog.show_save_dialog();
-                            Ok(())
-                        },
-                        PlayerCommand::SaveGameAs(name) => self.handle_save_game_as_command(name),
-                        PlayerCommand::LoadGame => Ok(()), // Handled in menu
-                        PlayerCommand::LoadGameFrom(name) => self.handle_load_game_from_slot_command(name),
-                        PlayerCommand::BackToMenu => {
-                            self.current_mode = GameMode::MainMenu;
-                            // ui_v2 handles menu refresh
-                            Ok(())
-                        }
-                        PlayerCommand::NewGame => {
-                            // NewGame commands should not be processed while in InGame mode
-                            // They should only be handled in MainMenu mode
-                            Ok(())
-                        }
-                        PlayerCommand::NewGameNamed(_) => {
-                            // NewGameNamed commands should not be processed while in InGame mode
-                            // They should only be handled in MainMenu mode
-                            Ok(())
-                        }
-                        _ => self.save_system.handle_event(event),
-                    }
-                } else {
-                    self.save_system.handle_event(event)
-                }
-            },
-            SystemId::UIRenderer => Ok(()), // UIBridge handles events differently
-        }
-    }
-
-    fn handle_save_game_command(&mut self) -> GameResult<()> {
-        // Use default name for quick save
-        let save_name = self.current_save_name.clone().unwrap_or_else(|| "quicksave".to_string());
-        self.handle_save_game_as_command(&save_name)
-    }
-
-    fn handle_load_game_command(&mut self) -> GameResult<()> {
-        // Use default name for quick load
-        let save_name = self.current_save_name.clone().unwrap_or_else(|| "quicksave".to_string());
-        self.handle_load_game_from_slot_command(&save_name)
-    }
-
-    fn handle_save_game_as_command(&mut self, name: &str) -> GameResult<()> {
-        // Update current save name for future saves
-        self.current_save_name = Some(name.to_string());
-        
-        // Extract the save system temporarily to avoid borrow conflicts
-        let mut save_system = std::mem::replace(&mut self.save_system, SaveSystem::new());
-        let result = save_system.save_game_to_slot(self, name);
-        self.save_system = save_system;
-        result
-    }
-
-    fn handle_load_game_from_slot_command(&mut self, name: &str) -> GameResult<()> {
-        // Extract the save system temporarily to avoid borrow conflicts
-        let mut save_system = std::mem::replace(&mut self.save_system, SaveSystem::new());
-        let save_data = save_system.load_game_from_slot(name)?;
-        self.save_system = save_system;
-        
-        // Apply the loaded data to the game state in the correct order
-        // Only load actual data if it exists (avoid loading empty vectors that clear game state)
-        if !save_data.factions.is_empty() {
-            self.faction_manager.load_factions(save_data.factions)?;
-        }
-        
-        if !save_data.planets.is_empty() {
-            self.planet_manager.load_planets(save_data.planets)?;
-        }
-        
-        if !save_data.ships.is_empty() {
-            self.ship_manager.load_ships(save_data.ships)?;
-        }
-        
-        // Set the tick counter last
-        self.time_manager.set_tick(save_data.tick)?;
-        
-        // Clear event bus to remove any stale events referencing old entities
-        self.event_bus.clear();
-        
-        // Reset UI renderer to clear any cached selections or state
-        self.ui_system = UISystem::new();
-        
-        // Switch to gameplay mode after successful load
-        self.current_mode = GameMode::InGame;
-        
-        Ok(())
-    }
-    
-    pub fn queue_event(&mut self, event: GameEvent) {
-        self.event_bus.queue_event(event);
-    }
-    
-    pub fn get_current_tick(&self) -> u64 {
-        self.time_manager.get_current_tick()
-    }
-    
-    pub fn is_dialog_active(&self) -> bool {
-        self.save_load_dialog.is_active()
-    }
-    
-    /// Get the current game configuration
-    pub fn get_game_configuration(&self) -> &GameConfiguration {
-        self.game_initializer.get_configuration()
-    }
-    
-    /// Set a new game configuration for future new games
-    pub fn set_game_configuration(&mut self, config: GameConfiguration) {
-        self.game_initializer.set_configuration(config);
-    }
-    
-    /// Cycle through different game configuration presets
-    fn cycle_game_configuration(&self, current: GameConfiguration) -> GameConfiguration {
-        // Define some preset configurations
-        let presets = [
-            // Small galaxy
-            GameConfiguration {
-                planet_count: 5,
-                starting_resources: ResourceBundle {
-                    minerals: 300,
-                    food: 200,
-                    energy: 150,
-                    alloys: 30,
-                    components: 15,
-                    fuel: 75,
-                },
-                starting_population: 750,
-                galaxy_size: GalaxySize::Small,
-                ai_opponents: 1,
-            },
-            // Medium galaxy
-            GameConfiguration {
-                planet_count: 8,
-                starting_resources: ResourceBundle {
-                    minerals: 500,
-                    food: 300,
-                    energy: 200,
-                    alloys: 50,
-                    components: 25,
-                    fuel: 100,
-                },
-                starting_population: 1000,
-                galaxy_size: GalaxySize::Medium,
-                ai_opponents: 2,
-            },
-            // Large galaxy
-            GameConfiguration {
-                planet_count: 12,
-                starting_resources: ResourceBundle {
-                    minerals: 800,
-                    food: 500,
-                    energy: 300,
-                    alloys: 80,
-                    components: 40,
-                    fuel: 150,
-                },
-                starting_population: 1500,
-                galaxy_size: GalaxySize::Large,
-                ai_opponents: 3,
-            },
-        ];
-        
-        // Find current preset and move to next
-        let current_index = presets.iter().position(|preset| {
-            preset.planet_count == current.planet_count && 
-            preset.galaxy_size == current.galaxy_size
-        }).unwrap_or(0);
-        
-        let next_index = (current_index + 1) % presets.len();
-        presets[next_index].clone()
-    }
-    
-    pub fn save_game(&mut self) -> GameResult<()> {
-        self.queue_event(GameEvent::PlayerCommand(PlayerCommand::SaveGame));
-        Ok(())
-    }
-    
-    pub fn load_game(&mut self) -> GameResult<()> {
-        self.queue_event(GameEvent::PlayerCommand(PlayerCommand::LoadGame));
-        Ok(())
-    }
-    
-    pub fn render(&mut self, _interpolation: f32) -> GameResult<()> {
-        // Clear screen
-        clear_background(BLACK);
-        
-        match self.current_mode {
-            GameMode::MainMenu => {
-                // Temporary basic menu display until proper UI is implemented
-                draw_text("STELLAR DOMINION", 200.0, 200.0, 60.0, WHITE);
-                draw_text("Press 'N' for New Game", 200.0, 300.0, 30.0, WHITE);
-                draw_text("Press 'L' to Load Game", 200.0, 350.0, 30.0, WHITE);
-                draw_text("Press 'Esc' to Exit", 200.0, 400.0, 30.0, WHITE);
-                
-                // Also try to render ui_v2 system in case it has content
-                self.ui_system.render();
-                Ok(())
-            }
-            GameMode::InGame => {
-                // Use ui_system for rendering
-                self.ui_system.render();
-                
-                // Render save/load dialog on top if active
-                self.save_load_dialog.render()?;
-                
-                // If no UI content, show basic game info
-                draw_text(&format!("Tick: {}", self.time_manager.get_current_tick()), 10.0, 30.0, 20.0, WHITE);
-                draw_text(&format!("Planets: {}", self.planet_manager.get_planet_count()), 10.0, 60.0, 20.0, WHITE);
-                draw_text("Press 'Esc' to return to menu", 10.0, 90.0, 20.0, WHITE);
-                
-                Ok(())
-            }
-        }
-    }
-    
-    /// Processes queued events manually - used for testing.
-    /// This allows tests to trigger event processing without running fixed_update.
-    /// Available in both unit tests and integration tests.
-    pub fn process_queued_events_for_test(&mut self) -> GameResult<()> {
-        self.process_queued_events()
-    }
-    
-    fn handle_menu_event(&mut self, event: GameEvent) -> GameResult<()> {
-        if let GameEvent::PlayerCommand(cmd) = event {
-            match cmd {
-                PlayerCommand::NewGame => {
-                    // Show new game dialog
-                    self.save_load_dialog.show_new_game_dialog();
-                }
-                PlayerCommand::NewGameNamed(name) => {
-                    // Store the game name for future saves
-                    self.current_save_name = Some(name.clone());
-                    
-                    // Close dialog FIRST before any other operations
-                    self.save_load_dialog.close();
-                    
-                    // Use GameInitializer to set up new game with current configuration
-                    self.game_initializer.initialize_game(
-                        &mut self.planet_manager,
-                        &mut self.ship_manager,
-                        &mut self.faction_manager,
-                    )?;
-                    
-                    // Reset other systems to initial state
-                    self.time_manager = TimeManager::new();
-                    self.resource_system = ResourceSystem::new();
-                    self.population_system = PopulationSystem::new();
-                    self.construction_system = ConstructionSystem::new();
-                    self.physics_engine = PhysicsEngine::new();
-                    self.combat_resolver = CombatResolver::new();
-                    
-                    // Clear UI state and switch to in-game mode
-                    self.ui_system = UISystem::new();
-                    self.event_bus.clear();
-                    
-                    // Ensure dialog stays closed during mode switch
-                    self.save_load_dialog.close();
-                    self.current_mode = GameMode::InGame;
-                    
-                    // Additional safety: ensure dialog is not active after mode switch
-                    self.save_load_dialog.close();
-                    
-                    // Auto-save the new game with the given name
-                    let mut save_system = std::mem::replace(&mut self.save_system, SaveSystem::new());
-                    let _result = save_system.save_game_to_slot(self, &name);
-                    self.save_system = save_system;
-                }
-                PlayerCommand::LoadGame => {
-                    // Show load game dialog with available saves
-                    let saves = self.save_system.list_saves()?;
-                    self.save_load_dialog.show_load_dialog(saves);
-                }
-                PlayerCommand::LoadGameFrom(name) => {
-                    // Store the game name for future saves
-                    self.current_save_name = Some(name.clone());
-                    
-                    // Close dialog FIRST before any other operations
-                    self.save_load_dialog.close();
-                    
-                    // First initialize a fresh game state to ensure all systems are properly set up
-                    // This prevents crashes from partially initialized systems during load
-                    self.time_manager = TimeManager::new();
-                    self.planet_manager = PlanetManager::new();
-                    self.ship_manager = ShipManager::new();
-                    self.faction_manager = FactionManager::new();
-                    self.resource_system = ResourceSystem::new();
-                    self.population_system = PopulationSystem::new();
-                    self.construction_system = ConstructionSystem::new();
-                    self.physics_engine = PhysicsEngine::new();
-                    self.combat_resolver = CombatResolver::new();
-                    
-                    // Clear all UI state to prevent stale entity references
-                    self.ui_system = UISystem::new();
-                    
-                    // Clear any queued events that might reference old entities
-                    self.event_bus.clear();
-                    
-                    // Now load the specific saved game
-                    match self.handle_load_game_from_slot_command(&name) {
-                        Ok(()) => {
-                            // Ensure dialog stays closed during mode switch
-                            self.save_load_dialog.close();
-                            self.current_mode = GameMode::InGame;
-                            
-                            // Additional safety: ensure dialog is not active after mode switch
-                            self.save_load_dialog.close();
-                        }
-                        Err(e) => {
-                            // On error, stay in menu mode, ensure dialog is closed, and propagate error
-                            self.save_load_dialog.close();
-                            self.current_mode = GameMode::MainMenu;
-                            return Err(e);
-                        }
-                    }
-                }
-                PlayerCommand::ExitGame => {
-                    // Set exit flag to be handled by main loop
-                    self.should_exit = true;
-                }
-                PlayerCommand::GameOptions => {
-                    // TODO: Implement game options UI
-                    // For now, just cycle through some preset configurations
-                    let current_config = self.game_initializer.get_configuration().clone();
-                    let new_config = self.cycle_game_configuration(current_config);
-                    self.game_initializer.set_configuration(new_config);
-                }
-                PlayerCommand::BackToMenu => {
-                    self.current_mode = GameMode::MainMenu;
-                    // ui_v2 handles menu refresh
-                }
-                _ => {
-                    // Other commands are not valid in menu mode
-                }
-            }
-        }
-        Ok(())
-    }
-    
-    /// Initialize basic UI for the main menu
-    fn initialize_basic_ui(&mut self) -> GameResult<()> {
-        // For now, just render some basic text to show the game is running
-        // This is a temporary solution until proper menu is implemented
-        Ok(())
-    }
-    
-    /// Initialize some demo content for testing and initial gameplay
-    fn initialize_demo_content(&mut self) -> GameResult<()> {
-        // Create a few test planets with basic properties
-        let planet1 = self.planet_manager.create_planet(
-            OrbitalElements {
-                semi_major_axis: 1.0,
-                period: 365.0,
-                phase: 0.0,
-            },
-            Some(0), // Player faction
-        )?;
-        
-        let planet2 = self.planet_manager.create_planet(
-            OrbitalElements {
-                semi_major_axis: 1.5,
-                period: 500.0,
-                phase: 1.57, // 90 degrees offset
-            },
-            None, // Neutral
-        )?;
-        
-        let planet3 = self.planet_manager.create_planet(
-            OrbitalElements {
-                semi_major_axis: 0.7,
-                period: 200.0,
-                phase: 3.14, // 180 degrees offset
-            },
-            None, // Neutral
-        )?;
-        
-        // Add some basic resources to the player planet
-        self.planet_manager.add_resources(planet1, ResourceBundle {
-            minerals: 500,
-            food: 300,
-            energy: 200,
-            alloys: 50,
-            components: 25,
-            fuel: 100,
-        })?;
-        
-        // Add some population to the player planet
-        self.planet_manager.update_population(planet1, 1000)?;
-        
-        // Create a test ship for the player
-        let ship1 = self.ship_manager.create_ship(
-            ShipClass::Scout,
-            Vector2::new(50.0, 50.0), // Starting position
-            0, // Player owner
-        )?;
-        
-        println!("Demo content initialized: {} planets and {} ship created with IDs: planets {}, {}, {} and ship {}", 
-                 3, 1, planet1, planet2, planet3, ship1);
-        Ok(())
-    }
-}
-
-// System trait definition
-pub trait GameSystem {
-    fn update(&mut self, delta: f32, events: &mut EventBus) -> GameResult<()>;
-    fn handle_event(&mut self, event: &GameEvent) -> GameResult<()>;
-}
\ No newline at end of file
@@ -1,6 +1,6 @@
 // src/main.rs
 use macroquad::prelude::*;
-use stellar_dominion::core::{GameState, GameResult};
+use stellar_dominion::{logging, GameClient, GameResult};
 
 const FIXED_TIMESTEP: f32 = 0.1;
 const MAX_SUBSTEPS: u32 = 10;
@@ -15,17 +15,34 @@ fn window_conf() -> Conf {
     }
 }
 
+/// Keep recent records for the developer console and, built with the
+/// `env-logger` feature, print those `RUST_LOG` asks for to standard error
+fn init_logging() {
+    #[cfg(feature = "env-logger")]
+    let forward = {
+        let logger = env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).build();
+        let level = logger.filter();
+        Some((Box::new(logger) as Box<dyn log::Log>, level))
+    };
+    #[cfg(not(feature = "env-logger"))]
+    let forward = None;
+    if let Err(e) = logging::init(log::LevelFilter::Info, forward) {
+        eprintln!("{}", e);
+    }
+}
+
 #[macroquad::main(window_conf)]
 async fn main() -> GameResult<()> {
-    println!("Starting Stellar Dominion...");
+    init_logging();
+    log::info!("Starting Stellar Dominion...");
     
-    let mut game_state = match GameState::new() {
-        Ok(state) => {
-            println!("Game state initialized successfully");
-            state
+    let mut client = match GameClient::new() {
+        Ok(client) => {
+            log::info!("Game state initialized successfully");
+            client
         }
         Err(e) => {
-            println!("Failed to initialize game state: {:?}", e);
+            log::error!("Failed to initialize game state: {:?}", e);
             return Err(e);
         }
     };
@@ -33,7 +50,7 @@ async fn main() -> GameResult<()> {
     let mut accumulator = 0.0;
     let mut last_time = get_time();
     
-    println!("Entering main game loop...");
+    log::info!("Entering main game loop...");
     
     loop {
         // Fixed timestep with interpolation
@@ -43,38 +60,41 @@ async fn main() -> GameResult<()> {
         
         accumulator += frame_time.min(FIXED_TIMESTEP * MAX_SUBSTEPS as f32);
         
-        // Check exit conditions BEFORE processing game input
-        // This prevents dialog escape from being processed twice
-        if (is_key_pressed(KeyCode::Escape) && !game_state.is_dialog_active()) || game_state.should_exit {
-            println!("Exit requested, shutting down game");
+        // Leaving is requested from the main menu or the pause menu
+        if client.should_exit() {
+            log::info!("Exit requested, shutting down game");
             break;
         }
         
         // Process input every frame for responsive controls
-        if let Err(e) = game_state.process_input() {
-            println!("Error in process_input: {:?}", e);
+        if let Err(e) = client.process_input() {
+            log::error!("Error in process_input: {:?}", e);
             return Err(e);
         }
         
         // Process fixed timestep updates
         while accumulator >= FIXED_TIMESTEP {
-            if let Err(e) = game_state.fixed_update(FIXED_TIMESTEP) {
-                println!("Error in fixed_update: {:?}", e);
-                return Err(e);
+            // A recoverable error costs this step its work, not the game
+            if let Err(e) = client.fixed_update(FIXED_TIMESTEP) {
+                if !e.is_recoverable() {
+                    log::error!("Error in fixed_update: {:?}", e);
+                    return Err(e);
+                }
+                log::warn!("Error in fixed_update, continuing: {:?}", e);
             }
             accumulator -= FIXED_TIMESTEP;
         }
         
         // Interpolated render
         let interpolation = accumulator / FIXED_TIMESTEP;
-        if let Err(e) = game_state.render(interpolation) {
-            println!("Error in render: {:?}", e);
+        if let Err(e) = client.render(interpolation) {
+            log::error!("Error in render: {:?}", e);
             return Err(e);
         }
         
         next_frame().await;
     }
     
-    println!("Game loop ended, shutting down");
+    log::info!("Game loop ended, shutting down");
     Ok(())
 }
\ No newline at end of file
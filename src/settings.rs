@@ -0,0 +1,165 @@
+// src/settings.rs
+//! User preferences kept between sessions
+//!
+//! [`SettingsManager`] holds the player's [`Settings`]: key bindings, theme,
+//! UI scale, volumes and the autosave interval. They are stored as TOML in
+//! [`SETTINGS_FILE`] under the platform's configuration directory, see
+//! [`config_dir`], and any setting missing from the file keeps its built-in
+//! default. The client applies them at startup and saves them whenever one
+//! changes.
+
+use crate::config::DEFAULT_AUTOSAVE_INTERVAL_TICKS;
+use crate::core::{GameError, GameResult};
+use crate::ui_v2::core::input_map::InputMap;
+use crate::ui_v2::core::ui_scale::clamp_ui_scale;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Name of the settings file in the configuration directory
+pub const SETTINGS_FILE: &str = "settings.toml";
+/// Environment variable naming a directory to keep settings in instead of
+/// the platform's configuration directory
+pub const CONFIG_DIR_ENV: &str = "STELLAR_DOMINION_CONFIG_DIR";
+/// Directory the game's files go in under the platform's configuration directory
+const APP_DIR: &str = "stellar-dominion";
+
+/// The directory settings are kept in: [`CONFIG_DIR_ENV`] when set, else
+/// `%APPDATA%` on Windows, `~/Library/Application Support` on macOS and
+/// `$XDG_CONFIG_HOME` or `~/.config` elsewhere, each with a
+/// `stellar-dominion` directory. Falls back to `config` in the working
+/// directory when none of those is known.
+pub fn config_dir() -> PathBuf {
+    let var = |name: &str| std::env::var_os(name).filter(|value| !value.is_empty()).map(PathBuf::from);
+    if let Some(dir) = var(CONFIG_DIR_ENV) {
+        return dir;
+    }
+    let base = if cfg!(target_os = "windows") {
+        var("APPDATA")
+    } else if cfg!(target_os = "macos") {
+        var("HOME").map(|home| home.join("Library").join("Application Support"))
+    } else {
+        var("XDG_CONFIG_HOME").or_else(|| var("HOME").map(|home| home.join(".config")))
+    };
+    base.map(|base| base.join(APP_DIR)).unwrap_or_else(|| PathBuf::from("config"))
+}
+
+/// Everything the player can set outside a game; settings missing from a
+/// file keep their defaults
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Settings {
+    /// Name of the theme for the map and UI
+    pub theme: String,
+    /// Size of the UI, from `UI_SCALE_MIN` to `UI_SCALE_MAX`
+    pub ui_scale: f32,
+    /// Overall volume, from 0.0 to 1.0
+    pub master_volume: f32,
+    /// Sound effect volume under the master volume, from 0.0 to 1.0
+    pub effects_volume: f32,
+    /// Ticks between autosaves in new games; 0 turns autosaving off
+    pub autosave_interval_ticks: u64,
+    /// Key for each input action
+    pub key_bindings: InputMap,
+}
+
+impl Settings {
+    /// The settings as a TOML document
+    pub fn to_toml(&self) -> GameResult<String> {
+        let text = toml::to_string(self)
+            .map_err(|e| GameError::InvalidOperation(format!("Settings could not be written: {}", e)))?;
+        Ok(format!("# Stellar Dominion settings\n\n{}", text))
+    }
+
+    /// Read a TOML document over the defaults. Scales and volumes out of
+    /// range are clamped; unknown settings and malformed values are errors.
+    pub fn from_toml(text: &str) -> GameResult<Self> {
+        let mut settings: Self = toml::from_str(text)
+            .map_err(|e| GameError::InvalidOperation(format!("Settings: {}", e)))?;
+        settings.ui_scale = clamp_ui_scale(settings.ui_scale);
+        settings.master_volume = clamp_volume(settings.master_volume);
+        settings.effects_volume = clamp_volume(settings.effects_volume);
+        Ok(settings)
+    }
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            theme: "Dark".to_string(),
+            ui_scale: 1.0,
+            master_volume: 1.0,
+            effects_volume: 1.0,
+            autosave_interval_ticks: DEFAULT_AUTOSAVE_INTERVAL_TICKS,
+            key_bindings: InputMap::new(),
+        }
+    }
+}
+
+/// The volume clamped to 0.0 to 1.0; silent for NaN
+fn clamp_volume(volume: f32) -> f32 {
+    if volume.is_nan() {
+        0.0
+    } else {
+        volume.clamp(0.0, 1.0)
+    }
+}
+
+/// The player's settings and the file they are saved to
+#[derive(Debug, Clone, Default)]
+pub struct SettingsManager {
+    settings: Settings,
+    path: Option<PathBuf>,
+}
+
+impl SettingsManager {
+    /// The defaults, kept in memory and never saved
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Settings from the file at `path`, saved back there on every change;
+    /// the defaults when the file does not exist
+    pub fn load(path: &Path) -> GameResult<Self> {
+        let settings = if path.exists() {
+            Settings::from_toml(&std::fs::read_to_string(path)?)?
+        } else {
+            Settings::default()
+        };
+        Ok(Self { settings, path: Some(path.to_path_buf()) })
+    }
+
+    /// Settings from [`SETTINGS_FILE`] in [`config_dir`]
+    pub fn load_default() -> GameResult<Self> {
+        Self::load(&config_dir().join(SETTINGS_FILE))
+    }
+
+    /// Current settings
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// File the settings are saved to, if any
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Change the settings and save them. The change stays in effect if
+    /// saving fails.
+    pub fn update(&mut self, change: impl FnOnce(&mut Settings)) -> GameResult<()> {
+        change(&mut self.settings);
+        self.save()
+    }
+
+    /// Write the settings to their file, creating its directory as needed;
+    /// does nothing for settings kept in memory
+    pub fn save(&self) -> GameResult<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, self.settings.to_toml()?)?;
+        Ok(())
+    }
+}
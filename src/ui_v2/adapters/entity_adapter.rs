@@ -2,6 +2,7 @@
 //! Base trait for entity adapters
 
 use crate::core::events::PlayerCommand;
+use crate::core::types::ResourceBundle;
 
 /// Trait for adapting game entities to UI display format
 pub trait EntityAdapter<T> {
@@ -60,4 +61,25 @@ pub fn format_resource(current: i32, capacity: i32) -> String {
     } else {
         format_number(current)
     }
-}
\ No newline at end of file
+}
+
+/// Helper function to list the non-zero amounts of a bundle, e.g. "50 Minerals, 10 Energy"
+pub fn format_bundle(bundle: &ResourceBundle) -> String {
+    let amounts: Vec<String> = [
+        ("Minerals", bundle.minerals),
+        ("Food", bundle.food),
+        ("Energy", bundle.energy),
+        ("Alloys", bundle.alloys),
+        ("Components", bundle.components),
+        ("Fuel", bundle.fuel),
+    ]
+    .into_iter()
+    .filter(|(_, amount)| *amount != 0)
+    .map(|(name, amount)| format!("{} {}", amount, name))
+    .collect();
+    if amounts.is_empty() {
+        "nothing".to_string()
+    } else {
+        amounts.join(", ")
+    }
+}
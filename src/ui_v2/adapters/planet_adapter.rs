@@ -2,7 +2,7 @@
 //! Adapter for Planet entities
 
 use super::{EntityAdapter, format_number, format_resource};
-use crate::core::types::Planet;
+use crate::core::types::{Planet, ResourceBundle, ResourceType, ShipClass};
 use crate::core::events::PlayerCommand;
 use macroquad::prelude::Color;
 
@@ -36,6 +36,36 @@ impl PlanetAdapter {
         self.show_development_slots = show;
         self
     }
+
+    /// Column titles of the empire overview, matching [`PlanetAdapter::overview_row`]
+    pub fn overview_columns() -> Vec<String> {
+        ["Planet", "Population"].into_iter().map(String::from)
+            .chain(ResourceType::ALL.iter().map(|resource| format!("{:?}", resource)))
+            .chain(["Buildings", "Net/tick"].into_iter().map(String::from))
+            .collect()
+    }
+
+    /// Empire overview row: the planet's id, population, stock of each
+    /// resource, building count and net production per tick
+    pub fn overview_row(&self, planet: &Planet, production: &ResourceBundle) -> Vec<String> {
+        [planet.id.to_string(), format_number(planet.population.total)].into_iter()
+            .chain(ResourceType::ALL.iter().map(|resource| planet.resources.current.get(*resource).to_string()))
+            .chain([planet.developments.len().to_string(), format!("{:+}", production.total())])
+            .collect()
+    }
+
+    /// Empire overview totals over every planet and its production, in the
+    /// row layout with the planet count in the first column
+    pub fn overview_totals(&self, planets: &[(&Planet, ResourceBundle)]) -> Vec<String> {
+        let stock = planets.iter().fold(ResourceBundle::default(), |stock, (planet, _)| stock + planet.resources.current);
+        let population: i32 = planets.iter().map(|(planet, _)| planet.population.total).sum();
+        let buildings: usize = planets.iter().map(|(planet, _)| planet.developments.len()).sum();
+        let production: i64 = planets.iter().map(|(_, production)| production.total()).sum();
+        [format!("{} planets", planets.len()), format_number(population)].into_iter()
+            .chain(ResourceType::ALL.iter().map(|resource| stock.get(*resource).to_string()))
+            .chain([buildings.to_string(), format!("{:+}", production)])
+            .collect()
+    }
 }
 
 impl EntityAdapter<Planet> for PlanetAdapter {
@@ -43,14 +73,25 @@ impl EntityAdapter<Planet> for PlanetAdapter {
         let mut fields = Vec::new();
 
         // Basic info
+        fields.push(("Name".to_string(), planet.display_name()));
         fields.push(("ID".to_string(), planet.id.to_string()));
         fields.push(("Faction".to_string(), 
             planet.controller.map_or("None".to_string(), |id| id.to_string())));
         fields.push(("Orbit".to_string(), format!("Axis: {:.1} AU", planet.position.semi_major_axis)));
+        fields.push(("Size".to_string(), planet.size.to_string()));
+        fields.push(("Environment".to_string(), format!("Minerals x{:.1}, Fertility x{:.1}, Habitability x{:.1}",
+            planet.traits.mineral_richness, planet.traits.fertility, planet.traits.habitability)));
 
         // Population
         fields.push(("Population".to_string(), format_number(planet.population.total)));
         fields.push(("Growth Rate".to_string(), format!("{:.2}", planet.population.growth_rate)));
+        fields.push(("Housing".to_string(), format!("{} / {}",
+            format_number(planet.population.total), format_number(planet.housing_capacity()))));
+        if planet.overcrowding() > 0.0 {
+            fields.push(("Overcrowded".to_string(), format!("{:.0}% over capacity", planet.overcrowding() * 100.0)));
+        }
+        fields.push(("Happiness".to_string(), format!("{:+.0}%", planet.population.happiness * 100.0)));
+        fields.push(("Tax Rate".to_string(), format!("{:?}", planet.tax_rate)));
 
         // Resources
         if self.show_detailed_resources {
@@ -83,8 +124,7 @@ impl EntityAdapter<Planet> for PlanetAdapter {
         // Development
         if self.show_development_slots {
             let used_slots = planet.developments.len();
-            // Calculate building slots: 10 + population/10000 (per architecture rules)
-            let total_slots = 10 + planet.population.total / 10000;
+            let total_slots = planet.building_slots();
             fields.push(("Development Slots".to_string(), format!("{} / {}", used_slots, total_slots)));
             
             // List developments
@@ -108,13 +148,13 @@ impl EntityAdapter<Planet> for PlanetAdapter {
         actions.push(("Manage Resources".to_string(), PlayerCommand::ShowResourcePanel));
 
         // Conditional actions based on planet state
-        let total_slots = 10 + planet.population.total / 10000;
-        if total_slots > planet.developments.len() as i32 {
-            actions.push(("Build Development".to_string(), PlayerCommand::BuildDevelopment(planet.id, "Infrastructure".to_string())));
+        let total_slots = planet.building_slots();
+        if total_slots > planet.developments.len() {
+            actions.push(("Build Structure".to_string(), PlayerCommand::ShowBuildMenu(planet.id)));
         }
 
         if planet.resources.current.energy > 100 {
-            actions.push(("Build Ship".to_string(), PlayerCommand::BuildShip(planet.id, "Scout".to_string())));
+            actions.push(("Build Ship".to_string(), PlayerCommand::BuildShip { planet: planet.id, ship_class: ShipClass::Scout }));
         }
 
         // Close panel action
@@ -138,8 +178,8 @@ impl EntityAdapter<Planet> for PlanetAdapter {
     }
 
     fn get_summary(&self, planet: &Planet) -> String {
-        format!("Planet {} - Pop: {} - Energy: {}", 
-            planet.id,
+        format!("{} - Pop: {} - Energy: {}", 
+            planet.display_name(),
             format_number(planet.population.total),
             format_number(planet.resources.current.energy)
         )
@@ -177,8 +217,8 @@ impl EntityAdapter<Planet> for PlanetAdapter {
 
     fn is_highlighted(&self, planet: &Planet) -> bool {
         // Highlight planets with available building slots or low resources
-        let total_slots = 10 + planet.population.total / 10000;
-        let has_available_slots = total_slots > planet.developments.len() as i32;
+        let total_slots = planet.building_slots();
+        let has_available_slots = total_slots > planet.developments.len();
         let low_energy = planet.resources.capacity.energy > 0 && 
             (planet.resources.current.energy as f32 / planet.resources.capacity.energy as f32) < 0.3;
         
@@ -2,7 +2,7 @@
 //! Adapter for Ship entities
 
 use super::{EntityAdapter, format_number};
-use crate::core::types::{Ship, ShipClass, ResourceBundle};
+use crate::core::types::{Ship, ShipClass, ShipStatus, ResourceBundle};
 use crate::core::events::PlayerCommand;
 use macroquad::prelude::Color;
 
@@ -36,6 +36,35 @@ impl ShipAdapter {
         self.show_movement_history = show;
         self
     }
+
+    /// What the ship is doing: fighting, moving, orbiting or idle
+    pub fn order_status(ship: &Ship, in_combat: bool) -> String {
+        match (in_combat, &ship.trajectory, ship.status) {
+            (true, _, _) => "Attacking".to_string(),
+            (false, Some(_), _) => "Moving".to_string(),
+            (false, None, ShipStatus::Orbiting(planet)) => format!("Orbiting {}", planet),
+            (false, None, _) => "Idle".to_string(),
+        }
+    }
+
+    /// Column titles of the fleet overview, matching [`ShipAdapter::fleet_row`]
+    pub fn fleet_columns() -> Vec<String> {
+        ["Ship", "Class", "Position", "Fuel", "Cargo", "Order", "ETA"].into_iter().map(String::from).collect()
+    }
+
+    /// Fleet overview row: the ship's id, class, position, fuel, cargo load,
+    /// order and ticks to arrival, with `order` from [`ShipAdapter::order_status`]
+    pub fn fleet_row(&self, ship: &Ship, order: &str, eta: Option<u64>) -> Vec<String> {
+        vec![
+            ship.id.to_string(),
+            format!("{:?}", ship.ship_class),
+            format!("({:.1}, {:.1})", ship.position.x, ship.position.y),
+            format!("{:.0}", ship.fuel),
+            format_number(ship.cargo.current_load()),
+            order.to_string(),
+            eta.map_or_else(|| "-".to_string(), |ticks| ticks.to_string()),
+        ]
+    }
 }
 
 impl EntityAdapter<Ship> for ShipAdapter {
@@ -43,20 +72,25 @@ impl EntityAdapter<Ship> for ShipAdapter {
         let mut fields = Vec::new();
 
         // Basic info
+        fields.push(("Name".to_string(), ship.display_name()));
         fields.push(("ID".to_string(), ship.id.to_string()));
         fields.push(("Type".to_string(), format!("{:?}", ship.ship_class)));
         fields.push(("Faction".to_string(), ship.owner.to_string()));
         fields.push(("Position".to_string(), format!("({:.1}, {:.1})", ship.position.x, ship.position.y)));
 
-        // Status based on trajectory
-        let status = match &ship.trajectory {
-            Some(traj) => format!("Moving to ({:.1}, {:.1})", traj.destination.x, traj.destination.y),
-            None => "Idle".to_string(),
+        // Status based on trajectory and orbit
+        let status = match (&ship.trajectory, ship.status) {
+            (Some(traj), _) => format!("Moving to ({:.1}, {:.1})", traj.destination.x, traj.destination.y),
+            (None, ShipStatus::Orbiting(planet)) => format!("Orbiting Planet {}", planet),
+            (None, _) => "Idle".to_string(),
         };
         fields.push(("Status".to_string(), status));
 
         // Fuel instead of speed
         fields.push(("Fuel".to_string(), format!("{:.1}%", ship.fuel)));
+        if ship.damage > 0 {
+            fields.push(("Hull Damage".to_string(), ship.damage.to_string()));
+        }
         
         if let Some(traj) = &ship.trajectory {
             let distance = ((traj.destination.x - ship.position.x).powi(2) + (traj.destination.y - ship.position.y).powi(2)).sqrt();
@@ -102,11 +136,11 @@ impl EntityAdapter<Ship> for ShipAdapter {
             actions.push(("Stop Ship".to_string(), PlayerCommand::StopShip(ship.id)));
         }
 
-        // Cargo actions
+        // Cargo actions; cargo moves to and from the planet the ship orbits
         let has_cargo = ship.cargo.current_load() > 0;
         
-        if has_cargo {
-            actions.push(("Unload Cargo".to_string(), PlayerCommand::UnloadShipCargo { ship: ship.id, planet: 0 }));
+        if let (true, ShipStatus::Orbiting(planet)) = (has_cargo, ship.status) {
+            actions.push(("Unload Cargo".to_string(), PlayerCommand::UnloadShipCargo { ship: ship.id, planet }));
         }
 
         // Ship type specific actions
@@ -115,10 +149,17 @@ impl EntityAdapter<Ship> for ShipAdapter {
                 actions.push(("Scout Area".to_string(), PlayerCommand::ScoutLocation(ship.position)));
             }
             ShipClass::Transport => {
-                actions.push(("Load Cargo".to_string(), PlayerCommand::LoadShipCargo { ship: ship.id, planet: 0, resources: ResourceBundle::default() }));
+                if let ShipStatus::Orbiting(planet) = ship.status {
+                    actions.push(("Load Cargo".to_string(), PlayerCommand::LoadShipCargo {
+                        ship: ship.id, planet, resources: ResourceBundle::default(), population: 0,
+                    }));
+                }
             }
             ShipClass::Colony => {
-                actions.push(("Colonize".to_string(), PlayerCommand::ColonizePlanet { ship: ship.id, planet: 0 }));
+                // Colonists land from orbit
+                if let ShipStatus::Orbiting(planet) = ship.status {
+                    actions.push(("Colonize".to_string(), PlayerCommand::ColonizePlanet { ship: ship.id, planet }));
+                }
             }
             ShipClass::Warship => {
                 actions.push(("Attack".to_string(), PlayerCommand::AttackTarget { attacker: ship.id, target: 0 }));
@@ -159,9 +200,8 @@ impl EntityAdapter<Ship> for ShipAdapter {
             None => "Idle",
         };
         
-        format!("{:?} {} - {} - ({:.0}, {:.0})", 
-            ship.ship_class,
-            ship.id,
+        format!("{} - {} - ({:.0}, {:.0})", 
+            ship.display_name(),
             status,
             ship.position.x,
             ship.position.y
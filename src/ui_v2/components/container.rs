@@ -359,6 +359,11 @@ impl<T> ListView<T> {
         self.scroll_offset = 0.0;
     }
 
+    /// Items in display order
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
     pub fn get_selected(&self) -> Option<&T> {
         self.selected_index.and_then(|idx| self.items.get(idx))
     }
@@ -413,23 +418,22 @@ impl<T> ListView<T> {
         )
     }
 
-    fn render_items(&self, context: &RenderContext) -> ComponentResult {
+    /// Index and screen rectangle of each item on show, after scrolling
+    pub fn item_rects(&self) -> Vec<(usize, Rect)> {
         let content_area = self.get_content_area();
         let visible_items = (content_area.h / self.item_height) as usize + 2;
         let start_item = (self.scroll_offset / self.item_height) as usize;
 
-        for (i, item) in self.items.iter()
-            .enumerate()
-            .skip(start_item)
-            .take(visible_items) {
-            
-            let item_y = content_area.y + (i as f32 * self.item_height) - self.scroll_offset;
-            
-            if item_y < content_area.y - self.item_height || item_y > content_area.y + content_area.h {
-                continue;
-            }
+        (start_item..self.items.len().min(start_item + visible_items))
+            .map(|i| (i, content_area.y + (i as f32 * self.item_height) - self.scroll_offset))
+            .filter(|&(_, item_y)| item_y >= content_area.y - self.item_height && item_y <= content_area.y + content_area.h)
+            .map(|(i, item_y)| (i, Rect::new(content_area.x, item_y, content_area.w, self.item_height)))
+            .collect()
+    }
 
-            let item_rect = Rect::new(content_area.x, item_y, content_area.w, self.item_height);
+    fn render_items(&self, context: &RenderContext) -> ComponentResult {
+        for (i, item_rect) in self.item_rects() {
+            let item = &self.items[i];
 
             // Render selection background
             if self.show_selection && self.selected_index == Some(i) {
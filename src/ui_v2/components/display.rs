@@ -219,6 +219,124 @@ impl UIComponent<()> for ProgressBar {
     }
 }
 
+/// One line of a [`LineChart`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChartSeries {
+    /// Name shown in the legend
+    pub label: String,
+    /// Line color
+    pub color: Color,
+    /// Values by tick, oldest first
+    pub points: Vec<(u64, i64)>,
+}
+
+/// Line chart of values over ticks, every series on one scale from zero
+pub struct LineChart {
+    base: BaseComponent,
+    title: String,
+    series: Vec<ChartSeries>,
+}
+
+impl LineChart {
+    /// Empty chart with the title above it
+    pub fn new(title: String) -> Self {
+        Self {
+            base: BaseComponent::new(),
+            title,
+            series: Vec::new(),
+        }
+    }
+
+    /// Position and size of the plot area
+    pub fn with_layout(mut self, layout: Layout) -> Self {
+        self.base = self.base.with_layout(layout);
+        self
+    }
+
+    /// Replace the lines drawn
+    pub fn set_series(&mut self, series: Vec<ChartSeries>) {
+        self.series = series;
+    }
+
+    /// Lines drawn, in legend order
+    pub fn series(&self) -> &[ChartSeries] {
+        &self.series
+    }
+
+    /// First and last tick of any series, and the highest value, at least 1
+    pub fn bounds(&self) -> Option<(u64, u64, i64)> {
+        let mut points = self.series.iter().flat_map(|series| series.points.iter());
+        let &(tick, value) = points.next()?;
+        Some(points.fold((tick, tick, value.max(1)), |(first, last, high), &(tick, value)| {
+            (first.min(tick), last.max(tick), high.max(value))
+        }))
+    }
+}
+
+impl UIComponent<()> for LineChart {
+    fn render(&mut self, _data: &(), context: &RenderContext) -> ComponentResult {
+        if !self.base.state.visible {
+            return Ok(None);
+        }
+
+        let rect = self.base.state.layout.get_rect();
+        let small = context.font_size * 0.8;
+        draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 1.0, context.theme.border_color);
+        draw_text(&self.title, rect.x + 4.0, rect.y - 4.0, context.font_size * 0.9, context.theme.text_color);
+        let Some((first, last, high)) = self.bounds() else {
+            draw_text("No data yet", rect.x + 10.0, rect.y + 20.0, small, context.theme.secondary_text_color);
+            return Ok(None);
+        };
+        draw_text(&high.to_string(), rect.x + 4.0, rect.y + 14.0, small, context.theme.secondary_text_color);
+        draw_text(&format!("Tick {}", last), rect.x + rect.w - 80.0, rect.y + rect.h - 4.0, small, context.theme.secondary_text_color);
+
+        let span = (last - first).max(1) as f32;
+        let point = |&(tick, value): &(u64, i64)| vec2(
+            rect.x + (tick - first) as f32 / span * rect.w,
+            rect.y + rect.h - value.max(0) as f32 / high as f32 * rect.h,
+        );
+        for (i, series) in self.series.iter().enumerate() {
+            for pair in series.points.windows(2) {
+                let (from, to) = (point(&pair[0]), point(&pair[1]));
+                draw_line(from.x, from.y, to.x, to.y, 1.5, series.color);
+            }
+            let legend_x = rect.x + rect.w - 120.0;
+            let legend_y = rect.y + 14.0 + i as f32 * small;
+            draw_rectangle(legend_x, legend_y - 8.0, 8.0, 8.0, series.color);
+            draw_text(&series.label, legend_x + 12.0, legend_y, small, context.theme.text_color);
+        }
+        Ok(None)
+    }
+
+    fn handle_input(&mut self, _input: &InputEvent) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn get_bounds(&self) -> Rect {
+        self.base.state.layout.get_rect()
+    }
+
+    fn set_position(&mut self, position: Vec2) {
+        self.base.state.layout.position = position;
+    }
+
+    fn set_size(&mut self, size: Vec2) {
+        self.base.state.layout.size = size;
+    }
+
+    fn is_visible(&self) -> bool {
+        self.base.state.visible
+    }
+
+    fn set_visible(&mut self, visible: bool) {
+        self.base.state.visible = visible;
+    }
+
+    fn get_state(&self) -> ComponentState {
+        self.base.state.clone()
+    }
+}
+
 // Placeholder structs for other display components
 pub struct DataTable {
     base: BaseComponent,
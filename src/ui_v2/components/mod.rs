@@ -18,7 +18,7 @@ pub mod layout;
 pub use base_component::{UIComponent, ComponentState};
 pub use interactive::{Button, Dropdown, Slider, TextInput};
 pub use container::{Panel, ListView};
-pub use display::{Label, ProgressBar, DataTable, ItemList};
+pub use display::{Label, ProgressBar, LineChart, ChartSeries, DataTable, ItemList};
 pub use layout::{Container, TabContainer, Splitter};
 
 // Convenience type aliases
@@ -0,0 +1,97 @@
+// src/ui_v2/core/box_select.rs
+//! Drag-select boxes on the galaxy map
+//!
+//! A left press on the map that no view claims starts a [`DragTracker`].
+//! Releasing within [`DRAG_THRESHOLD`] pixels of the press is a click,
+//! picked as before; dragging further draws a selection box, and releasing
+//! it selects every ship of the player's inside. The box is drawn in screen
+//! space and converted to a world rectangle with [`world_box`] when it is
+//! resolved, so zoom and pan at release time decide what is inside.
+
+use super::camera::Camera;
+use crate::core::types::*;
+use crate::GameState;
+use macroquad::prelude::{Rect, Vec2};
+
+/// Pixels the cursor must travel from the press for a drag to become a box
+pub const DRAG_THRESHOLD: f32 = 6.0;
+
+/// What a left press and release on the map amounted to, in screen pixels
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MapGesture {
+    /// A click at this point
+    Click(Vec2),
+    /// A drag selecting everything in this box
+    Box(Rect),
+}
+
+/// Follows a left-button drag that started on the map
+#[derive(Debug, Clone, Default)]
+pub struct DragTracker {
+    start: Option<Vec2>,
+    current: Vec2,
+}
+
+impl DragTracker {
+    /// No drag in progress
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The button went down on the map
+    pub fn press(&mut self, at: Vec2) {
+        self.start = Some(at);
+        self.current = at;
+    }
+
+    /// The cursor moved; ignored without a drag in progress
+    pub fn move_to(&mut self, to: Vec2) {
+        self.current = to;
+    }
+
+    /// The button came up; None when no drag started on the map
+    pub fn release(&mut self, at: Vec2) -> Option<MapGesture> {
+        let start = self.start.take()?;
+        if start.distance(at) < DRAG_THRESHOLD {
+            Some(MapGesture::Click(start))
+        } else {
+            Some(MapGesture::Box(rect_between(start, at)))
+        }
+    }
+
+    /// Drop a drag in progress, such as when a dialog takes the input
+    pub fn cancel(&mut self) {
+        self.start = None;
+    }
+
+    /// The box to draw while dragging past the threshold
+    pub fn drag_box(&self) -> Option<Rect> {
+        self.start
+            .filter(|start| start.distance(self.current) >= DRAG_THRESHOLD)
+            .map(|start| rect_between(start, self.current))
+    }
+}
+
+fn rect_between(a: Vec2, b: Vec2) -> Rect {
+    let (min, max) = (a.min(b), a.max(b));
+    Rect::new(min.x, min.y, max.x - min.x, max.y - min.y)
+}
+
+/// Lowest and highest world corners of a screen box as the camera sees it
+pub fn world_box(camera: &Camera, rect: Rect) -> (Vector2, Vector2) {
+    let a = camera.screen_to_world(rect.point());
+    let b = camera.screen_to_world(rect.point() + rect.size());
+    (Vector2::new(a.x.min(b.x), a.y.min(b.y)), Vector2::new(a.x.max(b.x), a.y.max(b.y)))
+}
+
+/// The faction's ships inside a screen box, by id
+pub fn ships_in_box(game: &GameState, camera: &Camera, rect: Rect, owner: FactionId) -> Vec<ShipId> {
+    let (min, max) = world_box(camera, rect);
+    let mut ships: Vec<ShipId> = game.ship_manager.get_ships_by_owner(owner).into_iter()
+        .filter(|&id| game.ship_manager.get_ship(id).is_ok_and(|ship| {
+            (min.x..=max.x).contains(&ship.position.x) && (min.y..=max.y).contains(&ship.position.y)
+        }))
+        .collect();
+    ships.sort_unstable();
+    ships
+}
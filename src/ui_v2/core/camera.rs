@@ -0,0 +1,222 @@
+// src/ui_v2/core/camera.rs
+//! Galaxy map camera
+//!
+//! Maps world positions in AU to screen pixels around a center point and a
+//! zoom in pixels per AU. Panning and zooming set where the camera is headed;
+//! [`Camera::update`] eases the view there every frame, so moves are smooth
+//! however coarse the input. Zooming keeps the world point under the cursor
+//! in place. The camera can follow a planet or ship, which the client looks
+//! up each frame since the camera knows nothing of the game.
+//!
+//! The view stays within the galaxy: the center cannot leave the galaxy's
+//! extent and the camera cannot zoom out past the whole galaxy fitting on
+//! screen. [`Camera::galaxy_extent`] measures the extent from the planets'
+//! orbits around their stars and the ships.
+
+use crate::core::types::*;
+use crate::GameState;
+use macroquad::prelude::Vec2;
+
+/// Zoom of a new camera, in pixels per AU
+pub const DEFAULT_ZOOM: f32 = 25.0;
+/// Closest zoom, in pixels per AU
+pub const MAX_ZOOM: f32 = 400.0;
+/// Zoom factor of one mouse wheel step
+pub const ZOOM_STEP: f32 = 1.2;
+/// How quickly the view closes in on its target, per second
+pub const CAMERA_SMOOTHING: f32 = 10.0;
+/// Smallest galaxy radius in AU, used while nothing is on the map
+pub const MIN_GALAXY_EXTENT: f32 = 5.0;
+/// Room left around the outermost orbit or ship
+const EXTENT_MARGIN: f32 = 1.1;
+
+/// Entity the camera keeps centered
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CameraTarget {
+    /// Planet on its orbit
+    Planet(PlanetId),
+    /// Ship the player can see
+    Ship(ShipId),
+}
+
+/// View onto the galaxy map
+#[derive(Debug, Clone)]
+pub struct Camera {
+    center: Vector2,
+    zoom: f32,
+    target_center: Vector2,
+    target_zoom: f32,
+    /// Galaxy radius in AU around the star
+    extent: f32,
+    /// Screen size in pixels
+    viewport: Vec2,
+    follow: Option<CameraTarget>,
+}
+
+impl Camera {
+    /// Camera over the star at [`DEFAULT_ZOOM`] on a screen of the given size
+    pub fn new(viewport: Vec2) -> Self {
+        // Zoom limits wait for the galaxy extent, which the camera does not know yet
+        Self {
+            center: Vector2::default(),
+            zoom: DEFAULT_ZOOM,
+            target_center: Vector2::default(),
+            target_zoom: DEFAULT_ZOOM,
+            extent: MIN_GALAXY_EXTENT,
+            viewport,
+            follow: None,
+        }
+    }
+
+    /// Radius around the first star that holds every planet's orbit and every ship
+    pub fn galaxy_extent(game: &GameState) -> f32 {
+        let star_map = game.physics_engine.star_map();
+        let orbits = game.planet_manager.get_all_planets().iter()
+            .map(|planet| star_map.star_position(planet.system).magnitude()
+                + planet.position.semi_major_axis * (1.0 + planet.position.eccentricity));
+        let ships = game.ship_manager.get_all_ships().iter()
+            .map(|ship| ship.position.magnitude());
+        orbits.chain(ships).fold(0.0, f32::max) * EXTENT_MARGIN
+    }
+
+    /// Use a new galaxy radius in AU, pulling the view back inside it
+    pub fn set_galaxy_extent(&mut self, extent: f32) {
+        self.extent = extent.max(MIN_GALAXY_EXTENT);
+        self.clamp_target();
+    }
+
+    /// Galaxy radius in AU
+    pub fn get_galaxy_extent(&self) -> f32 {
+        self.extent
+    }
+
+    /// Use a new screen size in pixels
+    pub fn set_viewport(&mut self, viewport: Vec2) {
+        self.viewport = viewport;
+        self.clamp_target();
+    }
+
+    /// World position shown in the middle of the screen
+    pub fn get_center(&self) -> Vector2 {
+        self.center
+    }
+
+    /// Pixels per AU
+    pub fn get_zoom(&self) -> f32 {
+        self.zoom
+    }
+
+    /// Where the view is headed
+    pub fn get_target_center(&self) -> Vector2 {
+        self.target_center
+    }
+
+    /// Zoom the view is headed to
+    pub fn get_target_zoom(&self) -> f32 {
+        self.target_zoom
+    }
+
+    /// Farthest zoom, with the whole galaxy on screen
+    pub fn min_zoom(&self) -> f32 {
+        (self.viewport.x.min(self.viewport.y) / (2.0 * self.extent)).min(MAX_ZOOM)
+    }
+
+    /// Screen point in pixels of a world position
+    pub fn world_to_screen(&self, position: Vector2) -> Vec2 {
+        Vec2::new(
+            self.viewport.x / 2.0 + (position.x - self.center.x) * self.zoom,
+            self.viewport.y / 2.0 + (position.y - self.center.y) * self.zoom,
+        )
+    }
+
+    /// World position under a screen point
+    pub fn screen_to_world(&self, point: Vec2) -> Vector2 {
+        Vector2::new(
+            self.center.x + (point.x - self.viewport.x / 2.0) / self.zoom,
+            self.center.y + (point.y - self.viewport.y / 2.0) / self.zoom,
+        )
+    }
+
+    /// Move the view by a distance in pixels, which stops following
+    pub fn pan_by(&mut self, screen_delta: Vec2) {
+        self.follow = None;
+        self.target_center.x += screen_delta.x / self.target_zoom;
+        self.target_center.y += screen_delta.y / self.target_zoom;
+        self.clamp_target();
+    }
+
+    /// Glide to a world position, which stops following
+    pub fn center_on(&mut self, position: Vector2) {
+        self.follow = None;
+        self.target_center = position;
+        self.clamp_target();
+    }
+
+    /// Glide to a zoom in pixels per AU, within the zoom limits
+    pub fn zoom_to(&mut self, zoom: f32) {
+        self.target_zoom = zoom;
+        self.clamp_target();
+    }
+
+    /// Zoom in by `steps` wheel steps, or out when negative, keeping the
+    /// world point under `screen_point` where it is. While following, the
+    /// followed entity stays centered instead.
+    pub fn zoom_at(&mut self, screen_point: Vec2, steps: f32) {
+        let anchor = self.screen_to_world(screen_point);
+        self.target_zoom = (self.target_zoom * ZOOM_STEP.powf(steps)).clamp(self.min_zoom(), MAX_ZOOM);
+        if self.follow.is_none() {
+            self.target_center = Vector2::new(
+                anchor.x - (screen_point.x - self.viewport.x / 2.0) / self.target_zoom,
+                anchor.y - (screen_point.y - self.viewport.y / 2.0) / self.target_zoom,
+            );
+        }
+        self.clamp_target();
+    }
+
+    /// Keep the entity centered, or stop following with `None`
+    pub fn follow(&mut self, target: Option<CameraTarget>) {
+        self.follow = target;
+    }
+
+    /// Entity being followed
+    pub fn get_follow_target(&self) -> Option<CameraTarget> {
+        self.follow
+    }
+
+    /// Ease the view toward its target. `followed_position` is where the
+    /// followed entity is now; `None` while following means it is gone or
+    /// out of sight, which stops following.
+    pub fn update(&mut self, delta_time: f32, followed_position: Option<Vector2>) {
+        if self.follow.is_some() {
+            match followed_position {
+                Some(position) => self.target_center = position,
+                None => self.follow = None,
+            }
+            self.clamp_target();
+        }
+
+        let t = 1.0 - (-CAMERA_SMOOTHING * delta_time.max(0.0)).exp();
+        self.center.x += (self.target_center.x - self.center.x) * t;
+        self.center.y += (self.target_center.y - self.center.y) * t;
+        self.zoom += (self.target_zoom - self.zoom) * t;
+        // Settle exactly once the remaining move is below a pixel
+        if self.center.distance_to(&self.target_center) * self.zoom < 0.5 && (self.target_zoom - self.zoom).abs() < 0.01 {
+            self.snap();
+        }
+    }
+
+    /// Jump straight to the target
+    pub fn snap(&mut self) {
+        self.center = self.target_center;
+        self.zoom = self.target_zoom;
+    }
+
+    fn clamp_target(&mut self) {
+        self.target_zoom = self.target_zoom.clamp(self.min_zoom(), MAX_ZOOM);
+        let distance = self.target_center.magnitude();
+        if distance > self.extent {
+            let scale = self.extent / distance;
+            self.target_center = Vector2::new(self.target_center.x * scale, self.target_center.y * scale);
+        }
+    }
+}
@@ -0,0 +1,130 @@
+// src/ui_v2/core/dialog_manager.rs
+//! Modal dialog stack
+//!
+//! Dialogs are drawn above all other views and the topmost dialog receives
+//! all input while any dialog is open. A dialog closes itself by becoming
+//! invisible; the manager drops it on the next input or update.
+
+use super::{RenderContext, ComponentResult, InputEvent, Tooltip};
+use macroquad::prelude::Rect;
+use crate::ui_v2::View;
+
+/// Identifier of an open dialog
+pub type DialogId = u32;
+
+/// Stack of open modal dialogs, topmost last
+pub struct DialogManager {
+    dialogs: Vec<(DialogId, Box<dyn View>)>,
+    next_id: DialogId,
+}
+
+impl DialogManager {
+    /// No dialogs open
+    pub fn new() -> Self {
+        Self {
+            dialogs: Vec::new(),
+            next_id: 0,
+        }
+    }
+
+    /// Open a dialog on top of any others
+    pub fn open(&mut self, mut dialog: Box<dyn View>) -> DialogId {
+        let id = self.next_id;
+        self.next_id += 1;
+        dialog.set_visible(true);
+        self.dialogs.push((id, dialog));
+        id
+    }
+
+    /// Close a dialog by id; returns false if it was not open
+    pub fn close(&mut self, id: DialogId) -> bool {
+        let before = self.dialogs.len();
+        self.dialogs.retain(|(dialog_id, _)| *dialog_id != id);
+        self.dialogs.len() != before
+    }
+
+    /// Close the topmost dialog, returning its view type
+    pub fn close_top(&mut self) -> Option<&'static str> {
+        self.dialogs.pop().map(|(_, dialog)| dialog.get_view_type())
+    }
+
+    /// Close every dialog of the given view type
+    pub fn close_type(&mut self, view_type: &str) {
+        self.dialogs.retain(|(_, dialog)| dialog.get_view_type() != view_type);
+    }
+
+    /// Close every dialog
+    pub fn close_all(&mut self) {
+        self.dialogs.clear();
+    }
+
+    /// True while any dialog is open and blocking input to other views
+    pub fn is_active(&self) -> bool {
+        !self.dialogs.is_empty()
+    }
+
+    /// True if a dialog of the view type is open
+    pub fn is_open(&self, view_type: &str) -> bool {
+        self.dialogs.iter().any(|(_, dialog)| dialog.get_view_type() == view_type)
+    }
+
+    /// View type of the dialog currently receiving input
+    pub fn top_view_type(&self) -> Option<&'static str> {
+        self.dialogs.last().map(|(_, dialog)| dialog.get_view_type())
+    }
+
+    /// Number of open dialogs
+    pub fn len(&self) -> usize {
+        self.dialogs.len()
+    }
+
+    /// True when no dialog is open
+    pub fn is_empty(&self) -> bool {
+        self.dialogs.is_empty()
+    }
+
+    /// Route input to the topmost dialog only
+    pub fn handle_input(&mut self, input: &InputEvent) -> ComponentResult {
+        let result = match self.dialogs.last_mut() {
+            Some((_, dialog)) => dialog.handle_input(input),
+            None => Ok(None),
+        };
+        self.remove_closed();
+        result
+    }
+
+    /// Update every open dialog and drop those that closed
+    pub fn update(&mut self, delta_time: f32) -> ComponentResult {
+        for (_, dialog) in &mut self.dialogs {
+            dialog.update(delta_time)?;
+        }
+        self.remove_closed();
+        Ok(None)
+    }
+
+    /// Tooltip regions of the topmost dialog, the only one the cursor can reach
+    pub fn tooltips(&self) -> Vec<(Rect, Tooltip)> {
+        self.dialogs.last()
+            .filter(|(_, dialog)| dialog.is_visible())
+            .map(|(_, dialog)| dialog.tooltips())
+            .unwrap_or_default()
+    }
+
+    /// Render dialogs bottom to top
+    pub fn render(&mut self, context: &RenderContext) -> ComponentResult {
+        for (_, dialog) in &mut self.dialogs {
+            dialog.render(context)?;
+        }
+        Ok(None)
+    }
+
+    fn remove_closed(&mut self) {
+        self.dialogs.retain(|(_, dialog)| dialog.is_visible());
+    }
+}
+
+impl Default for DialogManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
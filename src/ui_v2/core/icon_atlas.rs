@@ -0,0 +1,120 @@
+// src/ui_v2/core/icon_atlas.rs
+//! Planet and ship icons for the galaxy map
+//!
+//! An icon atlas is an image holding a row of square cells as tall as the
+//! image: a planet first, then a ship of each class in `ShipClass::ALL`
+//! order. Icons are drawn white on transparent and tinted with the owner's
+//! color when drawn, so one atlas serves every faction.
+//!
+//! Sprites only pay off up close. Below [`SPRITE_ZOOM`] the map keeps its
+//! dots, and it keeps them at every zoom when no atlas is installed at
+//! [`ICON_ATLAS_PATH`], so the game looks as it always did without assets.
+
+use crate::core::types::*;
+use macroquad::prelude::*;
+use std::path::Path;
+
+/// Where the client looks for an icon atlas
+pub const ICON_ATLAS_PATH: &str = "assets/icons.png";
+/// Zoom in pixels per AU from which planets and ships are drawn as sprites
+pub const SPRITE_ZOOM: f32 = 60.0;
+/// On-screen size of a planet sprite, in pixels
+pub const PLANET_ICON_SIZE: f32 = 20.0;
+/// On-screen size of a ship sprite, in pixels
+pub const SHIP_ICON_SIZE: f32 = 14.0;
+/// Cells in an atlas: the planet and one per ship class
+pub const ICON_CELLS: usize = 1 + ShipClass::ALL.len();
+
+/// A picture in the atlas
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Icon {
+    /// Planet icon
+    Planet,
+    /// Icon for the ship class
+    Ship(ShipClass),
+}
+
+impl Icon {
+    fn cell(self) -> usize {
+        match self {
+            Icon::Planet => 0,
+            Icon::Ship(class) => 1 + ShipClass::ALL.iter().position(|&other| other == class).unwrap_or(0),
+        }
+    }
+}
+
+/// How much detail the map draws entities with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IconDetail {
+    /// Plain coloured dots, when zoomed out
+    Dot,
+    /// Sprites from the atlas, when zoomed in
+    Sprite,
+}
+
+impl IconDetail {
+    /// Detail for a camera zoom in pixels per AU
+    pub fn at_zoom(zoom: f32) -> Self {
+        if zoom >= SPRITE_ZOOM {
+            IconDetail::Sprite
+        } else {
+            IconDetail::Dot
+        }
+    }
+}
+
+/// Where each icon sits in an atlas image
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AtlasLayout {
+    cell: f32,
+}
+
+impl AtlasLayout {
+    /// Layout of an atlas image of the given size, which must be wide
+    /// enough for every cell
+    pub fn for_size(width: u32, height: u32) -> GameResult<Self> {
+        if height == 0 || (width as usize) < height as usize * ICON_CELLS {
+            return Err(GameError::InvalidOperation(format!(
+                "Icon atlas is {}x{}; it needs {} square cells in a row",
+                width, height, ICON_CELLS
+            )));
+        }
+        Ok(Self { cell: height as f32 })
+    }
+
+    /// The icon's cell in image pixels
+    pub fn source(&self, icon: Icon) -> Rect {
+        Rect::new(icon.cell() as f32 * self.cell, 0.0, self.cell, self.cell)
+    }
+}
+
+/// A loaded icon atlas
+pub struct IconAtlas {
+    texture: Texture2D,
+    layout: AtlasLayout,
+}
+
+impl IconAtlas {
+    /// Load an atlas image; Ok(None) when there is no file, so the map
+    /// keeps drawing primitives
+    pub fn load_from_file(path: &Path) -> GameResult<Option<Self>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let image = Image::from_file_with_format(&std::fs::read(path)?, None)
+            .map_err(|e| GameError::InvalidOperation(format!("Cannot read icon atlas {}: {}", path.display(), e)))?;
+        let layout = AtlasLayout::for_size(image.width as u32, image.height as u32)?;
+        let texture = Texture2D::from_image(&image);
+        texture.set_filter(FilterMode::Linear);
+        Ok(Some(Self { texture, layout }))
+    }
+
+    /// Draw an icon `size` pixels across centered on a screen point, tinted
+    pub fn draw(&self, icon: Icon, x: f32, y: f32, size: f32, tint: Color) {
+        draw_texture_ex(&self.texture, x - size / 2.0, y - size / 2.0, tint, DrawTextureParams {
+            dest_size: Some(vec2(size, size)),
+            source: Some(self.layout.source(icon)),
+            ..Default::default()
+        });
+    }
+}
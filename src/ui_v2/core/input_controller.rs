@@ -6,6 +6,44 @@ use crate::core::events::PlayerCommand;
 use macroquad::prelude::Vec2;
 use macroquad::prelude::*;
 
+/// Limits protecting the UI pipeline from input floods
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputConfig {
+    /// Maximum events delivered per frame; release events are always kept
+    pub max_events_per_frame: usize,
+    /// Merge consecutive mouse moves and scrolls into a single event
+    pub coalesce_motion: bool,
+    /// Ignore repeat clicks of the same button within this many seconds
+    pub click_debounce: f32,
+}
+
+impl Default for InputConfig {
+    fn default() -> Self {
+        Self {
+            max_events_per_frame: 64,
+            coalesce_motion: true,
+            click_debounce: 0.05, // 50ms
+        }
+    }
+}
+
+/// Counters for input events removed before reaching the views
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InputMetrics {
+    /// Batches of events filtered
+    pub frames_processed: u64,
+    /// Events that came in
+    pub events_received: u64,
+    /// Mouse moves and scrolls merged into the one before
+    pub events_coalesced: u64,
+    /// Click bounces and their releases swallowed
+    pub events_debounced: u64,
+    /// Events dropped over the per-frame cap
+    pub events_dropped: u64,
+    /// Frames that went over the per-frame cap
+    pub frames_over_budget: u64,
+}
+
 /// Processes raw input and converts it to UI events
 pub struct InputController {
     last_mouse_pos: Vec2,
@@ -14,10 +52,19 @@ pub struct InputController {
     double_click_timer: f32,
     last_click_pos: Vec2,
     double_click_threshold: f32,
+    config: InputConfig,
+    metrics: InputMetrics,
+    click_cooldown: [f32; 3],
+    suppressed_release: [bool; 3],
 }
 
 impl InputController {
     pub fn new() -> Self {
+        Self::with_config(InputConfig::default())
+    }
+
+    /// Create a controller with custom flood protection limits
+    pub fn with_config(config: InputConfig) -> Self {
         Self {
             last_mouse_pos: Vec2::new(0.0, 0.0),
             mouse_pressed: [false; 3],
@@ -25,6 +72,10 @@ impl InputController {
             double_click_timer: 0.0,
             last_click_pos: Vec2::new(0.0, 0.0),
             double_click_threshold: 0.3, // 300ms
+            config,
+            metrics: InputMetrics::default(),
+            click_cooldown: [0.0; 3],
+            suppressed_release: [false; 3],
         }
     }
 
@@ -36,6 +87,7 @@ impl InputController {
         if self.double_click_timer > 0.0 {
             self.double_click_timer -= delta_time;
         }
+        self.advance_debounce(delta_time);
 
         // Mouse position
         let current_mouse = Vec2::from(mouse_position());
@@ -63,9 +115,114 @@ impl InputController {
             });
         }
 
+        self.filter_events(events)
+    }
+
+    /// Apply coalescing, click debounce and the per-frame cap to a batch of events.
+    ///
+    /// `process_input` runs every polled frame through this; injected or replayed
+    /// events should use it too so they get the same protection.
+    pub fn filter_events(&mut self, raw: Vec<InputEvent>) -> Vec<InputEvent> {
+        self.metrics.frames_processed += 1;
+        self.metrics.events_received += raw.len() as u64;
+
+        let mut events: Vec<InputEvent> = Vec::with_capacity(raw.len().min(self.config.max_events_per_frame));
+        for event in raw {
+            match event {
+                InputEvent::MouseClick { button, .. } => {
+                    if let Some(index) = button_index(button) {
+                        if self.click_cooldown[index] > 0.0 {
+                            // Swallow the bounce and the release that belongs to it
+                            self.suppressed_release[index] = true;
+                            self.metrics.events_debounced += 1;
+                            continue;
+                        }
+                        self.click_cooldown[index] = self.config.click_debounce;
+                    }
+                    events.push(event);
+                }
+                InputEvent::MouseRelease { button, .. } => {
+                    if let Some(index) = button_index(button) {
+                        if self.suppressed_release[index] {
+                            self.suppressed_release[index] = false;
+                            self.metrics.events_debounced += 1;
+                            continue;
+                        }
+                    }
+                    events.push(event);
+                }
+                InputEvent::MouseMove { .. } if self.config.coalesce_motion => {
+                    if let Some(last @ InputEvent::MouseMove { .. }) = events.last_mut() {
+                        *last = event;
+                        self.metrics.events_coalesced += 1;
+                    } else {
+                        events.push(event);
+                    }
+                }
+                InputEvent::Scroll { x, y, delta } if self.config.coalesce_motion => {
+                    if let Some(InputEvent::Scroll { x: last_x, y: last_y, delta: last_delta }) = events.last_mut() {
+                        *last_x = x;
+                        *last_y = y;
+                        *last_delta += delta;
+                        self.metrics.events_coalesced += 1;
+                    } else {
+                        events.push(event);
+                    }
+                }
+                _ => events.push(event),
+            }
+        }
+
+        if events.len() > self.config.max_events_per_frame {
+            self.metrics.frames_over_budget += 1;
+            // Releases are never dropped so no button or key is left stuck down
+            let mut budget = self.config.max_events_per_frame;
+            let mut dropped = 0;
+            events.retain(|event| {
+                if matches!(event, InputEvent::MouseRelease { .. } | InputEvent::KeyRelease { .. }) {
+                    return true;
+                }
+                if budget > 0 {
+                    budget -= 1;
+                    true
+                } else {
+                    dropped += 1;
+                    false
+                }
+            });
+            self.metrics.events_dropped += dropped;
+        }
+
         events
     }
 
+    /// Advance click debounce timers by the frame time
+    pub fn advance_debounce(&mut self, delta_time: f32) {
+        for cooldown in &mut self.click_cooldown {
+            *cooldown = (*cooldown - delta_time).max(0.0);
+        }
+    }
+
+    /// Current flood protection limits
+    pub fn get_config(&self) -> &InputConfig {
+        &self.config
+    }
+
+    /// Replace the flood protection limits
+    pub fn set_config(&mut self, config: InputConfig) {
+        self.config = config;
+    }
+
+    /// Counters of coalesced, debounced and dropped events since the last reset
+    pub fn get_metrics(&self) -> &InputMetrics {
+        &self.metrics
+    }
+
+    /// Reset the input counters
+    pub fn reset_metrics(&mut self) {
+        self.metrics = InputMetrics::default();
+    }
+
     fn process_mouse_buttons(&mut self, events: &mut Vec<InputEvent>, mouse_pos: Vec2) {
         let buttons = [MouseButton::Left, MouseButton::Right, MouseButton::Middle];
         
@@ -146,10 +303,6 @@ impl InputController {
                             // Could open help
                             commands.push(PlayerCommand::GameOptions);
                         }
-                        KeyCode::Space => {
-                            // Could pause/unpause
-                            commands.push(PlayerCommand::PauseGame(true));
-                        }
                         _ => {}
                     }
                 }
@@ -176,6 +329,15 @@ impl InputController {
     }
 }
 
+fn button_index(button: MouseButton) -> Option<usize> {
+    match button {
+        MouseButton::Left => Some(0),
+        MouseButton::Right => Some(1),
+        MouseButton::Middle => Some(2),
+        _ => None,
+    }
+}
+
 impl Default for InputController {
     fn default() -> Self {
         Self::new()
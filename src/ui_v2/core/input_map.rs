@@ -0,0 +1,326 @@
+// src/ui_v2/core/input_map.rs
+//! Rebindable keyboard controls
+//!
+//! The client asks the [`InputMap`] which key triggers each [`InputAction`]
+//! instead of testing fixed keys. Every action has exactly one key and no
+//! two actions share one: binding a key that is taken swaps the two
+//! actions' keys. The map is saved with the client's other settings in
+//! `settings.toml`, and actions missing from the file keep their default key.
+
+use crate::core::{GameError, GameResult};
+use macroquad::prelude::KeyCode;
+use serde::de::Error as _;
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::{BTreeMap, HashMap};
+
+/// Something the player can do with a key
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InputAction {
+    /// Open the pause menu, close the top dialog, or quit from the main menu
+    Menu,
+    /// Start a new game from the main menu
+    NewGame,
+    /// Load a saved game from the main menu
+    LoadGame,
+    /// Re-roll the starting layout in the new game menu
+    RerollLayout,
+    /// Step through the difficulty levels in the new game menu
+    CycleDifficulty,
+    /// Open the key bindings view
+    OpenKeyBindings,
+    /// Open the resource market
+    OpenMarket,
+    /// Open the timeline of notable events
+    OpenTimeline,
+    /// Open the overview of every planet in the empire
+    OpenEmpireOverview,
+    /// Open the overview of every ship in the fleet
+    OpenFleetOverview,
+    /// Open the statistics graphs
+    OpenGraphs,
+    /// Open the wizard that sets up a trade route for an idle transport
+    OpenShuttleRun,
+    /// Open the inbox of pending offers and prompts
+    OpenInbox,
+    /// Pause or resume the simulation
+    TogglePause,
+    /// Run a single tick while paused
+    StepTick,
+    /// Drop to the next slower game speed
+    SlowDown,
+    /// Go up to the next faster game speed
+    SpeedUp,
+    /// Fast-forward the simulation
+    FastForward,
+    /// Pan the camera left
+    PanLeft,
+    /// Pan the camera right
+    PanRight,
+    /// Pan the camera up
+    PanUp,
+    /// Pan the camera down
+    PanDown,
+    /// Zoom the camera in
+    ZoomIn,
+    /// Zoom the camera out
+    ZoomOut,
+    /// Keep the camera on the selected planet or ship
+    FollowSelection,
+    /// Select the player's next ship
+    SelectNextShip,
+    /// Switch to the next UI theme
+    CycleTheme,
+    /// Switch between one star system's planets and the map of all stars and lanes
+    ToggleGalaxyMap,
+}
+
+impl InputAction {
+    /// Every action, in the order the key bindings view and file list them
+    pub const ALL: [InputAction; 28] = [
+        InputAction::Menu,
+        InputAction::NewGame,
+        InputAction::LoadGame,
+        InputAction::RerollLayout,
+        InputAction::CycleDifficulty,
+        InputAction::OpenKeyBindings,
+        InputAction::OpenMarket,
+        InputAction::OpenTimeline,
+        InputAction::OpenEmpireOverview,
+        InputAction::OpenFleetOverview,
+        InputAction::OpenGraphs,
+        InputAction::OpenShuttleRun,
+        InputAction::OpenInbox,
+        InputAction::TogglePause,
+        InputAction::StepTick,
+        InputAction::SlowDown,
+        InputAction::SpeedUp,
+        InputAction::FastForward,
+        InputAction::PanLeft,
+        InputAction::PanRight,
+        InputAction::PanUp,
+        InputAction::PanDown,
+        InputAction::ZoomIn,
+        InputAction::ZoomOut,
+        InputAction::FollowSelection,
+        InputAction::SelectNextShip,
+        InputAction::CycleTheme,
+        InputAction::ToggleGalaxyMap,
+    ];
+
+    /// Name used in the bindings file
+    pub fn name(&self) -> &'static str {
+        match self {
+            InputAction::Menu => "Menu",
+            InputAction::NewGame => "NewGame",
+            InputAction::LoadGame => "LoadGame",
+            InputAction::RerollLayout => "RerollLayout",
+            InputAction::CycleDifficulty => "CycleDifficulty",
+            InputAction::OpenKeyBindings => "OpenKeyBindings",
+            InputAction::OpenMarket => "OpenMarket",
+            InputAction::OpenTimeline => "OpenTimeline",
+            InputAction::OpenEmpireOverview => "OpenEmpireOverview",
+            InputAction::OpenFleetOverview => "OpenFleetOverview",
+            InputAction::OpenGraphs => "OpenGraphs",
+            InputAction::OpenShuttleRun => "OpenShuttleRun",
+            InputAction::OpenInbox => "OpenInbox",
+            InputAction::TogglePause => "TogglePause",
+            InputAction::StepTick => "StepTick",
+            InputAction::SlowDown => "SlowDown",
+            InputAction::SpeedUp => "SpeedUp",
+            InputAction::FastForward => "FastForward",
+            InputAction::PanLeft => "PanLeft",
+            InputAction::PanRight => "PanRight",
+            InputAction::PanUp => "PanUp",
+            InputAction::PanDown => "PanDown",
+            InputAction::ZoomIn => "ZoomIn",
+            InputAction::ZoomOut => "ZoomOut",
+            InputAction::FollowSelection => "FollowSelection",
+            InputAction::SelectNextShip => "SelectNextShip",
+            InputAction::CycleTheme => "CycleTheme",
+            InputAction::ToggleGalaxyMap => "ToggleGalaxyMap",
+        }
+    }
+
+    /// What the action does, as shown in the key bindings view
+    pub fn label(&self) -> &'static str {
+        match self {
+            InputAction::Menu => "Menu / close dialog",
+            InputAction::NewGame => "New game",
+            InputAction::LoadGame => "Load game",
+            InputAction::RerollLayout => "Re-roll starting layout",
+            InputAction::CycleDifficulty => "Change difficulty",
+            InputAction::OpenKeyBindings => "Key bindings",
+            InputAction::OpenMarket => "Market",
+            InputAction::OpenTimeline => "Timeline",
+            InputAction::OpenEmpireOverview => "Empire overview",
+            InputAction::OpenFleetOverview => "Fleet overview",
+            InputAction::OpenGraphs => "Graphs",
+            InputAction::OpenShuttleRun => "Set up shuttle run",
+            InputAction::OpenInbox => "Inbox",
+            InputAction::TogglePause => "Pause / resume",
+            InputAction::StepTick => "Step one tick (paused)",
+            InputAction::SlowDown => "Slower",
+            InputAction::SpeedUp => "Faster",
+            InputAction::FastForward => "Fast-forward",
+            InputAction::PanLeft => "Pan left",
+            InputAction::PanRight => "Pan right",
+            InputAction::PanUp => "Pan up",
+            InputAction::PanDown => "Pan down",
+            InputAction::ZoomIn => "Zoom in",
+            InputAction::ZoomOut => "Zoom out",
+            InputAction::FollowSelection => "Follow selection",
+            InputAction::SelectNextShip => "Select next ship",
+            InputAction::CycleTheme => "Switch theme",
+            InputAction::ToggleGalaxyMap => "Galaxy / system map",
+        }
+    }
+
+    /// The action with this display name, if any
+    pub fn from_name(name: &str) -> Option<InputAction> {
+        InputAction::ALL.into_iter().find(|action| action.name() == name)
+    }
+
+    /// Key the action starts out on
+    pub fn default_key(&self) -> KeyCode {
+        match self {
+            InputAction::Menu => KeyCode::Escape,
+            InputAction::NewGame => KeyCode::N,
+            InputAction::LoadGame => KeyCode::L,
+            InputAction::RerollLayout => KeyCode::R,
+            InputAction::CycleDifficulty => KeyCode::D,
+            InputAction::OpenKeyBindings => KeyCode::K,
+            InputAction::OpenMarket => KeyCode::M,
+            InputAction::OpenTimeline => KeyCode::T,
+            InputAction::OpenEmpireOverview => KeyCode::E,
+            InputAction::OpenFleetOverview => KeyCode::S,
+            InputAction::OpenGraphs => KeyCode::C,
+            InputAction::OpenShuttleRun => KeyCode::H,
+            InputAction::OpenInbox => KeyCode::I,
+            InputAction::TogglePause => KeyCode::Space,
+            InputAction::StepTick => KeyCode::Period,
+            InputAction::SlowDown => KeyCode::Minus,
+            InputAction::SpeedUp => KeyCode::Equal,
+            InputAction::FastForward => KeyCode::G,
+            InputAction::PanLeft => KeyCode::Left,
+            InputAction::PanRight => KeyCode::Right,
+            InputAction::PanUp => KeyCode::Up,
+            InputAction::PanDown => KeyCode::Down,
+            InputAction::ZoomIn => KeyCode::PageUp,
+            InputAction::ZoomOut => KeyCode::PageDown,
+            InputAction::FollowSelection => KeyCode::F,
+            InputAction::SelectNextShip => KeyCode::Tab,
+            InputAction::CycleTheme => KeyCode::F2,
+            InputAction::ToggleGalaxyMap => KeyCode::V,
+        }
+    }
+}
+
+/// Keys that can be bound, with their names in the bindings file
+const KEY_NAMES: &[(KeyCode, &str)] = &[
+    (KeyCode::A, "A"), (KeyCode::B, "B"), (KeyCode::C, "C"), (KeyCode::D, "D"),
+    (KeyCode::E, "E"), (KeyCode::F, "F"), (KeyCode::G, "G"), (KeyCode::H, "H"),
+    (KeyCode::I, "I"), (KeyCode::J, "J"), (KeyCode::K, "K"), (KeyCode::L, "L"),
+    (KeyCode::M, "M"), (KeyCode::N, "N"), (KeyCode::O, "O"), (KeyCode::P, "P"),
+    (KeyCode::Q, "Q"), (KeyCode::R, "R"), (KeyCode::S, "S"), (KeyCode::T, "T"),
+    (KeyCode::U, "U"), (KeyCode::V, "V"), (KeyCode::W, "W"), (KeyCode::X, "X"),
+    (KeyCode::Y, "Y"), (KeyCode::Z, "Z"),
+    (KeyCode::Key0, "0"), (KeyCode::Key1, "1"), (KeyCode::Key2, "2"), (KeyCode::Key3, "3"),
+    (KeyCode::Key4, "4"), (KeyCode::Key5, "5"), (KeyCode::Key6, "6"), (KeyCode::Key7, "7"),
+    (KeyCode::Key8, "8"), (KeyCode::Key9, "9"),
+    (KeyCode::F1, "F1"), (KeyCode::F2, "F2"), (KeyCode::F3, "F3"), (KeyCode::F4, "F4"),
+    (KeyCode::F5, "F5"), (KeyCode::F6, "F6"), (KeyCode::F7, "F7"), (KeyCode::F8, "F8"),
+    (KeyCode::F9, "F9"), (KeyCode::F10, "F10"), (KeyCode::F11, "F11"), (KeyCode::F12, "F12"),
+    (KeyCode::Left, "Left"), (KeyCode::Right, "Right"), (KeyCode::Up, "Up"), (KeyCode::Down, "Down"),
+    (KeyCode::PageUp, "PageUp"), (KeyCode::PageDown, "PageDown"), (KeyCode::Home, "Home"), (KeyCode::End, "End"),
+    (KeyCode::Escape, "Escape"), (KeyCode::Space, "Space"), (KeyCode::Enter, "Enter"), (KeyCode::Tab, "Tab"),
+    (KeyCode::Backspace, "Backspace"), (KeyCode::Delete, "Delete"), (KeyCode::Insert, "Insert"),
+    (KeyCode::Minus, "Minus"), (KeyCode::Equal, "Equal"), (KeyCode::KpAdd, "KpAdd"), (KeyCode::KpSubtract, "KpSubtract"),
+    (KeyCode::Comma, "Comma"), (KeyCode::Period, "Period"),
+];
+
+/// Name of a key in the bindings file; None for keys that cannot be bound
+pub fn key_name(key: KeyCode) -> Option<&'static str> {
+    KEY_NAMES.iter().find(|(code, _)| *code == key).map(|(_, name)| *name)
+}
+
+/// Key with the given name in the bindings file
+pub fn key_from_name(name: &str) -> Option<KeyCode> {
+    KEY_NAMES.iter().find(|(_, key)| *key == name).map(|(code, _)| *code)
+}
+
+/// Which key triggers each action
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputMap {
+    bindings: HashMap<InputAction, KeyCode>,
+}
+
+impl InputMap {
+    /// Every action on its default key
+    pub fn new() -> Self {
+        Self {
+            bindings: InputAction::ALL.into_iter().map(|action| (action, action.default_key())).collect(),
+        }
+    }
+
+    /// Key bound to the action
+    pub fn key(&self, action: InputAction) -> KeyCode {
+        self.bindings[&action]
+    }
+
+    /// Action bound to the key, if any
+    pub fn action_for(&self, key: KeyCode) -> Option<InputAction> {
+        InputAction::ALL.into_iter().find(|action| self.bindings[action] == key)
+    }
+
+    /// Bind the action to the key. An action already on the key takes the
+    /// action's old key. Fails for keys [`key_name`] does not know.
+    pub fn bind(&mut self, action: InputAction, key: KeyCode) -> GameResult<()> {
+        if key_name(key).is_none() {
+            return Err(GameError::InvalidOperation(format!("{:?} cannot be bound", key)));
+        }
+        let old_key = self.key(action);
+        if let Some(other) = self.action_for(key) {
+            self.bindings.insert(other, old_key);
+        }
+        self.bindings.insert(action, key);
+        Ok(())
+    }
+
+    /// Put every action back on its default key
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl Default for InputMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Written as a table of action names to key names, in [`InputAction::ALL`] order
+impl Serialize for InputMap {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(InputAction::ALL.len()))?;
+        for action in InputAction::ALL {
+            map.serialize_entry(action.name(), key_name(self.key(action)).unwrap_or("?"))?;
+        }
+        map.end()
+    }
+}
+
+/// Read from a table of action names to key names, bound over the defaults
+/// so actions missing from it keep their default key
+impl<'de> Deserialize<'de> for InputMap {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut map = Self::new();
+        for (name, key) in BTreeMap::<String, String>::deserialize(deserializer)? {
+            let action = InputAction::from_name(&name)
+                .ok_or_else(|| D::Error::custom(format!("unknown action '{}'", name)))?;
+            let key = key_from_name(&key).ok_or_else(|| D::Error::custom(format!("unknown key '{}'", key)))?;
+            map.bind(action, key).map_err(D::Error::custom)?;
+        }
+        Ok(map)
+    }
+}
@@ -0,0 +1,86 @@
+// src/ui_v2/core/interpolation.rs
+//! Smooth map motion between fixed updates
+//!
+//! The simulation moves planets and ships once per tick, but frames come
+//! far more often. [`MotionBuffer::record`] keeps where every planet and
+//! ship was at the end of the previous fixed update and where it is now;
+//! the map draws them between the two by the fraction of the next fixed
+//! update that has already elapsed. Drawing lags the simulation by at most
+//! one fixed update in exchange for motion without jumps. Updates that run
+//! no tick, while paused or at slow speeds, leave both ends the same, so
+//! nothing drifts.
+
+use super::camera::CameraTarget;
+use crate::core::types::*;
+use crate::GameState;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Default)]
+struct Positions {
+    planets: HashMap<PlanetId, Vector2>,
+    ships: HashMap<ShipId, Vector2>,
+}
+
+/// Planet and ship positions at the last two fixed updates
+#[derive(Debug, Clone, Default)]
+pub struct MotionBuffer {
+    previous: Positions,
+    current: Positions,
+}
+
+impl MotionBuffer {
+    /// Empty buffer; entities are drawn where they are until recorded twice
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Note where everything is after a fixed update
+    pub fn record(&mut self, game: &GameState) {
+        let tick = game.get_current_tick();
+        let current = Positions {
+            planets: game.planet_manager.get_all_planets().iter()
+                .map(|planet| (planet.id, game.physics_engine.planet_position(planet, tick)))
+                .collect(),
+            ships: game.ship_manager.get_all_ships().iter()
+                .map(|ship| (ship.id, ship.position))
+                .collect(),
+        };
+        self.previous = std::mem::replace(&mut self.current, current);
+    }
+
+    /// Forget every position, for when the world is replaced
+    pub fn clear(&mut self) {
+        self.previous = Positions::default();
+        self.current = Positions::default();
+    }
+
+    /// Where to draw a planet `alpha` of the way from the previous fixed
+    /// update to the last; None for planets not recorded yet
+    pub fn planet_position(&self, id: PlanetId, alpha: f32) -> Option<Vector2> {
+        Self::blend(self.previous.planets.get(&id), self.current.planets.get(&id)?, alpha)
+    }
+
+    /// Where to draw a ship `alpha` of the way from the previous fixed
+    /// update to the last; None for ships not recorded yet
+    pub fn ship_position(&self, id: ShipId, alpha: f32) -> Option<Vector2> {
+        Self::blend(self.previous.ships.get(&id), self.current.ships.get(&id)?, alpha)
+    }
+
+    /// Where to draw a planet or ship
+    pub fn position(&self, target: CameraTarget, alpha: f32) -> Option<Vector2> {
+        match target {
+            CameraTarget::Planet(id) => self.planet_position(id, alpha),
+            CameraTarget::Ship(id) => self.ship_position(id, alpha),
+        }
+    }
+
+    /// New entities appear where they are rather than sliding in from nowhere
+    fn blend(previous: Option<&Vector2>, current: &Vector2, alpha: f32) -> Option<Vector2> {
+        let previous = previous.unwrap_or(current);
+        let alpha = alpha.clamp(0.0, 1.0);
+        Some(Vector2::new(
+            previous.x + (current.x - previous.x) * alpha,
+            previous.y + (current.y - previous.y) * alpha,
+        ))
+    }
+}
@@ -5,21 +5,63 @@
 //! - RenderContext: Rendering state and utilities
 //! - ComponentResult: Standardized component results
 //! - UISystem: Main coordinator for all UI operations
+//! - DialogManager: Modal dialog stack above all views
+//! - Camera: Pan, zoom and follow on the galaxy map
+//! - Picking: Resolving map clicks to the planet or ship under the cursor
+//! - DragTracker: Telling map clicks from drag-select boxes
+//! - MotionBuffer: Planet and ship positions to draw between fixed updates
+//! - RenderBatcher: Map primitives drawn as a few batched meshes
+//! - IconAtlas: Optional planet and ship sprites for close zoom
+//! - Selection: Selected planets and ships, and control group recall
+//! - OrderResolver: Orders the selection can be given from the context menu
+//! - TooltipManager: Delayed hover tooltips registered by views and the HUD
+//! - ui_scale: UI scale, drawing and input in scaled UI units
+//! - InputMap: Rebindable keys for the client's input actions
+//! - ThemeManager: Built-in themes switched at runtime
+//! - UIStateCache: Planet summaries and faction totals updated from state changes
 //! - Event types and routing
 
 pub mod ui_system;
 pub mod view_controller;
 pub mod input_controller;
 pub mod render_context;
+pub mod dialog_manager;
+pub mod camera;
+pub mod picking;
+pub mod box_select;
+pub mod interpolation;
+pub mod render_batch;
+pub mod icon_atlas;
+pub mod selection;
+pub mod order_resolver;
+pub mod tooltip;
+pub mod ui_scale;
+pub mod input_map;
+pub mod theme_manager;
+pub mod ui_state_cache;
 
 pub use ui_system::{UISystem, UIMetrics, UISystemBuilder};
 pub use view_controller::ViewController;
-pub use input_controller::InputController;
+pub use input_controller::{InputController, InputConfig, InputMetrics};
 pub use render_context::{RenderContext, Theme, ComponentResult, ComponentError};
+pub use dialog_manager::{DialogManager, DialogId};
+pub use camera::{Camera, CameraTarget};
+pub use box_select::{DragTracker, MapGesture};
+pub use interpolation::MotionBuffer;
+pub use render_batch::RenderBatcher;
+pub use icon_atlas::{IconAtlas, Icon, IconDetail};
+pub use selection::{Selection, GroupRecall};
+pub use order_resolver::{OrderResolver, Order, OrderOption};
+pub use tooltip::{TooltipManager, Tooltip, TooltipLine, TOOLTIP_DELAY};
+pub use ui_scale::{UI_SCALE_MIN, UI_SCALE_MAX};
+pub use input_map::{InputAction, InputMap};
+pub use theme_manager::ThemeManager;
+pub use ui_state_cache::{UIStateCache, PlanetSummary};
 
 // Types are defined below - no need for re-export
 
 use crate::core::types::*;
+use crate::systems::{Notification, ObjectiveTrack, PlanetKnowledge, ProductionReport, EmpireEconomy};
 use macroquad::prelude::*;
 use std::collections::HashMap;
 
@@ -40,6 +82,20 @@ pub enum InputEvent {
     Scroll { x: f32, y: f32, delta: f32 },
 }
 
+impl InputEvent {
+    /// The event with its position, if it has one, in UI units at the scale
+    pub fn to_ui(&self, scale: f32) -> InputEvent {
+        match *self {
+            InputEvent::MouseMove { x, y } => InputEvent::MouseMove { x: x / scale, y: y / scale },
+            InputEvent::MouseClick { x, y, button } => InputEvent::MouseClick { x: x / scale, y: y / scale, button },
+            InputEvent::MouseRelease { x, y, button } => InputEvent::MouseRelease { x: x / scale, y: y / scale, button },
+            InputEvent::Scroll { x, y, delta } => InputEvent::Scroll { x: x / scale, y: y / scale, delta },
+            InputEvent::KeyPress { key } => InputEvent::KeyPress { key },
+            InputEvent::KeyRelease { key } => InputEvent::KeyRelease { key },
+        }
+    }
+}
+
 /// Events for view lifecycle management
 #[derive(Debug, Clone)]
 pub enum ViewEvent {
@@ -70,6 +126,12 @@ pub enum ViewType {
     GameOptions,
     SaveLoad,
     ResourcePanel,
+    /// Alert log
+    Notifications,
+    /// Objective tracker
+    Objectives,
+    /// Details of the box-selected ships
+    SelectionPanel,
 }
 
 /// Generic data container for views
@@ -80,6 +142,28 @@ pub enum ViewData {
     Faction(Faction),
     ResourceBundle(ResourceBundle),
     Text(String),
+    /// A faction's notifications, most urgent first, as of `tick`
+    Notifications {
+        /// The notifications
+        notifications: Vec<Notification>,
+        /// Current tick
+        tick: u64,
+    },
+    /// A faction's objectives, None when it has none
+    Objectives(Option<ObjectiveTrack>),
+    /// A planet as the player knows it, from `GameState::known_planet`, at `tick`
+    KnownPlanet {
+        /// The planet as the player knows it
+        planet: Planet,
+        /// How much of it the player knows
+        knowledge: PlanetKnowledge,
+        /// Current tick
+        tick: u64,
+    },
+    /// A planet's resource rates by source, from `GameState::production_report`
+    ProductionReport(ProductionReport),
+    /// A faction's resources and projections, from `GameState::empire_economy`
+    EmpireEconomy(EmpireEconomy),
     Custom(HashMap<String, String>),
 }
 
@@ -0,0 +1,176 @@
+// src/ui_v2/core/order_resolver.rs
+//! Orders offered by the galaxy map's context menu
+//!
+//! Right-clicking the map asks [`OrderResolver`] which orders the player's
+//! selected ships can carry out against whatever is under the cursor: a
+//! point in space, a ship or a planet. Every candidate command is checked
+//! with the game's `CommandValidator`, including whether the player may give
+//! it, so the menu only lists orders the simulation would accept now. An
+//! order given to several ships at once becomes one command per ship.
+//!
+//! Cargo orders carry what the ship's hold has room for, taken from the
+//! planet's stock resource by resource in `ResourceType::ALL` order.
+
+use super::camera::CameraTarget;
+use super::picking;
+use super::selection::Selection;
+use crate::core::events::PlayerCommand;
+use crate::core::types::*;
+use crate::systems::CargoSystem;
+use crate::GameState;
+
+/// An order the context menu can offer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Order {
+    /// Fly to the target
+    Move,
+    /// Attack the targeted ship
+    Attack,
+    /// Settle the targeted planet
+    Colonize,
+    /// Load cargo at the targeted planet
+    LoadCargo,
+    /// Bombard the targeted planet
+    Bombard,
+    /// Run a trade route from the orbited planet to the targeted one
+    SetTradeRoute,
+}
+
+impl Order {
+    /// Every order, in the order the menu lists them
+    pub const ALL: [Order; 6] = [
+        Order::Move,
+        Order::Attack,
+        Order::Colonize,
+        Order::LoadCargo,
+        Order::Bombard,
+        Order::SetTradeRoute,
+    ];
+
+    /// Menu entry text
+    pub fn label(self) -> &'static str {
+        match self {
+            Order::Move => "Move",
+            Order::Attack => "Attack",
+            Order::Colonize => "Colonize",
+            Order::LoadCargo => "Load Cargo",
+            Order::Bombard => "Bombard",
+            Order::SetTradeRoute => "Set Trade Route",
+        }
+    }
+}
+
+/// An order and the commands that carry it out, one per ship taking part
+#[derive(Debug, Clone)]
+pub struct OrderOption {
+    /// Which order the entry gives
+    pub order: Order,
+    /// A validated command for each ship taking part
+    pub commands: Vec<PlayerCommand>,
+}
+
+impl OrderOption {
+    /// The command choosing this entry gives. A lone bombardment still asks
+    /// for confirmation, as from the planet panel, and orders for several
+    /// ships are given together.
+    pub fn command(&self) -> PlayerCommand {
+        match self.commands.as_slice() {
+            [PlayerCommand::BombardPlanet { ship, planet }] => PlayerCommand::ConfirmBombardment { ship: *ship, planet: *planet },
+            [command] => command.clone(),
+            commands => PlayerCommand::IssueOrders(commands.to_vec()),
+        }
+    }
+}
+
+/// Works out the orders a faction's selected ships can be given
+pub struct OrderResolver<'a> {
+    game: &'a GameState,
+    player: FactionId,
+}
+
+impl<'a> OrderResolver<'a> {
+    /// Resolver for orders the faction gives, as the game stands now
+    pub fn new(game: &'a GameState, player: FactionId) -> Self {
+        Self { game, player }
+    }
+
+    /// Orders for the selection against the planet or ship under the
+    /// cursor, or against the map point `point` when there is none. Orders
+    /// no selected ship can carry out are left out.
+    pub fn resolve(&self, selection: &Selection, target: Option<CameraTarget>, point: Vector2) -> Vec<OrderOption> {
+        let ships: Vec<&Ship> = selection.targets().iter()
+            .filter_map(|target| match target {
+                CameraTarget::Ship(id) => self.game.ship_manager.get_ship(*id).ok(),
+                CameraTarget::Planet(_) => None,
+            })
+            .filter(|ship| ship.owner == self.player)
+            .collect();
+        Order::ALL.into_iter()
+            .filter_map(|order| {
+                let commands: Vec<PlayerCommand> = ships.iter()
+                    .filter(|ship| target != Some(CameraTarget::Ship(ship.id)))
+                    .filter_map(|ship| self.candidate(order, ship, target, point))
+                    .filter(|command| self.accepts(command))
+                    .collect();
+                (!commands.is_empty()).then_some(OrderOption { order, commands })
+            })
+            .collect()
+    }
+
+    /// The command that would give the ship the order, before validation
+    fn candidate(&self, order: Order, ship: &Ship, target: Option<CameraTarget>, point: Vector2) -> Option<PlayerCommand> {
+        let planet = match target {
+            Some(CameraTarget::Planet(id)) => self.game.planet_manager.get_planet(id).ok(),
+            _ => None,
+        };
+        match order {
+            Order::Move => {
+                // Ships head for what was clicked rather than the empty space beside it
+                let destination = target.and_then(|target| picking::entity_position(self.game, target)).unwrap_or(point);
+                Some(PlayerCommand::MoveShip { ship: ship.id, target: destination })
+            }
+            Order::Attack => match target? {
+                CameraTarget::Ship(enemy) => Some(PlayerCommand::AttackTarget { attacker: ship.id, target: enemy }),
+                CameraTarget::Planet(_) => None,
+            },
+            Order::Colonize => Some(PlayerCommand::ColonizePlanet { ship: ship.id, planet: planet?.id }),
+            Order::LoadCargo => {
+                let planet = planet?;
+                let resources = cargo_fill(ship, planet);
+                (resources.total() > 0).then_some(PlayerCommand::LoadShipCargo { ship: ship.id, planet: planet.id, resources, population: 0 })
+            }
+            Order::Bombard => Some(PlayerCommand::BombardPlanet { ship: ship.id, planet: planet?.id }),
+            Order::SetTradeRoute => {
+                // From the planet the ship orbits to another of the faction's planets
+                let destination = planet.filter(|planet| planet.controller == Some(self.player))?;
+                let ShipStatus::Orbiting(from) = ship.status else {
+                    return None;
+                };
+                let source = self.game.planet_manager.get_planet(from).ok()
+                    .filter(|source| source.id != destination.id && source.controller == Some(self.player))?;
+                if !CargoSystem::has_cargo_hold(ship.ship_class) {
+                    return None;
+                }
+                let resources = cargo_fill(ship, source);
+                (resources.total() > 0).then_some(PlayerCommand::CreateTradeRoute(TradeRoute { ship: ship.id, from: source.id, to: destination.id, resources }))
+            }
+        }
+    }
+
+    fn accepts(&self, command: &PlayerCommand) -> bool {
+        let validator = self.game.command_validator();
+        validator.validate(command).is_ok() && validator.validate_issuer(self.player, command).is_ok()
+    }
+}
+
+/// As much of the planet's stock as fits in the ship's hold
+fn cargo_fill(ship: &Ship, planet: &Planet) -> ResourceBundle {
+    let mut space = ship.cargo.available_space().max(0);
+    let mut resources = ResourceBundle::default();
+    for resource in ResourceType::ALL {
+        let amount = planet.resources.current.get(resource).clamp(0, space);
+        resources.set(resource, amount);
+        space -= amount;
+    }
+    resources
+}
@@ -0,0 +1,74 @@
+// src/ui_v2/core/picking.rs
+//! Galaxy map picking
+//!
+//! Input is captured before the world is at hand: [`super::UISystem`] only
+//! queues the left clicks no view claimed. The client resolves them here
+//! against a read-only [`GameState`] once input is gathered, turning each
+//! into the planet or ship drawn under the cursor and its select command.
+//! Picking sees the map as the player's faction does, so ships out of
+//! sensor range cannot be picked. Candidates come from the PhysicsEngine's
+//! spatial index around the click, so a click costs the same however many
+//! ships are in the galaxy.
+
+use super::camera::{Camera, CameraTarget};
+use crate::core::events::PlayerCommand;
+use crate::core::types::*;
+use crate::systems::SpatialEntity;
+use crate::GameState;
+use macroquad::prelude::Vec2;
+
+/// Pixels from a planet or ship a click still picks it
+pub const PICK_RADIUS: f32 = 10.0;
+
+/// Ships the player's faction can see; every ship without a player faction
+pub fn visible_ships(game: &GameState) -> Vec<&Ship> {
+    let player = game.faction_manager.get_player_faction();
+    match player.and_then(|player| game.faction_visibility(player.id)) {
+        Some(visibility) => game.ship_manager.get_ships_visible_to(visibility),
+        None if player.is_some() => Vec::new(),
+        None => game.ship_manager.get_all_ships().iter().collect(),
+    }
+}
+
+/// Where a planet or ship is on the map now; None once it is gone or out of sight
+pub fn entity_position(game: &GameState, target: CameraTarget) -> Option<Vector2> {
+    match target {
+        CameraTarget::Planet(id) => game.planet_manager.get_planet(id).ok()
+            .map(|planet| game.physics_engine.planet_position(planet, game.get_current_tick())),
+        CameraTarget::Ship(id) => visible_ships(game).into_iter()
+            .find(|ship| ship.id == id)
+            .map(|ship| ship.position),
+    }
+}
+
+/// Ship or planet drawn nearest the screen point, within [`PICK_RADIUS`]
+pub fn pick_entity(game: &GameState, camera: &Camera, point: Vec2) -> Option<CameraTarget> {
+    let center = camera.screen_to_world(point);
+    let radius = center.distance_to(&camera.screen_to_world(point + Vec2::new(PICK_RADIUS, 0.0)));
+    let (ships, planets): (Vec<CameraTarget>, Vec<CameraTarget>) = game.physics_engine.entities_within(center, radius)
+        .into_iter()
+        .map(|(entity, _)| match entity {
+            SpatialEntity::Planet(id) => CameraTarget::Planet(id),
+            SpatialEntity::Ship(id) => CameraTarget::Ship(id),
+        })
+        .partition(|target| matches!(target, CameraTarget::Ship(_)));
+    // Ships come first so a ship drawn over its planet wins the tie
+    ships.into_iter().chain(planets)
+        .filter_map(|target| {
+            let distance = camera.world_to_screen(entity_position(game, target)?).distance(point);
+            (distance <= PICK_RADIUS).then_some((target, distance))
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(target, _)| target)
+}
+
+/// Select commands for the entities under each click, skipping empty space
+pub fn resolve_clicks(game: &GameState, camera: &Camera, clicks: &[Vec2]) -> Vec<PlayerCommand> {
+    clicks.iter()
+        .filter_map(|&click| pick_entity(game, camera, click))
+        .map(|target| match target {
+            CameraTarget::Planet(id) => PlayerCommand::SelectPlanet(id),
+            CameraTarget::Ship(id) => PlayerCommand::SelectShip(id),
+        })
+        .collect()
+}
@@ -0,0 +1,135 @@
+// src/ui_v2/core/render_batch.rs
+//! Batched drawing of map primitives
+//!
+//! Each macroquad `draw_circle` or `draw_rectangle` call builds its own
+//! vertex lists and hands them to the renderer on its own, which adds up
+//! with hundreds of ships and planets on screen. [`RenderBatcher`] queues
+//! filled circles, rectangles, lines and circle outlines as colored
+//! triangles into one reused mesh and draws it with a single `draw_mesh`
+//! call per [`MAX_BATCH_INDICES`] indices. Colors travel with the vertices,
+//! so primitives of every color share a batch. Circle points come from a
+//! table computed once rather than trigonometry per vertex.
+//!
+//! Everything queued is drawn on [`RenderBatcher::flush`], underneath
+//! anything drawn directly afterwards such as text.
+
+use macroquad::models::{draw_mesh, Mesh, Vertex};
+use macroquad::prelude::Color;
+use std::f32::consts::TAU;
+
+/// Points around a queued circle, as in macroquad's `draw_circle`
+pub const CIRCLE_SEGMENTS: usize = 20;
+/// Indices per mesh draw; below macroquad's default draw call capacity of 5000
+pub const MAX_BATCH_INDICES: usize = 4800;
+/// Vertices per mesh draw; below macroquad's default draw call capacity of 10000
+pub const MAX_BATCH_VERTICES: usize = 9600;
+
+/// Queues map primitives and draws them as few meshes
+pub struct RenderBatcher {
+    /// Cosine and sine of each circle point
+    unit_circle: Vec<(f32, f32)>,
+    mesh: Mesh,
+    shapes: usize,
+    draw_calls: usize,
+}
+
+impl Default for RenderBatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RenderBatcher {
+    /// Empty batcher
+    pub fn new() -> Self {
+        Self {
+            unit_circle: (0..CIRCLE_SEGMENTS)
+                .map(|i| (i as f32 / CIRCLE_SEGMENTS as f32 * TAU).sin_cos())
+                .map(|(sin, cos)| (cos, sin))
+                .collect(),
+            mesh: Mesh { vertices: Vec::with_capacity(MAX_BATCH_VERTICES), indices: Vec::with_capacity(MAX_BATCH_INDICES), texture: None },
+            shapes: 0,
+            draw_calls: 0,
+        }
+    }
+
+    /// Queue a filled circle
+    pub fn circle(&mut self, x: f32, y: f32, radius: f32, color: Color) {
+        let base = self.reserve(CIRCLE_SEGMENTS + 1, CIRCLE_SEGMENTS * 3);
+        self.mesh.vertices.push(vertex(x, y, color));
+        for (i, (cos, sin)) in self.unit_circle.iter().enumerate() {
+            self.mesh.vertices.push(vertex(x + radius * cos, y + radius * sin, color));
+            let next = (i + 1) % CIRCLE_SEGMENTS;
+            self.mesh.indices.extend_from_slice(&[base, base + 1 + i as u16, base + 1 + next as u16]);
+        }
+    }
+
+    /// Queue a circle outline `thickness` wide, centered on the radius
+    pub fn circle_lines(&mut self, x: f32, y: f32, radius: f32, thickness: f32, color: Color) {
+        let base = self.reserve(CIRCLE_SEGMENTS * 2, CIRCLE_SEGMENTS * 6);
+        let (inner, outer) = (radius - thickness / 2.0, radius + thickness / 2.0);
+        for (i, (cos, sin)) in self.unit_circle.iter().enumerate() {
+            self.mesh.vertices.push(vertex(x + inner * cos, y + inner * sin, color));
+            self.mesh.vertices.push(vertex(x + outer * cos, y + outer * sin, color));
+            let (this, next) = (base + 2 * i as u16, base + 2 * ((i + 1) % CIRCLE_SEGMENTS) as u16);
+            self.mesh.indices.extend_from_slice(&[this, this + 1, next + 1, this, next + 1, next]);
+        }
+    }
+
+    /// Queue a filled rectangle
+    pub fn rect(&mut self, x: f32, y: f32, width: f32, height: f32, color: Color) {
+        self.quad([(x, y), (x + width, y), (x + width, y + height), (x, y + height)], color);
+    }
+
+    /// Queue a line `thickness` wide; zero-length lines are skipped
+    pub fn line(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, thickness: f32, color: Color) {
+        let (dx, dy) = (x2 - x1, y2 - y1);
+        let length = (dx * dx + dy * dy).sqrt();
+        if length <= f32::EPSILON {
+            return;
+        }
+        // Half the thickness either side, across the line
+        let (nx, ny) = (-dy / length * thickness / 2.0, dx / length * thickness / 2.0);
+        self.quad([(x1 + nx, y1 + ny), (x2 + nx, y2 + ny), (x2 - nx, y2 - ny), (x1 - nx, y1 - ny)], color);
+    }
+
+    fn quad(&mut self, corners: [(f32, f32); 4], color: Color) {
+        let base = self.reserve(4, 6);
+        self.mesh.vertices.extend(corners.map(|(x, y)| vertex(x, y, color)));
+        self.mesh.indices.extend_from_slice(&[base, base + 1, base + 2, base, base + 2, base + 3]);
+    }
+
+    /// Make room for a shape, drawing what is queued if it would not fit,
+    /// and return the index of its first vertex
+    fn reserve(&mut self, vertices: usize, indices: usize) -> u16 {
+        if self.mesh.vertices.len() + vertices > MAX_BATCH_VERTICES || self.mesh.indices.len() + indices > MAX_BATCH_INDICES {
+            self.draw_queued();
+        }
+        self.shapes += 1;
+        self.mesh.vertices.len() as u16
+    }
+
+    fn draw_queued(&mut self) {
+        if self.mesh.indices.is_empty() {
+            return;
+        }
+        draw_mesh(&self.mesh);
+        self.mesh.vertices.clear();
+        self.mesh.indices.clear();
+        self.draw_calls += 1;
+    }
+
+    /// Draw everything queued. Returns how many shapes went out in how many
+    /// mesh draws since the last flush.
+    pub fn flush(&mut self) -> (usize, usize) {
+        self.draw_queued();
+        let drawn = (self.shapes, self.draw_calls);
+        self.shapes = 0;
+        self.draw_calls = 0;
+        drawn
+    }
+}
+
+fn vertex(x: f32, y: f32, color: Color) -> Vertex {
+    Vertex::new(x, y, 0.0, 0.0, 0.0, color)
+}
@@ -79,8 +79,14 @@ impl Default for RenderContext {
 }
 
 /// Theme configuration for consistent UI appearance
+///
+/// Besides the panel colors, a theme colors the galaxy map the client draws
+/// under the UI. [`Theme::dark`] is the default; [`Theme::high_contrast`]
+/// keeps to colors that stay apart under the common kinds of color blindness.
 #[derive(Debug, Clone)]
 pub struct Theme {
+    /// Name shown when switching themes
+    pub name: &'static str,
     pub primary_color: Color,
     pub secondary_color: Color,
     pub background_color: Color,
@@ -96,11 +102,31 @@ pub struct Theme {
     pub panel_alpha: f32,
     pub border_width: f32,
     pub corner_radius: f32,
+    /// Space behind the galaxy map and menus
+    pub map_background: Color,
+    /// Planets and ships of the player's faction
+    pub player_color: Color,
+    /// Planets and ships of other factions
+    pub rival_color: Color,
+    /// Unclaimed planets
+    pub neutral_color: Color,
+    /// Planets out of sensor range
+    pub unseen_color: Color,
+    /// Ring around the selected planet or ship
+    pub selection_color: Color,
 }
 
 impl Default for Theme {
     fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl Theme {
+    /// Blue panels over black space
+    pub fn dark() -> Self {
         Self {
+            name: "Dark",
             primary_color: Color::new(0.2, 0.4, 0.8, 1.0),
             secondary_color: Color::new(0.3, 0.3, 0.4, 1.0),
             background_color: Color::new(0.1, 0.1, 0.2, 0.9),
@@ -116,11 +142,44 @@ impl Default for Theme {
             panel_alpha: 0.9,
             border_width: 2.0,
             corner_radius: 4.0,
+            map_background: BLACK,
+            player_color: SKYBLUE,
+            rival_color: RED,
+            neutral_color: LIGHTGRAY,
+            unseen_color: DARKGRAY,
+            selection_color: YELLOW,
+        }
+    }
+
+    /// Black panels with bright borders, and factions told apart by blue
+    /// and orange rather than red and green
+    pub fn high_contrast() -> Self {
+        Self {
+            name: "High contrast",
+            primary_color: Color::new(0.0, 0.45, 0.7, 1.0),
+            secondary_color: Color::new(0.25, 0.25, 0.25, 1.0),
+            background_color: Color::new(0.0, 0.0, 0.0, 0.95),
+            panel_background: Color::new(0.0, 0.0, 0.0, 0.98),
+            text_color: WHITE,
+            secondary_text_color: Color::new(0.9, 0.9, 0.9, 1.0),
+            highlighted_text_color: Color::new(0.94, 0.89, 0.26, 1.0),
+            accent_color: Color::new(0.9, 0.62, 0.0, 1.0),
+            border_color: Color::new(0.94, 0.89, 0.26, 1.0),
+            success_color: Color::new(0.0, 0.62, 0.45, 1.0),
+            warning_color: Color::new(0.9, 0.62, 0.0, 1.0),
+            error_color: Color::new(0.84, 0.37, 0.0, 1.0),
+            panel_alpha: 1.0,
+            border_width: 3.0,
+            corner_radius: 0.0,
+            map_background: BLACK,
+            player_color: Color::new(0.34, 0.71, 0.91, 1.0),
+            rival_color: Color::new(0.9, 0.62, 0.0, 1.0),
+            neutral_color: WHITE,
+            unseen_color: Color::new(0.5, 0.5, 0.5, 1.0),
+            selection_color: Color::new(0.94, 0.89, 0.26, 1.0),
         }
     }
-}
 
-impl Theme {
     /// Get a dimmed version of a color
     pub fn dimmed(&self, color: Color) -> Color {
         Color::new(color.r * 0.7, color.g * 0.7, color.b * 0.7, color.a)
@@ -0,0 +1,131 @@
+// src/ui_v2/core/selection.rs
+//! Map selection and control groups
+//!
+//! The player can hold several planets and ships selected at once: a click
+//! selects one, Shift+click adds or removes one, and dragging a box selects
+//! the player's ships inside it, or with Shift adds them. The first selected is the
+//! primary, which the camera follows and next-ship cycling starts from.
+//!
+//! Ctrl with a digit stores the selection as that numbered control group of
+//! the player's faction, which saves it with the game. The digit alone
+//! selects the group again, and pressing it twice within
+//! [`DOUBLE_PRESS_SECONDS`] also centers the camera on it.
+
+use super::camera::CameraTarget;
+use crate::core::types::*;
+use macroquad::prelude::KeyCode;
+
+/// Longest gap between two presses of a group key that counts as a double press
+pub const DOUBLE_PRESS_SECONDS: f64 = 0.4;
+
+/// Keys of control groups 1 to 9
+pub const GROUP_KEYS: [KeyCode; 9] = [
+    KeyCode::Key1, KeyCode::Key2, KeyCode::Key3,
+    KeyCode::Key4, KeyCode::Key5, KeyCode::Key6,
+    KeyCode::Key7, KeyCode::Key8, KeyCode::Key9,
+];
+
+impl From<NamedEntity> for CameraTarget {
+    fn from(entity: NamedEntity) -> Self {
+        match entity {
+            NamedEntity::Planet(id) => CameraTarget::Planet(id),
+            NamedEntity::Ship(id) => CameraTarget::Ship(id),
+        }
+    }
+}
+
+impl From<CameraTarget> for NamedEntity {
+    fn from(target: CameraTarget) -> Self {
+        match target {
+            CameraTarget::Planet(id) => NamedEntity::Planet(id),
+            CameraTarget::Ship(id) => NamedEntity::Ship(id),
+        }
+    }
+}
+
+/// Planets and ships selected on the map, primary first
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Selection {
+    targets: Vec<CameraTarget>,
+}
+
+impl Selection {
+    /// Nothing selected
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Just the one planet or ship
+    pub fn single(target: CameraTarget) -> Self {
+        Self { targets: vec![target] }
+    }
+
+    /// The members of a control group, in group order
+    pub fn from_group(members: &[NamedEntity]) -> Self {
+        Self { targets: members.iter().map(|&member| member.into()).collect() }
+    }
+
+    /// The selection as control group members
+    pub fn members(&self) -> Vec<NamedEntity> {
+        self.targets.iter().map(|&target| target.into()).collect()
+    }
+
+    /// The first selected, which the camera follows
+    pub fn primary(&self) -> Option<CameraTarget> {
+        self.targets.first().copied()
+    }
+
+    /// Everything selected, primary first
+    pub fn targets(&self) -> &[CameraTarget] {
+        &self.targets
+    }
+
+    /// True when the planet or ship is selected
+    pub fn contains(&self, target: CameraTarget) -> bool {
+        self.targets.contains(&target)
+    }
+
+    /// Add the planet or ship, or remove it if it is already selected
+    pub fn toggle(&mut self, target: CameraTarget) {
+        match self.targets.iter().position(|&selected| selected == target) {
+            Some(index) => {
+                self.targets.remove(index);
+            }
+            None => self.targets.push(target),
+        }
+    }
+
+    /// Add the planet or ship unless it is already selected
+    pub fn insert(&mut self, target: CameraTarget) {
+        if !self.contains(target) {
+            self.targets.push(target);
+        }
+    }
+
+    /// Keep only what `keep` accepts, such as what still exists
+    pub fn retain(&mut self, keep: impl FnMut(&CameraTarget) -> bool) {
+        self.targets.retain(keep);
+    }
+
+    /// True when nothing is selected
+    pub fn is_empty(&self) -> bool {
+        self.targets.is_empty()
+    }
+}
+
+/// Tells a plain group recall from a double press
+#[derive(Debug, Clone, Default)]
+pub struct GroupRecall {
+    last: Option<(u8, f64)>,
+}
+
+impl GroupRecall {
+    /// Note a press of the group's key at `time` in seconds; true when it
+    /// is the second press in quick succession
+    pub fn press(&mut self, group: u8, time: f64) -> bool {
+        let double = self.last.is_some_and(|(last, at)| last == group && time - at <= DOUBLE_PRESS_SECONDS);
+        // A third press starts over rather than counting as another double
+        self.last = if double { None } else { Some((group, time)) };
+        double
+    }
+}
@@ -0,0 +1,59 @@
+// src/ui_v2/core/theme_manager.rs
+//! Runtime theme selection
+//!
+//! The client owns one [`ThemeManager`] and consults it for the galaxy map
+//! and HUD colors it draws itself. Whenever the theme changes, the client
+//! hands the new [`Theme`] to the UISystem, so panels and the map switch
+//! together on the next frame without a restart.
+
+use super::render_context::Theme;
+
+/// Built-in themes and the one in use
+#[derive(Debug, Clone)]
+pub struct ThemeManager {
+    themes: Vec<Theme>,
+    current: usize,
+}
+
+impl ThemeManager {
+    /// The built-in themes, starting with [`Theme::dark`]
+    pub fn new() -> Self {
+        Self {
+            themes: vec![Theme::dark(), Theme::high_contrast()],
+            current: 0,
+        }
+    }
+
+    /// Theme in use
+    pub fn current(&self) -> &Theme {
+        &self.themes[self.current]
+    }
+
+    /// Names of the available themes, in switching order
+    pub fn names(&self) -> Vec<&'static str> {
+        self.themes.iter().map(|theme| theme.name).collect()
+    }
+
+    /// Switch to the theme with the given name; false if there is none
+    pub fn select(&mut self, name: &str) -> bool {
+        match self.themes.iter().position(|theme| theme.name == name) {
+            Some(index) => {
+                self.current = index;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Switch to the next theme, wrapping around, and return it
+    pub fn cycle(&mut self) -> &Theme {
+        self.current = (self.current + 1) % self.themes.len();
+        self.current()
+    }
+}
+
+impl Default for ThemeManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
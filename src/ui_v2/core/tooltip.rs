@@ -0,0 +1,213 @@
+// src/ui_v2/core/tooltip.rs
+//! Hover tooltips
+//!
+//! Views describe parts of themselves with [`View::tooltips`](crate::ui_v2::View::tooltips),
+//! and the client can add regions of its own HUD with
+//! [`UISystem::register_tooltip`](crate::ui_v2::UISystem::register_tooltip).
+//! Each frame the [`TooltipManager`] looks for the region under the cursor,
+//! the last registered winning where regions overlap, and once the cursor
+//! has rested on one for [`TOOLTIP_DELAY`] seconds draws its tooltip beside
+//! the cursor, moved to the other side where it would run off the screen.
+//! Moving to another region starts the delay over; a region whose figures
+//! change while hovered keeps its place in the delay.
+
+use super::RenderContext;
+use crate::core::types::*;
+use crate::ui_v2::adapters::format_bundle;
+use macroquad::prelude::*;
+
+/// Seconds the cursor must rest on a region before its tooltip shows
+pub const TOOLTIP_DELAY: f32 = 0.5;
+/// Gap between the cursor and the tooltip's corner
+const CURSOR_OFFSET: Vec2 = Vec2::new(16.0, 20.0);
+const PADDING: f32 = 6.0;
+const LINE_SPACING: f32 = 1.3;
+
+/// One line of a tooltip, styled by what it says
+#[derive(Debug, Clone, PartialEq)]
+pub enum TooltipLine {
+    /// Plain description
+    Text(String),
+    /// What something costs
+    Cost {
+        /// Such as "Cost"
+        label: String,
+        /// Resources it takes
+        resources: ResourceBundle,
+    },
+    /// Resources gained or lost per tick
+    Rate {
+        /// Such as "Output"
+        label: String,
+        /// Change each tick
+        resources: ResourceBundle,
+    },
+    /// A key and what pressing it does
+    KeyHint {
+        /// Key or mouse action, as shown
+        key: String,
+        /// What it does
+        action: String,
+    },
+    /// Why something cannot be done now
+    Warning(String),
+}
+
+impl TooltipLine {
+    /// The line as drawn
+    pub fn text(&self) -> String {
+        match self {
+            TooltipLine::Text(text) | TooltipLine::Warning(text) => text.clone(),
+            TooltipLine::Cost { label, resources } => format!("{}: {}", label, format_bundle(resources)),
+            TooltipLine::Rate { label, resources } => format!("{}: {} per tick", label, format_bundle(resources)),
+            TooltipLine::KeyHint { key, action } => format!("[{}] {}", key, action),
+        }
+    }
+
+    fn color(&self, context: &RenderContext) -> Color {
+        match self {
+            TooltipLine::Text(_) => context.theme.text_color,
+            TooltipLine::Cost { .. } => context.theme.warning_color,
+            TooltipLine::Rate { .. } => context.theme.success_color,
+            TooltipLine::KeyHint { .. } => context.theme.accent_color,
+            TooltipLine::Warning(_) => context.theme.error_color,
+        }
+    }
+}
+
+/// A title and the lines beneath it
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tooltip {
+    /// First line, drawn larger
+    pub title: String,
+    /// Lines under the title, top to bottom
+    pub lines: Vec<TooltipLine>,
+}
+
+impl Tooltip {
+    /// Tooltip with just a title
+    pub fn new(title: impl Into<String>) -> Self {
+        Self { title: title.into(), lines: Vec::new() }
+    }
+
+    /// Add a plain line
+    pub fn text(self, text: impl Into<String>) -> Self {
+        self.line(TooltipLine::Text(text.into()))
+    }
+
+    /// Add a cost line
+    pub fn cost(self, label: impl Into<String>, resources: ResourceBundle) -> Self {
+        self.line(TooltipLine::Cost { label: label.into(), resources })
+    }
+
+    /// Add a per-tick rate line
+    pub fn rate(self, label: impl Into<String>, resources: ResourceBundle) -> Self {
+        self.line(TooltipLine::Rate { label: label.into(), resources })
+    }
+
+    /// Add a key hint
+    pub fn key_hint(self, key: impl Into<String>, action: impl Into<String>) -> Self {
+        self.line(TooltipLine::KeyHint { key: key.into(), action: action.into() })
+    }
+
+    /// Add a warning line
+    pub fn warning(self, text: impl Into<String>) -> Self {
+        self.line(TooltipLine::Warning(text.into()))
+    }
+
+    /// Add any line
+    pub fn line(mut self, line: TooltipLine) -> Self {
+        self.lines.push(line);
+        self
+    }
+
+    /// Width and height when drawn at the context's font size
+    fn measure(&self, context: &RenderContext) -> Vec2 {
+        let width = std::iter::once(measure_text(&self.title, None, context.font_size as u16, 1.0).width)
+            .chain(self.lines.iter().map(|line| measure_text(&line.text(), None, (context.font_size * 0.85) as u16, 1.0).width))
+            .fold(0.0, f32::max);
+        let height = context.font_size * LINE_SPACING * (1.0 + 0.85 * self.lines.len() as f32);
+        Vec2::new(width + 2.0 * PADDING, height + 2.0 * PADDING)
+    }
+}
+
+/// Where a tooltip of the given size goes: below and right of the cursor,
+/// flipped left or up where it would leave the screen, and never off the
+/// top left corner
+pub fn place_tooltip(size: Vec2, cursor: Vec2, screen: Vec2) -> Rect {
+    let mut x = cursor.x + CURSOR_OFFSET.x;
+    if x + size.x > screen.x {
+        x = cursor.x - CURSOR_OFFSET.x - size.x;
+    }
+    let mut y = cursor.y + CURSOR_OFFSET.y;
+    if y + size.y > screen.y {
+        y = cursor.y - size.y;
+    }
+    Rect::new(x.max(0.0), y.max(0.0), size.x, size.y)
+}
+
+/// Hover regions for the current frame and the tooltip the cursor rests on
+#[derive(Debug, Clone, Default)]
+pub struct TooltipManager {
+    regions: Vec<(Rect, Tooltip)>,
+    hovered: Option<(Rect, Tooltip)>,
+    hover_time: f32,
+    cursor: Vec2,
+}
+
+impl TooltipManager {
+    /// No regions and nothing hovered
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a region for the next [`TooltipManager::update`]
+    pub fn register(&mut self, rect: Rect, tooltip: Tooltip) {
+        self.regions.push((rect, tooltip));
+    }
+
+    /// Follow the cursor over the regions registered since the last update,
+    /// then forget them so the next frame registers afresh
+    pub fn update(&mut self, cursor: Vec2, delta_time: f32) {
+        self.cursor = cursor;
+        let under = self.regions.drain(..).rev().find(|(rect, _)| rect.contains(cursor));
+        // Still the same region if its place and title match, even as the
+        // figures in it change
+        let same = match (&under, &self.hovered) {
+            (Some((rect, tooltip)), Some((hovered_rect, hovered))) => rect == hovered_rect && tooltip.title == hovered.title,
+            _ => false,
+        };
+        self.hover_time = if same { self.hover_time + delta_time } else { 0.0 };
+        self.hovered = under;
+    }
+
+    /// Drop the regions registered since the last update, such as ones a
+    /// dialog now covers
+    pub fn discard_regions(&mut self) {
+        self.regions.clear();
+    }
+
+    /// The tooltip to show, once the cursor has rested long enough
+    pub fn active(&self) -> Option<&Tooltip> {
+        self.hovered.as_ref()
+            .filter(|_| self.hover_time >= TOOLTIP_DELAY)
+            .map(|(_, tooltip)| tooltip)
+    }
+
+    /// Draw the active tooltip, if any, near the cursor
+    pub fn render(&self, context: &RenderContext) {
+        let Some(tooltip) = self.active() else {
+            return;
+        };
+        let screen = Vec2::new(context.screen_width, context.screen_height);
+        let Rect { x, y, w, h } = place_tooltip(tooltip.measure(context), self.cursor, screen);
+        draw_rectangle(x, y, w, h, context.theme.panel_background);
+        draw_rectangle_lines(x, y, w, h, 1.0, context.theme.border_color);
+        let mut baseline = y + PADDING + context.font_size;
+        draw_text(&tooltip.title, x + PADDING, baseline, context.font_size, context.theme.text_color);
+        for line in &tooltip.lines {
+            baseline += context.font_size * 0.85 * LINE_SPACING;
+            draw_text(&line.text(), x + PADDING, baseline, context.font_size * 0.85, line.color(context));
+        }
+    }
+}
@@ -0,0 +1,36 @@
+// src/ui_v2/core/ui_scale.rs
+//! UI scaling
+//!
+//! Panels, dialogs and the HUD are laid out in UI units for a scale of 1.0.
+//! At another scale they are drawn through a camera that stretches UI units
+//! by the scale, so every position, size and font grows or shrinks together,
+//! and the `UISystem` divides input positions by the scale before views see
+//! them. The screen is `screen / scale` UI units across, as reported in
+//! `RenderContext::screen_width` and `screen_height`. The galaxy map is not
+//! scaled; map clicks and boxes stay in screen pixels.
+//!
+//! The scale is chosen in the options dialog and saved with the client's
+//! other settings.
+
+use macroquad::prelude::*;
+
+/// Smallest UI scale offered
+pub const UI_SCALE_MIN: f32 = 0.75;
+/// Largest UI scale offered
+pub const UI_SCALE_MAX: f32 = 2.0;
+
+/// The scale clamped to what the options offer; 1.0 for NaN
+pub fn clamp_ui_scale(scale: f32) -> f32 {
+    if scale.is_nan() {
+        1.0
+    } else {
+        scale.clamp(UI_SCALE_MIN, UI_SCALE_MAX)
+    }
+}
+
+/// Camera drawing UI units at the scale onto a screen of the given size
+pub fn ui_camera(scale: f32, screen: Vec2) -> Camera2D {
+    let size = screen / scale;
+    // A negative height keeps y growing downwards, as on the default camera
+    Camera2D::from_display_rect(Rect::new(0.0, size.y, size.x, -size.y))
+}
@@ -0,0 +1,181 @@
+// src/ui_v2/core/ui_state_cache.rs
+//! Derived UI data kept current from state changes
+//!
+//! Panels want summaries of the world rather than the world itself: each
+//! planet's name, owner and stock, and every faction's resources summed
+//! over its planets. [`UIStateCache::sync`] takes the state changes routed
+//! since the last frame from [`GameState::take_state_changes`] and updates
+//! only the planets, ships and factions they name, adjusting faction totals
+//! by the difference. When the game cannot vouch for the list, because the
+//! world was replaced or too much changed at once, everything is rebuilt.
+//!
+//! After a sync the cache answers which entities changed in it, so the
+//! client refreshes a panel only when what it shows is stale.
+
+use crate::core::events::StateChange;
+use crate::core::types::*;
+use crate::GameState;
+use std::collections::{HashMap, HashSet};
+
+/// What the UI shows of a planet at a glance
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlanetSummary {
+    /// The planet summarized
+    pub id: PlanetId,
+    /// Name as the player sees it
+    pub name: String,
+    /// Faction holding the planet, if any
+    pub controller: Option<FactionId>,
+    /// Total population
+    pub population: i32,
+    /// Resources in storage
+    pub stock: ResourceBundle,
+    /// Buildings developed on the planet
+    pub buildings: usize,
+    /// Hostile warships hold its orbit
+    pub blockaded: bool,
+}
+
+impl PlanetSummary {
+    fn of(planet: &Planet, blockaded: bool) -> Self {
+        Self {
+            id: planet.id,
+            name: planet.display_name(),
+            controller: planet.controller,
+            population: planet.population.total,
+            stock: planet.resources.current,
+            buildings: planet.developments.len(),
+            blockaded,
+        }
+    }
+}
+
+/// Planet summaries and faction totals, updated from state changes
+#[derive(Debug, Default)]
+pub struct UIStateCache {
+    planets: HashMap<PlanetId, PlanetSummary>,
+    totals: HashMap<FactionId, ResourceBundle>,
+    changed_planets: HashSet<PlanetId>,
+    changed_ships: HashSet<ShipId>,
+    changed_factions: HashSet<FactionId>,
+    /// The last sync rebuilt everything, so everything counts as changed
+    rebuilt: bool,
+}
+
+impl UIStateCache {
+    /// Empty cache; the first sync builds it
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Apply the state changes since the last sync, or rebuild if the game
+    /// cannot list them
+    pub fn sync(&mut self, game: &mut GameState) {
+        self.changed_planets.clear();
+        self.changed_ships.clear();
+        self.changed_factions.clear();
+        match game.take_state_changes() {
+            Some(changes) => {
+                self.rebuilt = false;
+                for change in &changes {
+                    self.apply(game, change);
+                }
+            }
+            None => self.rebuild(game),
+        }
+    }
+
+    fn rebuild(&mut self, game: &GameState) {
+        self.rebuilt = true;
+        self.planets = game.planet_manager.get_all_planets().iter()
+            .map(|planet| (planet.id, PlanetSummary::of(planet, game.combat_resolver.is_blockaded(planet.id))))
+            .collect();
+        self.totals.clear();
+        let controllers: HashSet<FactionId> = self.planets.values().filter_map(|summary| summary.controller).collect();
+        for faction in controllers {
+            self.recount(faction);
+        }
+    }
+
+    fn apply(&mut self, game: &GameState, change: &StateChange) {
+        match change {
+            StateChange::PlanetUpdated(planet)
+            | StateChange::BuildingStatusChanged { planet, .. }
+            | StateChange::PlanetBlockaded(planet)
+            | StateChange::BlockadeLifted(planet) => self.update_planet(game, *planet),
+            StateChange::ShipUpdated(ship) => {
+                self.changed_ships.insert(*ship);
+            }
+            StateChange::FactionUpdated(faction)
+            | StateChange::VictoryConditionMet { faction, .. }
+            | StateChange::GameOver(faction) => {
+                self.changed_factions.insert(*faction);
+            }
+            StateChange::GameLoaded => self.rebuild(game),
+            StateChange::CommandRejected { .. } | StateChange::SystemErrorOccurred { .. } => {}
+        }
+    }
+
+    /// Replace a planet's summary and move its stock between faction totals
+    fn update_planet(&mut self, game: &GameState, id: PlanetId) {
+        if !self.changed_planets.insert(id) {
+            // Already brought up to date by an earlier change this sync
+            return;
+        }
+        let summary = game.planet_manager.get_planet(id).ok()
+            .map(|planet| PlanetSummary::of(planet, game.combat_resolver.is_blockaded(id)));
+        let previous = match summary {
+            Some(summary) => self.planets.insert(id, summary),
+            None => self.planets.remove(&id),
+        };
+        if let Some((faction, stock)) = previous.and_then(|previous| Some((previous.controller?, previous.stock))) {
+            self.changed_factions.insert(faction);
+            let subtracted = self.totals.get_mut(&faction).map(|total| total.subtract(&stock));
+            if !matches!(subtracted, Some(Ok(()))) {
+                self.recount(faction);
+            }
+        }
+        if let Some((faction, stock)) = self.planets.get(&id).and_then(|current| Some((current.controller?, current.stock))) {
+            self.changed_factions.insert(faction);
+            if self.totals.entry(faction).or_default().add(&stock).is_err() {
+                self.recount(faction);
+            }
+        }
+    }
+
+    /// Sum a faction's total afresh from the summaries
+    fn recount(&mut self, faction: FactionId) {
+        let mut total = ResourceBundle::default();
+        for summary in self.planets.values().filter(|summary| summary.controller == Some(faction)) {
+            for resource in ResourceType::ALL {
+                total.set(resource, total.get(resource).saturating_add(summary.stock.get(resource)));
+            }
+        }
+        self.totals.insert(faction, total);
+    }
+
+    /// What the UI shows of a planet; None for planets that do not exist
+    pub fn planet_summary(&self, id: PlanetId) -> Option<&PlanetSummary> {
+        self.planets.get(&id)
+    }
+
+    /// Resources stored across the faction's planets
+    pub fn resource_totals(&self, faction: FactionId) -> ResourceBundle {
+        self.totals.get(&faction).copied().unwrap_or_default()
+    }
+
+    /// The planet changed in the last sync
+    pub fn planet_changed(&self, id: PlanetId) -> bool {
+        self.rebuilt || self.changed_planets.contains(&id)
+    }
+
+    /// The ship changed in the last sync
+    pub fn ship_changed(&self, id: ShipId) -> bool {
+        self.rebuilt || self.changed_ships.contains(&id)
+    }
+
+    /// The faction, or the planets and totals it holds, changed in the last sync
+    pub fn faction_changed(&self, id: FactionId) -> bool {
+        self.rebuilt || self.changed_factions.contains(&id)
+    }
+}
@@ -1,12 +1,21 @@
 // src/ui_v2/core/ui_system.rs
 //! Main UI system coordinator and entry point
+//!
+//! Views, dialogs and tooltips are drawn and given input in UI units at the
+//! UI scale; see [`ui_scale`](super::ui_scale).
 
 use super::{
     RenderContext, Theme, ComponentResult, 
-    ViewEvent, ViewId, ViewType
+    InputEvent, ViewEvent, ViewId, ViewType
 };
 use super::view_controller::ViewController;
-use super::input_controller::InputController;
+use super::input_controller::{InputController, InputConfig, InputMetrics};
+use super::dialog_manager::{DialogManager, DialogId};
+use super::box_select::{DragTracker, MapGesture};
+use super::tooltip::{TooltipManager, Tooltip};
+use super::ui_scale::{clamp_ui_scale, ui_camera};
+use crate::ui_v2::View;
+use crate::ui_v2::panels::MainMenuView;
 use crate::core::events::PlayerCommand;
 use macroquad::prelude::Vec2;
 use macroquad::prelude::*;
@@ -15,11 +24,21 @@ use macroquad::prelude::*;
 pub struct UISystem {
     view_controller: ViewController,
     input_controller: InputController,
+    dialog_manager: DialogManager,
     theme: Theme,
     screen_dimensions: (f32, f32),
+    /// UI scale chosen in the options, from `UI_SCALE_MIN` to `UI_SCALE_MAX`
     scale_factor: f32,
     font_size: f32,
     enabled: bool,
+    /// Left clicks no view claimed since the last [`UISystem::take_world_clicks`]
+    world_clicks: Vec<Vec2>,
+    /// Left-button drag that started on the map, if any
+    map_drag: DragTracker,
+    /// Boxes dragged on the map since the last [`UISystem::take_world_boxes`]
+    world_boxes: Vec<Rect>,
+    /// Hover regions and the tooltip under the cursor
+    tooltips: TooltipManager,
 }
 
 impl UISystem {
@@ -27,18 +46,22 @@ impl UISystem {
         Self {
             view_controller: ViewController::new(),
             input_controller: InputController::new(),
+            dialog_manager: DialogManager::new(),
             theme: Theme::default(),
             screen_dimensions: (1024.0, 768.0),
             scale_factor: 1.0,
             font_size: 16.0,
             enabled: true,
+            world_clicks: Vec::new(),
+            map_drag: DragTracker::new(),
+            world_boxes: Vec::new(),
+            tooltips: TooltipManager::new(),
         }
     }
 
     /// Initialize the UI system with screen dimensions
     pub fn initialize(&mut self, screen_width: f32, screen_height: f32) {
         self.screen_dimensions = (screen_width, screen_height);
-        self.update_scale_factor();
     }
 
     /// Main update loop - processes input and updates views
@@ -53,31 +76,102 @@ impl UISystem {
         let current_screen = (screen_width(), screen_height());
         if current_screen != self.screen_dimensions {
             self.screen_dimensions = current_screen;
-            self.update_scale_factor();
         }
 
-        // Process input events
+        // Process input events; views get positions in UI units, while the
+        // map keeps screen pixels
         let input_events = self.input_controller.process_input(delta_time);
+        let ui_events: Vec<InputEvent> = input_events.iter().map(|event| event.to_ui(self.scale_factor)).collect();
+        let ui_mouse = self.input_controller.get_mouse_position() / self.scale_factor;
+        
+        // An open dialog is modal: only it sees input and the views underneath wait
+        if self.dialog_manager.is_active() {
+            self.map_drag.cancel();
+            for event in &ui_events {
+                if let Ok(Some(command)) = self.dialog_manager.handle_input(event) {
+                    commands.push(command);
+                }
+            }
+            if let Err(e) = self.dialog_manager.update(delta_time) {
+                log::error!("Dialog update error: {:?}", e);
+            }
+            // Only the dialog's tooltips show; HUD regions are under it
+            self.tooltips.discard_regions();
+            for (rect, tooltip) in self.dialog_manager.tooltips() {
+                self.tooltips.register(rect, tooltip);
+            }
+            self.tooltips.update(ui_mouse, delta_time);
+            return commands;
+        }
         
         // Generate UI commands from input patterns
         let ui_commands = self.input_controller.generate_ui_commands(&input_events);
         commands.extend(ui_commands);
 
-        // Handle input events through view controller
-        for event in &input_events {
-            if let Ok(Some(command)) = self.view_controller.handle_input(event) {
-                commands.push(command);
+        // Handle input events through view controller; left presses no view
+        // turns into a command fall through to the galaxy map, as a click or
+        // a box once released
+        for (event, ui_event) in input_events.iter().zip(&ui_events) {
+            match self.view_controller.handle_input(ui_event) {
+                Ok(Some(command)) => commands.push(command),
+                _ => if let InputEvent::MouseClick { x, y, button: MouseButton::Left } = *event {
+                    self.map_drag.press(vec2(x, y));
+                },
+            }
+            match *event {
+                InputEvent::MouseMove { x, y } => self.map_drag.move_to(vec2(x, y)),
+                InputEvent::MouseRelease { x, y, button: MouseButton::Left } => match self.map_drag.release(vec2(x, y)) {
+                    Some(MapGesture::Click(point)) => self.world_clicks.push(point),
+                    Some(MapGesture::Box(rect)) => self.world_boxes.push(rect),
+                    None => {}
+                },
+                _ => {}
             }
         }
 
         // Update all views
         if let Err(e) = self.view_controller.update_all(delta_time) {
-            eprintln!("UI update error: {:?}", e);
+            log::error!("UI update error: {:?}", e);
+        }
+
+        // Views register after the client's HUD regions so theirs win where they overlap
+        for (rect, tooltip) in self.view_controller.tooltips() {
+            self.tooltips.register(rect, tooltip);
         }
+        self.tooltips.update(ui_mouse, delta_time);
 
         commands
     }
 
+    /// Left clicks on the galaxy map since the last call, in screen pixels,
+    /// for the client to resolve against the world
+    pub fn take_world_clicks(&mut self) -> Vec<Vec2> {
+        std::mem::take(&mut self.world_clicks)
+    }
+
+    /// Boxes dragged on the galaxy map since the last call, in screen
+    /// pixels, for the client to select inside
+    pub fn take_world_boxes(&mut self) -> Vec<Rect> {
+        std::mem::take(&mut self.world_boxes)
+    }
+
+    /// The selection box being dragged on the map, to draw
+    pub fn map_drag_box(&self) -> Option<Rect> {
+        self.map_drag.drag_box()
+    }
+
+    /// Show a tooltip while the cursor rests on `rect` during the next
+    /// update, for parts of the screen drawn outside any view; call every
+    /// frame the region is on screen
+    pub fn register_tooltip(&mut self, rect: Rect, tooltip: Tooltip) {
+        self.tooltips.register(rect, tooltip);
+    }
+
+    /// Hover regions and the tooltip showing, if any
+    pub fn tooltips(&self) -> &TooltipManager {
+        &self.tooltips
+    }
+
     /// Render all UI components
     pub fn render(&mut self) {
         if !self.enabled {
@@ -85,17 +179,27 @@ impl UISystem {
         }
 
         let context = self.create_render_context();
-        
+        self.begin_ui_camera();
+
         // Render all active views
         if let Err(e) = self.view_controller.render_all(&context) {
-            eprintln!("UI render error: {:?}", e);
+            log::error!("UI render error: {:?}", e);
         }
+        
+        // Dialogs draw over every other view
+        if let Err(e) = self.dialog_manager.render(&context) {
+            log::error!("Dialog render error: {:?}", e);
+        }
+
+        // Tooltips draw over everything, dialogs included
+        self.tooltips.render(&context);
+        self.end_ui_camera();
     }
     
     /// Send a view event to the system
     pub fn send_view_event(&mut self, event: ViewEvent) {
         if let Err(e) = self.view_controller.handle_view_event(event) {
-            eprintln!("View event error: {:?}", e);
+            log::error!("View event error: {:?}", e);
         }
     }
 
@@ -118,24 +222,45 @@ impl UISystem {
                 0 // Placeholder
             }
             ViewType::MainMenu => {
-                // Would create MenuView with appropriate configuration
-                0 // Placeholder
+                self.show_view(Box::new(MainMenuView::new()), ViewType::MainMenu)
             }
             ViewType::GameOptions => {
                 // Would create DialogView with options form
                 0 // Placeholder
             }
             ViewType::SaveLoad => {
-                // Would create DialogView with save/load interface
+                // Needs the save slot list; GameClient::open_save_load opens
+                // a SaveLoadPanel as a modal dialog instead
                 0 // Placeholder
             }
             ViewType::ResourcePanel => {
-                // Would create DataView with resource display
+                // Needs the player's economy; GameClient shows a
+                // ResourcePanelMigrated through show_view instead
+                0 // Placeholder
+            }
+            ViewType::Notifications => {
+                // Needs the player's faction; GameClient shows a
+                // NotificationPanel through show_view instead
+                0 // Placeholder
+            }
+            ViewType::Objectives => {
+                // Needs the player's faction; GameClient shows an
+                // ObjectivesPanel through show_view instead
+                0 // Placeholder
+            }
+            ViewType::SelectionPanel => {
+                // Needs the selected ships; GameClient shows a
+                // SelectionPanel through show_view instead
                 0 // Placeholder
             }
         }
     }
 
+    /// Show a view built by the caller, replacing any open view of the same type
+    pub fn show_view(&mut self, view: Box<dyn View>, view_type: ViewType) -> ViewId {
+        self.view_controller.create_view(view, view_type)
+    }
+
     /// Close a specific view
     pub fn close_view(&mut self, view_id: ViewId) {
         self.view_controller.close_view(view_id);
@@ -161,6 +286,28 @@ impl UISystem {
         &self.theme
     }
 
+    /// Set the UI scale, clamped to `UI_SCALE_MIN` to `UI_SCALE_MAX`
+    pub fn set_ui_scale(&mut self, scale: f32) {
+        self.scale_factor = clamp_ui_scale(scale);
+    }
+
+    /// Current UI scale
+    pub fn ui_scale(&self) -> f32 {
+        self.scale_factor
+    }
+
+    /// Draw the screen in UI units until `end_ui_camera`, for parts of the
+    /// UI drawn outside any view such as the client's HUD
+    pub fn begin_ui_camera(&self) {
+        push_camera_state();
+        set_camera(&ui_camera(self.scale_factor, vec2(screen_width(), screen_height())));
+    }
+
+    /// Go back to drawing in screen pixels
+    pub fn end_ui_camera(&self) {
+        pop_camera_state();
+    }
+
     /// Enable or disable the entire UI system
     pub fn set_enabled(&mut self, enabled: bool) {
         self.enabled = enabled;
@@ -181,6 +328,31 @@ impl UISystem {
         self.input_controller.is_any_mouse_button_down()
     }
 
+    /// Open a modal dialog above all views
+    pub fn open_dialog(&mut self, dialog: Box<dyn View>) -> DialogId {
+        self.dialog_manager.open(dialog)
+    }
+
+    /// Close the topmost dialog, returning its view type
+    pub fn close_top_dialog(&mut self) -> Option<&'static str> {
+        self.dialog_manager.close_top()
+    }
+
+    /// True while a modal dialog is blocking input to other views
+    pub fn is_dialog_active(&self) -> bool {
+        self.dialog_manager.is_active()
+    }
+
+    /// Modal dialog stack
+    pub fn get_dialog_manager(&self) -> &DialogManager {
+        &self.dialog_manager
+    }
+
+    /// Mutable modal dialog stack
+    pub fn get_dialog_manager_mut(&mut self) -> &mut DialogManager {
+        &mut self.dialog_manager
+    }
+
     /// Close all views
     pub fn close_all_views(&mut self) {
         self.view_controller.close_all();
@@ -193,19 +365,34 @@ impl UISystem {
             screen_dimensions: self.screen_dimensions,
             scale_factor: self.scale_factor,
             enabled: self.enabled,
+            input: self.input_controller.get_metrics().clone(),
         }
     }
 
-    /// Create render context for current frame
-    fn create_render_context(&self) -> RenderContext {
-        RenderContext::new()
+    /// Set the input flood protection limits
+    pub fn set_input_config(&mut self, config: InputConfig) {
+        self.input_controller.set_config(config);
+    }
+
+    /// Get the input flood protection limits
+    pub fn get_input_config(&self) -> &InputConfig {
+        self.input_controller.get_config()
     }
 
-    /// Update scale factor based on screen size
-    fn update_scale_factor(&mut self) {
-        // Scale UI based on screen height (768p = 1.0 scale)
-        self.scale_factor = (self.screen_dimensions.1 / 768.0).max(0.5).min(2.0);
-        self.font_size = 16.0 * self.scale_factor;
+    /// Get counters of coalesced, debounced and dropped input events
+    pub fn get_input_metrics(&self) -> &InputMetrics {
+        self.input_controller.get_metrics()
+    }
+
+    /// Create render context for current frame, with the screen and mouse
+    /// in UI units
+    fn create_render_context(&self) -> RenderContext {
+        let mut context = RenderContext::new();
+        context.screen_width /= self.scale_factor;
+        context.screen_height /= self.scale_factor;
+        context.mouse_position /= self.scale_factor;
+        context.scale_factor = self.scale_factor;
+        context
     }
 }
 
@@ -222,6 +409,8 @@ pub struct UIMetrics {
     pub screen_dimensions: (f32, f32),
     pub scale_factor: f32,
     pub enabled: bool,
+    /// Input filtering counters
+    pub input: InputMetrics,
 }
 
 /// UI System factory for creating common configurations
@@ -229,6 +418,7 @@ pub struct UISystemBuilder {
     theme: Option<Theme>,
     scale_factor: Option<f32>,
     font_size: Option<f32>,
+    input_config: Option<InputConfig>,
 }
 
 impl UISystemBuilder {
@@ -237,6 +427,7 @@ impl UISystemBuilder {
             theme: None,
             scale_factor: None,
             font_size: None,
+            input_config: None,
         }
     }
 
@@ -255,6 +446,12 @@ impl UISystemBuilder {
         self
     }
 
+    /// Override the input filtering settings
+    pub fn with_input_config(mut self, config: InputConfig) -> Self {
+        self.input_config = Some(config);
+        self
+    }
+
     pub fn build(self) -> UISystem {
         let mut system = UISystem::new();
         
@@ -263,12 +460,16 @@ impl UISystemBuilder {
         }
         
         if let Some(scale) = self.scale_factor {
-            system.scale_factor = scale;
+            system.set_ui_scale(scale);
         }
         
         if let Some(font_size) = self.font_size {
             system.font_size = font_size;
         }
+
+        if let Some(config) = self.input_config {
+            system.set_input_config(config);
+        }
         
         system
     }
@@ -1,8 +1,9 @@
 // src/ui_v2/core/view_controller.rs
 //! View lifecycle and coordination management
 
-use super::{ViewId, ViewEvent, ViewType, ViewData, ComponentResult};
+use super::{ViewId, ViewEvent, ViewType, ViewData, ComponentResult, Tooltip};
 use crate::ui_v2::views::View;
+use macroquad::prelude::Rect;
 // PlayerCommand import removed - handled by individual views
 use std::collections::HashMap;
 
@@ -165,6 +166,15 @@ impl ViewController {
         Ok(None)
     }
 
+    /// Tooltip regions of the visible views, bottom of the stack first
+    pub fn tooltips(&self) -> Vec<(Rect, Tooltip)> {
+        self.view_stack.iter()
+            .filter_map(|view_id| self.active_views.get(view_id))
+            .filter(|view| view.is_visible())
+            .flat_map(|view| view.tooltips())
+            .collect()
+    }
+
     /// Update all views
     pub fn update_all(&mut self, delta_time: f32) -> ComponentResult {
         for view in self.active_views.values_mut() {
@@ -24,6 +24,32 @@ pub use core::{
     UISystem,
     ViewController,
     InputController,
+    InputConfig,
+    InputMetrics,
+    DialogManager,
+    DialogId,
+    Camera,
+    CameraTarget,
+    DragTracker,
+    MapGesture,
+    MotionBuffer,
+    RenderBatcher,
+    IconAtlas,
+    Icon,
+    IconDetail,
+    Selection,
+    GroupRecall,
+    OrderResolver,
+    Order,
+    OrderOption,
+    TooltipManager,
+    Tooltip,
+    TooltipLine,
+    InputAction,
+    InputMap,
+    ThemeManager,
+    UIStateCache,
+    PlanetSummary,
     RenderContext,
     Layout,
     ComponentResult,
@@ -62,6 +88,54 @@ pub use panels::{
     PlanetPanelMigrated,
     ShipPanelMigrated,
     ResourcePanelMigrated,
+    ShuttleRunWizard,
+    SHUTTLE_RUN_WIZARD_VIEW_TYPE,
+    InboxPanel,
+    INBOX_VIEW_TYPE,
+    PauseMenu,
+    SaveLoadPanel,
+    SaveLoadMode,
+    MarketPanel,
+    MarketRow,
+    MARKET_VIEW_TYPE,
+    TRADE_LOT,
+    NotificationPanel,
+    NOTIFICATIONS_VIEW_TYPE,
+    ObjectivesPanel,
+    OBJECTIVES_VIEW_TYPE,
+    TimelinePanel,
+    TIMELINE_VIEW_TYPE,
+    KeyBindingsPanel,
+    KEY_BINDINGS_VIEW_TYPE,
+    EmpireOverviewView,
+    EMPIRE_OVERVIEW_VIEW_TYPE,
+    FleetOverviewView,
+    FleetEntry,
+    FLEET_OVERVIEW_VIEW_TYPE,
+    BuildMenuView,
+    BUILD_MENU_VIEW_TYPE,
+    ResourceTransferDialog,
+    TransferDestination,
+    TRANSFER_DIALOG_VIEW_TYPE,
+    BombardmentDialog,
+    BOMBARDMENT_DIALOG_VIEW_TYPE,
+    OrderMenu,
+    ORDER_MENU_VIEW_TYPE,
+    SelectionPanel,
+    SELECTION_PANEL_VIEW_TYPE,
+    ShipDesignerPanel,
+    SHIP_DESIGNER_VIEW_TYPE,
+    MainMenuView,
+    MainMenuEntry,
+    MAIN_MENU_VIEW_TYPE,
+    NewGameMenu,
+    NEW_GAME_MENU_VIEW_TYPE,
+    OptionsMenu,
+    OPTIONS_MENU_VIEW_TYPE,
+    GameOverView,
+    GAME_OVER_VIEW_TYPE,
+    GraphPanel,
+    GRAPH_PANEL_VIEW_TYPE,
 };
 
 // Version and compatibility info
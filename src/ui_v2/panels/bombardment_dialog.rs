@@ -0,0 +1,170 @@
+// src/ui_v2/panels/bombardment_dialog.rs
+//! Confirmation dialog for an orbital bombardment
+//!
+//! Bombardment wrecks buildings and kills population every tick until the
+//! warship leaves orbit, and every strike costs relation with the planet's
+//! faction, so the planet panel's Bombard buttons emit ConfirmBombardment
+//! rather than the order itself. The client answers by opening this dialog,
+//! which shows what the planet stands to lose and whether the order
+//! declares war; confirming emits BombardPlanet.
+
+use crate::ui_v2::{View, Panel, Button, RenderContext, ComponentResult, InputEvent, ViewData, Layout};
+use crate::ui_v2::components::base_component::UIComponent;
+use crate::core::{types::*, events::PlayerCommand, GameResult};
+use crate::GameState;
+use macroquad::prelude::*;
+
+/// View type reported by the bombardment dialog
+pub const BOMBARDMENT_DIALOG_VIEW_TYPE: &str = "BombardmentConfirmation";
+
+const PANEL_X: f32 = 312.0;
+const PANEL_Y: f32 = 200.0;
+const PANEL_WIDTH: f32 = 400.0;
+const PANEL_HEIGHT: f32 = 240.0;
+const LINE_HEIGHT: f32 = 24.0;
+
+/// Modal dialog asking whether a warship should bombard the planet it orbits
+pub struct BombardmentDialog {
+    main_panel: Panel,
+    confirm_button: Button,
+    cancel_button: Button,
+    ship: ShipId,
+    planet: PlanetId,
+    /// Lines describing the target, drawn above the buttons
+    details: Vec<String>,
+    /// The order declares war on a faction not yet at war with the attacker
+    declares_war: bool,
+    visible: bool,
+}
+
+impl BombardmentDialog {
+    /// Dialog for the order, describing the target with the given lines
+    pub fn new(ship: ShipId, planet: PlanetId, details: Vec<String>, declares_war: bool) -> Self {
+        let buttons_y = PANEL_Y + PANEL_HEIGHT - 50.0;
+        Self {
+            main_panel: Panel::new(format!("Bombard Planet {}?", planet))
+                .with_layout(Layout::new(PANEL_X, PANEL_Y, PANEL_WIDTH, PANEL_HEIGHT))
+                .collapsible(false),
+            confirm_button: Button::new("Bombard".to_string())
+                .with_layout(Layout::new(PANEL_X + 20.0, buttons_y, 120.0, 30.0)),
+            cancel_button: Button::new("Cancel".to_string())
+                .with_layout(Layout::new(PANEL_X + 160.0, buttons_y, 120.0, 30.0)),
+            ship,
+            planet,
+            details,
+            declares_war,
+            visible: true,
+        }
+    }
+
+    /// Dialog for the ship bombarding the planet, as the game stands now
+    pub fn for_game(game_state: &GameState, ship: ShipId, planet: PlanetId) -> GameResult<Self> {
+        let attacker = game_state.ship_manager.get_ship(ship)?.owner;
+        let target = game_state.planet_manager.get_planet(planet)?;
+        let owner = target.controller
+            .ok_or_else(|| GameError::InvalidTarget(format!("Planet {} has no enemy to bombard", planet)))?;
+        let owner_name = game_state.faction_manager.get_faction(owner)?.name.clone();
+        let declares_war = !game_state.faction_manager.relation(attacker, owner)?.at_war;
+        let details = vec![
+            format!("Ship {} will strike planet {} of {} every tick", ship, planet, owner_name),
+            format!("until it leaves orbit. Population {}, of them {} military,",
+                target.population.total, target.population.allocation.military),
+            format!("and {} buildings are at risk.", target.developments.len()),
+            format!("Relations with {} worsen with every strike.", owner_name),
+        ];
+        Ok(Self::new(ship, planet, details, declares_war))
+    }
+
+    /// Warship the order is for
+    pub fn ship(&self) -> ShipId {
+        self.ship
+    }
+
+    /// Planet the order is for
+    pub fn planet(&self) -> PlanetId {
+        self.planet
+    }
+
+    /// True if confirming declares war on the planet's faction
+    pub fn declares_war(&self) -> bool {
+        self.declares_war
+    }
+
+    /// Give the order and close
+    pub fn confirm(&mut self) -> PlayerCommand {
+        self.visible = false;
+        PlayerCommand::BombardPlanet { ship: self.ship, planet: self.planet }
+    }
+
+    /// Close without giving the order
+    pub fn cancel(&mut self) {
+        self.visible = false;
+    }
+}
+
+impl View for BombardmentDialog {
+    fn render(&mut self, context: &RenderContext) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        // Dim the game behind the dialog
+        draw_rectangle(0.0, 0.0, context.screen_width, context.screen_height, Color::new(0.0, 0.0, 0.0, 0.5));
+        self.main_panel.render(&(), context)?;
+        for (i, line) in self.details.iter().enumerate() {
+            let y = PANEL_Y + 60.0 + i as f32 * LINE_HEIGHT;
+            draw_text(line, PANEL_X + 20.0, y, context.font_size * 0.9, context.theme.text_color);
+        }
+        if self.declares_war {
+            let y = PANEL_Y + 60.0 + self.details.len() as f32 * LINE_HEIGHT;
+            draw_text("This declares war.", PANEL_X + 20.0, y, context.font_size * 0.9, context.theme.warning_color);
+        }
+        self.confirm_button.render(&(), context)?;
+        self.cancel_button.render(&(), context)?;
+        Ok(None)
+    }
+
+    fn handle_input(&mut self, input: &InputEvent) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        if let InputEvent::MouseClick { x, y, button: MouseButton::Left } = input {
+            let point = Vec2::new(*x, *y);
+            if self.confirm_button.get_layout().get_rect().contains(point) {
+                return Ok(Some(self.confirm()));
+            }
+            if self.cancel_button.get_layout().get_rect().contains(point) {
+                self.cancel();
+            }
+        }
+        Ok(None)
+    }
+
+    fn update(&mut self, delta_time: f32) -> ComponentResult {
+        if self.visible {
+            self.main_panel.update(delta_time)?;
+        }
+        Ok(None)
+    }
+
+    fn update_data(&mut self, _data: ViewData) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    fn refresh(&mut self) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn get_view_type(&self) -> &'static str {
+        BOMBARDMENT_DIALOG_VIEW_TYPE
+    }
+}
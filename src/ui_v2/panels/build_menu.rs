@@ -0,0 +1,226 @@
+// src/ui_v2/panels/build_menu.rs
+//! Build menu listing every building type a planet can construct
+//!
+//! Each row shows a building's cost, build time and output per tick from the
+//! game data, as returned by `GameState::building_build_options`. Buildings
+//! the planet cannot afford from its own stock, that are still locked, or that
+//! have no free slot left are grayed out with the reason. Clicking an
+//! available building emits BuildStructure for the planet and closes the menu.
+//! Hovering a row repeats its figures as a tooltip. The planet panel's
+//! Developments tab opens it with ShowBuildMenu.
+
+use crate::ui_v2::{View, Panel, Button, RenderContext, ComponentResult, InputEvent, ViewData, Layout, Tooltip};
+use crate::ui_v2::adapters::format_bundle;
+use crate::ui_v2::components::base_component::UIComponent;
+use crate::core::{types::*, events::PlayerCommand, GameResult};
+use crate::systems::BuildingBuildOption;
+use crate::GameState;
+use macroquad::prelude::*;
+
+/// View type reported by the build menu
+pub const BUILD_MENU_VIEW_TYPE: &str = "BuildMenu";
+
+const PANEL_X: f32 = 212.0;
+const PANEL_Y: f32 = 100.0;
+const PANEL_WIDTH: f32 = 600.0;
+const PANEL_HEIGHT: f32 = 500.0;
+const ROW_HEIGHT: f32 = 42.0;
+const BUTTON_WIDTH: f32 = 140.0;
+
+/// Modal dialog offering the buildings a planet can construct
+pub struct BuildMenuView {
+    main_panel: Panel,
+    build_buttons: Vec<(BuildingType, Button)>,
+    close_button: Button,
+    planet: PlanetId,
+    stock: ResourceBundle,
+    free_slots: usize,
+    options: Vec<BuildingBuildOption>,
+    visible: bool,
+}
+
+impl BuildMenuView {
+    /// Menu for a planet with the given stock and number of free building slots
+    pub fn new(planet: &Planet, options: Vec<BuildingBuildOption>, free_slots: usize) -> Self {
+        let mut view = Self {
+            main_panel: Panel::new(format!("Build on Planet {}", planet.id))
+                .with_layout(Layout::new(PANEL_X, PANEL_Y, PANEL_WIDTH, PANEL_HEIGHT))
+                .collapsible(false),
+            build_buttons: Vec::new(),
+            close_button: Button::new("Close".to_string())
+                .with_layout(Layout::new(PANEL_X + PANEL_WIDTH - 110.0, PANEL_Y + PANEL_HEIGHT - 40.0, 100.0, 30.0)),
+            planet: planet.id,
+            stock: planet.resources.current,
+            free_slots,
+            options,
+            visible: true,
+        };
+        view.build_buttons = view.options.iter()
+            .enumerate()
+            .filter(|(_, option)| view.unavailable_reason(option).is_none())
+            .map(|(i, option)| {
+                let layout = Layout::new(PANEL_X + 10.0, Self::row_y(i), BUTTON_WIDTH, ROW_HEIGHT - 8.0);
+                (option.building_type, Button::new(format!("{:?}", option.building_type)).with_layout(layout))
+            })
+            .collect();
+        view
+    }
+
+    /// Build menu for a planet as it is now
+    pub fn for_game(game_state: &GameState, planet: PlanetId) -> GameResult<Self> {
+        let options = game_state.building_build_options(planet)?;
+        let free_slots = game_state.planet_manager.get_available_building_slots(planet)?;
+        Ok(Self::new(game_state.planet_manager.get_planet(planet)?, options, free_slots))
+    }
+
+    /// Planet the menu builds on
+    pub fn planet(&self) -> PlanetId {
+        self.planet
+    }
+
+    /// Every building type offered, available or not
+    pub fn options(&self) -> &[BuildingBuildOption] {
+        &self.options
+    }
+
+    /// Building types that can be ordered now
+    pub fn available(&self) -> Vec<BuildingType> {
+        self.build_buttons.iter().map(|(building_type, _)| *building_type).collect()
+    }
+
+    /// Why a building type cannot be ordered now, or None when it can
+    pub fn unavailable_reason(&self, option: &BuildingBuildOption) -> Option<String> {
+        if !option.is_unlocked() {
+            Some(format!("Locked: {}", option.lock_reasons().join(", ")))
+        } else if self.free_slots == 0 {
+            Some("No free building slots".to_string())
+        } else if !self.stock.can_afford(&option.cost) {
+            Some("Not enough resources in stock".to_string())
+        } else {
+            None
+        }
+    }
+
+    /// Order the building if it is available, closing the menu
+    pub fn choose(&mut self, building_type: BuildingType) -> Option<PlayerCommand> {
+        if !self.available().contains(&building_type) {
+            return None;
+        }
+        self.close();
+        Some(PlayerCommand::BuildStructure { planet: self.planet, building_type })
+    }
+
+    /// Hide the dialog
+    pub fn close(&mut self) {
+        self.visible = false;
+    }
+
+    /// Tooltip for a building's row: what it costs and makes, and how to
+    /// order it or why it cannot be ordered
+    pub fn option_tooltip(&self, option: &BuildingBuildOption) -> Tooltip {
+        let tooltip = Tooltip::new(format!("{:?}", option.building_type))
+            .cost("Cost", option.cost)
+            .text(format!("Build time: {} ticks", option.build_time))
+            .rate("Output", option.production);
+        match self.unavailable_reason(option) {
+            Some(reason) => tooltip.warning(reason),
+            None => tooltip.key_hint("Click", "Build on this planet"),
+        }
+    }
+
+    fn row_y(index: usize) -> f32 {
+        PANEL_Y + 70.0 + index as f32 * ROW_HEIGHT
+    }
+}
+
+impl View for BuildMenuView {
+    fn render(&mut self, context: &RenderContext) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        // Dim the game behind the dialog
+        draw_rectangle(0.0, 0.0, context.screen_width, context.screen_height, Color::new(0.0, 0.0, 0.0, 0.5));
+        self.main_panel.render(&(), context)?;
+        let summary = format!("Stock: {}   Free slots: {}", format_bundle(&self.stock), self.free_slots);
+        draw_text(&summary, PANEL_X + 10.0, PANEL_Y + 55.0, context.font_size * 0.85, context.theme.text_color);
+
+        let text_x = PANEL_X + BUTTON_WIDTH + 20.0;
+        for (i, option) in self.options.iter().enumerate() {
+            let y = Self::row_y(i);
+            let reason = self.unavailable_reason(option);
+            let color = if reason.is_some() { GRAY } else { context.theme.text_color };
+            if reason.is_some() {
+                // Unavailable buildings get no button, only their name
+                draw_rectangle_lines(PANEL_X + 10.0, y, BUTTON_WIDTH, ROW_HEIGHT - 8.0, 1.0, GRAY);
+                draw_text(&format!("{:?}", option.building_type), PANEL_X + 18.0, y + 22.0, context.font_size * 0.9, GRAY);
+            }
+            let cost = format!("Cost: {} ({} ticks)", format_bundle(&option.cost), option.build_time);
+            draw_text(&cost, text_x, y + 14.0, context.font_size * 0.8, color);
+            let detail = reason.unwrap_or_else(|| format!("Output per tick: {}", format_bundle(&option.production)));
+            draw_text(&detail, text_x, y + 30.0, context.font_size * 0.8, color);
+        }
+        for (_, button) in &mut self.build_buttons {
+            button.render(&(), context)?;
+        }
+        self.close_button.render(&(), context)?;
+        Ok(None)
+    }
+
+    fn handle_input(&mut self, input: &InputEvent) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        if let InputEvent::MouseClick { x, y, button: MouseButton::Left } = input {
+            let point = Vec2::new(*x, *y);
+            if self.close_button.get_layout().get_rect().contains(point) {
+                self.close();
+                return Ok(None);
+            }
+            let chosen = self.build_buttons.iter()
+                .find(|(_, button)| button.get_layout().get_rect().contains(point))
+                .map(|(building_type, _)| *building_type);
+            if let Some(building_type) = chosen {
+                return Ok(self.choose(building_type));
+            }
+        }
+        Ok(None)
+    }
+
+    fn update(&mut self, delta_time: f32) -> ComponentResult {
+        if self.visible {
+            self.main_panel.update(delta_time)?;
+        }
+        Ok(None)
+    }
+
+    fn update_data(&mut self, _data: ViewData) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    fn refresh(&mut self) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn get_view_type(&self) -> &'static str {
+        BUILD_MENU_VIEW_TYPE
+    }
+
+    fn tooltips(&self) -> Vec<(Rect, Tooltip)> {
+        if !self.visible {
+            return Vec::new();
+        }
+        self.options.iter().enumerate()
+            .map(|(i, option)| (Rect::new(PANEL_X + 10.0, Self::row_y(i), PANEL_WIDTH - 20.0, ROW_HEIGHT - 8.0), self.option_tooltip(option)))
+            .collect()
+    }
+}
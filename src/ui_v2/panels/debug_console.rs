@@ -0,0 +1,93 @@
+// src/ui_v2/panels/debug_console.rs
+//! Developer console, built with the `debug` feature
+//!
+//! A box over the top left of the map with a TextInput for the command line
+//! and the latest lines of output above it. The client opens and closes it
+//! with the backtick key, feeds it the keys pressed while it is open and runs
+//! each submitted line through `stellar_dominion_core::console`.
+
+use crate::ui_v2::{RenderContext, ComponentResult, ComponentError, InputEvent, Layout};
+use crate::ui_v2::components::TextInput;
+use crate::ui_v2::components::base_component::{UIComponent, Stateful};
+use macroquad::prelude::*;
+use std::collections::VecDeque;
+
+/// Output lines kept and shown above the command line
+pub const CONSOLE_OUTPUT_LINES: usize = 12;
+
+const CONSOLE_X: f32 = 10.0;
+const CONSOLE_Y: f32 = 200.0;
+const CONSOLE_WIDTH: f32 = 760.0;
+const LINE_HEIGHT: f32 = 18.0;
+const INPUT_HEIGHT: f32 = 26.0;
+
+/// Command line with its output history
+pub struct DebugConsole {
+    input: TextInput,
+    output: VecDeque<String>,
+}
+
+impl DebugConsole {
+    /// Empty console with the command line focused
+    pub fn new() -> Self {
+        let input_y = CONSOLE_Y + CONSOLE_OUTPUT_LINES as f32 * LINE_HEIGHT + 8.0;
+        let mut input = TextInput::new()
+            .with_placeholder("Type 'help' for commands".to_string())
+            .with_layout(Layout::new(CONSOLE_X + 6.0, input_y, CONSOLE_WIDTH - 12.0, INPUT_HEIGHT));
+        input.set_focused(true);
+        Self { input, output: VecDeque::new() }
+    }
+
+    /// Text typed so far
+    pub fn line(&self) -> &str {
+        self.input.get_text()
+    }
+
+    /// Output lines, oldest first
+    pub fn output(&self) -> impl Iterator<Item = &str> {
+        self.output.iter().map(String::as_str)
+    }
+
+    /// Add a line of output, dropping the oldest beyond CONSOLE_OUTPUT_LINES
+    pub fn push_output(&mut self, line: String) {
+        if self.output.len() == CONSOLE_OUTPUT_LINES {
+            self.output.pop_front();
+        }
+        self.output.push_back(line);
+    }
+
+    /// Type a key into the command line; Enter submits it, echoing it to the
+    /// output and returning it unless it is blank
+    pub fn handle_key(&mut self, key: KeyCode) -> Result<Option<String>, ComponentError> {
+        if key == KeyCode::Enter {
+            let line = self.input.get_text().trim().to_string();
+            self.input.clear();
+            if line.is_empty() {
+                return Ok(None);
+            }
+            self.push_output(format!("> {}", line));
+            return Ok(Some(line));
+        }
+        self.input.handle_input(&InputEvent::KeyPress { key })?;
+        // Escape unfocuses the field, but the console keeps typing going to it
+        self.input.set_focused(true);
+        Ok(None)
+    }
+
+    /// Draw the output and the command line over the map
+    pub fn render(&mut self, context: &RenderContext) -> ComponentResult {
+        let height = CONSOLE_OUTPUT_LINES as f32 * LINE_HEIGHT + INPUT_HEIGHT + 16.0;
+        draw_rectangle(CONSOLE_X, CONSOLE_Y, CONSOLE_WIDTH, height, Color::new(0.0, 0.0, 0.0, 0.8));
+        draw_rectangle_lines(CONSOLE_X, CONSOLE_Y, CONSOLE_WIDTH, height, 1.0, context.theme.border_color);
+        for (i, line) in self.output.iter().enumerate() {
+            draw_text(line, CONSOLE_X + 8.0, CONSOLE_Y + (i + 1) as f32 * LINE_HEIGHT, 16.0, context.theme.text_color);
+        }
+        self.input.render(&(), context)
+    }
+}
+
+impl Default for DebugConsole {
+    fn default() -> Self {
+        Self::new()
+    }
+}
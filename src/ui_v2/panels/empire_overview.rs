@@ -0,0 +1,212 @@
+// src/ui_v2/panels/empire_overview.rs
+//! Empire overview dialog listing every planet a faction controls
+//!
+//! One DataView row per planet, built by PlanetAdapter, with its population,
+//! stock of each resource, building count and net production per tick. The
+//! table scrolls with the mouse wheel and sorts by any column; numbers sort
+//! by size. Clicking a planet's row emits ShowPlanet, which the client
+//! answers by centering the map on the planet and opening its panel. The
+//! empire's totals sit under the table, beside buttons that set the
+//! empire-wide tax rate. Like the timeline, the dialog shows a snapshot taken
+//! when it opens.
+
+use crate::ui_v2::{
+    View, Panel, Button, DataView, PlanetAdapter, RenderContext, ComponentResult, InputEvent, ViewData, Layout
+};
+use crate::ui_v2::views::{ColumnDefinition, TextAlign};
+use crate::ui_v2::components::base_component::UIComponent;
+use crate::core::{types::*, events::PlayerCommand, GameResult};
+use crate::GameState;
+use macroquad::prelude::*;
+
+/// View type reported by the empire overview dialog
+pub const EMPIRE_OVERVIEW_VIEW_TYPE: &str = "EmpireOverview";
+
+const PANEL_X: f32 = 62.0;
+const PANEL_Y: f32 = 80.0;
+const PANEL_WIDTH: f32 = 900.0;
+const PANEL_HEIGHT: f32 = 580.0;
+const TOTALS_HEIGHT: f32 = 30.0;
+
+/// Modal dialog with a sortable table of a faction's planets
+pub struct EmpireOverviewView {
+    main_panel: Panel,
+    table: DataView,
+    close_button: Button,
+    tax_buttons: Vec<Button>,
+    /// Planet ids in the order the rows were built
+    planets: Vec<PlanetId>,
+    totals: Vec<String>,
+    visible: bool,
+}
+
+impl EmpireOverviewView {
+    /// Dialog over planets with their production per tick
+    pub fn new(planets: &[(&Planet, ResourceBundle)]) -> Self {
+        let adapter = PlanetAdapter::simple();
+        let titles = PlanetAdapter::overview_columns();
+        let width = (PANEL_WIDTH - 20.0) / titles.len() as f32;
+        let columns = titles.into_iter()
+            .enumerate()
+            .map(|(i, title)| ColumnDefinition {
+                title,
+                width,
+                alignment: if i == 0 { TextAlign::Left } else { TextAlign::Right },
+                sortable: true,
+            })
+            .collect();
+        let table_y = PANEL_Y + 40.0;
+        let mut table = DataView::new(String::new())
+            .with_layout(Layout::new(PANEL_X + 10.0, table_y, PANEL_WIDTH - 20.0, PANEL_Y + PANEL_HEIGHT - table_y - TOTALS_HEIGHT - 50.0))
+            .with_columns(columns);
+        table.set_data(planets.iter().map(|(planet, production)| adapter.overview_row(planet, production)).collect());
+        let totals = adapter.overview_totals(planets);
+
+        Self {
+            main_panel: Panel::new("Empire Overview".to_string())
+                .with_layout(Layout::new(PANEL_X, PANEL_Y, PANEL_WIDTH, PANEL_HEIGHT))
+                .collapsible(false),
+            table,
+            close_button: Button::new("Close".to_string())
+                .with_layout(Layout::new(PANEL_X + PANEL_WIDTH - 110.0, PANEL_Y + PANEL_HEIGHT - 40.0, 100.0, 30.0)),
+            tax_buttons: Vec::new(),
+            planets: planets.iter().map(|(planet, _)| planet.id).collect(),
+            totals,
+            visible: true,
+        }
+    }
+
+    /// Overview of the faction's planets as they are now
+    pub fn for_game(game_state: &GameState, owner: FactionId) -> GameResult<Self> {
+        let planets = game_state.planet_manager.get_planets_by_faction(owner).into_iter()
+            .map(|planet| Ok((planet, game_state.resource_system.calculate_planet_production(planet)?)))
+            .collect::<GameResult<Vec<_>>>()?;
+        let mut dialog = Self::new(&planets);
+        dialog.rebuild_tax_buttons(owner, game_state.faction_manager.get_faction(owner)?.tax_rate);
+        Ok(dialog)
+    }
+
+    /// Rebuild the empire tax controls; the current rate is marked
+    fn rebuild_tax_buttons(&mut self, owner: FactionId, current: TaxRate) {
+        let button_width = 110.0;
+        self.tax_buttons = [TaxRate::Low, TaxRate::Normal, TaxRate::High].into_iter().enumerate()
+            .map(|(i, rate)| {
+                let marker = if rate == current { "*" } else { "" };
+                Button::new(format!("{}{:?} tax", marker, rate))
+                    .with_layout(Layout::new(PANEL_X + 10.0 + i as f32 * (button_width + 4.0), PANEL_Y + PANEL_HEIGHT - 40.0, button_width, 30.0))
+                    .with_click_command(PlayerCommand::SetFactionTaxRate { faction: owner, rate })
+            })
+            .collect();
+    }
+
+    /// Planets listed, in the order they were given
+    pub fn planets(&self) -> &[PlanetId] {
+        &self.planets
+    }
+
+    /// Totals row shown under the table
+    pub fn totals(&self) -> &[String] {
+        &self.totals
+    }
+
+    /// Hide the dialog
+    pub fn close(&mut self) {
+        self.visible = false;
+    }
+
+    /// Planet in the clicked row, which the first column names by id
+    fn selected_planet(&self) -> Option<PlanetId> {
+        self.table.get_selected_row()?.first()?.parse().ok()
+    }
+}
+
+impl View for EmpireOverviewView {
+    fn render(&mut self, context: &RenderContext) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        // Dim the game behind the dialog
+        draw_rectangle(0.0, 0.0, context.screen_width, context.screen_height, Color::new(0.0, 0.0, 0.0, 0.5));
+        self.main_panel.render(&(), context)?;
+        self.table.render(context)?;
+
+        let rect = self.table.get_layout().get_rect();
+        if self.planets.is_empty() {
+            draw_text("No planets under your control", rect.x + 20.0, rect.y + 60.0, context.font_size, context.theme.text_color);
+        }
+        let column_width = rect.w / self.totals.len().max(1) as f32;
+        for (i, total) in self.totals.iter().enumerate() {
+            let x = rect.x + i as f32 * column_width + 5.0;
+            draw_text(total, x, rect.y + rect.h + TOTALS_HEIGHT - 8.0, context.font_size * 0.85, context.theme.highlighted_text_color);
+        }
+        for button in &mut self.tax_buttons {
+            button.render(&(), context)?;
+        }
+        self.close_button.render(&(), context)?;
+        Ok(None)
+    }
+
+    fn handle_input(&mut self, input: &InputEvent) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        if let InputEvent::MouseClick { x, y, button: MouseButton::Left } = input {
+            if self.close_button.get_layout().get_rect().contains(Vec2::new(*x, *y)) {
+                self.close();
+                return Ok(None);
+            }
+        }
+        let mut clicked = None;
+        for button in &mut self.tax_buttons {
+            if let Some(command) = button.handle_input(input)? {
+                clicked = Some(command);
+                break;
+            }
+        }
+        if let Some(command) = clicked {
+            // Mark the new rate straight away; the snapshot is not rebuilt
+            if let PlayerCommand::SetFactionTaxRate { faction, rate } = command {
+                self.rebuild_tax_buttons(faction, rate);
+            }
+            return Ok(Some(command));
+        }
+        // Scrolling, sorting and row selection
+        let result = self.table.handle_input(input)?;
+        if let Some(planet) = self.selected_planet() {
+            self.table.clear_selection();
+            self.close();
+            return Ok(Some(PlayerCommand::ShowPlanet(planet)));
+        }
+        Ok(result)
+    }
+
+    fn update(&mut self, delta_time: f32) -> ComponentResult {
+        if self.visible {
+            self.main_panel.update(delta_time)?;
+            self.table.update(delta_time)?;
+        }
+        Ok(None)
+    }
+
+    fn update_data(&mut self, _data: ViewData) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    fn refresh(&mut self) -> ComponentResult {
+        self.table.refresh()
+    }
+
+    fn get_view_type(&self) -> &'static str {
+        EMPIRE_OVERVIEW_VIEW_TYPE
+    }
+}
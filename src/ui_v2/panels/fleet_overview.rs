@@ -0,0 +1,279 @@
+// src/ui_v2/panels/fleet_overview.rs
+//! Fleet overview dialog listing every ship a faction owns
+//!
+//! One DataView row per ship, built by ShipAdapter, with its class,
+//! position, fuel, cargo, current order and ticks to arrival from
+//! `ShipManager::get_trajectory_eta`. Buttons above the table narrow the list
+//! to one ship class. Each row on screen has a Return button that recalls
+//! the ship to the nearest planet its faction holds; clicking elsewhere in a
+//! row emits ShowShip, which the client answers by selecting the ship and
+//! centering the map on it. Like the empire overview, the dialog shows a
+//! snapshot taken when it opens.
+
+use crate::ui_v2::{
+    View, Panel, Button, DataView, ShipAdapter, RenderContext, ComponentResult, InputEvent, ViewData, Layout
+};
+use crate::ui_v2::views::{ColumnDefinition, TextAlign};
+use crate::ui_v2::components::base_component::UIComponent;
+use crate::core::{types::*, events::PlayerCommand, GameResult};
+use crate::GameState;
+use macroquad::prelude::*;
+
+/// View type reported by the fleet overview dialog
+pub const FLEET_OVERVIEW_VIEW_TYPE: &str = "FleetOverview";
+
+const PANEL_X: f32 = 62.0;
+const PANEL_Y: f32 = 80.0;
+const PANEL_WIDTH: f32 = 900.0;
+const PANEL_HEIGHT: f32 = 580.0;
+const FILTER_WIDTH: f32 = 110.0;
+const RETURN_COLUMN_WIDTH: f32 = 100.0;
+
+/// A ship as listed in the fleet overview
+#[derive(Debug, Clone)]
+pub struct FleetEntry {
+    /// Copy of the ship as it was when listed
+    pub ship: Ship,
+    /// True while the ship is fighting a battle
+    pub in_combat: bool,
+    /// Ticks until the ship reaches the end of its course
+    pub eta: Option<u64>,
+}
+
+/// Modal dialog with a filterable table of a faction's ships
+pub struct FleetOverviewView {
+    main_panel: Panel,
+    table: DataView,
+    filter_buttons: Vec<(Option<ShipClass>, Button)>,
+    return_buttons: Vec<(ShipId, Button)>,
+    close_button: Button,
+    adapter: ShipAdapter,
+    entries: Vec<FleetEntry>,
+    class_filter: Option<ShipClass>,
+    /// Ships recalled since the dialog opened
+    recalled: Vec<ShipId>,
+    visible: bool,
+}
+
+impl FleetOverviewView {
+    /// Dialog over the given ships
+    pub fn new(entries: Vec<FleetEntry>) -> Self {
+        let titles = ShipAdapter::fleet_columns();
+        let width = (PANEL_WIDTH - 20.0 - RETURN_COLUMN_WIDTH) / titles.len() as f32;
+        let mut columns: Vec<ColumnDefinition> = titles.into_iter()
+            .enumerate()
+            .map(|(i, title)| ColumnDefinition {
+                title,
+                width,
+                alignment: if i == 0 { TextAlign::Left } else { TextAlign::Right },
+                sortable: true,
+            })
+            .collect();
+        // Room for each row's Return button
+        columns.push(ColumnDefinition {
+            title: String::new(),
+            width: RETURN_COLUMN_WIDTH,
+            alignment: TextAlign::Center,
+            sortable: false,
+        });
+        let table_y = PANEL_Y + 80.0;
+        let table = DataView::new(String::new())
+            .with_layout(Layout::new(PANEL_X + 10.0, table_y, PANEL_WIDTH - 20.0, PANEL_Y + PANEL_HEIGHT - table_y - 50.0))
+            .with_columns(columns);
+        let filter_buttons = [None].into_iter()
+            .chain(ShipClass::ALL.into_iter().map(Some))
+            .enumerate()
+            .map(|(i, class)| {
+                let label = class.map_or_else(|| "All".to_string(), |class| format!("{:?}", class));
+                let layout = Layout::new(PANEL_X + 10.0 + i as f32 * (FILTER_WIDTH + 10.0), PANEL_Y + 40.0, FILTER_WIDTH, 30.0);
+                (class, Button::new(label).with_layout(layout))
+            })
+            .collect();
+
+        let mut view = Self {
+            main_panel: Panel::new("Fleet Overview".to_string())
+                .with_layout(Layout::new(PANEL_X, PANEL_Y, PANEL_WIDTH, PANEL_HEIGHT))
+                .collapsible(false),
+            table,
+            filter_buttons,
+            return_buttons: Vec::new(),
+            close_button: Button::new("Close".to_string())
+                .with_layout(Layout::new(PANEL_X + PANEL_WIDTH - 110.0, PANEL_Y + PANEL_HEIGHT - 40.0, 100.0, 30.0)),
+            adapter: ShipAdapter::simple(),
+            entries,
+            class_filter: None,
+            recalled: Vec::new(),
+            visible: true,
+        };
+        view.rebuild_rows();
+        view
+    }
+
+    /// Overview of the faction's ships as they are now
+    pub fn for_game(game_state: &GameState, owner: FactionId) -> GameResult<Self> {
+        let entries = game_state.ship_manager.get_all_ships().iter()
+            .filter(|ship| ship.owner == owner)
+            .map(|ship| Ok(FleetEntry {
+                ship: ship.clone(),
+                in_combat: game_state.combat_resolver.is_ship_in_combat(ship.id),
+                eta: game_state.ship_manager.get_trajectory_eta(ship.id)?,
+            }))
+            .collect::<GameResult<Vec<_>>>()?;
+        Ok(Self::new(entries))
+    }
+
+    /// Ships listed under the current filter, in the order they were given
+    pub fn ships(&self) -> Vec<ShipId> {
+        self.filtered().map(|entry| entry.ship.id).collect()
+    }
+
+    /// Ship class the list is narrowed to, if any
+    pub fn class_filter(&self) -> Option<ShipClass> {
+        self.class_filter
+    }
+
+    /// List only ships of the class, or every ship with None
+    pub fn show_class(&mut self, class: Option<ShipClass>) {
+        self.class_filter = class;
+        self.rebuild_rows();
+    }
+
+    /// Order the ship back to the nearest planet its faction holds
+    pub fn recall(&mut self, ship: ShipId) -> PlayerCommand {
+        if !self.recalled.contains(&ship) {
+            self.recalled.push(ship);
+        }
+        self.rebuild_rows();
+        PlayerCommand::RecallShip(ship)
+    }
+
+    /// Hide the dialog
+    pub fn close(&mut self) {
+        self.visible = false;
+    }
+
+    fn filtered(&self) -> impl Iterator<Item = &FleetEntry> {
+        self.entries.iter()
+            .filter(|entry| self.class_filter.is_none_or(|class| entry.ship.ship_class == class))
+    }
+
+    fn rebuild_rows(&mut self) {
+        let rows = self.filtered()
+            .map(|entry| {
+                let order = if self.recalled.contains(&entry.ship.id) {
+                    "Returning".to_string()
+                } else {
+                    ShipAdapter::order_status(&entry.ship, entry.in_combat)
+                };
+                self.adapter.fleet_row(&entry.ship, &order, entry.eta)
+            })
+            .collect();
+        self.table.set_data(rows);
+        self.rebuild_return_buttons();
+    }
+
+    /// Return buttons for the rows on screen, which change with scrolling and sorting
+    fn rebuild_return_buttons(&mut self) {
+        self.return_buttons = self.table.visible_rows().into_iter()
+            .filter_map(|(rect, row)| Some((rect, row.first()?.parse::<ShipId>().ok()?)))
+            .map(|(rect, ship)| {
+                let layout = Layout::new(rect.x + rect.w - RETURN_COLUMN_WIDTH + 5.0, rect.y + 2.0, RETURN_COLUMN_WIDTH - 10.0, rect.h - 4.0);
+                (ship, Button::new("Return".to_string()).with_layout(layout))
+            })
+            .collect();
+    }
+
+    /// Ship in the clicked row, which the first column names by id
+    fn selected_ship(&self) -> Option<ShipId> {
+        self.table.get_selected_row()?.first()?.parse().ok()
+    }
+}
+
+impl View for FleetOverviewView {
+    fn render(&mut self, context: &RenderContext) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        // Dim the game behind the dialog
+        draw_rectangle(0.0, 0.0, context.screen_width, context.screen_height, Color::new(0.0, 0.0, 0.0, 0.5));
+        self.main_panel.render(&(), context)?;
+        for (class, button) in &mut self.filter_buttons {
+            button.render(&(), context)?;
+            if *class == self.class_filter {
+                let rect = button.get_layout().get_rect();
+                draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 2.0, context.theme.accent_color);
+            }
+        }
+        self.table.render(context)?;
+        if self.entries.is_empty() {
+            let rect = self.table.get_layout().get_rect();
+            draw_text("No ships in your fleet", rect.x + 20.0, rect.y + 60.0, context.font_size, context.theme.text_color);
+        }
+        for (_, button) in &mut self.return_buttons {
+            button.render(&(), context)?;
+        }
+        self.close_button.render(&(), context)?;
+        Ok(None)
+    }
+
+    fn handle_input(&mut self, input: &InputEvent) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        if let InputEvent::MouseClick { x, y, button: MouseButton::Left } = input {
+            let point = Vec2::new(*x, *y);
+            let hit = |button: &Button| button.get_layout().get_rect().contains(point);
+            if hit(&self.close_button) {
+                self.close();
+                return Ok(None);
+            }
+            if let Some(class) = self.filter_buttons.iter().find(|(_, button)| hit(button)).map(|(class, _)| *class) {
+                self.show_class(class);
+                return Ok(None);
+            }
+            if let Some(ship) = self.return_buttons.iter().find(|(_, button)| hit(button)).map(|(ship, _)| *ship) {
+                return Ok(Some(self.recall(ship)));
+            }
+        }
+        // Scrolling, sorting and row selection
+        let result = self.table.handle_input(input)?;
+        self.rebuild_return_buttons();
+        if let Some(ship) = self.selected_ship() {
+            self.table.clear_selection();
+            self.close();
+            return Ok(Some(PlayerCommand::ShowShip(ship)));
+        }
+        Ok(result)
+    }
+
+    fn update(&mut self, delta_time: f32) -> ComponentResult {
+        if self.visible {
+            self.main_panel.update(delta_time)?;
+            self.table.update(delta_time)?;
+        }
+        Ok(None)
+    }
+
+    fn update_data(&mut self, _data: ViewData) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    fn refresh(&mut self) -> ComponentResult {
+        self.rebuild_rows();
+        Ok(None)
+    }
+
+    fn get_view_type(&self) -> &'static str {
+        FLEET_OVERVIEW_VIEW_TYPE
+    }
+}
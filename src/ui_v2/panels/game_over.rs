@@ -0,0 +1,261 @@
+// src/ui_v2/panels/game_over.rs
+//! End-of-game screen shown when a faction wins or the player is eliminated
+//!
+//! Titled Victory or Defeat with how the game was decided, it lists every
+//! faction's totals from the StatisticsSystem — planets, population,
+//! resources produced, ships built and battles won — and charts one of the
+//! statistics over the whole game, chosen with the buttons above the chart. Continue
+//! Playing resumes the game as a sandbox; Main Menu leaves it. Like the
+//! overviews, the screen shows a snapshot taken when it opens.
+
+use crate::ui_v2::{View, Panel, Button, RenderContext, ComponentResult, InputEvent, ViewData, Layout};
+use crate::ui_v2::components::base_component::UIComponent;
+use crate::ui_v2::components::{LineChart, ChartSeries};
+use crate::ui_v2::panels::graph_panel::faction_series;
+use crate::core::{types::*, events::PlayerCommand};
+use crate::systems::{FactionStatistics, StatisticsMetric, StatisticsSample};
+use crate::GameState;
+use macroquad::prelude::*;
+use std::collections::BTreeSet;
+
+/// View type reported by the game over screen
+pub const GAME_OVER_VIEW_TYPE: &str = "GameOver";
+
+const PANEL_X: f32 = 112.0;
+const PANEL_Y: f32 = 50.0;
+const PANEL_WIDTH: f32 = 800.0;
+const PANEL_HEIGHT: f32 = 660.0;
+const TABLE_Y: f32 = PANEL_Y + 120.0;
+const ROW_HEIGHT: f32 = 24.0;
+const CHART_X: f32 = PANEL_X + 20.0;
+const CHART_Y: f32 = PANEL_Y + 400.0;
+const CHART_WIDTH: f32 = PANEL_WIDTH - 40.0;
+const CHART_HEIGHT: f32 = 190.0;
+const METRIC_WIDTH: f32 = 90.0;
+
+/// Modal dialog summarizing a finished game
+pub struct GameOverView {
+    main_panel: Panel,
+    metric_buttons: Vec<(StatisticsMetric, Button)>,
+    continue_button: Button,
+    menu_button: Button,
+    title: String,
+    summary: String,
+    player: FactionId,
+    /// Each faction's name and latest totals, by faction id
+    factions: Vec<(String, FactionStatistics)>,
+    samples: Vec<StatisticsSample>,
+    metric: StatisticsMetric,
+    chart: LineChart,
+    visible: bool,
+}
+
+impl GameOverView {
+    /// Screen for the player with the given title, summary line and statistics
+    pub fn new(
+        player: FactionId,
+        title: String,
+        summary: String,
+        factions: Vec<(String, FactionStatistics)>,
+        samples: Vec<StatisticsSample>,
+    ) -> Self {
+        let metric_y = CHART_Y - 45.0;
+        let metric_buttons = StatisticsMetric::ALL.iter()
+            .enumerate()
+            .map(|(i, metric)| {
+                let layout = Layout::new(CHART_X + i as f32 * (METRIC_WIDTH + 5.0), metric_y, METRIC_WIDTH, 30.0);
+                (*metric, Button::new(metric.label().to_string()).with_layout(layout))
+            })
+            .collect();
+        let buttons_y = PANEL_Y + PANEL_HEIGHT - 45.0;
+
+        let mut view = Self {
+            main_panel: Panel::new("Game Over".to_string())
+                .with_layout(Layout::new(PANEL_X, PANEL_Y, PANEL_WIDTH, PANEL_HEIGHT))
+                .collapsible(false),
+            metric_buttons,
+            continue_button: Button::new("Continue Playing".to_string())
+                .with_layout(Layout::new(PANEL_X + 20.0, buttons_y, 180.0, 30.0)),
+            menu_button: Button::new("Main Menu".to_string())
+                .with_layout(Layout::new(PANEL_X + 220.0, buttons_y, 180.0, 30.0)),
+            title,
+            summary,
+            player,
+            factions,
+            samples,
+            metric: StatisticsMetric::Planets,
+            chart: LineChart::new(String::new())
+                .with_layout(Layout::new(CHART_X, CHART_Y, CHART_WIDTH, CHART_HEIGHT)),
+            visible: true,
+        };
+        view.show_metric(StatisticsMetric::Planets);
+        view
+    }
+
+    /// The end-of-game screen for the player, or None while the game is
+    /// still undecided and the player is still in it
+    pub fn for_game(game_state: &GameState, player: FactionId) -> Option<Self> {
+        let name = |faction: FactionId| game_state.faction_manager.get_faction(faction)
+            .map(|faction| faction.name.clone())
+            .unwrap_or_else(|_| format!("Faction {}", faction));
+        let winner = game_state.scenario_system.winner();
+        let (title, summary) = if game_state.scenario_system.is_eliminated(player) {
+            let summary = match winner {
+                Some((faction, victory)) => format!("Your empire has been eliminated. {} won a {:?} victory.", name(faction), victory),
+                None => "Your empire has been eliminated.".to_string(),
+            };
+            ("Defeat", summary)
+        } else {
+            let (faction, victory) = winner?;
+            let title = if faction == player { "Victory" } else { "Defeat" };
+            (title, format!("{} won a {:?} victory at tick {}.", name(faction), victory, game_state.get_current_tick()))
+        };
+
+        let factions = game_state.statistics_system.all_totals()
+            .map(|totals| (name(totals.faction), *totals))
+            .collect();
+        let samples = game_state.statistics_system.samples().to_vec();
+        Some(Self::new(player, title.to_string(), summary, factions, samples))
+    }
+
+    /// Victory or Defeat
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// How the game was decided
+    pub fn summary(&self) -> &str {
+        &self.summary
+    }
+
+    /// Every faction's name and totals when the game ended
+    pub fn factions(&self) -> &[(String, FactionStatistics)] {
+        &self.factions
+    }
+
+    /// Figure the chart shows
+    pub fn metric(&self) -> StatisticsMetric {
+        self.metric
+    }
+
+    /// Chart another figure
+    pub fn show_metric(&mut self, metric: StatisticsMetric) {
+        self.metric = metric;
+        let series = self.chart_series();
+        self.chart.set_series(series);
+    }
+
+    /// Close the screen and play on without victory conditions
+    pub fn continue_playing(&mut self) -> PlayerCommand {
+        self.visible = false;
+        PlayerCommand::PauseGame(false)
+    }
+
+    /// Close the screen and leave the game
+    pub fn return_to_menu(&mut self) -> PlayerCommand {
+        self.visible = false;
+        PlayerCommand::BackToMenu
+    }
+
+    fn chart_series(&self) -> Vec<ChartSeries> {
+        let factions: Vec<(FactionId, String)> = self.factions.iter()
+            .map(|(name, totals)| (totals.faction, name.clone()))
+            .collect();
+        faction_series(&self.samples, &factions, self.metric, &BTreeSet::new())
+    }
+}
+
+impl View for GameOverView {
+    fn render(&mut self, context: &RenderContext) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        // Dim the game behind the screen
+        draw_rectangle(0.0, 0.0, context.screen_width, context.screen_height, Color::new(0.0, 0.0, 0.0, 0.6));
+        self.main_panel.render(&(), context)?;
+        draw_text(&self.title, PANEL_X + 20.0, PANEL_Y + 75.0, 44.0, context.theme.accent_color);
+        draw_text(&self.summary, PANEL_X + 20.0, PANEL_Y + 100.0, context.font_size, context.theme.text_color);
+
+        let columns = ["Empire", "Planets", "Population", "Produced", "Ships Built", "Battles Won"];
+        let column_x = |i: usize| PANEL_X + 20.0 + if i == 0 { 0.0 } else { 180.0 + (i - 1) as f32 * 115.0 };
+        for (i, title) in columns.iter().enumerate() {
+            draw_text(title, column_x(i), TABLE_Y, context.font_size * 0.9, context.theme.secondary_text_color);
+        }
+        for (row, (name, totals)) in self.factions.iter().enumerate() {
+            let y = TABLE_Y + (row + 1) as f32 * ROW_HEIGHT;
+            let color = if totals.faction == self.player { context.theme.player_color } else { context.theme.text_color };
+            let cells = [
+                name.clone(),
+                totals.planets.to_string(),
+                totals.population.to_string(),
+                totals.resources_produced.to_string(),
+                totals.ships_built.to_string(),
+                totals.battles_won.to_string(),
+            ];
+            for (i, cell) in cells.iter().enumerate() {
+                draw_text(cell, column_x(i), y, context.font_size * 0.9, color);
+            }
+        }
+
+        for (metric, button) in &mut self.metric_buttons {
+            button.render(&(), context)?;
+            if *metric == self.metric {
+                let rect = button.get_layout().get_rect();
+                draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 2.0, context.theme.accent_color);
+            }
+        }
+        self.chart.render(&(), context)?;
+        self.continue_button.render(&(), context)?;
+        self.menu_button.render(&(), context)?;
+        Ok(None)
+    }
+
+    fn handle_input(&mut self, input: &InputEvent) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        if let InputEvent::MouseClick { x, y, button: MouseButton::Left } = input {
+            let point = Vec2::new(*x, *y);
+            let hit = |button: &Button| button.get_layout().get_rect().contains(point);
+            if hit(&self.continue_button) {
+                return Ok(Some(self.continue_playing()));
+            }
+            if hit(&self.menu_button) {
+                return Ok(Some(self.return_to_menu()));
+            }
+            if let Some(metric) = self.metric_buttons.iter().find(|(_, button)| hit(button)).map(|(metric, _)| *metric) {
+                self.show_metric(metric);
+            }
+        }
+        Ok(None)
+    }
+
+    fn update(&mut self, delta_time: f32) -> ComponentResult {
+        if self.visible {
+            self.main_panel.update(delta_time)?;
+        }
+        Ok(None)
+    }
+
+    fn update_data(&mut self, _data: ViewData) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    fn refresh(&mut self) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn get_view_type(&self) -> &'static str {
+        GAME_OVER_VIEW_TYPE
+    }
+}
@@ -0,0 +1,246 @@
+// src/ui_v2/panels/graph_panel.rs
+//! Graphs of every empire's statistics over the game
+//!
+//! A snapshot of the StatisticsSystem's samples, drawn as up to
+//! [`MAX_GRAPHS`] stacked line charts, one per chosen metric, with a line per
+//! faction. The metric buttons add or remove a chart, replacing the oldest
+//! one when all are in use; the faction buttons hide or show a faction's
+//! lines. Each faction keeps its color from [`SERIES_COLORS`] in every chart.
+
+use crate::ui_v2::{View, Panel, Button, RenderContext, ComponentResult, InputEvent, ViewData, Layout};
+use crate::ui_v2::components::base_component::UIComponent;
+use crate::ui_v2::components::{LineChart, ChartSeries};
+use crate::core::types::*;
+use crate::systems::{StatisticsMetric, StatisticsSample};
+use crate::systems::statistics::series;
+use crate::GameState;
+use macroquad::prelude::*;
+use std::collections::BTreeSet;
+
+/// View type reported by the graphs dialog
+pub const GRAPH_PANEL_VIEW_TYPE: &str = "Graphs";
+
+/// Charts shown at once
+pub const MAX_GRAPHS: usize = 3;
+
+/// Line color of each faction, in faction id order, repeating past the last
+pub const SERIES_COLORS: [Color; 6] = [SKYBLUE, RED, GOLD, LIME, VIOLET, ORANGE];
+
+const PANEL_X: f32 = 62.0;
+const PANEL_Y: f32 = 40.0;
+const PANEL_WIDTH: f32 = 900.0;
+const PANEL_HEIGHT: f32 = 690.0;
+const BUTTON_WIDTH: f32 = 104.0;
+const CHARTS_Y: f32 = PANEL_Y + 150.0;
+const CHART_SLOT_HEIGHT: f32 = 160.0;
+
+/// The lines of a metric for the given factions, colored by their position
+/// among all factions, as drawn by the graphs and the game over screen
+pub fn faction_series(
+    samples: &[StatisticsSample],
+    factions: &[(FactionId, String)],
+    metric: StatisticsMetric,
+    hidden: &BTreeSet<FactionId>,
+) -> Vec<ChartSeries> {
+    factions.iter()
+        .enumerate()
+        .filter(|(_, (faction, _))| !hidden.contains(faction))
+        .map(|(i, (faction, name))| ChartSeries {
+            label: name.clone(),
+            color: SERIES_COLORS[i % SERIES_COLORS.len()],
+            points: series(samples, *faction, metric),
+        })
+        .collect()
+}
+
+/// Modal dialog charting the empires' statistics
+pub struct GraphPanel {
+    main_panel: Panel,
+    metric_buttons: Vec<(StatisticsMetric, Button)>,
+    faction_buttons: Vec<(FactionId, Button)>,
+    close_button: Button,
+    /// Every faction with statistics and its name, by faction id
+    factions: Vec<(FactionId, String)>,
+    samples: Vec<StatisticsSample>,
+    /// Charted metrics, oldest choice first
+    metrics: Vec<StatisticsMetric>,
+    hidden: BTreeSet<FactionId>,
+    charts: Vec<LineChart>,
+    visible: bool,
+}
+
+impl GraphPanel {
+    /// Dialog over the given factions and samples, charting the score
+    pub fn new(factions: Vec<(FactionId, String)>, samples: Vec<StatisticsSample>) -> Self {
+        let button = |i: usize, row: usize, label: String| Button::new(label)
+            .with_layout(Layout::new(PANEL_X + 20.0 + i as f32 * (BUTTON_WIDTH + 6.0), PANEL_Y + 40.0 + row as f32 * 40.0, BUTTON_WIDTH, 30.0));
+        let mut panel = Self {
+            main_panel: Panel::new("Graphs".to_string())
+                .with_layout(Layout::new(PANEL_X, PANEL_Y, PANEL_WIDTH, PANEL_HEIGHT))
+                .collapsible(false),
+            metric_buttons: StatisticsMetric::ALL.iter()
+                .enumerate()
+                .map(|(i, metric)| (*metric, button(i, 0, metric.label().to_string())))
+                .collect(),
+            faction_buttons: factions.iter()
+                .enumerate()
+                .map(|(i, (faction, name))| (*faction, button(i, 1, name.clone())))
+                .collect(),
+            close_button: Button::new("Close".to_string())
+                .with_layout(Layout::new(PANEL_X + PANEL_WIDTH - 140.0, PANEL_Y + PANEL_HEIGHT - 45.0, 120.0, 30.0)),
+            factions,
+            samples,
+            metrics: vec![StatisticsMetric::Score],
+            hidden: BTreeSet::new(),
+            charts: Vec::new(),
+            visible: true,
+        };
+        panel.rebuild_charts();
+        panel
+    }
+
+    /// Dialog over the game's statistics as they are now
+    pub fn for_game(game_state: &GameState) -> Self {
+        let factions = game_state.statistics_system.all_totals()
+            .map(|totals| {
+                let name = game_state.faction_manager.get_faction(totals.faction)
+                    .map(|faction| faction.name.clone())
+                    .unwrap_or_else(|_| format!("Faction {}", totals.faction));
+                (totals.faction, name)
+            })
+            .collect();
+        Self::new(factions, game_state.statistics_system.samples().to_vec())
+    }
+
+    /// Charted metrics, top to bottom
+    pub fn metrics(&self) -> &[StatisticsMetric] {
+        &self.metrics
+    }
+
+    /// Add a chart of the metric, replacing the oldest when [`MAX_GRAPHS`]
+    /// are shown, or remove its chart unless it is the last one
+    pub fn toggle_metric(&mut self, metric: StatisticsMetric) {
+        if let Some(index) = self.metrics.iter().position(|charted| *charted == metric) {
+            if self.metrics.len() > 1 {
+                self.metrics.remove(index);
+            }
+        } else {
+            self.metrics.push(metric);
+            if self.metrics.len() > MAX_GRAPHS {
+                self.metrics.remove(0);
+            }
+        }
+        self.rebuild_charts();
+    }
+
+    /// Hide or show a faction's lines
+    pub fn toggle_faction(&mut self, faction: FactionId) {
+        if !self.hidden.remove(&faction) {
+            self.hidden.insert(faction);
+        }
+        self.rebuild_charts();
+    }
+
+    /// True while a faction's lines are drawn
+    pub fn is_faction_shown(&self, faction: FactionId) -> bool {
+        !self.hidden.contains(&faction)
+    }
+
+    /// Lines of a metric for the factions shown
+    pub fn chart_series(&self, metric: StatisticsMetric) -> Vec<ChartSeries> {
+        faction_series(&self.samples, &self.factions, metric, &self.hidden)
+    }
+
+    /// Hide the dialog
+    pub fn close(&mut self) {
+        self.visible = false;
+    }
+
+    fn rebuild_charts(&mut self) {
+        self.charts = self.metrics.iter()
+            .enumerate()
+            .map(|(slot, metric)| {
+                let layout = Layout::new(PANEL_X + 20.0, CHARTS_Y + slot as f32 * CHART_SLOT_HEIGHT, PANEL_WIDTH - 40.0, CHART_SLOT_HEIGHT - 30.0);
+                let mut chart = LineChart::new(metric.label().to_string()).with_layout(layout);
+                chart.set_series(self.chart_series(*metric));
+                chart
+            })
+            .collect();
+    }
+}
+
+impl View for GraphPanel {
+    fn render(&mut self, context: &RenderContext) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        // Dim the game behind the dialog
+        draw_rectangle(0.0, 0.0, context.screen_width, context.screen_height, Color::new(0.0, 0.0, 0.0, 0.5));
+        self.main_panel.render(&(), context)?;
+        for (metric, button) in &mut self.metric_buttons {
+            button.render(&(), context)?;
+            if self.metrics.contains(metric) {
+                let rect = button.get_layout().get_rect();
+                draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 2.0, context.theme.accent_color);
+            }
+        }
+        for (i, (faction, button)) in self.faction_buttons.iter_mut().enumerate() {
+            button.render(&(), context)?;
+            let rect = button.get_layout().get_rect();
+            let color = if self.hidden.contains(faction) { DARKGRAY } else { SERIES_COLORS[i % SERIES_COLORS.len()] };
+            draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 2.0, color);
+        }
+        for chart in &mut self.charts {
+            chart.render(&(), context)?;
+        }
+        self.close_button.render(&(), context)?;
+        Ok(None)
+    }
+
+    fn handle_input(&mut self, input: &InputEvent) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        if let InputEvent::MouseClick { x, y, button: MouseButton::Left } = input {
+            let point = Vec2::new(*x, *y);
+            let hit = |button: &Button| button.get_layout().get_rect().contains(point);
+            if hit(&self.close_button) {
+                self.close();
+            } else if let Some(metric) = self.metric_buttons.iter().find(|(_, button)| hit(button)).map(|(metric, _)| *metric) {
+                self.toggle_metric(metric);
+            } else if let Some(faction) = self.faction_buttons.iter().find(|(_, button)| hit(button)).map(|(faction, _)| *faction) {
+                self.toggle_faction(faction);
+            }
+        }
+        Ok(None)
+    }
+
+    fn update(&mut self, delta_time: f32) -> ComponentResult {
+        if self.visible {
+            self.main_panel.update(delta_time)?;
+        }
+        Ok(None)
+    }
+
+    fn update_data(&mut self, _data: ViewData) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    fn refresh(&mut self) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn get_view_type(&self) -> &'static str {
+        GRAPH_PANEL_VIEW_TYPE
+    }
+}
@@ -0,0 +1,222 @@
+// src/ui_v2/panels/inbox_panel.rs
+//! Player inbox for pending offers and prompts
+//!
+//! Shows a badge with the number of items waiting in the player faction's
+//! inbox. Clicking the badge opens a list of diplomatic proposals, trade
+//! offers and tutorial prompts, each with Accept/Decline buttons that emit a
+//! RespondToInbox command.
+
+use crate::ui_v2::{
+    View, Panel, Button, RenderContext, ComponentResult, InputEvent, ViewData, Layout
+};
+use crate::ui_v2::components::base_component::UIComponent;
+use crate::core::{types::*, events::PlayerCommand, GameResult};
+use crate::GameState;
+use macroquad::prelude::*;
+
+/// View type the inbox is opened under as a dialog
+pub const INBOX_VIEW_TYPE: &str = "InboxPanel";
+
+/// Items listed at once; older items wait until newer ones are answered
+const MAX_LISTED_ITEMS: usize = 8;
+
+/// One-line summary of an inbox item, including time left to answer
+pub fn describe_inbox_item(item: &InboxItem, current_tick: u64) -> String {
+    let summary = match &item.message {
+        InboxMessage::DiplomaticProposal { from, proposal } => {
+            let proposal = match proposal {
+                DiplomaticProposal::Peace => "Peace",
+                DiplomaticProposal::NonAggressionPact => "Non-aggression pact",
+                DiplomaticProposal::Alliance => "Alliance",
+            };
+            format!("{} proposed by faction {}", proposal, from)
+        }
+        InboxMessage::TradeOffer { from, offered, requested } => format!(
+            "Trade from faction {}: {} for {}", from, offered.total(), requested.total()
+        ),
+        InboxMessage::TutorialPrompt { title, .. } => title.clone(),
+    };
+    match item.expires_tick {
+        Some(expires) => format!("{} ({} ticks left)", summary, expires.saturating_sub(current_tick)),
+        None => summary,
+    }
+}
+
+/// Inbox badge and item list for the player's faction
+pub struct InboxPanel {
+    main_panel: Panel,
+    badge_button: Button,
+    response_buttons: Vec<Button>,
+
+    owner: FactionId,
+    items: Vec<InboxItem>,
+    current_tick: u64,
+    visible: bool,
+}
+
+impl InboxPanel {
+    /// Hidden, empty inbox for the faction
+    pub fn new(owner: FactionId) -> Self {
+        let mut panel = Self {
+            main_panel: Panel::new("Inbox".to_string())
+                .with_layout(Layout::new(200.0, 100.0, 460.0, 320.0))
+                .collapsible(false),
+            badge_button: Button::new("Inbox".to_string()),
+            response_buttons: Vec::new(),
+            owner,
+            items: Vec::new(),
+            current_tick: 0,
+            visible: false,
+        };
+        panel.rebuild_buttons();
+        panel
+    }
+
+    /// Open the owner's inbox with its items listed
+    pub fn for_game(game_state: &GameState, owner: FactionId) -> GameResult<Self> {
+        let mut panel = Self::new(owner);
+        panel.refresh_data(game_state)?;
+        panel.visible = true;
+        Ok(panel)
+    }
+
+    /// Reload the owner's inbox
+    pub fn refresh_data(&mut self, game_state: &GameState) -> GameResult<()> {
+        let faction = game_state.faction_manager.get_faction(self.owner)?;
+        self.items = faction.inbox.items().to_vec();
+        self.current_tick = game_state.get_current_tick();
+        self.rebuild_buttons();
+        Ok(())
+    }
+
+    /// Items listed, oldest first
+    pub fn items(&self) -> &[InboxItem] {
+        &self.items
+    }
+
+    /// Number of items waiting for an answer
+    pub fn badge_count(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Button text, with the count when anything is waiting
+    pub fn badge_text(&self) -> String {
+        match self.badge_count() {
+            0 => "Inbox".to_string(),
+            count => format!("Inbox ({})", count),
+        }
+    }
+
+    /// Show or hide the inbox
+    pub fn toggle(&mut self) {
+        self.visible = !self.visible;
+    }
+
+    /// Answer an item, removing it from the list until the next refresh
+    pub fn respond(&mut self, item: InboxItemId, response: InboxResponse) -> GameResult<PlayerCommand> {
+        let index = self.items.iter()
+            .position(|i| i.id == item)
+            .ok_or_else(|| GameError::InvalidTarget(format!("Inbox item {} is no longer pending", item)))?;
+        self.items.remove(index);
+        self.rebuild_buttons();
+        Ok(PlayerCommand::RespondToInbox { faction: self.owner, item, response })
+    }
+
+    /// Rebuild the badge label and the Accept/Decline buttons for listed items
+    fn rebuild_buttons(&mut self) {
+        self.badge_button = Button::new(self.badge_text())
+            .with_layout(Layout::new(10.0, 150.0, 110.0, 25.0))
+            .with_click_command(PlayerCommand::ShowFaction(self.owner));
+
+        let owner = self.owner;
+        self.response_buttons = self.items.iter()
+            .take(MAX_LISTED_ITEMS)
+            .enumerate()
+            .flat_map(|(i, item)| {
+                let y = 140.0 + i as f32 * 32.0;
+                let button = |label: &str, x: f32, response| Button::new(label.to_string())
+                    .with_layout(Layout::new(x, y, 70.0, 25.0))
+                    .with_click_command(PlayerCommand::RespondToInbox { faction: owner, item: item.id, response });
+                [
+                    button("Accept", 500.0, InboxResponse::Accept),
+                    button("Decline", 580.0, InboxResponse::Decline),
+                ]
+            })
+            .collect();
+    }
+}
+
+impl View for InboxPanel {
+    fn render(&mut self, context: &RenderContext) -> ComponentResult {
+        // The badge stays on screen so new items are noticed while the list is closed
+        self.badge_button.render(&(), context)?;
+        if !self.visible {
+            return Ok(None);
+        }
+
+        self.main_panel.render(&(), context)?;
+        if self.items.is_empty() {
+            draw_text("Nothing waiting for you", 220.0, 155.0, context.font_size, context.theme.text_color);
+        }
+        for (i, item) in self.items.iter().take(MAX_LISTED_ITEMS).enumerate() {
+            let text = describe_inbox_item(item, self.current_tick);
+            draw_text(&text, 220.0, 157.0 + i as f32 * 32.0, context.font_size * 0.8, context.theme.text_color);
+        }
+        for button in &mut self.response_buttons {
+            button.render(&(), context)?;
+        }
+        Ok(None)
+    }
+
+    fn handle_input(&mut self, input: &InputEvent) -> ComponentResult {
+        if let Ok(Some(_)) = self.badge_button.handle_input(input) {
+            self.toggle();
+            return Ok(None);
+        }
+        if !self.visible {
+            return Ok(None);
+        }
+
+        let mut clicked = None;
+        for button in &mut self.response_buttons {
+            if let Ok(Some(command)) = button.handle_input(input) {
+                clicked = Some(command);
+                break;
+            }
+        }
+        match clicked {
+            Some(PlayerCommand::RespondToInbox { item, response, .. }) => {
+                Ok(self.respond(item, response).ok())
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn update(&mut self, delta_time: f32) -> ComponentResult {
+        if self.visible {
+            self.main_panel.update(delta_time)?;
+        }
+        Ok(None)
+    }
+
+    fn update_data(&mut self, _data: ViewData) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    fn refresh(&mut self) -> ComponentResult {
+        self.rebuild_buttons();
+        Ok(None)
+    }
+
+    fn get_view_type(&self) -> &'static str {
+        INBOX_VIEW_TYPE
+    }
+}
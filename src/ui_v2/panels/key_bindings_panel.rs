@@ -0,0 +1,172 @@
+// src/ui_v2/panels/key_bindings_panel.rs
+//! Key bindings dialog
+//!
+//! Lists every input action with its key. Clicking an action waits for the
+//! next key press and binds it, swapping keys with any action that had it.
+//! The dialog works on a copy of the client's InputMap and reports each
+//! rebind as a BindKey command, which the client applies to its own map and
+//! saves. The menu key closes the dialog, so it cannot be chosen here.
+
+use crate::ui_v2::{
+    View, Panel, Button, RenderContext, ComponentResult, InputEvent, ViewData, Layout
+};
+use crate::ui_v2::components::base_component::UIComponent;
+use crate::ui_v2::core::input_map::{key_name, InputAction, InputMap};
+use crate::core::events::PlayerCommand;
+use macroquad::prelude::*;
+
+/// View type reported by the key bindings dialog
+pub const KEY_BINDINGS_VIEW_TYPE: &str = "KeyBindings";
+
+const PANEL_X: f32 = 162.0;
+const PANEL_Y: f32 = 120.0;
+const PANEL_WIDTH: f32 = 700.0;
+const ROW_HEIGHT: f32 = 34.0;
+const ROWS_PER_COLUMN: usize = InputAction::ALL.len().div_ceil(2);
+
+/// Modal dialog for rebinding keys
+pub struct KeyBindingsPanel {
+    main_panel: Panel,
+    action_buttons: Vec<(InputAction, Button)>,
+    close_button: Button,
+    input_map: InputMap,
+    /// Action waiting for its new key
+    awaiting: Option<InputAction>,
+    visible: bool,
+}
+
+impl KeyBindingsPanel {
+    /// Dialog over a copy of the bindings
+    pub fn new(input_map: InputMap) -> Self {
+        let height = 50.0 + ROWS_PER_COLUMN as f32 * ROW_HEIGHT + 50.0;
+        let mut panel = Self {
+            main_panel: Panel::new("Key Bindings".to_string())
+                .with_layout(Layout::new(PANEL_X, PANEL_Y, PANEL_WIDTH, height))
+                .collapsible(false),
+            action_buttons: Vec::new(),
+            close_button: Button::new("Close".to_string())
+                .with_layout(Layout::new(PANEL_X + PANEL_WIDTH - 110.0, PANEL_Y + height - 44.0, 100.0, 30.0)),
+            input_map,
+            awaiting: None,
+            visible: true,
+        };
+        panel.rebuild_buttons();
+        panel
+    }
+
+    /// Bindings as the dialog shows them
+    pub fn input_map(&self) -> &InputMap {
+        &self.input_map
+    }
+
+    /// Action waiting for a key press, if any
+    pub fn awaiting(&self) -> Option<InputAction> {
+        self.awaiting
+    }
+
+    /// Wait for the key to bind to the action
+    pub fn start_rebind(&mut self, action: InputAction) {
+        self.awaiting = Some(action);
+        self.rebuild_buttons();
+    }
+
+    /// Bind the key to the action waiting for one. Returns the command
+    /// reporting the change, or None if nothing was waiting or the key
+    /// cannot be bound.
+    pub fn press_key(&mut self, key: KeyCode) -> Option<PlayerCommand> {
+        let action = self.awaiting?;
+        let name = key_name(key)?;
+        self.input_map.bind(action, key).ok()?;
+        self.awaiting = None;
+        self.rebuild_buttons();
+        Some(PlayerCommand::BindKey { action: action.name().to_string(), key: name.to_string() })
+    }
+
+    /// Hide the dialog
+    pub fn close(&mut self) {
+        self.visible = false;
+    }
+
+    fn rebuild_buttons(&mut self) {
+        self.action_buttons = InputAction::ALL.iter()
+            .enumerate()
+            .map(|(i, action)| {
+                let key = if self.awaiting == Some(*action) {
+                    "press a key...".to_string()
+                } else {
+                    key_name(self.input_map.key(*action)).unwrap_or("?").to_string()
+                };
+                let column = (i / ROWS_PER_COLUMN) as f32;
+                let row = (i % ROWS_PER_COLUMN) as f32;
+                let layout = Layout::new(PANEL_X + 10.0 + column * (PANEL_WIDTH / 2.0), PANEL_Y + 40.0 + row * ROW_HEIGHT, PANEL_WIDTH / 2.0 - 20.0, ROW_HEIGHT - 6.0);
+                (*action, Button::new(format!("{}: {}", action.label(), key)).with_layout(layout))
+            })
+            .collect();
+    }
+}
+
+impl View for KeyBindingsPanel {
+    fn render(&mut self, context: &RenderContext) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        // Dim the game behind the dialog
+        draw_rectangle(0.0, 0.0, context.screen_width, context.screen_height, Color::new(0.0, 0.0, 0.0, 0.5));
+        self.main_panel.render(&(), context)?;
+        for (_, button) in &mut self.action_buttons {
+            button.render(&(), context)?;
+        }
+        self.close_button.render(&(), context)?;
+        Ok(None)
+    }
+
+    fn handle_input(&mut self, input: &InputEvent) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        match input {
+            InputEvent::KeyPress { key } => Ok(self.press_key(*key)),
+            InputEvent::MouseClick { x, y, button: MouseButton::Left } => {
+                let point = Vec2::new(*x, *y);
+                let hit = |button: &Button| button.get_layout().get_rect().contains(point);
+                if let Some(action) = self.action_buttons.iter().find(|(_, button)| hit(button)).map(|(action, _)| *action) {
+                    self.start_rebind(action);
+                } else if hit(&self.close_button) {
+                    self.close();
+                }
+                Ok(None)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn update(&mut self, delta_time: f32) -> ComponentResult {
+        if self.visible {
+            self.main_panel.update(delta_time)?;
+        }
+        Ok(None)
+    }
+
+    fn update_data(&mut self, _data: ViewData) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    fn refresh(&mut self) -> ComponentResult {
+        self.rebuild_buttons();
+        Ok(None)
+    }
+
+    fn get_view_type(&self) -> &'static str {
+        KEY_BINDINGS_VIEW_TYPE
+    }
+}
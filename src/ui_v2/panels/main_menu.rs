@@ -0,0 +1,158 @@
+// src/ui_v2/panels/main_menu.rs
+//! Main menu shown while the game is in GameMode::MainMenu
+//!
+//! Unlike the pause menu it is not a dialog but the MainMenu view, so the
+//! new game, load and options dialogs it opens stack above it. Each entry
+//! emits a command: New Game and Options ask the client for their dialogs,
+//! Load Game for the save/load dialog, and Quit leaves the application.
+
+use crate::ui_v2::{
+    View, Panel, Button, RenderContext, ComponentResult, InputEvent, ViewData, Layout
+};
+use crate::ui_v2::components::base_component::UIComponent;
+use crate::core::events::PlayerCommand;
+use macroquad::prelude::*;
+
+/// View type reported by the main menu
+pub const MAIN_MENU_VIEW_TYPE: &str = "MainMenu";
+
+const PANEL_X: f32 = 392.0;
+const PANEL_Y: f32 = 260.0;
+const PANEL_WIDTH: f32 = 240.0;
+
+/// Menu entries, top to bottom
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MainMenuEntry {
+    /// Open the new game dialog
+    NewGame,
+    /// Open the save/load dialog to load a save
+    LoadGame,
+    /// Open the options dialog
+    Options,
+    /// Leave the application
+    Quit,
+}
+
+impl MainMenuEntry {
+    /// Every entry, top to bottom
+    pub const ALL: [MainMenuEntry; 4] = [
+        MainMenuEntry::NewGame,
+        MainMenuEntry::LoadGame,
+        MainMenuEntry::Options,
+        MainMenuEntry::Quit,
+    ];
+
+    /// Text on the entry's button
+    pub fn label(&self) -> &'static str {
+        match self {
+            MainMenuEntry::NewGame => "New Game",
+            MainMenuEntry::LoadGame => "Load Game",
+            MainMenuEntry::Options => "Options",
+            MainMenuEntry::Quit => "Quit",
+        }
+    }
+
+    /// Command the entry emits
+    pub fn command(&self) -> PlayerCommand {
+        match self {
+            MainMenuEntry::NewGame => PlayerCommand::NewGame,
+            MainMenuEntry::LoadGame => PlayerCommand::LoadGame,
+            MainMenuEntry::Options => PlayerCommand::GameOptions,
+            MainMenuEntry::Quit => PlayerCommand::ExitGame,
+        }
+    }
+}
+
+/// Title screen with the main menu entries
+pub struct MainMenuView {
+    main_panel: Panel,
+    buttons: Vec<Button>,
+    visible: bool,
+}
+
+impl MainMenuView {
+    /// Menu with every entry
+    pub fn new() -> Self {
+        let buttons = MainMenuEntry::ALL.iter()
+            .enumerate()
+            .map(|(i, entry)| Button::new(entry.label().to_string())
+                .with_layout(Layout::new(PANEL_X + 20.0, PANEL_Y + 40.0 + i as f32 * 40.0, PANEL_WIDTH - 40.0, 30.0))
+                .with_click_command(entry.command()))
+            .collect();
+        Self {
+            main_panel: Panel::new("Main Menu".to_string())
+                .with_layout(Layout::new(PANEL_X, PANEL_Y, PANEL_WIDTH, 60.0 + MainMenuEntry::ALL.len() as f32 * 40.0))
+                .collapsible(false),
+            buttons,
+            visible: true,
+        }
+    }
+
+    /// Choose an entry as if its button was clicked
+    pub fn select(&mut self, entry: MainMenuEntry) -> PlayerCommand {
+        entry.command()
+    }
+}
+
+impl Default for MainMenuView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl View for MainMenuView {
+    fn render(&mut self, context: &RenderContext) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        draw_text("STELLAR DOMINION", PANEL_X - 130.0, PANEL_Y - 60.0, 60.0, context.theme.text_color);
+        self.main_panel.render(&(), context)?;
+        for button in &mut self.buttons {
+            button.render(&(), context)?;
+        }
+        Ok(None)
+    }
+
+    fn handle_input(&mut self, input: &InputEvent) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        let mut clicked = None;
+        for (i, button) in self.buttons.iter_mut().enumerate() {
+            if let Ok(Some(_)) = button.handle_input(input) {
+                clicked = Some(MainMenuEntry::ALL[i]);
+                break;
+            }
+        }
+        Ok(clicked.map(|entry| self.select(entry)))
+    }
+
+    fn update(&mut self, delta_time: f32) -> ComponentResult {
+        if self.visible {
+            self.main_panel.update(delta_time)?;
+        }
+        Ok(None)
+    }
+
+    fn update_data(&mut self, _data: ViewData) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    fn refresh(&mut self) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn get_view_type(&self) -> &'static str {
+        MAIN_MENU_VIEW_TYPE
+    }
+}
@@ -0,0 +1,239 @@
+// src/ui_v2/panels/market_panel.rs
+//! Galactic market dialog
+//!
+//! Lists the six resources with their current price, what a lot costs to buy
+//! and fetches when sold, the stock held at the faction's capital and a chart
+//! of recent prices. Buy and Sell buttons emit MarketBuy and MarketSell for
+//! one lot, traded through the capital. The dialog shows a snapshot; the
+//! client reopens it after each tick to show the new prices.
+
+use crate::ui_v2::{
+    View, Panel, Button, RenderContext, ComponentResult, InputEvent, ViewData, Layout
+};
+use crate::ui_v2::components::base_component::UIComponent;
+use crate::core::{types::*, events::PlayerCommand, GameResult};
+use crate::GameState;
+use macroquad::prelude::*;
+
+/// View type reported by the market dialog
+pub const MARKET_VIEW_TYPE: &str = "Market";
+/// Units bought or sold per click
+pub const TRADE_LOT: i32 = 100;
+
+const PANEL_X: f32 = 212.0;
+const PANEL_Y: f32 = 120.0;
+const PANEL_WIDTH: f32 = 600.0;
+const ROW_HEIGHT: f32 = 40.0;
+const CHART_X: f32 = PANEL_X + 300.0;
+const CHART_WIDTH: f32 = 120.0;
+
+/// One resource as listed in the market
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarketRow {
+    /// Resource listed
+    pub resource: ResourceType,
+    /// Market price of one unit
+    pub price: f32,
+    /// Credits paid for one lot
+    pub lot_cost: i32,
+    /// Credits received for one lot
+    pub lot_revenue: i32,
+    /// Units held at the faction's capital
+    pub stock: i32,
+    /// Recent prices, oldest first
+    pub history: Vec<f32>,
+}
+
+/// Modal dialog for trading resources for credits
+pub struct MarketPanel {
+    main_panel: Panel,
+    trade_buttons: Vec<Button>,
+    close_button: Button,
+
+    owner: FactionId,
+    capital: Option<PlanetId>,
+    credits: i32,
+    rows: Vec<MarketRow>,
+    visible: bool,
+}
+
+impl MarketPanel {
+    /// Dialog for the faction trading through `capital`
+    pub fn new(owner: FactionId, capital: Option<PlanetId>, credits: i32, rows: Vec<MarketRow>) -> Self {
+        let list_bottom = PANEL_Y + 60.0 + rows.len() as f32 * ROW_HEIGHT;
+        let mut panel = Self {
+            main_panel: Panel::new("Galactic Market".to_string())
+                .with_layout(Layout::new(PANEL_X, PANEL_Y, PANEL_WIDTH, list_bottom - PANEL_Y + 50.0))
+                .collapsible(false),
+            trade_buttons: Vec::new(),
+            close_button: Button::new("Close".to_string())
+                .with_layout(Layout::new(PANEL_X + 20.0, list_bottom + 10.0, 120.0, 30.0)),
+            owner,
+            capital,
+            credits,
+            rows,
+            visible: true,
+        };
+        panel.rebuild_buttons();
+        panel
+    }
+
+    /// Market as seen by the faction, trading through its capital (its first planet)
+    pub fn for_game(game_state: &GameState, owner: FactionId) -> GameResult<Self> {
+        let credits = game_state.faction_manager.get_faction(owner)?.credits;
+        let capital = game_state.planet_manager.get_planets_by_faction(owner).first().map(|planet| planet.id);
+        let stock = match capital {
+            Some(capital) => game_state.planet_manager.get_planet(capital)?.resources.current,
+            None => ResourceBundle::default(),
+        };
+        let market = &game_state.market_system;
+        let rows = ResourceType::ALL.iter()
+            .map(|&resource| Ok(MarketRow {
+                resource,
+                price: market.price(resource),
+                lot_cost: market.buy_cost(resource, TRADE_LOT)?,
+                lot_revenue: market.sell_revenue(resource, TRADE_LOT)?,
+                stock: stock.get(resource),
+                history: market.price_history(resource).map(|point| point.price).collect(),
+            }))
+            .collect::<GameResult<Vec<_>>>()?;
+        Ok(Self::new(owner, capital, credits, rows))
+    }
+
+    /// Credits the faction had when the dialog opened
+    pub fn credits(&self) -> i32 {
+        self.credits
+    }
+
+    /// Listed resources, in [`ResourceType::ALL`] order
+    pub fn rows(&self) -> &[MarketRow] {
+        &self.rows
+    }
+
+    /// Order for one lot of the resource; None without a capital to trade through
+    pub fn order(&self, resource: ResourceType, buy: bool) -> Option<PlayerCommand> {
+        let planet = self.capital?;
+        Some(if buy {
+            PlayerCommand::MarketBuy { faction: self.owner, planet, resource, amount: TRADE_LOT }
+        } else {
+            PlayerCommand::MarketSell { faction: self.owner, planet, resource, amount: TRADE_LOT }
+        })
+    }
+
+    /// Hide the dialog
+    pub fn close(&mut self) {
+        self.visible = false;
+    }
+
+    /// Buy and Sell buttons for every row
+    fn rebuild_buttons(&mut self) {
+        let rows: Vec<ResourceType> = self.rows.iter().map(|row| row.resource).collect();
+        self.trade_buttons = rows.into_iter()
+            .enumerate()
+            .flat_map(|(i, resource)| {
+                let y = PANEL_Y + 60.0 + i as f32 * ROW_HEIGHT;
+                [(true, "Buy", PANEL_X + 440.0), (false, "Sell", PANEL_X + 515.0)].into_iter()
+                    .filter_map(|(buy, label, x)| self.order(resource, buy).map(|command| {
+                        Button::new(format!("{} {}", label, TRADE_LOT))
+                            .with_layout(Layout::new(x, y, 70.0, ROW_HEIGHT - 8.0))
+                            .with_click_command(command)
+                    }))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+    }
+
+    /// Line chart of the row's recent prices, scaled to its own range
+    fn draw_history(row: &MarketRow, y: f32, color: Color) {
+        let height = ROW_HEIGHT - 12.0;
+        draw_rectangle_lines(CHART_X, y, CHART_WIDTH, height, 1.0, DARKGRAY);
+        if row.history.len() < 2 {
+            return;
+        }
+        let low = row.history.iter().copied().fold(f32::INFINITY, f32::min);
+        let high = row.history.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+        let range = (high - low).max(f32::EPSILON);
+        let step = CHART_WIDTH / (row.history.len() - 1) as f32;
+        let point = |i: usize, price: f32| vec2(CHART_X + i as f32 * step, y + height - (price - low) / range * height);
+        for (i, pair) in row.history.windows(2).enumerate() {
+            let (from, to) = (point(i, pair[0]), point(i + 1, pair[1]));
+            draw_line(from.x, from.y, to.x, to.y, 1.5, color);
+        }
+    }
+}
+
+impl View for MarketPanel {
+    fn render(&mut self, context: &RenderContext) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        // Dim the game behind the dialog
+        draw_rectangle(0.0, 0.0, context.screen_width, context.screen_height, Color::new(0.0, 0.0, 0.0, 0.5));
+        self.main_panel.render(&(), context)?;
+        let text_color = context.theme.text_color;
+        draw_text(&format!("Credits: {}", self.credits), PANEL_X + 20.0, PANEL_Y + 45.0, context.font_size, text_color);
+        if self.capital.is_none() {
+            draw_text("No planet to trade through", PANEL_X + 200.0, PANEL_Y + 45.0, context.font_size, context.theme.error_color);
+        }
+        for (i, row) in self.rows.iter().enumerate() {
+            let y = PANEL_Y + 60.0 + i as f32 * ROW_HEIGHT;
+            draw_text(
+                &format!("{:?}  {:.2}  buy {} / sell {}  held {}", row.resource, row.price, row.lot_cost, row.lot_revenue, row.stock),
+                PANEL_X + 20.0, y + 20.0, context.font_size * 0.8, text_color
+            );
+            Self::draw_history(row, y + 2.0, context.theme.accent_color);
+        }
+        for button in &mut self.trade_buttons {
+            button.render(&(), context)?;
+        }
+        self.close_button.render(&(), context)?;
+        Ok(None)
+    }
+
+    fn handle_input(&mut self, input: &InputEvent) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        for button in &mut self.trade_buttons {
+            if let Ok(Some(command)) = button.handle_input(input) {
+                return Ok(Some(command));
+            }
+        }
+        if let InputEvent::MouseClick { x, y, button: MouseButton::Left } = input {
+            if self.close_button.get_layout().get_rect().contains(Vec2::new(*x, *y)) {
+                self.close();
+            }
+        }
+        Ok(None)
+    }
+
+    fn update(&mut self, delta_time: f32) -> ComponentResult {
+        if self.visible {
+            self.main_panel.update(delta_time)?;
+        }
+        Ok(None)
+    }
+
+    fn update_data(&mut self, _data: ViewData) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    fn refresh(&mut self) -> ComponentResult {
+        self.rebuild_buttons();
+        Ok(None)
+    }
+
+    fn get_view_type(&self) -> &'static str {
+        MARKET_VIEW_TYPE
+    }
+}
@@ -7,7 +7,55 @@
 pub mod planet_panel_migrated;
 pub mod ship_panel_migrated;
 pub mod resource_panel_migrated;
+pub mod shuttle_run_wizard;
+pub mod inbox_panel;
+pub mod pause_menu;
+pub mod save_load_panel;
+pub mod market_panel;
+pub mod notification_panel;
+pub mod objectives_panel;
+pub mod timeline_panel;
+pub mod key_bindings_panel;
+pub mod empire_overview;
+pub mod fleet_overview;
+pub mod build_menu;
+pub mod transfer_dialog;
+pub mod bombardment_dialog;
+pub mod order_menu;
+pub mod selection_panel;
+pub mod ship_designer;
+pub mod main_menu;
+pub mod new_game_menu;
+pub mod options_menu;
+pub mod game_over;
+pub mod graph_panel;
+#[cfg(feature = "debug")]
+pub mod debug_console;
 
 pub use planet_panel_migrated::PlanetPanelMigrated;
 pub use ship_panel_migrated::ShipPanelMigrated;
-pub use resource_panel_migrated::ResourcePanelMigrated;
\ No newline at end of file
+pub use resource_panel_migrated::ResourcePanelMigrated;
+pub use shuttle_run_wizard::{ShuttleRunWizard, SHUTTLE_RUN_WIZARD_VIEW_TYPE};
+pub use inbox_panel::{InboxPanel, INBOX_VIEW_TYPE};
+pub use pause_menu::PauseMenu;
+pub use save_load_panel::{SaveLoadPanel, SaveLoadMode};
+pub use market_panel::{MarketPanel, MarketRow, MARKET_VIEW_TYPE, TRADE_LOT};
+pub use notification_panel::{NotificationPanel, NOTIFICATIONS_VIEW_TYPE};
+pub use objectives_panel::{ObjectivesPanel, OBJECTIVES_VIEW_TYPE};
+pub use timeline_panel::{TimelinePanel, TIMELINE_VIEW_TYPE};
+pub use key_bindings_panel::{KeyBindingsPanel, KEY_BINDINGS_VIEW_TYPE};
+pub use empire_overview::{EmpireOverviewView, EMPIRE_OVERVIEW_VIEW_TYPE};
+pub use fleet_overview::{FleetOverviewView, FleetEntry, FLEET_OVERVIEW_VIEW_TYPE};
+pub use build_menu::{BuildMenuView, BUILD_MENU_VIEW_TYPE};
+pub use transfer_dialog::{ResourceTransferDialog, TransferDestination, TRANSFER_DIALOG_VIEW_TYPE};
+pub use bombardment_dialog::{BombardmentDialog, BOMBARDMENT_DIALOG_VIEW_TYPE};
+pub use order_menu::{OrderMenu, ORDER_MENU_VIEW_TYPE};
+pub use selection_panel::{SelectionPanel, SELECTION_PANEL_VIEW_TYPE};
+pub use ship_designer::{ShipDesignerPanel, SHIP_DESIGNER_VIEW_TYPE};
+pub use main_menu::{MainMenuView, MainMenuEntry, MAIN_MENU_VIEW_TYPE};
+pub use new_game_menu::{NewGameMenu, NEW_GAME_MENU_VIEW_TYPE};
+pub use options_menu::{OptionsMenu, OPTIONS_MENU_VIEW_TYPE};
+pub use game_over::{GameOverView, GAME_OVER_VIEW_TYPE};
+pub use graph_panel::{GraphPanel, GRAPH_PANEL_VIEW_TYPE};
+#[cfg(feature = "debug")]
+pub use debug_console::DebugConsole;
\ No newline at end of file
@@ -0,0 +1,352 @@
+// src/ui_v2/panels/new_game_menu.rs
+//! New game dialog choosing the galaxy to generate
+//!
+//! Opened from the main menu over a copy of the current game configuration.
+//! Picking a galaxy size applies `GameConfiguration::for_galaxy_size` while
+//! keeping the chosen difficulty and seed; the number of AI opponents,
+//! difficulty and layout seed are then adjusted on their own, and the
+//! tutorial objectives, pirate raids and missile combat switched on or off,
+//! and the number of star systems picked from [`STAR_SYSTEM_CHOICES`]. Start checks the settings with
+//! the GalaxyGenerator first and shows why they fail instead of emitting
+//! StartNewGame for a galaxy that cannot be built.
+
+use crate::ui_v2::{View, Panel, Button, RenderContext, ComponentResult, InputEvent, ViewData, Layout};
+use crate::ui_v2::components::base_component::UIComponent;
+use crate::core::{types::*, events::PlayerCommand};
+use crate::systems::{GalaxyGenerator, StartGenerator};
+use crate::GameState;
+use macroquad::prelude::*;
+
+/// View type reported by the new game dialog
+pub const NEW_GAME_MENU_VIEW_TYPE: &str = "NewGameMenu";
+
+/// Most AI opponents offered, one per AI faction name
+pub const MAX_AI_OPPONENTS: usize = 8;
+
+const PANEL_X: f32 = 262.0;
+const PANEL_Y: f32 = 140.0;
+const PANEL_WIDTH: f32 = 500.0;
+const PANEL_HEIGHT: f32 = 600.0;
+const LABEL_X: f32 = PANEL_X + 20.0;
+const CONTROLS_X: f32 = PANEL_X + 160.0;
+const ROW_HEIGHT: f32 = 50.0;
+const CHOICE_WIDTH: f32 = 100.0;
+
+const DIFFICULTIES: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard];
+/// Star system counts offered
+pub const STAR_SYSTEM_CHOICES: [usize; 3] = [1, 3, 5];
+
+/// Modal dialog with the settings for a new game
+pub struct NewGameMenu {
+    main_panel: Panel,
+    size_buttons: Vec<(GalaxySize, Button)>,
+    fewer_opponents_button: Button,
+    more_opponents_button: Button,
+    difficulty_buttons: Vec<(Difficulty, Button)>,
+    tutorial_buttons: Vec<(bool, Button)>,
+    pirate_buttons: Vec<(bool, Button)>,
+    star_system_buttons: Vec<(usize, Button)>,
+    missile_buttons: Vec<(bool, Button)>,
+    reroll_button: Button,
+    start_button: Button,
+    back_button: Button,
+    configuration: GameConfiguration,
+    /// Why the last Start was refused
+    error: Option<String>,
+    visible: bool,
+}
+
+impl NewGameMenu {
+    /// Dialog starting from the given settings
+    pub fn new(configuration: GameConfiguration) -> Self {
+        let choice = |i: usize, row: usize, label: String| Button::new(label)
+            .with_layout(Layout::new(CONTROLS_X + i as f32 * (CHOICE_WIDTH + 10.0), Self::row_y(row), CHOICE_WIDTH, 30.0));
+        let buttons_y = PANEL_Y + PANEL_HEIGHT - 50.0;
+
+        Self {
+            main_panel: Panel::new("New Game".to_string())
+                .with_layout(Layout::new(PANEL_X, PANEL_Y, PANEL_WIDTH, PANEL_HEIGHT))
+                .collapsible(false),
+            size_buttons: GalaxySize::ALL.iter()
+                .enumerate()
+                .map(|(i, size)| (*size, choice(i, 0, format!("{:?}", size))))
+                .collect(),
+            fewer_opponents_button: choice(0, 1, "-".to_string()),
+            more_opponents_button: choice(2, 1, "+".to_string()),
+            difficulty_buttons: DIFFICULTIES.iter()
+                .enumerate()
+                .map(|(i, difficulty)| (*difficulty, choice(i, 2, format!("{:?}", difficulty))))
+                .collect(),
+            tutorial_buttons: vec![
+                (false, choice(0, 4, "Off".to_string())),
+                (true, choice(1, 4, "On".to_string())),
+            ],
+            pirate_buttons: vec![
+                (false, choice(0, 5, "Off".to_string())),
+                (true, choice(1, 5, "On".to_string())),
+            ],
+            star_system_buttons: STAR_SYSTEM_CHOICES.iter()
+                .enumerate()
+                .map(|(i, count)| (*count, choice(i, 6, count.to_string())))
+                .collect(),
+            missile_buttons: vec![
+                (false, choice(0, 7, "Off".to_string())),
+                (true, choice(1, 7, "On".to_string())),
+            ],
+            reroll_button: choice(2, 3, "Re-roll".to_string()),
+            start_button: Button::new("Start".to_string())
+                .with_layout(Layout::new(PANEL_X + 20.0, buttons_y, 120.0, 30.0)),
+            back_button: Button::new("Back".to_string())
+                .with_layout(Layout::new(PANEL_X + 160.0, buttons_y, 120.0, 30.0)),
+            configuration,
+            error: None,
+            visible: true,
+        }
+    }
+
+    /// Dialog over the game's current configuration
+    pub fn for_game(game_state: &GameState) -> Self {
+        Self::new(game_state.get_game_configuration().clone())
+    }
+
+    /// Settings the game would start with
+    pub fn configuration(&self) -> &GameConfiguration {
+        &self.configuration
+    }
+
+    /// Switch to a galaxy size's preset, keeping the difficulty, seed,
+    /// autosave, tutorial, pirate, star system and missile settings
+    pub fn set_galaxy_size(&mut self, galaxy_size: GalaxySize) {
+        let current = &self.configuration;
+        self.configuration = GameConfiguration {
+            difficulty: current.difficulty,
+            seed: current.seed,
+            distribution: current.distribution,
+            autosave: current.autosave,
+            tutorial: current.tutorial,
+            pirates: current.pirates,
+            star_systems: current.star_systems,
+            missiles: current.missiles,
+            ..GameConfiguration::for_galaxy_size(galaxy_size)
+        };
+        self.error = None;
+    }
+
+    /// Set the number of AI empires, up to [`MAX_AI_OPPONENTS`]
+    pub fn set_ai_opponents(&mut self, ai_opponents: usize) {
+        self.configuration.ai_opponents = ai_opponents.min(MAX_AI_OPPONENTS);
+        self.error = None;
+    }
+
+    /// Set the difficulty the AI empires play at
+    pub fn set_difficulty(&mut self, difficulty: Difficulty) {
+        self.configuration.difficulty = difficulty;
+        self.error = None;
+    }
+
+    /// Give the player the tutorial objectives, or not
+    pub fn set_tutorial(&mut self, tutorial: bool) {
+        self.configuration.tutorial = tutorial;
+        self.error = None;
+    }
+
+    /// Have pirates raid the galaxy, or not
+    pub fn set_pirates(&mut self, pirates: bool) {
+        self.configuration.pirates = pirates;
+        self.error = None;
+    }
+
+    /// Spread the planets over this many star systems
+    pub fn set_star_systems(&mut self, star_systems: usize) {
+        self.configuration.star_systems = star_systems.max(1);
+        self.error = None;
+    }
+
+    /// Fight ship battles with missiles, or not
+    pub fn set_missiles(&mut self, missiles: bool) {
+        self.configuration.missiles = missiles;
+        self.error = None;
+    }
+
+    /// Move to the next layout seed and return it
+    pub fn reroll_seed(&mut self) -> u64 {
+        self.configuration.seed = StartGenerator::from_configuration(&self.configuration).reroll();
+        self.error = None;
+        self.configuration.seed
+    }
+
+    /// Why the last attempt to start was refused
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Start a game with these settings and close; None, with the reason
+    /// kept in [`error`](Self::error), when they cannot generate a galaxy
+    pub fn start(&mut self) -> Option<PlayerCommand> {
+        if let Err(e) = GalaxyGenerator::from_configuration(&self.configuration).generate() {
+            self.error = Some(e.to_string());
+            return None;
+        }
+        self.close();
+        Some(PlayerCommand::StartNewGame(self.configuration.clone()))
+    }
+
+    /// Hide the dialog without starting
+    pub fn close(&mut self) {
+        self.visible = false;
+    }
+
+    fn row_y(row: usize) -> f32 {
+        PANEL_Y + 50.0 + row as f32 * ROW_HEIGHT
+    }
+}
+
+impl View for NewGameMenu {
+    fn render(&mut self, context: &RenderContext) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        // Dim the menu behind the dialog
+        draw_rectangle(0.0, 0.0, context.screen_width, context.screen_height, Color::new(0.0, 0.0, 0.0, 0.5));
+        self.main_panel.render(&(), context)?;
+        let label = |text: &str, row: usize| {
+            draw_text(text, LABEL_X, Self::row_y(row) + 20.0, context.font_size, context.theme.text_color);
+        };
+        label("Galaxy size", 0);
+        label("AI opponents", 1);
+        label("Difficulty", 2);
+        label("Layout seed", 3);
+        label("Tutorial", 4);
+        label("Pirates", 5);
+        label("Star systems", 6);
+        label("Missiles", 7);
+
+        for (size, button) in &mut self.size_buttons {
+            button.render(&(), context)?;
+            if *size == self.configuration.galaxy_size {
+                let rect = button.get_layout().get_rect();
+                draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 2.0, context.theme.accent_color);
+            }
+        }
+        for (difficulty, button) in &mut self.difficulty_buttons {
+            button.render(&(), context)?;
+            if *difficulty == self.configuration.difficulty {
+                let rect = button.get_layout().get_rect();
+                draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 2.0, context.theme.accent_color);
+            }
+        }
+        for (tutorial, button) in &mut self.tutorial_buttons {
+            button.render(&(), context)?;
+            if *tutorial == self.configuration.tutorial {
+                let rect = button.get_layout().get_rect();
+                draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 2.0, context.theme.accent_color);
+            }
+        }
+        for (pirates, button) in &mut self.pirate_buttons {
+            button.render(&(), context)?;
+            if *pirates == self.configuration.pirates {
+                let rect = button.get_layout().get_rect();
+                draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 2.0, context.theme.accent_color);
+            }
+        }
+        for (count, button) in &mut self.star_system_buttons {
+            button.render(&(), context)?;
+            if *count == self.configuration.star_systems {
+                let rect = button.get_layout().get_rect();
+                draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 2.0, context.theme.accent_color);
+            }
+        }
+        for (missiles, button) in &mut self.missile_buttons {
+            button.render(&(), context)?;
+            if *missiles == self.configuration.missiles {
+                let rect = button.get_layout().get_rect();
+                draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 2.0, context.theme.accent_color);
+            }
+        }
+        self.fewer_opponents_button.render(&(), context)?;
+        self.more_opponents_button.render(&(), context)?;
+        let value_x = CONTROLS_X + CHOICE_WIDTH + 50.0;
+        draw_text(&self.configuration.ai_opponents.to_string(), value_x, Self::row_y(1) + 20.0, context.font_size, context.theme.text_color);
+        draw_text(&self.configuration.seed.to_string(), CONTROLS_X, Self::row_y(3) + 20.0, context.font_size, context.theme.text_color);
+        self.reroll_button.render(&(), context)?;
+
+        let summary = format!(
+            "{} planets, {} settlers per home world",
+            self.configuration.planet_count, self.configuration.starting_population
+        );
+        draw_text(&summary, LABEL_X, Self::row_y(8) + 20.0, context.font_size * 0.9, context.theme.secondary_text_color);
+        if let Some(error) = &self.error {
+            draw_text(error, LABEL_X, Self::row_y(9) + 10.0, context.font_size * 0.8, context.theme.error_color);
+        }
+
+        self.start_button.render(&(), context)?;
+        self.back_button.render(&(), context)?;
+        Ok(None)
+    }
+
+    fn handle_input(&mut self, input: &InputEvent) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        if let InputEvent::MouseClick { x, y, button: MouseButton::Left } = input {
+            let point = Vec2::new(*x, *y);
+            let hit = |button: &Button| button.get_layout().get_rect().contains(point);
+            if hit(&self.start_button) {
+                return Ok(self.start());
+            }
+            if hit(&self.back_button) {
+                self.close();
+                return Ok(None);
+            }
+            if let Some(size) = self.size_buttons.iter().find(|(_, button)| hit(button)).map(|(size, _)| *size) {
+                self.set_galaxy_size(size);
+            } else if let Some(difficulty) = self.difficulty_buttons.iter().find(|(_, button)| hit(button)).map(|(difficulty, _)| *difficulty) {
+                self.set_difficulty(difficulty);
+            } else if let Some(tutorial) = self.tutorial_buttons.iter().find(|(_, button)| hit(button)).map(|(tutorial, _)| *tutorial) {
+                self.set_tutorial(tutorial);
+            } else if let Some(pirates) = self.pirate_buttons.iter().find(|(_, button)| hit(button)).map(|(pirates, _)| *pirates) {
+                self.set_pirates(pirates);
+            } else if let Some(count) = self.star_system_buttons.iter().find(|(_, button)| hit(button)).map(|(count, _)| *count) {
+                self.set_star_systems(count);
+            } else if let Some(missiles) = self.missile_buttons.iter().find(|(_, button)| hit(button)).map(|(missiles, _)| *missiles) {
+                self.set_missiles(missiles);
+            } else if hit(&self.fewer_opponents_button) {
+                self.set_ai_opponents(self.configuration.ai_opponents.saturating_sub(1));
+            } else if hit(&self.more_opponents_button) {
+                self.set_ai_opponents(self.configuration.ai_opponents + 1);
+            } else if hit(&self.reroll_button) {
+                self.reroll_seed();
+            }
+        }
+        Ok(None)
+    }
+
+    fn update(&mut self, delta_time: f32) -> ComponentResult {
+        if self.visible {
+            self.main_panel.update(delta_time)?;
+        }
+        Ok(None)
+    }
+
+    fn update_data(&mut self, _data: ViewData) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    fn refresh(&mut self) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn get_view_type(&self) -> &'static str {
+        NEW_GAME_MENU_VIEW_TYPE
+    }
+}
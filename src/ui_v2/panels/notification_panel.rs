@@ -0,0 +1,257 @@
+// src/ui_v2/panels/notification_panel.rs
+//! Toasts and alert log for the player's notifications
+//!
+//! New notifications pop up as toasts for [`TOAST_TICKS`] ticks, each with a
+//! button to dismiss it. The Alerts badge opens the full log, most urgent
+//! first, where notifications can be dismissed one by one or all at once.
+//! Dismissing emits `PlayerCommand::DismissNotification` and drops the entry
+//! locally until the next `ViewData::Notifications` update.
+
+use crate::ui_v2::{
+    View, Panel, Button, RenderContext, ComponentResult, InputEvent, ViewData, Layout
+};
+use crate::ui_v2::components::base_component::UIComponent;
+use crate::core::{types::*, events::PlayerCommand};
+use crate::systems::{Notification, NotificationKind, NotificationPriority};
+use crate::GameState;
+use macroquad::prelude::*;
+
+/// View type reported by the notification panel
+pub const NOTIFICATIONS_VIEW_TYPE: &str = "Notifications";
+/// Ticks a new notification stays up as a toast
+pub const TOAST_TICKS: u64 = 50;
+/// Toasts shown at once; the most urgent win
+const MAX_TOASTS: usize = 3;
+/// Log entries listed at once
+const MAX_LISTED: usize = 10;
+
+const TOAST_X: f32 = 560.0;
+const TOAST_WIDTH: f32 = 400.0;
+const LOG_X: f32 = 200.0;
+const LOG_Y: f32 = 100.0;
+
+/// One-line summary of a notification
+pub fn describe_notification(notification: &Notification) -> String {
+    match &notification.kind {
+        NotificationKind::LowStockpile { planet, resource, ticks_left } => {
+            format!("Planet {} runs out of {:?} in {} ticks", planet, resource, ticks_left)
+        }
+        NotificationKind::ResourceShortage { planet, resource } => {
+            format!("Planet {} is out of {:?}", planet, resource)
+        }
+        NotificationKind::UpkeepUnpaid { planet } => format!("Planet {} cannot pay its upkeep", planet),
+        NotificationKind::ShipStranded { ship, required, available } => {
+            format!("Ship {} is stranded: needs {:.0} fuel, has {:.0}", ship, required, available)
+        }
+        NotificationKind::CombatStarted { ship, enemy } => format!("Ship {} is in combat with ship {}", ship, enemy),
+        NotificationKind::PlanetBlockaded { planet } => format!("Planet {} is blockaded", planet),
+        NotificationKind::PlanetInvaded { planet } => format!("Planet {} is being invaded", planet),
+        NotificationKind::PlanetBombarded { planet } => format!("Planet {} is under orbital bombardment", planet),
+        NotificationKind::PlanetColonized { planet } => format!("Colony founded on planet {}", planet),
+        NotificationKind::PirateRaid { planet } => format!("Pirates are raiding planet {}", planet),
+    }
+}
+
+/// Toasts, Alerts badge and alert log for one faction
+pub struct NotificationPanel {
+    log_panel: Panel,
+    badge_button: Button,
+    dismiss_all_button: Button,
+    toast_buttons: Vec<Button>,
+    log_buttons: Vec<Button>,
+
+    owner: FactionId,
+    notifications: Vec<Notification>,
+    current_tick: u64,
+    log_open: bool,
+    visible: bool,
+}
+
+impl NotificationPanel {
+    /// Panel for the faction, empty until the first data update
+    pub fn new(owner: FactionId) -> Self {
+        let mut panel = Self {
+            log_panel: Panel::new("Alerts".to_string())
+                .with_layout(Layout::new(LOG_X, LOG_Y, 600.0, 80.0 + MAX_LISTED as f32 * 30.0))
+                .collapsible(false),
+            badge_button: Button::new("Alerts".to_string()),
+            dismiss_all_button: Button::new("Dismiss all".to_string())
+                .with_layout(Layout::new(LOG_X + 20.0, LOG_Y + 40.0 + MAX_LISTED as f32 * 30.0, 120.0, 25.0))
+                .with_click_command(PlayerCommand::DismissNotification { faction: owner, notification: None }),
+            toast_buttons: Vec::new(),
+            log_buttons: Vec::new(),
+            owner,
+            notifications: Vec::new(),
+            current_tick: 0,
+            log_open: false,
+            visible: true,
+        };
+        panel.rebuild_buttons();
+        panel
+    }
+
+    /// The faction's current notifications, as sent to the panel by the client
+    pub fn view_data(game_state: &GameState, owner: FactionId) -> ViewData {
+        ViewData::Notifications {
+            notifications: game_state.notification_system.notifications(owner).into_iter().cloned().collect(),
+            tick: game_state.get_current_tick(),
+        }
+    }
+
+    /// Notifications in the log, most urgent first
+    pub fn notifications(&self) -> &[Notification] {
+        &self.notifications
+    }
+
+    /// Notifications still young enough to show as toasts, most urgent first
+    pub fn toasts(&self) -> impl Iterator<Item = &Notification> {
+        let current_tick = self.current_tick;
+        self.notifications.iter()
+            .filter(move |notification| current_tick < notification.tick + TOAST_TICKS)
+            .take(MAX_TOASTS)
+    }
+
+    /// True while the full log is shown
+    pub fn is_log_open(&self) -> bool {
+        self.log_open
+    }
+
+    /// Show or hide the full log
+    pub fn toggle_log(&mut self) {
+        self.log_open = !self.log_open;
+    }
+
+    /// Dismiss a notification, or all of them with `None`, until the next update
+    pub fn dismiss(&mut self, notification: Option<NotificationId>) -> PlayerCommand {
+        match notification {
+            Some(id) => self.notifications.retain(|n| n.id != id),
+            None => self.notifications.clear(),
+        }
+        self.rebuild_buttons();
+        PlayerCommand::DismissNotification { faction: self.owner, notification }
+    }
+
+    /// Rebuild the badge label and the dismiss buttons for toasts and listed entries
+    fn rebuild_buttons(&mut self) {
+        let badge = match self.notifications.len() {
+            0 => "Alerts".to_string(),
+            count => format!("Alerts ({})", count),
+        };
+        self.badge_button = Button::new(badge).with_layout(Layout::new(10.0, 200.0, 110.0, 25.0));
+
+        let owner = self.owner;
+        let dismiss = |id, x, y| Button::new("x".to_string())
+            .with_layout(Layout::new(x, y, 25.0, 25.0))
+            .with_click_command(PlayerCommand::DismissNotification { faction: owner, notification: Some(id) });
+        self.toast_buttons = self.toasts()
+            .enumerate()
+            .map(|(i, toast)| dismiss(toast.id, TOAST_X + TOAST_WIDTH - 30.0, 15.0 + i as f32 * 34.0))
+            .collect();
+        self.log_buttons = self.notifications.iter()
+            .take(MAX_LISTED)
+            .enumerate()
+            .map(|(i, notification)| dismiss(notification.id, LOG_X + 560.0, LOG_Y + 35.0 + i as f32 * 30.0))
+            .collect();
+    }
+
+    fn priority_color(priority: NotificationPriority, context: &RenderContext) -> Color {
+        match priority {
+            NotificationPriority::Critical => context.theme.error_color,
+            NotificationPriority::High => context.theme.warning_color,
+            NotificationPriority::Normal => context.theme.text_color,
+        }
+    }
+}
+
+impl View for NotificationPanel {
+    fn render(&mut self, context: &RenderContext) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        self.badge_button.render(&(), context)?;
+        for (i, toast) in self.toasts().enumerate() {
+            let y = 10.0 + i as f32 * 34.0;
+            draw_rectangle(TOAST_X, y, TOAST_WIDTH, 30.0, Color::new(0.0, 0.0, 0.0, 0.8));
+            draw_rectangle_lines(TOAST_X, y, TOAST_WIDTH, 30.0, 2.0, Self::priority_color(toast.priority, context));
+            draw_text(&describe_notification(toast), TOAST_X + 8.0, y + 20.0, context.font_size * 0.8, context.theme.text_color);
+        }
+        for button in &mut self.toast_buttons {
+            button.render(&(), context)?;
+        }
+
+        if self.log_open {
+            self.log_panel.render(&(), context)?;
+            if self.notifications.is_empty() {
+                draw_text("No alerts", LOG_X + 20.0, LOG_Y + 52.0, context.font_size, context.theme.text_color);
+            }
+            for (i, notification) in self.notifications.iter().take(MAX_LISTED).enumerate() {
+                let text = format!("[{}] {}", notification.tick, describe_notification(notification));
+                let color = Self::priority_color(notification.priority, context);
+                draw_text(&text, LOG_X + 20.0, LOG_Y + 52.0 + i as f32 * 30.0, context.font_size * 0.8, color);
+            }
+            for button in &mut self.log_buttons {
+                button.render(&(), context)?;
+            }
+            self.dismiss_all_button.render(&(), context)?;
+        }
+        Ok(None)
+    }
+
+    fn handle_input(&mut self, input: &InputEvent) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+        if let InputEvent::MouseClick { x, y, button: MouseButton::Left } = input {
+            if self.badge_button.get_layout().get_rect().contains(Vec2::new(*x, *y)) {
+                self.toggle_log();
+                return Ok(None);
+            }
+        }
+
+        let log_open = self.log_open;
+        let log_buttons = self.log_buttons.iter_mut()
+            .chain(std::iter::once(&mut self.dismiss_all_button))
+            .filter(move |_| log_open);
+        let clicked = self.toast_buttons.iter_mut()
+            .chain(log_buttons)
+            .find_map(|button| button.handle_input(input).ok().flatten());
+        match clicked {
+            Some(PlayerCommand::DismissNotification { notification, .. }) => Ok(Some(self.dismiss(notification))),
+            _ => Ok(None),
+        }
+    }
+
+    fn update(&mut self, delta_time: f32) -> ComponentResult {
+        if self.log_open {
+            self.log_panel.update(delta_time)?;
+        }
+        Ok(None)
+    }
+
+    fn update_data(&mut self, data: ViewData) -> ComponentResult {
+        if let ViewData::Notifications { notifications, tick } = data {
+            self.notifications = notifications;
+            self.current_tick = tick;
+            self.rebuild_buttons();
+        }
+        Ok(None)
+    }
+
+    fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    fn refresh(&mut self) -> ComponentResult {
+        self.rebuild_buttons();
+        Ok(None)
+    }
+
+    fn get_view_type(&self) -> &'static str {
+        NOTIFICATIONS_VIEW_TYPE
+    }
+}
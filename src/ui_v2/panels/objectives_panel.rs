@@ -0,0 +1,122 @@
+// src/ui_v2/panels/objectives_panel.rs
+//! HUD box with the player's current objective
+//!
+//! Shows the objective being worked on, its hint and reward, and how many of
+//! the faction's objectives are done. Nothing is drawn while the faction has
+//! no objectives; once all are done the box says so. The client sends the
+//! track with `ViewData::Objectives` every frame.
+
+use crate::ui_v2::{View, RenderContext, ComponentResult, InputEvent, ViewData};
+use crate::core::types::*;
+use crate::systems::ObjectiveTrack;
+use crate::GameState;
+use macroquad::prelude::*;
+
+/// View type reported by the objectives panel
+pub const OBJECTIVES_VIEW_TYPE: &str = "Objectives";
+
+const PANEL_X: f32 = 724.0;
+const PANEL_Y: f32 = 130.0;
+const PANEL_WIDTH: f32 = 290.0;
+const PANEL_HEIGHT: f32 = 86.0;
+
+/// One-line summary of a reward, e.g. "100 Minerals, 50 Energy"
+pub fn describe_reward(reward: &ResourceBundle) -> String {
+    let parts: Vec<String> = ResourceType::ALL.iter()
+        .filter(|resource| reward.get(**resource) > 0)
+        .map(|resource| format!("{} {:?}", reward.get(*resource), resource))
+        .collect();
+    if parts.is_empty() {
+        "nothing".to_string()
+    } else {
+        parts.join(", ")
+    }
+}
+
+/// Current objective of one faction
+pub struct ObjectivesPanel {
+    owner: FactionId,
+    track: Option<ObjectiveTrack>,
+    visible: bool,
+}
+
+impl ObjectivesPanel {
+    /// Panel for the faction, empty until the first data update
+    pub fn new(owner: FactionId) -> Self {
+        Self { owner, track: None, visible: true }
+    }
+
+    /// The faction's objectives, as sent to the panel by the client
+    pub fn view_data(game_state: &GameState, owner: FactionId) -> ViewData {
+        ViewData::Objectives(game_state.objective_system.track(owner).cloned())
+    }
+
+    /// Faction whose objectives are shown
+    pub fn owner(&self) -> FactionId {
+        self.owner
+    }
+
+    /// Objectives shown, None when the faction has none
+    pub fn track(&self) -> Option<&ObjectiveTrack> {
+        self.track.as_ref()
+    }
+}
+
+impl View for ObjectivesPanel {
+    fn render(&mut self, context: &RenderContext) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+        let Some(track) = self.track.as_ref().filter(|track| !track.objectives().is_empty()) else {
+            return Ok(None);
+        };
+
+        draw_rectangle(PANEL_X, PANEL_Y, PANEL_WIDTH, PANEL_HEIGHT, Color::new(0.0, 0.0, 0.0, 0.7));
+        draw_rectangle_lines(PANEL_X, PANEL_Y, PANEL_WIDTH, PANEL_HEIGHT, 1.0, context.theme.border_color);
+        let header = format!("Objectives {}/{}", track.completed(), track.objectives().len());
+        draw_text(&header, PANEL_X + 8.0, PANEL_Y + 18.0, context.font_size * 0.8, context.theme.text_color);
+        match track.current() {
+            Some(objective) => {
+                draw_text(&objective.title, PANEL_X + 8.0, PANEL_Y + 40.0, context.font_size, context.theme.text_color);
+                draw_text(&objective.description, PANEL_X + 8.0, PANEL_Y + 58.0, context.font_size * 0.7, context.theme.text_color);
+                let reward = format!("Reward: {}", describe_reward(&objective.reward));
+                draw_text(&reward, PANEL_X + 8.0, PANEL_Y + 76.0, context.font_size * 0.7, context.theme.warning_color);
+            }
+            None => {
+                draw_text("All objectives complete", PANEL_X + 8.0, PANEL_Y + 40.0, context.font_size, context.theme.text_color);
+            }
+        }
+        Ok(None)
+    }
+
+    fn handle_input(&mut self, _input: &InputEvent) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn update(&mut self, _delta_time: f32) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn update_data(&mut self, data: ViewData) -> ComponentResult {
+        if let ViewData::Objectives(track) = data {
+            self.track = track;
+        }
+        Ok(None)
+    }
+
+    fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    fn refresh(&mut self) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn get_view_type(&self) -> &'static str {
+        OBJECTIVES_VIEW_TYPE
+    }
+}
@@ -0,0 +1,212 @@
+// src/ui_v2/panels/options_menu.rs
+//! Options dialog for volume, theme, UI scale and key bindings
+//!
+//! Opened from the main menu and from the pause menu's Settings. Settings
+//! apply as they change: moving the volume slider emits SetVolume, choosing
+//! a theme emits SetTheme and the UI scale buttons emit SetUiScale in steps
+//! of [`UI_SCALE_STEP`], which the client acts on without touching the
+//! simulation. Key Bindings asks the client to open the key bindings dialog
+//! over this one with ShowKeyBindings.
+
+use crate::ui_v2::{View, Panel, Button, RenderContext, ComponentResult, InputEvent, ViewData, Layout};
+use crate::ui_v2::components::Slider;
+use crate::ui_v2::components::base_component::UIComponent;
+use crate::ui_v2::core::ui_scale::clamp_ui_scale;
+use crate::core::events::PlayerCommand;
+use macroquad::prelude::*;
+
+/// View type reported by the options dialog
+pub const OPTIONS_MENU_VIEW_TYPE: &str = "OptionsMenu";
+
+const PANEL_X: f32 = 312.0;
+const PANEL_Y: f32 = 180.0;
+const PANEL_WIDTH: f32 = 400.0;
+const PANEL_HEIGHT: f32 = 300.0;
+const CONTROLS_X: f32 = PANEL_X + 120.0;
+const THEME_WIDTH: f32 = 120.0;
+/// How much one press of a UI scale button changes the scale
+pub const UI_SCALE_STEP: f32 = 0.25;
+
+/// Modal dialog with the client's settings
+pub struct OptionsMenu {
+    main_panel: Panel,
+    volume_slider: Slider,
+    theme_buttons: Vec<(&'static str, Button)>,
+    smaller_button: Button,
+    larger_button: Button,
+    key_bindings_button: Button,
+    close_button: Button,
+    theme: String,
+    ui_scale: f32,
+    visible: bool,
+}
+
+impl OptionsMenu {
+    /// Dialog offering the named themes, showing the current theme, volume
+    /// and UI scale
+    pub fn new(themes: Vec<&'static str>, theme: &str, volume: f32, ui_scale: f32) -> Self {
+        let theme_buttons = themes.into_iter()
+            .enumerate()
+            .map(|(i, name)| {
+                let layout = Layout::new(CONTROLS_X + i as f32 * (THEME_WIDTH + 10.0), PANEL_Y + 110.0, THEME_WIDTH, 30.0);
+                (name, Button::new(name.to_string()).with_layout(layout))
+            })
+            .collect();
+
+        Self {
+            main_panel: Panel::new("Options".to_string())
+                .with_layout(Layout::new(PANEL_X, PANEL_Y, PANEL_WIDTH, PANEL_HEIGHT))
+                .collapsible(false),
+            volume_slider: Slider::new(0.0, 1.0, volume)
+                .with_layout(Layout::new(CONTROLS_X, PANEL_Y + 55.0, 200.0, 30.0)),
+            theme_buttons,
+            smaller_button: Button::new("-".to_string())
+                .with_layout(Layout::new(CONTROLS_X, PANEL_Y + 210.0, 40.0, 30.0)),
+            larger_button: Button::new("+".to_string())
+                .with_layout(Layout::new(CONTROLS_X + 120.0, PANEL_Y + 210.0, 40.0, 30.0)),
+            key_bindings_button: Button::new("Key Bindings...".to_string())
+                .with_layout(Layout::new(CONTROLS_X, PANEL_Y + 165.0, 200.0, 30.0)),
+            close_button: Button::new("Close".to_string())
+                .with_layout(Layout::new(PANEL_X + PANEL_WIDTH - 110.0, PANEL_Y + PANEL_HEIGHT - 40.0, 100.0, 30.0)),
+            theme: theme.to_string(),
+            ui_scale: clamp_ui_scale(ui_scale),
+            visible: true,
+        }
+    }
+
+    /// Master volume shown on the slider, from 0.0 to 1.0
+    pub fn volume(&self) -> f32 {
+        self.volume_slider.get_value()
+    }
+
+    /// Set the master volume, clamped to 0.0 to 1.0
+    pub fn set_volume(&mut self, volume: f32) -> PlayerCommand {
+        self.volume_slider.set_value(volume);
+        PlayerCommand::SetVolume(self.volume())
+    }
+
+    /// Name of the theme marked as in use
+    pub fn theme(&self) -> &str {
+        &self.theme
+    }
+
+    /// Switch to one of the offered themes; None for a theme not offered
+    pub fn select_theme(&mut self, name: &str) -> Option<PlayerCommand> {
+        let (name, _) = self.theme_buttons.iter().find(|(theme, _)| *theme == name)?;
+        self.theme = name.to_string();
+        Some(PlayerCommand::SetTheme(self.theme.clone()))
+    }
+
+    /// UI scale shown
+    pub fn ui_scale(&self) -> f32 {
+        self.ui_scale
+    }
+
+    /// Change the UI scale by `steps` of [`UI_SCALE_STEP`], staying within
+    /// the offered range
+    pub fn step_ui_scale(&mut self, steps: i32) -> PlayerCommand {
+        self.ui_scale = clamp_ui_scale(self.ui_scale + steps as f32 * UI_SCALE_STEP);
+        PlayerCommand::SetUiScale(self.ui_scale)
+    }
+
+    /// Hide the dialog
+    pub fn close(&mut self) {
+        self.visible = false;
+    }
+}
+
+impl View for OptionsMenu {
+    fn render(&mut self, context: &RenderContext) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        // Dim whatever is behind the dialog
+        draw_rectangle(0.0, 0.0, context.screen_width, context.screen_height, Color::new(0.0, 0.0, 0.0, 0.5));
+        self.main_panel.render(&(), context)?;
+        let label = |text: &str, y: f32| {
+            draw_text(text, PANEL_X + 20.0, y + 20.0, context.font_size, context.theme.text_color);
+        };
+        label("Volume", PANEL_Y + 55.0);
+        label("Theme", PANEL_Y + 110.0);
+        label("Controls", PANEL_Y + 165.0);
+        label("UI Scale", PANEL_Y + 210.0);
+        let percent = format!("{:.0}%", self.ui_scale * 100.0);
+        draw_text(&percent, CONTROLS_X + 58.0, PANEL_Y + 230.0, context.font_size, context.theme.text_color);
+
+        self.volume_slider.render(&(), context)?;
+        for (name, button) in &mut self.theme_buttons {
+            button.render(&(), context)?;
+            if *name == self.theme {
+                let rect = button.get_layout().get_rect();
+                draw_rectangle_lines(rect.x, rect.y, rect.w, rect.h, 2.0, context.theme.accent_color);
+            }
+        }
+        self.smaller_button.render(&(), context)?;
+        self.larger_button.render(&(), context)?;
+        self.key_bindings_button.render(&(), context)?;
+        self.close_button.render(&(), context)?;
+        Ok(None)
+    }
+
+    fn handle_input(&mut self, input: &InputEvent) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        if let InputEvent::MouseClick { x, y, button: MouseButton::Left } = input {
+            let point = Vec2::new(*x, *y);
+            let hit = |button: &Button| button.get_layout().get_rect().contains(point);
+            if hit(&self.close_button) {
+                self.close();
+                return Ok(None);
+            }
+            if hit(&self.key_bindings_button) {
+                return Ok(Some(PlayerCommand::ShowKeyBindings));
+            }
+            if hit(&self.smaller_button) {
+                return Ok(Some(self.step_ui_scale(-1)));
+            }
+            if hit(&self.larger_button) {
+                return Ok(Some(self.step_ui_scale(1)));
+            }
+            if let Some(name) = self.theme_buttons.iter().find(|(_, button)| hit(button)).map(|(name, _)| *name) {
+                return Ok(self.select_theme(name));
+            }
+        }
+
+        let volume = self.volume();
+        self.volume_slider.handle_input(input)?;
+        if self.volume() != volume {
+            return Ok(Some(PlayerCommand::SetVolume(self.volume())));
+        }
+        Ok(None)
+    }
+
+    fn update(&mut self, delta_time: f32) -> ComponentResult {
+        if self.visible {
+            self.main_panel.update(delta_time)?;
+        }
+        Ok(None)
+    }
+
+    fn update_data(&mut self, _data: ViewData) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    fn refresh(&mut self) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn get_view_type(&self) -> &'static str {
+        OPTIONS_MENU_VIEW_TYPE
+    }
+}
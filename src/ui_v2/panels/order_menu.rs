@@ -0,0 +1,120 @@
+// src/ui_v2/panels/order_menu.rs
+//! Context menu of orders for the map selection
+//!
+//! Right-clicking the galaxy map opens this menu at the cursor, listing the
+//! orders the [`OrderResolver`](crate::ui_v2::OrderResolver) found for the
+//! selected ships and what was clicked. Choosing one emits its command and
+//! closes the menu; clicking anywhere else closes it without an order.
+
+use crate::ui_v2::{View, Button, RenderContext, ComponentResult, InputEvent, ViewData, Layout, OrderOption};
+use crate::ui_v2::components::base_component::UIComponent;
+use crate::core::events::PlayerCommand;
+use macroquad::prelude::*;
+
+/// View type reported by the order menu
+pub const ORDER_MENU_VIEW_TYPE: &str = "OrderMenu";
+
+const MENU_WIDTH: f32 = 160.0;
+const ENTRY_HEIGHT: f32 = 26.0;
+const PADDING: f32 = 4.0;
+
+/// Menu of orders opened at the cursor
+pub struct OrderMenu {
+    options: Vec<OrderOption>,
+    buttons: Vec<Button>,
+    bounds: Rect,
+    visible: bool,
+}
+
+impl OrderMenu {
+    /// Menu listing the options with its corner at a screen point, moved
+    /// back on screen where it would run off the edge
+    pub fn new(options: Vec<OrderOption>, at: Vec2, screen: Vec2) -> Self {
+        let height = options.len() as f32 * ENTRY_HEIGHT + 2.0 * PADDING;
+        let x = at.x.min(screen.x - MENU_WIDTH).max(0.0);
+        let y = at.y.min(screen.y - height).max(0.0);
+        let buttons = options.iter().enumerate()
+            .map(|(i, option)| Button::new(option.order.label().to_string())
+                .with_layout(Layout::new(x + PADDING, y + PADDING + i as f32 * ENTRY_HEIGHT, MENU_WIDTH - 2.0 * PADDING, ENTRY_HEIGHT - 2.0)))
+            .collect();
+        Self {
+            options,
+            buttons,
+            bounds: Rect::new(x, y, MENU_WIDTH, height),
+            visible: true,
+        }
+    }
+
+    /// Orders listed, top to bottom
+    pub fn options(&self) -> &[OrderOption] {
+        &self.options
+    }
+
+    /// Where the menu is drawn on screen
+    pub fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    /// Give the order at `index` and close; None if there is no such entry
+    pub fn choose(&mut self, index: usize) -> Option<PlayerCommand> {
+        let command = self.options.get(index)?.command();
+        self.visible = false;
+        Some(command)
+    }
+}
+
+impl View for OrderMenu {
+    fn render(&mut self, context: &RenderContext) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        let Rect { x, y, w, h } = self.bounds;
+        draw_rectangle(x, y, w, h, context.theme.panel_background);
+        draw_rectangle_lines(x, y, w, h, 1.0, context.theme.border_color);
+        for button in &mut self.buttons {
+            button.render(&(), context)?;
+        }
+        Ok(None)
+    }
+
+    fn handle_input(&mut self, input: &InputEvent) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        if let InputEvent::MouseClick { x, y, .. } = input {
+            let point = Vec2::new(*x, *y);
+            if let Some(index) = self.buttons.iter().position(|button| button.get_layout().get_rect().contains(point)) {
+                return Ok(self.choose(index));
+            }
+            // Any other click dismisses the menu
+            self.visible = false;
+        }
+        Ok(None)
+    }
+
+    fn update(&mut self, _delta_time: f32) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn update_data(&mut self, _data: ViewData) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    fn refresh(&mut self) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn get_view_type(&self) -> &'static str {
+        ORDER_MENU_VIEW_TYPE
+    }
+}
@@ -0,0 +1,181 @@
+// src/ui_v2/panels/pause_menu.rs
+//! In-game pause menu
+//!
+//! Opened with Escape as a modal dialog through the DialogManager. The game
+//! client pauses the simulation while it is open. Each entry emits a command;
+//! Resume, Quit to Main Menu and Quit to Desktop also close the menu.
+
+use crate::ui_v2::{
+    View, Panel, Button, RenderContext, ComponentResult, InputEvent, ViewData, Layout
+};
+use crate::ui_v2::components::base_component::UIComponent;
+use crate::core::events::PlayerCommand;
+use macroquad::prelude::*;
+
+/// View type reported by the pause menu
+pub const PAUSE_MENU_VIEW_TYPE: &str = "PauseMenu";
+
+/// Menu entries, top to bottom
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PauseMenuEntry {
+    /// Close the menu and unpause
+    Resume,
+    /// Save to the current slot
+    Save,
+    /// Save under a new name
+    SaveAs,
+    /// Open the load view
+    Load,
+    /// Open the settings
+    Settings,
+    /// Leave the game for the main menu
+    QuitToMainMenu,
+    /// Close the game
+    QuitToDesktop,
+}
+
+impl PauseMenuEntry {
+    /// Every entry, top to bottom
+    pub const ALL: [PauseMenuEntry; 7] = [
+        PauseMenuEntry::Resume,
+        PauseMenuEntry::Save,
+        PauseMenuEntry::SaveAs,
+        PauseMenuEntry::Load,
+        PauseMenuEntry::Settings,
+        PauseMenuEntry::QuitToMainMenu,
+        PauseMenuEntry::QuitToDesktop,
+    ];
+
+    /// Button text
+    pub fn label(&self) -> &'static str {
+        match self {
+            PauseMenuEntry::Resume => "Resume",
+            PauseMenuEntry::Save => "Save",
+            PauseMenuEntry::SaveAs => "Save As...",
+            PauseMenuEntry::Load => "Load",
+            PauseMenuEntry::Settings => "Settings",
+            PauseMenuEntry::QuitToMainMenu => "Quit to Main Menu",
+            PauseMenuEntry::QuitToDesktop => "Quit to Desktop",
+        }
+    }
+
+    /// Command the entry issues
+    pub fn command(&self) -> PlayerCommand {
+        match self {
+            PauseMenuEntry::Resume => PlayerCommand::PauseGame(false),
+            PauseMenuEntry::Save => PlayerCommand::SaveGame,
+            PauseMenuEntry::SaveAs => PlayerCommand::SaveGameDialog,
+            PauseMenuEntry::Load => PlayerCommand::LoadGame,
+            PauseMenuEntry::Settings => PlayerCommand::GameOptions,
+            PauseMenuEntry::QuitToMainMenu => PlayerCommand::BackToMenu,
+            PauseMenuEntry::QuitToDesktop => PlayerCommand::ExitGame,
+        }
+    }
+
+    /// True if choosing the entry leaves the pause menu
+    pub fn closes_menu(&self) -> bool {
+        matches!(
+            self,
+            PauseMenuEntry::Resume | PauseMenuEntry::QuitToMainMenu | PauseMenuEntry::QuitToDesktop
+        )
+    }
+}
+
+/// Modal pause menu dialog
+pub struct PauseMenu {
+    main_panel: Panel,
+    buttons: Vec<Button>,
+    visible: bool,
+}
+
+impl PauseMenu {
+    /// Hidden menu with a button per entry
+    pub fn new() -> Self {
+        let (x, y, width) = (392.0, 200.0, 240.0);
+        let buttons = PauseMenuEntry::ALL.iter()
+            .enumerate()
+            .map(|(i, entry)| Button::new(entry.label().to_string())
+                .with_layout(Layout::new(x + 20.0, y + 40.0 + i as f32 * 40.0, width - 40.0, 30.0))
+                .with_click_command(entry.command()))
+            .collect();
+        Self {
+            main_panel: Panel::new("Paused".to_string())
+                .with_layout(Layout::new(x, y, width, 340.0))
+                .collapsible(false),
+            buttons,
+            visible: true,
+        }
+    }
+
+    /// Choose an entry as if its button was clicked
+    pub fn select(&mut self, entry: PauseMenuEntry) -> PlayerCommand {
+        if entry.closes_menu() {
+            self.visible = false;
+        }
+        entry.command()
+    }
+}
+
+impl Default for PauseMenu {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl View for PauseMenu {
+    fn render(&mut self, context: &RenderContext) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        // Dim the game behind the menu
+        draw_rectangle(0.0, 0.0, context.screen_width, context.screen_height, Color::new(0.0, 0.0, 0.0, 0.5));
+        self.main_panel.render(&(), context)?;
+        for button in &mut self.buttons {
+            button.render(&(), context)?;
+        }
+        Ok(None)
+    }
+
+    fn handle_input(&mut self, input: &InputEvent) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        let mut clicked = None;
+        for (i, button) in self.buttons.iter_mut().enumerate() {
+            if let Ok(Some(_)) = button.handle_input(input) {
+                clicked = Some(PauseMenuEntry::ALL[i]);
+                break;
+            }
+        }
+        Ok(clicked.map(|entry| self.select(entry)))
+    }
+
+    fn update(&mut self, delta_time: f32) -> ComponentResult {
+        if self.visible {
+            self.main_panel.update(delta_time)?;
+        }
+        Ok(None)
+    }
+
+    fn update_data(&mut self, _data: ViewData) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    fn refresh(&mut self) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn get_view_type(&self) -> &'static str {
+        PAUSE_MENU_VIEW_TYPE
+    }
+}
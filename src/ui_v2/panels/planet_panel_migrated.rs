@@ -8,10 +8,18 @@ use crate::ui_v2::{
     View, EntityView, Panel, Button, ListView, 
     PlanetAdapter, RenderContext, ComponentResult, InputEvent, ViewData, Layout
 };
-use crate::ui_v2::components::base_component::UIComponent;
+use crate::ui_v2::components::base_component::{UIComponent, Stateful};
+use crate::ui_v2::components::TextInput;
 use crate::core::{types::*, events::PlayerCommand, GameResult};
+use crate::systems::{ShipBuildOption, PlanetKnowledge, ProductionReport};
 use macroquad::prelude::*;
 
+/// View type reported by the planet panel
+pub const PLANET_PANEL_VIEW_TYPE: &str = "PlanetPanelMigrated";
+
+/// Height of one row in the Resources tab
+const RESOURCE_ROW_HEIGHT: f32 = 22.0;
+
 /// Migrated PlanetPanel using ui_v2 components
 /// Replaces the 1,615-line old implementation with ~400 lines
 pub struct PlanetPanelMigrated {
@@ -27,11 +35,33 @@ pub struct PlanetPanelMigrated {
     
     // Tab content components
     resource_list: ListView<ResourceInfo>,
+    storage_priority_buttons: Vec<Button>,
+    storage_cap_buttons: Vec<Button>,
+    tax_rate_buttons: Vec<Button>,
+    storage_summary: String,
     development_list: ListView<DevelopmentInfo>,
+    build_button: Button,
+    transfer_button: Button,
+    ship_options: Vec<ShipBuildOption>,
+    ship_buttons: Vec<Button>,
+    design_button: Button,
+    /// The player's warships in orbit that could bombard the planet
+    bombardment_ships: Vec<ShipId>,
+    bombard_buttons: Vec<Button>,
+    rename_input: TextInput,
+    rename_button: Button,
     worker_panel: Panel,
     
     // State
     current_planet: Option<Planet>,
+    /// Rates by source for the player's own planet; None for other planets
+    production: Option<ProductionReport>,
+    blockaded: bool,
+    /// The player's faction, offered the rename field on planets it controls
+    player: Option<FactionId>,
+    /// How current the shown planet data is, and the tick it was shown at
+    knowledge: PlanetKnowledge,
+    tick: u64,
     visible: bool,
 }
 
@@ -49,6 +79,8 @@ struct ResourceInfo {
     current: i32,
     capacity: i32,
     production_rate: f32,
+    /// Tooltip naming the sources behind the rate
+    breakdown: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -76,7 +108,7 @@ impl PlanetPanelMigrated {
                 .with_click_command(PlayerCommand::ShowResourcePanel),
             Button::new("Developments".to_string())
                 .with_layout(Layout::new(210.0, 90.0, 100.0, 25.0))
-                .with_click_command(PlayerCommand::BuildDevelopment(0, "Infrastructure".to_string())),
+                .with_click_command(PlayerCommand::ShowBuildMenu(0)),
             Button::new("Workers".to_string())
                 .with_layout(Layout::new(315.0, 90.0, 85.0, 25.0))
                 .with_click_command(PlayerCommand::ManageWorkers(0)),
@@ -91,11 +123,20 @@ impl PlanetPanelMigrated {
         // Create resource list view
         let resource_list = ListView::new()
             .with_layout(Layout::new(20.0, 285.0, 380.0, 120.0))
-            .with_item_height(22.0);
+            .with_item_height(RESOURCE_ROW_HEIGHT)
+            .with_item_renderer(|info: &ResourceInfo, _, rect, context| {
+                let rate = match info.breakdown {
+                    Some(_) => format!(" {:+}/tick", info.production_rate),
+                    None => String::new(),
+                };
+                let text = format!("{}: {}/{}{}", info.name, info.current, info.capacity, rate);
+                draw_text(&text, rect.x + 4.0, rect.y + rect.h * 0.7, context.font_size * 0.9, context.theme.text_color);
+                Ok(None)
+            });
 
         // Create development list view
         let development_list = ListView::new()
-            .with_layout(Layout::new(20.0, 285.0, 380.0, 120.0))
+            .with_layout(Layout::new(20.0, 285.0, 380.0, 100.0))
             .with_item_height(25.0);
 
         // Create worker allocation panel
@@ -108,20 +149,51 @@ impl PlanetPanelMigrated {
             tab_buttons,
             active_tab: PlanetTab::Overview,
             resource_list,
+            storage_priority_buttons: Vec::new(),
+            storage_cap_buttons: Vec::new(),
+            tax_rate_buttons: Vec::new(),
+            storage_summary: String::new(),
             development_list,
+            build_button: Self::build_button(0),
+            transfer_button: Self::transfer_button(0),
+            ship_options: Vec::new(),
+            ship_buttons: Vec::new(),
+            design_button: Button::new("Design Ship...".to_string())
+                .with_layout(Layout::new(280.0, 422.0, 120.0, 22.0))
+                .with_click_command(PlayerCommand::ShowShipDesigner),
+            bombardment_ships: Vec::new(),
+            bombard_buttons: Vec::new(),
+            rename_input: TextInput::new()
+                .with_placeholder("New name".to_string())
+                .with_layout(Layout::new(20.0, 505.0, 280.0, 25.0)),
+            rename_button: Button::new("Rename".to_string())
+                .with_layout(Layout::new(310.0, 505.0, 90.0, 25.0)),
             worker_panel,
             current_planet: None,
+            production: None,
+            blockaded: false,
+            player: None,
+            knowledge: PlanetKnowledge::Live,
+            tick: 0,
             visible: false,
         }
     }
 
     /// Show planet information (replaces old show_planet method)
     pub fn show_planet(&mut self, planet: Planet) -> GameResult<()> {
+        // Refreshes of the same planet keep whatever is being typed
+        if self.current_planet.as_ref().is_none_or(|shown| shown.id != planet.id) {
+            self.rename_input.set_text(planet.name.clone().unwrap_or_default());
+        }
         self.current_planet = Some(planet.clone());
         self.visible = true;
+        self.build_button = Self::build_button(planet.id);
+        self.transfer_button = Self::transfer_button(planet.id);
         
         // Update entity view with new planet data
         self.entity_view.set_entity(planet.clone());
+        self.rebuild_ship_buttons();
+        self.rebuild_bombard_buttons();
         
         // Update tab content based on currently active tab
         self.update_tab_content(&planet)?;
@@ -129,10 +201,138 @@ impl PlanetPanelMigrated {
         Ok(())
     }
 
+    /// Button opening the build menu for the planet
+    fn build_button(planet: PlanetId) -> Button {
+        Button::new("Build Structure...".to_string())
+            .with_layout(Layout::new(20.0, 392.0, 380.0, 25.0))
+            .with_click_command(PlayerCommand::ShowBuildMenu(planet))
+    }
+
+    /// Button opening the resource transfer dialog for the planet
+    fn transfer_button(planet: PlanetId) -> Button {
+        Button::new("Transfer Resources...".to_string())
+            .with_layout(Layout::new(20.0, 505.0, 380.0, 25.0))
+            .with_click_command(PlayerCommand::ShowTransferDialog(planet))
+    }
+
+    /// Set the ship classes offered by the shipyard, as returned by `GameState::ship_build_options`
+    pub fn set_ship_build_options(&mut self, options: Vec<ShipBuildOption>) {
+        self.ship_options = options;
+        self.rebuild_ship_buttons();
+    }
+
+    /// Ship classes and designs the shipyard offers
+    pub fn ship_build_options(&self) -> &[ShipBuildOption] {
+        &self.ship_options
+    }
+
+    /// Buttons for unlocked ship classes and designs; locked ones are drawn as text with their reasons
+    fn rebuild_ship_buttons(&mut self) {
+        let Some(planet) = &self.current_planet else {
+            self.ship_buttons.clear();
+            return;
+        };
+        let planet_id = planet.id;
+        self.ship_buttons = self.ship_options.iter()
+            .enumerate()
+            .filter(|(_, option)| option.is_unlocked())
+            .map(|(i, option)| Button::new(format!("Build {} ({})", option.name(), option.cost.total()))
+                .with_layout(Layout::new(20.0, 435.0 + i as f32 * 24.0, 380.0, 22.0))
+                .with_click_command(option.build_command(planet_id)))
+            .collect();
+    }
+
+    /// Set the warships offered to bombard the planet, as returned by `GameState::bombardment_ships`
+    pub fn set_bombardment_ships(&mut self, ships: Vec<ShipId>) {
+        self.bombardment_ships = ships;
+        self.rebuild_bombard_buttons();
+    }
+
+    /// Warships currently offered to bombard the planet
+    pub fn bombardment_ships(&self) -> &[ShipId] {
+        &self.bombardment_ships
+    }
+
+    /// One button per warship, each asking for confirmation before the order is given
+    fn rebuild_bombard_buttons(&mut self) {
+        let Some(planet) = &self.current_planet else {
+            self.bombard_buttons.clear();
+            return;
+        };
+        let planet_id = planet.id;
+        self.bombard_buttons = self.bombardment_ships.iter()
+            .enumerate()
+            .map(|(i, ship)| Button::new(format!("Bombard with Ship {}...", ship))
+                .with_layout(Layout::new(20.0, 290.0 + i as f32 * 24.0, 380.0, 22.0))
+                .with_click_command(PlayerCommand::ConfirmBombardment { ship: *ship, planet: planet_id }))
+            .collect();
+    }
+
+    /// Set the shown planet's rates by source, as returned by
+    /// `GameState::production_report`; None hides the rates
+    pub fn set_production_report(&mut self, report: Option<ProductionReport>) {
+        self.production = report;
+        if let Some(planet) = self.current_planet.clone() {
+            if self.active_tab == PlanetTab::Resources {
+                let _ = self.update_resource_list(&planet);
+            }
+        }
+    }
+
+    /// Rates by source currently shown, if any
+    pub fn production_report(&self) -> Option<&ProductionReport> {
+        self.production.as_ref()
+    }
+
+    /// Set the player's faction, which may rename the planets it controls
+    pub fn set_player(&mut self, player: Option<FactionId>) {
+        self.player = player;
+    }
+
+    /// True if the player controls the shown planet and may rename it
+    pub fn is_renamable(&self) -> bool {
+        self.player.is_some() && self.current_planet.as_ref().is_some_and(|planet| planet.controller == self.player)
+    }
+
+    /// Name typed into the rename field
+    pub fn set_rename_text(&mut self, name: &str) {
+        self.rename_input.set_text(name.to_string());
+    }
+
+    /// RenameEntity for the shown planet with the typed name, if the player may rename it
+    pub fn rename_command(&self) -> Option<PlayerCommand> {
+        let planet = self.current_planet.as_ref().filter(|_| self.is_renamable())?;
+        let name = self.rename_input.get_text().trim();
+        (!name.is_empty()).then(|| PlayerCommand::RenameEntity { target: NamedEntity::Planet(planet.id), name: name.to_string() })
+    }
+
+    /// Mark the shown planet as blockaded, as reported by `CombatResolver::is_blockaded`
+    pub fn set_blockaded(&mut self, blockaded: bool) {
+        self.blockaded = blockaded;
+    }
+
+    /// True if the shown planet is blockaded
+    pub fn is_blockaded(&self) -> bool {
+        self.blockaded
+    }
+
+    /// Say whether the shown planet is live or from intel, as returned by
+    /// `GameState::known_planet` at `tick`
+    pub fn set_knowledge(&mut self, knowledge: PlanetKnowledge, tick: u64) {
+        self.knowledge = knowledge;
+        self.tick = tick;
+    }
+
+    /// Whether the shown planet is live or from intel
+    pub fn knowledge(&self) -> PlanetKnowledge {
+        self.knowledge
+    }
+
     /// Hide the panel (replaces old hide method)
     pub fn hide(&mut self) {
         self.visible = false;
         self.current_planet = None;
+        self.blockaded = false;
     }
 
     /// Check if panel is visible
@@ -180,48 +380,94 @@ impl PlanetPanelMigrated {
 
     /// Update resource list with current planet data
     fn update_resource_list(&mut self, planet: &Planet) -> GameResult<()> {
-        let resources = vec![
-            ResourceInfo {
-                name: "Energy".to_string(),
-                current: planet.resources.current.energy,
-                capacity: planet.resources.capacity.energy,
-                production_rate: 0.0, // Production rate not available in ResourceStorage
-            },
-            ResourceInfo {
-                name: "Minerals".to_string(),
-                current: planet.resources.current.minerals,
-                capacity: planet.resources.capacity.minerals,
-                production_rate: 0.0, // Production rate not available in ResourceStorage
-            },
-            ResourceInfo {
-                name: "Food".to_string(),
-                current: planet.resources.current.food,
-                capacity: planet.resources.capacity.food,
-                production_rate: 0.0, // Production rate not available in ResourceStorage
-            },
-            ResourceInfo {
-                name: "Alloys".to_string(),
-                current: planet.resources.current.alloys,
-                capacity: planet.resources.capacity.alloys,
-                production_rate: 0.0, // Production rate not available in ResourceStorage
-            },
-            ResourceInfo {
-                name: "Components".to_string(),
-                current: planet.resources.current.components,
-                capacity: planet.resources.capacity.components,
-                production_rate: 0.0, // Production rate not available in ResourceStorage
-            },
-        ];
+        let production = self.production.as_ref().filter(|report| report.planet == planet.id);
+        let resources = [
+            ResourceType::Energy,
+            ResourceType::Minerals,
+            ResourceType::Food,
+            ResourceType::Alloys,
+            ResourceType::Components,
+        ].into_iter().map(|resource| ResourceInfo {
+            name: format!("{:?}", resource),
+            current: planet.resources.current.get(resource),
+            capacity: planet.resources.capacity.get(resource),
+            production_rate: production.map_or(0.0, |report| report.net.get(resource) as f32),
+            breakdown: production.map(|report| report.describe(resource)),
+        }).collect();
         
         self.resource_list.set_items(resources);
+        self.storage_summary = describe_storage(&planet.storage_breakdown());
+        self.rebuild_storage_priority_buttons(planet);
+        self.rebuild_storage_cap_buttons(planet);
+        self.rebuild_tax_rate_buttons(planet);
         Ok(())
     }
 
+    /// Rebuild storage priority controls; clicking a resource raises its priority
+    fn rebuild_storage_priority_buttons(&mut self, planet: &Planet) {
+        let button_width = 60.0;
+        self.storage_priority_buttons = planet.storage_priority.order.iter().enumerate()
+            .map(|(i, resource)| {
+                let mut priority = planet.storage_priority.clone();
+                priority.raise(*resource);
+                Button::new(format!("{}. {:?}", i + 1, resource))
+                    .with_layout(Layout::new(20.0 + i as f32 * (button_width + 4.0), 425.0, button_width, 20.0))
+                    .with_click_command(PlayerCommand::SetStoragePriority {
+                        planet: planet.id,
+                        priority,
+                    })
+            })
+            .collect();
+    }
+
+    /// Rebuild storage cap controls, one under each priority button; clicking
+    /// steps the resource's cap through 75%, 50% and 25% of capacity, then off
+    fn rebuild_storage_cap_buttons(&mut self, planet: &Planet) {
+        let button_width = 60.0;
+        self.storage_cap_buttons = planet.storage_priority.order.iter().enumerate()
+            .map(|(i, resource)| {
+                let capacity = planet.resources.capacity.get(*resource);
+                let current = planet.storage_priority.cap(*resource);
+                let mut priority = planet.storage_priority.clone();
+                priority.set_cap(*resource, next_storage_cap(current, capacity));
+                let label = match current {
+                    Some(cap) => format!("cap {}", cap),
+                    None => "no cap".to_string(),
+                };
+                Button::new(label)
+                    .with_layout(Layout::new(20.0 + i as f32 * (button_width + 4.0), 447.0, button_width, 20.0))
+                    .with_click_command(PlayerCommand::SetStoragePriority {
+                        planet: planet.id,
+                        priority,
+                    })
+            })
+            .collect();
+    }
+
+    /// Rebuild tax rate controls; the current rate is marked
+    fn rebuild_tax_rate_buttons(&mut self, planet: &Planet) {
+        let button_width = 90.0;
+        self.tax_rate_buttons = [TaxRate::Low, TaxRate::Normal, TaxRate::High].into_iter().enumerate()
+            .map(|(i, rate)| {
+                let marker = if rate == planet.tax_rate { "*" } else { "" };
+                Button::new(format!("{}{:?} tax", marker, rate))
+                    .with_layout(Layout::new(20.0 + i as f32 * (button_width + 4.0), 470.0, button_width, 25.0))
+                    .with_click_command(PlayerCommand::SetTaxRate { planet: planet.id, rate })
+            })
+            .collect();
+    }
+
     /// Update development list with current planet data
     fn update_development_list(&mut self, planet: &Planet) -> GameResult<()> {
         let developments: Vec<DevelopmentInfo> = planet.developments.iter().map(|dev| {
+            // Shut down buildings are marked with the reason
+            let power = match dev.shutdown_reason() {
+                None => "",
+                Some(ShutdownReason::UpkeepUnpaid) => " (shut down)",
+                Some(ShutdownReason::Unpowered) => " (unpowered)",
+            };
             DevelopmentInfo {
-                name: format!("{:?}", dev.building_type), // Use building_type instead of development_type
+                name: format!("{:?}{}", dev.building_type, power), // Use building_type instead of development_type
                 level: dev.tier as i32, // Use tier instead of level
                 upgrade_cost: self.calculate_upgrade_cost(dev.tier as i32),
                 description: self.get_development_description(&format!("{:?}", dev.building_type)),
@@ -250,6 +496,27 @@ impl PlanetPanelMigrated {
         }
     }
 
+    /// Draw the rate breakdown of the resource row under the mouse
+    fn render_resource_tooltip(&self, context: &RenderContext) {
+        let (mouse_x, mouse_y) = mouse_position();
+        let list = self.resource_list.get_layout().get_rect();
+        if !list.contains(vec2(mouse_x, mouse_y)) {
+            return;
+        }
+        let row = ((mouse_y - list.y - self.resource_list.get_layout().padding) / RESOURCE_ROW_HEIGHT).floor();
+        let Some(breakdown) = (row >= 0.0)
+            .then(|| self.resource_list.items().get(row as usize))
+            .flatten()
+            .and_then(|info| info.breakdown.as_ref())
+        else {
+            return;
+        };
+        let size = context.font_size * 0.8;
+        let width = measure_text(breakdown, None, size as u16, 1.0).width + 8.0;
+        draw_rectangle(mouse_x + 12.0, mouse_y, width, size + 8.0, context.theme.panel_background);
+        draw_text(breakdown, mouse_x + 16.0, mouse_y + size + 2.0, size, context.theme.text_color);
+    }
+
     /// Render worker allocation interface
     fn render_worker_allocation(&self, planet: &Planet, context: &RenderContext) -> ComponentResult {
         let panel_rect = self.worker_panel.get_layout().get_rect();
@@ -296,6 +563,38 @@ impl PlanetPanelMigrated {
     }
 }
 
+/// Line saying how old the shown intel is at `tick`; None for live data
+pub fn describe_knowledge(knowledge: PlanetKnowledge, tick: u64) -> Option<String> {
+    match knowledge {
+        PlanetKnowledge::Live => None,
+        PlanetKnowledge::Intel { tick: as_of, ships_in_orbit } => Some(format!(
+            "Intel {} ticks old, {} ships in orbit", tick.saturating_sub(as_of), ships_in_orbit
+        )),
+        PlanetKnowledge::Unknown => Some("No intel: send a scout".to_string()),
+    }
+}
+
+/// Cap the storage cap button sets next: none, then 75%, 50% and 25% of
+/// `capacity`, then none again
+pub fn next_storage_cap(current: Option<i32>, capacity: i32) -> Option<i32> {
+    let steps = [capacity * 3 / 4, capacity / 2, capacity / 4];
+    match current {
+        None => Some(steps[0]),
+        Some(cap) => steps.iter().copied().find(|step| *step < cap),
+    }
+}
+
+/// Line saying where a planet's storage capacity comes from
+pub fn describe_storage(breakdown: &StorageBreakdown) -> String {
+    if breakdown.facility_tiers == 0 {
+        return "Storage: base only, build a StorageFacility for more".to_string();
+    }
+    format!(
+        "Storage: base + {} facility tiers (+{} minerals, +{} energy)",
+        breakdown.facility_tiers, breakdown.facilities.minerals, breakdown.facilities.energy
+    )
+}
+
 impl View for PlanetPanelMigrated {
     fn render(&mut self, context: &RenderContext) -> ComponentResult {
         if !self.visible {
@@ -304,6 +603,12 @@ impl View for PlanetPanelMigrated {
 
         // Render main panel background
         self.main_panel.render(&(), context)?;
+        if self.blockaded {
+            draw_text("BLOCKADED", 320.0, 75.0, context.font_size, RED);
+        }
+        if let Some(intel) = describe_knowledge(self.knowledge, self.tick) {
+            draw_text(&intel, 150.0, 75.0, context.font_size * 0.8, context.theme.secondary_text_color);
+        }
 
         // Render tab buttons with active state highlighting
         for (i, button) in self.tab_buttons.iter_mut().enumerate() {
@@ -315,13 +620,54 @@ impl View for PlanetPanelMigrated {
         match self.active_tab {
             PlanetTab::Overview => {
                 self.entity_view.render(context)?;
+                for button in &mut self.bombard_buttons {
+                    button.render(&(), context)?;
+                }
+                if self.is_renamable() {
+                    self.rename_input.render(&(), context)?;
+                    self.rename_button.render(&(), context)?;
+                }
             }
             PlanetTab::Resources => {
                 // Render resource list with custom item renderer
                 self.resource_list.render(&(), context)?;
+                self.render_resource_tooltip(context);
+                draw_text(
+                    "Storage priority (click to raise) and caps:",
+                    20.0,
+                    420.0,
+                    context.font_size * 0.9,
+                    context.theme.text_color
+                );
+                for button in self.storage_priority_buttons.iter_mut().chain(self.storage_cap_buttons.iter_mut()) {
+                    button.render(&(), context)?;
+                }
+                for button in &mut self.tax_rate_buttons {
+                    button.render(&(), context)?;
+                }
+                self.transfer_button.render(&(), context)?;
+                draw_text(&self.storage_summary, 20.0, 546.0, context.font_size * 0.8, context.theme.secondary_text_color);
             }
             PlanetTab::Developments => {
                 self.development_list.render(&(), context)?;
+                self.build_button.render(&(), context)?;
+                if self.blockaded {
+                    draw_text("Shipyard closed by blockade", 20.0, 428.0, context.font_size * 0.9, RED);
+                    return Ok(None);
+                }
+                if !self.ship_options.is_empty() {
+                    draw_text("Shipyard:", 20.0, 428.0, context.font_size * 0.9, context.theme.text_color);
+                    self.design_button.render(&(), context)?;
+                }
+                for (i, option) in self.ship_options.iter().enumerate() {
+                    if !option.is_unlocked() {
+                        let text = format!("{} (locked): {}", option.name(), option.lock_reasons().join(", "));
+                        draw_text(&text, 20.0, 451.0 + i as f32 * 24.0, context.font_size * 0.8, GRAY);
+                    }
+                }
+                for button in &mut self.ship_buttons {
+                    button.render(&(), context)?;
+                }
             }
             PlanetTab::Workers => {
                 self.worker_panel.render(&(), context)?;
@@ -350,7 +696,7 @@ impl View for PlanetPanelMigrated {
                     _ => PlanetTab::Overview,
                 };
                 if let Err(e) = self.switch_tab(new_tab) {
-                    eprintln!("Tab switch error: {:?}", e);
+                    log::error!("Tab switch error: {:?}", e);
                 }
                 return Ok(None);
             }
@@ -359,12 +705,52 @@ impl View for PlanetPanelMigrated {
         // Handle content input based on active tab
         match self.active_tab {
             PlanetTab::Overview => {
+                for button in &mut self.bombard_buttons {
+                    if let Some(command) = button.handle_input(input)? {
+                        return Ok(Some(command));
+                    }
+                }
+                if self.is_renamable() {
+                    // Enter in the field renames, as does the button
+                    let submitted = self.rename_input.is_focused() && matches!(input, InputEvent::KeyPress { key: KeyCode::Enter });
+                    self.rename_input.handle_input(input)?;
+                    let clicked = matches!(input, InputEvent::MouseClick { x, y, button: MouseButton::Left }
+                        if self.rename_button.get_layout().get_rect().contains(Vec2::new(*x, *y)));
+                    if submitted || clicked {
+                        return Ok(self.rename_command());
+                    }
+                }
                 self.entity_view.handle_input(input)
             }
             PlanetTab::Resources => {
+                for button in self.storage_priority_buttons.iter_mut()
+                    .chain(self.storage_cap_buttons.iter_mut())
+                    .chain(self.tax_rate_buttons.iter_mut())
+                    .chain(std::iter::once(&mut self.transfer_button))
+                {
+                    if let Some(command) = button.handle_input(input)? {
+                        return Ok(Some(command));
+                    }
+                }
                 self.resource_list.handle_input(input)
             }
             PlanetTab::Developments => {
+                if let Some(command) = self.build_button.handle_input(input)? {
+                    return Ok(Some(command));
+                }
+                // A blockaded shipyard takes no orders
+                if !self.blockaded {
+                    if !self.ship_options.is_empty() {
+                        if let Some(command) = self.design_button.handle_input(input)? {
+                            return Ok(Some(command));
+                        }
+                    }
+                    for button in &mut self.ship_buttons {
+                        if let Some(command) = button.handle_input(input)? {
+                            return Ok(Some(command));
+                        }
+                    }
+                }
                 self.development_list.handle_input(input)
             }
             PlanetTab::Workers => {
@@ -404,10 +790,23 @@ impl View for PlanetPanelMigrated {
     }
 
     fn update_data(&mut self, data: ViewData) -> ComponentResult {
-        if let ViewData::Planet(planet) = data {
-            if let Err(e) = self.show_planet(planet) {
-                eprintln!("Planet data update error: {:?}", e);
+        match data {
+            ViewData::Planet(planet) => {
+                self.set_knowledge(PlanetKnowledge::Live, self.tick);
+                if let Err(e) = self.show_planet(planet) {
+                    log::error!("Planet data update error: {:?}", e);
+                }
+            }
+            ViewData::KnownPlanet { planet, knowledge, tick } => {
+                self.set_knowledge(knowledge, tick);
+                if let Err(e) = self.show_planet(planet) {
+                    log::error!("Planet data update error: {:?}", e);
+                }
             }
+            ViewData::ProductionReport(report) => {
+                self.set_production_report(Some(report));
+            }
+            _ => {}
         }
         Ok(None)
     }
@@ -426,14 +825,14 @@ impl View for PlanetPanelMigrated {
     fn refresh(&mut self) -> ComponentResult {
         if let Some(planet) = self.current_planet.clone() {
             if let Err(e) = self.update_tab_content(&planet) {
-                eprintln!("Panel refresh error: {:?}", e);
+                log::error!("Panel refresh error: {:?}", e);
             }
         }
         Ok(None)
     }
 
     fn get_view_type(&self) -> &'static str {
-        "PlanetPanelMigrated"
+        PLANET_PANEL_VIEW_TYPE
     }
 }
 
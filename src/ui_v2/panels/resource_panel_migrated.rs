@@ -3,16 +3,27 @@
 //! 
 //! This replaces src/ui/panels/resource_panel.rs with a component-based approach
 //! using ui_v2 infrastructure. Maintains full compatibility with existing EventBus architecture.
+//!
+//! Shows the player's empire economy from `GameState::empire_economy`:
+//! every resource's stockpile and net rate, with how long until storage
+//! fills or the stockpile runs out, coloured by `OutlookLevel`. Hovering a
+//! row shows the same figures as a tooltip.
 
 use crate::ui_v2::{
-    View, DataView, Panel, Button, ListView,
-    RenderContext, ComponentResult, InputEvent, ViewData, Layout
+    View, DataView, Panel, ListView,
+    RenderContext, ComponentResult, InputEvent, ViewData, Layout, Tooltip
 };
 use crate::ui_v2::components::base_component::UIComponent;
-use crate::core::{types::*, GameResult};
+use crate::core::{GameResult, types::ResourceBundle};
+use crate::systems::{EmpireEconomy, OutlookLevel, ResourceOutlook};
 use crate::GameState;
 use macroquad::prelude::*;
 
+/// View type of the resource panel, for routing data updates
+pub const RESOURCE_PANEL_VIEW_TYPE: &str = "ResourcePanelMigrated";
+/// Height of one resource row
+const RESOURCE_ROW_HEIGHT: f32 = 22.0;
+
 /// Migrated ResourcePanel using ui_v2 components
 /// Replaces the 398-line old implementation with ~200 lines
 pub struct ResourcePanelMigrated {
@@ -21,30 +32,21 @@ pub struct ResourcePanelMigrated {
     
     // Resource display components
     empire_totals_view: DataView,
-    resource_list: ListView<ResourceDisplayInfo>,
+    resource_list: ListView<ResourceOutlook>,
     performance_panel: Panel,
     
     // State
-    cached_empire_totals: ResourceBundle,
+    economy: Option<EmpireEconomy>,
     cached_tick: u64,
     visible: bool,
     last_update_time: f32,
 }
 
-#[derive(Debug, Clone)]
-struct ResourceDisplayInfo {
-    name: String,
-    current: i32,
-    production_rate: f32,
-    total_across_empire: i32,
-    color: Color,
-}
-
 impl ResourcePanelMigrated {
     pub fn new() -> Self {
         // Create main panel for resource display
         let main_panel = Panel::new("Empire Resources".to_string())
-            .with_layout(Layout::new(0.0, 0.0, 800.0, 120.0)) // Full width, positioned at top
+            .with_layout(Layout::new(0.0, 0.0, 800.0, 220.0)) // Full width, positioned at top
             .collapsible(false);
 
         // Create empire totals view
@@ -53,8 +55,14 @@ impl ResourcePanelMigrated {
 
         // Create detailed resource list
         let resource_list = ListView::new()
-            .with_layout(Layout::new(10.0, 75.0, 780.0, 35.0))
-            .with_item_height(35.0); // Resources displayed vertically by default
+            .with_layout(Layout::new(10.0, 75.0, 600.0, 140.0))
+            .with_item_height(RESOURCE_ROW_HEIGHT)
+            .with_item_renderer(|outlook: &ResourceOutlook, _, rect, context| {
+                let text = format!("{:?}: {}/{} {:+}/tick  {}",
+                    outlook.resource, outlook.stockpile, outlook.capacity, outlook.net_per_tick, Self::projection(outlook));
+                draw_text(&text, rect.x + 4.0, rect.y + rect.h * 0.7, context.font_size * 0.9, Self::level_color(outlook.level(), context));
+                Ok(None)
+            });
 
         // Create performance panel for tick/FPS display
         let performance_panel = Panel::new("Performance".to_string())
@@ -65,7 +73,7 @@ impl ResourcePanelMigrated {
             empire_totals_view,
             resource_list,
             performance_panel,
-            cached_empire_totals: ResourceBundle::default(),
+            economy: None,
             cached_tick: 0,
             visible: true, // Resource panel is typically always visible
             last_update_time: 0.0,
@@ -101,98 +109,55 @@ impl ResourcePanelMigrated {
     }
 
     /// Update empire resource data
-    pub fn update_resources(&mut self, game_state: &GameState) -> GameResult<()> {
-        let current_tick = game_state.time_manager.get_current_tick();
-        
-        // Only update if tick has changed (performance optimization)
-        if current_tick != self.cached_tick {
-            self.cached_tick = current_tick;
-            
-            // Calculate empire totals
-            self.cached_empire_totals = self.calculate_empire_totals(game_state)?;
-            
-            // Update resource display list
-            self.update_resource_display_list()?;
-        }
-        
+    pub fn update_resources(&mut self, game_state: &mut GameState) -> GameResult<()> {
+        let Some(player) = game_state.faction_manager.get_player_faction().map(|player| player.id) else {
+            return Ok(());
+        };
+        let economy = game_state.empire_economy(player)?.clone();
+        self.set_economy(economy);
         Ok(())
     }
 
-    /// Calculate total resources across entire empire
-    fn calculate_empire_totals(&self, game_state: &GameState) -> GameResult<ResourceBundle> {
-        let mut totals = ResourceBundle::default();
-        
-        // Sum resources from all player-controlled planets
-        let planets = game_state.planet_manager.get_all_planets();
-        for planet in planets {
-            if planet.controller == Some(0) { // Player faction ID
-                totals.energy += planet.resources.current.energy;
-                totals.minerals += planet.resources.current.minerals;
-                totals.food += planet.resources.current.food;
-                totals.alloys += planet.resources.current.alloys;
-                totals.components += planet.resources.current.components;
-                totals.fuel += planet.resources.current.fuel;
-            }
+    /// Show the given economy
+    pub fn set_economy(&mut self, economy: EmpireEconomy) {
+        self.cached_tick = economy.tick;
+        self.resource_list.set_items(economy.outlooks.clone());
+        self.economy = Some(economy);
+    }
+
+    /// The economy on show, if any has been set
+    pub fn economy(&self) -> Option<&EmpireEconomy> {
+        self.economy.as_ref()
+    }
+
+    /// "full in 40 ticks", "empty in 12 ticks" or "steady"
+    fn projection(outlook: &ResourceOutlook) -> String {
+        match (outlook.ticks_to_empty(), outlook.ticks_to_full()) {
+            (Some(ticks), _) => format!("empty in {} ticks", ticks),
+            (_, Some(0)) => "full".to_string(),
+            (_, Some(ticks)) => format!("full in {} ticks", ticks),
+            _ => "steady".to_string(),
         }
-        
-        Ok(totals)
     }
 
-    /// Update the resource display list with current data
-    fn update_resource_display_list(&mut self) -> GameResult<()> {
-        let resources = vec![
-            ResourceDisplayInfo {
-                name: "Energy".to_string(),
-                current: self.cached_empire_totals.energy,
-                production_rate: self.calculate_total_production_rate("energy"),
-                total_across_empire: self.cached_empire_totals.energy,
-                color: Color::new(1.0, 1.0, 0.3, 1.0), // Yellow
-            },
-            ResourceDisplayInfo {
-                name: "Minerals".to_string(),
-                current: self.cached_empire_totals.minerals,
-                production_rate: self.calculate_total_production_rate("minerals"),
-                total_across_empire: self.cached_empire_totals.minerals,
-                color: Color::new(0.7, 0.7, 0.7, 1.0), // Gray
-            },
-            ResourceDisplayInfo {
-                name: "Food".to_string(),
-                current: self.cached_empire_totals.food,
-                production_rate: self.calculate_total_production_rate("food"),
-                total_across_empire: self.cached_empire_totals.food,
-                color: Color::new(0.3, 1.0, 0.3, 1.0), // Green
-            },
-            ResourceDisplayInfo {
-                name: "Alloys".to_string(),
-                current: self.cached_empire_totals.alloys,
-                production_rate: self.calculate_total_production_rate("alloys"),
-                total_across_empire: self.cached_empire_totals.alloys,
-                color: Color::new(0.3, 0.7, 1.0, 1.0), // Blue
-            },
-            ResourceDisplayInfo {
-                name: "Components".to_string(),
-                current: self.cached_empire_totals.components,
-                production_rate: self.calculate_total_production_rate("components"),
-                total_across_empire: self.cached_empire_totals.components,
-                color: Color::new(1.0, 0.6, 0.2, 1.0), // Orange
-            },
-        ];
-        
-        self.resource_list.set_items(resources);
-        Ok(())
+    /// Stockpile, storage and net rate of one resource, with its projection
+    fn outlook_tooltip(outlook: &ResourceOutlook) -> Tooltip {
+        let mut net = ResourceBundle::default();
+        net.set(outlook.resource, outlook.net_per_tick);
+        let tooltip = Tooltip::new(format!("{:?}", outlook.resource))
+            .text(format!("Stockpile: {} of {} storage", outlook.stockpile, outlook.capacity))
+            .rate("Net", net);
+        match outlook.level() {
+            OutlookLevel::Steady => tooltip.text(Self::projection(outlook)),
+            _ => tooltip.warning(Self::projection(outlook)),
+        }
     }
 
-    /// Calculate total production rate for a resource type across empire
-    fn calculate_total_production_rate(&self, resource_type: &str) -> f32 {
-        // Placeholder - would need access to game state to calculate real rates
-        // This would iterate through all planets and sum production rates
-        match resource_type {
-            "energy" => 45.5,
-            "minerals" => 32.1,
-            "food" => 28.7,
-            "alloys" => 15.3,
-            "components" => 38.9,
-            _ => 0.0,
+    fn level_color(level: OutlookLevel, context: &RenderContext) -> Color {
+        match level {
+            OutlookLevel::Steady => context.theme.text_color,
+            OutlookLevel::Warning => context.theme.warning_color,
+            OutlookLevel::Critical => context.theme.error_color,
         }
     }
 
@@ -209,7 +174,7 @@ impl ResourcePanelMigrated {
         let mut list_layout = self.resource_list.get_layout().clone();
         list_layout.position.x = x + 10.0;
         list_layout.position.y = y + 75.0;
-        list_layout.size.x = width - 20.0;
+        list_layout.size.x = width - 190.0; // Leave space for performance panel
         self.resource_list.set_layout(list_layout);
         
         // Update performance panel
@@ -220,7 +185,7 @@ impl ResourcePanelMigrated {
     }
 
     /// Render performance information (tick, FPS)
-    fn render_performance_info(&self, game_state: &GameState, context: &RenderContext) -> ComponentResult {
+    fn render_performance_info(&self, context: &RenderContext) -> ComponentResult {
         let perf_rect = self.performance_panel.get_layout().get_rect();
         let start_y = perf_rect.y + 25.0;
         
@@ -277,24 +242,23 @@ impl View for ResourcePanelMigrated {
         // Render main panel background
         self.main_panel.render(&(), context)?;
 
-        // Render empire totals summary
-        draw_text(
-            &format!("Empire Total: {} Energy, {} Minerals, {} Food, {} Alloys, {} Components",
-                self.cached_empire_totals.energy,
-                self.cached_empire_totals.minerals,
-                self.cached_empire_totals.food,
-                self.cached_empire_totals.alloys,
-                self.cached_empire_totals.components),
-            15.0, 60.0,
-            context.font_size * 0.9,
-            context.theme.text_color
-        );
+        // Render empire summary
+        let totals_rect = self.empire_totals_view.get_layout().get_rect();
+        let summary = match &self.economy {
+            Some(economy) => {
+                let warnings = economy.outlooks.iter().filter(|outlook| outlook.level() != OutlookLevel::Steady).count();
+                format!("{} planets, {} resources need attention", economy.planets, warnings)
+            }
+            None => "No economy data".to_string(),
+        };
+        draw_text(&summary, totals_rect.x + 5.0, totals_rect.y + 25.0, context.font_size * 0.9, context.theme.text_color);
 
         // Render detailed resource list
         self.resource_list.render(&(), context)?;
 
         // Render performance panel
         self.performance_panel.render(&(), context)?;
+        self.render_performance_info(context)?;
         
         Ok(None)
     }
@@ -330,8 +294,9 @@ impl View for ResourcePanelMigrated {
     }
 
     fn update_data(&mut self, data: ViewData) -> ComponentResult {
-        // Resource panel doesn't directly receive specific entity data
-        // It updates based on entire game state through update_resources()
+        if let ViewData::EmpireEconomy(economy) = data {
+            self.set_economy(economy);
+        }
         Ok(None)
     }
 
@@ -344,13 +309,21 @@ impl View for ResourcePanelMigrated {
     }
 
     fn refresh(&mut self) -> ComponentResult {
-        // Refresh would typically call update_resources() with current game state
-        // but that requires GameState access which isn't available in this context
+        // The client sends a new economy every fixed update; nothing to redo here
         Ok(None)
     }
 
     fn get_view_type(&self) -> &'static str {
-        "ResourcePanelMigrated"
+        RESOURCE_PANEL_VIEW_TYPE
+    }
+
+    fn tooltips(&self) -> Vec<(Rect, Tooltip)> {
+        if !self.visible {
+            return Vec::new();
+        }
+        self.resource_list.item_rects().into_iter()
+            .map(|(i, rect)| (rect, Self::outlook_tooltip(&self.resource_list.items()[i])))
+            .collect()
     }
 }
 
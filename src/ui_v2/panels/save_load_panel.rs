@@ -0,0 +1,278 @@
+// src/ui_v2/panels/save_load_panel.rs
+//! Save and load dialog for named save slots
+//!
+//! Lists the existing slots, newest first, with the time they were written
+//! and the tick they reached. In save mode a slot name can be typed or taken
+//! from the list; in load mode a slot is picked from the list. Confirming
+//! emits SaveGameToSlot or LoadGameFromSlot and closes the dialog.
+
+use crate::ui_v2::{
+    View, Panel, Button, RenderContext, ComponentResult, InputEvent, ViewData, Layout
+};
+use crate::ui_v2::components::TextInput;
+use crate::ui_v2::components::base_component::UIComponent;
+use crate::core::{events::PlayerCommand, GameResult};
+use crate::systems::save_system::SaveInfo;
+use crate::GameState;
+use macroquad::prelude::*;
+
+/// View type reported by the save/load dialog, matching `ViewType::SaveLoad`
+pub const SAVE_LOAD_VIEW_TYPE: &str = "SaveLoad";
+
+/// Slots listed at once; older slots are left off the list
+const MAX_LISTED_SLOTS: usize = 8;
+const PANEL_X: f32 = 262.0;
+const PANEL_Y: f32 = 150.0;
+const PANEL_WIDTH: f32 = 500.0;
+const ROW_HEIGHT: f32 = 30.0;
+
+/// Whether the dialog writes or reads a slot
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveLoadMode {
+    /// Write the game to a slot
+    Save,
+    /// Read a game from a slot
+    Load,
+}
+
+impl SaveLoadMode {
+    /// Dialog title
+    pub fn title(&self) -> &'static str {
+        match self {
+            SaveLoadMode::Save => "Save Game",
+            SaveLoadMode::Load => "Load Game",
+        }
+    }
+
+    /// Command that acts on the named slot in this mode
+    pub fn command(&self, slot: String) -> PlayerCommand {
+        match self {
+            SaveLoadMode::Save => PlayerCommand::SaveGameToSlot(slot),
+            SaveLoadMode::Load => PlayerCommand::LoadGameFromSlot(slot),
+        }
+    }
+}
+
+/// Seconds since the Unix epoch as "YYYY-MM-DD HH:MM" in UTC
+pub fn format_timestamp(seconds: u64) -> String {
+    let days = (seconds / 86_400) as i64;
+    let minutes_of_day = (seconds % 86_400) / 60;
+
+    // Civil date from days since 1970-01-01 (Howard Hinnant's algorithm)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!("{:04}-{:02}-{:02} {:02}:{:02}", year, month, day, minutes_of_day / 60, minutes_of_day % 60)
+}
+
+/// Modal dialog listing save slots
+pub struct SaveLoadPanel {
+    main_panel: Panel,
+    name_input: TextInput,
+    slot_buttons: Vec<Button>,
+    confirm_button: Button,
+    cancel_button: Button,
+
+    mode: SaveLoadMode,
+    slots: Vec<SaveInfo>,
+    selected: Option<usize>,
+    visible: bool,
+}
+
+impl SaveLoadPanel {
+    /// Open dialog listing the slots
+    pub fn new(mode: SaveLoadMode, slots: Vec<SaveInfo>) -> Self {
+        let list_bottom = PANEL_Y + 40.0 + MAX_LISTED_SLOTS as f32 * ROW_HEIGHT;
+        let mut panel = Self {
+            main_panel: Panel::new(mode.title().to_string())
+                .with_layout(Layout::new(PANEL_X, PANEL_Y, PANEL_WIDTH, list_bottom - PANEL_Y + 100.0))
+                .collapsible(false),
+            name_input: TextInput::new()
+                .with_placeholder("New slot name".to_string())
+                .with_layout(Layout::new(PANEL_X + 20.0, list_bottom + 10.0, PANEL_WIDTH - 40.0, 28.0)),
+            slot_buttons: Vec::new(),
+            confirm_button: Button::new(match mode {
+                SaveLoadMode::Save => "Save",
+                SaveLoadMode::Load => "Load",
+            }.to_string())
+                .with_layout(Layout::new(PANEL_X + 20.0, list_bottom + 50.0, 120.0, 30.0)),
+            cancel_button: Button::new("Cancel".to_string())
+                .with_layout(Layout::new(PANEL_X + 160.0, list_bottom + 50.0, 120.0, 30.0)),
+            mode,
+            slots,
+            selected: None,
+            visible: true,
+        };
+        panel.name_input.set_visible(mode == SaveLoadMode::Save);
+        panel.rebuild_buttons();
+        panel
+    }
+
+    /// Dialog listing the game's save slots; saving suggests the current slot name
+    pub fn for_game(mode: SaveLoadMode, game_state: &GameState) -> GameResult<Self> {
+        let mut panel = Self::new(mode, game_state.save_system.list_saves()?);
+        if mode == SaveLoadMode::Save {
+            if let Some(name) = &game_state.current_save_name {
+                panel.name_input.set_text(name.clone());
+            }
+        }
+        Ok(panel)
+    }
+
+    /// Whether the dialog saves or loads
+    pub fn mode(&self) -> SaveLoadMode {
+        self.mode
+    }
+
+    /// Listed slots, newest first
+    pub fn slots(&self) -> &[SaveInfo] {
+        &self.slots
+    }
+
+    /// The chosen slot, if one is listed
+    pub fn selected_slot(&self) -> Option<&SaveInfo> {
+        self.selected.and_then(|index| self.slots.get(index))
+    }
+
+    /// Pick a listed slot; in save mode its name replaces the typed one
+    pub fn select_slot(&mut self, index: usize) {
+        if let Some(slot) = self.slots.get(index) {
+            if self.mode == SaveLoadMode::Save {
+                self.name_input.set_text(slot.name.clone());
+            }
+            self.selected = Some(index);
+        }
+    }
+
+    /// Name typed into the slot name field
+    pub fn slot_name(&self) -> &str {
+        self.name_input.get_text().trim()
+    }
+
+    /// Type a slot name, selecting the listed slot with that name
+    pub fn set_slot_name(&mut self, name: &str) {
+        self.name_input.set_text(name.to_string());
+        self.selected = self.slots.iter().position(|slot| slot.name == name);
+    }
+
+    /// Act on the chosen slot and close; None while no slot is chosen
+    pub fn confirm(&mut self) -> Option<PlayerCommand> {
+        let slot = match self.mode {
+            SaveLoadMode::Save => Some(self.slot_name().to_string()).filter(|name| !name.is_empty()),
+            SaveLoadMode::Load => self.selected_slot().map(|slot| slot.name.clone()),
+        }?;
+        self.visible = false;
+        Some(self.mode.command(slot))
+    }
+
+    /// Close without acting
+    pub fn cancel(&mut self) {
+        self.visible = false;
+    }
+
+    /// One row per listed slot; clicking a row selects it
+    fn rebuild_buttons(&mut self) {
+        let mode = self.mode;
+        self.slot_buttons = self.slots.iter()
+            .take(MAX_LISTED_SLOTS)
+            .enumerate()
+            .map(|(i, slot)| Button::new(format!(
+                "{}   {}   tick {}", slot.name, format_timestamp(slot.timestamp), slot.tick
+            ))
+                .with_layout(Layout::new(PANEL_X + 20.0, PANEL_Y + 40.0 + i as f32 * ROW_HEIGHT, PANEL_WIDTH - 40.0, ROW_HEIGHT - 4.0))
+                .with_click_command(mode.command(slot.name.clone())))
+            .collect();
+    }
+}
+
+impl View for SaveLoadPanel {
+    fn render(&mut self, context: &RenderContext) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        // Dim the game behind the dialog
+        draw_rectangle(0.0, 0.0, context.screen_width, context.screen_height, Color::new(0.0, 0.0, 0.0, 0.5));
+        self.main_panel.render(&(), context)?;
+        if self.slots.is_empty() {
+            draw_text("No saved games", PANEL_X + 20.0, PANEL_Y + 60.0, context.font_size, context.theme.text_color);
+        }
+        for button in &mut self.slot_buttons {
+            button.render(&(), context)?;
+        }
+        if let Some(index) = self.selected.filter(|index| *index < MAX_LISTED_SLOTS) {
+            draw_rectangle_lines(
+                PANEL_X + 18.0, PANEL_Y + 38.0 + index as f32 * ROW_HEIGHT,
+                PANEL_WIDTH - 36.0, ROW_HEIGHT, 2.0, context.theme.accent_color
+            );
+        }
+        self.name_input.render(&(), context)?;
+        self.confirm_button.render(&(), context)?;
+        self.cancel_button.render(&(), context)?;
+        Ok(None)
+    }
+
+    fn handle_input(&mut self, input: &InputEvent) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        // Slot rows carry their command only so a click registers; clicking selects
+        let clicked_slot = self.slot_buttons.iter_mut()
+            .position(|button| matches!(button.handle_input(input), Ok(Some(_))));
+        if let Some(index) = clicked_slot {
+            self.select_slot(index);
+            return Ok(None);
+        }
+
+        self.name_input.handle_input(input)?;
+        if matches!(input, InputEvent::KeyPress { key: KeyCode::Enter }) && self.mode == SaveLoadMode::Save {
+            return Ok(self.confirm());
+        }
+
+        if let InputEvent::MouseClick { x, y, button: MouseButton::Left } = input {
+            let point = Vec2::new(*x, *y);
+            if self.confirm_button.get_layout().get_rect().contains(point) {
+                return Ok(self.confirm());
+            }
+            if self.cancel_button.get_layout().get_rect().contains(point) {
+                self.cancel();
+            }
+        }
+        Ok(None)
+    }
+
+    fn update(&mut self, delta_time: f32) -> ComponentResult {
+        if self.visible {
+            self.main_panel.update(delta_time)?;
+        }
+        Ok(None)
+    }
+
+    fn update_data(&mut self, _data: ViewData) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    fn refresh(&mut self) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn get_view_type(&self) -> &'static str {
+        SAVE_LOAD_VIEW_TYPE
+    }
+}
@@ -0,0 +1,160 @@
+// src/ui_v2/panels/selection_panel.rs
+//! Summary of a multi-ship selection
+//!
+//! While two or more of the player's ships are selected, by Shift+click, a
+//! drag-select box or a control group, this panel in the lower left counts
+//! them by class and gives orders to all of them at once: Stop halts them
+//! where they are and Recall sends each to its nearest friendly planet.
+//! Hovering the header lists the selection keys. The client rebuilds it
+//! whenever the selected ships change.
+
+use crate::ui_v2::{View, Button, RenderContext, ComponentResult, InputEvent, ViewData, Layout, Tooltip};
+use crate::ui_v2::components::base_component::UIComponent;
+use crate::core::{types::*, events::PlayerCommand};
+use crate::GameState;
+use macroquad::prelude::*;
+
+/// View type reported by the selection panel
+pub const SELECTION_PANEL_VIEW_TYPE: &str = "SelectionPanel";
+
+const PANEL_X: f32 = 10.0;
+const PANEL_Y: f32 = 560.0;
+const PANEL_WIDTH: f32 = 220.0;
+const LINE_HEIGHT: f32 = 20.0;
+
+/// Ship counts by class and orders for every selected ship
+pub struct SelectionPanel {
+    ships: Vec<ShipId>,
+    counts: Vec<(ShipClass, usize)>,
+    stop_button: Button,
+    recall_button: Button,
+    visible: bool,
+}
+
+impl SelectionPanel {
+    /// Panel for the given ships
+    pub fn new(ships: &[&Ship]) -> Self {
+        let counts: Vec<(ShipClass, usize)> = ShipClass::ALL.iter()
+            .map(|&class| (class, ships.iter().filter(|ship| ship.ship_class == class).count()))
+            .filter(|&(_, count)| count > 0)
+            .collect();
+        let buttons_y = PANEL_Y + 36.0 + counts.len() as f32 * LINE_HEIGHT;
+        Self {
+            ships: ships.iter().map(|ship| ship.id).collect(),
+            counts,
+            stop_button: Button::new("Stop".to_string())
+                .with_layout(Layout::new(PANEL_X + 8.0, buttons_y, 96.0, 26.0)),
+            recall_button: Button::new("Recall".to_string())
+                .with_layout(Layout::new(PANEL_X + 112.0, buttons_y, 96.0, 26.0)),
+            visible: true,
+        }
+    }
+
+    /// Panel for the ships with these ids that still exist
+    pub fn for_game(game_state: &GameState, ships: &[ShipId]) -> Self {
+        let ships: Vec<&Ship> = ships.iter().filter_map(|&id| game_state.ship_manager.get_ship(id).ok()).collect();
+        Self::new(&ships)
+    }
+
+    /// Ships the panel gives orders to
+    pub fn ships(&self) -> &[ShipId] {
+        &self.ships
+    }
+
+    /// How many ships of each class are selected, classes without any left out
+    pub fn counts(&self) -> &[(ShipClass, usize)] {
+        &self.counts
+    }
+
+    /// Stop every selected ship
+    pub fn stop_all(&self) -> PlayerCommand {
+        PlayerCommand::IssueOrders(self.ships.iter().map(|&ship| PlayerCommand::StopShip(ship)).collect())
+    }
+
+    /// Send every selected ship back to its nearest friendly planet
+    pub fn recall_all(&self) -> PlayerCommand {
+        PlayerCommand::IssueOrders(self.ships.iter().map(|&ship| PlayerCommand::RecallShip(ship)).collect())
+    }
+
+    fn height(&self) -> f32 {
+        70.0 + self.counts.len() as f32 * LINE_HEIGHT
+    }
+}
+
+impl View for SelectionPanel {
+    fn render(&mut self, context: &RenderContext) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        draw_rectangle(PANEL_X, PANEL_Y, PANEL_WIDTH, self.height(), Color::new(0.0, 0.0, 0.0, 0.7));
+        draw_rectangle_lines(PANEL_X, PANEL_Y, PANEL_WIDTH, self.height(), 1.0, context.theme.border_color);
+        let header = format!("{} ships selected", self.ships.len());
+        draw_text(&header, PANEL_X + 8.0, PANEL_Y + 20.0, context.font_size, context.theme.text_color);
+        for (i, (class, count)) in self.counts.iter().enumerate() {
+            let y = PANEL_Y + 40.0 + i as f32 * LINE_HEIGHT;
+            draw_text(&format!("{:?}: {}", class, count), PANEL_X + 16.0, y, context.font_size * 0.9, context.theme.text_color);
+        }
+        self.stop_button.render(&(), context)?;
+        self.recall_button.render(&(), context)?;
+        Ok(None)
+    }
+
+    fn handle_input(&mut self, input: &InputEvent) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        if let InputEvent::MouseClick { x, y, button: MouseButton::Left } = input {
+            let point = Vec2::new(*x, *y);
+            if self.stop_button.get_layout().get_rect().contains(point) {
+                return Ok(Some(self.stop_all()));
+            }
+            if self.recall_button.get_layout().get_rect().contains(point) {
+                return Ok(Some(self.recall_all()));
+            }
+        }
+        Ok(None)
+    }
+
+    fn update(&mut self, _delta_time: f32) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn update_data(&mut self, _data: ViewData) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    fn refresh(&mut self) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn get_view_type(&self) -> &'static str {
+        SELECTION_PANEL_VIEW_TYPE
+    }
+
+    fn tooltips(&self) -> Vec<(Rect, Tooltip)> {
+        if !self.visible {
+            return Vec::new();
+        }
+        let count = self.ships.len();
+        vec![
+            (Rect::new(PANEL_X, PANEL_Y, PANEL_WIDTH, 28.0), Tooltip::new(format!("{} ships selected", count))
+                .key_hint("Shift+Click", "Add or remove a ship")
+                .key_hint("Ctrl+1-9", "Save as a control group")
+                .key_hint("1-9", "Select a control group, twice to center it")),
+            (self.stop_button.get_layout().get_rect(), Tooltip::new("Stop")
+                .text(format!("Halt all {} ships where they are", count))),
+            (self.recall_button.get_layout().get_rect(), Tooltip::new("Recall")
+                .text(format!("Send all {} ships to their nearest friendly planet", count))),
+        ]
+    }
+}
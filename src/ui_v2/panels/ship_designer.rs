@@ -0,0 +1,243 @@
+// src/ui_v2/panels/ship_designer.rs
+//! Dialog for putting together a ship design
+//!
+//! The player picks a hull, fits modules into its slots, or starts from one
+//! of the preset classes, and watches the stats the design would have.
+//! Saving emits SaveShipDesign for the player's faction; the design then
+//! shows up among the shipyard options of the planet panel.
+
+use crate::ui_v2::{View, Panel, Button, RenderContext, ComponentResult, InputEvent, ViewData, Layout};
+use crate::ui_v2::components::base_component::UIComponent;
+use crate::ui_v2::components::TextInput;
+use crate::core::{types::*, events::PlayerCommand, HullSize, ShipDesign, ShipDesigner, ShipModule};
+use macroquad::prelude::*;
+
+/// View type reported by the ship designer
+pub const SHIP_DESIGNER_VIEW_TYPE: &str = "ShipDesigner";
+
+const PANEL_X: f32 = 262.0;
+const PANEL_Y: f32 = 110.0;
+const PANEL_WIDTH: f32 = 500.0;
+const PANEL_HEIGHT: f32 = 470.0;
+const LINE_HEIGHT: f32 = 22.0;
+
+/// Modal dialog building a ship design for a faction
+pub struct ShipDesignerPanel {
+    main_panel: Panel,
+    hull_buttons: Vec<(HullSize, Button)>,
+    module_buttons: Vec<(ShipModule, Button)>,
+    preset_buttons: Vec<(ShipClass, Button)>,
+    remove_button: Button,
+    name_input: TextInput,
+    save_button: Button,
+    cancel_button: Button,
+    designer: ShipDesigner,
+    faction: FactionId,
+    /// Why the last save or fitting was refused
+    error: Option<String>,
+    visible: bool,
+}
+
+impl ShipDesignerPanel {
+    /// Designer for the faction, starting from an empty small hull
+    pub fn new(faction: FactionId) -> Self {
+        let button = |label: String, x: f32, y: f32, width: f32| Button::new(label)
+            .with_layout(Layout::new(x, y, width, 24.0));
+        Self {
+            main_panel: Panel::new("Ship Designer".to_string())
+                .with_layout(Layout::new(PANEL_X, PANEL_Y, PANEL_WIDTH, PANEL_HEIGHT))
+                .collapsible(false),
+            hull_buttons: HullSize::ALL.iter().enumerate()
+                .map(|(i, &hull)| (hull, button(format!("{:?} ({})", hull, hull.slots()), PANEL_X + 90.0 + i as f32 * 130.0, PANEL_Y + 40.0, 120.0)))
+                .collect(),
+            module_buttons: ShipModule::ALL.iter().enumerate()
+                .map(|(i, &module)| (module, button(Self::module_label(module).to_string(), PANEL_X + 20.0 + i as f32 * 78.0, PANEL_Y + 100.0, 74.0)))
+                .collect(),
+            preset_buttons: ShipClass::ALL.iter().enumerate()
+                .map(|(i, &class)| (class, button(format!("{:?}", class), PANEL_X + 90.0 + i as f32 * 98.0, PANEL_Y + 150.0, 90.0)))
+                .collect(),
+            remove_button: button("Remove last".to_string(), PANEL_X + PANEL_WIDTH - 130.0, PANEL_Y + 180.0, 110.0),
+            name_input: TextInput::new()
+                .with_placeholder("Design name".to_string())
+                .with_layout(Layout::new(PANEL_X + 20.0, PANEL_Y + PANEL_HEIGHT - 90.0, PANEL_WIDTH - 40.0, 28.0)),
+            save_button: button("Save".to_string(), PANEL_X + 20.0, PANEL_Y + PANEL_HEIGHT - 50.0, 120.0),
+            cancel_button: button("Cancel".to_string(), PANEL_X + 160.0, PANEL_Y + PANEL_HEIGHT - 50.0, 120.0),
+            designer: ShipDesigner::new(HullSize::Small),
+            faction,
+            error: None,
+            visible: true,
+        }
+    }
+
+    fn module_label(module: ShipModule) -> &'static str {
+        match module {
+            ShipModule::Engine => "Engine",
+            ShipModule::CargoPod => "Cargo",
+            ShipModule::Weapon => "Weapon",
+            ShipModule::Sensor => "Sensor",
+            ShipModule::ColonyModule => "Colony",
+            ShipModule::FuelTank => "Fuel",
+        }
+    }
+
+    /// The design being put together
+    pub fn designer(&self) -> &ShipDesigner {
+        &self.designer
+    }
+
+    /// Name the design will be saved under
+    pub fn set_name(&mut self, name: &str) {
+        self.name_input.set_text(name.to_string());
+    }
+
+    /// Fit a module, noting why if there is no room
+    pub fn add_module(&mut self, module: ShipModule) {
+        self.error = self.designer.add_module(module).err().map(|e| e.to_string());
+    }
+
+    /// Start over from a preset class's hull and modules
+    pub fn load_preset(&mut self, ship_class: ShipClass) {
+        self.designer = ShipDesigner::from_design(&ShipDesign::preset(ship_class));
+        self.error = None;
+    }
+
+    /// Save the design and close, or stay open with the reason it cannot be built
+    pub fn confirm(&mut self) -> Option<PlayerCommand> {
+        match self.designer.finish(self.name_input.get_text()) {
+            Ok(design) => {
+                self.visible = false;
+                Some(PlayerCommand::SaveShipDesign { faction: self.faction, design })
+            }
+            Err(e) => {
+                self.error = Some(e.to_string());
+                None
+            }
+        }
+    }
+
+    /// Close without saving
+    pub fn cancel(&mut self) {
+        self.visible = false;
+    }
+
+    /// Slots and stats of the design as it stands
+    fn summary(&self) -> Vec<String> {
+        let stats = self.designer.stats();
+        let mut slots: Vec<&str> = self.designer.modules().iter().map(|&module| Self::module_label(module)).collect();
+        slots.extend(std::iter::repeat_n("-", self.designer.free_slots()));
+        vec![
+            format!("Slots: {}", slots.join(", ")),
+            format!("Role: {:?}", self.designer.design("").role()),
+            format!("Speed {:.1}  Cargo {}  Fuel {:.0}  Sensors {:.1}", stats.speed, stats.cargo_capacity, stats.fuel, stats.sensor_range),
+            format!("Hull {}  Shields {}  Firepower {}  Point defense {}%", stats.combat.hull, stats.combat.shields, stats.firepower(), stats.combat.point_defense),
+            format!("Cost {}  Build time {} ticks", stats.cost.total(), stats.build_time),
+        ]
+    }
+}
+
+impl View for ShipDesignerPanel {
+    fn render(&mut self, context: &RenderContext) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        // Dim the game behind the dialog
+        draw_rectangle(0.0, 0.0, context.screen_width, context.screen_height, Color::new(0.0, 0.0, 0.0, 0.5));
+        self.main_panel.render(&(), context)?;
+        let label = |text: &str, y: f32| draw_text(text, PANEL_X + 20.0, y, context.font_size * 0.9, context.theme.text_color);
+        label("Hull:", PANEL_Y + 57.0);
+        label("Fit a module:", PANEL_Y + 92.0);
+        label("Presets:", PANEL_Y + 167.0);
+        for (hull, button) in &mut self.hull_buttons {
+            button.render(&(), context)?;
+            if *hull == self.designer.hull() {
+                let rect = button.get_layout().get_rect();
+                draw_rectangle_lines(rect.x - 2.0, rect.y - 2.0, rect.w + 4.0, rect.h + 4.0, 2.0, context.theme.accent_color);
+            }
+        }
+        for (_, button) in &mut self.module_buttons {
+            button.render(&(), context)?;
+        }
+        for (_, button) in &mut self.preset_buttons {
+            button.render(&(), context)?;
+        }
+        self.remove_button.render(&(), context)?;
+        for (i, line) in self.summary().iter().enumerate() {
+            let y = PANEL_Y + 225.0 + i as f32 * LINE_HEIGHT;
+            draw_text(line, PANEL_X + 20.0, y, context.font_size * 0.85, context.theme.text_color);
+        }
+        if let Some(error) = &self.error {
+            let y = PANEL_Y + 225.0 + 5.0 * LINE_HEIGHT;
+            draw_text(error, PANEL_X + 20.0, y, context.font_size * 0.85, context.theme.error_color);
+        }
+        self.name_input.render(&(), context)?;
+        self.save_button.render(&(), context)?;
+        self.cancel_button.render(&(), context)?;
+        Ok(None)
+    }
+
+    fn handle_input(&mut self, input: &InputEvent) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        self.name_input.handle_input(input)?;
+        if matches!(input, InputEvent::KeyPress { key: KeyCode::Enter }) {
+            return Ok(self.confirm());
+        }
+
+        if let InputEvent::MouseClick { x, y, button: MouseButton::Left } = input {
+            let point = Vec2::new(*x, *y);
+            let hit = |button: &Button| button.get_layout().get_rect().contains(point);
+            if let Some((hull, _)) = self.hull_buttons.iter().find(|(_, button)| hit(button)) {
+                self.designer.set_hull(*hull);
+                self.error = None;
+            } else if let Some((module, _)) = self.module_buttons.iter().find(|(_, button)| hit(button)) {
+                let module = *module;
+                self.add_module(module);
+            } else if let Some((class, _)) = self.preset_buttons.iter().find(|(_, button)| hit(button)) {
+                let class = *class;
+                self.load_preset(class);
+            } else if hit(&self.remove_button) {
+                let last = self.designer.modules().len().checked_sub(1);
+                if let Some(slot) = last {
+                    self.designer.remove_module(slot);
+                }
+                self.error = None;
+            } else if hit(&self.save_button) {
+                return Ok(self.confirm());
+            } else if hit(&self.cancel_button) {
+                self.cancel();
+            }
+        }
+        Ok(None)
+    }
+
+    fn update(&mut self, delta_time: f32) -> ComponentResult {
+        if self.visible {
+            self.main_panel.update(delta_time)?;
+            self.name_input.update(delta_time)?;
+        }
+        Ok(None)
+    }
+
+    fn update_data(&mut self, _data: ViewData) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    fn refresh(&mut self) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn get_view_type(&self) -> &'static str {
+        SHIP_DESIGNER_VIEW_TYPE
+    }
+}
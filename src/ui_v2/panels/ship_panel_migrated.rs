@@ -8,10 +8,14 @@ use crate::ui_v2::{
     View, EntityView, Panel, Button, ListView, Dropdown,
     ShipAdapter, RenderContext, ComponentResult, InputEvent, ViewData, Layout
 };
-use crate::ui_v2::components::base_component::UIComponent;
+use crate::ui_v2::components::base_component::{UIComponent, Stateful};
+use crate::ui_v2::components::TextInput;
 use crate::core::{types::*, events::PlayerCommand, GameResult};
 use macroquad::prelude::*;
 
+/// View type of the ship panel, for targeting data updates
+pub const SHIP_PANEL_VIEW_TYPE: &str = "ShipPanelMigrated";
+
 /// Migrated ShipPanel using ui_v2 components
 /// Replaces the 753-line old implementation with ~350 lines
 pub struct ShipPanelMigrated {
@@ -28,9 +32,13 @@ pub struct ShipPanelMigrated {
     
     // Action buttons
     action_buttons: Vec<Button>,
+    rename_input: TextInput,
+    rename_button: Button,
     
     // State
     current_ship: Option<Ship>,
+    /// The player's faction, offered the rename field on its own ships
+    player: Option<FactionId>,
     available_ships: Vec<ShipInfo>,
     visible: bool,
 }
@@ -55,38 +63,38 @@ impl ShipPanelMigrated {
     pub fn new() -> Self {
         // Create main panel positioned at bottom-right
         let main_panel = Panel::new("Ship Information".to_string())
-            .with_layout(Layout::new(500.0, 300.0, 300.0, 450.0))
+            .with_layout(Layout::new(500.0, 260.0, 300.0, 490.0))
             .collapsible(false);
 
         // Create ship selector dropdown
         let ship_selector = Dropdown::new()
-            .with_layout(Layout::new(510.0, 340.0, 280.0, 30.0));
+            .with_layout(Layout::new(510.0, 300.0, 280.0, 30.0));
 
         // Create entity view for ship details
         let entity_view = EntityView::new(
             "Ship Details".to_string(),
             Box::new(ShipAdapter::new())
-        ).with_layout(Layout::new(510.0, 380.0, 280.0, 150.0));
+        ).with_layout(Layout::new(510.0, 340.0, 280.0, 150.0));
 
         // Create status panel for current state
         let status_panel = Panel::new("Status".to_string())
-            .with_layout(Layout::new(510.0, 540.0, 280.0, 80.0));
+            .with_layout(Layout::new(510.0, 500.0, 280.0, 80.0));
 
         // Create cargo list
         let cargo_list = ListView::new()
-            .with_layout(Layout::new(510.0, 630.0, 280.0, 80.0))
+            .with_layout(Layout::new(510.0, 590.0, 280.0, 80.0))
             .with_item_height(20.0);
 
         // Create action buttons
         let action_buttons = vec![
             Button::new("Move Ship".to_string())
-                .with_layout(Layout::new(510.0, 720.0, 85.0, 25.0))
+                .with_layout(Layout::new(510.0, 680.0, 85.0, 25.0))
                 .with_click_command(PlayerCommand::SelectShip(0)), // Will be updated dynamically
             Button::new("Manage Cargo".to_string())
-                .with_layout(Layout::new(600.0, 720.0, 95.0, 25.0))
+                .with_layout(Layout::new(600.0, 680.0, 95.0, 25.0))
                 .with_click_command(PlayerCommand::SelectShip(0)),
             Button::new("Recall Ship".to_string())
-                .with_layout(Layout::new(700.0, 720.0, 85.0, 25.0))
+                .with_layout(Layout::new(700.0, 680.0, 85.0, 25.0))
                 .with_click_command(PlayerCommand::SelectShip(0)),
         ];
 
@@ -97,7 +105,13 @@ impl ShipPanelMigrated {
             status_panel,
             cargo_list,
             action_buttons,
+            rename_input: TextInput::new()
+                .with_placeholder("New name".to_string())
+                .with_layout(Layout::new(510.0, 715.0, 190.0, 25.0)),
+            rename_button: Button::new("Rename".to_string())
+                .with_layout(Layout::new(705.0, 715.0, 80.0, 25.0)),
             current_ship: None,
+            player: None,
             available_ships: Vec::new(),
             visible: false,
         }
@@ -105,6 +119,10 @@ impl ShipPanelMigrated {
 
     /// Show ship information (replaces old show method)
     pub fn show_ship(&mut self, ship: Ship) -> GameResult<()> {
+        // Refreshes of the same ship keep whatever is being typed
+        if self.current_ship.as_ref().is_none_or(|shown| shown.id != ship.id) {
+            self.rename_input.set_text(ship.name.clone().unwrap_or_default());
+        }
         self.current_ship = Some(ship.clone());
         self.visible = true;
         
@@ -120,6 +138,28 @@ impl ShipPanelMigrated {
         Ok(())
     }
 
+    /// Set the player's faction, which may rename the ships it owns
+    pub fn set_player(&mut self, player: Option<FactionId>) {
+        self.player = player;
+    }
+
+    /// True if the player owns the shown ship and may rename it
+    pub fn is_renamable(&self) -> bool {
+        self.current_ship.as_ref().is_some_and(|ship| Some(ship.owner) == self.player)
+    }
+
+    /// Name typed into the rename field
+    pub fn set_rename_text(&mut self, name: &str) {
+        self.rename_input.set_text(name.to_string());
+    }
+
+    /// RenameEntity for the shown ship with the typed name, if the player may rename it
+    pub fn rename_command(&self) -> Option<PlayerCommand> {
+        let ship = self.current_ship.as_ref().filter(|_| self.is_renamable())?;
+        let name = self.rename_input.get_text().trim();
+        (!name.is_empty()).then(|| PlayerCommand::RenameEntity { target: NamedEntity::Ship(ship.id), name: name.to_string() })
+    }
+
     /// Hide the panel
     pub fn hide(&mut self) {
         self.visible = false;
@@ -147,7 +187,7 @@ impl ShipPanelMigrated {
         self.available_ships = ships.iter().map(|ship| {
             ShipInfo {
                 id: ship.id,
-                name: format!("{} {}", self.get_ship_class_name(ship.ship_class), ship.id),
+                name: ship.display_name(),
                 class: ship.ship_class,
                 status: if ship.trajectory.is_some() { "Moving".to_string() } else { "Idle".to_string() },
             }
@@ -229,16 +269,6 @@ impl ShipPanelMigrated {
         // Update other components similarly...
     }
 
-    /// Get ship class display name
-    fn get_ship_class_name(&self, class: ShipClass) -> &'static str {
-        match class {
-            ShipClass::Scout => "Scout",
-            ShipClass::Transport => "Transport",
-            ShipClass::Warship => "Warship",
-            ShipClass::Colony => "Colony",
-        }
-    }
-
     /// Render ship status information
     fn render_ship_status(&self, ship: &Ship, context: &RenderContext) -> ComponentResult {
         let status_rect = self.status_panel.get_layout().get_rect();
@@ -317,6 +347,10 @@ impl View for ShipPanelMigrated {
             for button in &mut self.action_buttons {
                 button.render(&(), context)?;
             }
+            if self.is_renamable() {
+                self.rename_input.render(&(), context)?;
+                self.rename_button.render(&(), context)?;
+            }
         } else {
             // Show "No ship selected" message
             let panel_rect = self.main_panel.get_layout().get_rect();
@@ -352,6 +386,17 @@ impl View for ShipPanelMigrated {
                 }
             }
             
+            if self.is_renamable() {
+                // Enter in the field renames, as does the button
+                let submitted = self.rename_input.is_focused() && matches!(input, InputEvent::KeyPress { key: KeyCode::Enter });
+                self.rename_input.handle_input(input)?;
+                let clicked = matches!(input, InputEvent::MouseClick { x, y, button: MouseButton::Left }
+                    if self.rename_button.get_layout().get_rect().contains(Vec2::new(*x, *y)));
+                if submitted || clicked {
+                    return Ok(self.rename_command());
+                }
+            }
+            
             // Handle entity view input
             self.entity_view.handle_input(input)?;
             
@@ -387,7 +432,7 @@ impl View for ShipPanelMigrated {
     fn update_data(&mut self, data: ViewData) -> ComponentResult {
         if let ViewData::Ship(ship) = data {
             if let Err(e) = self.show_ship(ship) {
-                eprintln!("Ship data update error: {:?}", e);
+                log::error!("Ship data update error: {:?}", e);
             }
         }
         Ok(None)
@@ -407,14 +452,14 @@ impl View for ShipPanelMigrated {
     fn refresh(&mut self) -> ComponentResult {
         if let Some(ship) = self.current_ship.clone() {
             if let Err(e) = self.update_cargo_list(&ship) {
-                eprintln!("Ship panel refresh error: {:?}", e);
+                log::error!("Ship panel refresh error: {:?}", e);
             }
         }
         Ok(None)
     }
 
     fn get_view_type(&self) -> &'static str {
-        "ShipPanelMigrated"
+        SHIP_PANEL_VIEW_TYPE
     }
 }
 
@@ -0,0 +1,413 @@
+// src/ui_v2/panels/shuttle_run_wizard.rs
+//! "Set up shuttle run" wizard
+//!
+//! Walks the player through picking a source planet, a destination and the
+//! cargo to move, then picks an idle transport automatically and emits a
+//! single CreateTradeRoute command. Expected throughput is shown before the
+//! route is confirmed.
+
+use crate::ui_v2::{
+    View, Panel, Button, RenderContext, ComponentResult, InputEvent, ViewData, Layout
+};
+use crate::ui_v2::components::base_component::UIComponent;
+use crate::core::{types::*, events::PlayerCommand, GameResult};
+use crate::GameState;
+use macroquad::prelude::*;
+
+/// View type of the shuttle run wizard
+pub const SHUTTLE_RUN_WIZARD_VIEW_TYPE: &str = "ShuttleRunWizard";
+
+/// Ticks spent loading at the source and unloading at the destination
+const DOCKING_TICKS: u64 = 2;
+/// Travel speed used for estimates, matching PhysicsEngine's base speed
+const TRANSPORT_SPEED: f32 = 10.0;
+/// Amount added per click when choosing cargo
+const CARGO_STEP: i32 = 100;
+
+/// Wizard steps in order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WizardStep {
+    /// Pick the planet the cargo is loaded at
+    SelectSource,
+    /// Pick the planet it is delivered to
+    SelectDestination,
+    /// Pick the cargo
+    SelectResources,
+    /// Review the plan and confirm
+    Confirm,
+}
+
+/// A proposed shuttle run with the transport chosen for it
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShuttlePlan {
+    /// The route to set up, with the chosen transport
+    pub route: TradeRoute,
+    /// Ticks for one trip there and back
+    pub round_trip_ticks: u64,
+    /// Units delivered per 100 ticks
+    pub throughput_per_100_ticks: i64,
+}
+
+/// Pick an idle transport that can carry the cargo and estimate its throughput.
+///
+/// `idle_transports` should come from `ShipManager::get_idle_transports`; the
+/// first ship with enough capacity is chosen so the result is deterministic.
+pub fn plan_shuttle_run(
+    source: &Planet,
+    destination: &Planet,
+    resources: ResourceBundle,
+    idle_transports: &[Ship],
+) -> GameResult<ShuttlePlan> {
+    if source.id == destination.id {
+        return Err(GameError::InvalidOperation("Source and destination must differ".into()));
+    }
+    resources.validate_non_negative()?;
+    let cargo = resources.total();
+    if cargo <= 0 {
+        return Err(GameError::InvalidOperation("Select some cargo to ship".into()));
+    }
+
+    let ship = idle_transports.iter()
+        .find(|ship| ship.ship_class == ShipClass::Transport && ship.cargo.capacity as i64 >= cargo)
+        .ok_or_else(|| GameError::InvalidOperation(
+            format!("No idle transport can carry {} units", cargo)
+        ))?;
+
+    let distance = orbit_position(&source.position).distance_to(&orbit_position(&destination.position));
+    let travel_ticks = (distance / TRANSPORT_SPEED).ceil().max(1.0) as u64;
+    let round_trip_ticks = 2 * travel_ticks + 2 * DOCKING_TICKS;
+
+    Ok(ShuttlePlan {
+        route: TradeRoute {
+            ship: ship.id,
+            from: source.id,
+            to: destination.id,
+            resources,
+        },
+        round_trip_ticks,
+        throughput_per_100_ticks: cargo * 100 / round_trip_ticks as i64,
+    })
+}
+
+fn orbit_position(orbit: &OrbitalElements) -> Vector2 {
+    Vector2::new(
+        orbit.semi_major_axis * orbit.phase.cos(),
+        orbit.semi_major_axis * orbit.phase.sin(),
+    )
+}
+
+/// Wizard view for setting up a trade route in a few clicks
+pub struct ShuttleRunWizard {
+    main_panel: Panel,
+    option_buttons: Vec<Button>,
+    back_button: Button,
+    next_button: Button,
+
+    owner: FactionId,
+    step: WizardStep,
+    planets: Vec<Planet>,
+    idle_transports: Vec<Ship>,
+    source: Option<PlanetId>,
+    destination: Option<PlanetId>,
+    resources: ResourceBundle,
+    plan: Option<ShuttlePlan>,
+    last_error: Option<String>,
+    visible: bool,
+}
+
+impl ShuttleRunWizard {
+    /// Hidden wizard for the faction, with no data loaded
+    pub fn new(owner: FactionId) -> Self {
+        Self {
+            main_panel: Panel::new("Set Up Shuttle Run".to_string())
+                .with_layout(Layout::new(200.0, 100.0, 400.0, 360.0))
+                .collapsible(false),
+            option_buttons: Vec::new(),
+            back_button: Button::new("Back".to_string())
+                .with_layout(Layout::new(220.0, 420.0, 80.0, 25.0))
+                .with_click_command(PlayerCommand::ShowResourcePanel),
+            next_button: Button::new("Next".to_string())
+                .with_layout(Layout::new(500.0, 420.0, 80.0, 25.0))
+                .with_click_command(PlayerCommand::ShowResourcePanel),
+            owner,
+            step: WizardStep::SelectSource,
+            planets: Vec::new(),
+            idle_transports: Vec::new(),
+            source: None,
+            destination: None,
+            resources: ResourceBundle::default(),
+            plan: None,
+            last_error: None,
+            visible: false,
+        }
+    }
+
+    /// Wizard for the faction's planets and idle transports as they are now
+    pub fn for_game(game_state: &GameState, owner: FactionId) -> GameResult<Self> {
+        let mut wizard = Self::new(owner);
+        wizard.open(game_state)?;
+        Ok(wizard)
+    }
+
+    /// Open the wizard with fresh planet and fleet data
+    pub fn open(&mut self, game_state: &GameState) -> GameResult<()> {
+        self.refresh_data(game_state)?;
+        self.step = WizardStep::SelectSource;
+        self.source = None;
+        self.destination = None;
+        self.resources = ResourceBundle::default();
+        self.plan = None;
+        self.last_error = None;
+        self.visible = true;
+        self.rebuild_options();
+        Ok(())
+    }
+
+    /// Reload owned planets and idle transports
+    pub fn refresh_data(&mut self, game_state: &GameState) -> GameResult<()> {
+        self.planets = game_state.planet_manager.get_planets_by_faction(self.owner)
+            .into_iter()
+            .cloned()
+            .collect();
+        self.idle_transports = game_state.ship_manager.get_idle_transports(self.owner)
+            .into_iter()
+            .map(|id| game_state.ship_manager.get_ship(id).cloned())
+            .collect::<GameResult<Vec<_>>>()?;
+        Ok(())
+    }
+
+    /// Current step
+    pub fn step(&self) -> WizardStep {
+        self.step
+    }
+
+    /// The proposed run, once the cargo is chosen
+    pub fn plan(&self) -> Option<&ShuttlePlan> {
+        self.plan.as_ref()
+    }
+
+    /// Use one of the faction's planets as the source
+    pub fn select_source(&mut self, planet: PlanetId) -> GameResult<()> {
+        self.owned_planet(planet)?;
+        self.source = Some(planet);
+        self.step = WizardStep::SelectDestination;
+        self.rebuild_options();
+        Ok(())
+    }
+
+    /// Use another of the faction's planets as the destination
+    pub fn select_destination(&mut self, planet: PlanetId) -> GameResult<()> {
+        self.owned_planet(planet)?;
+        if Some(planet) == self.source {
+            return Err(GameError::InvalidOperation("Source and destination must differ".into()));
+        }
+        self.destination = Some(planet);
+        self.step = WizardStep::SelectResources;
+        self.rebuild_options();
+        Ok(())
+    }
+
+    /// Set the cargo per trip and compute the plan, advancing to confirmation
+    pub fn set_resources(&mut self, resources: ResourceBundle) -> GameResult<&ShuttlePlan> {
+        let (source, destination) = match (self.source, self.destination) {
+            (Some(source), Some(destination)) => (source, destination),
+            _ => return Err(GameError::InvalidOperation("Select source and destination first".into())),
+        };
+        let plan = plan_shuttle_run(
+            self.owned_planet(source)?,
+            self.owned_planet(destination)?,
+            resources,
+            &self.idle_transports,
+        )?;
+        self.resources = resources;
+        self.step = WizardStep::Confirm;
+        self.rebuild_options();
+        Ok(self.plan.insert(plan))
+    }
+
+    /// Produce the command that creates the route and close the wizard
+    pub fn confirm(&mut self) -> GameResult<PlayerCommand> {
+        let plan = self.plan.take()
+            .ok_or_else(|| GameError::InvalidOperation("No shuttle run planned".into()))?;
+        self.visible = false;
+        Ok(PlayerCommand::CreateTradeRoute(plan.route))
+    }
+
+    /// Go back one step
+    pub fn back(&mut self) {
+        self.step = match self.step {
+            WizardStep::SelectSource => {
+                self.visible = false;
+                WizardStep::SelectSource
+            }
+            WizardStep::SelectDestination => WizardStep::SelectSource,
+            WizardStep::SelectResources => WizardStep::SelectDestination,
+            WizardStep::Confirm => {
+                self.plan = None;
+                WizardStep::SelectResources
+            }
+        };
+        self.rebuild_options();
+    }
+
+    fn owned_planet(&self, planet: PlanetId) -> GameResult<&Planet> {
+        self.planets.iter()
+            .find(|p| p.id == planet)
+            .ok_or_else(|| GameError::InvalidTarget(format!("Planet {} is not one of your planets", planet)))
+    }
+
+    /// Rebuild the clickable options for the current step
+    fn rebuild_options(&mut self) {
+        let row = |i: usize| Layout::new(220.0, 140.0 + i as f32 * 30.0, 360.0, 25.0);
+        self.option_buttons = match self.step {
+            WizardStep::SelectSource | WizardStep::SelectDestination => self.planets.iter()
+                .filter(|p| self.step == WizardStep::SelectSource || Some(p.id) != self.source)
+                .enumerate()
+                .map(|(i, p)| Button::new(format!("Planet {} (pop {})", p.id, p.population.total))
+                    .with_layout(row(i))
+                    .with_click_command(PlayerCommand::SelectPlanet(p.id)))
+                .collect(),
+            WizardStep::SelectResources => ResourceType::ALL.iter()
+                .enumerate()
+                .map(|(i, resource)| Button::new(format!(
+                        "+{} {:?} (now {})", CARGO_STEP, resource, self.resources.get(*resource)))
+                    .with_layout(row(i))
+                    .with_click_command(PlayerCommand::ShowResourcePanel))
+                .collect(),
+            WizardStep::Confirm => Vec::new(),
+        };
+    }
+
+    fn handle_option(&mut self, index: usize, command: PlayerCommand) -> GameResult<()> {
+        match (self.step, command) {
+            (WizardStep::SelectSource, PlayerCommand::SelectPlanet(planet)) => self.select_source(planet),
+            (WizardStep::SelectDestination, PlayerCommand::SelectPlanet(planet)) => self.select_destination(planet),
+            (WizardStep::SelectResources, _) => {
+                if let Some(resource) = ResourceType::ALL.get(index) {
+                    let amount = self.resources.get(*resource) + CARGO_STEP;
+                    self.resources.set(*resource, amount);
+                    self.rebuild_options();
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn step_title(&self) -> &'static str {
+        match self.step {
+            WizardStep::SelectSource => "1. Pick the source planet",
+            WizardStep::SelectDestination => "2. Pick the destination planet",
+            WizardStep::SelectResources => "3. Choose cargo per trip",
+            WizardStep::Confirm => "4. Confirm shuttle run",
+        }
+    }
+}
+
+impl View for ShuttleRunWizard {
+    fn render(&mut self, context: &RenderContext) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        self.main_panel.render(&(), context)?;
+        draw_text(self.step_title(), 220.0, 130.0, context.font_size, context.theme.text_color);
+
+        for button in &mut self.option_buttons {
+            button.render(&(), context)?;
+        }
+
+        if let Some(plan) = &self.plan {
+            let lines = [
+                format!("Transport: ship {}", plan.route.ship),
+                format!("Route: planet {} -> planet {}", plan.route.from, plan.route.to),
+                format!("Cargo per trip: {}", plan.route.resources.total()),
+                format!("Round trip: {} ticks", plan.round_trip_ticks),
+                format!("Expected throughput: {} per 100 ticks", plan.throughput_per_100_ticks),
+            ];
+            for (i, line) in lines.iter().enumerate() {
+                draw_text(line, 220.0, 165.0 + i as f32 * 25.0, context.font_size * 0.9, context.theme.text_color);
+            }
+        }
+
+        if let Some(error) = &self.last_error {
+            draw_text(error, 220.0, 405.0, context.font_size * 0.8, context.theme.error_color);
+        }
+
+        self.back_button.render(&(), context)?;
+        if matches!(self.step, WizardStep::SelectResources | WizardStep::Confirm) {
+            self.next_button.render(&(), context)?;
+        }
+        Ok(None)
+    }
+
+    fn handle_input(&mut self, input: &InputEvent) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        if let Ok(Some(_)) = self.back_button.handle_input(input) {
+            self.last_error = None;
+            self.back();
+            return Ok(None);
+        }
+
+        if let Ok(Some(_)) = self.next_button.handle_input(input) {
+            let result = match self.step {
+                WizardStep::SelectResources => self.set_resources(self.resources).map(|_| None),
+                WizardStep::Confirm => self.confirm().map(Some),
+                _ => Ok(None),
+            };
+            return match result {
+                Ok(command) => {
+                    self.last_error = None;
+                    Ok(command)
+                }
+                Err(e) => {
+                    self.last_error = Some(e.to_string());
+                    Ok(None)
+                }
+            };
+        }
+
+        let mut clicked = None;
+        for (i, button) in self.option_buttons.iter_mut().enumerate() {
+            if let Ok(Some(command)) = button.handle_input(input) {
+                clicked = Some((i, command));
+                break;
+            }
+        }
+        if let Some((index, command)) = clicked {
+            self.last_error = self.handle_option(index, command).err().map(|e| e.to_string());
+        }
+        Ok(None)
+    }
+
+    fn update(&mut self, delta_time: f32) -> ComponentResult {
+        if self.visible {
+            self.main_panel.update(delta_time)?;
+        }
+        Ok(None)
+    }
+
+    fn update_data(&mut self, _data: ViewData) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    fn refresh(&mut self) -> ComponentResult {
+        self.rebuild_options();
+        Ok(None)
+    }
+
+    fn get_view_type(&self) -> &'static str {
+        SHUTTLE_RUN_WIZARD_VIEW_TYPE
+    }
+}
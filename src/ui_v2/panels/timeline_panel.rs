@@ -0,0 +1,281 @@
+// src/ui_v2/panels/timeline_panel.rs
+//! Timeline dialog for browsing the game's notable events
+//!
+//! Lists what HistorySystem recorded, newest first, in a DataView that
+//! scrolls with the mouse wheel and sorts by any column. The buttons above
+//! the table narrow the list to one category, cycle through the factions
+//! and through the recent tick spans. The dialog works on a snapshot of the
+//! timeline taken when it opens; filtering queries that snapshot.
+
+use crate::ui_v2::{
+    View, Panel, Button, DataView, RenderContext, ComponentResult, InputEvent, ViewData, Layout
+};
+use crate::ui_v2::views::{ColumnDefinition, TextAlign};
+use crate::ui_v2::components::base_component::UIComponent;
+use crate::core::{types::*, events::SimulationEvent};
+use crate::systems::{HistorySystem, HistoryCategory, TimelineEntry, TimelineFilter};
+use crate::GameState;
+use macroquad::prelude::*;
+
+/// View type reported by the timeline dialog
+pub const TIMELINE_VIEW_TYPE: &str = "Timeline";
+/// Tick spans the span button cycles through; None lists the whole game
+pub const TIMELINE_SPANS: [Option<u64>; 4] = [None, Some(100), Some(1_000), Some(10_000)];
+
+const PANEL_X: f32 = 112.0;
+const PANEL_Y: f32 = 80.0;
+const PANEL_WIDTH: f32 = 800.0;
+const PANEL_HEIGHT: f32 = 580.0;
+const BUTTON_HEIGHT: f32 = 28.0;
+const CATEGORY_BUTTON_WIDTH: f32 = 94.0;
+
+/// One-line summary of a timeline event
+pub fn describe_event(event: &SimulationEvent) -> String {
+    match event {
+        SimulationEvent::CombatResolved { attacker, defender, outcome } => format!(
+            "Ship {} attacked ship {}: faction {} won after {} rounds, {} ships lost",
+            attacker, defender, outcome.winner, outcome.rounds,
+            outcome.attacker_losses.len() + outcome.defender_losses.len()
+        ),
+        SimulationEvent::PlanetColonized { planet, colonists, .. } => {
+            format!("Colony of {} founded on planet {}", colonists, planet)
+        }
+        SimulationEvent::ConstructionCompleted { planet, building } => format!("{:?} built on planet {}", building, planet),
+        SimulationEvent::ShipCompleted { planet, ship } => format!("Ship {} launched at planet {}", ship, planet),
+        SimulationEvent::PlanetConquered { planet, new_owner } => format!("Planet {} taken by faction {}", planet, new_owner),
+        SimulationEvent::PlanetInvaded { planet, survivors, .. } => {
+            format!("Planet {} fell to invaders, {} troops landed", planet, survivors)
+        }
+        SimulationEvent::InvasionRepelled { planet, .. } => format!("Invasion of planet {} repelled", planet),
+        SimulationEvent::TreatySigned { treaty, .. } => format!("{:?} signed", treaty.kind),
+        SimulationEvent::TreatyExpired { treaty, .. } => format!("{:?} expired", treaty.kind),
+        SimulationEvent::WarDeclared { aggressor, target } => format!("Faction {} declared war on faction {}", aggressor, target),
+        SimulationEvent::TradeAccepted { .. } => "Trade agreed".to_string(),
+        SimulationEvent::TechnologyUnlocked { technology, .. } => format!("{:?} technology unlocked", technology),
+        SimulationEvent::PlanetDiscovered { planet, .. } => format!("Planet {} discovered", planet),
+        other => format!("{:?}", other),
+    }
+}
+
+/// Modal dialog listing the timeline with category, faction and tick filters
+pub struct TimelinePanel {
+    main_panel: Panel,
+    table: DataView,
+    category_buttons: Vec<(Option<HistoryCategory>, Button)>,
+    faction_button: Button,
+    span_button: Button,
+    close_button: Button,
+
+    history: HistorySystem,
+    /// Factions with their names, in the order the faction button cycles
+    factions: Vec<(FactionId, String)>,
+    current_tick: u64,
+    filter: TimelineFilter,
+    span: Option<u64>,
+    visible: bool,
+}
+
+impl TimelinePanel {
+    /// Dialog over a snapshot of the timeline, as of `current_tick`
+    pub fn new(history: HistorySystem, factions: Vec<(FactionId, String)>, current_tick: u64) -> Self {
+        let table_y = PANEL_Y + 40.0 + 2.0 * (BUTTON_HEIGHT + 8.0);
+        let columns = [("Tick", 80.0, TextAlign::Right), ("Type", 110.0, TextAlign::Left), ("Factions", 150.0, TextAlign::Left), ("Event", 430.0, TextAlign::Left)];
+        let mut panel = Self {
+            main_panel: Panel::new("Timeline".to_string())
+                .with_layout(Layout::new(PANEL_X, PANEL_Y, PANEL_WIDTH, PANEL_HEIGHT))
+                .collapsible(false),
+            table: DataView::new(String::new())
+                .with_layout(Layout::new(PANEL_X + 10.0, table_y, PANEL_WIDTH - 20.0, PANEL_Y + PANEL_HEIGHT - table_y - 10.0))
+                .with_columns(columns.into_iter()
+                    .map(|(title, width, alignment)| ColumnDefinition { title: title.to_string(), width, alignment, sortable: true })
+                    .collect()),
+            category_buttons: Vec::new(),
+            faction_button: Button::new(String::new()),
+            span_button: Button::new(String::new()),
+            close_button: Button::new(String::new()),
+            history,
+            factions,
+            current_tick,
+            filter: TimelineFilter::default(),
+            span: None,
+            visible: true,
+        };
+        panel.apply_filter();
+        panel
+    }
+
+    /// The game's timeline with every faction's name
+    pub fn for_game(game_state: &GameState) -> Self {
+        let factions = game_state.faction_manager.get_all_factions().iter()
+            .map(|faction| (faction.id, faction.name.clone()))
+            .collect();
+        Self::new(game_state.history_system.clone(), factions, game_state.get_current_tick())
+    }
+
+    /// Filter the listed entries pass
+    pub fn filter(&self) -> &TimelineFilter {
+        &self.filter
+    }
+
+    /// Entries passing the filter, newest first
+    pub fn entries(&self) -> Vec<&TimelineEntry> {
+        let mut entries = self.history.query(&self.filter);
+        entries.reverse();
+        entries
+    }
+
+    /// List only the category, or every category with `None`
+    pub fn show_category(&mut self, category: Option<HistoryCategory>) {
+        self.filter.categories = category.into_iter().collect();
+        self.apply_filter();
+    }
+
+    /// Move the faction filter on to the next faction, then back to all
+    pub fn cycle_faction(&mut self) {
+        let next = match self.filter.faction {
+            None => 0,
+            Some(current) => self.factions.iter().position(|(id, _)| *id == current).map_or(0, |i| i + 1),
+        };
+        self.filter.faction = self.factions.get(next).map(|(id, _)| *id);
+        self.apply_filter();
+    }
+
+    /// Move the tick filter on to the next of [`TIMELINE_SPANS`]
+    pub fn cycle_span(&mut self) {
+        let next = TIMELINE_SPANS.iter().position(|span| *span == self.span).map_or(0, |i| i + 1);
+        self.span = TIMELINE_SPANS[next % TIMELINE_SPANS.len()];
+        self.filter.from_tick = self.span.map_or(0, |span| self.current_tick.saturating_sub(span));
+        self.apply_filter();
+    }
+
+    /// Hide the dialog
+    pub fn close(&mut self) {
+        self.visible = false;
+    }
+
+    fn faction_name(&self, faction: FactionId) -> String {
+        self.factions.iter()
+            .find(|(id, _)| *id == faction)
+            .map_or_else(|| format!("Faction {}", faction), |(_, name)| name.clone())
+    }
+
+    /// Fill the table with the entries passing the filter and relabel the buttons
+    fn apply_filter(&mut self) {
+        let rows = self.entries().into_iter()
+            .map(|entry| vec![
+                format!("{:>8}", entry.tick),
+                format!("{:?}", entry.category),
+                entry.factions.iter().map(|faction| self.faction_name(*faction)).collect::<Vec<_>>().join(", "),
+                describe_event(&entry.event),
+            ])
+            .collect();
+        self.table.set_data(rows);
+        self.rebuild_buttons();
+    }
+
+    fn rebuild_buttons(&mut self) {
+        let button = |label: String, x: f32, y: f32, width: f32| {
+            Button::new(label).with_layout(Layout::new(x, y, width, BUTTON_HEIGHT))
+        };
+        let top = PANEL_Y + 40.0;
+        let selected = self.filter.categories.first().copied();
+        self.category_buttons = std::iter::once(None)
+            .chain(HistoryCategory::ALL.into_iter().map(Some))
+            .enumerate()
+            .map(|(i, category)| {
+                let name = category.map_or_else(|| "All".to_string(), |category| format!("{:?}", category));
+                let label = if category == selected { format!("[{}]", name) } else { name };
+                (category, button(label, PANEL_X + 10.0 + i as f32 * (CATEGORY_BUTTON_WIDTH + 4.0), top, CATEGORY_BUTTON_WIDTH))
+            })
+            .collect();
+
+        let second = top + BUTTON_HEIGHT + 8.0;
+        let faction = self.filter.faction.map_or_else(|| "all".to_string(), |faction| self.faction_name(faction));
+        self.faction_button = button(format!("Faction: {}", faction), PANEL_X + 10.0, second, 220.0);
+        let span = self.span.map_or_else(|| "whole game".to_string(), |span| format!("last {} ticks", span));
+        self.span_button = button(format!("Ticks: {}", span), PANEL_X + 240.0, second, 220.0);
+        self.close_button = button("Close".to_string(), PANEL_X + PANEL_WIDTH - 110.0, second, 100.0);
+    }
+}
+
+impl View for TimelinePanel {
+    fn render(&mut self, context: &RenderContext) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        // Dim the game behind the dialog
+        draw_rectangle(0.0, 0.0, context.screen_width, context.screen_height, Color::new(0.0, 0.0, 0.0, 0.5));
+        self.main_panel.render(&(), context)?;
+        for (_, button) in &mut self.category_buttons {
+            button.render(&(), context)?;
+        }
+        self.faction_button.render(&(), context)?;
+        self.span_button.render(&(), context)?;
+        self.close_button.render(&(), context)?;
+        self.table.render(context)?;
+        if self.history.is_empty() {
+            let rect = self.table.get_layout().get_rect();
+            draw_text("Nothing has happened yet", rect.x + 20.0, rect.y + 60.0, context.font_size, context.theme.text_color);
+        }
+        Ok(None)
+    }
+
+    fn handle_input(&mut self, input: &InputEvent) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        if let InputEvent::MouseClick { x, y, button: MouseButton::Left } = input {
+            let point = Vec2::new(*x, *y);
+            let hit = |button: &Button| button.get_layout().get_rect().contains(point);
+            if let Some(category) = self.category_buttons.iter().find(|(_, button)| hit(button)).map(|(category, _)| *category) {
+                self.show_category(category);
+                return Ok(None);
+            }
+            if hit(&self.faction_button) {
+                self.cycle_faction();
+                return Ok(None);
+            }
+            if hit(&self.span_button) {
+                self.cycle_span();
+                return Ok(None);
+            }
+            if hit(&self.close_button) {
+                self.close();
+                return Ok(None);
+            }
+        }
+        // Scrolling, sorting and row selection
+        self.table.handle_input(input)
+    }
+
+    fn update(&mut self, delta_time: f32) -> ComponentResult {
+        if self.visible {
+            self.main_panel.update(delta_time)?;
+            self.table.update(delta_time)?;
+        }
+        Ok(None)
+    }
+
+    fn update_data(&mut self, _data: ViewData) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    fn refresh(&mut self) -> ComponentResult {
+        self.apply_filter();
+        Ok(None)
+    }
+
+    fn get_view_type(&self) -> &'static str {
+        TIMELINE_VIEW_TYPE
+    }
+}
@@ -0,0 +1,268 @@
+// src/ui_v2/panels/transfer_dialog.rs
+//! Resource transfer dialog for sending stock from one planet to another
+//!
+//! A Dropdown picks the destination among the other planets the source
+//! planet's faction controls, each labelled with the ticks a departure now
+//! would take from `PhysicsEngine::transfer_at`. One amount field per
+//! resource takes the quantity to send; amounts are clamped to what the
+//! source planet has in stock as they are typed. Confirming emits a
+//! TransferResources command naming both planets and the full bundle.
+//! The planet panel's Resources tab opens it with ShowTransferDialog.
+
+use crate::ui_v2::{View, Panel, Button, RenderContext, ComponentResult, InputEvent, ViewData, Layout};
+use crate::ui_v2::components::{Dropdown, TextInput};
+use crate::ui_v2::components::base_component::UIComponent;
+use crate::core::{types::*, events::PlayerCommand, GameResult};
+use crate::GameState;
+use macroquad::prelude::*;
+
+/// View type reported by the resource transfer dialog
+pub const TRANSFER_DIALOG_VIEW_TYPE: &str = "ResourceTransfer";
+
+const PANEL_X: f32 = 312.0;
+const PANEL_Y: f32 = 120.0;
+const PANEL_WIDTH: f32 = 400.0;
+const PANEL_HEIGHT: f32 = 420.0;
+const ROW_HEIGHT: f32 = 34.0;
+const ROWS_Y: f32 = PANEL_Y + 95.0;
+
+/// A planet resources can be sent to
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TransferDestination {
+    /// The receiving planet
+    pub planet: PlanetId,
+    /// Ticks a shipment leaving now takes to arrive
+    pub travel_ticks: u64,
+}
+
+/// Modal dialog choosing a destination and the amounts to transfer
+pub struct ResourceTransferDialog {
+    main_panel: Panel,
+    destination_dropdown: Dropdown<PlanetId>,
+    amount_inputs: Vec<(ResourceType, TextInput)>,
+    confirm_button: Button,
+    cancel_button: Button,
+    source: PlanetId,
+    stock: ResourceBundle,
+    destinations: Vec<TransferDestination>,
+    visible: bool,
+}
+
+impl ResourceTransferDialog {
+    /// Dialog sending from a planet's stock to one of the given destinations
+    pub fn new(source: &Planet, destinations: Vec<TransferDestination>) -> Self {
+        let destination_dropdown = Dropdown::new()
+            .with_items(destinations.iter()
+                .map(|destination| (destination.planet, format!("Planet {} ({} ticks)", destination.planet, destination.travel_ticks)))
+                .collect())
+            .with_layout(Layout::new(PANEL_X + 110.0, PANEL_Y + 45.0, PANEL_WIDTH - 130.0, 28.0));
+        let amount_inputs = ResourceType::ALL.iter()
+            .enumerate()
+            .map(|(i, resource)| {
+                let layout = Layout::new(PANEL_X + 110.0, ROWS_Y + i as f32 * ROW_HEIGHT, 120.0, 26.0);
+                (*resource, TextInput::new().with_placeholder("0".to_string()).with_layout(layout))
+            })
+            .collect();
+        let buttons_y = PANEL_Y + PANEL_HEIGHT - 50.0;
+
+        Self {
+            main_panel: Panel::new(format!("Transfer from Planet {}", source.id))
+                .with_layout(Layout::new(PANEL_X, PANEL_Y, PANEL_WIDTH, PANEL_HEIGHT))
+                .collapsible(false),
+            destination_dropdown,
+            amount_inputs,
+            confirm_button: Button::new("Transfer".to_string())
+                .with_layout(Layout::new(PANEL_X + 20.0, buttons_y, 120.0, 30.0)),
+            cancel_button: Button::new("Cancel".to_string())
+                .with_layout(Layout::new(PANEL_X + 160.0, buttons_y, 120.0, 30.0)),
+            source: source.id,
+            stock: source.resources.current,
+            destinations,
+            visible: true,
+        }
+    }
+
+    /// Transfer dialog for a planet, offering its faction's other planets
+    pub fn for_game(game_state: &GameState, source: PlanetId) -> GameResult<Self> {
+        let planet = game_state.planet_manager.get_planet(source)?;
+        let tick = game_state.get_current_tick();
+        let destinations = game_state.planet_manager.get_all_planets().iter()
+            .filter(|other| other.id != planet.id && other.controller.is_some() && other.controller == planet.controller)
+            .map(|other| {
+                let transfer = game_state.physics_engine.transfer_at(&planet.position, &other.position, tick);
+                TransferDestination {
+                    planet: other.id,
+                    travel_ticks: transfer.arrival_tick - transfer.departure_tick,
+                }
+            })
+            .collect();
+        Ok(Self::new(planet, destinations))
+    }
+
+    /// Planets offered as destinations
+    pub fn destinations(&self) -> &[TransferDestination] {
+        &self.destinations
+    }
+
+    /// Pick the receiving planet; planets not offered are ignored
+    pub fn select_destination(&mut self, planet: PlanetId) {
+        if let Some(index) = self.destinations.iter().position(|destination| destination.planet == planet) {
+            self.destination_dropdown.set_selected_index(Some(index));
+        }
+    }
+
+    /// Receiving planet, once one is picked
+    pub fn destination(&self) -> Option<PlanetId> {
+        self.destination_dropdown.get_selected().copied()
+    }
+
+    /// Ticks the shipment would take to reach the picked planet
+    pub fn travel_ticks(&self) -> Option<u64> {
+        let planet = self.destination()?;
+        self.destinations.iter()
+            .find(|destination| destination.planet == planet)
+            .map(|destination| destination.travel_ticks)
+    }
+
+    /// Set the amount of a resource to send, clamped to the source planet's stock
+    pub fn set_amount(&mut self, resource: ResourceType, amount: i32) {
+        let amount = amount.clamp(0, self.stock.get(resource).max(0));
+        if let Some((_, input)) = self.amount_inputs.iter_mut().find(|(r, _)| *r == resource) {
+            input.set_text(amount.to_string());
+        }
+    }
+
+    /// Everything entered for sending
+    pub fn resources(&self) -> ResourceBundle {
+        let mut resources = ResourceBundle::default();
+        for (resource, input) in &self.amount_inputs {
+            resources.set(*resource, input.get_text().trim().parse().unwrap_or(0));
+        }
+        resources
+    }
+
+    /// Send the entered amounts and close; None until a destination and some amount are chosen
+    pub fn confirm(&mut self) -> Option<PlayerCommand> {
+        let to = self.destination()?;
+        let resources = self.resources();
+        if resources.total() == 0 {
+            return None;
+        }
+        self.visible = false;
+        Some(PlayerCommand::TransferResources { from: self.source, to, resources })
+    }
+
+    /// Close without sending anything
+    pub fn cancel(&mut self) {
+        self.visible = false;
+    }
+
+    /// Replace anything typed that is not a number within the stock by the nearest amount that is
+    fn clamp_amounts(&mut self) {
+        for (resource, input) in &mut self.amount_inputs {
+            let text = input.get_text().trim();
+            if text.is_empty() {
+                continue;
+            }
+            let digits: String = text.chars().filter(char::is_ascii_digit).collect();
+            let amount = digits.parse::<i64>().unwrap_or(0).min(self.stock.get(*resource).max(0) as i64);
+            if amount.to_string() != text {
+                input.set_text(amount.to_string());
+            }
+        }
+    }
+}
+
+impl View for ResourceTransferDialog {
+    fn render(&mut self, context: &RenderContext) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        // Dim the game behind the dialog
+        draw_rectangle(0.0, 0.0, context.screen_width, context.screen_height, Color::new(0.0, 0.0, 0.0, 0.5));
+        self.main_panel.render(&(), context)?;
+        draw_text("Send to:", PANEL_X + 20.0, PANEL_Y + 65.0, context.font_size, context.theme.text_color);
+        if self.destinations.is_empty() {
+            draw_text("No other planets to send to", PANEL_X + 110.0, PANEL_Y + 90.0, context.font_size * 0.8, GRAY);
+        }
+
+        for (i, (resource, input)) in self.amount_inputs.iter_mut().enumerate() {
+            let y = ROWS_Y + i as f32 * ROW_HEIGHT;
+            draw_text(&format!("{:?}", resource), PANEL_X + 20.0, y + 19.0, context.font_size * 0.9, context.theme.text_color);
+            input.render(&(), context)?;
+            let available = format!("of {}", self.stock.get(*resource));
+            draw_text(&available, PANEL_X + 240.0, y + 19.0, context.font_size * 0.9, GRAY);
+        }
+
+        let estimate = match self.travel_ticks() {
+            Some(ticks) => format!("Arrives in {} ticks", ticks),
+            None => "Pick a destination".to_string(),
+        };
+        draw_text(&estimate, PANEL_X + 20.0, ROWS_Y + 6.0 * ROW_HEIGHT + 20.0, context.font_size, context.theme.text_color);
+
+        self.confirm_button.render(&(), context)?;
+        self.cancel_button.render(&(), context)?;
+        // Drawn last so the open list covers the fields below it
+        self.destination_dropdown.render(&(), context)?;
+        Ok(None)
+    }
+
+    fn handle_input(&mut self, input: &InputEvent) -> ComponentResult {
+        if !self.visible {
+            return Ok(None);
+        }
+
+        // An open destination list takes the click before the fields under it
+        let list_open = self.destination_dropdown.get_bounds().h > self.destination_dropdown.get_layout().get_rect().h;
+        self.destination_dropdown.handle_input(input)?;
+        if list_open {
+            return Ok(None);
+        }
+
+        for (_, amount_input) in &mut self.amount_inputs {
+            amount_input.handle_input(input)?;
+        }
+        if let InputEvent::KeyPress { .. } = input {
+            self.clamp_amounts();
+        }
+
+        if let InputEvent::MouseClick { x, y, button: MouseButton::Left } = input {
+            let point = Vec2::new(*x, *y);
+            if self.confirm_button.get_layout().get_rect().contains(point) {
+                return Ok(self.confirm());
+            }
+            if self.cancel_button.get_layout().get_rect().contains(point) {
+                self.cancel();
+            }
+        }
+        Ok(None)
+    }
+
+    fn update(&mut self, delta_time: f32) -> ComponentResult {
+        if self.visible {
+            self.main_panel.update(delta_time)?;
+        }
+        Ok(None)
+    }
+
+    fn update_data(&mut self, _data: ViewData) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+    }
+
+    fn refresh(&mut self) -> ComponentResult {
+        Ok(None)
+    }
+
+    fn get_view_type(&self) -> &'static str {
+        TRANSFER_DIALOG_VIEW_TYPE
+    }
+}
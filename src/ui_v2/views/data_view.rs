@@ -85,6 +85,25 @@ impl DataView {
         self.selected_row.and_then(|idx| self.rows.get(idx))
     }
 
+    /// Forget the selected row
+    pub fn clear_selection(&mut self) {
+        self.selected_row = None;
+    }
+
+    /// Rows fully on screen at the current scroll position, in display
+    /// order, with the screen area each one covers
+    pub fn visible_rows(&self) -> Vec<(Rect, &Vec<String>)> {
+        let content_area = self.base.get_content_area();
+        let data_start_y = content_area.y + self.header_height;
+        let data_end_y = content_area.y + content_area.h;
+        self.rows.iter()
+            .enumerate()
+            .map(|(row_idx, row)| (data_start_y + row_idx as f32 * self.row_height - self.scroll_offset, row))
+            .filter(|(row_y, _)| *row_y >= data_start_y && *row_y + self.row_height <= data_end_y)
+            .map(|(row_y, row)| (Rect::new(content_area.x, row_y, content_area.w, self.row_height), row))
+            .collect()
+    }
+
     pub fn set_sortable(&mut self, sortable: bool) {
         self.sortable = sortable;
     }
@@ -97,7 +116,10 @@ impl DataView {
                     let a_val = a.get(col_idx).unwrap_or(&empty_string);
                     let b_val = b.get(col_idx).unwrap_or(&empty_string);
                     
-                    let cmp = a_val.cmp(b_val);
+                    let cmp = match (numeric_value(a_val), numeric_value(b_val)) {
+                        (Some(a_num), Some(b_num)) => a_num.total_cmp(&b_num),
+                        _ => a_val.cmp(b_val),
+                    };
                     if self.sort_ascending { cmp } else { cmp.reverse() }
                 });
             }
@@ -279,6 +301,20 @@ impl DataView {
     }
 }
 
+/// Value of a numeric cell such as "-12", "+40" or "1.5K", so numbers sort
+/// by size rather than as text
+fn numeric_value(cell: &str) -> Option<f64> {
+    let cell = cell.trim();
+    let (number, scale) = match cell.strip_suffix('K') {
+        Some(number) => (number, 1_000.0),
+        None => match cell.strip_suffix('M') {
+            Some(number) => (number, 1_000_000.0),
+            None => (cell, 1.0),
+        },
+    };
+    number.parse::<f64>().ok().map(|value| value * scale)
+}
+
 impl View for DataView {
     fn render(&mut self, context: &RenderContext) -> ComponentResult {
         self.base.render_base(context)?;
@@ -11,7 +11,8 @@ pub use entity_view::*;
 pub use data_view::*;
 pub use dialog_view::*;
 
-use crate::ui_v2::core::{RenderContext, ComponentResult, InputEvent, ViewData};
+use crate::ui_v2::core::{RenderContext, ComponentResult, InputEvent, ViewData, Tooltip};
+use macroquad::prelude::Rect;
 use crate::core::events::PlayerCommand;
 
 /// Core trait that all views must implement
@@ -39,4 +40,9 @@ pub trait View {
     
     /// Get view's unique type identifier
     fn get_view_type(&self) -> &'static str;
+
+    /// Screen regions with the tooltip to show while hovering each; none by default
+    fn tooltips(&self) -> Vec<(Rect, Tooltip)> {
+        Vec::new()
+    }
 }
\ No newline at end of file
@@ -0,0 +1,78 @@
+// tests/camera_test.rs
+use macroquad::prelude::Vec2;
+use stellar_dominion::core::types::Vector2;
+use stellar_dominion::ui_v2::core::camera::{DEFAULT_ZOOM, MAX_ZOOM, MIN_GALAXY_EXTENT};
+use stellar_dominion::ui_v2::{Camera, CameraTarget};
+
+fn camera() -> Camera {
+    let mut camera = Camera::new(Vec2::new(800.0, 600.0));
+    camera.set_galaxy_extent(50.0);
+    camera
+}
+
+#[test]
+fn test_zoom_keeps_point_under_cursor() {
+    let mut camera = camera();
+    let cursor = Vec2::new(700.0, 100.0);
+    let anchor = camera.screen_to_world(cursor);
+
+    camera.zoom_at(cursor, 3.0);
+    camera.snap();
+
+    assert!(camera.get_zoom() > DEFAULT_ZOOM);
+    let after = camera.world_to_screen(anchor);
+    assert!(after.distance(cursor) < 0.01, "Zoom moved the cursor's point to {:?}", after);
+}
+
+#[test]
+fn test_pan_eases_toward_target() {
+    let mut camera = camera();
+    camera.pan_by(Vec2::new(250.0, 0.0));
+    let target = camera.get_target_center();
+    assert_eq!(target.x, 10.0);
+
+    camera.update(0.016, None);
+    let first = camera.get_center().x;
+    assert!(first > 0.0 && first < target.x, "One frame covers part of the move");
+
+    for _ in 0..120 {
+        camera.update(0.016, None);
+    }
+    assert_eq!(camera.get_center(), target);
+}
+
+#[test]
+fn test_follow_tracks_target_and_stops_when_gone() {
+    let mut camera = camera();
+    camera.follow(Some(CameraTarget::Ship(7)));
+
+    camera.update(0.016, Some(Vector2::new(3.0, -4.0)));
+    assert_eq!(camera.get_target_center(), Vector2::new(3.0, -4.0));
+    camera.update(0.016, Some(Vector2::new(4.0, -4.0)));
+    assert_eq!(camera.get_target_center(), Vector2::new(4.0, -4.0));
+
+    camera.update(0.016, None);
+    assert_eq!(camera.get_follow_target(), None);
+
+    camera.follow(Some(CameraTarget::Planet(1)));
+    camera.pan_by(Vec2::new(10.0, 0.0));
+    assert_eq!(camera.get_follow_target(), None, "Panning stops following");
+}
+
+#[test]
+fn test_bounds_follow_galaxy_extent() {
+    let mut camera = camera();
+    camera.pan_by(Vec2::new(1_000_000.0, 0.0));
+    assert_eq!(camera.get_target_center().x, 50.0);
+    camera.snap();
+
+    camera.zoom_at(Vec2::new(400.0, 300.0), -100.0);
+    assert_eq!(camera.get_target_zoom(), 600.0 / 100.0, "Zoomed out to the whole galaxy on screen");
+    camera.zoom_at(Vec2::new(400.0, 300.0), 100.0);
+    assert_eq!(camera.get_target_zoom(), MAX_ZOOM);
+
+    camera.set_galaxy_extent(20.0);
+    assert_eq!(camera.get_target_center().x, 20.0, "A smaller galaxy pulls the view back in");
+    camera.set_galaxy_extent(0.0);
+    assert_eq!(camera.get_galaxy_extent(), MIN_GALAXY_EXTENT);
+}
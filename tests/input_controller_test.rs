@@ -0,0 +1,72 @@
+// tests/input_controller_test.rs
+use macroquad::prelude::{KeyCode, MouseButton};
+use stellar_dominion::ui_v2::{InputConfig, InputController, InputEvent};
+
+fn moves(count: usize) -> Vec<InputEvent> {
+    (0..count)
+        .map(|i| InputEvent::MouseMove { x: i as f32, y: 0.0 })
+        .collect()
+}
+
+#[test]
+fn test_consecutive_motion_is_coalesced() {
+    let mut controller = InputController::new();
+    let mut raw = moves(500);
+    raw.push(InputEvent::Scroll { x: 1.0, y: 1.0, delta: 1.0 });
+    raw.push(InputEvent::Scroll { x: 2.0, y: 2.0, delta: -3.0 });
+    raw.push(InputEvent::KeyPress { key: KeyCode::A });
+    raw.extend(moves(2));
+
+    let events = controller.filter_events(raw);
+
+    assert_eq!(events.len(), 4);
+    assert!(matches!(events[0], InputEvent::MouseMove { x, .. } if x == 499.0));
+    assert!(matches!(events[1], InputEvent::Scroll { x, delta, .. } if x == 2.0 && delta == -2.0));
+    assert!(matches!(events[2], InputEvent::KeyPress { key: KeyCode::A }));
+    assert!(matches!(events[3], InputEvent::MouseMove { x, .. } if x == 1.0));
+
+    let metrics = controller.get_metrics();
+    assert_eq!(metrics.events_received, 505);
+    assert_eq!(metrics.events_coalesced, 501);
+    assert_eq!(metrics.events_dropped, 0);
+}
+
+#[test]
+fn test_click_spam_is_debounced_with_matching_releases() {
+    let mut controller = InputController::with_config(InputConfig {
+        click_debounce: 0.1,
+        ..Default::default()
+    });
+    let click = InputEvent::MouseClick { x: 5.0, y: 5.0, button: MouseButton::Left };
+    let release = InputEvent::MouseRelease { x: 5.0, y: 5.0, button: MouseButton::Left };
+
+    let events = controller.filter_events(vec![click.clone(), release.clone(), click.clone(), release.clone()]);
+    assert_eq!(events.len(), 2, "Second click and its release fall inside the debounce window");
+    assert_eq!(controller.get_metrics().events_debounced, 2);
+
+    controller.advance_debounce(0.2);
+    let events = controller.filter_events(vec![click, release]);
+    assert_eq!(events.len(), 2, "Clicks after the window are delivered");
+}
+
+#[test]
+fn test_per_frame_cap_drops_excess_but_keeps_releases() {
+    let mut controller = InputController::with_config(InputConfig {
+        max_events_per_frame: 3,
+        coalesce_motion: false,
+        click_debounce: 0.0,
+    });
+    let mut raw = moves(10);
+    raw.push(InputEvent::KeyRelease { key: KeyCode::Space });
+
+    let events = controller.filter_events(raw);
+
+    assert_eq!(events.len(), 4);
+    assert!(matches!(events[3], InputEvent::KeyRelease { key: KeyCode::Space }));
+    let metrics = controller.get_metrics();
+    assert_eq!(metrics.events_dropped, 7);
+    assert_eq!(metrics.frames_over_budget, 1);
+
+    controller.reset_metrics();
+    assert_eq!(controller.get_metrics().events_dropped, 0);
+}
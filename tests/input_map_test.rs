@@ -0,0 +1,59 @@
+// tests/input_map_test.rs
+use macroquad::prelude::{KeyCode, MouseButton};
+use stellar_dominion::core::events::PlayerCommand;
+use stellar_dominion::settings::Settings;
+use stellar_dominion::ui_v2::{InputAction, InputEvent, InputMap, KeyBindingsPanel, View};
+
+#[test]
+fn test_binding_a_taken_key_swaps_actions() {
+    let mut map = InputMap::new();
+    assert_eq!(map.key(InputAction::OpenMarket), KeyCode::M);
+    assert_eq!(map.action_for(KeyCode::T), Some(InputAction::OpenTimeline));
+
+    map.bind(InputAction::OpenMarket, KeyCode::T).unwrap();
+    assert_eq!(map.key(InputAction::OpenMarket), KeyCode::T);
+    assert_eq!(map.key(InputAction::OpenTimeline), KeyCode::M, "The displaced action takes the old key");
+
+    map.bind(InputAction::ZoomIn, KeyCode::Q).unwrap();
+    assert_eq!(map.action_for(KeyCode::PageUp), None);
+    assert!(map.bind(InputAction::ZoomIn, KeyCode::LeftSuper).is_err());
+
+    map.reset();
+    assert_eq!(map, InputMap::new());
+}
+
+#[test]
+fn test_bindings_round_trip_through_the_settings_file() {
+    let mut settings = Settings::default();
+    settings.key_bindings.bind(InputAction::FastForward, KeyCode::Space).unwrap();
+    settings.key_bindings.bind(InputAction::Menu, KeyCode::F10).unwrap();
+
+    let loaded = Settings::from_toml(&settings.to_toml().unwrap()).unwrap();
+    assert_eq!(loaded.key_bindings, settings.key_bindings);
+
+    let partial = Settings::from_toml("# Only one change\n[key_bindings]\nSelectNextShip = \"1\"\n").unwrap();
+    assert_eq!(partial.key_bindings.key(InputAction::SelectNextShip), KeyCode::Key1);
+    assert_eq!(partial.key_bindings.key(InputAction::OpenMarket), KeyCode::M);
+
+    assert!(Settings::from_toml("[key_bindings]\nWarp = \"W\"\n").is_err());
+    assert!(Settings::from_toml("[key_bindings]\nOpenMarket = \"Hyper\"\n").is_err());
+    assert!(Settings::from_toml("[key_bindings]\nOpenMarket = 5\n").is_err());
+}
+
+#[test]
+fn test_key_bindings_panel_reports_rebinds() {
+    let mut panel = KeyBindingsPanel::new(InputMap::new());
+    assert!(panel.handle_input(&InputEvent::KeyPress { key: KeyCode::X }).unwrap().is_none(), "Keys do nothing until an action is picked");
+
+    // First action button, top left
+    panel.handle_input(&InputEvent::MouseClick { x: 200.0, y: 170.0, button: MouseButton::Left }).unwrap();
+    assert_eq!(panel.awaiting(), Some(InputAction::Menu));
+
+    assert!(panel.handle_input(&InputEvent::KeyPress { key: KeyCode::LeftSuper }).unwrap().is_none());
+    assert_eq!(panel.awaiting(), Some(InputAction::Menu), "Unbindable keys are ignored");
+
+    let command = panel.handle_input(&InputEvent::KeyPress { key: KeyCode::Backspace }).unwrap();
+    assert!(matches!(command, Some(PlayerCommand::BindKey { ref action, ref key }) if action == "Menu" && key == "Backspace"));
+    assert_eq!(panel.awaiting(), None);
+    assert_eq!(panel.input_map().key(InputAction::Menu), KeyCode::Backspace);
+}
@@ -1,5 +1,14 @@
 // tests/integration_tests.rs
 use stellar_dominion::core::*;
+use stellar_dominion::systems::NotificationKind;
+
+/// Reason given for the most recent rejected command still in the event history
+fn last_rejection(game_state: &GameState) -> Option<String> {
+    game_state.event_bus.event_history.iter().rev().find_map(|event| match event {
+        GameEvent::StateChanged(StateChange::CommandRejected { reason, .. }) => Some(reason.clone()),
+        _ => None,
+    })
+}
 
 #[test]
 fn test_full_game_loop() {
@@ -372,15 +381,6 @@ fn test_construction_chain_workflow() {
     let planet_id = game_state.planet_manager.create_planet(OrbitalElements::default(), Some(0)).unwrap();
     
     // Add sufficient resources for construction with proper storage capacity
-    let storage_upgrade = ResourceBundle {
-        minerals: 5000,
-        food: 2000,
-        energy: 2000,
-        alloys: 1000,
-        components: 500,
-        fuel: 500,
-    };
-    
     let construction_resources = ResourceBundle {
         minerals: 1000,
         food: 500,
@@ -390,7 +390,9 @@ fn test_construction_chain_workflow() {
         fuel: 100,
     };
     
-    game_state.planet_manager.upgrade_storage(planet_id, storage_upgrade).unwrap();
+    for _ in 0..2 {
+        game_state.planet_manager.add_building(planet_id, BuildingType::StorageFacility).unwrap();
+    }
     game_state.planet_manager.add_resources(planet_id, construction_resources).unwrap();
     game_state.planet_manager.update_population(planet_id, 10000).unwrap();
     
@@ -527,17 +529,9 @@ fn test_state_consistency_validation() {
         fuel: 1500,
     };
     
-    // Set storage capacity before adding resources to avoid overflow
-    let storage_upgrade = ResourceBundle {
-        minerals: 10000,
-        food: 8000,
-        energy: 6000,
-        alloys: 3000,
-        components: 1500,
-        fuel: 2000,
-    };
-    
-    game_state.planet_manager.upgrade_storage(planet_id, storage_upgrade).unwrap();
+    for _ in 0..2 {
+        game_state.planet_manager.add_building(planet_id, BuildingType::StorageFacility).unwrap();
+    }
     game_state.planet_manager.add_resources(planet_id, initial_resources).unwrap();
     
     // Perform multiple operations that should maintain consistency
@@ -581,6 +575,7 @@ fn test_state_consistency_validation() {
 #[test]
 fn test_ship_colonization_workflow() {
     let mut game_state = GameState::new().unwrap();
+    game_state.current_mode = GameMode::InGame;
     
     // Setup: Create faction, colony ship, and target planet
     let faction_id = game_state.faction_manager.create_faction(
@@ -594,75 +589,72 @@ fn test_ship_colonization_workflow() {
         None, // Uncontrolled planet
     ).unwrap();
     
-    // Load colony ship with population and resources
-    let colonist_cargo = ResourceBundle {
-        minerals: 100,
-        food: 200,
-        energy: 50,
-        alloys: 25,
-        components: 10,
-        fuel: 50,
-    };
-    
-    // Colony ships should be able to carry cargo - this test validates ship type constraints
-    // For testing purposes, we'll use a Transport ship which can carry cargo
+    // Colony ships leave the shipyard with colonists and supplies aboard
     let colony_ship_id = game_state.ship_manager.create_ship(
-        ShipClass::Transport, // Use Transport instead of Colony for cargo testing
+        ShipClass::Colony,
         Vector2::new(0.0, 0.0),
         faction_id,
     ).unwrap();
+    let cargo = game_state.ship_manager.get_ship(colony_ship_id).unwrap().cargo.clone();
+    assert!(cargo.population > 0 && cargo.resources.total() > 0);
+    
+    let colonize = GameEvent::PlayerCommand(PlayerCommand::ColonizePlanet {
+        ship: colony_ship_id,
+        planet: target_planet_id,
+    });
+    // Colonists only land from orbit
+    game_state.queue_event(colonize.clone());
+    game_state.process_queued_events_for_test().unwrap();
+    assert!(last_rejection(&game_state).unwrap().contains("must orbit"));
     
-    // Load transport ship with colonist resources
-    game_state.ship_manager.load_cargo(colony_ship_id, colonist_cargo).unwrap();
-    
-    // Update ship cargo with population manually for colony ship
-    // TODO: Colony ship population loading would need a proper manager method
-    // ship.cargo.population = 1000;
-    
-    // Move ship to target planet (simulate movement completion)
+    // Fly to the planet and drop into orbit
     let target_planet = game_state.planet_manager.get_planet(target_planet_id).unwrap();
-    let planet_position = Vector2::new(
-        target_planet.position.semi_major_axis * target_planet.position.phase.cos(),
-        target_planet.position.semi_major_axis * target_planet.position.phase.sin(),
-    );
-    
+    let tick = game_state.get_current_tick();
+    let planet_position = game_state.physics_engine.calculate_orbital_position(&target_planet.position, tick);
     game_state.ship_manager.update_position(colony_ship_id, planet_position).unwrap();
+    for _ in 0..3 {
+        game_state.fixed_update(0.1).unwrap();
+    }
+    assert_eq!(game_state.ship_manager.get_ship(colony_ship_id).unwrap().status, ShipStatus::Orbiting(target_planet_id));
     
-    // Issue colonization command
-    game_state.event_bus.queue_event(GameEvent::PlayerCommand(
-        PlayerCommand::ColonizePlanet {
-            ship: colony_ship_id,
-            planet: target_planet_id,
-        }
-    ));
-    
-    // Process colonization through multiple systems
-    game_state.physics_engine.update(0.1, &mut game_state.event_bus).unwrap();
-    game_state.population_system.update(0.1, &mut game_state.event_bus).unwrap();
-    
-    // Process colonization events through proper event system
+    game_state.queue_event(colonize.clone());
     game_state.process_queued_events_for_test().unwrap();
     
-    // Simulate colonization process manually since the full colonization system may not be implemented
-    // Transfer ship cargo to planet
-    let ship = game_state.ship_manager.get_ship(colony_ship_id).unwrap();
-    game_state.planet_manager.add_resources(target_planet_id, ship.cargo.resources).unwrap();
-    game_state.planet_manager.update_population(target_planet_id, 1000).unwrap();
-    
-    // Set planet controller
-    game_state.planet_manager.change_controller(target_planet_id, Some(faction_id)).unwrap();
-    
-    // Verify colonization success
+    // The ship is used up founding the colony
+    assert!(game_state.ship_manager.get_ship(colony_ship_id).is_err(), "Colony ship should be consumed");
     let colonized_planet = game_state.planet_manager.get_planet(target_planet_id).unwrap();
     
     assert_eq!(colonized_planet.controller, Some(faction_id), 
         "Planet should be controlled by colonizing faction");
     
-    assert!(colonized_planet.population.total > 0, 
+    assert_eq!(colonized_planet.population.total, cargo.population,
         "Planet should have population after colonization");
     
-    assert!(colonized_planet.resources.current.total() > 0, 
+    assert_eq!(colonized_planet.resources.current, cargo.resources,
         "Planet should have resources from colony ship");
+    
+    // Factions and notifications hear about the new colony
+    game_state.process_queued_events_for_test().unwrap();
+    assert!(game_state.event_bus.event_history.iter().any(|event| matches!(
+        event,
+        GameEvent::SimulationEvent(SimulationEvent::PlanetColonized { planet, faction, .. })
+            if *planet == target_planet_id && *faction == faction_id
+    )));
+    assert!(game_state.faction_manager.get_faction(faction_id).unwrap().has_explored(target_planet_id));
+    assert!(game_state.notification_system.notifications(faction_id).iter()
+        .any(|notification| notification.kind == NotificationKind::PlanetColonized { planet: target_planet_id }));
+    
+    // A planet can only be colonized once
+    let orbit = game_state.planet_manager.get_planet(target_planet_id).unwrap().position;
+    let planet_position = game_state.physics_engine.calculate_orbital_position(&orbit, game_state.get_current_tick());
+    let second_ship = game_state.ship_manager.create_ship(ShipClass::Colony, planet_position, faction_id).unwrap();
+    for _ in 0..3 {
+        game_state.fixed_update(0.1).unwrap();
+    }
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::ColonizePlanet { ship: second_ship, planet: target_planet_id }));
+    game_state.process_queued_events_for_test().unwrap();
+    assert!(last_rejection(&game_state).unwrap().contains("already held"));
+    assert!(game_state.ship_manager.get_ship(second_ship).is_ok());
 }
 
 #[test]
@@ -848,22 +840,19 @@ fn test_complex_resource_transfer_workflow() {
             semi_major_axis: 10.0,
             period: 500.0,
             phase: 1.57, // 90 degrees offset
+            eccentricity: 0.0,
+            argument_of_periapsis: 0.0,
         },
         Some(faction_id),
     ).unwrap();
     
     // Setup source planet with resources and storage
-    let storage_upgrade = ResourceBundle {
-        minerals: 10000,
-        food: 8000,
-        energy: 6000,
-        alloys: 3000,
-        components: 1500,
-        fuel: 2000,
-    };
-    
-    game_state.planet_manager.upgrade_storage(source_planet, storage_upgrade).unwrap();
-    game_state.planet_manager.upgrade_storage(destination_planet, storage_upgrade).unwrap();
+    for _ in 0..2 {
+        game_state.planet_manager.add_building(source_planet, BuildingType::StorageFacility).unwrap();
+    }
+    for _ in 0..2 {
+        game_state.planet_manager.add_building(destination_planet, BuildingType::StorageFacility).unwrap();
+    }
     
     let initial_resources = ResourceBundle {
         minerals: 5000,
@@ -931,15 +920,6 @@ fn test_ship_cargo_operation_workflows() {
     ).unwrap();
     
     // Setup planet with resources and storage
-    let storage_capacity = ResourceBundle {
-        minerals: 10000,
-        food: 8000,
-        energy: 6000,
-        alloys: 3000,
-        components: 1500,
-        fuel: 2000,
-    };
-    
     let planet_resources = ResourceBundle {
         minerals: 3000,
         food: 2000,
@@ -949,15 +929,26 @@ fn test_ship_cargo_operation_workflows() {
         fuel: 600,
     };
     
-    game_state.planet_manager.upgrade_storage(cargo_planet, storage_capacity).unwrap();
+    for _ in 0..2 {
+        game_state.planet_manager.add_building(cargo_planet, BuildingType::StorageFacility).unwrap();
+    }
     game_state.planet_manager.add_resources(cargo_planet, planet_resources).unwrap();
     
-    // Create transport ship at planet location
+    game_state.planet_manager.update_population(cargo_planet, 500).unwrap();
+    
+    // Create transport ship at planet location and let it settle into orbit
+    game_state.current_mode = GameMode::InGame;
+    let orbit = game_state.planet_manager.get_planet(cargo_planet).unwrap().position;
     let cargo_ship = game_state.ship_manager.create_ship(
         ShipClass::Transport,
-        Vector2::new(0.0, 0.0), // Same position as planet
+        game_state.physics_engine.calculate_orbital_position(&orbit, game_state.get_current_tick()),
         faction_id,
     ).unwrap();
+    for _ in 0..3 {
+        game_state.fixed_update(0.1).unwrap();
+    }
+    assert_eq!(game_state.ship_manager.get_ship(cargo_ship).unwrap().status, ShipStatus::Orbiting(cargo_planet));
+    let stock_before = game_state.planet_manager.get_planet(cargo_planet).unwrap().resources.current;
     
     // Test cargo loading workflow (reduce amounts to stay within available resources)
     let cargo_to_load = ResourceBundle {
@@ -968,22 +959,31 @@ fn test_ship_cargo_operation_workflows() {
         components: 25,
         fuel: 50,
     };
+    let load = |resources, population| GameEvent::PlayerCommand(PlayerCommand::LoadShipCargo {
+        ship: cargo_ship,
+        planet: cargo_planet,
+        resources,
+        population,
+    });
+    
+    // Loads beyond the hold or the planet's stock are refused outright
+    game_state.queue_event(load(cargo_to_load, 500));
+    game_state.process_queued_events_for_test().unwrap();
+    assert!(last_rejection(&game_state).unwrap().contains("cargo space: 1275 requested, 1000 available"));
+    game_state.queue_event(load(ResourceBundle { alloys: 5000, ..Default::default() }, 0));
+    game_state.process_queued_events_for_test().unwrap();
+    assert!(last_rejection(&game_state).unwrap().contains("Insufficient resources"));
     
-    game_state.event_bus.queue_event(GameEvent::PlayerCommand(
-        PlayerCommand::LoadShipCargo {
-            ship: cargo_ship,
-            planet: cargo_planet,
-            resources: cargo_to_load,
-        }
-    ));
-    
-    // Process cargo loading
-    game_state.resource_system.update(0.1, &mut game_state.event_bus).unwrap();
+    game_state.queue_event(load(cargo_to_load, 100));
     game_state.process_queued_events_for_test().unwrap();
     
-    // Verify cargo loading (may depend on implementation)
-    let _loaded_ship = game_state.ship_manager.get_ship(cargo_ship).unwrap();
-    let _planet_after_load = game_state.planet_manager.get_planet(cargo_planet).unwrap();
+    // Resources and settlers leave the planet and fill the hold together
+    let loaded_ship = game_state.ship_manager.get_ship(cargo_ship).unwrap();
+    assert_eq!(loaded_ship.cargo.resources, cargo_to_load);
+    assert_eq!(loaded_ship.cargo.population, 100);
+    let planet_after_load = game_state.planet_manager.get_planet(cargo_planet).unwrap();
+    assert_eq!(planet_after_load.resources.current.minerals, stock_before.minerals - 300);
+    assert_eq!(planet_after_load.population.total, 400);
     
     // Test cargo unloading workflow
     game_state.event_bus.queue_event(GameEvent::PlayerCommand(
@@ -992,18 +992,21 @@ fn test_ship_cargo_operation_workflows() {
             planet: cargo_planet,
         }
     ));
-    
-    // Process cargo unloading
-    game_state.resource_system.update(0.1, &mut game_state.event_bus).unwrap();
     game_state.process_queued_events_for_test().unwrap();
     
     // Verify system consistency after cargo operations
     let final_ship = game_state.ship_manager.get_ship(cargo_ship).unwrap();
     let final_planet = game_state.planet_manager.get_planet(cargo_planet).unwrap();
     
+    assert_eq!(final_ship.cargo.current_load(), 0, "Everything aboard goes ashore");
+    assert_eq!(final_planet.resources.current, stock_before);
+    assert_eq!(final_planet.population.total, 500);
     assert_eq!(final_ship.owner, faction_id, "Ship ownership should remain consistent");
     assert_eq!(final_planet.controller, Some(faction_id), "Planet control should remain consistent");
-    assert!(final_planet.resources.current.minerals >= 0, "Planet resources should not go negative");
+    assert!(game_state.event_bus.event_history.iter().any(|event| matches!(
+        event,
+        GameEvent::SimulationEvent(SimulationEvent::CargoUnloaded { population: 100, .. })
+    )));
 }
 
 #[test]
@@ -1110,21 +1113,14 @@ fn test_large_scale_simulation_stress() {
                     semi_major_axis: 5.0 + (i as f32) * 3.0 + (j as f32),
                     period: 365.0 + (i as f32) * 50.0,
                     phase: (i as f32) * 1.0 + (j as f32) * 0.5,
+                    eccentricity: 0.0,
+                    argument_of_periapsis: 0.0,
                 },
                 Some(faction_id),
             ).unwrap();
             planet_ids.push(planet_id);
             
             // Add basic infrastructure to planets
-            let storage_upgrade = ResourceBundle {
-                minerals: 5000,
-                food: 3000,
-                energy: 2000,
-                alloys: 1000,
-                components: 500,
-                fuel: 1000,
-            };
-            
             let initial_resources = ResourceBundle {
                 minerals: 1000,
                 food: 800,
@@ -1134,7 +1130,9 @@ fn test_large_scale_simulation_stress() {
                 fuel: 200,
             };
             
-            game_state.planet_manager.upgrade_storage(planet_id, storage_upgrade).unwrap();
+            for _ in 0..2 {
+                game_state.planet_manager.add_building(planet_id, BuildingType::StorageFacility).unwrap();
+            }
             game_state.planet_manager.add_resources(planet_id, initial_resources).unwrap();
             game_state.planet_manager.update_population(planet_id, 5000).unwrap();
         }
@@ -1189,4 +1187,1188 @@ fn test_large_scale_simulation_stress() {
     assert_eq!(faction_ids.len(), 3, "All factions should still exist");
     assert_eq!(planet_ids.len(), 6, "All planets should still exist");
     assert_eq!(ship_ids.len(), 6, "All ships should still exist");
-}
\ No newline at end of file
+}
+#[test]
+fn test_population_growth_respects_housing_cap() {
+    let mut game_state = GameState::new().unwrap();
+    let planet_id = game_state.planet_manager.create_planet(OrbitalElements::default(), Some(0)).unwrap();
+    let capacity = game_state.planet_manager.get_planet(planet_id).unwrap().housing_capacity();
+    assert_eq!(capacity, DEFAULT_PLANET_SIZE as i32 * HOUSING_PER_PLANET_SIZE);
+    
+    // Habitats raise the cap
+    game_state.planet_manager.add_building(planet_id, BuildingType::Habitat).unwrap();
+    let capacity = game_state.planet_manager.get_planet(planet_id).unwrap().housing_capacity();
+    assert_eq!(capacity, DEFAULT_PLANET_SIZE as i32 * HOUSING_PER_PLANET_SIZE + HOUSING_PER_HABITAT_TIER);
+    
+    // Growth just below the cap is clamped to the remaining housing
+    game_state.planet_manager.update_population(planet_id, capacity - 10).unwrap();
+    game_state.queue_event(GameEvent::SimulationEvent(
+        SimulationEvent::PopulationGrowth { planet: planet_id, amount: 500 }
+    ));
+    game_state.process_queued_events_for_test().unwrap();
+    let planet = game_state.planet_manager.get_planet(planet_id).unwrap();
+    assert_eq!(planet.population.total, capacity);
+    assert_eq!(planet.housing_space(), 0);
+    
+    // A full planet produces no further growth
+    let mut bus = EventBus::new();
+    game_state.population_system
+        .process_planet_growth(planet_id, capacity, capacity * 2, capacity, 1.0, &mut bus)
+        .unwrap();
+    assert!(!bus.queued_events.iter().any(|e| matches!(e,
+        GameEvent::SimulationEvent(SimulationEvent::PopulationGrowth { .. }))));
+}
+
+#[test]
+fn test_shuttle_run_wizard_creates_trade_route() {
+    use stellar_dominion::ui_v2::panels::shuttle_run_wizard::WizardStep;
+    use stellar_dominion::ui_v2::ShuttleRunWizard;
+    
+    let mut game_state = GameState::new().unwrap();
+    let source = game_state.planet_manager.create_planet(OrbitalElements::default(), Some(0)).unwrap();
+    let destination = game_state.planet_manager.create_planet(
+        OrbitalElements { semi_major_axis: 25.0, period: 200.0, phase: 0.0, eccentricity: 0.0, argument_of_periapsis: 0.0 }, Some(0)
+    ).unwrap();
+    let transport = game_state.ship_manager.create_ship(ShipClass::Transport, Vector2::new(0.0, 0.0), 0).unwrap();
+    
+    let mut wizard = ShuttleRunWizard::new(0);
+    wizard.open(&game_state).unwrap();
+    wizard.select_source(source).unwrap();
+    assert!(wizard.select_destination(source).is_err());
+    wizard.select_destination(destination).unwrap();
+    assert_eq!(wizard.step(), WizardStep::SelectResources);
+    
+    let plan = wizard.set_resources(ResourceBundle { food: 400, ..Default::default() }).unwrap().clone();
+    assert_eq!(plan.route.ship, transport);
+    assert!(plan.round_trip_ticks > 0);
+    assert_eq!(plan.throughput_per_100_ticks, 400 * 100 / plan.round_trip_ticks as i64);
+    
+    let command = wizard.confirm().unwrap();
+    game_state.queue_event(GameEvent::PlayerCommand(command));
+    game_state.process_queued_events_for_test().unwrap();
+    assert_eq!(game_state.ship_manager.get_trade_route(transport), Some(&plan.route));
+    assert!(game_state.ship_manager.get_idle_transports(0).is_empty());
+}
+
+#[test]
+fn test_faction_inbox_offers_are_answered_or_expire() {
+    use stellar_dominion::ui_v2::InboxPanel;
+    
+    let mut game_state = GameState::new().unwrap();
+    game_state.current_mode = GameMode::InGame;
+    let player = game_state.faction_manager.create_faction("Player".to_string(), true, AIPersonality::Balanced).unwrap();
+    let ai = game_state.faction_manager.create_faction("Guild".to_string(), false, AIPersonality::Economic).unwrap();
+    let player_capital = game_state.planet_manager.create_planet(OrbitalElements::default(), Some(player)).unwrap();
+    let ai_capital = game_state.planet_manager.create_planet(OrbitalElements::default(), Some(ai)).unwrap();
+    for planet in [player_capital, ai_capital] {
+        game_state.planet_manager.add_resources(planet, ResourceBundle { minerals: 500, food: 500, ..Default::default() }).unwrap();
+    }
+    let stock = |game_state: &GameState, planet| game_state.planet_manager.get_planet(planet).unwrap().resources.current;
+    let (player_before, ai_before) = (stock(&game_state, player_capital), stock(&game_state, ai_capital));
+    
+    let trade = InboxMessage::TradeOffer {
+        from: ai,
+        offered: ResourceBundle { minerals: 200, ..Default::default() },
+        requested: ResourceBundle { food: 150, ..Default::default() },
+    };
+    let peace = InboxMessage::DiplomaticProposal { from: player, proposal: DiplomaticProposal::Peace };
+    game_state.queue_event(GameEvent::SimulationEvent(SimulationEvent::InboxMessageSent { to: player, message: trade.clone() }));
+    game_state.queue_event(GameEvent::SimulationEvent(SimulationEvent::InboxMessageSent { to: ai, message: peace }));
+    game_state.fixed_update(0.1).unwrap();
+    
+    // The player sees the offer behind a badge, with the default expiry applied
+    let mut panel = InboxPanel::new(player);
+    panel.refresh_data(&game_state).unwrap();
+    assert_eq!(panel.badge_text(), "Inbox (1)");
+    let item = panel.items()[0].clone();
+    assert_eq!(item.expires_tick, Some(item.received_tick + TRADE_OFFER_LIFETIME_TICKS));
+    
+    // The AI answers its proposal on its own within a couple of ticks
+    for _ in 0..2 {
+        game_state.fixed_update(0.1).unwrap();
+    }
+    assert!(game_state.faction_manager.get_faction(ai).unwrap().inbox.is_empty());
+    assert!(game_state.event_bus.event_history.iter().any(|event| matches!(
+        event,
+        GameEvent::SimulationEvent(SimulationEvent::InboxItemResolved { faction, response: InboxResponse::Accept, .. }) if *faction == ai
+    )));
+    
+    // Accepting the proposal signs the treaty it offered
+    for _ in 0..2 {
+        game_state.fixed_update(0.1).unwrap();
+    }
+    let treaty = game_state.faction_manager.relation(ai, player).unwrap().treaty;
+    assert_eq!(treaty.map(|treaty| treaty.kind), Some(DiplomaticProposal::Peace));
+    
+    // Accepting the trade swaps the goods between the two capitals
+    let command = panel.respond(item.id, InboxResponse::Accept).unwrap();
+    assert_eq!(panel.badge_count(), 0);
+    game_state.queue_event(GameEvent::PlayerCommand(command));
+    for _ in 0..3 {
+        game_state.fixed_update(0.1).unwrap();
+    }
+    assert!(game_state.faction_manager.get_faction(player).unwrap().inbox.is_empty());
+    let (player_after, ai_after) = (stock(&game_state, player_capital), stock(&game_state, ai_capital));
+    assert_eq!(player_after.minerals - player_before.minerals, 200);
+    assert_eq!(player_before.food - player_after.food, 150);
+    assert_eq!(ai_before.minerals - ai_after.minerals, 200);
+    assert_eq!(ai_after.food - ai_before.food, 150);
+    
+    // Unanswered offers lapse once their expiry tick passes
+    game_state.queue_event(GameEvent::SimulationEvent(SimulationEvent::InboxMessageSent { to: player, message: trade }));
+    game_state.fixed_update(0.1).unwrap();
+    let expires = game_state.faction_manager.get_faction(player).unwrap().inbox.items()[0].expires_tick.unwrap();
+    while game_state.get_current_tick() < expires {
+        game_state.fixed_update(0.1).unwrap();
+    }
+    assert!(game_state.faction_manager.get_faction(player).unwrap().inbox.is_empty());
+    assert!(game_state.event_bus.event_history.iter().any(|event| matches!(
+        event,
+        GameEvent::SimulationEvent(SimulationEvent::InboxItemExpired { faction, .. }) if *faction == player
+    )));
+}
+
+#[test]
+fn test_market_trades_resources_for_credits_and_moves_prices() {
+    use stellar_dominion::ui_v2::{MarketPanel, TRADE_LOT};
+    use stellar_dominion::systems::market::base_price;
+    
+    let mut game_state = GameState::new().unwrap();
+    game_state.current_mode = GameMode::InGame;
+    let player = game_state.faction_manager.create_faction("Player".to_string(), true, AIPersonality::Balanced).unwrap();
+    let home = game_state.planet_manager.create_planet(OrbitalElements::default(), Some(player)).unwrap();
+    let credits = |game_state: &GameState| game_state.faction_manager.get_faction(player).unwrap().credits;
+    assert_eq!(credits(&game_state), STARTING_CREDITS);
+    
+    // The market dialog quotes a lot and buys it for the player's capital
+    let panel = MarketPanel::for_game(&game_state, player).unwrap();
+    let alloys = panel.rows().iter().find(|row| row.resource == ResourceType::Alloys).unwrap().clone();
+    assert_eq!(alloys.lot_cost, game_state.market_system.buy_cost(ResourceType::Alloys, TRADE_LOT).unwrap());
+    let order = panel.order(ResourceType::Alloys, true).unwrap();
+    assert!(matches!(order, PlayerCommand::MarketBuy { faction, planet, resource: ResourceType::Alloys, amount: TRADE_LOT } if faction == player && planet == home));
+    game_state.issue_command(player, order);
+    game_state.process_queued_events_for_test().unwrap();
+    assert_eq!(credits(&game_state), STARTING_CREDITS - alloys.lot_cost);
+    assert_eq!(game_state.planet_manager.get_planet(home).unwrap().resources.current.alloys, TRADE_LOT);
+    
+    // Selling part of it back earns credits
+    let revenue = game_state.market_system.sell_revenue(ResourceType::Alloys, 50).unwrap();
+    game_state.issue_command(player, PlayerCommand::MarketSell { faction: player, planet: home, resource: ResourceType::Alloys, amount: 50 });
+    game_state.process_queued_events_for_test().unwrap();
+    assert_eq!(credits(&game_state), STARTING_CREDITS - alloys.lot_cost + revenue);
+    assert_eq!(game_state.planet_manager.get_planet(home).unwrap().resources.current.alloys, 50);
+    
+    // Orders the faction cannot pay for or has no stock for are rejected
+    let balance = credits(&game_state);
+    game_state.issue_command(player, PlayerCommand::MarketBuy { faction: player, planet: home, resource: ResourceType::Components, amount: 400 });
+    game_state.issue_command(player, PlayerCommand::MarketSell { faction: player, planet: home, resource: ResourceType::Fuel, amount: 10 });
+    game_state.process_queued_events_for_test().unwrap();
+    assert!(last_rejection(&game_state).is_some());
+    assert_eq!(credits(&game_state), balance);
+    assert_eq!(game_state.planet_manager.get_planet(home).unwrap().resources.current.components, 0);
+    
+    // Net buying raises the price at the next price update
+    game_state.queue_event(GameEvent::SimulationEvent(SimulationEvent::TickCompleted(10)));
+    game_state.process_queued_events_for_test().unwrap();
+    assert!(game_state.market_system.price(ResourceType::Alloys) > base_price(ResourceType::Alloys));
+    assert_eq!(game_state.market_system.price_history(ResourceType::Alloys).count(), 1);
+}
+
+#[test]
+fn test_notifications_alert_owners_and_can_be_dismissed() {
+    use stellar_dominion::systems::{NotificationKind, NotificationPriority};
+    use stellar_dominion::ui_v2::{NotificationPanel, View};
+    
+    let mut game_state = GameState::new().unwrap();
+    game_state.current_mode = GameMode::InGame;
+    let player = game_state.faction_manager.create_faction("Player".to_string(), true, AIPersonality::Balanced).unwrap();
+    let enemy = game_state.faction_manager.create_faction("Enemy".to_string(), false, AIPersonality::Aggressive).unwrap();
+    let home = game_state.planet_manager.create_planet(OrbitalElements::default(), Some(player)).unwrap();
+    game_state.planet_manager.add_resources(home, ResourceBundle { food: 100, ..Default::default() }).unwrap();
+    let guard = game_state.ship_manager.create_ship(ShipClass::Warship, Vector2::new(1.0, 0.0), player).unwrap();
+    let raider = game_state.ship_manager.create_ship(ShipClass::Warship, Vector2::new(1.5, 0.0), enemy).unwrap();
+    
+    // A draining stockpile, an empty one and an attack raise alerts for the owners involved
+    for _ in 0..3 {
+        game_state.queue_event(GameEvent::SimulationEvent(SimulationEvent::ResourcesProduced {
+            planet: home, resources: ResourceBundle { food: -10, ..Default::default() },
+        }));
+    }
+    game_state.queue_event(GameEvent::SimulationEvent(SimulationEvent::ResourceShortage { planet: home, resource: ResourceType::Energy }));
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::AttackTarget { attacker: raider, target: guard }));
+    game_state.process_queued_events_for_test().unwrap();
+    
+    let alerts = game_state.notification_system.notifications(player);
+    assert_eq!(alerts.len(), 3, "Repeated low stock warnings are raised once: {:?}", alerts);
+    assert_eq!(alerts[0].kind, NotificationKind::ResourceShortage { planet: home, resource: ResourceType::Energy });
+    assert_eq!(alerts[0].priority, NotificationPriority::Critical);
+    assert!(alerts.iter().any(|n| n.kind == NotificationKind::LowStockpile { planet: home, resource: ResourceType::Food, ticks_left: 10 }));
+    assert!(alerts.iter().any(|n| n.kind == NotificationKind::CombatStarted { ship: guard, enemy: raider }));
+    assert_eq!(game_state.notification_system.notifications(enemy).len(), 1);
+    
+    // The panel shows them as toasts and dismisses through a command
+    let mut panel = NotificationPanel::new(player);
+    panel.update_data(NotificationPanel::view_data(&game_state, player)).unwrap();
+    assert_eq!(panel.toasts().count(), 3);
+    let shortage = panel.notifications()[0].id;
+    let command = panel.dismiss(Some(shortage));
+    assert_eq!(panel.notifications().len(), 2);
+    game_state.issue_command(player, command);
+    game_state.issue_command(player, PlayerCommand::DismissNotification { faction: enemy, notification: None });
+    game_state.process_queued_events_for_test().unwrap();
+    assert_eq!(game_state.notification_system.notifications(player).len(), 2);
+    assert_eq!(game_state.notification_system.notifications(enemy).len(), 1, "Factions only dismiss their own alerts");
+    
+    game_state.issue_command(player, PlayerCommand::DismissNotification { faction: player, notification: None });
+    game_state.process_queued_events_for_test().unwrap();
+    assert!(game_state.notification_system.notifications(player).is_empty());
+}
+
+#[test]
+fn test_timeline_records_notable_events_with_the_factions_involved() {
+    use stellar_dominion::systems::{HistoryCategory, TimelineFilter};
+    use stellar_dominion::ui_v2::TimelinePanel;
+    
+    let mut game_state = GameState::new().unwrap();
+    game_state.current_mode = GameMode::InGame;
+    let player = game_state.faction_manager.create_faction("Player".to_string(), true, AIPersonality::Balanced).unwrap();
+    let enemy = game_state.faction_manager.create_faction("Enemy".to_string(), false, AIPersonality::Aggressive).unwrap();
+    let home = game_state.planet_manager.create_planet(OrbitalElements::default(), Some(player)).unwrap();
+    let guard = game_state.ship_manager.create_ship(ShipClass::Warship, Vector2::new(1.0, 0.0), player).unwrap();
+    let raider = game_state.ship_manager.create_ship(ShipClass::Warship, Vector2::new(1.5, 0.0), enemy).unwrap();
+    
+    // Routine events stay off the timeline; the raider is lost in the battle but its owner is still known
+    game_state.queue_event(GameEvent::SimulationEvent(SimulationEvent::PopulationGrowth { planet: home, amount: 5 }));
+    game_state.queue_event(GameEvent::SimulationEvent(SimulationEvent::ConstructionCompleted { planet: home, building: BuildingType::Mine }));
+    game_state.queue_event(GameEvent::SimulationEvent(SimulationEvent::CombatResolved {
+        attacker: raider,
+        defender: guard,
+        outcome: CombatOutcome {
+            winner: player, attacker_losses: vec![raider], defender_losses: Vec::new(), retreated: Vec::new(),
+            rounds: 3, attacker_damage: 40, defender_damage: 10,
+        },
+    }));
+    game_state.process_queued_events_for_test().unwrap();
+    assert!(game_state.ship_manager.get_ship(raider).is_err());
+    for _ in 0..3 {
+        game_state.fixed_update(0.1).unwrap();
+    }
+    game_state.queue_event(GameEvent::SimulationEvent(SimulationEvent::PlanetConquered { planet: home, new_owner: enemy }));
+    game_state.process_queued_events_for_test().unwrap();
+    
+    let history = &game_state.history_system;
+    // The player's sensors also discovered its own planet on the first ticks
+    assert_eq!(history.len(), 4, "{:?}", history.query(&TimelineFilter::default()));
+    assert_eq!(history.count(HistoryCategory::Exploration), 1);
+    let battles = history.query(&TimelineFilter { categories: vec![HistoryCategory::Combat], ..Default::default() });
+    assert_eq!(battles.len(), 1);
+    assert_eq!(battles[0].factions, vec![enemy, player]);
+    let conquest = history.query(&TimelineFilter { categories: vec![HistoryCategory::Conquest], ..Default::default() });
+    assert_eq!(conquest[0].factions, vec![player, enemy], "The planet's previous owner took part");
+    assert_eq!(conquest[0].tick, 3);
+    assert_eq!(history.query(&TimelineFilter { from_tick: 3, ..Default::default() }).len(), 1);
+    
+    // The timeline dialog lists them newest first and filters by category, faction and recent ticks
+    let mut panel = TimelinePanel::for_game(&game_state);
+    let categories = |panel: &TimelinePanel| panel.entries().iter().map(|entry| entry.category).collect::<Vec<_>>();
+    assert_eq!(categories(&panel), vec![
+        HistoryCategory::Conquest, HistoryCategory::Exploration, HistoryCategory::Combat, HistoryCategory::Construction,
+    ]);
+    panel.show_category(Some(HistoryCategory::Construction));
+    assert_eq!(categories(&panel), vec![HistoryCategory::Construction]);
+    panel.show_category(None);
+    panel.cycle_faction();
+    panel.cycle_faction();
+    assert_eq!(panel.filter().faction, Some(enemy));
+    assert_eq!(categories(&panel), vec![HistoryCategory::Conquest, HistoryCategory::Combat]);
+    panel.cycle_span();
+    assert_eq!(panel.filter().from_tick, 0);
+    assert_eq!(panel.entries().len(), 2);
+}
+
+#[test]
+fn test_empire_overview_lists_the_players_planets_sorted_by_number() {
+    use macroquad::prelude::MouseButton;
+    use stellar_dominion::ui_v2::{EmpireOverviewView, InputEvent, PlanetAdapter, View};
+    
+    let mut game_state = GameState::new().unwrap();
+    game_state.current_mode = GameMode::InGame;
+    let player = game_state.faction_manager.create_faction("Player".to_string(), true, AIPersonality::Balanced).unwrap();
+    let rival = game_state.faction_manager.create_faction("Rival".to_string(), false, AIPersonality::Balanced).unwrap();
+    let mut planets = Vec::new();
+    for population in [12_000, 500, 3_000] {
+        let planet = game_state.planet_manager.create_planet(OrbitalElements::default(), Some(player)).unwrap();
+        let current = game_state.planet_manager.get_planet(planet).unwrap().population.total;
+        game_state.planet_manager.update_population(planet, population - current).unwrap();
+        planets.push(planet);
+    }
+    game_state.planet_manager.create_planet(OrbitalElements::default(), Some(rival)).unwrap();
+    
+    // One row per planet the player controls, with the empire's totals underneath
+    let mut panel = EmpireOverviewView::for_game(&game_state, player).unwrap();
+    assert_eq!(panel.planets(), planets.as_slice());
+    let columns = PlanetAdapter::overview_columns();
+    assert_eq!(panel.totals().len(), columns.len());
+    assert_eq!(panel.totals()[0], "3 planets");
+    assert_eq!(panel.totals()[1], "15.5K");
+    
+    // Sorting by population orders "500" before "3.0K" and "12.0K", so the first row is the smallest planet
+    let column_width = 880.0 / columns.len() as f32;
+    let header = InputEvent::MouseClick { x: 77.0 + column_width * 1.5, y: 140.0, button: MouseButton::Left };
+    assert!(panel.handle_input(&header).unwrap().is_none());
+    let first_row = InputEvent::MouseClick { x: 100.0, y: 167.0, button: MouseButton::Left };
+    let command = panel.handle_input(&first_row).unwrap();
+    assert!(matches!(command, Some(PlayerCommand::ShowPlanet(id)) if id == planets[1]));
+    assert!(!panel.is_visible(), "Picking a planet closes the overview");
+}
+
+#[test]
+fn test_fleet_overview_recalls_ships_to_the_nearest_held_planet() {
+    use stellar_dominion::ui_v2::FleetOverviewView;
+    
+    let mut game_state = GameState::new().unwrap();
+    game_state.current_mode = GameMode::InGame;
+    let player = game_state.faction_manager.create_faction("Player".to_string(), true, AIPersonality::Balanced).unwrap();
+    let rival = game_state.faction_manager.create_faction("Rival".to_string(), false, AIPersonality::Balanced).unwrap();
+    let orbit = |radius: f32| OrbitalElements { semi_major_axis: radius, period: 100_000.0, ..Default::default() };
+    let near = game_state.planet_manager.create_planet(orbit(20.0), Some(player)).unwrap();
+    game_state.planet_manager.create_planet(orbit(2.0), Some(player)).unwrap();
+    game_state.planet_manager.create_planet(orbit(35.0), Some(rival)).unwrap();
+    let warship = game_state.ship_manager.create_ship(ShipClass::Warship, Vector2::new(45.0, 0.0), player).unwrap();
+    let scout = game_state.ship_manager.create_ship(ShipClass::Scout, Vector2::new(3.0, 0.0), player).unwrap();
+    game_state.ship_manager.create_ship(ShipClass::Scout, Vector2::new(8.0, 0.0), rival).unwrap();
+    
+    // Only the player's ships are listed, and the class filter narrows them
+    let mut panel = FleetOverviewView::for_game(&game_state, player).unwrap();
+    assert_eq!(panel.ships(), vec![warship, scout]);
+    panel.show_class(Some(ShipClass::Scout));
+    assert_eq!(panel.ships(), vec![scout]);
+    panel.show_class(None);
+    
+    // Recalling heads for the closest planet the player holds, not the rival's nearer one
+    assert_eq!(game_state.ship_manager.get_trajectory_eta(warship).unwrap(), None);
+    let recall = panel.recall(warship);
+    assert!(matches!(recall, PlayerCommand::RecallShip(id) if id == warship));
+    game_state.issue_command(player, recall);
+    game_state.process_queued_events_for_test().unwrap();
+    let tick = game_state.get_current_tick();
+    let haven = game_state.physics_engine.calculate_orbital_position(
+        &game_state.planet_manager.get_planet(near).unwrap().position, tick);
+    let ship = game_state.ship_manager.get_ship(warship).unwrap();
+    let trajectory = ship.trajectory.as_ref().expect("The recalled ship has a course");
+    assert!(trajectory.destination.distance_to(&haven) < 1e-3);
+    
+    // The ETA counts the ticks each leg takes at ship speed, and shrinks as the ship travels
+    let eta = game_state.ship_manager.get_trajectory_eta(warship).unwrap().unwrap();
+    assert_eq!(eta, 3, "25 units at 10 per tick");
+    game_state.fixed_update(0.1).unwrap();
+    assert_eq!(game_state.ship_manager.get_trajectory_eta(warship).unwrap(), Some(eta - 1));
+}
+
+#[test]
+fn test_build_menu_offers_affordable_buildings_and_orders_them() {
+    use stellar_dominion::ui_v2::{BuildMenuView, View};
+    
+    let mut game_state = GameState::new().unwrap();
+    game_state.current_mode = GameMode::InGame;
+    let player = game_state.faction_manager.create_faction("Player".to_string(), true, AIPersonality::Balanced).unwrap();
+    let planet = game_state.planet_manager.create_planet(OrbitalElements::default(), Some(player)).unwrap();
+    let farm = *game_state.construction_system.get_game_data().building(BuildingType::Farm).unwrap();
+    game_state.planet_manager.modify_planet(planet, |p| {
+        p.resources.current = farm.cost;
+        Ok(())
+    }).unwrap();
+    
+    // Every building is listed with its game data, but only the Farm is within the planet's means
+    let options = game_state.building_build_options(planet).unwrap();
+    assert_eq!(options.iter().map(|option| option.building_type).collect::<Vec<_>>(), BuildingType::ALL.to_vec());
+    let farm_option = options.iter().find(|option| option.building_type == BuildingType::Farm).unwrap();
+    assert_eq!((farm_option.cost, farm_option.production), (farm.cost, farm.net_production()));
+    let mut menu = BuildMenuView::for_game(&game_state, planet).unwrap();
+    assert_eq!(menu.available(), vec![BuildingType::Farm]);
+    let defense = menu.options().iter().find(|option| option.building_type == BuildingType::DefensePlatform).unwrap();
+    assert!(menu.unavailable_reason(defense).unwrap().starts_with("Locked"));
+    assert!(menu.choose(BuildingType::Mine).is_none(), "Grayed-out buildings cannot be ordered");
+    
+    // Choosing the Farm orders it for this planet
+    let command = menu.choose(BuildingType::Farm).unwrap();
+    assert!(matches!(command, PlayerCommand::BuildStructure { planet: p, building_type: BuildingType::Farm } if p == planet));
+    assert!(!menu.is_visible());
+    game_state.issue_command(player, command);
+    game_state.process_queued_events_for_test().unwrap();
+    assert_eq!(game_state.planet_manager.get_building_count(planet, BuildingType::Farm).unwrap(), 1);
+}
+
+#[test]
+fn test_transfer_dialog_clamps_amounts_and_names_both_planets() {
+    use macroquad::prelude::{KeyCode, MouseButton};
+    use stellar_dominion::ui_v2::{ResourceTransferDialog, InputEvent, View};
+    
+    let mut game_state = GameState::new().unwrap();
+    game_state.current_mode = GameMode::InGame;
+    let player = game_state.faction_manager.create_faction("Player".to_string(), true, AIPersonality::Balanced).unwrap();
+    let rival = game_state.faction_manager.create_faction("Rival".to_string(), false, AIPersonality::Balanced).unwrap();
+    let orbit = |radius: f32| OrbitalElements { semi_major_axis: radius, period: 100_000.0, ..Default::default() };
+    let source = game_state.planet_manager.create_planet(orbit(10.0), Some(player)).unwrap();
+    let colony = game_state.planet_manager.create_planet(orbit(40.0), Some(player)).unwrap();
+    game_state.planet_manager.create_planet(orbit(20.0), Some(rival)).unwrap();
+    game_state.planet_manager.modify_planet(source, |p| {
+        p.resources.current = ResourceBundle { minerals: 100, food: 50, ..Default::default() };
+        Ok(())
+    }).unwrap();
+    
+    // Only the faction's other planets are offered, with the flight time of a departure now
+    let mut dialog = ResourceTransferDialog::for_game(&game_state, source).unwrap();
+    assert_eq!(dialog.destinations().iter().map(|d| d.planet).collect::<Vec<_>>(), vec![colony]);
+    assert!(dialog.destinations()[0].travel_ticks >= 3, "30 units at 10 per tick");
+    
+    // Amounts cannot exceed the stock, whether set or typed into the Food field
+    dialog.set_amount(ResourceType::Minerals, 500);
+    dialog.handle_input(&InputEvent::MouseClick { x: 430.0, y: 255.0, button: MouseButton::Left }).unwrap();
+    dialog.handle_input(&InputEvent::KeyPress { key: KeyCode::Key9 }).unwrap();
+    dialog.handle_input(&InputEvent::KeyPress { key: KeyCode::Key9 }).unwrap();
+    assert_eq!(dialog.resources(), ResourceBundle { minerals: 100, food: 50, ..Default::default() });
+    assert!(dialog.confirm().is_none(), "No destination picked yet");
+    
+    dialog.select_destination(colony);
+    assert_eq!(dialog.travel_ticks(), Some(dialog.destinations()[0].travel_ticks));
+    let command = dialog.confirm().unwrap();
+    assert!(matches!(command, PlayerCommand::TransferResources { from, to, resources }
+        if from == source && to == colony && resources.minerals == 100 && resources.food == 50));
+    assert!(!dialog.is_visible());
+    game_state.issue_command(player, command);
+    game_state.process_queued_events_for_test().unwrap();
+    assert!(last_rejection(&game_state).is_none(), "The transfer passes validation");
+}
+
+#[test]
+fn test_new_game_menu_starts_the_configured_galaxy() {
+    use macroquad::prelude::MouseButton;
+    use stellar_dominion::ui_v2::{MainMenuView, MainMenuEntry, NewGameMenu, OptionsMenu, InputEvent, View};
+    use stellar_dominion::ui_v2::panels::new_game_menu::MAX_AI_OPPONENTS;
+    
+    // The main menu's entries open the dialogs; Quit, the last button, leaves
+    let mut menu = MainMenuView::new();
+    assert!(matches!(menu.select(MainMenuEntry::NewGame), PlayerCommand::NewGame));
+    assert!(matches!(menu.select(MainMenuEntry::Options), PlayerCommand::GameOptions));
+    let quit = menu.handle_input(&InputEvent::MouseClick { x: 450.0, y: 430.0, button: MouseButton::Left }).unwrap();
+    assert!(matches!(quit, Some(PlayerCommand::ExitGame)));
+    
+    let mut game_state = GameState::new().unwrap();
+    let mut dialog = NewGameMenu::for_game(&game_state);
+    dialog.set_galaxy_size(GalaxySize::Large);
+    dialog.set_difficulty(Difficulty::Hard);
+    dialog.set_ai_opponents(MAX_AI_OPPONENTS + 5);
+    assert_eq!(dialog.configuration().ai_opponents, MAX_AI_OPPONENTS);
+    dialog.set_ai_opponents(2);
+    let seed = dialog.reroll_seed();
+    // The size preset keeps the other choices
+    dialog.set_galaxy_size(GalaxySize::Medium);
+    assert_eq!(dialog.configuration().difficulty, Difficulty::Hard);
+    assert_eq!(dialog.configuration().seed, seed);
+    
+    let command = dialog.start().unwrap();
+    assert!(!dialog.is_visible());
+    assert!(matches!(&command, PlayerCommand::StartNewGame(config) if config.galaxy_size == GalaxySize::Medium));
+    game_state.handle_menu_event(GameEvent::PlayerCommand(command)).unwrap();
+    assert_eq!(game_state.current_mode, GameMode::InGame);
+    let config = game_state.get_game_configuration();
+    assert_eq!((config.galaxy_size, config.difficulty, config.seed), (GalaxySize::Medium, Difficulty::Hard, seed));
+    assert_eq!(game_state.faction_manager.scoring_factions().count(), 1 + GameConfiguration::for_galaxy_size(GalaxySize::Medium).ai_opponents);
+    
+    // Options apply as they change
+    let mut options = OptionsMenu::new(vec!["Dark", "High contrast"], "Dark", 0.8, 1.0);
+    assert!(options.select_theme("Neon").is_none());
+    assert!(matches!(options.select_theme("High contrast"), Some(PlayerCommand::SetTheme(name)) if name == "High contrast"));
+    assert!(matches!(options.set_volume(1.5), PlayerCommand::SetVolume(volume) if volume == 1.0));
+}
+
+#[test]
+fn test_game_over_screen_summarizes_the_conquest() {
+    use stellar_dominion::systems::StatisticsMetric;
+    use stellar_dominion::ui_v2::{GameOverView, View};
+    
+    let mut game_state = GameState::new().unwrap();
+    game_state.current_mode = GameMode::InGame;
+    let player = game_state.faction_manager.create_faction("Player".to_string(), true, AIPersonality::Balanced).unwrap();
+    let enemy = game_state.faction_manager.create_faction("Enemy".to_string(), false, AIPersonality::Aggressive).unwrap();
+    let home = game_state.planet_manager.create_planet(OrbitalElements::default(), Some(player)).unwrap();
+    let colony = game_state.planet_manager.create_planet(OrbitalElements::default(), Some(enemy)).unwrap();
+    let raider = game_state.ship_manager.create_ship(ShipClass::Warship, Vector2::new(1.5, 0.0), enemy).unwrap();
+    game_state.queue_event(GameEvent::SimulationEvent(SimulationEvent::ResourcesProduced {
+        planet: home, resources: ResourceBundle { minerals: 7, food: -2, ..Default::default() },
+    }));
+    for _ in 0..3 {
+        game_state.fixed_update(0.1).unwrap();
+    }
+    assert!(GameOverView::for_game(&game_state, player).is_none(), "Nothing is decided while both empires stand");
+    
+    // Losing its last planet and ship knocks the enemy out and leaves the player the winner
+    game_state.planet_manager.change_controller(colony, Some(player)).unwrap();
+    game_state.ship_manager.destroy_ship(raider).unwrap();
+    for _ in 0..3 {
+        game_state.fixed_update(0.1).unwrap();
+    }
+    assert!(game_state.scenario_system.is_eliminated(enemy));
+    assert_eq!(game_state.scenario_system.winner(), Some((player, VictoryType::Conquest)));
+    
+    let totals = game_state.statistics_system.totals(player).copied().unwrap();
+    assert_eq!(totals.planets, 2);
+    assert!(totals.resources_produced >= 7, "The planets' own output adds to the queued production");
+    assert_eq!(game_state.statistics_system.totals(enemy).map(|totals| (totals.planets, totals.ships)), Some((0, 0)));
+    
+    let mut screen = GameOverView::for_game(&game_state, player).unwrap();
+    assert_eq!(screen.title(), "Victory");
+    assert!(screen.summary().contains("Conquest"), "{}", screen.summary());
+    assert_eq!(screen.factions().iter().map(|(name, _)| name.as_str()).collect::<Vec<_>>(), vec!["Player", "Enemy"]);
+    screen.show_metric(StatisticsMetric::ResourcesProduced);
+    assert_eq!(screen.metric(), StatisticsMetric::ResourcesProduced);
+    assert_eq!(GameOverView::for_game(&game_state, enemy).unwrap().title(), "Defeat");
+    
+    assert!(matches!(screen.continue_playing(), PlayerCommand::PauseGame(false)));
+    assert!(!screen.is_visible());
+    let mut screen = GameOverView::for_game(&game_state, player).unwrap();
+    assert!(matches!(screen.return_to_menu(), PlayerCommand::BackToMenu));
+}
+
+#[test]
+fn test_graph_panel_charts_chosen_metrics_per_faction() {
+    use stellar_dominion::systems::StatisticsMetric;
+    use stellar_dominion::ui_v2::GraphPanel;
+    use stellar_dominion::ui_v2::panels::graph_panel::MAX_GRAPHS;
+    
+    let mut game_state = GameState::new().unwrap();
+    game_state.current_mode = GameMode::InGame;
+    let player = game_state.faction_manager.create_faction("Player".to_string(), true, AIPersonality::Balanced).unwrap();
+    let enemy = game_state.faction_manager.create_faction("Enemy".to_string(), false, AIPersonality::Aggressive).unwrap();
+    game_state.planet_manager.create_planet(OrbitalElements::default(), Some(player)).unwrap();
+    game_state.planet_manager.create_planet(OrbitalElements::default(), Some(player)).unwrap();
+    game_state.ship_manager.create_ship(ShipClass::Warship, Vector2::new(1.5, 0.0), enemy).unwrap();
+    for _ in 0..20 {
+        game_state.fixed_update(0.1).unwrap();
+    }
+    let samples = game_state.statistics_system.series(player, StatisticsMetric::Planets);
+    assert!(!samples.is_empty() && samples.iter().all(|(_, planets)| *planets == 2), "{:?}", samples);
+    
+    let mut graphs = GraphPanel::for_game(&game_state);
+    assert_eq!(graphs.metrics(), &[StatisticsMetric::Score]);
+    let lines = graphs.chart_series(StatisticsMetric::Ships);
+    assert_eq!(lines.iter().map(|line| line.label.as_str()).collect::<Vec<_>>(), vec!["Player", "Enemy"]);
+    assert!(lines[1].points.iter().all(|(_, ships)| *ships == 1));
+    assert_ne!(lines[0].color, lines[1].color, "Each faction keeps its own color");
+    
+    // Choosing more metrics than fit replaces the oldest chart; the last one cannot be removed
+    for metric in [StatisticsMetric::Planets, StatisticsMetric::Population, StatisticsMetric::Stockpile] {
+        graphs.toggle_metric(metric);
+    }
+    assert_eq!(graphs.metrics().len(), MAX_GRAPHS);
+    assert_eq!(graphs.metrics()[0], StatisticsMetric::Planets);
+    for metric in [StatisticsMetric::Planets, StatisticsMetric::Population, StatisticsMetric::Stockpile] {
+        graphs.toggle_metric(metric);
+    }
+    assert_eq!(graphs.metrics(), &[StatisticsMetric::Stockpile]);
+    
+    graphs.toggle_faction(enemy);
+    assert!(!graphs.is_faction_shown(enemy));
+    assert_eq!(graphs.chart_series(StatisticsMetric::Ships).len(), 1);
+}
+
+#[test]
+fn test_warships_bombard_enemy_planets_until_they_leave_orbit() {
+    use stellar_dominion::systems::combat_resolver::{BOMBARDMENT_CASUALTIES, BOMBARDMENT_RELATION_PENALTY};
+    use stellar_dominion::systems::diplomacy::WAR_DECLARED_PENALTY;
+    use stellar_dominion::ui_v2::BombardmentDialog;
+    let mut game_state = GameState::new().unwrap();
+    game_state.current_mode = GameMode::InGame;
+    let player = game_state.faction_manager.create_faction("Player".to_string(), true, AIPersonality::Balanced).unwrap();
+    let enemy = game_state.faction_manager.create_faction("Enemy".to_string(), false, AIPersonality::Aggressive).unwrap();
+    let orbit = |semi_major_axis: f32| OrbitalElements { semi_major_axis, period: 1_000_000.0, phase: 0.0, eccentricity: 0.0, argument_of_periapsis: 0.0 };
+    let target = game_state.planet_manager.create_planet(orbit(4.0), Some(enemy)).unwrap();
+    game_state.planet_manager.update_population(target, 300).unwrap();
+    game_state.planet_manager.set_worker_allocation(target, WorkerAllocation { military: 40, unassigned: 260, ..Default::default() }).unwrap();
+    for building in [BuildingType::Mine, BuildingType::Farm, BuildingType::Factory] {
+        game_state.planet_manager.add_building(target, building).unwrap();
+    }
+    let buildings = game_state.planet_manager.get_planet(target).unwrap().developments.len() as i32;
+    let scout = game_state.ship_manager.create_ship(ShipClass::Scout, Vector2::new(3.5, 0.0), player).unwrap();
+    let warship = game_state.ship_manager.create_ship(ShipClass::Warship, Vector2::new(3.5, 0.0), player).unwrap();
+    for ship in [scout, warship] {
+        game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::MoveShip { ship, target: Vector2::new(4.0, 0.0) }));
+    }
+    for _ in 0..5 {
+        game_state.fixed_update(0.1).unwrap();
+    }
+    assert_eq!(game_state.bombardment_ships(target, player).unwrap(), vec![warship], "Only warships bombard");
+    let mut dialog = BombardmentDialog::for_game(&game_state, warship, target).unwrap();
+    assert!(dialog.declares_war());
+    let order = dialog.confirm();
+    assert!(matches!(order, PlayerCommand::BombardPlanet { ship, planet } if ship == warship && planet == target));
+    
+    // Only warships can bombard, and the order declares war
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::BombardPlanet { ship: scout, planet: target }));
+    game_state.process_queued_events_for_test().unwrap();
+    assert!(last_rejection(&game_state).unwrap().contains("cannot bombard a planet"));
+    assert!(!game_state.faction_manager.get_faction(player).unwrap().is_at_war_with(enemy));
+    game_state.queue_event(GameEvent::PlayerCommand(order));
+    game_state.process_queued_events_for_test().unwrap();
+    assert!(game_state.combat_resolver.is_ship_bombarding(warship));
+    assert!(game_state.bombardment_ships(target, player).unwrap().is_empty());
+    
+    // Strikes are collected tick by tick, as the event history only keeps the latest events
+    let run = |game_state: &mut GameState, ticks: usize| {
+        let mut strikes = Vec::new();
+        for _ in 0..ticks {
+            let tick = game_state.get_current_tick();
+            game_state.fixed_update(0.1).unwrap();
+            let next = game_state.get_current_tick();
+            strikes.extend(game_state.event_bus.events_since(tick).filter_map(|entry| match &entry.event {
+                GameEvent::SimulationEvent(SimulationEvent::PlanetBombarded { planet, strike, .. }) if entry.tick < next && *planet == target => Some(*strike),
+                _ => None,
+            }));
+        }
+        strikes
+    };
+    let strikes = run(&mut game_state, 15);
+    assert!(game_state.faction_manager.get_faction(player).unwrap().is_at_war_with(enemy));
+    
+    // One strike per tick, each wrecking a building or killing the garrison first
+    assert!(strikes.len() >= 10, "Got {} strikes", strikes.len());
+    let population_strikes = strikes.iter().filter(|strike| matches!(strike, BombardmentStrike::Population { .. })).count() as i32;
+    assert!(population_strikes < strikes.len() as i32, "Some strikes hit buildings");
+    let planet = game_state.planet_manager.get_planet(target).unwrap();
+    assert!((planet.developments.len() as i32) < buildings);
+    let casualties = 40 - planet.population.allocation.military;
+    assert!(casualties >= population_strikes * BOMBARDMENT_CASUALTIES && casualties <= strikes.len() as i32 * BOMBARDMENT_CASUALTIES);
+    let relation = game_state.faction_manager.relation(enemy, player).unwrap();
+    assert!(relation.score <= WAR_DECLARED_PENALTY + BOMBARDMENT_RELATION_PENALTY * strikes.len() as i32);
+    assert!(game_state.notification_system.notifications(enemy).iter().any(|notification| notification.kind == NotificationKind::PlanetBombarded { planet: target }));
+    
+    // Leaving orbit ends the bombardment
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::MoveShip { ship: warship, target: Vector2::new(2.0, 0.0) }));
+    run(&mut game_state, 3);
+    assert!(!game_state.combat_resolver.is_ship_bombarding(warship));
+    assert!(run(&mut game_state, 5).is_empty());
+}
+
+#[test]
+fn test_planets_and_ships_carry_names_the_player_can_change() {
+    use macroquad::prelude::MouseButton;
+    use stellar_dominion::ui_v2::{ShipPanelMigrated, InputEvent, View};
+    
+    let config = GameConfiguration { seed: 12, ai_opponents: 1, planet_count: 12, ..Default::default() };
+    let mut game_state = GameState::new().unwrap();
+    game_state.set_game_configuration(config.clone());
+    game_state.start_new_game().unwrap();
+    
+    // The galaxy generator names every planet, distinctly and by the seed
+    let names: Vec<String> = game_state.planet_manager.get_all_planets().iter()
+        .map(|planet| planet.name.clone().unwrap())
+        .collect();
+    let distinct: std::collections::HashSet<&String> = names.iter().collect();
+    assert_eq!(distinct.len(), names.len());
+    let mut replay = GameState::new().unwrap();
+    replay.set_game_configuration(config);
+    replay.start_new_game().unwrap();
+    let replayed: Vec<Option<String>> = replay.planet_manager.get_all_planets().iter().map(|planet| planet.name.clone()).collect();
+    assert_eq!(replayed, names.iter().cloned().map(Some).collect::<Vec<_>>());
+    
+    let player = game_state.faction_manager.get_player_faction().unwrap().id;
+    let home = game_state.planet_manager.get_planets_by_faction(player)[0].id;
+    let rival = game_state.planet_manager.get_planets_by_faction(1)[0].id;
+    let ship = game_state.ship_manager.get_ships_by_owner(player)[0];
+    let ship_class = game_state.ship_manager.get_ship(ship).unwrap().ship_class;
+    assert_eq!(game_state.ship_manager.get_ship(ship).unwrap().display_name(), format!("{:?} {}", ship_class, ship));
+    
+    let rename = |target, name: &str| PlayerCommand::RenameEntity { target, name: name.to_string() };
+    game_state.issue_command(player, rename(NamedEntity::Planet(home), "  New Haven "));
+    
+    // The ship panel offers its Rename button, inside the default window, on the player's own ships
+    let mut panel = ShipPanelMigrated::new();
+    panel.set_player(Some(player));
+    panel.show_ship(game_state.ship_manager.get_ship(ship).unwrap().clone()).unwrap();
+    panel.set_rename_text("Pathfinder");
+    let command = panel.handle_input(&InputEvent::MouseClick { x: 740.0, y: 725.0, button: MouseButton::Left }).unwrap();
+    game_state.issue_command(player, command.unwrap());
+    game_state.process_queued_events_for_test().unwrap();
+    assert_eq!(game_state.planet_manager.get_planet(home).unwrap().display_name(), "New Haven");
+    assert_eq!(game_state.ship_manager.get_ship(ship).unwrap().display_name(), "Pathfinder");
+    
+    // Blank names and other factions' planets are refused
+    game_state.issue_command(player, rename(NamedEntity::Planet(home), "   "));
+    game_state.process_queued_events_for_test().unwrap();
+    assert!(last_rejection(&game_state).unwrap().contains("cannot be empty"));
+    game_state.issue_command(player, rename(NamedEntity::Planet(rival), "Mine Now"));
+    game_state.process_queued_events_for_test().unwrap();
+    assert!(last_rejection(&game_state).unwrap().contains("does not control"));
+    assert_eq!(game_state.planet_manager.get_planet(home).unwrap().display_name(), "New Haven");
+    let rival_index = game_state.planet_manager.get_all_planets().iter().position(|planet| planet.id == rival).unwrap();
+    assert_eq!(game_state.planet_manager.get_planet(rival).unwrap().name.as_ref(), Some(&names[rival_index]));
+}
+
+#[test]
+fn test_ui_state_cache_follows_state_changes() {
+    use stellar_dominion::ui_v2::UIStateCache;
+    
+    let mut game_state = GameState::new().unwrap();
+    game_state.set_game_configuration(GameConfiguration { seed: 5, ai_opponents: 1, ..Default::default() });
+    game_state.start_new_game().unwrap();
+    let player = game_state.faction_manager.get_player_faction().unwrap().id;
+    let stored = |game_state: &GameState| {
+        let mut total = ResourceBundle::default();
+        for planet in game_state.planet_manager.get_planets_by_faction(player) {
+            total.add(&planet.resources.current).unwrap();
+        }
+        total
+    };
+    
+    // The first sync after a new game builds everything
+    let mut cache = UIStateCache::new();
+    cache.sync(&mut game_state);
+    let home = game_state.planet_manager.get_planets_by_faction(player)[0].id;
+    assert!(cache.planet_changed(home));
+    assert_eq!(cache.resource_totals(player), stored(&game_state));
+    
+    // Production updates the totals by each planet's difference
+    for _ in 0..5 {
+        game_state.fixed_update(0.1).unwrap();
+    }
+    cache.sync(&mut game_state);
+    assert!(cache.planet_changed(home) && cache.faction_changed(player));
+    assert_eq!(cache.resource_totals(player), stored(&game_state));
+    
+    // Nothing changes while paused
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::PauseGame(true)));
+    game_state.process_queued_events_for_test().unwrap();
+    cache.sync(&mut game_state);
+    game_state.fixed_update(0.1).unwrap();
+    cache.sync(&mut game_state);
+    assert!(!cache.planet_changed(home) && !cache.faction_changed(player));
+    
+    // Renames reach the summaries
+    game_state.issue_command(player, PlayerCommand::RenameEntity { target: NamedEntity::Planet(home), name: "Cradle".to_string() });
+    game_state.process_queued_events_for_test().unwrap();
+    cache.sync(&mut game_state);
+    assert!(cache.planet_changed(home));
+    assert_eq!(cache.planet_summary(home).unwrap().name, "Cradle");
+}
+
+#[test]
+fn test_motion_buffer_draws_ships_between_fixed_updates() {
+    use stellar_dominion::ui_v2::MotionBuffer;
+    
+    let mut game_state = GameState::new().unwrap();
+    game_state.set_game_configuration(GameConfiguration { seed: 9, ai_opponents: 1, ..Default::default() });
+    game_state.start_new_game().unwrap();
+    let player = game_state.faction_manager.get_player_faction().unwrap().id;
+    let ship = game_state.ship_manager.get_ships_by_owner(player)[0];
+    let start = game_state.ship_manager.get_ship(ship).unwrap().position;
+    let target = Vector2::new(start.x + 3.0, start.y + 2.0);
+    game_state.issue_command(player, PlayerCommand::MoveShip { ship, target });
+    game_state.process_queued_events_for_test().unwrap();
+    
+    let mut motion = MotionBuffer::new();
+    motion.record(&game_state);
+    let before = game_state.ship_manager.get_ship(ship).unwrap().position;
+    // A ship seen once is drawn where it is
+    assert_eq!(motion.ship_position(ship, 0.3), Some(before));
+    
+    game_state.fixed_update(0.1).unwrap();
+    motion.record(&game_state);
+    let after = game_state.ship_manager.get_ship(ship).unwrap().position;
+    assert!(before.distance_to(&after) > 0.0);
+    assert_eq!(motion.ship_position(ship, 0.0), Some(before));
+    assert_eq!(motion.ship_position(ship, 1.0), Some(after));
+    let halfway = motion.ship_position(ship, 0.5).unwrap();
+    assert!((halfway.distance_to(&before) - before.distance_to(&after) / 2.0).abs() < 1e-3);
+    
+    // An update that runs no tick holds everything still
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::PauseGame(true)));
+    game_state.fixed_update(0.1).unwrap();
+    motion.record(&game_state);
+    assert_eq!(motion.ship_position(ship, 0.5), Some(after));
+}
+
+#[test]
+fn test_icon_atlas_layout_and_detail_levels() {
+    use stellar_dominion::ui_v2::core::icon_atlas::{AtlasLayout, ICON_CELLS, SPRITE_ZOOM};
+    use stellar_dominion::ui_v2::{Icon, IconAtlas, IconDetail};
+    
+    // Dots when zoomed out, sprites up close
+    assert_eq!(IconDetail::at_zoom(SPRITE_ZOOM / 2.0), IconDetail::Dot);
+    assert_eq!(IconDetail::at_zoom(SPRITE_ZOOM), IconDetail::Sprite);
+    
+    // One square cell per icon, the planet first and ships in class order
+    let layout = AtlasLayout::for_size(32 * ICON_CELLS as u32, 32).unwrap();
+    assert_eq!(layout.source(Icon::Planet).x, 0.0);
+    let warship = ShipClass::ALL.iter().position(|&class| class == ShipClass::Warship).unwrap();
+    let source = layout.source(Icon::Ship(ShipClass::Warship));
+    assert_eq!((source.x, source.w, source.h), (32.0 * (1 + warship) as f32, 32.0, 32.0));
+    assert!(AtlasLayout::for_size(32 * (ICON_CELLS as u32 - 1), 32).is_err());
+    
+    // Without an atlas the map keeps its primitives
+    assert!(IconAtlas::load_from_file(std::path::Path::new("missing/icons.png")).unwrap().is_none());
+}
+
+#[test]
+fn test_control_groups_are_kept_per_faction_and_saved() {
+    use stellar_dominion::ui_v2::{CameraTarget, GroupRecall, Selection};
+    use stellar_dominion::ui_v2::core::selection::DOUBLE_PRESS_SECONDS;
+    use stellar_dominion::systems::SaveSystem;
+    
+    let mut game_state = GameState::new().unwrap();
+    game_state.set_game_configuration(GameConfiguration { seed: 4, ai_opponents: 1, ..Default::default() });
+    game_state.start_new_game().unwrap();
+    let player = game_state.faction_manager.get_player_faction().unwrap().id;
+    let home = game_state.planet_manager.get_planets_by_faction(player)[0].id;
+    let ships = game_state.ship_manager.get_ships_by_owner(player);
+    let rival_ship = game_state.ship_manager.get_ships_by_owner(1)[0];
+    
+    // A multi-selection becomes the group's members, repeats dropped
+    let mut selection = Selection::single(CameraTarget::Ship(ships[0]));
+    selection.toggle(CameraTarget::Planet(home));
+    selection.toggle(CameraTarget::Ship(ships[0]));
+    selection.toggle(CameraTarget::Ship(ships[0]));
+    assert_eq!(selection.primary(), Some(CameraTarget::Planet(home)));
+    let mut members = selection.members();
+    members.push(NamedEntity::Planet(home));
+    game_state.issue_command(player, PlayerCommand::AssignControlGroup { faction: player, group: 3, members });
+    game_state.process_queued_events_for_test().unwrap();
+    let group = game_state.faction_manager.get_faction(player).unwrap().control_group(3).to_vec();
+    assert_eq!(group, vec![NamedEntity::Planet(home), NamedEntity::Ship(ships[0])]);
+    assert_eq!(Selection::from_group(&group), selection);
+    
+    // Other factions' ships and groups past 9 are refused
+    game_state.issue_command(player, PlayerCommand::AssignControlGroup { faction: player, group: 4, members: vec![NamedEntity::Ship(rival_ship)] });
+    game_state.process_queued_events_for_test().unwrap();
+    assert!(last_rejection(&game_state).unwrap().contains("does not control"));
+    game_state.issue_command(player, PlayerCommand::AssignControlGroup { faction: player, group: 10, members: vec![NamedEntity::Planet(home)] });
+    game_state.process_queued_events_for_test().unwrap();
+    assert!(last_rejection(&game_state).unwrap().contains("out of bounds"));
+    
+    // Groups are saved with the faction
+    let path = std::env::temp_dir().join(format!("control_groups_{}.json", std::process::id()));
+    SaveSystem::export_json(&game_state, &path).unwrap();
+    let mut loaded = GameState::new().unwrap();
+    loaded.apply_save_data(SaveSystem::import_json(&path).unwrap()).unwrap();
+    std::fs::remove_file(&path).ok();
+    assert_eq!(loaded.faction_manager.get_faction(player).unwrap().control_group(3), group.as_slice());
+    
+    // An empty selection clears the group
+    game_state.issue_command(player, PlayerCommand::AssignControlGroup { faction: player, group: 3, members: Vec::new() });
+    game_state.process_queued_events_for_test().unwrap();
+    assert!(game_state.faction_manager.get_faction(player).unwrap().control_group(3).is_empty());
+    
+    // A quick second press of the same key is a double press
+    let mut recall = GroupRecall::default();
+    assert!(!recall.press(3, 10.0));
+    assert!(recall.press(3, 10.0 + DOUBLE_PRESS_SECONDS / 2.0));
+    assert!(!recall.press(3, 11.0));
+    assert!(!recall.press(4, 11.1));
+    assert!(!recall.press(4, 12.0));
+}
+
+#[test]
+fn test_context_menu_offers_orders_the_selection_can_carry_out() {
+    use stellar_dominion::ui_v2::{CameraTarget, Order, OrderResolver, Selection};
+    
+    let mut game_state = GameState::new().unwrap();
+    game_state.set_game_configuration(GameConfiguration { seed: 4, ai_opponents: 1, ..Default::default() });
+    game_state.start_new_game().unwrap();
+    let player = game_state.faction_manager.get_player_faction().unwrap().id;
+    let home = game_state.planet_manager.get_planets_by_faction(player)[0].id;
+    let scout = game_state.ship_manager.get_ships_by_owner(player)[0];
+    let rival_ship = game_state.ship_manager.get_ships_by_owner(1)[0];
+    let orbit = game_state.planet_manager.get_planet(home).unwrap().position;
+    let transport = game_state.ship_manager.create_ship(
+        ShipClass::Transport,
+        game_state.physics_engine.calculate_orbital_position(&orbit, game_state.get_current_tick()),
+        player,
+    ).unwrap();
+    for _ in 0..3 {
+        game_state.fixed_update(0.1).unwrap();
+    }
+    assert_eq!(game_state.ship_manager.get_ship(transport).unwrap().status, ShipStatus::Orbiting(home));
+    
+    let mut selection = Selection::single(CameraTarget::Ship(scout));
+    selection.toggle(CameraTarget::Ship(transport));
+    let orders = |game_state: &GameState, target| -> Vec<Order> {
+        OrderResolver::new(game_state, player).resolve(&selection, target, Vector2::new(5.0, 5.0))
+            .iter().map(|option| option.order).collect()
+    };
+    
+    // Empty space can only be moved to; the home planet can be loaded from
+    // but neither colonized nor bombarded
+    assert_eq!(orders(&game_state, None), vec![Order::Move]);
+    assert_eq!(orders(&game_state, Some(CameraTarget::Planet(home))), vec![Order::Move, Order::LoadCargo]);
+    assert!(orders(&game_state, Some(CameraTarget::Ship(rival_ship))).contains(&Order::Attack));
+    assert!(OrderResolver::new(&game_state, player)
+        .resolve(&Selection::single(CameraTarget::Planet(home)), None, Vector2::new(5.0, 5.0))
+        .is_empty());
+    
+    // The cargo loaded is what fits in the hold
+    let resolver = OrderResolver::new(&game_state, player);
+    let options = resolver.resolve(&selection, Some(CameraTarget::Planet(home)), Vector2::default());
+    let load = options.iter().find(|option| option.order == Order::LoadCargo).unwrap();
+    let PlayerCommand::LoadShipCargo { ship, resources, .. } = load.command() else {
+        panic!("expected a cargo order, got {:?}", load.command());
+    };
+    assert_eq!(ship, transport);
+    assert_eq!(resources.total(), game_state.ship_manager.get_ship(transport).unwrap().cargo.capacity as i64);
+    
+    // Moving both ships is one menu entry issuing an order for each
+    let command = options[0].command();
+    assert!(matches!(&command, PlayerCommand::IssueOrders(orders) if orders.len() == 2));
+    game_state.issue_command(player, command);
+    game_state.process_queued_events_for_test().unwrap();
+    assert_eq!(last_rejection(&game_state), None);
+    for ship in [scout, transport] {
+        assert!(game_state.ship_manager.get_ship(ship).unwrap().trajectory.is_some());
+    }
+}
+
+#[test]
+fn test_drag_select_box_picks_the_players_ships_inside() {
+    use stellar_dominion::ui_v2::{Camera, DragTracker, MapGesture, SelectionPanel};
+    use stellar_dominion::ui_v2::core::box_select::{ships_in_box, world_box, DRAG_THRESHOLD};
+    use macroquad::prelude::{vec2, Rect};
+    
+    // A short drag is still a click at the press; a longer one is a box
+    let mut drag = DragTracker::new();
+    assert_eq!(drag.release(vec2(5.0, 5.0)), None);
+    drag.press(vec2(100.0, 100.0));
+    drag.move_to(vec2(100.0 + DRAG_THRESHOLD / 2.0, 100.0));
+    assert_eq!(drag.drag_box(), None);
+    assert_eq!(drag.release(vec2(101.0, 101.0)), Some(MapGesture::Click(vec2(100.0, 100.0))));
+    drag.press(vec2(300.0, 200.0));
+    drag.move_to(vec2(100.0, 250.0));
+    assert_eq!(drag.drag_box(), Some(Rect::new(100.0, 200.0, 200.0, 50.0)));
+    assert_eq!(drag.release(vec2(100.0, 250.0)), Some(MapGesture::Box(Rect::new(100.0, 200.0, 200.0, 50.0))));
+    assert_eq!(drag.drag_box(), None);
+    
+    let mut game_state = GameState::new().unwrap();
+    game_state.set_game_configuration(GameConfiguration { seed: 4, ai_opponents: 1, ..Default::default() });
+    game_state.start_new_game().unwrap();
+    let player = game_state.faction_manager.get_player_faction().unwrap().id;
+    let camera = Camera::new(vec2(1000.0, 800.0));
+    let inside = camera.screen_to_world(vec2(500.0, 400.0));
+    let outside = camera.screen_to_world(vec2(900.0, 700.0));
+    let warship = game_state.ship_manager.create_ship(ShipClass::Warship, inside, player).unwrap();
+    let transport = game_state.ship_manager.create_ship(ShipClass::Transport, inside, player).unwrap();
+    game_state.ship_manager.create_ship(ShipClass::Scout, outside, player).unwrap();
+    game_state.ship_manager.create_ship(ShipClass::Warship, inside, 1).unwrap();
+    
+    // The box is converted to world space, and only the player's ships in it count
+    let rect = Rect::new(450.0, 350.0, 100.0, 100.0);
+    let (min, max) = world_box(&camera, rect);
+    assert!(min.x < inside.x && inside.x < max.x && min.y < inside.y && inside.y < max.y);
+    let mut selected = vec![warship, transport];
+    selected.sort_unstable();
+    assert_eq!(ships_in_box(&game_state, &camera, rect, player), selected);
+    
+    // The panel counts them by class and orders them all at once
+    let panel = SelectionPanel::for_game(&game_state, &selected);
+    assert_eq!(panel.counts(), &[(ShipClass::Transport, 1), (ShipClass::Warship, 1)]);
+    game_state.issue_command(player, PlayerCommand::MoveShip { ship: warship, target: outside });
+    game_state.issue_command(player, PlayerCommand::MoveShip { ship: transport, target: outside });
+    game_state.process_queued_events_for_test().unwrap();
+    assert!(selected.iter().all(|&ship| game_state.ship_manager.get_ship(ship).unwrap().trajectory.is_some()));
+    game_state.issue_command(player, panel.stop_all());
+    game_state.process_queued_events_for_test().unwrap();
+    assert_eq!(last_rejection(&game_state), None);
+    for ship in selected {
+        assert!(game_state.ship_manager.get_ship(ship).unwrap().trajectory.is_none());
+    }
+}
+
+#[test]
+fn test_tooltips_show_after_hover_delay_and_stay_on_screen() {
+    use stellar_dominion::ui_v2::{BuildMenuView, View, Tooltip, TooltipLine, TooltipManager};
+    use stellar_dominion::ui_v2::core::tooltip::{place_tooltip, TOOLTIP_DELAY};
+    use macroquad::prelude::{vec2, Rect};
+    
+    // Nothing shows until the cursor has rested on a region for the delay
+    let region = Rect::new(100.0, 100.0, 50.0, 20.0);
+    let tooltip = |stock: i32| Tooltip::new("Farm").text(format!("Stock {}", stock)).key_hint("Click", "Build");
+    let mut tooltips = TooltipManager::new();
+    tooltips.register(region, tooltip(1));
+    tooltips.update(vec2(120.0, 110.0), TOOLTIP_DELAY / 2.0);
+    assert_eq!(tooltips.active(), None);
+    tooltips.register(region, tooltip(1));
+    tooltips.update(vec2(125.0, 110.0), TOOLTIP_DELAY);
+    assert_eq!(tooltips.active().map(|tooltip| tooltip.title.as_str()), Some("Farm"));
+    
+    // Changing figures keep it up; leaving the region hides it and starts the delay over
+    tooltips.register(region, tooltip(2));
+    tooltips.update(vec2(125.0, 110.0), 0.01);
+    assert_eq!(tooltips.active().unwrap().lines[0], TooltipLine::Text("Stock 2".to_string()));
+    tooltips.register(region, tooltip(2));
+    tooltips.update(vec2(10.0, 10.0), 0.01);
+    assert_eq!(tooltips.active(), None);
+    tooltips.register(region, tooltip(2));
+    tooltips.update(vec2(120.0, 110.0), 0.01);
+    assert_eq!(tooltips.active(), None);
+    
+    // Tooltips sit below right of the cursor, flipped back onto the screen at its edges
+    let screen = vec2(800.0, 600.0);
+    let size = vec2(200.0, 80.0);
+    let placed = place_tooltip(size, vec2(100.0, 100.0), screen);
+    assert!(placed.x > 100.0 && placed.y > 100.0);
+    let placed = place_tooltip(size, vec2(790.0, 590.0), screen);
+    assert!(placed.right() <= 790.0 && placed.bottom() <= 590.0);
+    assert_eq!(place_tooltip(vec2(900.0, 700.0), vec2(10.0, 10.0), screen).point(), vec2(0.0, 0.0));
+    
+    // Build menu rows describe cost, output and how to order, or why not
+    let mut game_state = GameState::new().unwrap();
+    let player = game_state.faction_manager.create_faction("Player".to_string(), true, AIPersonality::Balanced).unwrap();
+    let planet = game_state.planet_manager.create_planet(OrbitalElements::default(), Some(player)).unwrap();
+    let farm = *game_state.construction_system.get_game_data().building(BuildingType::Farm).unwrap();
+    game_state.planet_manager.modify_planet(planet, |p| {
+        p.resources.current = farm.cost;
+        Ok(())
+    }).unwrap();
+    let menu = BuildMenuView::for_game(&game_state, planet).unwrap();
+    let rows = menu.tooltips();
+    assert_eq!(rows.len(), menu.options().len());
+    let farm_tooltip = &rows.iter().find(|(_, tooltip)| tooltip.title == "Farm").unwrap().1;
+    assert!(farm_tooltip.lines.contains(&TooltipLine::Cost { label: "Cost".to_string(), resources: farm.cost }));
+    assert!(farm_tooltip.lines.contains(&TooltipLine::KeyHint { key: "Click".to_string(), action: "Build on this planet".to_string() }));
+    let mine_tooltip = &rows.iter().find(|(_, tooltip)| tooltip.title == "Mine").unwrap().1;
+    assert!(matches!(mine_tooltip.lines.last(), Some(TooltipLine::Warning(_))));
+}
+
+#[test]
+fn test_ui_scale_is_clamped_persisted_and_applied_to_input() {
+    use stellar_dominion::ui_v2::{InputEvent, OptionsMenu, UISystem};
+    use stellar_dominion::ui_v2::core::ui_scale::{UI_SCALE_MAX, UI_SCALE_MIN};
+    use macroquad::prelude::MouseButton;
+    
+    // The options step the scale within the offered range
+    let mut options = OptionsMenu::new(vec!["Dark"], "Dark", 1.0, 1.75);
+    assert!(matches!(options.step_ui_scale(1), PlayerCommand::SetUiScale(scale) if scale == UI_SCALE_MAX));
+    assert!(matches!(options.step_ui_scale(1), PlayerCommand::SetUiScale(scale) if scale == UI_SCALE_MAX));
+    assert!(matches!(options.step_ui_scale(-10), PlayerCommand::SetUiScale(scale) if scale == UI_SCALE_MIN));
+    let mut ui = UISystem::new();
+    ui.set_ui_scale(5.0);
+    assert_eq!(ui.ui_scale(), UI_SCALE_MAX);
+    ui.set_ui_scale(f32::NAN);
+    assert_eq!(ui.ui_scale(), 1.0);
+    
+    // Views see pointer positions in UI units; keys are untouched
+    let click = InputEvent::MouseClick { x: 300.0, y: 150.0, button: MouseButton::Left };
+    assert!(matches!(click.to_ui(1.5), InputEvent::MouseClick { x, y, .. } if x == 200.0 && y == 100.0));
+    assert!(matches!(InputEvent::Scroll { x: 30.0, y: 60.0, delta: 1.0 }.to_ui(2.0), InputEvent::Scroll { x, y, delta } if x == 15.0 && y == 30.0 && delta == 1.0));
+}
+
+#[test]
+fn test_settings_round_trip_through_the_settings_file() {
+    use stellar_dominion::settings::{config_dir, Settings, SettingsManager, CONFIG_DIR_ENV, SETTINGS_FILE};
+    use stellar_dominion::ui_v2::InputAction;
+    use stellar_dominion::ui_v2::core::ui_scale::UI_SCALE_MAX;
+    use macroquad::prelude::KeyCode;
+    
+    // Every setting survives being written and read back
+    let mut settings = Settings {
+        theme: "High \"Contrast\"".to_string(),
+        ui_scale: 1.5,
+        master_volume: 0.4,
+        effects_volume: 0.25,
+        autosave_interval_ticks: 600,
+        ..Settings::default()
+    };
+    settings.key_bindings.bind(InputAction::OpenMarket, KeyCode::F5).unwrap();
+    assert_eq!(Settings::from_toml(&settings.to_toml().unwrap()).unwrap(), settings);
+    
+    // Missing settings keep their defaults; out of range ones are clamped
+    let partial = Settings::from_toml("# mine\nui_scale = 9 # too big\nmaster_volume = -1\n").unwrap();
+    assert_eq!(partial.ui_scale, UI_SCALE_MAX);
+    assert_eq!(partial.master_volume, 0.0);
+    assert_eq!(partial.theme, Settings::default().theme);
+    assert!(Settings::from_toml("brightness = 1\n").is_err());
+    assert!(Settings::from_toml("master_volume = \"loud\"\n").is_err());
+    assert!(Settings::from_toml("[key_bindings]\nOpenMarket = \"NoSuchKey\"\n").is_err());
+    
+    // Any valid TOML is read, such as literal strings and inline tables
+    let edited = Settings::from_toml("theme = 'C:\\Themes\\Light'\nkey_bindings = { OpenMarket = \"F5\" }\n").unwrap();
+    assert_eq!(edited.theme, "C:\\Themes\\Light");
+    assert_eq!(edited.key_bindings.key(InputAction::OpenMarket), KeyCode::F5);
+    
+    // The manager saves each change and loads it again
+    let dir = std::env::temp_dir().join(format!("stellar_dominion_settings_{}", std::process::id()));
+    let path = dir.join(SETTINGS_FILE);
+    let mut manager = SettingsManager::load(&path).unwrap();
+    assert_eq!(manager.settings(), &Settings::default());
+    manager.update(|settings| settings.theme = "Light".to_string()).unwrap();
+    assert_eq!(SettingsManager::load(&path).unwrap().settings().theme, "Light");
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert!(SettingsManager::new().update(|settings| settings.ui_scale = 2.0).is_ok());
+    
+    // The configuration directory follows the override when it is set
+    if let Some(dir) = std::env::var_os(CONFIG_DIR_ENV) {
+        assert_eq!(config_dir(), std::path::PathBuf::from(dir));
+    } else {
+        assert!(config_dir().ends_with("stellar-dominion") || config_dir().ends_with("config"));
+    }
+}
+
+#[test]
+fn test_audio_plays_each_effect_once_for_the_players_events() {
+    use stellar_dominion::audio::{AudioBackend, AudioSystem, SoundEffect};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    
+    struct Recorder(Rc<RefCell<Vec<(SoundEffect, f32)>>>);
+    impl AudioBackend for Recorder {
+        fn play(&mut self, effect: SoundEffect, volume: f32) {
+            self.0.borrow_mut().push((effect, volume));
+        }
+    }
+    let played = Rc::new(RefCell::new(Vec::new()));
+    let take = || played.borrow_mut().drain(..).map(|(effect, _)| effect).collect::<Vec<_>>();
+    
+    let mut game_state = GameState::new().unwrap();
+    let player = game_state.faction_manager.create_faction("Player".to_string(), true, AIPersonality::Balanced).unwrap();
+    let rival = game_state.faction_manager.create_faction("Rival".to_string(), false, AIPersonality::Aggressive).unwrap();
+    let home = game_state.planet_manager.create_planet(OrbitalElements::default(), Some(player)).unwrap();
+    let enemy_world = game_state.planet_manager.create_planet(OrbitalElements::default(), Some(rival)).unwrap();
+    let ours = game_state.ship_manager.create_ship(ShipClass::Warship, Vector2::default(), player).unwrap();
+    let theirs = game_state.ship_manager.create_ship(ShipClass::Warship, Vector2::default(), rival).unwrap();
+    let mut audio = AudioSystem::new(Box::new(Recorder(played.clone())));
+    audio.skip_to_now(&game_state);
+    audio.hear(&game_state);
+    assert!(take().is_empty());
+    
+    // Only the player's planets count, and several events make one sound
+    let completed = |planet| GameEvent::SimulationEvent(SimulationEvent::ConstructionCompleted { planet, building: BuildingType::Farm });
+    game_state.event_bus.queue_event(completed(enemy_world));
+    audio.hear(&game_state);
+    assert!(take().is_empty());
+    game_state.event_bus.queue_event(completed(home));
+    game_state.event_bus.queue_event(completed(home));
+    audio.hear(&game_state);
+    assert_eq!(take(), vec![SoundEffect::ConstructionComplete]);
+    audio.hear(&game_state);
+    assert!(take().is_empty());
+    
+    // A battle the player's ship fought ends with an explosion even once the ship is gone
+    game_state.event_bus.queue_event(GameEvent::SimulationEvent(SimulationEvent::ShipCombat { attacker: theirs, defender: ours, attacker_damage: 5, defender_damage: 50 }));
+    audio.hear(&game_state);
+    assert_eq!(take(), vec![SoundEffect::WeaponFire]);
+    game_state.ship_manager.destroy_ship(ours).unwrap();
+    let outcome = CombatOutcome { winner: rival, attacker_losses: vec![], defender_losses: vec![ours], retreated: vec![], rounds: 1, attacker_damage: 5, defender_damage: 50 };
+    game_state.event_bus.queue_event(GameEvent::SimulationEvent(SimulationEvent::CombatResolved { attacker: theirs, defender: ours, outcome }));
+    audio.hear(&game_state);
+    assert_eq!(take(), vec![SoundEffect::Explosion]);
+    
+    // Urgent notifications sound an alert; volumes scale and can silence effects
+    game_state.notification_system.notify(player, NotificationKind::PlanetColonized { planet: home });
+    audio.hear(&game_state);
+    assert_eq!(take(), vec![SoundEffect::Notification]);
+    game_state.notification_system.notify(player, NotificationKind::PirateRaid { planet: home });
+    audio.set_volumes(0.5, 0.5);
+    audio.hear(&game_state);
+    assert_eq!(played.borrow().as_slice(), &[(SoundEffect::Alert, 0.25)]);
+    take();
+    audio.set_volumes(1.0, 0.0);
+    audio.play(SoundEffect::Click);
+    assert!(take().is_empty());
+}
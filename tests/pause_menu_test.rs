@@ -0,0 +1,48 @@
+// tests/pause_menu_test.rs
+use macroquad::prelude::MouseButton;
+use stellar_dominion::core::events::PlayerCommand;
+use stellar_dominion::ui_v2::panels::pause_menu::{PauseMenuEntry, PAUSE_MENU_VIEW_TYPE};
+use stellar_dominion::ui_v2::{DialogManager, InboxPanel, InputEvent, PauseMenu};
+
+/// Click the centre of a pause menu entry's button
+fn click(entry: PauseMenuEntry) -> InputEvent {
+    let index = PauseMenuEntry::ALL.iter().position(|e| *e == entry).unwrap();
+    InputEvent::MouseClick { x: 500.0, y: 255.0 + index as f32 * 40.0, button: MouseButton::Left }
+}
+
+#[test]
+fn test_pause_menu_entries_emit_commands() {
+    let mut dialogs = DialogManager::new();
+    dialogs.open(Box::new(PauseMenu::new()));
+
+    let command = dialogs.handle_input(&click(PauseMenuEntry::Save)).unwrap();
+    assert!(matches!(command, Some(PlayerCommand::SaveGame)));
+    assert!(dialogs.is_open(PAUSE_MENU_VIEW_TYPE), "Saving keeps the menu open");
+
+    let command = dialogs.handle_input(&click(PauseMenuEntry::Resume)).unwrap();
+    assert!(matches!(command, Some(PlayerCommand::PauseGame(false))));
+    assert!(!dialogs.is_active(), "Resume closes the menu");
+
+    dialogs.open(Box::new(PauseMenu::new()));
+    let command = dialogs.handle_input(&click(PauseMenuEntry::QuitToDesktop)).unwrap();
+    assert!(matches!(command, Some(PlayerCommand::ExitGame)));
+    assert!(!dialogs.is_active());
+}
+
+#[test]
+fn test_only_topmost_dialog_receives_input() {
+    let mut dialogs = DialogManager::new();
+    dialogs.open(Box::new(PauseMenu::new()));
+    dialogs.open(Box::new(InboxPanel::new(0)));
+    assert_eq!(dialogs.top_view_type(), Some("InboxPanel"));
+
+    // The click lands on the pause menu's Resume button but the inbox is on top
+    let command = dialogs.handle_input(&click(PauseMenuEntry::Resume)).unwrap();
+    assert!(command.is_none());
+    assert_eq!(dialogs.len(), 2);
+
+    assert_eq!(dialogs.close_top(), Some("InboxPanel"));
+    let command = dialogs.handle_input(&click(PauseMenuEntry::Resume)).unwrap();
+    assert!(matches!(command, Some(PlayerCommand::PauseGame(false))));
+    assert!(dialogs.is_empty());
+}
@@ -1,5 +1,5 @@
 // tests/phase2_integration_test.rs
-use stellar_dominion::core::{GameState, GameResult, GameEvent, GameSystem};
+use stellar_dominion::core::{GameState, GameResult, GameEvent};
 use stellar_dominion::core::events::PlayerCommand;
 
 #[test]
@@ -13,6 +13,8 @@ fn test_orbital_position_calculation() {
         semi_major_axis: 5.0,
         period: 100.0, // 100 ticks for full orbit
         phase: 0.0,
+        eccentricity: 0.0,
+        argument_of_periapsis: 0.0,
     };
     
     // Test position at start
@@ -40,6 +42,8 @@ fn test_public_api_functionality() {
         semi_major_axis: 5.0,
         period: 100.0,
         phase: 0.0,
+        eccentricity: 0.0,
+        argument_of_periapsis: 0.0,
     };
     
     // Test calculate_orbital_position with different ticks
@@ -168,6 +172,8 @@ fn test_invalid_orbital_elements() {
         semi_major_axis: 5.0,
         period: 0.0,
         phase: 0.0,
+        eccentricity: 0.0,
+        argument_of_periapsis: 0.0,
     };
     
     let pos1 = physics.calculate_orbital_position(&invalid_orbit1, 50);
@@ -179,6 +185,8 @@ fn test_invalid_orbital_elements() {
         semi_major_axis: -5.0,
         period: 100.0,
         phase: 0.0,
+        eccentricity: 0.0,
+        argument_of_periapsis: 0.0,
     };
     
     let pos2 = physics.calculate_orbital_position(&invalid_orbit2, 50);
@@ -218,6 +226,8 @@ fn test_deterministic_behavior() {
         semi_major_axis: 7.5,
         period: 365.0,
         phase: 1.57, // π/2 radians
+        eccentricity: 0.0,
+        argument_of_periapsis: 0.0,
     };
     
     // Same inputs should produce identical outputs
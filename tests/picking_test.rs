@@ -0,0 +1,63 @@
+// tests/picking_test.rs
+use macroquad::prelude::Vec2;
+use stellar_dominion::core::*;
+use stellar_dominion::ui_v2::core::picking::{pick_entity, resolve_clicks, PICK_RADIUS};
+use stellar_dominion::ui_v2::{Camera, CameraTarget};
+
+fn camera() -> Camera {
+    let mut camera = Camera::new(Vec2::new(800.0, 600.0));
+    camera.set_galaxy_extent(50.0);
+    camera
+}
+
+#[test]
+fn test_clicks_resolve_to_entity_under_cursor() {
+    let mut game_state = GameState::new().unwrap();
+    let faction = game_state.faction_manager.create_faction("Rivals".to_string(), false, AIPersonality::Balanced).unwrap();
+    let orbit = OrbitalElements { semi_major_axis: 4.0, period: 1000.0, ..Default::default() };
+    let planet = game_state.planet_manager.create_planet(orbit, Some(faction)).unwrap();
+    let planet_position = game_state.physics_engine.calculate_orbital_position(&orbit, game_state.get_current_tick());
+    let ship = game_state.ship_manager.create_ship(ShipClass::Scout, Vector2::new(-6.0, 2.0), faction).unwrap();
+    game_state.refresh_spatial_index();
+
+    let camera = camera();
+    let planet_point = camera.world_to_screen(planet_position);
+    let ship_point = camera.world_to_screen(Vector2::new(-6.0, 2.0));
+
+    assert_eq!(pick_entity(&game_state, &camera, planet_point + Vec2::new(PICK_RADIUS - 1.0, 0.0)), Some(CameraTarget::Planet(planet)));
+    assert_eq!(pick_entity(&game_state, &camera, ship_point), Some(CameraTarget::Ship(ship)));
+    assert_eq!(pick_entity(&game_state, &camera, planet_point + Vec2::new(PICK_RADIUS + 1.0, 0.0)), None);
+
+    let commands = resolve_clicks(&game_state, &camera, &[Vec2::new(0.0, 0.0), ship_point, planet_point]);
+    assert_eq!(commands.len(), 2, "A click on empty space selects nothing");
+    assert!(matches!(commands[0], PlayerCommand::SelectShip(id) if id == ship));
+    assert!(matches!(commands[1], PlayerCommand::SelectPlanet(id) if id == planet));
+}
+
+#[test]
+fn test_ship_over_planet_wins_the_pick() {
+    let mut game_state = GameState::new().unwrap();
+    let faction = game_state.faction_manager.create_faction("Rivals".to_string(), false, AIPersonality::Balanced).unwrap();
+    let orbit = OrbitalElements { semi_major_axis: 4.0, period: 1000.0, ..Default::default() };
+    game_state.planet_manager.create_planet(orbit, Some(faction)).unwrap();
+    let planet_position = game_state.physics_engine.calculate_orbital_position(&orbit, game_state.get_current_tick());
+    let ship = game_state.ship_manager.create_ship(ShipClass::Scout, planet_position, faction).unwrap();
+    game_state.refresh_spatial_index();
+
+    let camera = camera();
+    let point = camera.world_to_screen(planet_position);
+    assert_eq!(pick_entity(&game_state, &camera, point), Some(CameraTarget::Ship(ship)));
+}
+
+#[test]
+fn test_hidden_ships_cannot_be_picked() {
+    let mut game_state = GameState::new().unwrap();
+    game_state.faction_manager.create_faction("Player".to_string(), true, AIPersonality::Balanced).unwrap();
+    let rivals = game_state.faction_manager.create_faction("Rivals".to_string(), false, AIPersonality::Balanced).unwrap();
+    game_state.ship_manager.create_ship(ShipClass::Scout, Vector2::new(-6.0, 2.0), rivals).unwrap();
+    game_state.refresh_spatial_index();
+
+    let camera = camera();
+    let point = camera.world_to_screen(Vector2::new(-6.0, 2.0));
+    assert_eq!(pick_entity(&game_state, &camera, point), None);
+}
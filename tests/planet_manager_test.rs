@@ -141,4 +141,79 @@ mod tests {
         let result = manager.update_population(planet_id, 20);
         assert!(result.is_err());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_storage_priority_caps_and_order() {
+        let mut manager = PlanetManager::new();
+        let planet_id = manager.create_planet(OrbitalElements::default(), None).unwrap();
+        
+        // Duplicate entries are rejected
+        let mut invalid = StoragePriority::default();
+        invalid.order.push(ResourceType::Food);
+        assert!(manager.set_storage_priority(planet_id, invalid).is_err());
+        
+        // Drop fuel entirely and cap minerals at 50
+        let mut priority = StoragePriority::default();
+        priority.order.retain(|r| *r != ResourceType::Fuel);
+        priority.caps.insert(ResourceType::Minerals, 50);
+        manager.set_storage_priority(planet_id, priority).unwrap();
+        
+        let planet = manager.get_planet(planet_id).unwrap();
+        let production = ResourceBundle { minerals: 100, food: 10, fuel: 10, ..Default::default() };
+        let stored = planet.storage_priority.apply(&production, &planet.resources);
+        assert_eq!(stored.minerals, 50);
+        assert_eq!(stored.food, 10);
+        assert_eq!(stored.fuel, 0);
+    }
+
+    #[test]
+    fn test_storage_priority_fills_each_resource_within_its_own_capacity() {
+        let mut manager = PlanetManager::new();
+        let planet_id = manager.create_planet(OrbitalElements::default(), None).unwrap();
+        let planet = manager.get_planet(planet_id).unwrap();
+        
+        // Full minerals and fuel leave the other resources' room untouched
+        let mut storage = planet.resources.clone();
+        storage.current.minerals = storage.capacity.minerals;
+        storage.current.fuel = storage.capacity.fuel;
+        let production = ResourceBundle { minerals: 20, food: 20, energy: 20, alloys: 20, fuel: 20, ..Default::default() };
+        let stored = planet.storage_priority.apply(&production, &storage);
+        assert_eq!(stored, ResourceBundle { food: 20, energy: 20, alloys: 20, ..Default::default() });
+        
+        // Food is cut at its capacity whatever its place in the order
+        storage.current.food = storage.capacity.food - 5;
+        let mut food_first = planet.storage_priority.clone();
+        food_first.raise(ResourceType::Food);
+        assert_eq!(food_first.order[0], ResourceType::Food);
+        assert_eq!(food_first.apply(&production, &storage), planet.storage_priority.apply(&production, &storage));
+        assert_eq!(food_first.apply(&production, &storage).food, 5);
+        
+        // A cap cuts a resource below its capacity and leaves the rest alone
+        food_first.set_cap(ResourceType::Energy, Some(storage.current.energy + 5));
+        assert_eq!(food_first.cap(ResourceType::Energy), Some(storage.current.energy + 5));
+        let stored = food_first.apply(&production, &storage);
+        assert_eq!((stored.food, stored.energy, stored.alloys), (5, 5, 20));
+        food_first.set_cap(ResourceType::Energy, None);
+        assert_eq!(food_first.cap(ResourceType::Energy), None);
+    }
+
+    #[test]
+    fn test_storing_production_applies_the_storage_policy_once() {
+        let mut manager = PlanetManager::new();
+        let planet_id = manager.create_planet(OrbitalElements::default(), None).unwrap();
+        // Well over 12000 units in stock still leaves each resource its own room
+        let stock = ResourceBundle { minerals: 9000, food: 4000, fuel: 1900, ..Default::default() };
+        manager.add_resources(planet_id, stock).unwrap();
+        let mut priority = StoragePriority::default();
+        priority.order.retain(|r| *r != ResourceType::Alloys);
+        priority.set_cap(ResourceType::Food, Some(4005));
+        manager.set_storage_priority(planet_id, priority).unwrap();
+
+        let production = ResourceBundle { minerals: 20, food: 20, alloys: 20, fuel: 200, ..Default::default() };
+        let mut transaction = manager.transaction(planet_id).unwrap();
+        let stored = transaction.store_up_to_capacity(&production).unwrap();
+        assert_eq!(stored, ResourceBundle { minerals: 20, food: 5, fuel: 100, ..Default::default() });
+        let planet = transaction.commit().unwrap();
+        assert_eq!(planet.resources.current.fuel, planet.resources.capacity.fuel);
+    }
+}
@@ -0,0 +1,83 @@
+// tests/save_load_view_test.rs
+use macroquad::prelude::MouseButton;
+use stellar_dominion::core::*;
+use stellar_dominion::systems::save_system::SaveInfo;
+use stellar_dominion::ui_v2::panels::save_load_panel::{format_timestamp, SAVE_LOAD_VIEW_TYPE};
+use stellar_dominion::ui_v2::{DialogManager, InputEvent, SaveLoadMode, SaveLoadPanel, View};
+
+fn slot(name: &str, timestamp: u64, tick: u64) -> SaveInfo {
+    SaveInfo { name: name.to_string(), timestamp, tick, planets: 3, ships: 2, factions: 2 }
+}
+
+fn click(x: f32, y: f32) -> InputEvent {
+    InputEvent::MouseClick { x, y, button: MouseButton::Left }
+}
+
+/// Click the listed slot row at `index`
+fn click_slot(index: usize) -> InputEvent {
+    click(500.0, 200.0 + index as f32 * 30.0)
+}
+
+fn click_confirm() -> InputEvent {
+    click(340.0, 495.0)
+}
+
+#[test]
+fn test_load_dialog_emits_command_for_selected_slot() {
+    let mut dialogs = DialogManager::new();
+    let slots = vec![slot("empire", 1_700_000_000, 900), slot("early", 1_600_000_000, 40)];
+    dialogs.open(Box::new(SaveLoadPanel::new(SaveLoadMode::Load, slots)));
+
+    // Nothing to load until a slot is picked
+    assert!(dialogs.handle_input(&click_confirm()).unwrap().is_none());
+    assert!(dialogs.is_open(SAVE_LOAD_VIEW_TYPE));
+
+    assert!(dialogs.handle_input(&click_slot(1)).unwrap().is_none());
+    let command = dialogs.handle_input(&click_confirm()).unwrap();
+    assert!(matches!(command, Some(PlayerCommand::LoadGameFromSlot(name)) if name == "early"));
+    assert!(!dialogs.is_active(), "Loading closes the dialog");
+}
+
+#[test]
+fn test_save_dialog_uses_typed_or_listed_slot_name() {
+    let mut panel = SaveLoadPanel::new(SaveLoadMode::Save, vec![slot("empire", 1_700_000_000, 900)]);
+    assert!(panel.confirm().is_none(), "A slot name is required");
+
+    panel.select_slot(0);
+    assert_eq!(panel.slot_name(), "empire");
+    panel.set_slot_name("  second campaign ");
+    assert!(panel.selected_slot().is_none());
+    assert!(matches!(panel.confirm(), Some(PlayerCommand::SaveGameToSlot(name)) if name == "second campaign"));
+    assert!(!panel.is_visible());
+
+    assert_eq!(format_timestamp(0), "1970-01-01 00:00");
+    assert_eq!(format_timestamp(1_700_000_000), "2023-11-14 22:13");
+}
+
+#[test]
+fn test_game_state_saves_and_loads_named_slots() {
+    let mut game_state = GameState::new().unwrap();
+    game_state.start_new_game().unwrap();
+    game_state.queue_event(GameEvent::PlayerCommand(PlayerCommand::SaveGameToSlot("test_slot_view".to_string())));
+    game_state.fixed_update(0.1).unwrap();
+    game_state.save_system.wait_for_pending_save(&mut game_state.event_bus);
+    assert_eq!(game_state.current_save_name.as_deref(), Some("test_slot_view"));
+
+    // The dialog lists the new slot
+    let panel = SaveLoadPanel::for_game(SaveLoadMode::Load, &game_state).unwrap();
+    let saved = panel.slots().iter().find(|info| info.name == "test_slot_view").unwrap();
+    let saved_tick = saved.tick;
+
+    for _ in 0..5 {
+        game_state.fixed_update(0.1).unwrap();
+    }
+    assert!(game_state.get_current_tick() > saved_tick);
+    game_state.handle_menu_event(GameEvent::PlayerCommand(PlayerCommand::LoadGameFromSlot("test_slot_view".to_string()))).unwrap();
+    assert_eq!(game_state.get_current_tick(), saved_tick);
+    assert_eq!(game_state.current_mode, GameMode::InGame);
+
+    // Slot names cannot reach outside the save directory
+    let escape = PlayerCommand::LoadGameFromSlot("../test_slot_view".to_string());
+    assert!(game_state.handle_menu_event(GameEvent::PlayerCommand(escape)).is_err());
+    game_state.save_system.delete_save("test_slot_view").unwrap();
+}
@@ -13,6 +13,7 @@ use stellar_dominion::systems::SaveSystem;
 use stellar_dominion::systems::save_system::{SaveData, SaveInfo};
 use std::fs;
 use std::path::PathBuf;
+use std::collections::{BTreeMap, BTreeSet};
 
 /// Test fixture for creating save system test data
 struct SaveTestFixture;
@@ -50,6 +51,7 @@ impl SaveTestFixture {
             starting_population: 2000,
             galaxy_size: GalaxySize::Medium,
             ai_opponents: 3,
+            ..Default::default()
         }
     }
     
@@ -62,6 +64,8 @@ impl SaveTestFixture {
                 semi_major_axis: 1.0 + (id as f32 * 0.5),
                 period: 365.0 + (id as f32 * 50.0),
                 phase: (id as f32 * 0.1),
+                eccentricity: 0.0,
+                argument_of_periapsis: 0.0,
             },
             resources: ResourceStorage {
                 current: ResourceBundle {
@@ -84,6 +88,7 @@ impl SaveTestFixture {
             population: Demographics {
                 total: total_pop,
                 growth_rate: 0.02,
+                happiness: 0.0,
                 allocation: WorkerAllocation {
                     agriculture: 150,
                     mining: 200,
@@ -94,18 +99,17 @@ impl SaveTestFixture {
                 },
             },
             developments: vec![
-                Building {
-                    building_type: BuildingType::Mine,
-                    tier: 1,
-                    operational: true,
-                },
-                Building {
-                    building_type: BuildingType::Farm,
-                    tier: 1,
-                    operational: true,
-                },
+                Building::new(BuildingType::Mine, 1),
+                Building::new(BuildingType::Farm, 1),
             ],
             controller: Some(0),
+            storage_priority: StoragePriority::default(),
+            power_priority: PowerPriority::default(),
+            size: DEFAULT_PLANET_SIZE,
+            tax_rate: TaxRate::default(),
+            traits: PlanetTraits::default(),
+            system: 0,
+            name: None,
         }
     }
     
@@ -130,6 +134,11 @@ impl SaveTestFixture {
             },
             fuel: 100.0,
             owner: faction_id,
+            status: ShipStatus::Idle,
+            orbit_phase: 0.0,
+            damage: 0,
+            design: None,
+            name: None,
         }
     }
     
@@ -141,6 +150,16 @@ impl SaveTestFixture {
             is_player: id == 0,
             ai_type: AIPersonality::Balanced,
             score: 1000 + (id as i32 * 100),
+            inbox: Inbox::default(),
+            technologies: BTreeSet::new(),
+            research: ResearchState::default(),
+            relations: BTreeMap::new(),
+            explored_planets: BTreeSet::new(),
+            kind: FactionKind::Empire,
+            credits: 0,
+            tax_rate: TaxRate::default(),
+            ship_designs: Vec::new(),
+            control_groups: BTreeMap::new(),
         }
     }
     
@@ -167,6 +186,12 @@ impl SaveTestFixture {
                 Self::create_test_faction(2), // AI faction
             ],
             game_configuration: Self::create_test_game_configuration(),
+            market: Default::default(),
+            rng: None,
+            statistics: Default::default(),
+            intel: Default::default(),
+            star_map: Default::default(),
+            trade_routes: Vec::new(),
         }
     }
 }
@@ -268,6 +293,81 @@ mod save_load_tests {
         assert!(save_system.validate_save_integrity(&invalid_workers).is_err());
     }
     
+    #[test]
+    fn test_background_save_reports_completion() {
+        let mut game_state = GameState::new().unwrap();
+        game_state.faction_manager.create_faction("Player".to_string(), true, AIPersonality::Balanced).unwrap();
+        game_state.planet_manager.create_planet(OrbitalElements::default(), Some(0)).unwrap();
+        
+        let directory = std::env::temp_dir().join(format!("stellar_dominion_background_save_{}", std::process::id()));
+        let mut save_system = SaveSystem::with_directory(&directory);
+        save_system.begin_save(SaveData::capture(&game_state, "test_background_save")).unwrap();
+        assert!(save_system.is_saving());
+        
+        // Concurrent saves are rejected until the first finishes
+        assert!(save_system.begin_save(SaveData::capture(&game_state, "test_background_save")).is_err());
+        
+        let mut event_bus = EventBus::new();
+        save_system.wait_for_pending_save(&mut event_bus);
+        assert!(!save_system.is_saving());
+        assert!(event_bus.queued_events.iter().any(|e| matches!(e,
+            GameEvent::SimulationEvent(SimulationEvent::SaveCompleted { slot }) if slot == "test_background_save")));
+        
+        // The writer thread compresses the slot
+        let written = fs::read(directory.join("test_background_save.sav")).unwrap();
+        assert_eq!(&written[..2], &[0x1f, 0x8b]);
+        
+        let loaded = save_system.load_game_from_slot("test_background_save").unwrap();
+        assert_eq!(loaded.planets.len(), 1);
+        fs::remove_dir_all(&directory).ok();
+    }
+    
+    #[test]
+    fn test_uncompressed_slots_still_load() {
+        let mut game_state = GameState::new().unwrap();
+        game_state.faction_manager.create_faction("Player".to_string(), true, AIPersonality::Balanced).unwrap();
+        game_state.planet_manager.create_planet(OrbitalElements::default(), Some(0)).unwrap();
+        
+        let directory = std::env::temp_dir().join(format!("stellar_dominion_plain_save_{}", std::process::id()));
+        let save_system = SaveSystem::with_directory(&directory);
+        // Exports are the plain JSON older versions saved slots as
+        SaveSystem::export_json(&game_state, &directory.join("plain.sav")).unwrap();
+        
+        let loaded = save_system.load_game_from_slot("plain").unwrap();
+        assert_eq!(loaded.planets.len(), 1);
+        fs::remove_dir_all(&directory).ok();
+    }
+    
+    #[test]
+    fn test_autosave_rotates_slots_and_skips_while_paused() {
+        let mut game_state = GameState::new().unwrap();
+        game_state.set_game_configuration(GameConfiguration {
+            autosave: AutosaveSettings { interval_ticks: 5, slots: 2, skip_while_paused: true },
+            ..Default::default()
+        });
+        game_state.start_new_game().unwrap();
+        
+        // Ticks 5, 10 and 15 write autosave_1, autosave_2, then autosave_1 again
+        for _ in 0..15 {
+            game_state.fixed_update(0.1).unwrap();
+            game_state.save_system.wait_for_pending_save(&mut game_state.event_bus);
+        }
+        let save_system = SaveSystem::new();
+        assert_eq!(save_system.load_game_from_slot("autosave_1").unwrap().tick, 15);
+        assert_eq!(save_system.load_game_from_slot("autosave_2").unwrap().tick, 10);
+        assert!(!save_system.save_exists("autosave_3"));
+        assert!(game_state.current_save_name.is_none(), "Autosaves do not become the quick save slot");
+        
+        // A due autosave is skipped while paused
+        game_state.time_manager.handle_event(&GameEvent::PlayerCommand(PlayerCommand::PauseGame(true))).unwrap();
+        let mut autosaver = SaveSystem::new();
+        autosaver.set_autosave_settings(game_state.save_system.autosave_settings());
+        assert_eq!(autosaver.next_autosave_slot(game_state.is_paused()), None);
+        
+        save_system.delete_save("autosave_1").unwrap();
+        save_system.delete_save("autosave_2").unwrap();
+    }
+    
     #[test]
     fn test_save_name_validation() {
         let save_system = SaveSystem::new();
@@ -358,6 +458,12 @@ mod error_handling_tests {
             ships: vec![],
             factions: vec![],
             game_configuration: SaveTestFixture::create_test_game_configuration(),
+            market: Default::default(),
+            rng: None,
+            statistics: Default::default(),
+            intel: Default::default(),
+            star_map: Default::default(),
+            trade_routes: Vec::new(),
         };
         
         // Should fail validation due to empty planets and factions
@@ -430,4 +536,125 @@ mod cleanup {
             }
         }
     }
-}
\ No newline at end of file
+}
+/// JSON export and import of a running game
+#[cfg(test)]
+mod json_export_tests {
+    use super::*;
+    
+    fn export_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("stellar_dominion_{}_{}.json", name, std::process::id()))
+    }
+    
+    #[test]
+    fn test_export_import_round_trip_is_stable() {
+        let mut game_state = GameState::new().unwrap();
+        game_state.start_new_game().unwrap();
+        for _ in 0..5 {
+            game_state.fixed_update(0.1).unwrap();
+        }
+        
+        let first = export_path("first");
+        let second = export_path("second");
+        SaveSystem::export_json(&game_state, &first).unwrap();
+        SaveSystem::export_json(&game_state, &second).unwrap();
+        let exported = fs::read_to_string(&first).unwrap();
+        assert_eq!(exported, fs::read_to_string(&second).unwrap(), "The same state exports identically");
+        
+        // Importing into a fresh game and exporting again gives the same file
+        let imported = SaveSystem::import_json(&first).unwrap();
+        let mut restored = GameState::new().unwrap();
+        restored.apply_save_data(imported).unwrap();
+        SaveSystem::export_json(&restored, &second).unwrap();
+        assert_eq!(exported, fs::read_to_string(&second).unwrap());
+        
+        fs::remove_file(&first).ok();
+        fs::remove_file(&second).ok();
+    }
+    
+    #[test]
+    fn test_random_numbers_continue_after_a_round_trip() {
+        let mut game_state = GameState::new().unwrap();
+        game_state.set_game_configuration(GameConfiguration { seed: 99, ..Default::default() });
+        game_state.start_new_game().unwrap();
+        assert_eq!(game_state.event_bus.rng, SimRng::new(99), "Each game starts from its seed");
+        game_state.event_bus.rng.next_u64(RngStream::Combat);
+        
+        let path = export_path("rng");
+        SaveSystem::export_json(&game_state, &path).unwrap();
+        let imported = SaveSystem::import_json(&path).unwrap();
+        let mut restored = GameState::new().unwrap();
+        restored.apply_save_data(imported.clone()).unwrap();
+        assert_eq!(restored.event_bus.rng, game_state.event_bus.rng);
+        assert_eq!(restored.event_bus.rng.next_u64(RngStream::Combat), game_state.event_bus.rng.next_u64(RngStream::Combat));
+        
+        // Saves from before the generator was saved start over from the seed
+        restored.apply_save_data(SaveData { rng: None, ..imported }).unwrap();
+        assert_eq!(restored.event_bus.rng, SimRng::new(99));
+        
+        fs::remove_file(&path).ok();
+    }
+    
+    #[test]
+    fn test_statistics_continue_after_a_round_trip() {
+        let mut game_state = GameState::new().unwrap();
+        game_state.start_new_game().unwrap();
+        for _ in 0..25 {
+            game_state.fixed_update(0.1).unwrap();
+        }
+        assert!(!game_state.statistics_system.samples().is_empty());
+        
+        let path = export_path("statistics");
+        SaveSystem::export_json(&game_state, &path).unwrap();
+        let imported = SaveSystem::import_json(&path).unwrap();
+        let mut restored = GameState::new().unwrap();
+        restored.apply_save_data(imported.clone()).unwrap();
+        assert_eq!(restored.statistics_system.state(), game_state.statistics_system.state());
+        
+        // Saves from before statistics were saved start counting again
+        restored.apply_save_data(SaveData { statistics: Default::default(), ..imported }).unwrap();
+        assert!(restored.statistics_system.samples().is_empty());
+        
+        fs::remove_file(&path).ok();
+    }
+    
+    #[test]
+    fn test_import_rejects_inconsistent_state() {
+        let path = export_path("invalid");
+        
+        // Managers load each kind of entity on its own, so the export carries the mismatch
+        let mut orphaned = SaveTestFixture::create_test_save_data("orphaned", 10);
+        orphaned.ships[0].owner = 9;
+        let mut game_state = GameState::new().unwrap();
+        game_state.apply_save_data(orphaned).unwrap();
+        SaveSystem::export_json(&game_state, &path).unwrap();
+        assert!(matches!(SaveSystem::import_json(&path), Err(GameError::SaveCorrupted { .. })));
+        
+        fs::write(&path, "{ \"version\": 1, \"planets\": ").unwrap();
+        assert!(matches!(SaveSystem::import_json(&path), Err(GameError::SaveCorrupted { .. })));
+        
+        fs::remove_file(&path).ok();
+    }
+    
+    #[test]
+    fn test_trade_routes_survive_a_round_trip() {
+        let mut save_data = SaveTestFixture::create_test_save_data("routes", 10);
+        save_data.ships[0].ship_class = ShipClass::Transport;
+        let route = TradeRoute { ship: 0, from: 0, to: 2, resources: ResourceBundle { minerals: 100, ..Default::default() } };
+        save_data.trade_routes = vec![route.clone()];
+        let mut game_state = GameState::new().unwrap();
+        game_state.apply_save_data(save_data.clone()).unwrap();
+        
+        let path = export_path("routes");
+        SaveSystem::export_json(&game_state, &path).unwrap();
+        let mut restored = GameState::new().unwrap();
+        restored.apply_save_data(SaveSystem::import_json(&path).unwrap()).unwrap();
+        assert_eq!(restored.ship_manager.get_trade_route(0), Some(&route));
+        
+        // A route naming a ship the save does not have is corruption
+        save_data.trade_routes[0].ship = 9;
+        assert!(matches!(SaveSystem::new().validate_save_integrity(&save_data), Err(GameError::SaveCorrupted { .. })));
+        
+        fs::remove_file(&path).ok();
+    }
+}
@@ -39,12 +39,16 @@ fn test_orbital_elements_deterministic() {
         semi_major_axis: 5.0,
         period: 365.0,
         phase: 0.0,
+        eccentricity: 0.0,
+        argument_of_periapsis: 0.0,
     };
     
     let orbit2 = OrbitalElements {
         semi_major_axis: 5.0,
         period: 365.0,
         phase: 0.0,
+        eccentricity: 0.0,
+        argument_of_periapsis: 0.0,
     };
     
     // Same parameters should produce same results
@@ -90,12 +94,16 @@ fn test_transfer_window_logic() {
         semi_major_axis: 5.0,
         period: 365.0,
         phase: 0.0,
+        eccentricity: 0.0,
+        argument_of_periapsis: 0.0,
     };
     
     let planet2_orbit = OrbitalElements {
         semi_major_axis: 8.0,
         period: 500.0,
         phase: 1.57, // 90 degrees
+        eccentricity: 0.0,
+        argument_of_periapsis: 0.0,
     };
     
     // Transfer windows should be calculable
@@ -114,6 +122,7 @@ fn test_ship_arrival_detection() {
         departure_time: 0,
         arrival_time: 100,
         fuel_cost: 10.0,
+        waypoints: Vec::new(),
     };
     
     // Should be able to determine when ship has arrived
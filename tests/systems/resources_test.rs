@@ -53,6 +53,7 @@ fn test_planet_production_calculation() {
         population: Demographics {
             total: 1000,
             growth_rate: 0.02,
+            happiness: 0.0,
             allocation: WorkerAllocation {
                 agriculture: 200,
                 mining: 300,
@@ -67,14 +68,20 @@ fn test_planet_production_calculation() {
                 building_type: BuildingType::Mine,
                 tier: 1,
                 operational: true,
+                disabled: false,
             },
             Building {
                 building_type: BuildingType::Farm,
                 tier: 1,
                 operational: true,
+                disabled: false,
             },
         ],
         controller: Some(1),
+        storage_priority: StoragePriority::default(),
+        power_priority: PowerPriority::default(),
+        size: DEFAULT_PLANET_SIZE,
+        tax_rate: TaxRate::default(),
     };
     
     // Test production calculation
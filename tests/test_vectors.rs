@@ -0,0 +1,23 @@
+// tests/test_vectors.rs
+//! Runs the built-in deterministic test vectors. Requires `--features test-api`.
+#![cfg(feature = "test-api")]
+
+use stellar_dominion::test_api::*;
+
+#[test]
+fn test_builtin_vectors_match_reference_hashes() {
+    let report = run_all(&builtin_vectors());
+    assert!(report.is_success(), "{}", report);
+}
+
+#[test]
+fn test_runner_reports_mismatch() {
+    let mut vectors = builtin_vectors();
+    vectors.truncate(1);
+    vectors[0].expected_hash ^= 1;
+
+    let report = run_all(&vectors);
+    assert_eq!(report.total, 1);
+    assert_eq!(report.mismatches.len(), 1);
+    assert!(report.to_string().contains(vectors[0].name));
+}
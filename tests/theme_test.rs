@@ -0,0 +1,36 @@
+// tests/theme_test.rs
+use stellar_dominion::ui_v2::core::Theme;
+use stellar_dominion::ui_v2::{ThemeManager, UISystem};
+
+#[test]
+fn test_theme_manager_cycles_built_in_themes() {
+    let mut themes = ThemeManager::new();
+    assert_eq!(themes.names(), vec!["Dark", "High contrast"]);
+    assert_eq!(themes.current().name, Theme::default().name);
+
+    assert_eq!(themes.cycle().name, "High contrast");
+    assert_eq!(themes.cycle().name, "Dark", "Cycling wraps around");
+
+    assert!(themes.select("High contrast"));
+    assert_eq!(themes.current().name, "High contrast");
+    assert!(!themes.select("Neon"));
+    assert_eq!(themes.current().name, "High contrast", "An unknown name keeps the theme");
+}
+
+#[test]
+fn test_high_contrast_separates_factions() {
+    let theme = Theme::high_contrast();
+    let distance = |a: macroquad::prelude::Color, b: macroquad::prelude::Color| {
+        ((a.r - b.r).powi(2) + (a.g - b.g).powi(2) + (a.b - b.b).powi(2)).sqrt()
+    };
+    assert!(distance(theme.player_color, theme.rival_color) > 0.5);
+    assert!(distance(theme.text_color, theme.panel_background) > 1.5);
+}
+
+#[test]
+fn test_ui_system_takes_switched_theme() {
+    let mut themes = ThemeManager::new();
+    let mut ui_system = UISystem::new();
+    ui_system.set_theme(themes.cycle().clone());
+    assert_eq!(ui_system.get_theme().name, "High contrast");
+}